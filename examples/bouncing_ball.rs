@@ -0,0 +1,96 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Bounces a ball off the edges of the window, driving the window, the renderer and a
+//! `StepTimer` directly instead of through the `app` game loop.
+
+use sky_labs::prelude::*;
+
+const RADIUS: f32 = 24.0;
+/// Initial velocity of the ball, in pixels per second.
+const VELOCITY: Vector2<f32> = Vector2::new(320.0, 240.0);
+/// Longest time step simulated at once, so the ball doesn't tunnel out of the window
+/// after a stall such as dragging the window around.
+const MAX_STEP_SECONDS: f32 = 0.1;
+
+struct Ball {
+    center: Vector2<f32>,
+    velocity: Vector2<f32>,
+}
+
+impl Ball {
+    /// Moves the ball by `dt` seconds, reflecting it off the edges of an area of `bounds`
+    /// with its top left corner at the origin.
+    fn update(&mut self, dt: f32, bounds: Size<f32>) {
+        self.center += self.velocity * dt;
+
+        // The inward normal of each edge and how far the ball went past it.
+        let max = Vector2::new(bounds.width - RADIUS, bounds.height - RADIUS);
+        let edges = [
+            (Vector2::new(1.0, 0.0), RADIUS - self.center.x),
+            (Vector2::new(-1.0, 0.0), self.center.x - max.x),
+            (Vector2::new(0.0, 1.0), RADIUS - self.center.y),
+            (Vector2::new(0.0, -1.0), self.center.y - max.y),
+        ];
+        for (normal, depth) in edges {
+            let speed_towards_edge = self.velocity.dot(normal);
+            // Only reflect a ball still moving out, so one past both edges of a window
+            // too small for it doesn't flip back and forth every frame.
+            if depth > 0.0 && speed_towards_edge < 0.0 {
+                self.velocity -= normal * (2.0 * speed_towards_edge);
+                self.center += normal * (2.0 * depth);
+            }
+        }
+    }
+}
+
+fn main() {
+    let mut window = Window::create();
+    window.set_title("Bouncing ball");
+    let renderer = DefaultRenderer::create_for_window(&window);
+    let background = Color::<f32>::from_rgba_hex(0x101020FF);
+    let ball_color = Color::<f32>::from_rgba_hex(0xF05040FF);
+
+    let mut ball = Ball {
+        center: Vector2::new(RADIUS * 2.0, RADIUS * 2.0),
+        velocity: VELOCITY,
+    };
+    let mut timer = StepTimer::new();
+
+    loop {
+        match window.pump_messages() {
+            WindowProcessResult::Exit => break,
+            WindowProcessResult::Error(e) => panic!("Unable to process messages: {}", e),
+            _ => {}
+        }
+
+        // The renderer follows the window size on its own, the queue only needs draining.
+        while window.poll_event().is_some() {}
+
+        timer.tick();
+        let dt = (timer.elapsed_seconds() as f32).min(MAX_STEP_SECONDS);
+        ball.update(dt, renderer.size());
+
+        let mut drawing_session = renderer.begin_draw();
+        drawing_session.clear(&background);
+        drawing_session.draw_circle_centered_at(&ball.center, RADIUS, &ball_color);
+        renderer.end_draw(drawing_session);
+        renderer.present();
+    }
+}
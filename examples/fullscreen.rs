@@ -0,0 +1,53 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Toggles between windowed and borderless fullscreen with F11.
+
+use sky_labs::input::keyboard::get_key_state;
+use sky_labs::prelude::*;
+
+fn main() {
+    let mut window = Window::create();
+    let renderer = DefaultRenderer::create_for_window(&window);
+    let background = Color::<f32>::from_rgba_hex(0x1E3A5FFF);
+
+    loop {
+        match window.pump_messages() {
+            WindowProcessResult::Exit => break,
+            WindowProcessResult::Error(e) => panic!("Unable to process messages: {}", e),
+            _ => {}
+        }
+
+        // The renderer follows the window size on its own, the queue only needs draining.
+        while window.poll_event().is_some() {}
+
+        if get_key_state(VirtualKey::F11) == KeyState::WasPressed {
+            let mode = match window.fullscreen_mode() {
+                FullscreenMode::Windowed => FullscreenMode::Borderless,
+                FullscreenMode::Borderless => FullscreenMode::Windowed,
+            };
+            window.set_fullscreen(mode);
+        }
+
+        let mut drawing_session = renderer.begin_draw();
+        drawing_session.clear(&background);
+        renderer.end_draw(drawing_session);
+        renderer.present();
+    }
+}
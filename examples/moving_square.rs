@@ -0,0 +1,74 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Moves a square around with the arrow keys using the `app` game loop.
+
+use sky_labs::app::run;
+use sky_labs::prelude::*;
+
+/// Speed of the square, in pixels per second.
+const SPEED: f32 = 300.0;
+const SQUARE_SIZE: f32 = 50.0;
+
+struct MovingSquare {
+    position: Vector2<f32>,
+}
+
+impl Application for MovingSquare {
+    fn update(&mut self, dt: f64, input: &InputState) {
+        let mut direction = Vector2::<f32>::zero();
+        if input.is_key_down(VirtualKey::LEFT) {
+            direction.x -= 1.0;
+        }
+        if input.is_key_down(VirtualKey::RIGHT) {
+            direction.x += 1.0;
+        }
+        if input.is_key_down(VirtualKey::UP) {
+            direction.y -= 1.0;
+        }
+        if input.is_key_down(VirtualKey::DOWN) {
+            direction.y += 1.0;
+        }
+        self.position += direction * (SPEED * dt as f32);
+    }
+
+    fn render(&mut self, session: &mut dyn DrawingSession) {
+        session.clear(&Color::from_rgba_hex(0x101020FF));
+        let square = Rect {
+            x: self.position.x,
+            y: self.position.y,
+            width: SQUARE_SIZE,
+            height: SQUARE_SIZE,
+        };
+        session.draw_rectangle(&square, &Color::from_rgba_hex(0xF0C040FF));
+    }
+}
+
+fn main() {
+    let config = AppConfig {
+        title: String::from("Moving square"),
+        ..Default::default()
+    };
+    let app = MovingSquare {
+        position: Vector2::new(100.0, 100.0),
+    };
+    if let Err(e) = run(config, app) {
+        eprintln!("{}", e);
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Snake on a `Grid`, steered with the arrow keys. The snake moves a cell per fixed time
+//! step, whatever the framerate of the `app` game loop. Space restarts after a game over.
+
+use std::collections::VecDeque;
+
+use sky_labs::app::run;
+use sky_labs::math::grid::Grid;
+use sky_labs::math::Direction;
+use sky_labs::prelude::*;
+
+/// Seconds between two moves of the snake.
+const STEP_SECONDS: f64 = 0.12;
+const CELL_SIZE: f32 = 40.0;
+/// Columns and rows of the board, filling the default 1280x720 window.
+const DIMENSIONS: Vector2<u32> = Vector2::new(32, 18);
+const INITIAL_LENGTH: usize = 3;
+
+const KEYS: [(VirtualKey, Direction); 4] = [
+    (VirtualKey::UP, Direction::North),
+    (VirtualKey::RIGHT, Direction::East),
+    (VirtualKey::DOWN, Direction::South),
+    (VirtualKey::LEFT, Direction::West),
+];
+
+struct Snake {
+    grid: Grid,
+    /// Cells of the snake, from its head to its tail.
+    body: VecDeque<Vector2<i32>>,
+    direction: Direction,
+    /// Direction of the next move, applied at the next step so that two key presses within
+    /// a step can't turn the snake back into itself.
+    next_direction: Direction,
+    food: Vector2<i32>,
+    /// Time not yet consumed by steps, in seconds.
+    accumulator: f64,
+    game_over: bool,
+    /// State of the xorshift generator placing the food.
+    seed: u32,
+}
+
+impl Snake {
+    fn new() -> Self {
+        let mut snake = Snake {
+            grid: Grid::new(Size::new(CELL_SIZE, CELL_SIZE), Vector2::zero(), DIMENSIONS),
+            body: VecDeque::new(),
+            direction: Direction::East,
+            next_direction: Direction::East,
+            food: Vector2::zero(),
+            accumulator: 0.0,
+            game_over: false,
+            seed: 0x9E3779B9,
+        };
+        snake.restart();
+        snake
+    }
+
+    fn restart(&mut self) {
+        let head = Vector2::new(DIMENSIONS.x as i32 / 4, DIMENSIONS.y as i32 / 2);
+        self.body.clear();
+        self.body
+            .extend((0..INITIAL_LENGTH as i32).map(|i| head - Vector2::new(i, 0)));
+        self.direction = Direction::East;
+        self.next_direction = Direction::East;
+        self.accumulator = 0.0;
+        self.game_over = false;
+        self.place_food();
+    }
+
+    /// Moves the food to a random cell not taken by the snake.
+    fn place_food(&mut self) {
+        let cell_count = DIMENSIONS.x * DIMENSIONS.y;
+        if self.body.len() >= cell_count as usize {
+            self.game_over = true;
+            return;
+        }
+        loop {
+            self.seed ^= self.seed << 13;
+            self.seed ^= self.seed >> 17;
+            self.seed ^= self.seed << 5;
+            let index = self.seed % cell_count;
+            let cell = Vector2::new((index % DIMENSIONS.x) as i32, (index / DIMENSIONS.x) as i32);
+            if !self.body.contains(&cell) {
+                self.food = cell;
+                return;
+            }
+        }
+    }
+
+    /// Moves the snake a cell, growing it if it reaches the food.
+    /// Hitting a wall or itself ends the game.
+    fn step(&mut self) {
+        self.direction = self.next_direction;
+        let Some(head) = self.grid.neighbor(self.body[0], self.direction) else {
+            self.game_over = true;
+            return;
+        };
+
+        let eats = head == self.food;
+        if !eats {
+            self.body.pop_back();
+        }
+        if self.body.contains(&head) {
+            self.game_over = true;
+            return;
+        }
+        self.body.push_front(head);
+        if eats {
+            self.place_food();
+        }
+    }
+}
+
+impl Application for Snake {
+    fn update(&mut self, dt: f64, input: &InputState) {
+        if self.game_over {
+            if input.is_key_down(VirtualKey::SPACE) {
+                self.restart();
+            }
+            return;
+        }
+
+        for (key, direction) in KEYS {
+            if input.is_key_down(key) && direction != self.direction.opposite() {
+                self.next_direction = direction;
+            }
+        }
+
+        self.accumulator += dt;
+        while self.accumulator >= STEP_SECONDS && !self.game_over {
+            self.accumulator -= STEP_SECONDS;
+            self.step();
+        }
+    }
+
+    fn render(&mut self, session: &mut dyn DrawingSession) {
+        session.clear(&Color::from_rgba_hex(0x102010FF));
+
+        session.draw_circle_centered_at(
+            &self.grid.cell_center(self.food),
+            CELL_SIZE * 0.4,
+            &Color::from_rgba_hex(0xF04040FF),
+        );
+
+        let color = if self.game_over {
+            Color::from_rgba_hex(0x808080FF)
+        } else {
+            Color::from_rgba_hex(0x60D060FF)
+        };
+        for cell in &self.body {
+            let rect = self.grid.cell_to_world(*cell);
+            // Leave a gap between the segments of the snake.
+            let segment = Rect {
+                x: rect.x + 2.0,
+                y: rect.y + 2.0,
+                width: rect.width - 4.0,
+                height: rect.height - 4.0,
+            };
+            session.draw_rectangle(&segment, &color);
+        }
+    }
+}
+
+fn main() {
+    let config = AppConfig {
+        title: String::from("Snake"),
+        size: Size::new(
+            DIMENSIONS.x * CELL_SIZE as u32,
+            DIMENSIONS.y * CELL_SIZE as u32,
+        ),
+        ..Default::default()
+    };
+    if let Err(e) = run(config, Snake::new()) {
+        eprintln!("{}", e);
+    }
+}
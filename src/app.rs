@@ -0,0 +1,170 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use std::time::{Duration, Instant};
+
+use crate::{
+    events::{renderer::RendererEvent, Event, Observer, SyncEventQueue},
+    input::{InputSource, InputState, LiveInput},
+    math::Size,
+    renderer::{DefaultRenderer, DrawingSession, Renderer, RendererType},
+    timer::StepTimer,
+    window::{Window, WindowProcessResult},
+};
+
+/// Game callbacks driven by `run`.
+pub trait Application {
+    /// Advances the game state by `dt` seconds.
+    fn update(&mut self, dt: f64, input: &InputState);
+
+    /// Draws the current game state. The session is submitted and presented after returning.
+    fn render(&mut self, session: &mut dyn DrawingSession);
+
+    /// Called with the events of the renderer. The renderer was already recreated when this
+    /// receives `RendererEvent::DeviceLost`, so only resources the application created on it,
+    /// if any, have to be created again. Does nothing by default.
+    fn on_renderer_event(&mut self, _event: &RendererEvent) {}
+}
+
+/// Window and renderer settings used by `run`.
+pub struct AppConfig {
+    pub title: String,
+    /// Initial size of the client area, in physical pixels.
+    pub size: Size<u32>,
+    pub renderer: RendererType,
+    /// Keep calling `Application::update` while the window is not focused.
+    pub update_when_unfocused: bool,
+    /// Time between frames while the window is minimized, waiting for messages in between
+    /// rather than looping as fast as possible. `None` keeps running frames at full speed.
+    pub minimized_frame_interval: Option<Duration>,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        AppConfig {
+            title: String::from("sky-labs"),
+            size: Size::new(1280, 720),
+            renderer: RendererType::Direct3D12,
+            update_when_unfocused: true,
+            // About 10 frames per second.
+            minimized_frame_interval: Some(Duration::from_millis(100)),
+        }
+    }
+}
+
+/// Creates the window and renderer described by `config` and runs the game loop until the
+/// window is closed: process pending messages, resize the renderer if needed, update, render, present.
+/// While the window is minimized, frames are throttled to `AppConfig::minimized_frame_interval`.
+/// The renderer is recreated when its device is lost, see `Application::on_renderer_event`.
+/// Returns an error if the renderer backend is unavailable, message processing fails or the
+/// renderer can't be recreated.
+pub fn run<App: Application>(config: AppConfig, app: App) -> Result<(), String> {
+    run_with_input(config, app, &mut LiveInput::new())
+}
+
+/// Runs the game loop like `run`, with the input of each frame taken from `input`, e.g. to
+/// replay a recording with `InputPlayer`. Also returns once `input` runs out of frames.
+/// The recorded time steps replace the measured ones, so replays update the same way.
+pub fn run_with_input<App: Application>(
+    config: AppConfig,
+    app: App,
+    input: &mut impl InputSource,
+) -> Result<(), String> {
+    run_loop(config, app, input, |_| {})
+}
+
+/// Runs the game loop like `run`, also handing the events emitted into `events`, for instance
+/// by worker threads, to `Observer::on_event` of the application. The queue is drained once
+/// per frame before `Application::update`, even when `update` is skipped while unfocused.
+pub fn run_with_events<App: Application + Observer<T>, T: Event + Send>(
+    config: AppConfig,
+    app: App,
+    mut events: SyncEventQueue<T>,
+) -> Result<(), String> {
+    run_loop(config, app, &mut LiveInput::new(), |app: &mut App| {
+        events.drain(|event| app.on_event(&event))
+    })
+}
+
+/// The loop shared by the `run` functions, calling `before_update` each frame before updating.
+fn run_loop<App: Application>(
+    config: AppConfig,
+    mut app: App,
+    input: &mut impl InputSource,
+    mut before_update: impl FnMut(&mut App),
+) -> Result<(), String> {
+    let mut window = Window::create();
+    window.set_title(&config.title);
+    window.set_size(config.size);
+
+    let mut renderer = match config.renderer {
+        RendererType::Direct3D12 => DefaultRenderer::create_for_window(&window),
+        RendererType::Direct2D => return Err(String::from("Direct2D renderer is not available.")),
+    };
+
+    let mut timer = StepTimer::new();
+    loop {
+        match window.pump_messages() {
+            WindowProcessResult::Exit => return Ok(()),
+            WindowProcessResult::Error(e) => return Err(e),
+            _ => {}
+        }
+        // Messages arriving while minimized are processed as they come, without starting frames
+        // before the interval is over.
+        if let Some(interval) = config.minimized_frame_interval {
+            let next_frame = Instant::now() + interval;
+            while window.is_minimized() {
+                let Some(remaining) = next_frame.checked_duration_since(Instant::now()) else {
+                    break;
+                };
+                match window.wait_message(Some(remaining)) {
+                    WindowProcessResult::Exit => return Ok(()),
+                    WindowProcessResult::Error(e) => return Err(e),
+                    _ => {}
+                }
+            }
+        }
+
+        // Before drawing, so the renderer is recreated before it resizes for the window events.
+        while let Some(event) = renderer.poll_event() {
+            if let RendererEvent::DeviceLost(_) = event {
+                renderer.recreate(&window).map_err(|e| e.to_string())?;
+            }
+            app.on_renderer_event(&event);
+        }
+
+        // Taking the events is enough: the renderer observes the window and resizes its buffers
+        // at the next `begin_draw`.
+        while window.poll_event().is_some() {}
+
+        timer.tick();
+        let Some(frame) = input.next_frame(window.is_focused(), timer.elapsed_seconds()) else {
+            return Ok(());
+        };
+        before_update(&mut app);
+        if frame.state.is_focused() || config.update_when_unfocused {
+            app.update(frame.dt, &frame.state);
+        }
+
+        let mut drawing_session = renderer.begin_draw();
+        app.render(&mut drawing_session);
+        renderer.end_draw(drawing_session);
+        renderer.present();
+    }
+}
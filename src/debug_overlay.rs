@@ -0,0 +1,309 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Immediate mode overlay to read values out while the game runs: frames per second, counters,
+//! vectors and graphs of values over the last frames.
+//!
+//! The overlay draws on any `DrawingSession`, here one of the software renderer where a game
+//! would use the `DefaultRenderer` of its window:
+//!
+//! ```no_run
+//! use sky_labs::debug_overlay::DebugOverlay;
+//! use sky_labs::math::Size;
+//! use sky_labs::renderer::{software::SoftwareRenderer, Renderer};
+//! use sky_labs::timer::StepTimer;
+//!
+//! let renderer = SoftwareRenderer::new(Size::new(640, 480));
+//! let mut overlay = DebugOverlay::new();
+//! let mut timer = StepTimer::new();
+//! loop {
+//!     timer.tick();
+//!     overlay.begin_frame(timer.real_elapsed_seconds());
+//!     overlay.value("Entities", 42.0);
+//!     overlay.graph("Frame time", timer.real_elapsed_seconds() as f32 * 1000.0);
+//!
+//!     let mut session = renderer.begin_draw();
+//!     overlay.draw(&mut session);
+//!     renderer.end_draw(session);
+//! }
+//! ```
+
+use std::fmt::Write;
+
+use crate::math::{Rect, Vector2};
+use crate::renderer::{Color, DrawingSession, TextFormat, TextWrapping};
+use crate::timer::{FramerateCounter, PerformanceCounter};
+
+/// Width of the overlay, in pixels.
+pub const OVERLAY_WIDTH: f32 = 240.0;
+
+/// Height of each row of text, in pixels.
+pub const LINE_HEIGHT: f32 = 20.0;
+
+/// Height of the bars of a graph at its largest value, in pixels.
+pub const GRAPH_HEIGHT: f32 = 32.0;
+
+/// Number of values a graph keeps, the oldest being dropped past it.
+pub const GRAPH_CAPACITY: usize = 60;
+
+/// Space between the edges of the background and its contents, in pixels.
+const PADDING: f32 = 4.0;
+
+/// Color of the background, translucent to see the game behind.
+const BACKGROUND_COLOR: Color<f32> = Color {
+    r: 0.0,
+    g: 0.0,
+    b: 0.0,
+    a: 0.6,
+};
+
+/// Color of the bars of the graphs.
+const GRAPH_COLOR: Color<f32> = Color {
+    r: 0.0,
+    g: 1.0,
+    b: 0.0,
+    a: 1.0,
+};
+
+/// Row of the overlay, formatted into a buffer kept from frame to frame.
+#[derive(Default)]
+struct Row {
+    text: String,
+    /// Index in `DebugOverlay::graphs` of the graph drawn under the text, if any.
+    graph: Option<usize>,
+}
+
+/// Ring buffer of the last values of a graph, kept across frames.
+struct Graph {
+    label: String,
+    values: Box<[f32]>,
+    /// Index the next value is written to.
+    next: usize,
+    /// Number of values recorded, up to `GRAPH_CAPACITY`.
+    len: usize,
+}
+
+impl Graph {
+    fn new(label: &str) -> Self {
+        Self {
+            label: String::from(label),
+            values: vec![0.0; GRAPH_CAPACITY].into_boxed_slice(),
+            next: 0,
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, value: f32) {
+        self.values[self.next] = value;
+        self.next = (self.next + 1) % GRAPH_CAPACITY;
+        self.len = (self.len + 1).min(GRAPH_CAPACITY);
+    }
+
+    /// Returns the recorded values, from the oldest to the latest.
+    fn values(&self) -> impl Iterator<Item = f32> + '_ {
+        let (latest, oldest) = self.values[..self.len].split_at(self.next);
+        oldest.iter().chain(latest).copied()
+    }
+}
+
+/// Rows of debug text collected during the update of a frame and drawn over the game, at
+/// `position`, with the frames per second on the first row.
+///
+/// The rows are formatted into buffers reused from frame to frame, and each graph allocates
+/// its values once, the first time its label is given, so a steady set of rows doesn't
+/// allocate. While hidden, the overlay only counts frames: collecting and drawing do nothing.
+pub struct DebugOverlay {
+    /// Whether rows are collected and drawn. The frames are counted either way, so the
+    /// framerate is right as soon as the overlay is shown.
+    pub visible: bool,
+    /// Top left corner of the overlay, in pixels of the render target.
+    pub position: Vector2<f32>,
+    framerate: FramerateCounter,
+    /// Row buffers, the first `row_count` of them holding the rows of this frame.
+    rows: Vec<Row>,
+    row_count: usize,
+    graphs: Vec<Graph>,
+}
+
+impl DebugOverlay {
+    /// Creates a visible overlay at the top left corner.
+    pub fn new() -> Self {
+        PerformanceCounter::init();
+        Self {
+            visible: true,
+            position: Vector2::ZERO,
+            framerate: FramerateCounter::default(),
+            rows: Vec::new(),
+            row_count: 0,
+            graphs: Vec::new(),
+        }
+    }
+
+    /// Counts a frame of `dt` seconds and forgets the rows of the previous one. Call it before
+    /// collecting the rows of each frame, `dt` being the real time elapsed so the framerate
+    /// isn't scaled with the game.
+    pub fn begin_frame(&mut self, dt: f64) {
+        let ticks = dt.max(0.0) * PerformanceCounter::frequency() as f64;
+        self.framerate
+            .tick(PerformanceCounter::from_ticks(ticks.round() as u64));
+        self.row_count = 0;
+        if self.visible {
+            let frames_per_second = self.framerate.frames_per_second;
+            write!(self.next_row(None), "{frames_per_second} fps").unwrap();
+        }
+    }
+
+    /// Returns the frame counter the first row is read from.
+    pub fn framerate(&self) -> &FramerateCounter {
+        &self.framerate
+    }
+
+    /// Adds a row of `text`.
+    pub fn line(&mut self, text: &str) {
+        if self.visible {
+            self.next_row(None).push_str(text);
+        }
+    }
+
+    /// Adds a row of `label` and `value`. Whole numbers are shown without decimals, so
+    /// counts read naturally, and other numbers with three.
+    pub fn value(&mut self, label: &str, value: f64) {
+        if self.visible {
+            let row = self.next_row(None);
+            if value.fract() == 0.0 {
+                write!(row, "{label}: {value:.0}").unwrap();
+            } else {
+                write!(row, "{label}: {value:.3}").unwrap();
+            }
+        }
+    }
+
+    /// Adds a row of `label` and the components of `vector`.
+    pub fn vector2(&mut self, label: &str, vector: Vector2<f32>) {
+        if self.visible {
+            let Vector2 { x, y } = vector;
+            write!(self.next_row(None), "{label}: ({x:.2}, {y:.2})").unwrap();
+        }
+    }
+
+    /// Adds `value` to the graph of `label` and a row of `label` and `value`, with the bars of
+    /// the last `GRAPH_CAPACITY` values of the graph under it. The bars are scaled so the
+    /// largest value is `GRAPH_HEIGHT` high, negative values having no bar.
+    pub fn graph(&mut self, label: &str, value: f32) {
+        if !self.visible {
+            return;
+        }
+        let index = match self.graphs.iter().position(|graph| graph.label == label) {
+            Some(index) => index,
+            None => {
+                self.graphs.push(Graph::new(label));
+                self.graphs.len() - 1
+            }
+        };
+        self.graphs[index].push(value);
+        write!(self.next_row(Some(index)), "{label}: {value:.2}").unwrap();
+    }
+
+    /// Draws the rows of this frame over a translucent background, each below the previous
+    /// one. Does nothing while hidden.
+    pub fn draw<T: DrawingSession + ?Sized>(&self, session: &mut T) {
+        if !self.visible || self.row_count == 0 {
+            return;
+        }
+        let rows = &self.rows[..self.row_count];
+        let content_height: f32 = rows.iter().map(row_height).sum();
+        session.draw_rectangle(
+            &Rect {
+                x: self.position.x,
+                y: self.position.y,
+                width: OVERLAY_WIDTH,
+                height: content_height + 2.0 * PADDING,
+            },
+            &BACKGROUND_COLOR,
+        );
+
+        let format = TextFormat {
+            wrapping: TextWrapping::None,
+            ..TextFormat::default()
+        };
+        let left = self.position.x + PADDING;
+        let width = OVERLAY_WIDTH - 2.0 * PADDING;
+        let mut top = self.position.y + PADDING;
+        for row in rows {
+            let line = Rect {
+                x: left,
+                y: top,
+                width,
+                height: LINE_HEIGHT,
+            };
+            session.draw_text(&row.text, &format, &line);
+            if let Some(index) = row.graph {
+                draw_graph(session, &self.graphs[index], left, top + LINE_HEIGHT);
+            }
+            top += row_height(row);
+        }
+    }
+
+    /// Returns the buffer of the next row, emptied, reusing those of the previous frames.
+    fn next_row(&mut self, graph: Option<usize>) -> &mut String {
+        if self.row_count == self.rows.len() {
+            self.rows.push(Row::default());
+        }
+        let row = &mut self.rows[self.row_count];
+        self.row_count += 1;
+        row.text.clear();
+        row.graph = graph;
+        &mut row.text
+    }
+}
+
+impl Default for DebugOverlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Height a row takes, with the bars of its graph if it has one.
+fn row_height(row: &Row) -> f32 {
+    match row.graph {
+        Some(_) => LINE_HEIGHT + GRAPH_HEIGHT,
+        None => LINE_HEIGHT,
+    }
+}
+
+/// Draws the bars of `graph` from the oldest value on the left, below `top`.
+fn draw_graph<T: DrawingSession + ?Sized>(session: &mut T, graph: &Graph, left: f32, top: f32) {
+    let max = graph.values().fold(0.0, f32::max);
+    if max <= 0.0 {
+        return;
+    }
+    let bar_width = (OVERLAY_WIDTH - 2.0 * PADDING) / GRAPH_CAPACITY as f32;
+    for (i, value) in graph.values().enumerate() {
+        let height = value.max(0.0) / max * GRAPH_HEIGHT;
+        if height > 0.0 {
+            let bar = Rect {
+                x: left + i as f32 * bar_width,
+                y: top + GRAPH_HEIGHT - height,
+                width: bar_width,
+                height,
+            };
+            session.draw_rectangle(&bar, &GRAPH_COLOR);
+        }
+    }
+}
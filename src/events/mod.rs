@@ -17,9 +17,23 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+pub mod gamepad;
+pub mod renderer;
+mod sync_queue;
+pub mod window;
+
 use std::{cell::RefCell, rc::Weak};
 
-pub trait Event {}
+use crate::timer::PerformanceCounter;
+
+pub use self::sync_queue::{EventSender, SyncEventQueue};
+
+pub trait Event {
+    /// When the event happened, for events that record it. User defined events don't have to.
+    fn timestamp(&self) -> Option<PerformanceCounter> {
+        None
+    }
+}
 
 pub trait Observable<T: Event> {
     fn regiter(&mut self, obsever:Weak<RefCell<dyn Observer<T>>>);
@@ -0,0 +1,33 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Events published by renderers, taken with `Direct3D12Renderer::poll_event`.
+
+use super::Event;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RendererEvent {
+    /// The GPU device was removed or reset, for instance by a driver update, a GPU reset or the
+    /// window moving to another adapter, carrying the reason reported by the driver.
+    /// Nothing is drawn until the renderer is rebuilt with `Direct3D12Renderer::recreate`,
+    /// which `app::run` does before passing the event to `Application::on_renderer_event`.
+    DeviceLost(String),
+}
+
+impl Event for RendererEvent {}
@@ -0,0 +1,120 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Delivery of events emitted on other threads, such as asset loading or networking workers,
+//! to the thread running the game loop.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, Weak};
+
+use super::Event;
+
+/// A queue of events emitted from any thread and processed on the thread owning the queue.
+///
+/// `Observable` and `Observer` are built on `Rc<RefCell<...>>` and stay on one thread. Workers
+/// instead emit through an `EventSender` obtained from `sender`, and the owning thread handles
+/// everything emitted since the previous call with `drain`. Events from one sender arrive in the
+/// order they were emitted, events from different senders are interleaved in the order they
+/// reached the queue. `app::run_with_events` drains a queue once per frame, before `update`.
+///
+/// ```
+/// use sky_labs::events::{Event, SyncEventQueue};
+///
+/// struct Loaded(u32);
+/// impl Event for Loaded {}
+///
+/// let mut queue = SyncEventQueue::new();
+/// let sender = queue.sender();
+/// std::thread::spawn(move || sender.emit(Loaded(7))).join().unwrap();
+///
+/// let mut loaded = Vec::new();
+/// queue.drain(|Loaded(id)| loaded.push(id));
+/// assert_eq!(loaded, [7]);
+/// ```
+pub struct SyncEventQueue<T: Event + Send> {
+    shared: Arc<Mutex<VecDeque<T>>>,
+    /// Events taken out of `shared` by `drain`, kept to reuse its allocation.
+    draining: VecDeque<T>,
+}
+
+impl<T: Event + Send> SyncEventQueue<T> {
+    pub fn new() -> Self {
+        Self {
+            shared: Arc::new(Mutex::new(VecDeque::new())),
+            draining: VecDeque::new(),
+        }
+    }
+
+    /// Returns a handle emitting events into this queue, which can be cloned and sent to other
+    /// threads.
+    pub fn sender(&self) -> EventSender<T> {
+        EventSender {
+            shared: Arc::downgrade(&self.shared),
+        }
+    }
+
+    /// Calls `handler` with each event emitted since the previous call, in order.
+    /// Events emitted while draining, including by `handler`, are left for the next call, so
+    /// workers emitting continuously can't keep this from returning.
+    pub fn drain(&mut self, mut handler: impl FnMut(T)) {
+        std::mem::swap(&mut *lock(&self.shared), &mut self.draining);
+        for event in self.draining.drain(..) {
+            handler(event);
+        }
+    }
+}
+
+impl<T: Event + Send> Default for SyncEventQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Handle to emit events into a `SyncEventQueue` from any thread, created by
+/// `SyncEventQueue::sender`.
+pub struct EventSender<T: Event + Send> {
+    shared: Weak<Mutex<VecDeque<T>>>,
+}
+
+impl<T: Event + Send> EventSender<T> {
+    /// Adds `event` to the queue, to be handled by the next `SyncEventQueue::drain`.
+    /// Returns false, dropping the event, if the queue no longer exists.
+    pub fn emit(&self, event: T) -> bool {
+        let Some(shared) = self.shared.upgrade() else {
+            return false;
+        };
+        lock(&shared).push_back(event);
+        true
+    }
+}
+
+// Derived `Clone` would require `T: Clone`.
+impl<T: Event + Send> Clone for EventSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+/// Locks the queue, ignoring poisoning: a panic while holding the lock can't leave the
+/// `VecDeque` itself inconsistent.
+fn lock<T>(shared: &Mutex<VecDeque<T>>) -> MutexGuard<'_, VecDeque<T>> {
+    shared.lock().unwrap_or_else(PoisonError::into_inner)
+}
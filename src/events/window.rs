@@ -0,0 +1,153 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Events published by windows, taken with `Window::poll_event` in the order they happened.
+
+use super::Event;
+use crate::math::{Size, Vector2};
+use crate::timer::PerformanceCounter;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WindowEvent {
+    /// The client area changed size, carrying the new size in physical pixels.
+    /// Renderers created with `RendererOptions::auto_resize` follow it, others must be resized
+    /// by calling `resize` on them with the new size.
+    Resized(Size<u32>),
+    /// The window moved, carrying the screen position of the client area origin in physical pixels.
+    Moved(Vector2<i32>),
+    /// The window gained keyboard focus.
+    FocusGained,
+    /// The window lost keyboard focus. Keys held at that point get no `Released` event.
+    FocusLost,
+    /// The window was minimized. Its client area is then empty, which a `Resized` to a zero
+    /// size reports after it.
+    Minimized,
+    /// The window was restored or maximized after being minimized, a `Resized` to the size of
+    /// its client area following.
+    Restored,
+    /// The user asked to close the window, e.g. with the close button or Alt+F4.
+    /// The window is then closed, and message processing returns `Exit`.
+    CloseRequested,
+    /// The window moved to a monitor with a different DPI, carrying the new scale factor.
+    /// The client area has already been resized to the size suggested by the system.
+    DpiChanged(f32),
+    Key(KeyEvent),
+    /// A character was typed, while text input is enabled with `Window::set_text_input_enabled`.
+    Text(TextInputEvent),
+    Mouse(MouseEvent),
+}
+
+/// Input events report when their message was received. Changes to the window itself don't.
+impl Event for WindowEvent {
+    fn timestamp(&self) -> Option<PerformanceCounter> {
+        match self {
+            WindowEvent::Key(event) => event.timestamp(),
+            WindowEvent::Text(event) => event.timestamp(),
+            WindowEvent::Mouse(event) => event.timestamp(),
+            _ => None,
+        }
+    }
+}
+
+/// Whether a key or mouse button went down or up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ButtonState {
+    Pressed,
+    Released,
+}
+
+/// A key went down or up while the window had focus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyEvent {
+    /// Virtual-key code of the key, as in `VirtualKey`.
+    pub key: u16,
+    pub state: ButtonState,
+    /// The key was already down, this is an auto-repeat of a held key. Always false on release.
+    pub repeat: bool,
+    /// When the window received the key message.
+    pub timestamp: PerformanceCounter,
+}
+
+impl Event for KeyEvent {
+    fn timestamp(&self) -> Option<PerformanceCounter> {
+        Some(self.timestamp)
+    }
+}
+
+/// A character typed by the user, after keyboard layout, dead keys and input method composition.
+/// Control characters such as backspace, tab and enter are not reported, they arrive as
+/// `KeyEvent`s only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextInputEvent {
+    pub character: char,
+    /// When the window received the character message.
+    pub timestamp: PerformanceCounter,
+}
+
+impl Event for TextInputEvent {
+    fn timestamp(&self) -> Option<PerformanceCounter> {
+        Some(self.timestamp)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+    /// First extra button, usually "back".
+    X1,
+    /// Second extra button, usually "forward".
+    X2,
+}
+
+/// Mouse input over the client area. Positions are in client coordinates, in physical pixels.
+/// Every event carries when the window received its message.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MouseEvent {
+    /// The cursor moved to a new position.
+    Moved {
+        position: Vector2<i32>,
+        timestamp: PerformanceCounter,
+    },
+    /// A button went down or up with the cursor at `position`. Double clicks are reported as
+    /// a second `Pressed`.
+    Button {
+        button: MouseButton,
+        state: ButtonState,
+        position: Vector2<i32>,
+        timestamp: PerformanceCounter,
+    },
+    /// The vertical wheel turned by `delta` notches. Positive values are away from the user.
+    /// Precise touchpads and wheels report fractions of a notch.
+    Wheel {
+        delta: f32,
+        timestamp: PerformanceCounter,
+    },
+}
+
+impl Event for MouseEvent {
+    fn timestamp(&self) -> Option<PerformanceCounter> {
+        match self {
+            MouseEvent::Moved { timestamp, .. }
+            | MouseEvent::Button { timestamp, .. }
+            | MouseEvent::Wheel { timestamp, .. } => Some(*timestamp),
+        }
+    }
+}
@@ -0,0 +1,273 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Gamepads polled through XInput, one per player index. On platforms without a gamepad
+//! backend, gamepads are never connected.
+
+use std::ops::{BitAnd, BitOr, BitOrAssign};
+
+use crate::events::gamepad::GamepadEvent;
+use crate::math::Vector2;
+
+#[cfg(target_os = "windows")]
+use crate::win::gamepad::{read_gamepad, write_vibration};
+
+/// Number of gamepads XInput supports, player indices go from 0 to `MAX_GAMEPADS - 1`.
+pub const MAX_GAMEPADS: u32 = 4;
+
+/// Dead zone of the sticks until changed with `Gamepad::set_dead_zone`, the one XInput
+/// recommends for the left stick (`XINPUT_GAMEPAD_LEFT_THUMB_DEADZONE`).
+pub const DEFAULT_DEAD_ZONE: f32 = 7849.0 / i16::MAX as f32;
+
+/// Set of gamepad buttons, combined with `|`. The bits are laid out like the XInput
+/// `XINPUT_GAMEPAD_*` flags on every platform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct GamepadButtons(u16);
+
+impl GamepadButtons {
+    pub const DPAD_UP: Self = Self(0x0001);
+    pub const DPAD_DOWN: Self = Self(0x0002);
+    pub const DPAD_LEFT: Self = Self(0x0004);
+    pub const DPAD_RIGHT: Self = Self(0x0008);
+    pub const START: Self = Self(0x0010);
+    pub const BACK: Self = Self(0x0020);
+    /// Left stick pressed down.
+    pub const LEFT_THUMB: Self = Self(0x0040);
+    /// Right stick pressed down.
+    pub const RIGHT_THUMB: Self = Self(0x0080);
+    pub const LEFT_SHOULDER: Self = Self(0x0100);
+    pub const RIGHT_SHOULDER: Self = Self(0x0200);
+    pub const A: Self = Self(0x1000);
+    pub const B: Self = Self(0x2000);
+    pub const X: Self = Self(0x4000);
+    pub const Y: Self = Self(0x8000);
+    /// Every button above.
+    pub const ALL: Self = Self(
+        Self::DPAD_UP.0
+            | Self::DPAD_DOWN.0
+            | Self::DPAD_LEFT.0
+            | Self::DPAD_RIGHT.0
+            | Self::START.0
+            | Self::BACK.0
+            | Self::LEFT_THUMB.0
+            | Self::RIGHT_THUMB.0
+            | Self::LEFT_SHOULDER.0
+            | Self::RIGHT_SHOULDER.0
+            | Self::A.0
+            | Self::B.0
+            | Self::X.0
+            | Self::Y.0,
+    );
+
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Buttons set in `bits`, laid out like `XINPUT_GAMEPAD::wButtons`. Unknown bits are dropped.
+    pub const fn from_bits_truncate(bits: u16) -> Self {
+        Self(bits & Self::ALL.0)
+    }
+
+    pub const fn bits(self) -> u16 {
+        self.0
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Whether every button of `other` is in the set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Whether any button of `other` is in the set.
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+impl BitOr for GamepadButtons {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for GamepadButtons {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for GamepadButtons {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+/// Input of a gamepad at its last update.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct GamepadState {
+    pub buttons: GamepadButtons,
+    /// Position of the left stick in `[-1, 1]`, positive y is up, after the dead zone.
+    pub left_stick: Vector2<f32>,
+    /// Position of the right stick in `[-1, 1]`, positive y is up, after the dead zone.
+    pub right_stick: Vector2<f32>,
+    /// How far the left trigger is pulled, in `[0, 1]`.
+    pub left_trigger: f32,
+    /// How far the right trigger is pulled, in `[0, 1]`.
+    pub right_trigger: f32,
+}
+
+/// Gamepad of one player index, read by calling `update` once per frame.
+#[derive(Debug, Clone)]
+pub struct Gamepad {
+    index: u32,
+    dead_zone: f32,
+    connected: bool,
+    state: GamepadState,
+}
+
+impl Gamepad {
+    /// Gamepad of player `index`, disconnected until the first `update`.
+    /// Panics if `index` is not below `MAX_GAMEPADS`.
+    pub fn new(index: u32) -> Self {
+        assert!(
+            index < MAX_GAMEPADS,
+            "gamepad index {index} is not below {MAX_GAMEPADS}"
+        );
+        Self {
+            index,
+            dead_zone: DEFAULT_DEAD_ZONE,
+            connected: false,
+            state: GamepadState::default(),
+        }
+    }
+
+    /// Gamepads of every player index.
+    pub fn all() -> [Self; MAX_GAMEPADS as usize] {
+        std::array::from_fn(|index| Self::new(index as u32))
+    }
+
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Reads the current state of the gamepad. Returns an event when it was connected or
+    /// disconnected since the previous update. A disconnected gamepad has a default state.
+    pub fn update(&mut self) -> Option<GamepadEvent> {
+        let state = read_gamepad(self.index, self.dead_zone);
+        self.state = state.unwrap_or_default();
+
+        let connected = state.is_some();
+        if connected == self.connected {
+            return None;
+        }
+        self.connected = connected;
+        Some(if connected {
+            GamepadEvent::Connected(self.index)
+        } else {
+            GamepadEvent::Disconnected(self.index)
+        })
+    }
+
+    /// Whether the gamepad was connected at the last update.
+    pub fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    pub fn state(&self) -> &GamepadState {
+        &self.state
+    }
+
+    pub fn buttons(&self) -> GamepadButtons {
+        self.state.buttons
+    }
+
+    /// Whether all of `buttons` were held at the last update.
+    pub fn is_pressed(&self, buttons: GamepadButtons) -> bool {
+        self.state.buttons.contains(buttons)
+    }
+
+    pub fn left_stick(&self) -> Vector2<f32> {
+        self.state.left_stick
+    }
+
+    pub fn right_stick(&self) -> Vector2<f32> {
+        self.state.right_stick
+    }
+
+    pub fn left_trigger(&self) -> f32 {
+        self.state.left_trigger
+    }
+
+    pub fn right_trigger(&self) -> f32 {
+        self.state.right_trigger
+    }
+
+    pub fn dead_zone(&self) -> f32 {
+        self.dead_zone
+    }
+
+    /// Sets the radius of the stick dead zone, in `[0, 1)`, applied from the next update.
+    pub fn set_dead_zone(&mut self, dead_zone: f32) {
+        assert!(
+            (0.0..1.0).contains(&dead_zone),
+            "dead zone {dead_zone} is not in [0, 1)"
+        );
+        self.dead_zone = dead_zone;
+    }
+
+    /// Sets the speed of the low frequency (left) and high frequency (right) motors, in `[0, 1]`.
+    /// The motors keep running until set to zero.
+    pub fn set_vibration(&self, left: f32, right: f32) -> Result<(), String> {
+        let speed = |value: f32| (value.clamp(0.0, 1.0) * u16::MAX as f32).round() as u16;
+        write_vibration(self.index, speed(left), speed(right))
+    }
+}
+
+/// Backend of the platforms without gamepad support, where no gamepad is ever connected.
+#[cfg(not(target_os = "windows"))]
+fn read_gamepad(_index: u32, _dead_zone: f32) -> Option<GamepadState> {
+    None
+}
+
+#[cfg(not(target_os = "windows"))]
+fn write_vibration(_index: u32, _left: u16, _right: u16) -> Result<(), String> {
+    Err(String::from("Gamepads are not supported on this platform."))
+}
+
+/// Applies a circular dead zone of radius `dead_zone` to a stick position in `[-1, 1]`.
+/// Positions within the dead zone become zero. Past it, the distance to the center is rescaled
+/// to go from 0 at the dead zone edge to 1 at the rim, keeping the direction, so small
+/// movements out of the dead zone don't jump. Diagonals are treated like the axes, unlike a
+/// dead zone applied to each axis on its own.
+pub fn apply_radial_dead_zone(stick: Vector2<f32>, dead_zone: f32) -> Vector2<f32> {
+    debug_assert!((0.0..1.0).contains(&dead_zone));
+    let magnitude = stick.x.hypot(stick.y);
+    if magnitude <= dead_zone {
+        return Vector2::new(0.0, 0.0);
+    }
+    let scale = (magnitude.min(1.0) - dead_zone) / (1.0 - dead_zone) / magnitude;
+    Vector2::new(stick.x * scale, stick.y * scale)
+}
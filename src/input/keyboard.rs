@@ -17,24 +17,102 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
-use windows::Win32::UI::Input::KeyboardAndMouse::*;
+//! Keyboard state in platform-neutral terms. Keys are identified by `VirtualKey`, the
+//! virtual-key codes of Windows, which is the only platform with a backend for now. Elsewhere,
+//! every key is reported as released.
 
-#[derive(PartialEq, Eq, PartialOrd, Ord)]
+#[cfg(target_os = "windows")]
+use windows::Win32::UI::Input::KeyboardAndMouse::VIRTUAL_KEY;
+
+#[cfg(target_os = "windows")]
+use crate::win::keyboard::async_key_state;
+
+/// Virtual-key code of a key, with the values of the Windows `VIRTUAL_KEY` codes on every
+/// platform. Letters and digits have the code of their uppercase ASCII character, see
+/// `from_ascii`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct VirtualKey(pub u16);
+
+impl VirtualKey {
+    pub const BACK: Self = Self(0x08);
+    pub const TAB: Self = Self(0x09);
+    pub const RETURN: Self = Self(0x0D);
+    pub const SHIFT: Self = Self(0x10);
+    pub const CONTROL: Self = Self(0x11);
+    /// The Alt key.
+    pub const MENU: Self = Self(0x12);
+    pub const PAUSE: Self = Self(0x13);
+    pub const ESCAPE: Self = Self(0x1B);
+    pub const SPACE: Self = Self(0x20);
+    /// The Page Up key.
+    pub const PRIOR: Self = Self(0x21);
+    /// The Page Down key.
+    pub const NEXT: Self = Self(0x22);
+    pub const END: Self = Self(0x23);
+    pub const HOME: Self = Self(0x24);
+    pub const LEFT: Self = Self(0x25);
+    pub const UP: Self = Self(0x26);
+    pub const RIGHT: Self = Self(0x27);
+    pub const DOWN: Self = Self(0x28);
+    pub const INSERT: Self = Self(0x2D);
+    pub const DELETE: Self = Self(0x2E);
+    pub const F1: Self = Self(0x70);
+    pub const F2: Self = Self(0x71);
+    pub const F3: Self = Self(0x72);
+    pub const F4: Self = Self(0x73);
+    pub const F5: Self = Self(0x74);
+    pub const F6: Self = Self(0x75);
+    pub const F7: Self = Self(0x76);
+    pub const F8: Self = Self(0x77);
+    pub const F9: Self = Self(0x78);
+    pub const F10: Self = Self(0x79);
+    pub const F11: Self = Self(0x7A);
+    pub const F12: Self = Self(0x7B);
+
+    /// Key of a letter, in either case, or a digit of the main keyboard.
+    /// Returns `None` for any other character.
+    pub const fn from_ascii(character: u8) -> Option<Self> {
+        match character {
+            b'0'..=b'9' | b'A'..=b'Z' => Some(Self(character as u16)),
+            b'a'..=b'z' => Some(Self(character.to_ascii_uppercase() as u16)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+impl From<VIRTUAL_KEY> for VirtualKey {
+    fn from(key: VIRTUAL_KEY) -> Self {
+        Self(key.0)
+    }
+}
+
+#[cfg(target_os = "windows")]
+impl From<VirtualKey> for VIRTUAL_KEY {
+    fn from(key: VirtualKey) -> Self {
+        VIRTUAL_KEY(key.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub enum KeyState {
     /// The key is currently pressed
     Pressed,
     /// The key is not currently pressed
+    #[default]
     Released,
     /// The key was pressed since the last call to get_key_state
     WasPressed,
 }
 
-pub fn get_key_state(key: VIRTUAL_KEY) -> KeyState {
-    unsafe {
-        match GetAsyncKeyState(key.0 as i32) {
-            i16::MIN..=-1 => KeyState::Pressed,
-            0 => KeyState::Released,
-            1.. => KeyState::WasPressed,
-        }
-    }
+/// Reads the state of `key` from the system, which is always `Released` on platforms without
+/// a keyboard backend.
+pub fn get_key_state(key: impl Into<VirtualKey>) -> KeyState {
+    async_key_state(key.into())
+}
+
+/// Backend of the platforms without keyboard support, where no key is ever pressed.
+#[cfg(not(target_os = "windows"))]
+fn async_key_state(_key: VirtualKey) -> KeyState {
+    KeyState::Released
 }
@@ -17,4 +17,147 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+pub mod gamepad;
 pub mod keyboard;
+pub mod recorder;
+
+use self::gamepad::{Gamepad, GamepadState, MAX_GAMEPADS};
+use self::keyboard::{get_key_state, KeyState, VirtualKey};
+
+/// Number of virtual-key codes, the size of the key table of `InputState`.
+pub const KEY_COUNT: usize = 256;
+
+/// Input available to the application during a frame, captured at the start of the frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InputState {
+    focused: bool,
+    keys: [KeyState; KEY_COUNT],
+    gamepads: [Option<GamepadState>; MAX_GAMEPADS as usize],
+}
+
+impl Default for InputState {
+    /// Unfocused, with every key released and no gamepad connected.
+    fn default() -> Self {
+        InputState {
+            focused: false,
+            keys: [KeyState::Released; KEY_COUNT],
+            gamepads: [None; MAX_GAMEPADS as usize],
+        }
+    }
+}
+
+impl InputState {
+    /// Captures the state of every key, which are all released while the window isn't focused.
+    pub fn new(focused: bool) -> Self {
+        let mut state = InputState {
+            focused,
+            ..Default::default()
+        };
+        if focused {
+            for (key, key_state) in state.keys.iter_mut().enumerate() {
+                *key_state = get_key_state(VirtualKey(key as u16));
+            }
+        }
+        state
+    }
+
+    /// Captures the keys like `new`, and the state of the gamepads connected at their last update.
+    pub fn with_gamepads(focused: bool, gamepads: &[Gamepad]) -> Self {
+        let mut state = Self::new(focused);
+        for gamepad in gamepads {
+            let connected = gamepad.is_connected().then(|| *gamepad.state());
+            state.set_gamepad(gamepad.index(), connected);
+        }
+        state
+    }
+
+    /// Whether the window has focus. Keys are reported as released while it doesn't.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    pub fn key_state(&self, key: impl Into<VirtualKey>) -> KeyState {
+        if self.focused {
+            let key = key.into();
+            self.keys.get(key.0 as usize).copied().unwrap_or_default()
+        } else {
+            KeyState::Released
+        }
+    }
+
+    /// Panics if `key` is not below `KEY_COUNT`.
+    pub fn set_key_state(&mut self, key: impl Into<VirtualKey>, state: KeyState) {
+        self.keys[key.into().0 as usize] = state;
+    }
+
+    pub fn is_key_down(&self, key: impl Into<VirtualKey>) -> bool {
+        self.key_state(key) == KeyState::Pressed
+    }
+
+    /// State of the gamepad of player `index`, or `None` if it isn't connected.
+    pub fn gamepad(&self, index: u32) -> Option<&GamepadState> {
+        self.gamepads.get(index as usize)?.as_ref()
+    }
+
+    /// Panics if `index` is not below `MAX_GAMEPADS`.
+    pub fn set_gamepad(&mut self, index: u32, state: Option<GamepadState>) {
+        self.gamepads[index as usize] = state;
+    }
+}
+
+/// Input of one frame, with the time elapsed since the previous frame in seconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InputFrame {
+    pub dt: f64,
+    pub state: InputState,
+}
+
+/// Provides the input of each frame to the game loop, live or from a recording.
+pub trait InputSource {
+    /// Input of the next frame, or `None` once the source has no more frames.
+    /// `focused` and `dt` describe the frame being run, sources replaying recorded frames
+    /// ignore them.
+    fn next_frame(&mut self, focused: bool, dt: f64) -> Option<InputFrame>;
+}
+
+impl<S: InputSource + ?Sized> InputSource for &mut S {
+    fn next_frame(&mut self, focused: bool, dt: f64) -> Option<InputFrame> {
+        (**self).next_frame(focused, dt)
+    }
+}
+
+/// Input read from the keyboard and gamepads as the game runs. Never runs out of frames.
+#[derive(Debug, Clone)]
+pub struct LiveInput {
+    gamepads: [Gamepad; MAX_GAMEPADS as usize],
+}
+
+impl LiveInput {
+    pub fn new() -> Self {
+        LiveInput {
+            gamepads: Gamepad::all(),
+        }
+    }
+}
+
+impl Default for LiveInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InputSource for LiveInput {
+    fn next_frame(&mut self, focused: bool, dt: f64) -> Option<InputFrame> {
+        for gamepad in &mut self.gamepads {
+            gamepad.update();
+        }
+        Some(InputFrame {
+            dt,
+            state: InputState::with_gamepads(focused, &self.gamepads),
+        })
+    }
+}
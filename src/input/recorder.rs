@@ -0,0 +1,294 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Recording of the input of game sessions, replayed frame by frame to reproduce them.
+//!
+//! Recordings are binary files where every value is little-endian:
+//! - A header with the magic bytes `SKYINPUT` and the format version as a `u16`.
+//! - A record per frame: `dt` as an `f64`; a byte with bit 0 set if the window was focused;
+//!   the number of keys not released as a `u16`, followed by the virtual-key code and state of
+//!   each as bytes (0 released, 1 pressed, 2 was pressed); a byte with bit `i` set if gamepad `i`
+//!   is connected, followed for each connected gamepad by its buttons as a `u16`, then its left
+//!   stick x and y, right stick x and y, left trigger and right trigger as `f32`s.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use super::gamepad::{GamepadButtons, GamepadState, MAX_GAMEPADS};
+use super::keyboard::{KeyState, VirtualKey};
+use super::{InputFrame, InputSource, InputState, KEY_COUNT};
+use crate::math::Vector2;
+
+const MAGIC: &[u8; 8] = b"SKYINPUT";
+
+/// Version of the recording format, increased on every change so older recordings are rejected.
+pub const FORMAT_VERSION: u16 = 1;
+
+/// Writes the frames of a game session to a recording file.
+pub struct InputRecorder {
+    writer: BufWriter<File>,
+}
+
+impl InputRecorder {
+    /// Creates the recording file at `path`, replacing any existing file.
+    pub fn start(path: impl AsRef<Path>) -> Result<Self, String> {
+        let file = File::create(path).map_err(|e| e.to_string())?;
+        let mut writer = BufWriter::new(file);
+        writer.write_all(MAGIC).map_err(|e| e.to_string())?;
+        writer
+            .write_all(&FORMAT_VERSION.to_le_bytes())
+            .map_err(|e| e.to_string())?;
+        Ok(Self { writer })
+    }
+
+    /// Appends a frame to the recording.
+    pub fn record(&mut self, frame: &InputFrame) -> Result<(), String> {
+        self.writer
+            .write_all(&encode_frame(frame))
+            .map_err(|e| e.to_string())
+    }
+
+    /// Writes the frames still buffered and closes the file.
+    pub fn stop(mut self) -> Result<(), String> {
+        self.writer.flush().map_err(|e| e.to_string())
+    }
+}
+
+/// Input source passing the frames of another source through while recording them.
+pub struct RecordingInput<S: InputSource> {
+    source: S,
+    recorder: Option<InputRecorder>,
+    error: Option<String>,
+}
+
+impl<S: InputSource> RecordingInput<S> {
+    pub fn new(source: S, recorder: InputRecorder) -> Self {
+        Self {
+            source,
+            recorder: Some(recorder),
+            error: None,
+        }
+    }
+
+    /// Stops the recording, returning the first error met while recording, after which frames
+    /// were no longer recorded.
+    pub fn stop(self) -> Result<(), String> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        match self.recorder {
+            Some(recorder) => recorder.stop(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<S: InputSource> InputSource for RecordingInput<S> {
+    fn next_frame(&mut self, focused: bool, dt: f64) -> Option<InputFrame> {
+        let frame = self.source.next_frame(focused, dt)?;
+        if let Some(recorder) = &mut self.recorder {
+            if let Err(error) = recorder.record(&frame) {
+                self.error = Some(error);
+                self.recorder = None;
+            }
+        }
+        Some(frame)
+    }
+}
+
+/// Replays the frames of a recording, in the order they were recorded.
+pub struct InputPlayer {
+    frames: std::vec::IntoIter<InputFrame>,
+}
+
+impl InputPlayer {
+    /// Reads the recording at `path`. Fails if the file is not a recording, was written with
+    /// another format version or is truncated.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, String> {
+        let bytes = std::fs::read(path).map_err(|e| e.to_string())?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Reads a recording from its bytes, see `from_file`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let mut reader = Reader { bytes };
+        if reader.take(MAGIC.len()).ok() != Some(MAGIC.as_slice()) {
+            return Err(String::from("Not an input recording."));
+        }
+        let version = reader.u16()?;
+        if version != FORMAT_VERSION {
+            return Err(format!(
+                "Input recording version {version} is not supported, expected {FORMAT_VERSION}."
+            ));
+        }
+
+        let mut frames = Vec::new();
+        while !reader.bytes.is_empty() {
+            frames.push(decode_frame(&mut reader)?);
+        }
+        Ok(Self {
+            frames: frames.into_iter(),
+        })
+    }
+
+    /// Number of frames not replayed yet.
+    pub fn remaining_frames(&self) -> usize {
+        self.frames.len()
+    }
+}
+
+impl InputSource for InputPlayer {
+    fn next_frame(&mut self, _focused: bool, _dt: f64) -> Option<InputFrame> {
+        self.frames.next()
+    }
+}
+
+fn encode_frame(frame: &InputFrame) -> Vec<u8> {
+    let state = &frame.state;
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&frame.dt.to_le_bytes());
+    bytes.push(state.focused as u8);
+
+    let pressed: Vec<(usize, &KeyState)> = state
+        .keys
+        .iter()
+        .enumerate()
+        .filter(|(_, key_state)| **key_state != KeyState::Released)
+        .collect();
+    bytes.extend_from_slice(&(pressed.len() as u16).to_le_bytes());
+    for (key, key_state) in pressed {
+        bytes.push(key as u8);
+        bytes.push(match key_state {
+            KeyState::Released => 0,
+            KeyState::Pressed => 1,
+            KeyState::WasPressed => 2,
+        });
+    }
+
+    let connected = state
+        .gamepads
+        .iter()
+        .enumerate()
+        .filter(|(_, gamepad)| gamepad.is_some())
+        .fold(0u8, |mask, (index, _)| mask | 1 << index);
+    bytes.push(connected);
+    for gamepad in state.gamepads.iter().flatten() {
+        bytes.extend_from_slice(&gamepad.buttons.bits().to_le_bytes());
+        for value in [
+            gamepad.left_stick.x,
+            gamepad.left_stick.y,
+            gamepad.right_stick.x,
+            gamepad.right_stick.y,
+            gamepad.left_trigger,
+            gamepad.right_trigger,
+        ] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+    bytes
+}
+
+fn decode_frame(reader: &mut Reader) -> Result<InputFrame, String> {
+    let dt = reader.f64()?;
+    let mut state = InputState {
+        focused: reader.u8()? & 1 != 0,
+        ..Default::default()
+    };
+
+    let key_count = reader.u16()? as usize;
+    if key_count > KEY_COUNT {
+        return Err(format!("Input recording has {key_count} keys in a frame."));
+    }
+    for _ in 0..key_count {
+        let key = reader.u8()?;
+        let key_state = match reader.u8()? {
+            0 => KeyState::Released,
+            1 => KeyState::Pressed,
+            2 => KeyState::WasPressed,
+            value => return Err(format!("Input recording has an invalid key state {value}.")),
+        };
+        state.set_key_state(VirtualKey(key as u16), key_state);
+    }
+
+    let connected = reader.u8()?;
+    if connected >> MAX_GAMEPADS != 0 {
+        return Err(format!(
+            "Input recording has an invalid gamepad mask {connected:#x}."
+        ));
+    }
+    for index in 0..MAX_GAMEPADS {
+        if connected & 1 << index == 0 {
+            continue;
+        }
+        let buttons = GamepadButtons::from_bits_truncate(reader.u16()?);
+        let mut values = [0.0f32; 6];
+        for value in &mut values {
+            *value = reader.f32()?;
+        }
+        let [left_x, left_y, right_x, right_y, left_trigger, right_trigger] = values;
+        state.set_gamepad(
+            index,
+            Some(GamepadState {
+                buttons,
+                left_stick: Vector2::new(left_x, left_y),
+                right_stick: Vector2::new(right_x, right_y),
+                left_trigger,
+                right_trigger,
+            }),
+        );
+    }
+    Ok(InputFrame { dt, state })
+}
+
+/// Reads little-endian values from the front of a byte slice.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8], String> {
+        if self.bytes.len() < count {
+            return Err(String::from("Input recording is truncated."));
+        }
+        let (taken, rest) = self.bytes.split_at(count);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        self.array().map(u16::from_le_bytes)
+    }
+
+    fn f32(&mut self) -> Result<f32, String> {
+        self.array().map(f32::from_le_bytes)
+    }
+
+    fn f64(&mut self) -> Result<f64, String> {
+        self.array().map(f64::from_le_bytes)
+    }
+}
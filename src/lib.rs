@@ -17,12 +17,29 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+#[cfg(all(target_os = "windows", feature = "renderer-d3d12"))]
+pub mod app;
+#[cfg(feature = "renderer")]
+pub mod debug_overlay;
+#[cfg(feature = "windowing")]
 pub mod window;
+#[cfg(feature = "windowing")]
 pub mod input;
+#[cfg(feature = "math")]
 pub mod math;
+#[cfg(feature = "windowing")]
 pub mod timer;
+#[cfg(feature = "windowing")]
 pub mod events;
+#[cfg(feature = "renderer")]
 pub mod renderer;
+#[cfg(all(target_os = "windows", feature = "renderer-d3d12"))]
+pub mod prelude;
 
-#[cfg(target_os = "windows")]
-mod win;
+#[cfg(feature = "math")]
+pub use self::math::{Matrix2x2, Matrix3x3, Matrix4x4, Rect, Size, Vector2, Vector3, Vector4};
+#[cfg(feature = "renderer")]
+pub use self::renderer::Color;
+
+#[cfg(all(target_os = "windows", feature = "windowing"))]
+pub mod win;
@@ -0,0 +1,184 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use super::number::{FloatingPointNumber, FromDouble, Wrap};
+use std::f64::consts::PI;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// An angle, stored in radians, whose unit is explicit at construction and when reading it back.
+///
+/// Functions taking a raw `rad: f32` make it easy to pass degrees by mistake, so the rotation
+/// functions have variants taking an `Angle`, such as `Matrix4x4::make_rotation_x_angle`, which
+/// are preferred:
+///
+/// ```
+/// use sky_labs::math::{Angle, Matrix3x3};
+///
+/// let rotation = Matrix3x3::<f32>::make_rotation_2d_angle(Angle::degrees(90.0));
+/// assert_eq!(Angle::degrees(180.0f64).to_radians(), std::f64::consts::PI);
+/// ```
+///
+/// Conversions divide by 180 before multiplying by π, so angles that are a power of two fraction
+/// of a half turn, such as 45, 90, 180 or 360 degrees, convert exactly both ways.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Default)]
+pub struct Angle<T: FloatingPointNumber>(T);
+
+impl<T: FloatingPointNumber> Angle<T> {
+    /// Creates an angle from a value in radians.
+    pub const fn radians(radians: T) -> Self {
+        Self(radians)
+    }
+
+    /// Creates an angle from a value in degrees.
+    pub fn degrees(degrees: T) -> Self {
+        Self(degrees / T::from_double(180.0) * T::from_double(PI))
+    }
+
+    /// Returns the angle in radians.
+    pub const fn to_radians(self) -> T {
+        self.0
+    }
+
+    /// Returns the angle in degrees.
+    pub fn to_degrees(self) -> T {
+        self.0 / T::from_double(PI) * T::from_double(180.0)
+    }
+}
+
+// The trigonometric functions aren't part of `FloatingPointNumber`, so they are generated for
+// each precision.
+macro_rules! impl_angle_trigonometry {
+    ($($t:ty)*) => ($(
+        impl Angle<$t> {
+            #[inline]
+            pub fn sin(self) -> $t {
+                self.0.sin()
+            }
+
+            #[inline]
+            pub fn cos(self) -> $t {
+                self.0.cos()
+            }
+
+            #[inline]
+            pub fn tan(self) -> $t {
+                self.0.tan()
+            }
+
+            /// Returns the sine and the cosine, computed together.
+            #[inline]
+            pub fn sin_cos(self) -> ($t, $t) {
+                self.0.sin_cos()
+            }
+
+            /// Returns the same direction as an angle in `[0, 2π)`.
+            pub fn normalized(self) -> Self {
+                Self(self.0.wrap(0.0, <$t>::from_double(2.0 * PI)))
+            }
+        }
+    )*)
+}
+
+impl_angle_trigonometry! { f32 f64 }
+
+impl<T: FloatingPointNumber> Add for Angle<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+forward_ref_binop!(impl<T> Add, add for Angle<T>, Angle<T> where T: FloatingPointNumber);
+
+impl<T: FloatingPointNumber> Sub for Angle<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+forward_ref_binop!(impl<T> Sub, sub for Angle<T>, Angle<T> where T: FloatingPointNumber);
+
+impl<T: FloatingPointNumber> Mul<T> for Angle<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+forward_ref_binop!(impl<T> Mul, mul for Angle<T>, T where T: FloatingPointNumber);
+implement_scalar_lhs_mul! {
+    Angle<f32>, f32;
+    Angle<f64>, f64
+}
+
+impl<T: FloatingPointNumber> Div<T> for Angle<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self::Output {
+        Self(self.0 / rhs)
+    }
+}
+forward_ref_binop!(impl<T> Div, div for Angle<T>, T where T: FloatingPointNumber);
+
+impl<T: FloatingPointNumber> Neg for Angle<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+forward_ref_unop!(impl<T> Neg, neg for Angle<T> where T: FloatingPointNumber);
+
+impl<T: FloatingPointNumber> AddAssign for Angle<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+forward_ref_op_assign!(impl<T> AddAssign, add_assign for Angle<T>, Angle<T> where T: FloatingPointNumber);
+
+impl<T: FloatingPointNumber> SubAssign for Angle<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+forward_ref_op_assign!(impl<T> SubAssign, sub_assign for Angle<T>, Angle<T> where T: FloatingPointNumber);
+
+impl<T: FloatingPointNumber> MulAssign<T> for Angle<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.0 *= rhs;
+    }
+}
+forward_ref_op_assign!(impl<T> MulAssign, mul_assign for Angle<T>, T where T: FloatingPointNumber);
+
+impl<T: FloatingPointNumber> DivAssign<T> for Angle<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.0 /= rhs;
+    }
+}
+forward_ref_op_assign!(impl<T> DivAssign, div_assign for Angle<T>, T where T: FloatingPointNumber);
@@ -0,0 +1,203 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Overlap tests between 2D shapes: points, circles, segments and rectangles.
+//!
+//! Shapes that only touch, such as tangent circles or a segment ending on the edge of a
+//! rectangle, count as overlapping. Rectangles are expected to have a non-negative size.
+
+use super::{Rect, Vector2};
+
+/// Returns whether `point` is inside the circle of `radius` around `center`, or on its edge.
+pub fn point_in_circle(point: Vector2<f32>, center: Vector2<f32>, radius: f32) -> bool {
+    (point - center).norm_squared() <= radius * radius
+}
+
+/// Returns whether two circles overlap or touch.
+pub fn circle_intersects_circle(
+    center_a: Vector2<f32>,
+    radius_a: f32,
+    center_b: Vector2<f32>,
+    radius_b: f32,
+) -> bool {
+    point_in_circle(center_a, center_b, radius_a + radius_b)
+}
+
+/// Returns whether the circle of `radius` around `center` overlaps or touches `rect`.
+pub fn circle_intersects_rect(center: Vector2<f32>, radius: f32, rect: &Rect<f32>) -> bool {
+    let closest = Vector2::new(
+        center.x.clamp(rect.x, rect.x + rect.width),
+        center.y.clamp(rect.y, rect.y + rect.height),
+    );
+    point_in_circle(closest, center, radius)
+}
+
+/// Returns whether the segment from `from` to `to` overlaps or touches `rect`,
+/// including when it is entirely inside it.
+pub fn segment_intersects_rect(from: Vector2<f32>, to: Vector2<f32>, rect: &Rect<f32>) -> bool {
+    let min = Vector2::new(rect.x, rect.y);
+    let max = Vector2::new(rect.x + rect.width, rect.y + rect.height);
+    box_entry_time(from, to - from, min, max).is_some()
+}
+
+/// Returns the intersection of the segment from `a_from` to `a_to` with the segment from `b_from`
+/// to `b_to`, or `None` if they don't meet. Collinear segments overlapping along a stretch return
+/// the point of that stretch closest to `a_from`. A segment of zero length is a point.
+pub fn segment_intersects_segment(
+    a_from: Vector2<f32>,
+    a_to: Vector2<f32>,
+    b_from: Vector2<f32>,
+    b_to: Vector2<f32>,
+) -> Option<Vector2<f32>> {
+    let a = a_to - a_from;
+    let b = b_to - b_from;
+    let offset = b_from - a_from;
+    let denominator = a.cross(&b);
+    if denominator != 0.0 {
+        // Positions along `a` and `b` where the lines through them cross.
+        let t = offset.cross(&b) / denominator;
+        let u = offset.cross(&a) / denominator;
+        return if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+            Some(a_from + a * t)
+        } else {
+            None
+        };
+    }
+
+    // Parallel segments only meet if they are on the same line.
+    if offset.cross(&a) != 0.0 || offset.cross(&b) != 0.0 {
+        return None;
+    }
+    let a_length_squared = a.norm_squared();
+    if a_length_squared == 0.0 {
+        let b_length_squared = b.norm_squared();
+        if b_length_squared == 0.0 {
+            return (a_from == b_from).then_some(a_from);
+        }
+        let u = -offset.dot(b) / b_length_squared;
+        return (0.0..=1.0).contains(&u).then_some(a_from);
+    }
+    // Range of `b` projected on `a`, overlapping `a` where it overlaps `[0, 1]`.
+    let start = offset.dot(a) / a_length_squared;
+    let end = start + b.dot(a) / a_length_squared;
+    let (start, end) = (start.min(end), start.max(end));
+    if start > 1.0 || end < 0.0 {
+        return None;
+    }
+    Some(a_from + a * start.max(0.0))
+}
+
+/// Returns the time of impact of the circle of `radius` moving from `center` by `velocity` with
+/// `rect`, as a fraction of `velocity` between 0 and 1, or `None` if it doesn't reach `rect`.
+/// Returns 0 if the circle overlaps `rect` from the start. Unlike testing the end position,
+/// this catches fast circles that would go through `rect` between two frames.
+/// The circle is at `center + velocity * time` on impact, touching `rect`.
+pub fn swept_circle_intersects_rect(
+    center: Vector2<f32>,
+    radius: f32,
+    velocity: Vector2<f32>,
+    rect: &Rect<f32>,
+) -> Option<f32> {
+    if circle_intersects_rect(center, radius, rect) {
+        return Some(0.0);
+    }
+
+    // The center hits the rectangle grown by the radius with rounded corners: the union of
+    // the rectangle grown horizontally, the one grown vertically, and circles at the corners.
+    let (left, top) = (rect.x, rect.y);
+    let (right, bottom) = (rect.x + rect.width, rect.y + rect.height);
+    let wide = box_entry_time(
+        center,
+        velocity,
+        Vector2::new(left - radius, top),
+        Vector2::new(right + radius, bottom),
+    );
+    let tall = box_entry_time(
+        center,
+        velocity,
+        Vector2::new(left, top - radius),
+        Vector2::new(right, bottom + radius),
+    );
+    let corners = [
+        Vector2::new(left, top),
+        Vector2::new(right, top),
+        Vector2::new(left, bottom),
+        Vector2::new(right, bottom),
+    ]
+    .map(|corner| circle_entry_time(center, velocity, corner, radius));
+
+    [wide, tall]
+        .into_iter()
+        .chain(corners)
+        .flatten()
+        .min_by(f32::total_cmp)
+}
+
+/// Returns the first time between 0 and 1 at which `origin + direction * time` is inside the
+/// box from `min` to `max`, using the slab method.
+fn box_entry_time(
+    origin: Vector2<f32>,
+    direction: Vector2<f32>,
+    min: Vector2<f32>,
+    max: Vector2<f32>,
+) -> Option<f32> {
+    let mut enter = 0.0f32;
+    let mut exit = 1.0f32;
+    for axis in 0..2 {
+        let (origin, direction) = (origin[axis], direction[axis]);
+        if direction == 0.0 {
+            if origin < min[axis] || origin > max[axis] {
+                return None;
+            }
+            continue;
+        }
+        let near = (min[axis] - origin) / direction;
+        let far = (max[axis] - origin) / direction;
+        enter = enter.max(near.min(far));
+        exit = exit.min(near.max(far));
+        if enter > exit {
+            return None;
+        }
+    }
+    Some(enter)
+}
+
+/// Returns the first time between 0 and 1 at which `origin + direction * time` is inside the
+/// circle of `radius` around `center`.
+fn circle_entry_time(
+    origin: Vector2<f32>,
+    direction: Vector2<f32>,
+    center: Vector2<f32>,
+    radius: f32,
+) -> Option<f32> {
+    let offset = origin - center;
+    let c = offset.norm_squared() - radius * radius;
+    if c <= 0.0 {
+        return Some(0.0);
+    }
+    let a = direction.norm_squared();
+    let b = offset.dot(direction);
+    let discriminant = b * b - a * c;
+    if a == 0.0 || b >= 0.0 || discriminant < 0.0 {
+        // Not moving, moving away, or passing beside the circle.
+        return None;
+    }
+    let time = (-b - discriminant.sqrt()) / a;
+    (time <= 1.0).then_some(time)
+}
@@ -0,0 +1,244 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Curves for smooth paths: quadratic and cubic Bezier curves, centripetal Catmull-Rom splines,
+//! and arc length tables to move along them at a uniform speed.
+//!
+//! The functions are generic over `CurvePoint`, implemented by `Vector2` and `Vector3` of `f32`
+//! and `f64`. Curves are evaluated at a parameter `t` from 0 at their start to 1 at their end,
+//! and tangents are the derivatives with respect to `t`, not normalized.
+
+use std::ops::{Add, Mul, Sub};
+
+use super::{AsDouble, FloatingPointNumber, FromDouble, Number, Vector2, Vector3};
+
+/// A point curves can be evaluated on, with components of type `Scalar`.
+pub trait CurvePoint:
+    Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Self::Scalar, Output = Self>
+{
+    type Scalar: FloatingPointNumber;
+
+    /// Returns the distance to `other`.
+    fn distance(&self, other: &Self) -> f64;
+}
+
+impl<T: FloatingPointNumber> CurvePoint for Vector2<T> {
+    type Scalar = T;
+
+    fn distance(&self, other: &Self) -> f64 {
+        self.distance_to(other)
+    }
+}
+
+impl<T: FloatingPointNumber> CurvePoint for Vector3<T> {
+    type Scalar = T;
+
+    fn distance(&self, other: &Self) -> f64 {
+        self.distance_to(other)
+    }
+}
+
+/// Shorthand for a constant of the scalar type of `P`.
+fn scalar<P: CurvePoint>(value: f64) -> P::Scalar {
+    P::Scalar::from_double(value)
+}
+
+/// Evaluates the quadratic Bezier curve from `p0` to `p2` with control point `p1` at `t`.
+pub fn quadratic_bezier<P: CurvePoint>(p0: P, p1: P, p2: P, t: P::Scalar) -> P {
+    let s = P::Scalar::ONE - t;
+    p0 * (s * s) + p1 * (scalar::<P>(2.0) * s * t) + p2 * (t * t)
+}
+
+/// Returns the tangent of the quadratic Bezier curve from `p0` to `p2` with control point `p1`
+/// at `t`, its derivative with respect to `t`.
+pub fn quadratic_bezier_tangent<P: CurvePoint>(p0: P, p1: P, p2: P, t: P::Scalar) -> P {
+    let s = P::Scalar::ONE - t;
+    ((p1 - p0) * s + (p2 - p1) * t) * scalar::<P>(2.0)
+}
+
+/// Evaluates the cubic Bezier curve from `p0` to `p3` with control points `p1` and `p2` at `t`.
+pub fn bezier<P: CurvePoint>(p0: P, p1: P, p2: P, p3: P, t: P::Scalar) -> P {
+    let s = P::Scalar::ONE - t;
+    let three = scalar::<P>(3.0);
+    p0 * (s * s * s) + p1 * (three * s * s * t) + p2 * (three * s * t * t) + p3 * (t * t * t)
+}
+
+/// Returns the tangent of the cubic Bezier curve from `p0` to `p3` with control points `p1`
+/// and `p2` at `t`, its derivative with respect to `t`.
+pub fn bezier_tangent<P: CurvePoint>(p0: P, p1: P, p2: P, p3: P, t: P::Scalar) -> P {
+    let s = P::Scalar::ONE - t;
+    let three = scalar::<P>(3.0);
+    (p1 - p0) * (three * s * s)
+        + (p2 - p1) * (scalar::<P>(6.0) * s * t)
+        + (p3 - p2) * (three * t * t)
+}
+
+/// Coefficients `[a, b, c, d]` of the cubic `a t³ + b t² + c t + d` of the centripetal
+/// Catmull-Rom segment from `p1` to `p2`.
+///
+/// The tangents come from the formulation of Yuksel et al., with knots spaced by the square root
+/// of the distance between points. A zero distance to `p0` or `p3`, as when an endpoint is
+/// duplicated, drops the term of that neighbor, which gives the tangent of a neighbor mirrored
+/// through the endpoint.
+fn catmull_rom_coefficients<P: CurvePoint>(p0: P, p1: P, p2: P, p3: P) -> [P; 4] {
+    let zero = p1 * P::Scalar::ZERO;
+    let t01 = p0.distance(&p1).sqrt();
+    let t12 = p1.distance(&p2).sqrt();
+    let t23 = p2.distance(&p3).sqrt();
+    if t12 == 0.0 {
+        return [zero, zero, zero, p1];
+    }
+
+    let chord = p2 - p1;
+    let m1 = if t01 == 0.0 {
+        chord
+    } else {
+        chord + ((p1 - p0) * scalar::<P>(t12 / t01) - (p2 - p0) * scalar::<P>(t12 / (t01 + t12)))
+    };
+    let m2 = if t23 == 0.0 {
+        chord
+    } else {
+        chord + ((p3 - p2) * scalar::<P>(t12 / t23) - (p3 - p1) * scalar::<P>(t12 / (t12 + t23)))
+    };
+
+    let two = scalar::<P>(2.0);
+    let three = scalar::<P>(3.0);
+    [m1 + m2 - chord * two, chord * three - m1 * two - m2, m1, p1]
+}
+
+/// Evaluates the centripetal Catmull-Rom segment from `p1` to `p2` at `t`, with `p0` and `p3`
+/// the points before and after it. Passes through `p1` at `t = 0` and `p2` at `t = 1`.
+/// Centripetal segments don't form cusps or loops within themselves.
+///
+/// For the first and last segments of a path, pass the endpoint twice, as `catmull_rom_spline`
+/// does: the tangent there then points to the next point, as if it were mirrored.
+pub fn catmull_rom<P: CurvePoint>(p0: P, p1: P, p2: P, p3: P, t: P::Scalar) -> P {
+    let [a, b, c, d] = catmull_rom_coefficients(p0, p1, p2, p3);
+    ((a * t + b) * t + c) * t + d
+}
+
+/// Returns the tangent of the centripetal Catmull-Rom segment from `p1` to `p2` at `t`,
+/// its derivative with respect to `t`. See `catmull_rom`.
+pub fn catmull_rom_tangent<P: CurvePoint>(p0: P, p1: P, p2: P, p3: P, t: P::Scalar) -> P {
+    let [a, b, c, _] = catmull_rom_coefficients(p0, p1, p2, p3);
+    (a * (scalar::<P>(3.0) * t) + b * scalar::<P>(2.0)) * t + c
+}
+
+/// Returns the four points of the Catmull-Rom segment from `points[segment]` to
+/// `points[segment + 1]`. The first and last points are duplicated where the segment has no
+/// point before or after it.
+///
+/// # Panics
+/// Panics if `segment + 1` is out of `points`.
+pub fn catmull_rom_segment<P: CurvePoint>(points: &[P], segment: usize) -> [P; 4] {
+    let last = points.len() - 1;
+    assert!(segment < last, "`segment` must be followed by a point");
+    [
+        points[segment.saturating_sub(1)],
+        points[segment],
+        points[segment + 1],
+        points[(segment + 2).min(last)],
+    ]
+}
+
+/// Evaluates the centripetal Catmull-Rom spline through all of `points` at `t`, from the first
+/// point at `t = 0` to the last at `t = 1`, each segment spanning an equal range of `t`.
+/// `t` is clamped to `[0, 1]`, and a single point is returned as is.
+///
+/// # Panics
+/// Panics if `points` is empty.
+pub fn catmull_rom_spline<P: CurvePoint>(points: &[P], t: P::Scalar) -> P {
+    assert!(!points.is_empty(), "`points` must not be empty");
+    let segments = points.len() - 1;
+    if segments == 0 {
+        return points[0];
+    }
+    let position = t.as_double().clamp(0.0, 1.0) * segments as f64;
+    let segment = (position.floor() as usize).min(segments - 1);
+    let [p0, p1, p2, p3] = catmull_rom_segment(points, segment);
+    catmull_rom(p0, p1, p2, p3, scalar::<P>(position - segment as f64))
+}
+
+/// Table mapping distances along a curve to the parameter `t` reaching them, so that moving
+/// by equal distances moves at a uniform speed whatever the spacing of the control points.
+/// Built by `arc_length_parameterize`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArcLengthTable<T: FloatingPointNumber> {
+    /// Length of the curve from `t = 0` to `t = i / (lengths.len() - 1)`, never decreasing.
+    lengths: Vec<T>,
+}
+
+impl<T: FloatingPointNumber> ArcLengthTable<T> {
+    /// Returns the length of the whole curve, as approximated by the table.
+    pub fn length(&self) -> T {
+        self.lengths[self.lengths.len() - 1]
+    }
+
+    /// Returns the parameter `t` at `distance` along the curve from its start, interpolating
+    /// linearly between samples. `distance` is clamped to the length of the curve, and
+    /// the result never decreases as `distance` increases.
+    pub fn t_at_distance(&self, distance: T) -> T {
+        let samples = self.lengths.len() - 1;
+        let distance = if distance < T::ZERO {
+            T::ZERO
+        } else if distance > self.length() {
+            self.length()
+        } else {
+            distance
+        };
+        // First sample at least `distance` away, so the sample before it is below.
+        let index = self
+            .lengths
+            .partition_point(|&length| length < distance)
+            .max(1);
+        if index > samples {
+            return T::ONE;
+        }
+        let (before, after) = (self.lengths[index - 1], self.lengths[index]);
+        let fraction = if after > before {
+            (distance - before) / (after - before)
+        } else {
+            T::ZERO
+        };
+        (T::from_double((index - 1) as f64) + fraction) / T::from_double(samples as f64)
+    }
+}
+
+/// Samples `curve` at `samples + 1` evenly spaced parameters from 0 to 1, and returns the table
+/// of the length of the polyline through them. More samples follow the curve more closely.
+///
+/// # Panics
+/// Panics if `samples` is zero.
+pub fn arc_length_parameterize<P: CurvePoint>(
+    curve: impl Fn(P::Scalar) -> P,
+    samples: usize,
+) -> ArcLengthTable<P::Scalar> {
+    assert!(samples > 0, "`samples` must not be zero");
+    let mut lengths = Vec::with_capacity(samples + 1);
+    let mut previous = curve(P::Scalar::ZERO);
+    let mut length = 0.0;
+    lengths.push(P::Scalar::ZERO);
+    for sample in 1..=samples {
+        let point = curve(scalar::<P>(sample as f64 / samples as f64));
+        length += previous.distance(&point);
+        lengths.push(scalar::<P>(length));
+        previous = point;
+    }
+    ArcLengthTable { lengths }
+}
@@ -0,0 +1,411 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Conversions between the math types and the Direct2D and DirectWrite ones, enabled by the
+//! renderer features.
+//! The conversions of references reinterpret the value in place, as both have the same layout.
+
+use windows::Foundation::Numerics::Matrix3x2;
+use windows::Win32::Graphics::Direct2D::Common::{
+    D2D_MATRIX_3X2_F, D2D_MATRIX_3X2_F_0, D2D_POINT_2F, D2D_POINT_2U, D2D_RECT_F, D2D_SIZE_F,
+    D2D_SIZE_U, D2D_VECTOR_2F, D2D_VECTOR_3F, D2D_VECTOR_4F,
+};
+use windows::Win32::Graphics::DirectWrite::DWRITE_MATRIX;
+
+use super::{Matrix3x3, Matrix4x4, Rect, Size, Vector2, Vector3, Vector4};
+
+impl Into<D2D_SIZE_F> for Vector2<f32> {
+    fn into(self) -> D2D_SIZE_F {
+        D2D_SIZE_F {
+            width: self.x,
+            height: self.y,
+        }
+    }
+}
+
+impl<'a> Into<&'a D2D_SIZE_F> for &'a Vector2<f32> {
+    fn into(self) -> &'a D2D_SIZE_F {
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl From<D2D_SIZE_F> for Vector2<f32> {
+    fn from(value: D2D_SIZE_F) -> Self {
+        Self {
+            x: value.width,
+            y: value.height,
+        }
+    }
+}
+
+impl<'a> From<&'a D2D_SIZE_F> for &'a Vector2<f32> {
+    fn from(value: &'a D2D_SIZE_F) -> Self {
+        unsafe { std::mem::transmute(value) }
+    }
+}
+
+impl Into<D2D_SIZE_U> for Vector2<u32> {
+    fn into(self) -> D2D_SIZE_U {
+        D2D_SIZE_U {
+            width: self.x,
+            height: self.y,
+        }
+    }
+}
+
+impl<'a> Into<&'a D2D_SIZE_U> for &'a Vector2<u32> {
+    fn into(self) -> &'a D2D_SIZE_U {
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl From<D2D_SIZE_U> for Vector2<u32> {
+    fn from(value: D2D_SIZE_U) -> Self {
+        Self {
+            x: value.width,
+            y: value.height,
+        }
+    }
+}
+
+impl<'a> From<&'a D2D_SIZE_U> for &'a Vector2<u32> {
+    fn from(value: &'a D2D_SIZE_U) -> Self {
+        unsafe { std::mem::transmute(value) }
+    }
+}
+
+impl Into<D2D_POINT_2F> for Vector2<f32> {
+    fn into(self) -> D2D_POINT_2F {
+        D2D_POINT_2F {
+            x: self.x,
+            y: self.y,
+        }
+    }
+}
+
+impl<'a> Into<&'a D2D_POINT_2F> for &'a Vector2<f32> {
+    fn into(self) -> &'a D2D_POINT_2F {
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl From<D2D_POINT_2F> for Vector2<f32> {
+    fn from(value: D2D_POINT_2F) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+        }
+    }
+}
+
+impl<'a> From<&'a D2D_POINT_2F> for &'a Vector2<f32> {
+    fn from(value: &'a D2D_POINT_2F) -> Self {
+        unsafe { std::mem::transmute(value) }
+    }
+}
+
+impl Into<D2D_POINT_2U> for Vector2<u32> {
+    fn into(self) -> D2D_POINT_2U {
+        D2D_POINT_2U {
+            x: self.x,
+            y: self.y,
+        }
+    }
+}
+
+impl<'a> Into<&'a D2D_POINT_2U> for &'a Vector2<u32> {
+    fn into(self) -> &'a D2D_POINT_2U {
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl From<D2D_POINT_2U> for Vector2<u32> {
+    fn from(value: D2D_POINT_2U) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+        }
+    }
+}
+
+impl<'a> From<&'a D2D_POINT_2U> for &'a Vector2<u32> {
+    fn from(value: &'a D2D_POINT_2U) -> Self {
+        unsafe { std::mem::transmute(value) }
+    }
+}
+
+impl Into<D2D_VECTOR_2F> for Vector2<f32> {
+    fn into(self) -> D2D_VECTOR_2F {
+        D2D_VECTOR_2F {
+            x: self.x,
+            y: self.y,
+        }
+    }
+}
+
+impl<'a> Into<&'a D2D_VECTOR_2F> for &'a Vector2<u32> {
+    fn into(self) -> &'a D2D_VECTOR_2F {
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl From<D2D_VECTOR_2F> for Vector2<f32> {
+    fn from(value: D2D_VECTOR_2F) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+        }
+    }
+}
+
+impl<'a> From<&'a D2D_VECTOR_2F> for &'a Vector2<f32> {
+    fn from(value: &'a D2D_VECTOR_2F) -> Self {
+        unsafe { std::mem::transmute(value) }
+    }
+}
+
+impl Into<D2D_VECTOR_3F> for Vector3<f32> {
+    fn into(self) -> D2D_VECTOR_3F {
+        D2D_VECTOR_3F {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        }
+    }
+}
+
+impl<'a> Into<&'a D2D_VECTOR_3F> for &'a Vector3<f32> {
+    fn into(self) -> &'a D2D_VECTOR_3F {
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl From<D2D_VECTOR_3F> for Vector3<f32> {
+    fn from(value: D2D_VECTOR_3F) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+impl<'a> From<&'a D2D_VECTOR_3F> for &'a Vector3<f32> {
+    fn from(value: &'a D2D_VECTOR_3F) -> Self {
+        unsafe { std::mem::transmute(value) }
+    }
+}
+
+impl Into<D2D_VECTOR_4F> for Vector4<f32> {
+    fn into(self) -> D2D_VECTOR_4F {
+        D2D_VECTOR_4F {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+            w: self.w,
+        }
+    }
+}
+
+impl<'a> Into<&'a D2D_VECTOR_4F> for &'a Vector4<f32> {
+    fn into(self) -> &'a D2D_VECTOR_4F {
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl From<D2D_VECTOR_4F> for Vector4<f32> {
+    fn from(value: D2D_VECTOR_4F) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+            w: value.w,
+        }
+    }
+}
+
+impl<'a> From<&'a D2D_VECTOR_4F> for &'a Vector4<f32> {
+    fn from(value: &'a D2D_VECTOR_4F) -> Self {
+        unsafe { std::mem::transmute(value) }
+    }
+}
+
+impl Into<D2D_SIZE_F> for Size<f32> {
+    fn into(self) -> D2D_SIZE_F {
+        D2D_SIZE_F {
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl From<D2D_SIZE_F> for Size<f32> {
+    fn from(value: D2D_SIZE_F) -> Self {
+        Self {
+            width: value.width,
+            height: value.height,
+        }
+    }
+}
+
+/// Direct2D rectangles are given by their edges rather than their origin and size.
+impl From<Rect<f32>> for D2D_RECT_F {
+    fn from(rect: Rect<f32>) -> Self {
+        Self {
+            left: rect.x,
+            top: rect.y,
+            right: rect.x + rect.width,
+            bottom: rect.y + rect.height,
+        }
+    }
+}
+
+impl From<D2D_RECT_F> for Rect<f32> {
+    fn from(rect: D2D_RECT_F) -> Self {
+        Self {
+            x: rect.left,
+            y: rect.top,
+            width: rect.right - rect.left,
+            height: rect.bottom - rect.top,
+        }
+    }
+}
+
+// Direct2D and DirectWrite transform row vectors, `(x, y, 1) * M`, while the matrices here
+// transform column vectors, `M * (x, y, 1)`. So the 3x2 matrices hold the transpose of the
+// upper two rows: `m11`, `m21` and `dx` make the first row, `m12`, `m22` and `dy` the second.
+
+/// Returns the 2D affine part of `matrix` as `[m11, m12, m21, m22, dx, dy]`.
+fn affine_2d(matrix: &Matrix3x3<f32>) -> [f32; 6] {
+    debug_assert!(
+        matrix[2] == Vector3::new(0.0, 0.0, 1.0),
+        "the last row of a 2D transform must be (0, 0, 1), got {:?}",
+        matrix[2]
+    );
+    [
+        matrix[0][0],
+        matrix[1][0],
+        matrix[0][1],
+        matrix[1][1],
+        matrix[0][2],
+        matrix[1][2],
+    ]
+}
+
+/// Builds the 2D transform from `[m11, m12, m21, m22, dx, dy]`.
+fn from_affine_2d([m11, m12, m21, m22, dx, dy]: [f32; 6]) -> Matrix3x3<f32> {
+    Matrix3x3::from_mat([[m11, m21, dx], [m12, m22, dy], [0.0, 0.0, 1.0]])
+}
+
+/// The last row is dropped, so it must be `(0, 0, 1)`. This is checked in debug builds.
+impl From<Matrix3x3<f32>> for D2D_MATRIX_3X2_F {
+    fn from(matrix: Matrix3x3<f32>) -> Self {
+        Self {
+            Anonymous: D2D_MATRIX_3X2_F_0 {
+                m: affine_2d(&matrix),
+            },
+        }
+    }
+}
+
+impl From<D2D_MATRIX_3X2_F> for Matrix3x3<f32> {
+    fn from(matrix: D2D_MATRIX_3X2_F) -> Self {
+        // All the members of the union are views of the same six floats.
+        from_affine_2d(unsafe { matrix.Anonymous.m })
+    }
+}
+
+/// The type the Direct2D methods take for `D2D1_MATRIX_3X2_F`.
+/// The last row is dropped, so it must be `(0, 0, 1)`. This is checked in debug builds.
+impl From<Matrix3x3<f32>> for Matrix3x2 {
+    fn from(matrix: Matrix3x3<f32>) -> Self {
+        let [m11, m12, m21, m22, dx, dy] = affine_2d(&matrix);
+        Self {
+            M11: m11,
+            M12: m12,
+            M21: m21,
+            M22: m22,
+            M31: dx,
+            M32: dy,
+        }
+    }
+}
+
+impl From<Matrix3x2> for Matrix3x3<f32> {
+    fn from(matrix: Matrix3x2) -> Self {
+        from_affine_2d([
+            matrix.M11, matrix.M12, matrix.M21, matrix.M22, matrix.M31, matrix.M32,
+        ])
+    }
+}
+
+/// The last row is dropped, so it must be `(0, 0, 1)`. This is checked in debug builds.
+impl From<Matrix3x3<f32>> for DWRITE_MATRIX {
+    fn from(matrix: Matrix3x3<f32>) -> Self {
+        let [m11, m12, m21, m22, dx, dy] = affine_2d(&matrix);
+        Self {
+            m11,
+            m12,
+            m21,
+            m22,
+            dx,
+            dy,
+        }
+    }
+}
+
+impl From<DWRITE_MATRIX> for Matrix3x3<f32> {
+    fn from(matrix: DWRITE_MATRIX) -> Self {
+        from_affine_2d([
+            matrix.m11, matrix.m12, matrix.m21, matrix.m22, matrix.dx, matrix.dy,
+        ])
+    }
+}
+
+/// Keeps the transform of the XY plane, as text is laid out at `z = 0`. The Z row and column are
+/// dropped, and so is the last row, which must be `(0, 0, 0, 1)`. This is checked in debug builds.
+impl From<Matrix4x4<f32>> for DWRITE_MATRIX {
+    fn from(matrix: Matrix4x4<f32>) -> Self {
+        debug_assert!(
+            matrix[3] == Vector4::new(0.0, 0.0, 0.0, 1.0),
+            "the last row of an affine transform must be (0, 0, 0, 1), got {:?}",
+            matrix[3]
+        );
+        Self {
+            m11: matrix[0][0],
+            m12: matrix[1][0],
+            m21: matrix[0][1],
+            m22: matrix[1][1],
+            dx: matrix[0][3],
+            dy: matrix[1][3],
+        }
+    }
+}
+
+/// The transform leaves Z unchanged.
+impl From<DWRITE_MATRIX> for Matrix4x4<f32> {
+    fn from(matrix: DWRITE_MATRIX) -> Self {
+        Matrix4x4::from_mat([
+            [matrix.m11, matrix.m21, 0.0, matrix.dx],
+            [matrix.m12, matrix.m22, 0.0, matrix.dy],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+}
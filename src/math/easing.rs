@@ -0,0 +1,280 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Easing functions for animations, mapping the progress `t` of an animation to the fraction
+//! of the change applied at that point.
+//!
+//! Every function clamps `t` to `[0, 1]`, and returns exactly 0 at `t = 0` and 1 at `t = 1`.
+//! In between, back, bounce and elastic easings leave `[0, 1]` or go back and forth, the others
+//! only increase. `_in` easings start slowly, `_out` easings end slowly, and `_in_out` easings
+//! do both, symmetrically around `t = 0.5`.
+//! Use `ease` or the `ease_to` methods of the vectors and `Color` to pick an easing at runtime.
+
+use std::f64::consts::PI;
+
+/// Overshoot of the back easings, making them go about 10% past their target.
+pub const DEFAULT_BACK_OVERSHOOT: f64 = 1.70158;
+
+/// Easing functions of this module, to choose one at runtime with `ease`.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum Easing {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuartIn,
+    QuartOut,
+    QuartInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    /// Back easings with the given overshoot, `DEFAULT_BACK_OVERSHOOT` for the usual one.
+    BackIn {
+        overshoot: f64,
+    },
+    BackOut {
+        overshoot: f64,
+    },
+    BackInOut {
+        overshoot: f64,
+    },
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+}
+
+/// Applies the easing `kind` to `t`.
+pub fn ease(kind: Easing, t: f64) -> f64 {
+    match kind {
+        Easing::Linear => linear(t),
+        Easing::QuadIn => quad_in(t),
+        Easing::QuadOut => quad_out(t),
+        Easing::QuadInOut => quad_in_out(t),
+        Easing::CubicIn => cubic_in(t),
+        Easing::CubicOut => cubic_out(t),
+        Easing::CubicInOut => cubic_in_out(t),
+        Easing::QuartIn => quart_in(t),
+        Easing::QuartOut => quart_out(t),
+        Easing::QuartInOut => quart_in_out(t),
+        Easing::SineIn => sine_in(t),
+        Easing::SineOut => sine_out(t),
+        Easing::SineInOut => sine_in_out(t),
+        Easing::ExpoIn => expo_in(t),
+        Easing::ExpoOut => expo_out(t),
+        Easing::ExpoInOut => expo_in_out(t),
+        Easing::BackIn { overshoot } => back_in_with_overshoot(t, overshoot),
+        Easing::BackOut { overshoot } => back_out_with_overshoot(t, overshoot),
+        Easing::BackInOut { overshoot } => back_in_out_with_overshoot(t, overshoot),
+        Easing::BounceIn => bounce_in(t),
+        Easing::BounceOut => bounce_out(t),
+        Easing::BounceInOut => bounce_in_out(t),
+        Easing::ElasticIn => elastic_in(t),
+        Easing::ElasticOut => elastic_out(t),
+        Easing::ElasticInOut => elastic_in_out(t),
+    }
+}
+
+/// Clamps `t` and returns the exact ends, evaluating `easing` only strictly between them.
+#[inline]
+fn clamped(t: f64, easing: impl FnOnce(f64) -> f64) -> f64 {
+    if t <= 0.0 {
+        0.0
+    } else if t >= 1.0 {
+        1.0
+    } else {
+        easing(t)
+    }
+}
+
+/// Builds the out easing of an in easing, by mirroring it.
+#[inline]
+fn out_of(t: f64, easing_in: impl FnOnce(f64) -> f64) -> f64 {
+    1.0 - easing_in(1.0 - t)
+}
+
+/// Builds the in-out easing of an in easing, with its first half sped up and its second half
+/// mirrored.
+#[inline]
+fn in_out_of(t: f64, easing_in: impl Fn(f64) -> f64) -> f64 {
+    if t < 0.5 {
+        easing_in(2.0 * t) / 2.0
+    } else {
+        1.0 - easing_in(2.0 - 2.0 * t) / 2.0
+    }
+}
+
+/// No easing, `t` itself.
+pub fn linear(t: f64) -> f64 {
+    clamped(t, |t| t)
+}
+
+pub fn quad_in(t: f64) -> f64 {
+    clamped(t, |t| t * t)
+}
+
+pub fn quad_out(t: f64) -> f64 {
+    clamped(t, |t| out_of(t, |t| t * t))
+}
+
+pub fn quad_in_out(t: f64) -> f64 {
+    clamped(t, |t| in_out_of(t, |t| t * t))
+}
+
+pub fn cubic_in(t: f64) -> f64 {
+    clamped(t, |t| t * t * t)
+}
+
+pub fn cubic_out(t: f64) -> f64 {
+    clamped(t, |t| out_of(t, |t| t * t * t))
+}
+
+pub fn cubic_in_out(t: f64) -> f64 {
+    clamped(t, |t| in_out_of(t, |t| t * t * t))
+}
+
+pub fn quart_in(t: f64) -> f64 {
+    clamped(t, |t| t.powi(4))
+}
+
+pub fn quart_out(t: f64) -> f64 {
+    clamped(t, |t| out_of(t, |t| t.powi(4)))
+}
+
+pub fn quart_in_out(t: f64) -> f64 {
+    clamped(t, |t| in_out_of(t, |t| t.powi(4)))
+}
+
+/// Follows a quarter of a cosine wave.
+pub fn sine_in(t: f64) -> f64 {
+    clamped(t, |t| 1.0 - (t * PI / 2.0).cos())
+}
+
+pub fn sine_out(t: f64) -> f64 {
+    clamped(t, |t| (t * PI / 2.0).sin())
+}
+
+pub fn sine_in_out(t: f64) -> f64 {
+    clamped(t, |t| (1.0 - (t * PI).cos()) / 2.0)
+}
+
+/// Doubles every tenth of `t`, starting from about 0.001.
+pub fn expo_in(t: f64) -> f64 {
+    clamped(t, |t| (10.0 * t - 10.0).exp2())
+}
+
+pub fn expo_out(t: f64) -> f64 {
+    clamped(t, |t| out_of(t, |t| (10.0 * t - 10.0).exp2()))
+}
+
+pub fn expo_in_out(t: f64) -> f64 {
+    clamped(t, |t| in_out_of(t, |t| (10.0 * t - 10.0).exp2()))
+}
+
+/// Backs away from the target before going to it, with `DEFAULT_BACK_OVERSHOOT`.
+pub fn back_in(t: f64) -> f64 {
+    back_in_with_overshoot(t, DEFAULT_BACK_OVERSHOOT)
+}
+
+/// Goes past the target before coming back to it, with `DEFAULT_BACK_OVERSHOOT`.
+pub fn back_out(t: f64) -> f64 {
+    back_out_with_overshoot(t, DEFAULT_BACK_OVERSHOOT)
+}
+
+pub fn back_in_out(t: f64) -> f64 {
+    back_in_out_with_overshoot(t, DEFAULT_BACK_OVERSHOOT)
+}
+
+/// Back easing going further back the larger `overshoot` is. An overshoot of 0 is `cubic_in`.
+pub fn back_in_with_overshoot(t: f64, overshoot: f64) -> f64 {
+    clamped(t, |t| t * t * ((overshoot + 1.0) * t - overshoot))
+}
+
+pub fn back_out_with_overshoot(t: f64, overshoot: f64) -> f64 {
+    clamped(t, |t| {
+        out_of(t, |t| t * t * ((overshoot + 1.0) * t - overshoot))
+    })
+}
+
+pub fn back_in_out_with_overshoot(t: f64, overshoot: f64) -> f64 {
+    clamped(t, |t| {
+        in_out_of(t, |t| t * t * ((overshoot + 1.0) * t - overshoot))
+    })
+}
+
+/// Bounces on the start with decreasing heights before going to the target.
+pub fn bounce_in(t: f64) -> f64 {
+    clamped(t, |t| out_of(t, bounce))
+}
+
+/// Falls to the target and bounces on it with decreasing heights.
+pub fn bounce_out(t: f64) -> f64 {
+    clamped(t, bounce)
+}
+
+pub fn bounce_in_out(t: f64) -> f64 {
+    clamped(t, |t| in_out_of(t, |t| out_of(t, bounce)))
+}
+
+/// Three bounces of a ball falling to 1, made of parabolas of decreasing heights.
+fn bounce(t: f64) -> f64 {
+    const STRENGTH: f64 = 7.5625;
+    const DURATION: f64 = 2.75;
+    if t < 1.0 / DURATION {
+        STRENGTH * t * t
+    } else if t < 2.0 / DURATION {
+        let t = t - 1.5 / DURATION;
+        STRENGTH * t * t + 0.75
+    } else if t < 2.5 / DURATION {
+        let t = t - 2.25 / DURATION;
+        STRENGTH * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / DURATION;
+        STRENGTH * t * t + 0.984375
+    }
+}
+
+/// Oscillates around the start with a growing amplitude before snapping to the target.
+pub fn elastic_in(t: f64) -> f64 {
+    clamped(t, elastic)
+}
+
+/// Snaps past the target and oscillates around it with a decreasing amplitude.
+pub fn elastic_out(t: f64) -> f64 {
+    clamped(t, |t| out_of(t, elastic))
+}
+
+pub fn elastic_in_out(t: f64) -> f64 {
+    clamped(t, |t| in_out_of(t, elastic))
+}
+
+/// Sine wave of period 0.3 under an exponentially growing envelope, reaching 1 at `t = 1`.
+fn elastic(t: f64) -> f64 {
+    const PERIOD: f64 = 0.3;
+    -(10.0 * t - 10.0).exp2() * ((t - 1.0 - PERIOD / 4.0) * 2.0 * PI / PERIOD).sin()
+}
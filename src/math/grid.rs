@@ -0,0 +1,218 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Coordinates of the cells of a grid, such as a tile map or the board of a snake game.
+//!
+//! Cells are indexed by column and row from the top left cell `(0, 0)`, with rows growing down
+//! like window coordinates. Cells are half-open: a point on the boundary between two cells is in
+//! the one to its right or below it.
+
+use super::{Direction, Rect, Size, Vector2};
+
+/// Offsets of the diagonal neighbors, clockwise from the top right one.
+const DIAGONALS: [Vector2<i32>; 4] = [
+    Vector2::new(1, -1),
+    Vector2::new(1, 1),
+    Vector2::new(-1, 1),
+    Vector2::new(-1, -1),
+];
+
+/// A grid of `dimensions.x` columns and `dimensions.y` rows of cells of `cell_size`,
+/// with the top left corner of its first cell at `origin`, in world coordinates.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Grid {
+    pub cell_size: Size<f32>,
+    pub origin: Vector2<f32>,
+    pub dimensions: Vector2<u32>,
+}
+
+impl Grid {
+    pub const fn new(cell_size: Size<f32>, origin: Vector2<f32>, dimensions: Vector2<u32>) -> Self {
+        Self {
+            cell_size,
+            origin,
+            dimensions,
+        }
+    }
+
+    /// Returns whether `cell` is one of the cells of the grid.
+    pub fn contains(&self, cell: Vector2<i32>) -> bool {
+        (0..self.dimensions.x as i64).contains(&(cell.x as i64))
+            && (0..self.dimensions.y as i64).contains(&(cell.y as i64))
+    }
+
+    /// Returns the area covered by `cell` in world coordinates. Cells outside the grid are
+    /// placed as if it extended indefinitely.
+    pub fn cell_to_world(&self, cell: Vector2<i32>) -> Rect<f32> {
+        Rect {
+            x: self.origin.x + cell.x as f32 * self.cell_size.width,
+            y: self.origin.y + cell.y as f32 * self.cell_size.height,
+            width: self.cell_size.width,
+            height: self.cell_size.height,
+        }
+    }
+
+    /// Returns the center of `cell` in world coordinates.
+    pub fn cell_center(&self, cell: Vector2<i32>) -> Vector2<f32> {
+        let area = self.cell_to_world(cell);
+        Vector2::new(area.x + area.width / 2.0, area.y + area.height / 2.0)
+    }
+
+    /// Returns the cell containing the world coordinates `point`, or `None` outside the grid or
+    /// if a coordinate isn't finite.
+    pub fn world_to_cell(&self, point: Vector2<f32>) -> Option<Vector2<i32>> {
+        let column = ((point.x - self.origin.x) / self.cell_size.width).floor();
+        let row = ((point.y - self.origin.y) / self.cell_size.height).floor();
+        // NaN fails every comparison below and would cast to cell 0.
+        if !column.is_finite()
+            || !row.is_finite()
+            || column < 0.0
+            || row < 0.0
+            || column >= self.dimensions.x as f32
+            || row >= self.dimensions.y as f32
+        {
+            return None;
+        }
+        Some(Vector2::new(column as i32, row as i32))
+    }
+
+    /// Returns the cell next to `cell` in `direction`, or `None` past the edges of the grid.
+    pub fn neighbor(&self, cell: Vector2<i32>, direction: Direction) -> Option<Vector2<i32>> {
+        let neighbor = cell.checked_add(&direction.offset())?;
+        self.contains(neighbor).then_some(neighbor)
+    }
+
+    /// Returns the cells of the grid next to `cell`: the ones in each `Direction` in order,
+    /// followed by the diagonal ones clockwise from the top right with `include_diagonals`.
+    /// Cells past the edges of the grid are skipped.
+    pub fn neighbors(
+        &self,
+        cell: Vector2<i32>,
+        include_diagonals: bool,
+    ) -> impl Iterator<Item = Vector2<i32>> + '_ {
+        neighbor_offsets(include_diagonals)
+            .filter_map(move |offset| cell.checked_add(&offset))
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    /// Returns the cells of the grid overlapping `area`, in world coordinates, row by row.
+    /// Cells only touching its edges don't overlap it, and an empty area, or one with a NaN or
+    /// infinite coordinate or size, overlaps no cell.
+    pub fn cells_in_rect(&self, area: &Rect<f32>) -> impl Iterator<Item = Vector2<i32>> {
+        let (columns, rows) = if area.width > 0.0 && area.height > 0.0 {
+            (
+                self.overlapped_range(
+                    area.x - self.origin.x,
+                    area.width,
+                    self.cell_size.width,
+                    self.dimensions.x,
+                ),
+                self.overlapped_range(
+                    area.y - self.origin.y,
+                    area.height,
+                    self.cell_size.height,
+                    self.dimensions.y,
+                ),
+            )
+        } else {
+            (0..0, 0..0)
+        };
+        rows.flat_map(move |row| columns.clone().map(move |column| Vector2::new(column, row)))
+    }
+
+    /// Returns the indices of the cells of `cell_length` overlapping the span from `start` of
+    /// `length` along one axis, among `count` cells. Empty if `start` or `length` isn't finite.
+    fn overlapped_range(
+        &self,
+        start: f32,
+        length: f32,
+        cell_length: f32,
+        count: u32,
+    ) -> std::ops::Range<i32> {
+        // Clamping to the grid would turn NaN into its first or last cell.
+        if !start.is_finite() || !length.is_finite() {
+            return 0..0;
+        }
+        let first = (start / cell_length).floor().max(0.0);
+        let end = ((start + length) / cell_length).ceil().min(count as f32);
+        if first >= end {
+            return 0..0;
+        }
+        first as i32..end as i32
+    }
+
+    /// Returns the cell of the grid `cell` ends up in on a toroidal map, where leaving the grid
+    /// on one side enters it again from the opposite side.
+    ///
+    /// # Panics
+    /// Panics if the grid has no cells.
+    pub fn wrap_cell(&self, cell: Vector2<i32>) -> Vector2<i32> {
+        self.wrap_offset(cell, Vector2::ZERO)
+    }
+
+    /// Returns the cell next to `cell` in `direction` on a toroidal map, see `wrap_cell`.
+    ///
+    /// # Panics
+    /// Panics if the grid has no cells.
+    pub fn neighbor_wrapping(&self, cell: Vector2<i32>, direction: Direction) -> Vector2<i32> {
+        self.wrap_offset(cell, direction.offset())
+    }
+
+    /// Returns the cells next to `cell` on a toroidal map, in the order of `neighbors`.
+    /// On grids narrower than three cells, the same cell can be next to `cell` on both sides
+    /// and is returned for each.
+    ///
+    /// # Panics
+    /// Panics if the grid has no cells.
+    pub fn neighbors_wrapping(
+        &self,
+        cell: Vector2<i32>,
+        include_diagonals: bool,
+    ) -> impl Iterator<Item = Vector2<i32>> + '_ {
+        neighbor_offsets(include_diagonals).map(move |offset| self.wrap_offset(cell, offset))
+    }
+
+    /// Wraps `cell + offset` into the grid, without overflowing for cells near the `i32` limits.
+    fn wrap_offset(&self, cell: Vector2<i32>, offset: Vector2<i32>) -> Vector2<i32> {
+        assert!(
+            self.dimensions.x > 0 && self.dimensions.y > 0,
+            "Cannot wrap cells in a grid without cells."
+        );
+        let moved = Vector2::new(
+            cell.x as i64 + offset.x as i64,
+            cell.y as i64 + offset.y as i64,
+        );
+        let dimensions = Vector2::new(self.dimensions.x as i64, self.dimensions.y as i64);
+        let wrapped = moved.wrap(&Vector2::ZERO, &dimensions);
+        Vector2::new(wrapped.x as i32, wrapped.y as i32)
+    }
+}
+
+/// Offsets of the neighbors of a cell, in the order documented by `Grid::neighbors`.
+fn neighbor_offsets(include_diagonals: bool) -> impl Iterator<Item = Vector2<i32>> {
+    let diagonals = if include_diagonals {
+        &DIAGONALS[..]
+    } else {
+        &[]
+    };
+    Direction::ALL
+        .into_iter()
+        .map(Direction::offset)
+        .chain(diagonals.iter().copied())
+}
@@ -0,0 +1,87 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Conversions between `f32` and the bits of IEEE 754 half-precision floats,
+//! for vertex data uploaded to the GPU as `DXGI_FORMAT_R16*_FLOAT`.
+
+/// Converts `value` to the bits of the nearest half-precision float, rounding ties to even.
+/// Values beyond the half range become infinity and NaNs stay NaNs.
+pub fn f32_to_f16_bits(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x7f_ffff;
+
+    if exponent == 0xff {
+        // Infinity, or NaN with its payload truncated and the quiet bit set.
+        let nan = if mantissa != 0 {
+            0x0200 | (mantissa >> 13) as u16
+        } else {
+            0
+        };
+        return sign | 0x7c00 | nan;
+    }
+
+    let half_exponent = exponent - 127 + 15;
+    if half_exponent >= 0x1f {
+        return sign | 0x7c00;
+    }
+    if half_exponent <= 0 {
+        // Below 2^-25 everything rounds to zero, including f32 subnormals.
+        if half_exponent < -10 {
+            return sign;
+        }
+        // Half subnormal: the implicit bit becomes part of the mantissa.
+        let shift = (14 - half_exponent) as u32;
+        return sign | round_shift_right(mantissa | 0x80_0000, shift) as u16;
+    }
+
+    // A carry out of the mantissa moves to the next exponent, up to infinity.
+    let magnitude = ((half_exponent as u32) << 10) + round_shift_right(mantissa, 13);
+    sign | magnitude as u16
+}
+
+/// Converts the bits of a half-precision float to `f32`, which is always exact.
+pub fn f16_bits_to_f32(bits: u16) -> f32 {
+    let sign = ((bits & 0x8000) as u32) << 16;
+    let exponent = ((bits >> 10) & 0x1f) as u32;
+    let mantissa = (bits & 0x3ff) as u32;
+
+    let magnitude = match exponent {
+        0 => {
+            // Zero or subnormal, worth mantissa * 2^-24.
+            (mantissa as f32 * f32::from_bits(0x3380_0000)).to_bits()
+        }
+        0x1f => 0x7f80_0000 | (mantissa << 13),
+        _ => ((exponent + 127 - 15) << 23) | (mantissa << 13),
+    };
+    f32::from_bits(sign | magnitude)
+}
+
+/// Shifts `value` right by `shift` bits, rounding to nearest with ties to even.
+fn round_shift_right(value: u32, shift: u32) -> u32 {
+    let result = value >> shift;
+    let remainder = value & ((1 << shift) - 1);
+    let halfway = 1 << (shift - 1);
+    if remainder > halfway || (remainder == halfway && result & 1 == 1) {
+        result + 1
+    } else {
+        result
+    }
+}
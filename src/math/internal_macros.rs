@@ -75,6 +75,19 @@ macro_rules! forward_ref_op_assign {
     };
 }
 
+/// Implements `Ord` for integer `$type`s, comparing the `$field`s in order.
+// The derived `PartialOrd` compares fields in declaration order, which is the order used here.
+macro_rules! implement_lexicographic_ord {
+    ($type:ident, $($field:ident),+) => {
+        #[allow(clippy::derive_ord_xor_partial_ord)]
+        impl<T: IntegerNumber> Ord for $type<T> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                Ordering::Equal$(.then_with(|| self.$field.cmp(&other.$field)))+
+            }
+        }
+    };
+}
+
 macro_rules! implement_scalar_lhs_mul {
     ($($vec_type:ty, $t:ty);+) => ($(
         impl Mul<$vec_type> for $t {
@@ -114,3 +127,67 @@ macro_rules! implement_scalar_lhs_mul {
         }
     )*);
 }
+
+macro_rules! implement_vector_reductions {
+    ($($vec_type:ident),+) => ($(
+        impl<T: Number> $vec_type<T> {
+            /// Returns the smallest component.
+            /// NaN components propagate: the result is NaN if any component is NaN.
+            pub fn min_element(&self) -> T {
+                self.as_slice()[self.argmin()]
+            }
+
+            /// Returns the largest component.
+            /// NaN components propagate: the result is NaN if any component is NaN.
+            pub fn max_element(&self) -> T {
+                self.as_slice()[self.argmax()]
+            }
+
+            /// Returns the largest absolute value of the components, NaN if any component is NaN.
+            pub fn abs_max_element(&self) -> T {
+                let mut abs = *self.as_slice();
+                for component in abs.iter_mut() {
+                    *component = crate::math::Abs::abs(*component);
+                }
+                abs[Self::select_component(&abs, |candidate, best| candidate > best)]
+            }
+
+            /// Returns the sum of the components.
+            pub fn sum(&self) -> T {
+                self.as_slice().iter().fold(T::ZERO, |sum, &component| sum + component)
+            }
+
+            /// Returns the product of the components.
+            pub fn product(&self) -> T {
+                self.as_slice().iter().fold(T::ONE, |product, &component| product * component)
+            }
+
+            /// Returns the index of the smallest component, the first one on ties.
+            /// If any component is NaN, returns the index of the first NaN component.
+            pub fn argmin(&self) -> usize {
+                Self::select_component(self.as_slice(), |candidate, best| candidate < best)
+            }
+
+            /// Returns the index of the largest component, the first one on ties.
+            /// If any component is NaN, returns the index of the first NaN component.
+            pub fn argmax(&self) -> usize {
+                Self::select_component(self.as_slice(), |candidate, best| candidate > best)
+            }
+
+            /// Index of the component preferred by `better`, NaN components being preferred to all others.
+            fn select_component(components: &[T], better: impl Fn(T, T) -> bool) -> usize {
+                let is_nan = |value: T| value.partial_cmp(&value).is_none();
+                let mut best = 0;
+                for (index, &component) in components.iter().enumerate().skip(1) {
+                    if is_nan(components[best]) {
+                        break;
+                    }
+                    if is_nan(component) || better(component, components[best]) {
+                        best = index;
+                    }
+                }
+                best
+            }
+        }
+    )+)
+}
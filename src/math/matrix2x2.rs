@@ -0,0 +1,515 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub};
+
+use crate::math::{Angle, SignedNumber, Vector2};
+
+/// A 2x2 matrix represented as an array of two `Vector2<T>` **rows**.
+/// It supports addition, subtraction, multiplication by a scalar,
+/// multiplication by another matrix, and multiplication by a vector.
+/// It also provides indexing for accessing individual rows, so you can use `matrix[0]` to access the first row.
+/// And `matrix[0][0]` to access the first element of the first row.
+/// It is generic over any type `T` that implements the `SignedNumber` trait.
+/// The matrix is stored in row-major order.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[repr(C)]
+pub struct Matrix2x2<T: SignedNumber> {
+    mat: [Vector2<T>; 2],
+}
+
+impl<T: SignedNumber> Neg for Matrix2x2<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self {
+            mat: [
+                Vector2 {
+                    x: -self[0][0],
+                    y: -self[0][1],
+                },
+                Vector2 {
+                    x: -self[1][0],
+                    y: -self[1][1],
+                },
+            ],
+        }
+    }
+}
+forward_ref_unop!(impl<T> Neg, neg for Matrix2x2<T> where T: SignedNumber);
+
+impl<T: SignedNumber> Add for Matrix2x2<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            mat: [
+                Vector2 {
+                    x: self[0][0] + rhs[0][0],
+                    y: self[0][1] + rhs[0][1],
+                },
+                Vector2 {
+                    x: self[1][0] + rhs[1][0],
+                    y: self[1][1] + rhs[1][1],
+                },
+            ],
+        }
+    }
+}
+forward_ref_binop!(impl<T> Add, add for Matrix2x2<T>, Matrix2x2<T> where T: SignedNumber);
+
+impl<T: SignedNumber> AddAssign for Matrix2x2<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self[0][0] += rhs[0][0];
+        self[0][1] += rhs[0][1];
+        self[1][0] += rhs[1][0];
+        self[1][1] += rhs[1][1];
+    }
+}
+forward_ref_op_assign!(impl<T> AddAssign, add_assign for Matrix2x2<T>, Matrix2x2<T> where T: SignedNumber);
+
+impl<T: SignedNumber> Sub for Matrix2x2<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            mat: [
+                Vector2 {
+                    x: self[0][0] - rhs[0][0],
+                    y: self[0][1] - rhs[0][1],
+                },
+                Vector2 {
+                    x: self[1][0] - rhs[1][0],
+                    y: self[1][1] - rhs[1][1],
+                },
+            ],
+        }
+    }
+}
+forward_ref_binop!(impl<T> Sub, sub for Matrix2x2<T>, Matrix2x2<T> where T: SignedNumber);
+
+// Right-hand side scalar multiplication
+impl<T: SignedNumber> Mul<T> for Matrix2x2<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            mat: [self[0] * rhs, self[1] * rhs],
+        }
+    }
+}
+forward_ref_binop!(impl<T> Mul, mul for Matrix2x2<T>, T where T: SignedNumber);
+implement_scalar_lhs_mul! {
+    Matrix2x2<i32>, i32;
+    Matrix2x2<i64>, i64;
+    Matrix2x2<f32>, f32;
+    Matrix2x2<f64>, f64
+}
+
+impl<T: SignedNumber> MulAssign<T> for Matrix2x2<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self[0] *= rhs;
+        self[1] *= rhs;
+    }
+}
+forward_ref_op_assign!(impl<T> MulAssign, mul_assign for Matrix2x2<T>, T where T: SignedNumber);
+
+/// Right-hand side multiplication of a vector. Consider the vector as a column vector.
+impl<T: SignedNumber> Mul<Vector2<T>> for Matrix2x2<T> {
+    type Output = Vector2<T>;
+
+    #[inline]
+    fn mul(self, rhs: Vector2<T>) -> Self::Output {
+        Vector2 {
+            x: self[0].dot(rhs),
+            y: self[1].dot(rhs),
+        }
+    }
+}
+forward_ref_binop!(impl<T> Mul, mul for Matrix2x2<T>, Vector2<T> where T: SignedNumber);
+
+/// Left-hand side multiplication of a vector. Consider the vector as a row vector.
+impl<T: SignedNumber> Mul<Matrix2x2<T>> for Vector2<T> {
+    type Output = Vector2<T>;
+
+    #[inline]
+    fn mul(self, rhs: Matrix2x2<T>) -> Self::Output {
+        Vector2 {
+            x: self.x * rhs[0][0] + self.y * rhs[1][0],
+            y: self.x * rhs[0][1] + self.y * rhs[1][1],
+        }
+    }
+}
+forward_ref_binop!(impl<T> Mul, mul for Vector2<T>, Matrix2x2<T> where T: SignedNumber);
+
+// Matrix multiplication
+impl<T: SignedNumber> Mul<Matrix2x2<T>> for Matrix2x2<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Matrix2x2<T>) -> Self::Output {
+        Self {
+            mat: [
+                Vector2 {
+                    x: self[0][0] * rhs[0][0] + self[0][1] * rhs[1][0],
+                    y: self[0][0] * rhs[0][1] + self[0][1] * rhs[1][1],
+                },
+                Vector2 {
+                    x: self[1][0] * rhs[0][0] + self[1][1] * rhs[1][0],
+                    y: self[1][0] * rhs[0][1] + self[1][1] * rhs[1][1],
+                },
+            ],
+        }
+    }
+}
+forward_ref_binop!(impl<T> Mul, mul for Matrix2x2<T>, Matrix2x2<T> where T: SignedNumber);
+
+// Division by scalar
+impl<T: SignedNumber> Div<T> for Matrix2x2<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self::Output {
+        Self {
+            mat: [self[0] / rhs, self[1] / rhs],
+        }
+    }
+}
+forward_ref_binop!(impl<T> Div, div for Matrix2x2<T>, T where T: SignedNumber);
+
+// Division assignment by scalar
+impl<T: SignedNumber> DivAssign<T> for Matrix2x2<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self[0] /= rhs;
+        self[1] /= rhs;
+    }
+}
+forward_ref_op_assign!(impl<T> DivAssign, div_assign for Matrix2x2<T>, T where T: SignedNumber);
+
+impl<T: SignedNumber> From<&[T]> for Matrix2x2<T> {
+    #[inline]
+    fn from(slice: &[T]) -> Self {
+        Self::from_slice(slice)
+    }
+}
+
+impl<T: SignedNumber> From<[T; 4]> for Matrix2x2<T> {
+    #[inline]
+    fn from(array: [T; 4]) -> Self {
+        Self::from_array(array)
+    }
+}
+
+impl<T: SignedNumber> From<[[T; 2]; 2]> for Matrix2x2<T> {
+    #[inline]
+    fn from(mat: [[T; 2]; 2]) -> Self {
+        Self::from_mat(mat)
+    }
+}
+
+impl<T: SignedNumber> Index<usize> for Matrix2x2<T> {
+    type Output = Vector2<T>;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        debug_assert!(index < 2);
+        &self.mat[index]
+    }
+}
+
+impl<T: SignedNumber> IndexMut<usize> for Matrix2x2<T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        debug_assert!(index < 2);
+        &mut self.mat[index]
+    }
+}
+
+impl<T: SignedNumber> Index<(usize, usize)> for Matrix2x2<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        debug_assert!(index.0 < 2 && index.1 < 2);
+        &self.mat[index.0][index.1]
+    }
+}
+
+impl<T: SignedNumber> IndexMut<(usize, usize)> for Matrix2x2<T> {
+    #[inline]
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        debug_assert!(index.0 < 2 && index.1 < 2);
+        &mut self.mat[index.0][index.1]
+    }
+}
+
+impl<T: SignedNumber> Matrix2x2<T> {
+    /// A matrix with all elements set to zero.
+    pub const ZERO: Self = Self {
+        mat: [Vector2::ZERO, Vector2::ZERO],
+    };
+
+    /// The identity matrix, with ones on the diagonal and zeros elsewhere.
+    pub const IDENTITY: Self = Self {
+        mat: [Vector2::new(T::ONE, T::ZERO), Vector2::new(T::ZERO, T::ONE)],
+    };
+
+    /// Creates a new `Matrix2x2` with the given rows.
+    pub const fn new(rows: [Vector2<T>; 2]) -> Self {
+        Self { mat: rows }
+    }
+
+    /// Creates a new `Matrix2x2` with all elements initialized to zero.
+    pub const fn zero() -> Self {
+        Self::ZERO
+    }
+
+    /// Creates a new `Matrix2x2` with all elements initialized to one.
+    pub const fn one() -> Self {
+        Self {
+            mat: [Vector2::ONE, Vector2::ONE],
+        }
+    }
+
+    /// Creates a new `Matrix2x2` that represents the identity matrix.
+    /// The identity matrix has ones on the diagonal and zeros elsewhere.
+    pub const fn identity() -> Self {
+        Self::IDENTITY
+    }
+
+    /// Returns the transpose of the matrix.
+    /// The transpose of a matrix is obtained by swapping its rows and columns.
+    /// For each element `mat[i][j]`, the transpose will have `mat[j][i]`.
+    pub fn transpose(&self) -> Self {
+        Self {
+            mat: [
+                Vector2::new(self[0][0], self[1][0]),
+                Vector2::new(self[0][1], self[1][1]),
+            ],
+        }
+    }
+
+    /// Returns the determinant of the matrix.
+    pub fn determinant(&self) -> T {
+        self[0][0] * self[1][1] - self[0][1] * self[1][0]
+    }
+
+    /// Returns the inverse of the matrix if it exists.
+    /// The inverse is the adjugate, swapping the diagonal and negating the rest, over the determinant.
+    pub fn inverse(&self) -> Option<Self> {
+        let determinant = self.determinant();
+        if determinant == T::zero() {
+            return None; // Matrix is singular, no inverse exists
+        }
+
+        let inv_det = T::one() / determinant;
+
+        Some(Self {
+            mat: [
+                Vector2::new(self[1][1] * inv_det, -self[0][1] * inv_det),
+                Vector2::new(-self[1][0] * inv_det, self[0][0] * inv_det),
+            ],
+        })
+    }
+
+    /// Returns the inverse of the matrix like `inverse`, or `None` if it is nearly singular: if
+    /// the magnitude of its determinant is below `epsilon` times the largest determinant a matrix
+    /// of the same Frobenius norm can have, that of a scaled rotation. Unlike comparing the
+    /// determinant itself, this doesn't depend on the scale of the matrix.
+    /// See `condition_estimate` to measure how close to singular the matrix is.
+    pub fn inverse_checked(&self, epsilon: T) -> Option<Self> {
+        let norm_squared: f64 = self
+            .mat
+            .iter()
+            .map(|row| row.norm_squared().as_double())
+            .sum();
+        let largest_determinant = norm_squared / 2.0;
+        if largest_determinant == 0.0
+            || self.determinant().as_double().abs() < epsilon.as_double() * largest_determinant
+        {
+            return None;
+        }
+        self.inverse()
+    }
+
+    /// Right-multiplies each vector of `vectors` by the matrix, writing the results to `out`.
+    /// Does not allocate, so `out` can be reused across frames.
+    ///
+    /// # Panics
+    /// Panics if `vectors` and `out` have different lengths.
+    pub fn transform_into(&self, vectors: &[Vector2<T>], out: &mut [Vector2<T>]) {
+        assert_eq!(
+            vectors.len(),
+            out.len(),
+            "`out` must have the length of `vectors`"
+        );
+        for (vector, result) in vectors.iter().zip(out.iter_mut()) {
+            *result = *self * *vector;
+        }
+    }
+
+    /// Right-multiplies each vector of `vectors` by the matrix, replacing it with the result.
+    pub fn transform_in_place(&self, vectors: &mut [Vector2<T>]) {
+        for vector in vectors.iter_mut() {
+            *vector = *self * *vector;
+        }
+    }
+
+    /// Returns the rows of the matrix as an array of `Vector2<T>`.
+    pub fn rows(&self) -> &[Vector2<T>; 2] {
+        &self.mat
+    }
+
+    /// Returns mutable access to the rows of the matrix as an array of `Vector2<T>`.
+    pub fn rows_mut(&mut self) -> &mut [Vector2<T>; 2] {
+        &mut self.mat
+    }
+
+    /// Creates a `Matrix2x2` from a 2D array.
+    pub const fn from_mat(mat: [[T; 2]; 2]) -> Self {
+        Self {
+            mat: [Vector2::from_array(mat[0]), Vector2::from_array(mat[1])],
+        }
+    }
+
+    /// Converts the `Matrix2x2` to a 2D array.
+    pub const fn to_mat(&self) -> [[T; 2]; 2] {
+        [self.mat[0].to_array(), self.mat[1].to_array()]
+    }
+
+    /// Creates a `Matrix2x2` from a flat array of 4 elements.
+    /// The elements are arranged in row-major order.
+    pub const fn from_array(arr: [T; 4]) -> Self {
+        Self {
+            mat: [
+                Vector2::from_array([arr[0], arr[1]]),
+                Vector2::from_array([arr[2], arr[3]]),
+            ],
+        }
+    }
+
+    /// Converts the `Matrix2x2` to a flat array of 4 elements.
+    /// The elements are arranged in row-major order.
+    pub const fn to_array(&self) -> [T; 4] {
+        [self.mat[0].x, self.mat[0].y, self.mat[1].x, self.mat[1].y]
+    }
+
+    /// Flattens the matrix into a single array of 4 elements in row-major order.
+    /// Alias for `to_array()`.
+    pub const fn flatten(&self) -> [T; 4] {
+        self.to_array()
+    }
+
+    /// Creates a `Matrix2x2` from the first 4 elements of `slice`, in row-major order.
+    pub const fn from_slice(slice: &[T]) -> Self {
+        debug_assert!(slice.len() >= 4, "Matrix2x2 needs at least 4 elements");
+        Self {
+            mat: [
+                Vector2::new(slice[0], slice[1]),
+                Vector2::new(slice[2], slice[3]),
+            ],
+        }
+    }
+
+    /// Returns the matrix as a slice of `T` elements.
+    /// This allows you to access the matrix elements in a flat manner.
+    pub fn as_slice(&self) -> &[T; 4] {
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Returns mutable access to the matrix as a slice of `T` elements.
+    /// This allows you to modify the matrix elements in a flat manner.
+    pub fn as_mut_slice(&mut self) -> &mut [T; 4] {
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Returns a pointer to the first element of the matrix.
+    /// This is useful for low-level operations or when interfacing with C code.
+    ///
+    /// # Safety
+    /// The pointer is only valid for the 4 elements of the matrix, while it is borrowed.
+    pub unsafe fn as_ptr(&self) -> *const T {
+        self[0].as_ptr()
+    }
+
+    /// Returns a mutable pointer to the first element of the matrix.
+    /// This is useful for low-level operations or when interfacing with C code.
+    ///
+    /// # Safety
+    /// The pointer is only valid for the 4 elements of the matrix, while it is borrowed.
+    pub unsafe fn as_mut_ptr(&mut self) -> *mut T {
+        self[0].as_mut_ptr()
+    }
+}
+
+// The transform constructors only make sense for floating point numbers.
+// They are generated for each precision from a single definition, so they can't drift apart.
+macro_rules! impl_matrix2x2_transforms {
+    ($($t:ty)*) => ($(
+        impl Matrix2x2<$t> {
+            /// Creates a rotation matrix that rotates points counter-clockwise
+            /// around the origin by the specified angle in radians.
+            /// Prefer `make_rotation_angle`, which takes an `Angle`.
+            pub fn make_rotation(rad: $t) -> Self {
+                let cos = rad.cos();
+                let sin = rad.sin();
+                Self {
+                    mat: [Vector2::new(cos, -sin), Vector2::new(sin, cos)],
+                }
+            }
+
+            /// Same as `make_rotation`, taking an `Angle` instead of radians.
+            pub fn make_rotation_angle(angle: Angle<$t>) -> Self {
+                Self::make_rotation(angle.to_radians())
+            }
+
+            /// Creates a scaling matrix that scales points by `sx` and `sy`.
+            pub fn make_scaling(sx: $t, sy: $t) -> Self {
+                Self {
+                    mat: [Vector2::new(sx, 0.0), Vector2::new(0.0, sy)],
+                }
+            }
+
+            /// Estimates the condition number of the matrix, the product of the Frobenius norms
+            /// of the matrix and of its inverse, for diagnostics. It is at least the dimension of
+            /// the matrix, reached by rotations, and grows as the matrix gets closer to singular,
+            /// bounding how much the relative errors of inputs are amplified in the results.
+            /// Infinite for singular matrices.
+            pub fn condition_estimate(&self) -> f64 {
+                match self.inverse() {
+                    Some(inverse) => {
+                        let norm_squared =
+                            |m: &Self| m.mat.iter().map(|row| row.norm_squared()).sum::<$t>() as f64;
+                        (norm_squared(self) * norm_squared(&inverse)).sqrt()
+                    },
+                    None => f64::INFINITY,
+                }
+            }
+        }
+    )*)
+}
+
+impl_matrix2x2_transforms! { f32 f64 }
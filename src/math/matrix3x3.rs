@@ -19,7 +19,7 @@
 
 use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub};
 
-use crate::math::{SignedNumber, Vector3};
+use crate::math::{Angle, SignedNumber, Vector2, Vector3};
 
 /// A 3x3 matrix represented as an array of three `Vector3<T>` **rows**.
 /// It supports addition, subtraction, multiplication by a scalar,
@@ -193,6 +193,23 @@ impl<T: SignedNumber> Mul<Matrix3x3<T>> for Vector3<T> {
 }
 forward_ref_binop!(impl<T> Mul, mul for Vector3<T>, Matrix3x3<T> where T: SignedNumber);
 
+/// Right-hand side multiplication of a 2D point, as the homogeneous column vector `(x, y, 1)`,
+/// so the translation applies. Like `transform_point_2d`, the last row is assumed to be
+/// `(0, 0, 1)` and no perspective divide is performed.
+/// Use `transform_vector_2d` for directions, which the translation must not move.
+impl<T: SignedNumber> Mul<Vector2<T>> for Matrix3x3<T> {
+    type Output = Vector2<T>;
+
+    #[inline]
+    fn mul(self, rhs: Vector2<T>) -> Self::Output {
+        Vector2 {
+            x: self[0][0] * rhs.x + self[0][1] * rhs.y + self[0][2],
+            y: self[1][0] * rhs.x + self[1][1] * rhs.y + self[1][2],
+        }
+    }
+}
+forward_ref_binop!(impl<T> Mul, mul for Matrix3x3<T>, Vector2<T> where T: SignedNumber);
+
 // Matrix multiplication
 impl<T: SignedNumber> Mul<Matrix3x3<T>> for Matrix3x3<T> {
     type Output = Self;
@@ -312,35 +329,41 @@ impl<T: SignedNumber> IndexMut<(usize, usize)> for Matrix3x3<T> {
 }
 
 impl<T: SignedNumber> Matrix3x3<T> {
+    /// A matrix with all elements set to zero.
+    pub const ZERO: Self = Self {
+        mat: [Vector3::ZERO, Vector3::ZERO, Vector3::ZERO],
+    };
+
+    /// The identity matrix, with ones on the diagonal and zeros elsewhere.
+    pub const IDENTITY: Self = Self {
+        mat: [
+            Vector3::new(T::ONE, T::ZERO, T::ZERO),
+            Vector3::new(T::ZERO, T::ONE, T::ZERO),
+            Vector3::new(T::ZERO, T::ZERO, T::ONE),
+        ],
+    };
+
     /// Creates a new `Matrix3x3` with the given rows.
     pub const fn new(rows: [Vector3<T>; 3]) -> Self {
         Self { mat: rows }
     }
 
     /// Creates a new `Matrix3x3` with all elements initialized to zero.
-    pub fn zero() -> Self {
-        Self {
-            mat: [Vector3::zero(), Vector3::zero(), Vector3::zero()],
-        }
+    pub const fn zero() -> Self {
+        Self::ZERO
     }
 
     /// Creates a new `Matrix3x3` with all elements initialized to one.
-    pub fn one() -> Self {
+    pub const fn one() -> Self {
         Self {
-            mat: [Vector3::one(), Vector3::one(), Vector3::one()],
+            mat: [Vector3::ONE, Vector3::ONE, Vector3::ONE],
         }
     }
 
     /// Creates a new `Matrix3x3` that represents the identity matrix.
     /// The identity matrix has ones on the diagonal and zeros elsewhere.
-    pub fn identity() -> Self {
-        Self {
-            mat: [
-                Vector3::new(T::one(), T::zero(), T::zero()),
-                Vector3::new(T::zero(), T::one(), T::zero()),
-                Vector3::new(T::zero(), T::zero(), T::one()),
-            ],
-        }
+    pub const fn identity() -> Self {
+        Self::IDENTITY
     }
 
     /// Returns the transpose of the matrix.
@@ -348,11 +371,7 @@ impl<T: SignedNumber> Matrix3x3<T> {
     /// For each element `mat[i][j]`, the transpose will have `mat[j][i]`.
     pub fn transpose(&self) -> Self {
         Self {
-            mat: [
-                Vector3::new(self[0][0], self[1][0], self[2][0]),
-                Vector3::new(self[0][1], self[1][1], self[2][1]),
-                Vector3::new(self[0][2], self[1][2], self[2][2]),
-            ],
+            mat: self.columns(),
         }
     }
 
@@ -363,12 +382,28 @@ impl<T: SignedNumber> Matrix3x3<T> {
             + self[0][2] * (self[1][0] * self[2][1] - self[1][1] * self[2][0])
     }
 
+    /// Returns the trace of the matrix, the sum of its diagonal elements.
+    pub fn trace(&self) -> T {
+        self[0][0] + self[1][1] + self[2][2]
+    }
+
+    /// Returns whether the matrix equals its transpose, each element within `epsilon`.
+    pub fn is_symmetric(&self, epsilon: T) -> bool {
+        (0..3).all(|row| {
+            (row + 1..3).all(|column| (self[row][column] - self[column][row]).abs() <= epsilon)
+        })
+    }
+
+    /// Returns whether every element is finite, neither infinite nor NaN.
+    /// Always true for integer matrices.
+    pub fn is_finite(&self) -> bool {
+        self.mat.iter().all(|row| row.is_finite())
+    }
+
     /// Returns the inverse of the matrix if it exists.
     /// The inverse is calculated using the adjugate method.
     pub fn inverse(&self) -> Option<Self> {
-        let col0 = Vector3::new(self[0][0], self[1][0], self[2][0]);
-        let col1 = Vector3::new(self[0][1], self[1][1], self[2][1]);
-        let col2 = Vector3::new(self[0][2], self[1][2], self[2][2]);
+        let [col0, col1, col2] = self.columns();
         let cofactor_row0 = col1.cross(&col2);
         let cofactor_row1 = col2.cross(&col0);
         let cofactor_row2 = col0.cross(&col1);
@@ -389,6 +424,49 @@ impl<T: SignedNumber> Matrix3x3<T> {
         })
     }
 
+    /// Returns the inverse of the matrix like `inverse`, or `None` if it is nearly singular: if
+    /// the magnitude of its determinant is below `epsilon` times the largest determinant a matrix
+    /// of the same Frobenius norm can have, that of a scaled rotation. Unlike comparing the
+    /// determinant itself, this doesn't depend on the scale of the matrix.
+    /// See `condition_estimate` to measure how close to singular the matrix is.
+    pub fn inverse_checked(&self, epsilon: T) -> Option<Self> {
+        let norm_squared: f64 = self
+            .mat
+            .iter()
+            .map(|row| row.norm_squared().as_double())
+            .sum();
+        let largest_determinant = (norm_squared / 3.0).powf(1.5);
+        if largest_determinant == 0.0
+            || self.determinant().as_double().abs() < epsilon.as_double() * largest_determinant
+        {
+            return None;
+        }
+        self.inverse()
+    }
+
+    /// Right-multiplies each vector of `vectors` by the matrix, writing the results to `out`.
+    /// Does not allocate, so `out` can be reused across frames.
+    ///
+    /// # Panics
+    /// Panics if `vectors` and `out` have different lengths.
+    pub fn transform_into(&self, vectors: &[Vector3<T>], out: &mut [Vector3<T>]) {
+        assert_eq!(
+            vectors.len(),
+            out.len(),
+            "`out` must have the length of `vectors`"
+        );
+        for (vector, result) in vectors.iter().zip(out.iter_mut()) {
+            *result = *self * *vector;
+        }
+    }
+
+    /// Right-multiplies each vector of `vectors` by the matrix, replacing it with the result.
+    pub fn transform_in_place(&self, vectors: &mut [Vector3<T>]) {
+        for vector in vectors.iter_mut() {
+            *vector = *self * *vector;
+        }
+    }
+
     /// Returns the rows of the matrix as an array of `Vector3<T>`.
     pub fn rows(&self) -> &[Vector3<T>; 3] {
         &self.mat
@@ -399,6 +477,30 @@ impl<T: SignedNumber> Matrix3x3<T> {
         &mut self.mat
     }
 
+    /// Returns the column at `index` of the matrix.
+    pub fn column(&self, index: usize) -> Vector3<T> {
+        debug_assert!(index < 3);
+        Vector3::new(self[0][index], self[1][index], self[2][index])
+    }
+
+    /// Replaces the column at `index` of the matrix, keeping the others.
+    pub fn set_column(&mut self, index: usize, column: Vector3<T>) {
+        debug_assert!(index < 3);
+        self[0][index] = column.x;
+        self[1][index] = column.y;
+        self[2][index] = column.z;
+    }
+
+    /// Returns the columns of the matrix, which are the rows of its transpose.
+    pub fn columns(&self) -> [Vector3<T>; 3] {
+        [self.column(0), self.column(1), self.column(2)]
+    }
+
+    /// Creates a `Matrix3x3` from its columns.
+    pub fn from_columns(columns: [Vector3<T>; 3]) -> Self {
+        Self { mat: columns }.transpose()
+    }
+
     /// Creates a `Matrix3x3` from a 2D array.
     pub const fn from_mat(mat: [[T; 3]; 3]) -> Self {
         Self {
@@ -478,313 +580,434 @@ impl<T: SignedNumber> Matrix3x3<T> {
 
     /// Returns a pointer to the first element of the matrix.
     /// This is useful for low-level operations or when interfacing with C code.
+    ///
+    /// # Safety
+    /// Only the 9 elements, row after row, may be accessed through the pointer, while the matrix
+    /// is borrowed.
     pub unsafe fn as_ptr(&self) -> *const T {
         self[0].as_ptr()
     }
 
     /// Returns a mutable pointer to the first element of the matrix.
     /// This is useful for low-level operations or when interfacing with C code.
+    ///
+    /// # Safety
+    /// Only the 9 elements, row after row, may be accessed through the pointer, while the matrix
+    /// is borrowed.
     pub unsafe fn as_mut_ptr(&mut self) -> *mut T {
         self[0].as_mut_ptr()
     }
 }
 
-impl Matrix3x3<f32> {
-    /// Creates a transform matrix to rotate around the X-axis.
-    /// This matrix rotates points in the YZ plane by the specified angle in radians when applied to a vector.
-    /// Assuming a right-handed coordinate system.
-    pub fn make_rotation_x(rad: f32) -> Self {
-        let cos = rad.cos();
-        let sin = rad.sin();
-        Self {
-            mat: [
-                Vector3::new(1.0, 0.0, 0.0),
-                Vector3::new(0.0, cos, -sin),
-                Vector3::new(0.0, sin, cos),
-            ],
-        }
-    }
-
-    /// Creates a transform matrix to rotate around the Y-axis.
-    /// This matrix rotates points in the XZ plane by the specified angle in radians when applied to a vector.
-    /// Assuming a right-handed coordinate system.
-    pub fn make_rotation_y(rad: f32) -> Self {
-        let cos = rad.cos();
-        let sin = rad.sin();
-        Self {
-            mat: [
-                Vector3::new(cos, 0.0, sin),
-                Vector3::new(0.0, 1.0, 0.0),
-                Vector3::new(-sin, 0.0, cos),
-            ],
-        }
-    }
-
-    /// Creates a transform matrix to rotate around the Z-axis.
-    /// This matrix rotates points in the XY plane by the specified angle in radians when applied to a vector.
-    /// Assuming a right-handed coordinate system.
-    pub fn make_rotation_z(rad: f32) -> Self {
-        let cos = rad.cos();
-        let sin = rad.sin();
-        Self {
-            mat: [
-                Vector3::new(cos, -sin, 0.0),
-                Vector3::new(sin, cos, 0.0),
-                Vector3::new(0.0, 0.0, 1.0),
-            ],
-        }
-    }
-
-    /// Creates a rotation matrix around an arbitrary axis.
-    /// Assuming a right-handed coordinate system.
-    pub fn make_rotation(rad: f32, axis: &Vector3<f32>) -> Self {
-        let cos = rad.cos();
-        let sin = rad.sin();
-        let one_minus_cos = 1.0 - cos;
-
-        let x = axis.x;
-        let y = axis.y;
-        let z = axis.z;
-
-        Self {
-            mat: [
-                Vector3::new(
-                    cos + x * x * one_minus_cos,
-                    y * x * one_minus_cos + z * sin,
-                    z * x * one_minus_cos - y * sin,
-                ),
-                Vector3::new(
-                    x * y * one_minus_cos - z * sin,
-                    cos + y * y * one_minus_cos,
-                    z * y * one_minus_cos + x * sin,
-                ),
-                Vector3::new(
-                    x * z * one_minus_cos + y * sin,
-                    y * z * one_minus_cos - x * sin,
-                    cos + z * z * one_minus_cos,
-                ),
-            ],
-        }
-    }
-
-    /// Creates a scaling matrix that scales points by the specified factors along each axis.
-    pub fn make_scaling(sx: f32, sy: f32, sz: f32) -> Self {
-        Self {
-            mat: [
-                Vector3::new(sx, 0.0, 0.0),
-                Vector3::new(0.0, sy, 0.0),
-                Vector3::new(0.0, 0.0, sz),
-            ],
-        }
-    }
-
-    /// Creates a scaling matrix that scales points along the specified axis by the given factor.
-    /// Assumes the axis is normalized.
-    pub fn make_scaling_axis(axis: &Vector3<f32>, factor: f32) -> Self {
-        debug_assert!(axis.is_normalized(), "Axis must be normalized");
-        let x = axis.x * factor;
-        let y = axis.y * factor;
-        let z = axis.z * factor;
-        Self {
-            mat: [
-                Vector3::new(x, 0.0, 0.0),
-                Vector3::new(0.0, y, 0.0),
-                Vector3::new(0.0, 0.0, z),
-            ],
-        }
-    }
-
-    /// Creates a reflection matrix that reflects points through the specified plane.
-    /// The plane is defined by its normal vector.
-    /// Assumes the normal vector is normalized.
-    pub fn make_reflection(normal: &Vector3<f32>) -> Self {
-        debug_assert!(normal.is_normalized(), "Normal vector must be normalized");
-        let x = normal.x * -2.0;
-        let y = normal.y * -2.0;
-        let z = normal.z * -2.0;
-        Self {
-            mat: [
-                Vector3::new(1.0 + x * normal.x, x * normal.y, x * normal.z),
-                Vector3::new(y * normal.x, 1.0 + y * normal.y, y * normal.z),
-                Vector3::new(z * normal.x, z * normal.y, 1.0 + z * normal.z),
-            ],
-        }
-    }
-
-    /// Creates a skew transformation matrix that skews points by `rad` along
-    /// the `direction` in regards to the `pivot` axis, which is used to
-    /// measure the distance to determine how far to skew.
-    /// It assumes the `direction` vector is normalized and
-    /// the `pivot` is non-zero and perpendicular to the `direction` vector.
-    pub fn make_skew(rad: f32, direction: &Vector3<f32>, pivot: &Vector3<f32>) -> Self {
-        debug_assert!(direction.is_normalized(), "Direction must be normalized");
-        debug_assert!(pivot.magnitude() > 0.0, "`pivot` must not be origin");
-        debug_assert!(
-            pivot.dot(&direction) == 0.0,
-            "`pivot` must be perpendicular to `direction`"
-        );
-
-        let tan = rad.tan();
-        let x = direction.x * tan;
-        let y = direction.y * tan;
-        let z = direction.z * tan;
-
-        Self {
-            mat: [
-                Vector3::new(x * pivot.x + 1.0, x * pivot.y, x * pivot.z),
-                Vector3::new(y * pivot.x, y * pivot.y + 1.0, y * pivot.z),
-                Vector3::new(z * pivot.x, z * pivot.y, z * pivot.z + 1.0),
-            ],
-        }
-    }
+// The transform constructors only make sense for floating point numbers.
+// They are generated for each precision from a single definition, so they can't drift apart.
+macro_rules! impl_matrix3x3_transforms {
+    ($($t:ty)*) => ($(
+        impl Matrix3x3<$t> {
+            /// Creates a transform matrix to rotate around the X-axis.
+            /// This matrix rotates points in the YZ plane by the specified angle in radians when applied to a vector.
+            /// Assuming a right-handed coordinate system.
+            /// Prefer `make_rotation_x_angle`, which takes an `Angle`.
+            pub fn make_rotation_x(rad: $t) -> Self {
+                let cos = rad.cos();
+                let sin = rad.sin();
+                Self {
+                    mat: [
+                        Vector3::new(1.0, 0.0, 0.0),
+                        Vector3::new(0.0, cos, -sin),
+                        Vector3::new(0.0, sin, cos),
+                    ],
+                }
+            }
+
+            /// Same as `make_rotation_x`, taking an `Angle` instead of radians.
+            pub fn make_rotation_x_angle(angle: Angle<$t>) -> Self {
+                Self::make_rotation_x(angle.to_radians())
+            }
+
+            /// Creates a transform matrix to rotate around the Y-axis.
+            /// This matrix rotates points in the XZ plane by the specified angle in radians when applied to a vector.
+            /// Assuming a right-handed coordinate system.
+            /// Prefer `make_rotation_y_angle`, which takes an `Angle`.
+            pub fn make_rotation_y(rad: $t) -> Self {
+                let cos = rad.cos();
+                let sin = rad.sin();
+                Self {
+                    mat: [
+                        Vector3::new(cos, 0.0, sin),
+                        Vector3::new(0.0, 1.0, 0.0),
+                        Vector3::new(-sin, 0.0, cos),
+                    ],
+                }
+            }
+
+            /// Same as `make_rotation_y`, taking an `Angle` instead of radians.
+            pub fn make_rotation_y_angle(angle: Angle<$t>) -> Self {
+                Self::make_rotation_y(angle.to_radians())
+            }
+
+            /// Creates a transform matrix to rotate around the Z-axis.
+            /// This matrix rotates points in the XY plane by the specified angle in radians when applied to a vector.
+            /// Assuming a right-handed coordinate system.
+            /// Prefer `make_rotation_z_angle`, which takes an `Angle`.
+            pub fn make_rotation_z(rad: $t) -> Self {
+                let cos = rad.cos();
+                let sin = rad.sin();
+                Self {
+                    mat: [
+                        Vector3::new(cos, -sin, 0.0),
+                        Vector3::new(sin, cos, 0.0),
+                        Vector3::new(0.0, 0.0, 1.0),
+                    ],
+                }
+            }
+
+            /// Same as `make_rotation_z`, taking an `Angle` instead of radians.
+            pub fn make_rotation_z_angle(angle: Angle<$t>) -> Self {
+                Self::make_rotation_z(angle.to_radians())
+            }
+
+            /// Creates a rotation matrix around an arbitrary axis, normalizing the axis first.
+            /// The axis must not be zero. Use `make_rotation_normalized` to skip the
+            /// normalization for an axis that is already normalized.
+            /// Assuming a right-handed coordinate system.
+            /// Prefer `make_rotation_angle`, which takes an `Angle`.
+            pub fn make_rotation(rad: $t, axis: &Vector3<$t>) -> Self {
+                debug_assert!(axis.norm_squared() > 0.0, "`axis` must not be zero");
+                Self::make_rotation_normalized(rad, &axis.normalize())
+            }
+
+            /// Same as `make_rotation`, taking an `Angle` instead of radians.
+            pub fn make_rotation_angle(angle: Angle<$t>, axis: &Vector3<$t>) -> Self {
+                Self::make_rotation(angle.to_radians(), axis)
+            }
+
+            /// Creates a rotation matrix around an arbitrary axis, which must be normalized.
+            /// Assuming a right-handed coordinate system.
+            /// Prefer `make_rotation_normalized_angle`, which takes an `Angle`.
+            pub fn make_rotation_normalized(rad: $t, axis: &Vector3<$t>) -> Self {
+                debug_assert!(axis.is_normalized(), "`axis` must be normalized");
+                let cos = rad.cos();
+                let sin = rad.sin();
+                let one_minus_cos = 1.0 - cos;
+
+                let x = axis.x;
+                let y = axis.y;
+                let z = axis.z;
+
+                Self {
+                    mat: [
+                        Vector3::new(
+                            cos + x * x * one_minus_cos,
+                            x * y * one_minus_cos - z * sin,
+                            x * z * one_minus_cos + y * sin,
+                        ),
+                        Vector3::new(
+                            y * x * one_minus_cos + z * sin,
+                            cos + y * y * one_minus_cos,
+                            y * z * one_minus_cos - x * sin,
+                        ),
+                        Vector3::new(
+                            z * x * one_minus_cos - y * sin,
+                            z * y * one_minus_cos + x * sin,
+                            cos + z * z * one_minus_cos,
+                        ),
+                    ],
+                }
+            }
+
+            /// Same as `make_rotation_normalized`, taking an `Angle` instead of radians.
+            pub fn make_rotation_normalized_angle(angle: Angle<$t>, axis: &Vector3<$t>) -> Self {
+                Self::make_rotation_normalized(angle.to_radians(), axis)
+            }
+
+            /// Creates a scaling matrix that scales points by the specified factors along each axis.
+            pub fn make_scaling(sx: $t, sy: $t, sz: $t) -> Self {
+                Self {
+                    mat: [
+                        Vector3::new(sx, 0.0, 0.0),
+                        Vector3::new(0.0, sy, 0.0),
+                        Vector3::new(0.0, 0.0, sz),
+                    ],
+                }
+            }
+
+            /// Same as `make_scaling`, taking the factors as a vector.
+            pub fn make_scaling_v(scale: &Vector3<$t>) -> Self {
+                Self::make_scaling(scale.x, scale.y, scale.z)
+            }
+
+            /// Creates a scaling matrix that scales points along the specified axis by the given factor.
+            /// Components perpendicular to the axis are left unchanged.
+            /// Assumes the axis is normalized.
+            pub fn make_scaling_axis(axis: &Vector3<$t>, factor: $t) -> Self {
+                debug_assert!(axis.is_normalized(), "`axis` must be normalized");
+                // I + (factor - 1) * axis * axis^T
+                let k = factor - 1.0;
+                let x = axis.x * k;
+                let y = axis.y * k;
+                let z = axis.z * k;
+                Self {
+                    mat: [
+                        Vector3::new(1.0 + x * axis.x, x * axis.y, x * axis.z),
+                        Vector3::new(y * axis.x, 1.0 + y * axis.y, y * axis.z),
+                        Vector3::new(z * axis.x, z * axis.y, 1.0 + z * axis.z),
+                    ],
+                }
+            }
+
+            /// Creates a reflection matrix that reflects points through the specified plane.
+            /// The plane is defined by its normal vector.
+            /// Assumes the normal vector is normalized.
+            pub fn make_reflection(normal: &Vector3<$t>) -> Self {
+                debug_assert!(normal.is_normalized(), "`normal` must be normalized");
+                let x = normal.x * -2.0;
+                let y = normal.y * -2.0;
+                let z = normal.z * -2.0;
+                Self {
+                    mat: [
+                        Vector3::new(1.0 + x * normal.x, x * normal.y, x * normal.z),
+                        Vector3::new(y * normal.x, 1.0 + y * normal.y, y * normal.z),
+                        Vector3::new(z * normal.x, z * normal.y, 1.0 + z * normal.z),
+                    ],
+                }
+            }
+
+            /// Creates a skew transformation matrix that skews points by `rad` along
+            /// the `direction` in regards to the `pivot` axis, which is used to
+            /// measure the distance to determine how far to skew.
+            /// It assumes the `direction` vector is normalized and the `pivot` is non-zero
+            /// and perpendicular to the `direction` vector, up to a tolerance relative to
+            /// the length of `pivot`. The length of `pivot` scales the skew.
+            /// Use `try_make_skew` when the vectors aren't known to meet these conditions.
+            pub fn make_skew(rad: $t, direction: &Vector3<$t>, pivot: &Vector3<$t>) -> Self {
+                debug_assert!(direction.is_normalized(), "`direction` must be normalized");
+                debug_assert!(pivot.magnitude() > 0.0, "`pivot` must not be origin");
+                debug_assert!(
+                    Self::is_valid_skew(direction, pivot),
+                    "`pivot` must be perpendicular to `direction`"
+                );
+
+                let tan = rad.tan();
+                let x = direction.x * tan;
+                let y = direction.y * tan;
+                let z = direction.z * tan;
+
+                Self {
+                    mat: [
+                        Vector3::new(x * pivot.x + 1.0, x * pivot.y, x * pivot.z),
+                        Vector3::new(y * pivot.x, y * pivot.y + 1.0, y * pivot.z),
+                        Vector3::new(z * pivot.x, z * pivot.y, z * pivot.z + 1.0),
+                    ],
+                }
+            }
+
+            /// Same as `make_skew`, returning `None` instead of asserting if `direction`
+            /// and `pivot` don't meet its conditions.
+            pub fn try_make_skew(
+                rad: $t,
+                direction: &Vector3<$t>,
+                pivot: &Vector3<$t>,
+            ) -> Option<Self> {
+                if Self::is_valid_skew(direction, pivot) {
+                    Some(Self::make_skew(rad, direction, pivot))
+                } else {
+                    None
+                }
+            }
+
+            /// Checks the conditions `make_skew` expects from `direction` and `pivot`.
+            /// They are perpendicular if their dot product is within `sqrt(EPSILON)` times
+            /// the length of `pivot`, which accepts directions computed by rotations.
+            pub(crate) fn is_valid_skew(direction: &Vector3<$t>, pivot: &Vector3<$t>) -> bool {
+                let pivot_length = pivot.norm_squared().sqrt();
+                direction.is_normalized()
+                    && pivot_length > 0.0
+                    && pivot.dot(direction).abs() <= <$t>::EPSILON.sqrt() * pivot_length
+            }
+
+            /// Creates a 2D homogeneous translation matrix that translates points by `tx` and `ty`.
+            /// Points are treated as column vectors `(x, y, 1)`, so the translation is stored in the last column.
+            pub fn make_translation_2d(tx: $t, ty: $t) -> Self {
+                Self {
+                    mat: [
+                        Vector3::new(1.0, 0.0, tx),
+                        Vector3::new(0.0, 1.0, ty),
+                        Vector3::new(0.0, 0.0, 1.0),
+                    ],
+                }
+            }
+
+            /// Creates a 2D homogeneous rotation matrix that rotates points counter-clockwise
+            /// around the origin by the specified angle in radians.
+            /// Prefer `make_rotation_2d_angle`, which takes an `Angle`.
+            pub fn make_rotation_2d(rad: $t) -> Self {
+                let cos = rad.cos();
+                let sin = rad.sin();
+                Self {
+                    mat: [
+                        Vector3::new(cos, -sin, 0.0),
+                        Vector3::new(sin, cos, 0.0),
+                        Vector3::new(0.0, 0.0, 1.0),
+                    ],
+                }
+            }
+
+            /// Same as `make_rotation_2d`, taking an `Angle` instead of radians.
+            pub fn make_rotation_2d_angle(angle: Angle<$t>) -> Self {
+                Self::make_rotation_2d(angle.to_radians())
+            }
+
+            /// Creates a 2D homogeneous scaling matrix that scales points by `sx` and `sy`.
+            pub fn make_scaling_2d(sx: $t, sy: $t) -> Self {
+                Self {
+                    mat: [
+                        Vector3::new(sx, 0.0, 0.0),
+                        Vector3::new(0.0, sy, 0.0),
+                        Vector3::new(0.0, 0.0, 1.0),
+                    ],
+                }
+            }
+
+            /// Creates a 2D homogeneous skew matrix.
+            /// `rad_x` skews points along the X-axis proportionally to their Y coordinate,
+            /// and `rad_y` skews points along the Y-axis proportionally to their X coordinate.
+            pub fn make_skew_2d(rad_x: $t, rad_y: $t) -> Self {
+                Self {
+                    mat: [
+                        Vector3::new(1.0, rad_x.tan(), 0.0),
+                        Vector3::new(rad_y.tan(), 1.0, 0.0),
+                        Vector3::new(0.0, 0.0, 1.0),
+                    ],
+                }
+            }
+
+            /// Creates the matrix mapping the X, Y and Z axes to `x`, `y` and `z`,
+            /// which become its columns. It is a rotation if the basis is orthonormal and right-handed.
+            pub fn from_basis(x: &Vector3<$t>, y: &Vector3<$t>, z: &Vector3<$t>) -> Self {
+                Self::from_columns([*x, *y, *z])
+            }
+
+            /// Creates the rotation mapping the Z axis to `forward` and the Y axis as close
+            /// as possible to `up`. Neither needs to be normalized, but `forward` must be non-zero.
+            /// If `up` is parallel to `forward`, an arbitrary perpendicular up direction is used.
+            /// Assuming a right-handed coordinate system.
+            pub fn look_rotation(forward: &Vector3<$t>, up: &Vector3<$t>) -> Self {
+                debug_assert!(forward.magnitude() > 0.0, "`forward` must not be zero");
+                let z = forward.normalize();
+                let right = up.cross(&z);
+                let x = if right.norm_squared() > <$t>::EPSILON * up.norm_squared() {
+                    right.normalize()
+                } else {
+                    z.any_orthonormal_pair().0
+                };
+                let y = z.cross(&x);
+                Self::from_basis(&x, &y, &z)
+            }
+
+            /// Applies the matrix as a 2D affine transform to a point, including the translation.
+            /// The last row is assumed to be `(0, 0, 1)`, so no perspective divide is performed.
+            pub fn transform_point_2d(&self, p: Vector2<$t>) -> Vector2<$t> {
+                Vector2::new(
+                    self[0][0] * p.x + self[0][1] * p.y + self[0][2],
+                    self[1][0] * p.x + self[1][1] * p.y + self[1][2],
+                )
+            }
+
+            /// Applies the matrix as a 2D affine transform to a direction vector, ignoring the translation.
+            pub fn transform_vector_2d(&self, v: Vector2<$t>) -> Vector2<$t> {
+                Vector2::new(
+                    self[0][0] * v.x + self[0][1] * v.y,
+                    self[1][0] * v.x + self[1][1] * v.y,
+                )
+            }
+
+            /// Applies `transform_point_2d` to each point of `points`, writing the results to `out`.
+            /// Does not allocate, so `out` can be reused across frames.
+            ///
+            /// # Panics
+            /// Panics if `points` and `out` have different lengths.
+            pub fn transform_points_2d_into(
+                &self,
+                points: &[Vector2<$t>],
+                out: &mut [Vector2<$t>],
+            ) {
+                assert_eq!(points.len(), out.len(), "`out` must have the length of `points`");
+                for (point, result) in points.iter().zip(out.iter_mut()) {
+                    *result = self.transform_point_2d(*point);
+                }
+            }
+
+            /// Applies `transform_vector_2d` to each vector of `vectors`, writing the results to `out`.
+            /// Does not allocate, so `out` can be reused across frames.
+            ///
+            /// # Panics
+            /// Panics if `vectors` and `out` have different lengths.
+            pub fn transform_vectors_2d_into(
+                &self,
+                vectors: &[Vector2<$t>],
+                out: &mut [Vector2<$t>],
+            ) {
+                assert_eq!(vectors.len(), out.len(), "`out` must have the length of `vectors`");
+                for (vector, result) in vectors.iter().zip(out.iter_mut()) {
+                    *result = self.transform_vector_2d(*vector);
+                }
+            }
+
+            /// Returns the Frobenius norm of the matrix, the square root of the sum of the
+            /// squares of its elements.
+            pub fn frobenius_norm(&self) -> $t {
+                self.mat.iter().map(|row| row.norm_squared()).sum::<$t>().sqrt()
+            }
+
+            /// Estimates the condition number of the matrix, the product of the Frobenius norms
+            /// of the matrix and of its inverse, for diagnostics. It is at least the dimension of
+            /// the matrix, reached by rotations, and grows as the matrix gets closer to singular,
+            /// bounding how much the relative errors of inputs are amplified in the results.
+            /// Infinite for singular matrices.
+            pub fn condition_estimate(&self) -> f64 {
+                match self.inverse() {
+                    Some(inverse) => self.frobenius_norm() as f64 * inverse.frobenius_norm() as f64,
+                    None => f64::INFINITY,
+                }
+            }
+
+            /// Returns whether the rows of the matrix are orthonormal: every element of the
+            /// product of the matrix with its transpose is within `epsilon` of the identity.
+            /// Rotations and reflections are orthogonal.
+            pub fn is_orthogonal(&self, epsilon: $t) -> bool {
+                let product = *self * self.transpose();
+                (0..3).all(|row| {
+                    (0..3).all(|column| {
+                        (product[row][column] - Self::IDENTITY[row][column]).abs() <= epsilon
+                    })
+                })
+            }
+
+            /// Returns the orthogonal matrix nearest to this one by Gram-Schmidt on its columns,
+            /// the images of the axes: the first keeps its direction and the second stays in the
+            /// plane of the first two. Fixes up the drift of a rotation accumulated over many
+            /// incremental multiplications, keeping it a rotation.
+            /// Returns `None` if the columns are linearly dependent, the matrix being singular.
+            pub fn orthonormalize(&self) -> Option<Self> {
+                // Removes from `column` its components along the orthonormal `basis`, then
+                // normalizes it unless little more than rounding error is left.
+                let orthonormal = |column: Vector3<$t>, basis: &[Vector3<$t>]| {
+                    let rest = basis
+                        .iter()
+                        .fold(column, |rest, axis| rest - *axis * rest.dot(axis));
+                    let length_squared = rest.norm_squared();
+                    (length_squared > <$t>::EPSILON * column.norm_squared())
+                        .then(|| rest / length_squared.sqrt())
+                };
+                let [x, y, z] = self.transpose().mat;
+                let x = orthonormal(x, &[])?;
+                let y = orthonormal(y, &[x])?;
+                let z = orthonormal(z, &[x, y])?;
+                Some(Self::from_basis(&x, &y, &z))
+            }
+        }
+    )*)
 }
 
-impl Matrix3x3<f64> {
-    /// Creates a transform matrix to rotate around the X-axis.
-    /// This matrix rotates points in the YZ plane by the specified angle in radians when applied to a vector.
-    /// Assuming a right-handed coordinate system.
-    pub fn make_rotation_x(rad: f64) -> Self {
-        let cos = rad.cos();
-        let sin = rad.sin();
-        Self {
-            mat: [
-                Vector3::new(1.0, 0.0, 0.0),
-                Vector3::new(0.0, cos, -sin),
-                Vector3::new(0.0, sin, cos),
-            ],
-        }
-    }
-
-    /// Creates a transform matrix to rotate around the Y-axis.
-    /// This matrix rotates points in the XZ plane by the specified angle in radians when applied to a vector.
-    /// Assuming a right-handed coordinate system.
-    pub fn make_rotation_y(rad: f64) -> Self {
-        let cos = rad.cos();
-        let sin = rad.sin();
-        Self {
-            mat: [
-                Vector3::new(cos, 0.0, sin),
-                Vector3::new(0.0, 1.0, 0.0),
-                Vector3::new(-sin, 0.0, cos),
-            ],
-        }
-    }
-
-    /// Creates a transform matrix to rotate around the Z-axis.
-    /// This matrix rotates points in the XY plane by the specified angle in radians when applied to a vector.
-    /// Assuming a right-handed coordinate system.
-    pub fn make_rotation_z(rad: f64) -> Self {
-        let cos = rad.cos();
-        let sin = rad.sin();
-        Self {
-            mat: [
-                Vector3::new(cos, -sin, 0.0),
-                Vector3::new(sin, cos, 0.0),
-                Vector3::new(0.0, 0.0, 1.0),
-            ],
-        }
-    }
-
-    /// Creates a transform matrix to rotate around an arbitrary axis.
-    /// Assuming a right-handed coordinate system.
-    pub fn make_rotation(rad: f64, axis: &Vector3<f64>) -> Self {
-        let cos = rad.cos();
-        let sin = rad.sin();
-        let one_minus_cos = 1.0 - cos;
-
-        let x = axis.x;
-        let y = axis.y;
-        let z = axis.z;
-
-        Self {
-            mat: [
-                Vector3::new(
-                    cos + x * x * one_minus_cos,
-                    y * x * one_minus_cos + z * sin,
-                    z * x * one_minus_cos + y * sin,
-                ),
-                Vector3::new(
-                    x * y * one_minus_cos - z * sin,
-                    cos + y * y * one_minus_cos,
-                    z * y * one_minus_cos + x * sin,
-                ),
-                Vector3::new(
-                    x * z * one_minus_cos + y * sin,
-                    y * z * one_minus_cos - x * sin,
-                    cos + z * z * one_minus_cos,
-                ),
-            ],
-        }
-    }
-
-    /// Creates a scaling matrix that scales points by the specified factors along each axis.
-    pub fn make_scaling(sx: f64, sy: f64, sz: f64) -> Self {
-        Self {
-            mat: [
-                Vector3::new(sx, 0.0, 0.0),
-                Vector3::new(0.0, sy, 0.0),
-                Vector3::new(0.0, 0.0, sz),
-            ],
-        }
-    }
-
-    /// Creates a scaling matrix that scales points along the specified axis by the given factor.
-    /// Assumes the axis is normalized.
-    pub fn make_scaling_axis(axis: &Vector3<f64>, factor: f64) -> Self {
-        debug_assert!(axis.is_normalized(), "`axis` must be a normalized");
-        let x = axis.x * factor;
-        let y = axis.y * factor;
-        let z = axis.z * factor;
-        Self {
-            mat: [
-                Vector3::new(x, 0.0, 0.0),
-                Vector3::new(0.0, y, 0.0),
-                Vector3::new(0.0, 0.0, z),
-            ],
-        }
-    }
-
-    /// Creates a reflection matrix that reflects points through the specified plane.
-    /// The plane is defined by its normal vector.
-    /// Assumes the normal vector is normalized.
-    pub fn make_reflection(normal: &Vector3<f64>) -> Self {
-        debug_assert!(normal.is_normalized(), "`normal` must be normalized");
-        let x = normal.x * -2.0;
-        let y = normal.y * -2.0;
-        let z = normal.z * -2.0;
-        Self {
-            mat: [
-                Vector3::new(1.0 + x * normal.x, x * normal.y, x * normal.z),
-                Vector3::new(y * normal.x, 1.0 + y * normal.y, y * normal.z),
-                Vector3::new(z * normal.x, z * normal.y, 1.0 + z * normal.z),
-            ],
-        }
-    }
-
-    /// Creates a skew transformation matrix that skews points by `rad` along
-    /// the `direction` in regards to the `pivot` axis, which is used to
-    /// measure the distance to determine how far to skew.
-    /// It assumes the `direction` vector is normalized and
-    /// the `pivot` is non-zero and perpendicular to the `direction` vector.
-    pub fn make_skew(rad: f64, direction: &Vector3<f64>, pivot: &Vector3<f64>) -> Self {
-        debug_assert!(direction.is_normalized(), "`direction` must be normalized");
-        debug_assert!(pivot.magnitude() > 0.0, "`pivot` must not be origin");
-        debug_assert!(
-            pivot.dot(&direction) == 0.0,
-            "`pivot` must be perpendicular to `direction`"
-        );
-
-        let tan = rad.tan();
-        let x = direction.x * tan;
-        let y = direction.y * tan;
-        let z = direction.z * tan;
-
-        Self {
-            mat: [
-                Vector3::new(x * pivot.x + 1.0, x * pivot.y, x * pivot.z),
-                Vector3::new(y * pivot.x, y * pivot.y + 1.0, y * pivot.z),
-                Vector3::new(z * pivot.x, z * pivot.y, z * pivot.z + 1.0),
-            ],
-        }
-    }
-}
+impl_matrix3x3_transforms! { f32 f64 }
@@ -17,11 +17,12 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+use std::fmt;
 use std::ops::{
     Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
 };
 
-use crate::math::{SignedNumber, Vector3, Vector4};
+use crate::math::{Angle, Matrix3x3, SignedNumber, Vector3, Vector4};
 
 /// A 4x4 matrix represented as an array of four `Vector4<T>` as rows.
 /// It supports addition, subtraction, multiplication by a scalar,
@@ -33,6 +34,15 @@ use crate::math::{SignedNumber, Vector3, Vector4};
 /// The transform matrices are supported for `FloatingPointNumber` trait,
 /// They are designed for working with 3-dimensional coordinate systems
 /// with quaternion support, and follow the right-handed coordinate system convention.
+///
+/// # GPU layout
+/// Transforms apply to column vectors (`matrix * vector`), so the translation is in the last
+/// column, at indices 3, 7 and 11 of `to_array` and `as_slice`, and the images of the axes are
+/// the first three columns: see `translation` and `basis_x`.
+/// HLSL packs constant buffer matrices in column-major order unless the shader declares them
+/// `row_major`, and uses the same `mul(matrix, vector)` convention, so matrices uploaded as is
+/// end up transposed. Use `as_gpu_bytes` with the `MatrixLayout` the shader expects, or
+/// `to_array_column_major`, so the layout is visible where the matrix is uploaded.
 #[derive(Debug, Copy, Clone, PartialEq, Default)]
 #[repr(C)]
 pub struct Matrix4x4<T: SignedNumber> {
@@ -306,6 +316,24 @@ impl<T: SignedNumber> Mul<Matrix4x4<T>> for Vector4<T> {
     }
 }
 
+/// Right-multiplication of a point, as the column vector `(x, y, z, 1)`, so the translation
+/// applies. The matrix is assumed to be affine, its last row `(0, 0, 0, 1)`, and no perspective
+/// divide is performed: multiply a `Vector4` to project a point.
+/// Use `transform_direction` for directions, which the translation must not move.
+impl<T: SignedNumber> Mul<Vector3<T>> for Matrix4x4<T> {
+    type Output = Vector3<T>;
+
+    #[inline]
+    fn mul(self, rhs: Vector3<T>) -> Self::Output {
+        Vector3 {
+            x: self[0][0] * rhs.x + self[0][1] * rhs.y + self[0][2] * rhs.z + self[0][3],
+            y: self[1][0] * rhs.x + self[1][1] * rhs.y + self[1][2] * rhs.z + self[1][3],
+            z: self[2][0] * rhs.x + self[2][1] * rhs.y + self[2][2] * rhs.z + self[2][3],
+        }
+    }
+}
+forward_ref_binop!(impl<T> Mul, mul for Matrix4x4<T>, Vector3<T> where T: SignedNumber);
+
 impl<T: SignedNumber> From<&[T]> for Matrix4x4<T> {
     #[inline]
     fn from(slice: &[T]) -> Self {
@@ -372,66 +400,42 @@ impl<T: SignedNumber> IndexMut<(usize, usize)> for Matrix4x4<T> {
 }
 
 impl<T: SignedNumber> Matrix4x4<T> {
+    /// A matrix with all elements set to zero.
+    pub const ZERO: Self = Self {
+        mat: [Vector4::ZERO, Vector4::ZERO, Vector4::ZERO, Vector4::ZERO],
+    };
+
+    /// The identity matrix, with ones on the diagonal and zeros elsewhere.
+    pub const IDENTITY: Self = Self {
+        mat: [
+            Vector4::new(T::ONE, T::ZERO, T::ZERO, T::ZERO),
+            Vector4::new(T::ZERO, T::ONE, T::ZERO, T::ZERO),
+            Vector4::new(T::ZERO, T::ZERO, T::ONE, T::ZERO),
+            Vector4::new(T::ZERO, T::ZERO, T::ZERO, T::ONE),
+        ],
+    };
+
     /// Creates a new `Matrix4x4` with the given rows.
-    pub fn new(rows: [Vector4<T>; 4]) -> Self {
+    pub const fn new(rows: [Vector4<T>; 4]) -> Self {
         Self { mat: rows }
     }
 
     /// Creates a new `Matrix4x4` with all elements initialized to zero.
-    pub fn zero() -> Self {
-        Self {
-            mat: [
-                Vector4::zero(),
-                Vector4::zero(),
-                Vector4::zero(),
-                Vector4::zero(),
-            ],
-        }
+    pub const fn zero() -> Self {
+        Self::ZERO
     }
 
     /// Creates a new `Matrix4x4` with all elements initialized to one.
-    pub fn one() -> Self {
+    pub const fn one() -> Self {
         Self {
-            mat: [
-                Vector4::one(),
-                Vector4::one(),
-                Vector4::one(),
-                Vector4::one(),
-            ],
+            mat: [Vector4::ONE, Vector4::ONE, Vector4::ONE, Vector4::ONE],
         }
     }
 
     /// Creates a new `Matrix4x4` that represents the identity matrix.
     /// The identity matrix has ones on the diagonal and zeros elsewhere.
-    pub fn identity() -> Self {
-        Self {
-            mat: [
-                Vector4 {
-                    x: T::one(),
-                    y: T::zero(),
-                    z: T::zero(),
-                    w: T::zero(),
-                },
-                Vector4 {
-                    x: T::zero(),
-                    y: T::one(),
-                    z: T::zero(),
-                    w: T::zero(),
-                },
-                Vector4 {
-                    x: T::zero(),
-                    y: T::zero(),
-                    z: T::one(),
-                    w: T::zero(),
-                },
-                Vector4 {
-                    x: T::zero(),
-                    y: T::zero(),
-                    z: T::zero(),
-                    w: T::one(),
-                },
-            ],
-        }
+    pub const fn identity() -> Self {
+        Self::IDENTITY
     }
 
     /// Returns the transpose of the matrix.
@@ -439,12 +443,7 @@ impl<T: SignedNumber> Matrix4x4<T> {
     /// For each element `mat[i][j]`, the transpose will have `mat[j][i]`.
     pub fn transpose(&self) -> Self {
         Self {
-            mat: [
-                Vector4::new(self[0][0], self[1][0], self[2][0], self[3][0]),
-                Vector4::new(self[0][1], self[1][1], self[2][1], self[3][1]),
-                Vector4::new(self[0][2], self[1][2], self[2][2], self[3][2]),
-                Vector4::new(self[0][3], self[1][3], self[2][3], self[3][3]),
-            ],
+            mat: self.columns(),
         }
     }
 
@@ -468,11 +467,29 @@ impl<T: SignedNumber> Matrix4x4<T> {
                     + self[1][2] * (self[2][0] * self[3][1] - self[2][1] * self[3][0]))
     }
 
+    /// Returns the trace of the matrix, the sum of its diagonal elements.
+    pub fn trace(&self) -> T {
+        self[0][0] + self[1][1] + self[2][2] + self[3][3]
+    }
+
+    /// Returns whether the matrix equals its transpose, each element within `epsilon`.
+    pub fn is_symmetric(&self, epsilon: T) -> bool {
+        (0..4).all(|row| {
+            (row + 1..4).all(|column| (self[row][column] - self[column][row]).abs() <= epsilon)
+        })
+    }
+
+    /// Returns whether every element is finite, neither infinite nor NaN.
+    /// Always true for integer matrices.
+    pub fn is_finite(&self) -> bool {
+        self.mat.iter().all(|row| row.is_finite())
+    }
+
     pub fn inverse(&self) -> Option<Self> {
-        let col0 = Vector3::<T>::new(self[0][0], self[1][0], self[2][0]);
-        let col1 = Vector3::<T>::new(self[0][1], self[1][1], self[2][1]);
-        let col2 = Vector3::<T>::new(self[0][2], self[1][2], self[2][2]);
-        let col3 = Vector3::<T>::new(self[0][3], self[1][3], self[2][3]);
+        // The first three elements of each column, the last row is handled separately.
+        let [col0, col1, col2, col3] = self
+            .columns()
+            .map(|column| Vector3::new(column.x, column.y, column.z));
 
         let x = self[3][0];
         let y = self[3][1];
@@ -510,6 +527,59 @@ impl<T: SignedNumber> Matrix4x4<T> {
         })
     }
 
+    /// Returns the inverse of the matrix like `inverse`, or `None` if it is nearly singular: if
+    /// the magnitude of its determinant is below `epsilon` times the largest determinant a matrix
+    /// of the same Frobenius norm can have, that of a scaled rotation. Unlike comparing the
+    /// determinant itself, this doesn't depend on the scale of the matrix.
+    /// See `condition_estimate` to measure how close to singular the matrix is.
+    pub fn inverse_checked(&self, epsilon: T) -> Option<Self> {
+        let norm_squared: f64 = self
+            .mat
+            .iter()
+            .map(|row| row.norm_squared().as_double())
+            .sum();
+        let largest_determinant = (norm_squared / 4.0).powi(2);
+        if largest_determinant == 0.0
+            || self.determinant().as_double().abs() < epsilon.as_double() * largest_determinant
+        {
+            return None;
+        }
+        self.inverse()
+    }
+
+    /// Transforms a direction, as the column vector `(x, y, z, 0)`, so the translation
+    /// doesn't apply, unlike multiplying the matrix by a `Vector3`, which transforms a point.
+    pub fn transform_direction(&self, v: Vector3<T>) -> Vector3<T> {
+        Vector3 {
+            x: self[0][0] * v.x + self[0][1] * v.y + self[0][2] * v.z,
+            y: self[1][0] * v.x + self[1][1] * v.y + self[1][2] * v.z,
+            z: self[2][0] * v.x + self[2][1] * v.y + self[2][2] * v.z,
+        }
+    }
+
+    /// Right-multiplies each vector of `vectors` by the matrix, writing the results to `out`.
+    /// Does not allocate, so `out` can be reused across frames.
+    ///
+    /// # Panics
+    /// Panics if `vectors` and `out` have different lengths.
+    pub fn transform_into(&self, vectors: &[Vector4<T>], out: &mut [Vector4<T>]) {
+        assert_eq!(
+            vectors.len(),
+            out.len(),
+            "`out` must have the length of `vectors`"
+        );
+        for (vector, result) in vectors.iter().zip(out.iter_mut()) {
+            *result = *self * *vector;
+        }
+    }
+
+    /// Right-multiplies each vector of `vectors` by the matrix, replacing it with the result.
+    pub fn transform_in_place(&self, vectors: &mut [Vector4<T>]) {
+        for vector in vectors.iter_mut() {
+            *vector = *self * *vector;
+        }
+    }
+
     /// Returns the rows of the matrix as an array of `Vector4<T>`.
     pub fn rows(&self) -> &[Vector4<T>; 4] {
         &self.mat
@@ -520,6 +590,75 @@ impl<T: SignedNumber> Matrix4x4<T> {
         &mut self.mat
     }
 
+    /// Returns the column at `index` of the matrix.
+    pub fn column(&self, index: usize) -> Vector4<T> {
+        debug_assert!(index < 4);
+        Vector4::new(
+            self[0][index],
+            self[1][index],
+            self[2][index],
+            self[3][index],
+        )
+    }
+
+    /// Replaces the column at `index` of the matrix, keeping the others.
+    pub fn set_column(&mut self, index: usize, column: Vector4<T>) {
+        debug_assert!(index < 4);
+        self[0][index] = column.x;
+        self[1][index] = column.y;
+        self[2][index] = column.z;
+        self[3][index] = column.w;
+    }
+
+    /// Returns the columns of the matrix, which are the rows of its transpose.
+    pub fn columns(&self) -> [Vector4<T>; 4] {
+        [
+            self.column(0),
+            self.column(1),
+            self.column(2),
+            self.column(3),
+        ]
+    }
+
+    /// Creates a `Matrix4x4` from its columns.
+    pub fn from_columns(columns: [Vector4<T>; 4]) -> Self {
+        Self { mat: columns }.transpose()
+    }
+
+    /// Returns the translation of the transform: the first three elements of the fourth
+    /// column, since transforms apply to column vectors.
+    pub fn translation(&self) -> Vector3<T> {
+        Vector3::new(self[0][3], self[1][3], self[2][3])
+    }
+
+    /// Replaces the translation of the transform, in the fourth column, keeping the rest.
+    pub fn set_translation(&mut self, translation: &Vector3<T>) {
+        self[0][3] = translation.x;
+        self[1][3] = translation.y;
+        self[2][3] = translation.z;
+    }
+
+    /// Returns the first column of the rotation and scale part: where the X-axis is mapped.
+    pub fn basis_x(&self) -> Vector3<T> {
+        Vector3::new(self[0][0], self[1][0], self[2][0])
+    }
+
+    /// Returns the second column of the rotation and scale part: where the Y-axis is mapped.
+    pub fn basis_y(&self) -> Vector3<T> {
+        Vector3::new(self[0][1], self[1][1], self[2][1])
+    }
+
+    /// Returns the third column of the rotation and scale part: where the Z-axis is mapped.
+    pub fn basis_z(&self) -> Vector3<T> {
+        Vector3::new(self[0][2], self[1][2], self[2][2])
+    }
+
+    /// Returns whether the last row is exactly `(0, 0, 0, 1)`, so the matrix has no
+    /// projection and maps points to points with `w = 1`.
+    pub fn is_affine(&self) -> bool {
+        self[3] == Vector4::new(T::zero(), T::zero(), T::zero(), T::one())
+    }
+
     /// Creates a `Matrix4x4` from a 2D array.
     pub const fn from_mat(mat: [[T; 4]; 4]) -> Self {
         Self {
@@ -578,6 +717,19 @@ impl<T: SignedNumber> Matrix4x4<T> {
         ]
     }
 
+    /// Creates a `Matrix4x4` from a flat array of 16 elements in column-major order,
+    /// as read from an HLSL constant buffer without `row_major`.
+    pub fn from_array_column_major(arr: [T; 16]) -> Self {
+        Self::from_array(arr).transpose()
+    }
+
+    /// Converts the `Matrix4x4` to a flat array of 16 elements in column-major order,
+    /// as expected by HLSL constant buffers without `row_major`.
+    /// The translation of a transform is at indices 12, 13 and 14.
+    pub fn to_array_column_major(&self) -> [T; 16] {
+        self.transpose().to_array()
+    }
+
     pub const fn from_slice(slice: &[T]) -> Self {
         debug_assert!(slice.len() >= 16, "Matrix4x4 requires at least 16 elements");
         Self {
@@ -598,321 +750,416 @@ impl<T: SignedNumber> Matrix4x4<T> {
         unsafe { std::mem::transmute(self) }
     }
 
+    /// Returns a pointer to the first element of the matrix.
+    ///
+    /// # Safety
+    /// Only the 16 elements, row after row, may be accessed through the pointer, while the
+    /// matrix is borrowed.
     pub unsafe fn as_ptr(&self) -> *const T {
         self[0].as_ptr()
     }
 
+    /// Returns a mutable pointer to the first element of the matrix.
+    ///
+    /// # Safety
+    /// Only the 16 elements, row after row, may be accessed through the pointer, while the
+    /// matrix is borrowed.
     pub unsafe fn as_mut_ptr(&mut self) -> *mut T {
         self[0].as_mut_ptr()
     }
 }
 
-impl Matrix4x4<f32> {
-    /// Creates a translation matrix that translates points by the specified amounts along each axis.
-    pub fn make_translation(tx: f32, ty: f32, tz: f32) -> Self {
-        Self::from_mat([
-            [1.0, 0.0, 0.0, tx],
-            [0.0, 1.0, 0.0, ty],
-            [0.0, 0.0, 1.0, tz],
-            [0.0, 0.0, 0.0, 1.0],
-        ])
-    }
-
-    /// Creates a transform matrix to rotate around the X-axis.
-    /// This matrix rotates points in the YZ plane by the specified angle in radians when applied to a vector.
-    /// Assuming a right-handed coordinate system.
-    pub fn make_rotation_x(rad: f32) -> Self {
-        let cos = rad.cos();
-        let sin = rad.sin();
-        Self::from_mat([
-            [1.0, 0.0, 0.0, 0.0],
-            [0.0, cos, -sin, 0.0],
-            [0.0, sin, cos, 0.0],
-            [0.0, 0.0, 0.0, 1.0],
-        ])
-    }
-
-    /// Creates a transform matrix to rotate around the Y-axis.
-    /// This matrix rotates points in the XZ plane by the specified angle in radians when applied to a vector.
-    /// Assuming a right-handed coordinate system.
-    pub fn make_rotation_y(rad: f32) -> Self {
-        let cos = rad.cos();
-        let sin = rad.sin();
-        Self::from_mat([
-            [cos, 0.0, sin, 0.0],
-            [0.0, 1.0, 0.0, 0.0],
-            [-sin, 0.0, cos, 0.0],
-            [0.0, 0.0, 0.0, 1.0],
-        ])
-    }
-
-    /// Creates a transform matrix to rotate around the Z-axis.
-    /// This matrix rotates points in the XY plane by the specified angle in radians when applied to a vector.
-    /// Assuming a right-handed coordinate system.
-    pub fn make_rotation_z(rad: f32) -> Self {
-        let cos = rad.cos();
-        let sin = rad.sin();
-        Self::from_mat([
-            [cos, -sin, 0.0, 0.0],
-            [sin, cos, 0.0, 0.0],
-            [0.0, 0.0, 1.0, 0.0],
-            [0.0, 0.0, 0.0, 1.0],
-        ])
-    }
-
-    /// Creates a rotation matrix around an arbitrary axis.
-    /// Assuming a right-handed coordinate system.
-    pub fn make_rotation(rad: f32, axis: &Vector3<f32>) -> Self {
-        let cos = rad.cos();
-        let sin = rad.sin();
-        let one_minus_cos = 1.0 - cos;
-
-        let x = axis.x;
-        let y = axis.y;
-        let z = axis.z;
-
-        Self::from_mat([
-            [
-                cos + x * x * one_minus_cos,
-                y * x * one_minus_cos + z * sin,
-                z * x * one_minus_cos - y * sin,
-                0.0,
-            ],
-            [
-                x * y * one_minus_cos - z * sin,
-                cos + y * y * one_minus_cos,
-                z * y * one_minus_cos + x * sin,
-                0.0,
-            ],
-            [
-                x * z * one_minus_cos + y * sin,
-                y * z * one_minus_cos - x * sin,
-                cos + z * z * one_minus_cos,
-                0.0,
-            ],
-            [0.0, 0.0, 0.0, 1.0],
-        ])
-    }
-
-    /// Creates a scaling matrix that scales points by the specified factors along each axis.
-    pub fn make_scaling(sx: f32, sy: f32, sz: f32) -> Self {
-        Self::from_mat([
-            [sx, 0.0, 0.0, 0.0],
-            [0.0, sy, 0.0, 0.0],
-            [0.0, 0.0, sz, 0.0],
-            [0.0, 0.0, 0.0, 1.0],
-        ])
-    }
-
-    /// Creates a scaling matrix that scales points along the specified axis by the given factor.
-    /// Assumes the axis is normalized.
-    pub fn make_scaling_axis(axis: &Vector3<f32>, factor: f32) -> Self {
-        debug_assert!(axis.is_normalized(), "Axis must be normalized");
-        let x = axis.x * factor;
-        let y = axis.y * factor;
-        let z = axis.z * factor;
-        Self::from_mat([
-            [x, 0.0, 0.0, 0.0],
-            [0.0, y, 0.0, 0.0],
-            [0.0, 0.0, z, 0.0],
-            [0.0, 0.0, 0.0, 1.0],
-        ])
-    }
-
-    /// Creates a reflection matrix that reflects points through the specified plane.
-    /// The plane is defined by its normal vector.
-    /// Assumes the normal vector is normalized.
-    pub fn make_reflection(normal: &Vector3<f32>) -> Self {
-        debug_assert!(normal.is_normalized(), "Normal vector must be normalized");
-        let x = normal.x * -2.0;
-        let y = normal.y * -2.0;
-        let z = normal.z * -2.0;
-        Self::from_mat([
-            [1.0 + x * normal.x, x * normal.y, x * normal.z, 0.0],
-            [y * normal.x, 1.0 + y * normal.y, y * normal.z, 0.0],
-            [z * normal.x, z * normal.y, 1.0 + z * normal.z, 0.0],
-            [0.0, 0.0, 0.0, 1.0],
-        ])
-    }
-
-    /// Creates a skew transformation matrix that skews points by `rad` along
-    /// the `direction` in regards to the `pivot` axis, which is used to
-    /// measure the distance to determine how far to skew.
-    /// It assumes the `direction` vector is normalized and
-    /// the `pivot` is non-zero and perpendicular to the `direction` vector.
-    pub fn make_skew(rad: f32, direction: &Vector3<f32>, pivot: &Vector3<f32>) -> Self {
-        debug_assert!(direction.is_normalized(), "`direction` must be normalized");
-        debug_assert!(pivot.magnitude() > 0.0, "`pivot` must not be origin");
-        debug_assert!(
-            pivot.dot(&direction) == 0.0,
-            "`pivot` must be perpendicular to `direction`"
-        );
-
-        let tan = rad.tan();
-        let x = direction.x * tan;
-        let y = direction.y * tan;
-        let z = direction.z * tan;
+/// Order of the elements of a matrix in memory, see `Matrix4x4::as_gpu_bytes`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MatrixLayout {
+    /// Rows one after the other, as stored by `Matrix4x4` and by HLSL `row_major` matrices.
+    RowMajor,
+    /// Columns one after the other, the default packing of HLSL constant buffers.
+    ColumnMajor,
+}
 
-        Self::from_mat([
-            [x * pivot.x + 1.0, x * pivot.y, x * pivot.z, 0.0],
-            [y * pivot.x, y * pivot.y + 1.0, y * pivot.z, 0.0],
-            [z * pivot.x, z * pivot.y, z * pivot.z + 1.0, 0.0],
-            [0.0, 0.0, 0.0, 1.0],
-        ])
+impl Matrix4x4<f32> {
+    /// Returns the 64 bytes to upload to a GPU buffer, with the elements in the given `layout`.
+    /// The elements are little-endian, like on every GPU Direct3D supports.
+    pub fn as_gpu_bytes(&self, layout: MatrixLayout) -> [u8; 64] {
+        let elements = match layout {
+            MatrixLayout::RowMajor => self.to_array(),
+            MatrixLayout::ColumnMajor => self.to_array_column_major(),
+        };
+        let mut bytes = [0; 64];
+        for (chunk, element) in bytes.chunks_exact_mut(4).zip(elements) {
+            chunk.copy_from_slice(&element.to_le_bytes());
+        }
+        bytes
     }
 }
 
-impl Matrix4x4<f64> {
-    /// Creates a translation matrix that translates points by the specified amounts along each axis.
-    pub fn make_translation(tx: f64, ty: f64, tz: f64) -> Self {
-        Self::from_mat([
-            [1.0, 0.0, 0.0, tx],
-            [0.0, 1.0, 0.0, ty],
-            [0.0, 0.0, 1.0, tz],
-            [0.0, 0.0, 0.0, 1.0],
-        ])
-    }
-
-    /// Creates a transform matrix to rotate around the X-axis.
-    /// This matrix rotates points in the YZ plane by the specified angle in radians when applied to a vector.
-    /// Assuming a right-handed coordinate system.
-    pub fn make_rotation_x(rad: f64) -> Self {
-        let cos = rad.cos();
-        let sin = rad.sin();
-        Self::from_mat([
-            [1.0, 0.0, 0.0, 0.0],
-            [0.0, cos, -sin, 0.0],
-            [0.0, sin, cos, 0.0],
-            [0.0, 0.0, 0.0, 1.0],
-        ])
-    }
-
-    /// Creates a transform matrix to rotate around the Y-axis.
-    /// This matrix rotates points in the XZ plane by the specified angle in radians when applied to a vector.
-    /// Assuming a right-handed coordinate system.
-    pub fn make_rotation_y(rad: f64) -> Self {
-        let cos = rad.cos();
-        let sin = rad.sin();
-        Self::from_mat([
-            [cos, 0.0, sin, 0.0],
-            [0.0, 1.0, 0.0, 0.0],
-            [-sin, 0.0, cos, 0.0],
-            [0.0, 0.0, 0.0, 1.0],
-        ])
-    }
-
-    /// Creates a transform matrix to rotate around the Z-axis.
-    /// This matrix rotates points in the XY plane by the specified angle in radians when applied to a vector.
-    /// Assuming a right-handed coordinate system.
-    pub fn make_rotation_z(rad: f64) -> Self {
-        let cos = rad.cos();
-        let sin = rad.sin();
-        Self::from_mat([
-            [cos, -sin, 0.0, 0.0],
-            [sin, cos, 0.0, 0.0],
-            [0.0, 0.0, 1.0, 0.0],
-            [0.0, 0.0, 0.0, 1.0],
-        ])
-    }
-
-    /// Creates a transform matrix to rotate around an arbitrary axis.
-    /// Assuming a right-handed coordinate system.
-    pub fn make_rotation(rad: f64, axis: &Vector3<f64>) -> Self {
-        let cos = rad.cos();
-        let sin = rad.sin();
-        let one_minus_cos = 1.0 - cos;
-
-        let x = axis.x;
-        let y = axis.y;
-        let z = axis.z;
-
-        Self::from_mat([
-            [
-                cos + x * x * one_minus_cos,
-                y * x * one_minus_cos + z * sin,
-                z * x * one_minus_cos + y * sin,
-                0.0,
-            ],
-            [
-                x * y * one_minus_cos - z * sin,
-                cos + y * y * one_minus_cos,
-                z * y * one_minus_cos + x * sin,
-                0.0,
-            ],
-            [
-                x * z * one_minus_cos + y * sin,
-                y * z * one_minus_cos - x * sin,
-                cos + z * z * one_minus_cos,
-                0.0,
-            ],
-            [0.0, 0.0, 0.0, 1.0],
-        ])
-    }
-
-    /// Creates a scaling matrix that scales points by the specified factors along each axis.
-    pub fn make_scaling(sx: f64, sy: f64, sz: f64) -> Self {
-        Self::from_mat([
-            [sx, 0.0, 0.0, 0.0],
-            [0.0, sy, 0.0, 0.0],
-            [0.0, 0.0, sz, 0.0],
-            [0.0, 0.0, 0.0, 1.0],
-        ])
-    }
-
-    /// Creates a scaling matrix that scales points along the specified axis by the given factor.
-    /// Assumes the axis is normalized.
-    pub fn make_scaling_axis(axis: &Vector3<f64>, factor: f64) -> Self {
-        debug_assert!(axis.is_normalized(), "`axis` must be a normalized");
-        let x = axis.x * factor;
-        let y = axis.y * factor;
-        let z = axis.z * factor;
-        Self::from_mat([
-            [x, 0.0, 0.0, 0.0],
-            [0.0, y, 0.0, 0.0],
-            [0.0, 0.0, z, 0.0],
-            [0.0, 0.0, 0.0, 1.0],
-        ])
-    }
-
-    /// Creates a reflection matrix that reflects points through the specified plane.
-    /// The plane is defined by its normal vector.
-    /// Assumes the normal vector is normalized.
-    pub fn make_reflection(normal: &Vector3<f64>) -> Self {
-        debug_assert!(normal.is_normalized(), "`normal` must be normalized");
-        let x = normal.x * -2.0;
-        let y = normal.y * -2.0;
-        let z = normal.z * -2.0;
-        Self::from_mat([
-            [1.0 + x * normal.x, x * normal.y, x * normal.z, 0.0],
-            [y * normal.x, 1.0 + y * normal.y, y * normal.z, 0.0],
-            [z * normal.x, z * normal.y, 1.0 + z * normal.z, 0.0],
-            [0.0, 0.0, 0.0, 1.0],
-        ])
-    }
-
-    /// Creates a skew transformation matrix that skews points by `rad` along
-    /// the `direction` in regards to the `pivot` axis, which is used to
-    /// measure the distance to determine how far to skew.
-    /// It assumes the `direction` vector is normalized and
-    /// the `pivot` is non-zero and perpendicular to the `direction` vector.
-    pub fn make_skew(rad: f64, direction: &Vector3<f64>, pivot: &Vector3<f64>) -> Self {
-        debug_assert!(direction.is_normalized(), "`direction` must be normalized");
-        debug_assert!(pivot.magnitude() > 0.0, "`pivot` must not be origin");
-        debug_assert!(
-            pivot.dot(&direction) == 0.0,
-            "`pivot` must be perpendicular to `direction`"
-        );
-
-        let tan = rad.tan();
-        let x = direction.x * tan;
-        let y = direction.y * tan;
-        let z = direction.z * tan;
-
-        Self::from_mat([
-            [x * pivot.x + 1.0, x * pivot.y, x * pivot.z, 0.0],
-            [y * pivot.x, y * pivot.y + 1.0, y * pivot.z, 0.0],
-            [z * pivot.x, z * pivot.y, z * pivot.z + 1.0, 0.0],
-            [0.0, 0.0, 0.0, 1.0],
-        ])
+/// Formats the matrix one row per line, passing the precision and width to the elements:
+/// `format!("{:.1}", Matrix4x4::<f32>::identity())` starts with `[1.0, 0.0, 0.0, 0.0]`.
+impl<T: SignedNumber + fmt::Display> fmt::Display for Matrix4x4<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.mat.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "[")?;
+            for (j, element) in [row.x, row.y, row.z, row.w].iter().enumerate() {
+                if j > 0 {
+                    write!(f, ", ")?;
+                }
+                fmt::Display::fmt(element, f)?;
+            }
+            write!(f, "]")?;
+        }
+        Ok(())
     }
 }
+
+// The transform constructors only make sense for floating point numbers.
+// They are generated for each precision from a single definition, so they can't drift apart.
+macro_rules! impl_matrix4x4_transforms {
+    ($($t:ty)*) => ($(
+        impl Matrix4x4<$t> {
+            /// Creates a translation matrix that translates points by the specified amounts along each axis.
+            pub fn make_translation(tx: $t, ty: $t, tz: $t) -> Self {
+                Self::from_mat([
+                    [1.0, 0.0, 0.0, tx],
+                    [0.0, 1.0, 0.0, ty],
+                    [0.0, 0.0, 1.0, tz],
+                    [0.0, 0.0, 0.0, 1.0],
+                ])
+            }
+
+            /// Same as `make_translation`, taking the translation as a vector.
+            pub fn make_translation_v(translation: &Vector3<$t>) -> Self {
+                Self::make_translation(translation.x, translation.y, translation.z)
+            }
+
+            /// Creates a transform matrix to rotate around the X-axis.
+            /// This matrix rotates points in the YZ plane by the specified angle in radians when applied to a vector.
+            /// Assuming a right-handed coordinate system.
+            /// Prefer `make_rotation_x_angle`, which takes an `Angle`.
+            pub fn make_rotation_x(rad: $t) -> Self {
+                let cos = rad.cos();
+                let sin = rad.sin();
+                Self::from_mat([
+                    [1.0, 0.0, 0.0, 0.0],
+                    [0.0, cos, -sin, 0.0],
+                    [0.0, sin, cos, 0.0],
+                    [0.0, 0.0, 0.0, 1.0],
+                ])
+            }
+
+            /// Same as `make_rotation_x`, taking an `Angle` instead of radians.
+            pub fn make_rotation_x_angle(angle: Angle<$t>) -> Self {
+                Self::make_rotation_x(angle.to_radians())
+            }
+
+            /// Creates a transform matrix to rotate around the Y-axis.
+            /// This matrix rotates points in the XZ plane by the specified angle in radians when applied to a vector.
+            /// Assuming a right-handed coordinate system.
+            /// Prefer `make_rotation_y_angle`, which takes an `Angle`.
+            pub fn make_rotation_y(rad: $t) -> Self {
+                let cos = rad.cos();
+                let sin = rad.sin();
+                Self::from_mat([
+                    [cos, 0.0, sin, 0.0],
+                    [0.0, 1.0, 0.0, 0.0],
+                    [-sin, 0.0, cos, 0.0],
+                    [0.0, 0.0, 0.0, 1.0],
+                ])
+            }
+
+            /// Same as `make_rotation_y`, taking an `Angle` instead of radians.
+            pub fn make_rotation_y_angle(angle: Angle<$t>) -> Self {
+                Self::make_rotation_y(angle.to_radians())
+            }
+
+            /// Creates a transform matrix to rotate around the Z-axis.
+            /// This matrix rotates points in the XY plane by the specified angle in radians when applied to a vector.
+            /// Assuming a right-handed coordinate system.
+            /// Prefer `make_rotation_z_angle`, which takes an `Angle`.
+            pub fn make_rotation_z(rad: $t) -> Self {
+                let cos = rad.cos();
+                let sin = rad.sin();
+                Self::from_mat([
+                    [cos, -sin, 0.0, 0.0],
+                    [sin, cos, 0.0, 0.0],
+                    [0.0, 0.0, 1.0, 0.0],
+                    [0.0, 0.0, 0.0, 1.0],
+                ])
+            }
+
+            /// Same as `make_rotation_z`, taking an `Angle` instead of radians.
+            pub fn make_rotation_z_angle(angle: Angle<$t>) -> Self {
+                Self::make_rotation_z(angle.to_radians())
+            }
+
+            /// Creates a rotation matrix around an arbitrary axis, normalizing the axis first.
+            /// The axis must not be zero. Use `make_rotation_normalized` to skip the
+            /// normalization for an axis that is already normalized.
+            /// Assuming a right-handed coordinate system.
+            /// Prefer `make_rotation_angle`, which takes an `Angle`.
+            pub fn make_rotation(rad: $t, axis: &Vector3<$t>) -> Self {
+                debug_assert!(axis.norm_squared() > 0.0, "`axis` must not be zero");
+                Self::make_rotation_normalized(rad, &axis.normalize())
+            }
+
+            /// Same as `make_rotation`, taking an `Angle` instead of radians.
+            pub fn make_rotation_angle(angle: Angle<$t>, axis: &Vector3<$t>) -> Self {
+                Self::make_rotation(angle.to_radians(), axis)
+            }
+
+            /// Creates a rotation matrix around an arbitrary axis, which must be normalized.
+            /// Assuming a right-handed coordinate system.
+            /// Prefer `make_rotation_normalized_angle`, which takes an `Angle`.
+            pub fn make_rotation_normalized(rad: $t, axis: &Vector3<$t>) -> Self {
+                debug_assert!(axis.is_normalized(), "`axis` must be normalized");
+                let cos = rad.cos();
+                let sin = rad.sin();
+                let one_minus_cos = 1.0 - cos;
+
+                let x = axis.x;
+                let y = axis.y;
+                let z = axis.z;
+
+                Self::from_mat([
+                    [
+                        cos + x * x * one_minus_cos,
+                        x * y * one_minus_cos - z * sin,
+                        x * z * one_minus_cos + y * sin,
+                        0.0,
+                    ],
+                    [
+                        y * x * one_minus_cos + z * sin,
+                        cos + y * y * one_minus_cos,
+                        y * z * one_minus_cos - x * sin,
+                        0.0,
+                    ],
+                    [
+                        z * x * one_minus_cos - y * sin,
+                        z * y * one_minus_cos + x * sin,
+                        cos + z * z * one_minus_cos,
+                        0.0,
+                    ],
+                    [0.0, 0.0, 0.0, 1.0],
+                ])
+            }
+
+            /// Same as `make_rotation_normalized`, taking an `Angle` instead of radians.
+            pub fn make_rotation_normalized_angle(angle: Angle<$t>, axis: &Vector3<$t>) -> Self {
+                Self::make_rotation_normalized(angle.to_radians(), axis)
+            }
+
+            /// Creates a scaling matrix that scales points by the specified factors along each axis.
+            pub fn make_scaling(sx: $t, sy: $t, sz: $t) -> Self {
+                Self::from_mat([
+                    [sx, 0.0, 0.0, 0.0],
+                    [0.0, sy, 0.0, 0.0],
+                    [0.0, 0.0, sz, 0.0],
+                    [0.0, 0.0, 0.0, 1.0],
+                ])
+            }
+
+            /// Same as `make_scaling`, taking the factors as a vector.
+            pub fn make_scaling_v(scale: &Vector3<$t>) -> Self {
+                Self::make_scaling(scale.x, scale.y, scale.z)
+            }
+
+            /// Creates a scaling matrix that scales points along the specified axis by the given factor.
+            /// Components perpendicular to the axis are left unchanged.
+            /// Assumes the axis is normalized.
+            pub fn make_scaling_axis(axis: &Vector3<$t>, factor: $t) -> Self {
+                debug_assert!(axis.is_normalized(), "`axis` must be normalized");
+                // I + (factor - 1) * axis * axis^T
+                let k = factor - 1.0;
+                let x = axis.x * k;
+                let y = axis.y * k;
+                let z = axis.z * k;
+                Self::from_mat([
+                    [1.0 + x * axis.x, x * axis.y, x * axis.z, 0.0],
+                    [y * axis.x, 1.0 + y * axis.y, y * axis.z, 0.0],
+                    [z * axis.x, z * axis.y, 1.0 + z * axis.z, 0.0],
+                    [0.0, 0.0, 0.0, 1.0],
+                ])
+            }
+
+            /// Creates a reflection matrix that reflects points through the specified plane.
+            /// The plane is defined by its normal vector.
+            /// Assumes the normal vector is normalized.
+            pub fn make_reflection(normal: &Vector3<$t>) -> Self {
+                debug_assert!(normal.is_normalized(), "`normal` must be normalized");
+                let x = normal.x * -2.0;
+                let y = normal.y * -2.0;
+                let z = normal.z * -2.0;
+                Self::from_mat([
+                    [1.0 + x * normal.x, x * normal.y, x * normal.z, 0.0],
+                    [y * normal.x, 1.0 + y * normal.y, y * normal.z, 0.0],
+                    [z * normal.x, z * normal.y, 1.0 + z * normal.z, 0.0],
+                    [0.0, 0.0, 0.0, 1.0],
+                ])
+            }
+
+            /// Creates a skew transformation matrix that skews points by `rad` along
+            /// the `direction` in regards to the `pivot` axis, which is used to
+            /// measure the distance to determine how far to skew.
+            /// It assumes the `direction` vector is normalized and the `pivot` is non-zero
+            /// and perpendicular to the `direction` vector, up to a tolerance relative to
+            /// the length of `pivot`. The length of `pivot` scales the skew.
+            /// Use `try_make_skew` when the vectors aren't known to meet these conditions.
+            pub fn make_skew(rad: $t, direction: &Vector3<$t>, pivot: &Vector3<$t>) -> Self {
+                debug_assert!(direction.is_normalized(), "`direction` must be normalized");
+                debug_assert!(pivot.magnitude() > 0.0, "`pivot` must not be origin");
+                debug_assert!(
+                    Matrix3x3::<$t>::is_valid_skew(direction, pivot),
+                    "`pivot` must be perpendicular to `direction`"
+                );
+
+                let tan = rad.tan();
+                let x = direction.x * tan;
+                let y = direction.y * tan;
+                let z = direction.z * tan;
+
+                Self::from_mat([
+                    [x * pivot.x + 1.0, x * pivot.y, x * pivot.z, 0.0],
+                    [y * pivot.x, y * pivot.y + 1.0, y * pivot.z, 0.0],
+                    [z * pivot.x, z * pivot.y, z * pivot.z + 1.0, 0.0],
+                    [0.0, 0.0, 0.0, 1.0],
+                ])
+            }
+
+            /// Same as `make_skew`, returning `None` instead of asserting if `direction`
+            /// and `pivot` don't meet its conditions.
+            pub fn try_make_skew(
+                rad: $t,
+                direction: &Vector3<$t>,
+                pivot: &Vector3<$t>,
+            ) -> Option<Self> {
+                if Matrix3x3::<$t>::is_valid_skew(direction, pivot) {
+                    Some(Self::make_skew(rad, direction, pivot))
+                } else {
+                    None
+                }
+            }
+
+            /// Returns `self * Self::make_translation_v(translation)`.
+            ///
+            /// The builder methods multiply on the right, so a chain reads in the order of the
+            /// product: `Matrix4x4::identity().translate(&t).rotate_z(r).scale_by(&s)` is
+            /// `T * R * S`, which applied to a point scales it first, then rotates it, then
+            /// translates it.
+            pub fn translate(&self, translation: &Vector3<$t>) -> Self {
+                *self * Self::make_translation_v(translation)
+            }
+
+            /// Returns `self * Self::make_rotation_x(rad)`, see `translate` for the order.
+            /// Prefer `rotate_x_angle`, which takes an `Angle`.
+            pub fn rotate_x(&self, rad: $t) -> Self {
+                *self * Self::make_rotation_x(rad)
+            }
+
+            /// Same as `rotate_x`, taking an `Angle` instead of radians.
+            pub fn rotate_x_angle(&self, angle: Angle<$t>) -> Self {
+                self.rotate_x(angle.to_radians())
+            }
+
+            /// Returns `self * Self::make_rotation_y(rad)`, see `translate` for the order.
+            /// Prefer `rotate_y_angle`, which takes an `Angle`.
+            pub fn rotate_y(&self, rad: $t) -> Self {
+                *self * Self::make_rotation_y(rad)
+            }
+
+            /// Same as `rotate_y`, taking an `Angle` instead of radians.
+            pub fn rotate_y_angle(&self, angle: Angle<$t>) -> Self {
+                self.rotate_y(angle.to_radians())
+            }
+
+            /// Returns `self * Self::make_rotation_z(rad)`, see `translate` for the order.
+            /// Prefer `rotate_z_angle`, which takes an `Angle`.
+            pub fn rotate_z(&self, rad: $t) -> Self {
+                *self * Self::make_rotation_z(rad)
+            }
+
+            /// Same as `rotate_z`, taking an `Angle` instead of radians.
+            pub fn rotate_z_angle(&self, angle: Angle<$t>) -> Self {
+                self.rotate_z(angle.to_radians())
+            }
+
+            /// Returns `self * Self::make_scaling_v(scale)`, see `translate` for the order.
+            /// Named apart from `scale`, which reads the scale of the matrix back.
+            pub fn scale_by(&self, scale: &Vector3<$t>) -> Self {
+                *self * Self::make_scaling_v(scale)
+            }
+
+            /// Returns the scale of the transform along each axis, as the lengths of the basis
+            /// columns. The lengths are never negative: a reflection (negative scale) can't be
+            /// told apart from a rotation by the basis lengths, so its sign is not recovered.
+            pub fn scale(&self) -> Vector3<$t> {
+                Vector3::new(
+                    self.basis_x().norm_squared().sqrt(),
+                    self.basis_y().norm_squared().sqrt(),
+                    self.basis_z().norm_squared().sqrt(),
+                )
+            }
+
+            /// Returns whether every element is within `epsilon` of the identity matrix.
+            pub fn is_identity(&self, epsilon: $t) -> bool {
+                let identity = Self::identity();
+                self.mat
+                    .iter()
+                    .zip(identity.rows())
+                    .all(|(row, expected)| {
+                        (row.x - expected.x).abs() <= epsilon
+                            && (row.y - expected.y).abs() <= epsilon
+                            && (row.z - expected.z).abs() <= epsilon
+                            && (row.w - expected.w).abs() <= epsilon
+                    })
+            }
+
+            /// Returns the Frobenius norm of the matrix, the square root of the sum of the
+            /// squares of its elements.
+            pub fn frobenius_norm(&self) -> $t {
+                self.mat.iter().map(|row| row.norm_squared()).sum::<$t>().sqrt()
+            }
+
+            /// Estimates the condition number of the matrix, the product of the Frobenius norms
+            /// of the matrix and of its inverse, for diagnostics. It is at least the dimension of
+            /// the matrix, reached by rotations, and grows as the matrix gets closer to singular,
+            /// bounding how much the relative errors of inputs are amplified in the results.
+            /// Infinite for singular matrices.
+            pub fn condition_estimate(&self) -> f64 {
+                match self.inverse() {
+                    Some(inverse) => self.frobenius_norm() as f64 * inverse.frobenius_norm() as f64,
+                    None => f64::INFINITY,
+                }
+            }
+
+            /// Returns whether the rows of the matrix are orthonormal, the product of the matrix
+            /// with its transpose being the identity within `epsilon`. Unlike the rotation in
+            /// the upper left 3x3 part of a transform, a translation isn't orthogonal.
+            pub fn is_orthogonal(&self, epsilon: $t) -> bool {
+                (*self * self.transpose()).is_identity(epsilon)
+            }
+
+            /// Returns the matrix with its upper left 3x3 part orthonormalized like
+            /// `Matrix3x3::orthonormalize`, leaving the translation and the last row as they are.
+            /// Removes the scale and skew of a transform, and the drift of its rotation.
+            /// Returns `None` if the 3x3 part is singular.
+            pub fn orthonormalize(&self) -> Option<Self> {
+                let rotation = Matrix3x3::new(
+                    [0, 1, 2].map(|row| Vector3::new(self[row][0], self[row][1], self[row][2])),
+                )
+                .orthonormalize()?;
+                let mut result = *self;
+                for row in 0..3 {
+                    for column in 0..3 {
+                        result[row][column] = rotation[row][column];
+                    }
+                }
+                Some(result)
+            }
+        }
+    )*)
+}
+
+impl_matrix4x4_transforms! { f32 f64 }
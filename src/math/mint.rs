@@ -0,0 +1,89 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Conversions between the math types and the `mint` interoperability types, enabled by the
+//! `mint` feature. Matrices here are row-major: converting to or from the column-major `mint`
+//! types transposes, so both represent the same transform.
+
+use super::{Matrix2x2, Matrix3x3, Matrix4x4, Number, SignedNumber, Vector2, Vector3, Vector4};
+
+macro_rules! impl_mint_vector {
+    ($($vector:ident { $($field:ident),+ })+) => ($(
+        impl<T: Number> From<$vector<T>> for ::mint::$vector<T> {
+            fn from(v: $vector<T>) -> Self {
+                Self { $($field: v.$field),+ }
+            }
+        }
+
+        impl<T: Number> From<::mint::$vector<T>> for $vector<T> {
+            fn from(v: ::mint::$vector<T>) -> Self {
+                Self { $($field: v.$field),+ }
+            }
+        }
+
+        impl<T: Number> ::mint::IntoMint for $vector<T> {
+            type MintType = ::mint::$vector<T>;
+        }
+    )+)
+}
+
+impl_mint_vector! {
+    Vector2 { x, y }
+    Vector3 { x, y, z }
+    Vector4 { x, y, z, w }
+}
+
+macro_rules! impl_mint_matrix {
+    ($($matrix:ident, $row_matrix:ident, $column_matrix:ident { $($field:ident: $index:expr),+ })+) => ($(
+        impl<T: SignedNumber> From<$matrix<T>> for ::mint::$row_matrix<T> {
+            fn from(m: $matrix<T>) -> Self {
+                Self { $($field: m[$index].into()),+ }
+            }
+        }
+
+        impl<T: SignedNumber> From<::mint::$row_matrix<T>> for $matrix<T> {
+            fn from(m: ::mint::$row_matrix<T>) -> Self {
+                Self::new([$(m.$field.into()),+])
+            }
+        }
+
+        impl<T: SignedNumber> From<$matrix<T>> for ::mint::$column_matrix<T> {
+            fn from(m: $matrix<T>) -> Self {
+                let columns = m.transpose();
+                Self { $($field: columns[$index].into()),+ }
+            }
+        }
+
+        impl<T: SignedNumber> From<::mint::$column_matrix<T>> for $matrix<T> {
+            fn from(m: ::mint::$column_matrix<T>) -> Self {
+                Self::new([$(m.$field.into()),+]).transpose()
+            }
+        }
+
+        impl<T: SignedNumber> ::mint::IntoMint for $matrix<T> {
+            type MintType = ::mint::$row_matrix<T>;
+        }
+    )+)
+}
+
+impl_mint_matrix! {
+    Matrix2x2, RowMatrix2, ColumnMatrix2 { x: 0, y: 1 }
+    Matrix3x3, RowMatrix3, ColumnMatrix3 { x: 0, y: 1, z: 2 }
+    Matrix4x4, RowMatrix4, ColumnMatrix4 { x: 0, y: 1, z: 2, w: 3 }
+}
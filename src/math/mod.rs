@@ -20,31 +20,91 @@
 #[macro_use]
 mod internal_macros;
 
+mod angle;
+pub mod collision2d;
+pub mod curve;
+#[cfg(all(
+    target_os = "windows",
+    any(feature = "renderer-d3d12", feature = "renderer-d2d")
+))]
+mod d2d;
+pub mod easing;
+pub mod grid;
+mod half;
+mod matrix2x2;
 mod matrix3x3;
 mod matrix4x4;
+#[cfg(feature = "mint")]
+mod mint;
 mod number;
 mod perspective;
+#[cfg(feature = "rand")]
+mod rand;
 mod rect;
 mod size;
+mod transform2d;
+pub mod triangle;
 mod vector2;
 mod vector3;
 mod vector4;
 
+pub use self::angle::Angle;
+pub use self::half::{f16_bits_to_f32, f32_to_f16_bits};
+pub use self::matrix2x2::Matrix2x2;
 pub use self::matrix3x3::Matrix3x3;
-pub use self::matrix4x4::Matrix4x4;
-pub use self::number::Wrap;
-pub(crate) use self::number::*;
+pub use self::matrix4x4::{Matrix4x4, MatrixLayout};
+pub use self::number::{
+    wrap_angle_degrees, wrap_angle_radians, Abs, AsDouble, Fixed32, FloatingPointNumber,
+    FromDouble, IntegerNumber, Number, SignedInteger, SignedNumber, Wrap,
+};
 pub use self::perspective::*;
 pub use self::rect::Rect;
 pub use self::size::Size;
+pub use self::transform2d::Transform2D;
 pub use self::vector2::Vector2;
 pub use self::vector3::Vector3;
 pub use self::vector4::Vector4;
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// Default tolerance used by `is_normalized` on vectors, compared against `|length² - 1|`.
+/// A vector built with `normalize` passes, a vector such as `(2, 0, 0)` does not.
+/// Use `is_normalized_within` to check with a different tolerance.
+pub const NORMALIZED_EPSILON: f64 = 1e-6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Direction {
     North,
     East,
     South,
     West,
 }
+
+impl Direction {
+    /// All the directions, clockwise from `North`.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Returns the step of one cell in this direction. The y axis points down, like window
+    /// coordinates, so `North` is `(0, -1)`.
+    pub const fn offset(self) -> Vector2<i32> {
+        match self {
+            Direction::North => Vector2::new(0, -1),
+            Direction::East => Vector2::new(1, 0),
+            Direction::South => Vector2::new(0, 1),
+            Direction::West => Vector2::new(-1, 0),
+        }
+    }
+
+    /// Returns the direction pointing the other way.
+    pub const fn opposite(self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+}
@@ -19,15 +19,73 @@
 
 mod abs;
 mod as_double;
+mod fixed;
 mod wrap;
 
-pub(crate) use self::abs::Abs;
-pub(crate) use self::as_double::AsDouble;
-pub(crate) use self::as_double::FromDouble;
-pub use self::wrap::Wrap;
+pub use self::abs::Abs;
+pub use self::as_double::AsDouble;
+pub use self::as_double::FromDouble;
+pub use self::fixed::Fixed32;
+pub use self::wrap::{wrap_angle_degrees, wrap_angle_radians, Wrap};
 
+use std::hash::Hash;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
+/// Scalar type usable in the math types, such as `Vector2` or `Matrix4x4`.
+///
+/// Implemented for `u32`, `u64`, `i32`, `i64`, `f32`, `f64` and the fixed-point `Fixed32`.
+/// Other scalars can implement it along with `Abs`, `AsDouble` and `FromDouble`:
+///
+/// ```
+/// use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+///
+/// use sky_labs::math::{Abs, AsDouble, FromDouble, Number, Vector2};
+///
+/// /// Fixed-point number with 16 fractional bits.
+/// #[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+/// struct Fixed(i32);
+///
+/// impl Add for Fixed {
+///     type Output = Self;
+///     fn add(self, rhs: Self) -> Self { Fixed(self.0 + rhs.0) }
+/// }
+/// impl Sub for Fixed {
+///     type Output = Self;
+///     fn sub(self, rhs: Self) -> Self { Fixed(self.0 - rhs.0) }
+/// }
+/// impl Mul for Fixed {
+///     type Output = Self;
+///     fn mul(self, rhs: Self) -> Self { Fixed(((self.0 as i64 * rhs.0 as i64) >> 16) as i32) }
+/// }
+/// impl Div for Fixed {
+///     type Output = Self;
+///     fn div(self, rhs: Self) -> Self { Fixed((((self.0 as i64) << 16) / rhs.0 as i64) as i32) }
+/// }
+/// impl AddAssign for Fixed { fn add_assign(&mut self, rhs: Self) { *self = *self + rhs } }
+/// impl SubAssign for Fixed { fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs } }
+/// impl MulAssign for Fixed { fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs } }
+/// impl DivAssign for Fixed { fn div_assign(&mut self, rhs: Self) { *self = *self / rhs } }
+///
+/// impl Abs for Fixed {
+///     fn abs(self) -> Self { Fixed(self.0.abs()) }
+/// }
+/// impl AsDouble for Fixed {
+///     fn as_double(self) -> f64 { self.0 as f64 / 65536.0 }
+/// }
+/// impl FromDouble for Fixed {
+///     fn from_double(value: f64) -> Self { Fixed((value * 65536.0) as i32) }
+/// }
+///
+/// impl Number for Fixed {
+///     const ZERO: Self = Fixed(0);
+///     const ONE: Self = Fixed(1 << 16);
+/// }
+///
+/// let half = Fixed::from_double(0.5);
+/// let v = Vector2::new(Fixed::ONE, half) + Vector2::new(half, half);
+/// assert_eq!(v, Vector2::new(Fixed::from_double(1.5), Fixed::ONE));
+/// assert_eq!((v * Fixed::from_double(2.0)).x.as_double(), 3.0);
+/// ```
 // TODO: consider making this trait const once issue is merged
 // https://github.com/rust-lang/rust/issues/143874
 pub trait Number:
@@ -48,17 +106,36 @@ pub trait Number:
     + Abs
     + FromDouble
 {
-    fn zero() -> Self;
-    fn one() -> Self;
+    const ZERO: Self;
+    const ONE: Self;
+
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    /// Returns `|self - other|`, which unlike `abs(self - other)` doesn't underflow for unsigned
+    /// types.
+    #[inline]
+    fn abs_diff(self, other: Self) -> Self {
+        if self < other {
+            other - self
+        } else {
+            self - other
+        }
+    }
 }
 
 macro_rules! impl_number {
     ($($t:ty)*) => ($(
         impl Number for $t {
-            #[inline]
-            fn zero() -> $t { 0 as $t }
-            #[inline]
-            fn one() -> $t { 1 as $t }
+            const ZERO: $t = 0 as $t;
+            const ONE: $t = 1 as $t;
         }
     )*)
 }
@@ -71,9 +148,57 @@ impl SignedNumber for f32 {}
 impl SignedNumber for i64 {}
 impl SignedNumber for i32 {}
 
-pub trait SignedInteger: SignedNumber {}
-impl SignedInteger for i64 {}
-impl SignedInteger for i32 {}
+/// Integer numbers, exposing the overflow-aware arithmetic of the primitive integer types.
+pub trait IntegerNumber: Number + Eq + Ord + Hash {
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn saturating_add(self, rhs: Self) -> Self;
+    fn saturating_sub(self, rhs: Self) -> Self;
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn wrapping_sub(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_integer_number {
+    ($($t:ty)*) => ($(
+        impl IntegerNumber for $t {
+            #[inline]
+            fn checked_add(self, rhs: $t) -> Option<$t> { <$t>::checked_add(self, rhs) }
+            #[inline]
+            fn checked_sub(self, rhs: $t) -> Option<$t> { <$t>::checked_sub(self, rhs) }
+            #[inline]
+            fn saturating_add(self, rhs: $t) -> $t { <$t>::saturating_add(self, rhs) }
+            #[inline]
+            fn saturating_sub(self, rhs: $t) -> $t { <$t>::saturating_sub(self, rhs) }
+            #[inline]
+            fn wrapping_add(self, rhs: $t) -> $t { <$t>::wrapping_add(self, rhs) }
+            #[inline]
+            fn wrapping_sub(self, rhs: $t) -> $t { <$t>::wrapping_sub(self, rhs) }
+        }
+    )*)
+}
+
+impl_integer_number! { u32 u64 i32 i64 }
+
+pub trait SignedInteger: SignedNumber + IntegerNumber {
+    /// The unsigned integer type with the same width.
+    type Unsigned: IntegerNumber;
+
+    /// Converts to the unsigned type with the same width, returning `None` if the value is negative.
+    fn try_to_unsigned(self) -> Option<Self::Unsigned>;
+}
+
+macro_rules! impl_signed_integer {
+    ($($t:ty => $u:ty)*) => ($(
+        impl SignedInteger for $t {
+            type Unsigned = $u;
+
+            #[inline]
+            fn try_to_unsigned(self) -> Option<$u> { <$u>::try_from(self).ok() }
+        }
+    )*)
+}
+
+impl_signed_integer! { i32 => u32 i64 => u64 }
 
 pub trait FloatingPointNumber: SignedNumber {}
 impl FloatingPointNumber for f64 {}
@@ -17,7 +17,10 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+/// Lossy conversion to `f64`, used by the math types for their floating-point computations.
 pub trait AsDouble {
+    // Implemented for `Copy` numbers only, which are taken by value like the `as` casts.
+    #[allow(clippy::wrong_self_convention)]
     fn as_double(self) -> f64;
 }
 
@@ -32,6 +35,7 @@ macro_rules! impl_as_double {
 
 impl_as_double! { f64 f32 i32 i64 u32 u64 }
 
+/// Lossy conversion from `f64`, truncating toward zero for integer types.
 pub trait FromDouble {
     fn from_double(value: f64) -> Self;
 }
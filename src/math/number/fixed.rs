@@ -0,0 +1,256 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Fixed-point scalar, computing the same results on every machine unlike floating-point.
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::{Abs, AsDouble, FromDouble, Number, SignedNumber};
+use crate::math::{Vector2, Vector3};
+
+/// Signed fixed-point number with 16 integer and 16 fractional bits, covering
+/// `[-32768, 32768)` in steps of `1 / 65536`.
+///
+/// Every operation is made of integer arithmetic, so the results are bit for bit the same on
+/// every machine, as lockstep simulations need. Arithmetic saturates at `MIN` and `MAX` instead
+/// of wrapping, and the `checked_` methods return `None` instead. Products and quotients are
+/// computed on 64 bits, products rounding to the nearest step and quotients truncating toward
+/// zero. Dividing by zero panics, like integer division.
+///
+/// Only the conversions with `as_double` and `from_double` go through floating-point.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed32(i32);
+
+impl Fixed32 {
+    /// Number of fractional bits.
+    pub const FRAC_BITS: u32 = 16;
+    pub const MIN: Self = Self(i32::MIN);
+    pub const MAX: Self = Self(i32::MAX);
+    /// The smallest positive value, `1 / 65536`.
+    pub const EPSILON: Self = Self(1);
+
+    /// Creates the number whose binary representation is `bits`, the value times 65536.
+    pub const fn from_bits(bits: i32) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the binary representation of the number, the value times 65536.
+    pub const fn to_bits(self) -> i32 {
+        self.0
+    }
+
+    /// Converts an integer, saturating outside of the range of `Fixed32`.
+    pub const fn from_int(value: i32) -> Self {
+        Self::saturate(value as i64 * Self::ONE.0 as i64)
+    }
+
+    /// Returns the largest integer less than or equal to the number.
+    pub const fn to_int(self) -> i32 {
+        self.0 >> Self::FRAC_BITS
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        i32::try_from(Self::wide_mul(self, rhs)).ok().map(Self)
+    }
+
+    /// Returns `None` if `rhs` is zero or the quotient overflows.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.0 == 0 {
+            return None;
+        }
+        i32::try_from(Self::wide_div(self, rhs)).ok().map(Self)
+    }
+
+    /// Returns the square root, computed on integers so it is deterministic, rounded down to a
+    /// multiple of `EPSILON`.
+    ///
+    /// # Panics
+    /// Panics if the number is negative.
+    pub fn sqrt(self) -> Self {
+        assert!(
+            self.0 >= 0,
+            "Square root of negative number {}.",
+            self.as_double()
+        );
+        // sqrt(bits / 2^16) * 2^16 = sqrt(bits * 2^16), which stays below 2^31.
+        Self(((self.0 as u64) << Self::FRAC_BITS).isqrt() as i32)
+    }
+
+    /// Product of the representations, rounded to the nearest step, before saturation.
+    const fn wide_mul(lhs: Self, rhs: Self) -> i64 {
+        (lhs.0 as i64 * rhs.0 as i64 + (1 << (Self::FRAC_BITS - 1))) >> Self::FRAC_BITS
+    }
+
+    /// Quotient of the representations, truncated toward zero, before saturation.
+    const fn wide_div(lhs: Self, rhs: Self) -> i64 {
+        ((lhs.0 as i64) << Self::FRAC_BITS) / rhs.0 as i64
+    }
+
+    const fn saturate(bits: i64) -> Self {
+        if bits > i32::MAX as i64 {
+            Self::MAX
+        } else if bits < i32::MIN as i64 {
+            Self::MIN
+        } else {
+            Self(bits as i32)
+        }
+    }
+}
+
+impl Add for Fixed32 {
+    type Output = Self;
+
+    /// Saturating addition.
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl Sub for Fixed32 {
+    type Output = Self;
+
+    /// Saturating subtraction.
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl Mul for Fixed32 {
+    type Output = Self;
+
+    /// Saturating multiplication, rounded to the nearest step.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::saturate(Self::wide_mul(self, rhs))
+    }
+}
+
+impl Div for Fixed32 {
+    type Output = Self;
+
+    /// Saturating division, truncated toward zero. Panics if `rhs` is zero.
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        Self::saturate(Self::wide_div(self, rhs))
+    }
+}
+
+impl Neg for Fixed32 {
+    type Output = Self;
+
+    /// Saturating negation, `-MIN` is `MAX`.
+    #[inline]
+    fn neg(self) -> Self {
+        Self(self.0.saturating_neg())
+    }
+}
+
+impl AddAssign for Fixed32 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Fixed32 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Fixed32 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Fixed32 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Abs for Fixed32 {
+    /// Saturating absolute value, `MIN.abs()` is `MAX`.
+    fn abs(self) -> Self {
+        Self(self.0.saturating_abs())
+    }
+}
+
+impl AsDouble for Fixed32 {
+    /// Exact, every `Fixed32` is a `f64`.
+    #[inline]
+    fn as_double(self) -> f64 {
+        self.0 as f64 / Self::ONE.0 as f64
+    }
+}
+
+impl FromDouble for Fixed32 {
+    /// Rounds to the nearest step, saturating outside of the range of `Fixed32`. NaN is zero.
+    #[inline]
+    fn from_double(value: f64) -> Self {
+        Self((value * Self::ONE.0 as f64).round() as i32)
+    }
+}
+
+impl Number for Fixed32 {
+    const ZERO: Self = Self(0);
+    const ONE: Self = Self(1 << Self::FRAC_BITS);
+}
+
+impl SignedNumber for Fixed32 {}
+
+/// Length of the vector of representations `components`, as a representation.
+fn magnitude_bits(components: &[i32]) -> Fixed32 {
+    // Each square is below 2^62, so the sum of up to 3 of them fits in a u64.
+    let norm_squared: u64 = components
+        .iter()
+        .map(|&component| component.unsigned_abs() as u64 * component.unsigned_abs() as u64)
+        .sum();
+    Fixed32::saturate(norm_squared.isqrt() as i64)
+}
+
+impl Vector2<Fixed32> {
+    /// Returns the magnitude of the vector computed on integers, unlike `magnitude`, which goes
+    /// through `f64`. Rounded down, saturating at `Fixed32::MAX`.
+    pub fn fixed_magnitude(&self) -> Fixed32 {
+        magnitude_bits(&[self.x.0, self.y.0])
+    }
+}
+
+impl Vector3<Fixed32> {
+    /// Returns the magnitude of the vector computed on integers, unlike `magnitude`, which goes
+    /// through `f64`. Rounded down, saturating at `Fixed32::MAX`.
+    pub fn fixed_magnitude(&self) -> Fixed32 {
+        magnitude_bits(&[self.x.0, self.y.0, self.z.0])
+    }
+}
@@ -17,26 +17,84 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
-use super::Number;
-
-pub trait Wrap
-where
-    Self: Number,
-{
-    fn wrap_around(self, min_limit: Self, max_limit: Self) -> Self {
-        if self < min_limit {
-            max_limit - self.abs()
-        } else if self > max_limit {
-            min_limit + self - max_limit
-        } else {
-            self
+use std::f64::consts::PI;
+
+use super::{FloatingPointNumber, Number};
+
+/// Wrapping of values into an interval, for periodic quantities such as angles,
+/// tile coordinates or looping animations.
+///
+/// `wrap` uses the half-open interval `[min, max)`: `max` itself wraps to `min`, like the
+/// remainder of a division. `ping_pong` uses the closed interval `[min, max]` and reflects
+/// at both boundaries. Both panic if `max <= min`.
+/// Integer arithmetic is done in a wider type, so it can't overflow near the type limits.
+pub trait Wrap: Number {
+    /// Wraps `self` into `[min, max)`, so that the result differs from `self`
+    /// by a multiple of `max - min`.
+    fn wrap(self, min: Self, max: Self) -> Self;
+
+    /// Reflects `self` into `[min, max]`, going back and forth between the boundaries
+    /// as `self` increases: `min` maps to `min`, `max` to `max` and `2 * max - min` to `min`.
+    fn ping_pong(self, min: Self, max: Self) -> Self;
+}
+
+macro_rules! impl_wrap_integer {
+    ($($t:ty)*) => ($(
+        impl Wrap for $t {
+            fn wrap(self, min: $t, max: $t) -> $t {
+                assert!(min < max, "wrap requires min < max");
+                let range = max as i128 - min as i128;
+                (min as i128 + (self as i128 - min as i128).rem_euclid(range)) as $t
+            }
+
+            fn ping_pong(self, min: $t, max: $t) -> $t {
+                assert!(min < max, "ping_pong requires min < max");
+                let range = max as i128 - min as i128;
+                let offset = (self as i128 - min as i128).rem_euclid(2 * range);
+                if offset <= range {
+                    (min as i128 + offset) as $t
+                } else {
+                    (max as i128 - (offset - range)) as $t
+                }
+            }
         }
-    }
+    )*)
 }
 
-impl Wrap for f64 {}
-impl Wrap for f32 {}
-impl Wrap for i64 {}
-impl Wrap for i32 {}
-impl Wrap for u64 {}
-impl Wrap for u32 {}
+impl_wrap_integer! { i32 i64 u32 u64 }
+
+macro_rules! impl_wrap_float {
+    ($($t:ty)*) => ($(
+        impl Wrap for $t {
+            fn wrap(self, min: $t, max: $t) -> $t {
+                assert!(min < max, "wrap requires min < max");
+                let wrapped = min + (self - min).rem_euclid(max - min);
+                // Rounding can land exactly on `max` for values just below `min`.
+                if wrapped < max { wrapped } else { min }
+            }
+
+            fn ping_pong(self, min: $t, max: $t) -> $t {
+                assert!(min < max, "ping_pong requires min < max");
+                let range = max - min;
+                let offset = (self - min).rem_euclid(2.0 * range);
+                if offset <= range {
+                    min + offset
+                } else {
+                    (max - (offset - range)).max(min)
+                }
+            }
+        }
+    )*)
+}
+
+impl_wrap_float! { f32 f64 }
+
+/// Wraps an angle in radians into `[-π, π)`.
+pub fn wrap_angle_radians<T: FloatingPointNumber + Wrap>(angle: T) -> T {
+    angle.wrap(T::from_double(-PI), T::from_double(PI))
+}
+
+/// Wraps an angle in degrees into `[-180, 180)`.
+pub fn wrap_angle_degrees<T: FloatingPointNumber + Wrap>(angle: T) -> T {
+    angle.wrap(T::from_double(-180.0), T::from_double(180.0))
+}
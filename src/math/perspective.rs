@@ -17,40 +17,153 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+//! Projection matrices, created with `Matrix4x4::make_perspective`,
+//! `Matrix4x4::make_perspective_reversed_z` and `Matrix4x4::make_orthographic`.
+//! View space is left-handed, looking down +z. Perspective projections put the view space `z`
+//! in the projected `w`, so points must be divided by `w` after the projection.
+//!
+//! The free functions `perspective_f32` and the like are deprecated in favor of the constructors:
+//!
+//! ```compile_fail
+//! #![deny(deprecated)]
+//! use sky_labs::math::{perspective_f32, DepthRange};
+//!
+//! let projection = perspective_f32(1.5, 1.0, 0.1, 10.0, DepthRange::ZeroToOne);
+//! ```
+
 use super::Matrix4x4;
 
-pub fn perspective_f32(
-    horizontal_fov: f32,
-    aspect_ratio: f32,
-    near_field: f32,
-    far_field: f32,
-) -> Matrix4x4<f32> {
-    let focal_length = 1.0 / (horizontal_fov / 2.0).tan();
-    let range_inv = 1.0 / (far_field - near_field);
-    let far_range = far_field * range_inv;
-
-    Matrix4x4::from_mat([
-        [focal_length / aspect_ratio, 0.0, 0.0, 0.0],
-        [0.0, focal_length, 0.0, 0.0],
-        [0.0, 0.0, far_range, -1.0 * near_field * far_range],
-        [0.0, 0.0, 1.0, 0.0],
-    ])
+/// Range of the depth of visible points after the perspective divide.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum DepthRange {
+    /// Depth between 0 and 1, as used by Direct3D, Metal and Vulkan.
+    #[default]
+    ZeroToOne,
+    /// Depth between -1 and 1, as used by OpenGL.
+    NegativeOneToOne,
+}
+
+macro_rules! impl_projections {
+    ($($t:ty, $perspective:ident, $perspective_reversed_z:ident;)*) => ($(
+        impl Matrix4x4<$t> {
+            /// Creates a perspective projection mapping `near_field` to the lowest depth of
+            /// `depth_range` and `far_field` to the highest.
+            pub fn make_perspective(
+                horizontal_fov: $t,
+                aspect_ratio: $t,
+                near_field: $t,
+                far_field: $t,
+                depth_range: DepthRange,
+            ) -> Self {
+                let focal_length = 1.0 / (horizontal_fov / 2.0).tan();
+                let range_inv = 1.0 / (far_field - near_field);
+                let (depth_scale, depth_offset) = match depth_range {
+                    DepthRange::ZeroToOne => (
+                        far_field * range_inv,
+                        -near_field * far_field * range_inv,
+                    ),
+                    DepthRange::NegativeOneToOne => (
+                        (far_field + near_field) * range_inv,
+                        -2.0 * near_field * far_field * range_inv,
+                    ),
+                };
+
+                Self::from_mat([
+                    [focal_length / aspect_ratio, 0.0, 0.0, 0.0],
+                    [0.0, focal_length, 0.0, 0.0],
+                    [0.0, 0.0, depth_scale, depth_offset],
+                    [0.0, 0.0, 1.0, 0.0],
+                ])
+            }
+
+            /// Creates a perspective projection with reversed depth and the far plane at
+            /// infinity: `near_field` is mapped to the highest depth of `depth_range`, and the
+            /// depth decreases towards the lowest one with distance, without reaching it.
+            /// Spreads floating point depth precision evenly over distance, use it with a
+            /// "greater" depth test and a depth buffer cleared to the lowest depth.
+            pub fn make_perspective_reversed_z(
+                horizontal_fov: $t,
+                aspect_ratio: $t,
+                near_field: $t,
+                depth_range: DepthRange,
+            ) -> Self {
+                let focal_length = 1.0 / (horizontal_fov / 2.0).tan();
+                let (depth_scale, depth_offset) = match depth_range {
+                    DepthRange::ZeroToOne => (0.0, near_field),
+                    DepthRange::NegativeOneToOne => (-1.0, 2.0 * near_field),
+                };
+
+                Self::from_mat([
+                    [focal_length / aspect_ratio, 0.0, 0.0, 0.0],
+                    [0.0, focal_length, 0.0, 0.0],
+                    [0.0, 0.0, depth_scale, depth_offset],
+                    [0.0, 0.0, 1.0, 0.0],
+                ])
+            }
+
+            /// Creates an orthographic projection of the box `width` by `height` centered on
+            /// the view axis, mapping its sides to -1 and 1, `near_field` to the lowest depth
+            /// of `depth_range` and `far_field` to the highest. The projected `w` stays 1.
+            pub fn make_orthographic(
+                width: $t,
+                height: $t,
+                near_field: $t,
+                far_field: $t,
+                depth_range: DepthRange,
+            ) -> Self {
+                let range_inv = 1.0 / (far_field - near_field);
+                let (depth_scale, depth_offset) = match depth_range {
+                    DepthRange::ZeroToOne => (range_inv, -near_field * range_inv),
+                    DepthRange::NegativeOneToOne => (
+                        2.0 * range_inv,
+                        -(far_field + near_field) * range_inv,
+                    ),
+                };
+
+                Self::from_mat([
+                    [2.0 / width, 0.0, 0.0, 0.0],
+                    [0.0, 2.0 / height, 0.0, 0.0],
+                    [0.0, 0.0, depth_scale, depth_offset],
+                    [0.0, 0.0, 0.0, 1.0],
+                ])
+            }
+        }
+
+        #[deprecated(note = "Use `Matrix4x4::make_perspective` instead.")]
+        pub fn $perspective(
+            horizontal_fov: $t,
+            aspect_ratio: $t,
+            near_field: $t,
+            far_field: $t,
+            depth_range: DepthRange,
+        ) -> Matrix4x4<$t> {
+            Matrix4x4::<$t>::make_perspective(
+                horizontal_fov,
+                aspect_ratio,
+                near_field,
+                far_field,
+                depth_range,
+            )
+        }
+
+        #[deprecated(note = "Use `Matrix4x4::make_perspective_reversed_z` instead.")]
+        pub fn $perspective_reversed_z(
+            horizontal_fov: $t,
+            aspect_ratio: $t,
+            near_field: $t,
+            depth_range: DepthRange,
+        ) -> Matrix4x4<$t> {
+            Matrix4x4::<$t>::make_perspective_reversed_z(
+                horizontal_fov,
+                aspect_ratio,
+                near_field,
+                depth_range,
+            )
+        }
+    )*)
 }
 
-pub fn perspective_f64(
-    horizontal_fov: f64,
-    aspect_ratio: f64,
-    near_field: f64,
-    far_field: f64,
-) -> Matrix4x4<f64> {
-    let focal_length = 1.0 / (horizontal_fov / 2.0).tan();
-    let range_inv = 1.0 / (far_field - near_field);
-    let far_range = far_field * range_inv;
-
-    Matrix4x4::from_mat([
-        [focal_length / aspect_ratio, 0.0, 0.0, 0.0],
-        [0.0, focal_length, 0.0, 0.0],
-        [0.0, 0.0, far_range, -1.0 * near_field * far_range],
-        [0.0, 0.0, 1.0, 0.0],
-    ])
+impl_projections! {
+    f32, perspective_f32, perspective_reversed_z_f32;
+    f64, perspective_f64, perspective_reversed_z_f64;
 }
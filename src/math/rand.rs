@@ -0,0 +1,127 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Random generation of the math types, enabled by the `rand` feature.
+//! `Standard` samples each component in `[0, 1)` for floating point types and over the whole
+//! range for integers, and a `Rect` samples points uniformly inside it.
+
+use std::f64::consts::TAU;
+
+use ::rand::distributions::{Distribution, Standard};
+use ::rand::Rng;
+
+use super::{Matrix3x3, Number, Rect, Vector2, Vector3, Vector4};
+
+macro_rules! impl_standard_vector {
+    ($($vector:ident { $($field:ident),+ })+) => ($(
+        impl<T: Number> Distribution<$vector<T>> for Standard
+        where
+            Standard: Distribution<T>,
+        {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vector<T> {
+                $vector { $($field: rng.gen()),+ }
+            }
+        }
+    )+)
+}
+
+impl_standard_vector! {
+    Vector2 { x, y }
+    Vector3 { x, y, z }
+    Vector4 { x, y, z, w }
+}
+
+macro_rules! impl_random {
+    ($($t:ty)*) => ($(
+        impl Distribution<Vector2<$t>> for Rect<$t> {
+            /// Samples a point uniformly inside the rectangle.
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector2<$t> {
+                Vector2::new(
+                    self.x + rng.gen::<$t>() * self.width,
+                    self.y + rng.gen::<$t>() * self.height,
+                )
+            }
+        }
+
+        impl Vector2<$t> {
+            /// Returns a unit vector with a direction uniformly distributed on the unit circle.
+            pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                let angle = rng.gen::<$t>() * TAU as $t;
+                Self::new(angle.cos(), angle.sin())
+            }
+        }
+
+        impl Vector3<$t> {
+            /// Returns a unit vector with a direction uniformly distributed on the unit sphere.
+            pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                // Archimedes: the height of a uniform point on the sphere is uniform.
+                let z = rng.gen::<$t>() * 2.0 - 1.0;
+                let angle = rng.gen::<$t>() * TAU as $t;
+                let radius = (1.0 - z * z).max(0.0).sqrt();
+                Self::new(radius * angle.cos(), radius * angle.sin(), z)
+            }
+
+            /// Returns a point uniformly distributed in the box between the corners `min` and
+            /// `max`.
+            pub fn random_in_box<R: Rng + ?Sized>(rng: &mut R, min: &Self, max: &Self) -> Self {
+                Self::new(
+                    min.x + rng.gen::<$t>() * (max.x - min.x),
+                    min.y + rng.gen::<$t>() * (max.y - min.y),
+                    min.z + rng.gen::<$t>() * (max.z - min.z),
+                )
+            }
+        }
+
+        impl Matrix3x3<$t> {
+            /// Returns a rotation matrix uniformly distributed over all the rotations.
+            /// Built from a uniform unit quaternion (Shoemake's method): sampling Euler angles
+            /// uniformly instead would cluster the rotations around the poles.
+            pub fn random_rotation<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                let u = rng.gen::<$t>();
+                let first_angle = rng.gen::<$t>() * TAU as $t;
+                let second_angle = rng.gen::<$t>() * TAU as $t;
+                let (first_radius, second_radius) = ((1.0 - u).sqrt(), u.sqrt());
+                let x = first_radius * first_angle.sin();
+                let y = first_radius * first_angle.cos();
+                let z = second_radius * second_angle.sin();
+                let w = second_radius * second_angle.cos();
+
+                Self::new([
+                    Vector3::new(
+                        1.0 - 2.0 * (y * y + z * z),
+                        2.0 * (x * y - z * w),
+                        2.0 * (x * z + y * w),
+                    ),
+                    Vector3::new(
+                        2.0 * (x * y + z * w),
+                        1.0 - 2.0 * (x * x + z * z),
+                        2.0 * (y * z - x * w),
+                    ),
+                    Vector3::new(
+                        2.0 * (x * z - y * w),
+                        2.0 * (y * z + x * w),
+                        1.0 - 2.0 * (x * x + y * y),
+                    ),
+                ])
+            }
+        }
+    )*)
+}
+
+impl_random! { f32 f64 }
@@ -19,7 +19,7 @@
 
 use super::Number;
 
-#[derive(Clone, Copy, Default, Debug)]
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
 pub struct Rect<T:Number> {
     pub x: T,
     pub y: T,
@@ -27,4 +27,93 @@ pub struct Rect<T:Number> {
     pub height: T,
 }
 
-// TODO: Implement methods for Rect
\ No newline at end of file
+impl<T: Number> Rect<T> {
+    /// Returns the rectangle with `f` applied to its position and size.
+    fn map<U: Number>(self, f: impl Fn(T) -> U) -> Rect<U> {
+        Rect {
+            x: f(self.x),
+            y: f(self.y),
+            width: f(self.width),
+            height: f(self.height),
+        }
+    }
+}
+
+impl Rect<i32> {
+    /// Converts to floating point, for instance a window rectangle to render target pixels.
+    /// Values beyond 2^24 are rounded to the nearest `f32`.
+    pub fn to_f32(self) -> Rect<f32> {
+        self.map(|value| value as f32)
+    }
+}
+
+impl Rect<u32> {
+    /// Converts to floating point. Values above 2^24 are rounded to the nearest `f32`.
+    pub fn to_f32(self) -> Rect<f32> {
+        self.map(|value| value as f32)
+    }
+}
+
+/// Conversions to pixel rectangles, rounding the position and the size separately as named,
+/// so the right and bottom edges may move by up to a pixel more than the position.
+/// NaN gives 0, and values out of range give the nearest representable one.
+impl Rect<f32> {
+    /// Rounds each value to the nearest integer, halfway cases away from zero.
+    pub fn to_i32_round(self) -> Rect<i32> {
+        self.map(|value| value.round() as i32)
+    }
+
+    /// Rounds each value down.
+    pub fn to_i32_floor(self) -> Rect<i32> {
+        self.map(|value| value.floor() as i32)
+    }
+
+    /// Rounds each value up.
+    pub fn to_i32_ceil(self) -> Rect<i32> {
+        self.map(|value| value.ceil() as i32)
+    }
+
+    /// Rounds each value to the nearest integer, halfway cases away from zero.
+    /// Negative values give 0.
+    pub fn to_u32_round(self) -> Rect<u32> {
+        self.map(|value| value.round() as u32)
+    }
+
+    /// Rounds each value down. Negative values give 0.
+    pub fn to_u32_floor(self) -> Rect<u32> {
+        self.map(|value| value.floor() as u32)
+    }
+
+    /// Rounds each value up. Negative values give 0.
+    pub fn to_u32_ceil(self) -> Rect<u32> {
+        self.map(|value| value.ceil() as u32)
+    }
+}
+
+#[cfg(all(target_os = "windows", feature = "windowing"))]
+use windows::Win32::Foundation::RECT;
+
+/// Win32 rectangles are given by their edges, the right and bottom ones excluded.
+#[cfg(all(target_os = "windows", feature = "windowing"))]
+impl From<Rect<i32>> for RECT {
+    fn from(rect: Rect<i32>) -> Self {
+        Self {
+            left: rect.x,
+            top: rect.y,
+            right: rect.x + rect.width,
+            bottom: rect.y + rect.height,
+        }
+    }
+}
+
+#[cfg(all(target_os = "windows", feature = "windowing"))]
+impl From<RECT> for Rect<i32> {
+    fn from(rect: RECT) -> Self {
+        Self {
+            x: rect.left,
+            y: rect.top,
+            width: rect.right - rect.left,
+            height: rect.bottom - rect.top,
+        }
+    }
+}
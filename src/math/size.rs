@@ -17,8 +17,11 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
-use super::number::Number;
+use super::number::{IntegerNumber, Number};
 use super::Vector2;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::ops::{Div, DivAssign, Mul, MulAssign};
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Default)]
 pub struct Size<T: Number> {
@@ -26,11 +29,130 @@ pub struct Size<T: Number> {
     pub height: T,
 }
 
+impl<T: IntegerNumber> Eq for Size<T> {}
+
+implement_lexicographic_ord!(Size, width, height);
+
+impl<T: IntegerNumber> Hash for Size<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
 impl<T: Number> Size<T> {
     pub fn new(width: T, height: T) -> Self {
         Self { width, height }
     }
+
+    /// Returns the size with `f` applied to the width and the height.
+    fn map<U: Number>(self, f: impl Fn(T) -> U) -> Size<U> {
+        Size {
+            width: f(self.width),
+            height: f(self.height),
+        }
+    }
+}
+
+impl Size<u32> {
+    /// Converts to floating point, for instance a window size to a render target size.
+    /// Sides above 2^24 are rounded to the nearest `f32`.
+    pub fn to_f32(self) -> Size<f32> {
+        self.map(|side| side as f32)
+    }
+}
+
+/// Conversions to pixel sizes, rounding each side as named. Negative sides and NaN give 0,
+/// and sides past `u32::MAX` give `u32::MAX`.
+impl Size<f32> {
+    /// Rounds each side to the nearest integer, halfway cases away from zero.
+    pub fn to_u32_round(self) -> Size<u32> {
+        self.map(|side| side.round() as u32)
+    }
+
+    /// Rounds each side down.
+    pub fn to_u32_floor(self) -> Size<u32> {
+        self.map(|side| side.floor() as u32)
+    }
+
+    /// Rounds each side up.
+    pub fn to_u32_ceil(self) -> Size<u32> {
+        self.map(|side| side.ceil() as u32)
+    }
+}
+
+/// Lossless conversions, like those between the primitive types. `Size<u32>` doesn't convert
+/// into `Size<f32>` with `From` as large sides lose precision, see `Size::to_f32`.
+macro_rules! impl_size_from_lossless {
+    ($($from:ty => $to:ty),*) => ($(
+        impl From<Size<$from>> for Size<$to> {
+            fn from(size: Size<$from>) -> Self {
+                size.map(<$to>::from)
+            }
+        }
+    )*)
+}
+
+impl_size_from_lossless! {
+    u32 => u64,
+    u32 => i64,
+    u32 => f64,
+    i32 => i64,
+    i32 => f64,
+    f32 => f64
+}
+
+impl<T: Number> Mul<T> for Size<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            width: self.width * rhs,
+            height: self.height * rhs,
+        }
+    }
+}
+forward_ref_binop!(impl<T> Mul, mul for Size<T>, T where T: Number);
+implement_scalar_lhs_mul! {
+    Size<u32>, u32;
+    Size<u64>, u64;
+    Size<i32>, i32;
+    Size<i64>, i64;
+    Size<f32>, f32;
+    Size<f64>, f64
+}
+
+impl<T: Number> MulAssign<T> for Size<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.width *= rhs;
+        self.height *= rhs;
+    }
+}
+forward_ref_op_assign!(impl<T> MulAssign, mul_assign for Size<T>, T where T: Number);
+
+impl<T: Number> Div<T> for Size<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self::Output {
+        Self {
+            width: self.width / rhs,
+            height: self.height / rhs,
+        }
+    }
+}
+forward_ref_binop!(impl<T> Div, div for Size<T>, T where T: Number);
+
+impl<T: Number> DivAssign<T> for Size<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.width /= rhs;
+        self.height /= rhs;
+    }
 }
+forward_ref_op_assign!(impl<T> DivAssign, div_assign for Size<T>, T where T: Number);
 
 impl<T: Number> From<Vector2<T>> for Size<T> {
     fn from(point: Vector2<T>) -> Self {
@@ -41,25 +163,28 @@ impl<T: Number> From<Vector2<T>> for Size<T> {
     }
 }
 
-#[cfg(target_os = "windows")]
-use windows::Win32::Graphics::Direct2D::Common::D2D_SIZE_F;
+#[cfg(all(target_os = "windows", feature = "renderer-d3d12"))]
+use windows::Win32::Graphics::Dxgi::DXGI_SWAP_CHAIN_DESC1;
 
-#[cfg(target_os = "windows")]
-impl Into<D2D_SIZE_F> for Size<f32> {
-    fn into(self) -> D2D_SIZE_F {
-        D2D_SIZE_F {
-            width: self.width,
-            height: self.height,
+#[cfg(all(target_os = "windows", feature = "renderer-d3d12"))]
+impl Size<u32> {
+    /// Returns `desc` with the width and height of its buffers set to this size.
+    pub fn to_swap_chain_desc(self, desc: DXGI_SWAP_CHAIN_DESC1) -> DXGI_SWAP_CHAIN_DESC1 {
+        DXGI_SWAP_CHAIN_DESC1 {
+            Width: self.width,
+            Height: self.height,
+            ..desc
         }
     }
 }
 
-#[cfg(target_os = "windows")]
-impl From<D2D_SIZE_F> for Size<f32> {
-    fn from(value: D2D_SIZE_F) -> Self {
+/// Size of the buffers of the swap chain described by `desc`.
+#[cfg(all(target_os = "windows", feature = "renderer-d3d12"))]
+impl From<&DXGI_SWAP_CHAIN_DESC1> for Size<u32> {
+    fn from(desc: &DXGI_SWAP_CHAIN_DESC1) -> Self {
         Self {
-            width: value.width,
-            height: value.height,
+            width: desc.Width,
+            height: desc.Height,
         }
     }
 }
@@ -0,0 +1,157 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Position, rotation and scale of 2D objects such as sprites, kept apart instead of multiplied
+//! into a matrix so each can be read and animated on its own.
+
+use super::{wrap_angle_radians, Matrix3x3, Vector2, Vector3};
+
+/// Largest error `Transform2D::from_matrix3` accepts in the last row of a matrix and, relative to
+/// the lengths of the columns, in their dot product.
+const DECOMPOSITION_TOLERANCE: f32 = 1e-5;
+
+/// Transform of a 2D object, applied to points in the order scale, rotation, translation.
+///
+/// ```
+/// use std::f32::consts::FRAC_PI_2;
+/// use sky_labs::math::{Transform2D, Vector2};
+///
+/// let sprite = Transform2D::new(Vector2::new(10.0, 0.0), FRAC_PI_2, Vector2::new(2.0, 2.0));
+/// let corner = sprite.transform_point(Vector2::new(1.0, 0.0));
+/// assert!((corner - Vector2::new(10.0, 2.0)).magnitude() < 1e-6);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Transform2D {
+    /// Translation, applied last.
+    pub position: Vector2<f32>,
+    /// Counter-clockwise rotation around the origin, in radians, applied after the scale.
+    pub rotation: f32,
+    /// Scale along the X and Y axes of the object, applied first. Negative values mirror it.
+    pub scale: Vector2<f32>,
+}
+
+impl Transform2D {
+    /// The transform leaving points unchanged.
+    pub const IDENTITY: Self = Self {
+        position: Vector2::new(0.0, 0.0),
+        rotation: 0.0,
+        scale: Vector2::new(1.0, 1.0),
+    };
+
+    /// Creates a transform from its position, rotation in radians and scale.
+    pub const fn new(position: Vector2<f32>, rotation: f32, scale: Vector2<f32>) -> Self {
+        Self {
+            position,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Returns the matrix applying the transform to points `(x, y, 1)`, see
+    /// `Matrix3x3::transform_point_2d`.
+    pub fn to_matrix3(&self) -> Matrix3x3<f32> {
+        let (sin, cos) = self.rotation.sin_cos();
+        Matrix3x3::from_columns([
+            Vector3::new(cos * self.scale.x, sin * self.scale.x, 0.0),
+            Vector3::new(-sin * self.scale.y, cos * self.scale.y, 0.0),
+            Vector3::new(self.position.x, self.position.y, 1.0),
+        ])
+    }
+
+    /// Splits an affine matrix into a transform, the inverse of `to_matrix3`.
+    /// Returns `None` if the matrix has a projection, a skew or a zero scale, which a transform
+    /// can't hold. A mirrored matrix gets a negative Y scale, and the rotation is in `[-π, π]`.
+    pub fn from_matrix3(matrix: &Matrix3x3<f32>) -> Option<Self> {
+        let last_row = matrix[2];
+        if last_row.x.abs() > DECOMPOSITION_TOLERANCE
+            || last_row.y.abs() > DECOMPOSITION_TOLERANCE
+            || (last_row.z - 1.0).abs() > DECOMPOSITION_TOLERANCE
+        {
+            return None;
+        }
+        let x_axis = Vector2::new(matrix[0][0], matrix[1][0]);
+        let y_axis = Vector2::new(matrix[0][1], matrix[1][1]);
+        let scale_x = x_axis.magnitude() as f32;
+        let scale_y = y_axis.magnitude() as f32;
+        if scale_x == 0.0
+            || scale_y == 0.0
+            || x_axis.dot(y_axis).abs() > DECOMPOSITION_TOLERANCE * scale_x * scale_y
+        {
+            return None;
+        }
+        Some(Self {
+            position: Vector2::new(matrix[0][2], matrix[1][2]),
+            rotation: x_axis.y.atan2(x_axis.x),
+            scale: Vector2::new(scale_x, scale_y * x_axis.cross(&y_axis).signum()),
+        })
+    }
+
+    /// Returns the transform applying this one and then `other`, such as the transform of a
+    /// sprite relative to its parent then the transform of the parent.
+    ///
+    /// Rotations add and scales multiply. This is exact when `other` scales uniformly or this
+    /// transform isn't rotated: otherwise the result would need a skew, and the scale of `other`
+    /// is applied along the rotated axes instead.
+    pub fn then(&self, other: &Self) -> Self {
+        Self {
+            position: other.transform_point(self.position),
+            rotation: self.rotation + other.rotation,
+            scale: Vector2::new(self.scale.x * other.scale.x, self.scale.y * other.scale.y),
+        }
+    }
+
+    /// Returns the transform undoing this one, or `None` if it has a zero scale, or a different
+    /// scale on each axis together with a rotation, whose inverse would need a skew.
+    /// Use the inverse of `to_matrix3` for those.
+    pub fn inverse(&self) -> Option<Self> {
+        Self::from_matrix3(&self.to_matrix3().inverse()?)
+    }
+
+    /// Applies the transform to a point: scales, rotates then translates it.
+    pub fn transform_point(&self, point: Vector2<f32>) -> Vector2<f32> {
+        self.transform_vector(point) + self.position
+    }
+
+    /// Applies the transform to a direction, ignoring the position: scales then rotates it.
+    pub fn transform_vector(&self, vector: Vector2<f32>) -> Vector2<f32> {
+        let (sin, cos) = self.rotation.sin_cos();
+        let x = vector.x * self.scale.x;
+        let y = vector.y * self.scale.y;
+        Vector2::new(cos * x - sin * y, sin * x + cos * y)
+    }
+
+    /// Interpolates between this transform and `other` by `t`, `t = 0` returning this transform
+    /// and `t = 1` a transform equivalent to `other`. Position and scale are interpolated
+    /// linearly, and the rotation along the shortest arc: from 350 to 10 degrees it goes through
+    /// 0 degrees, not 180, so the result can be outside of `[-π, π)`.
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        let rotation_delta = wrap_angle_radians(other.rotation - self.rotation);
+        Self {
+            position: self.position.lerp(&other.position, t as f64),
+            rotation: self.rotation + rotation_delta * t,
+            scale: self.scale.lerp(&other.scale, t as f64),
+        }
+    }
+}
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
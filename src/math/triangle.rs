@@ -0,0 +1,245 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Triangle queries for meshes and picking: area, normal, barycentric coordinates, closest
+//! points and ray intersection, and the triangulation of convex polygons. Each function comes
+//! in an `f32` and an `f64` version.
+//!
+//! A triangle is degenerate when its corners are collinear, which includes slivers too thin
+//! for their normal to be represented. Points on an edge or a corner count as inside.
+
+use super::{FloatingPointNumber, Vector2, Vector3};
+
+/// Intersection of a ray with a triangle, as returned by `ray_intersection_f32` and
+/// `ray_intersection_f64`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RayHit<T: FloatingPointNumber> {
+    /// Distance along the ray in multiples of its direction: the point hit is `origin + direction * t`.
+    pub t: T,
+    /// Barycentric coordinates of the point hit, the weights of the corners `a`, `b` and `c`.
+    pub barycentric: Vector3<T>,
+}
+
+macro_rules! impl_triangle {
+    ($($t:ty, $area:ident, $normal:ident, $barycentric:ident, $contains_point_2d:ident,
+        $triangulate_convex:ident, $closest_point:ident, $closest_point_on_segment:ident,
+        $ray_intersection:ident;)*) => ($(
+        /// Returns the area of the triangle `a`, `b`, `c`, zero if it is degenerate.
+        pub fn $area(a: &Vector3<$t>, b: &Vector3<$t>, c: &Vector3<$t>) -> $t {
+            let normal = (*b - *a).cross(&(*c - *a));
+            normal.norm_squared().sqrt() / 2.0
+        }
+
+        /// Returns the unit normal of the triangle `a`, `b`, `c`, facing the side the corners
+        /// are seen counter-clockwise from in a right-handed coordinate system.
+        /// Returns `None` if the triangle is degenerate.
+        pub fn $normal(a: &Vector3<$t>, b: &Vector3<$t>, c: &Vector3<$t>) -> Option<Vector3<$t>> {
+            (*b - *a).cross(&(*c - *a)).try_normalize()
+        }
+
+        /// Returns the barycentric coordinates of `p` in the triangle `a`, `b`, `c`: the weights
+        /// of `a`, `b` and `c`, summing to one, whose weighted sum of the corners is `p`.
+        /// A point off the plane of the triangle is projected onto it first.
+        /// All the coordinates are between 0 and 1 for points inside the triangle.
+        /// Returns `None` if the triangle is degenerate.
+        pub fn $barycentric(
+            p: &Vector3<$t>,
+            a: &Vector3<$t>,
+            b: &Vector3<$t>,
+            c: &Vector3<$t>,
+        ) -> Option<Vector3<$t>> {
+            let normal = (*b - *a).cross(&(*c - *a));
+            let area_squared = normal.norm_squared();
+            if area_squared == 0.0 {
+                return None;
+            }
+            // Each weight is the signed area of the triangle `p` forms with the opposite edge.
+            let u = (*c - *b).cross(&(*p - *b)).dot(&normal) / area_squared;
+            let v = (*a - *c).cross(&(*p - *c)).dot(&normal) / area_squared;
+            Some(Vector3::new(u, v, 1.0 - u - v))
+        }
+
+        /// Returns whether `p` is inside the 2D triangle `a`, `b`, `c`, in either winding.
+        /// Points on an edge or a corner are inside, and degenerate triangles contain no point.
+        pub fn $contains_point_2d(
+            p: &Vector2<$t>,
+            a: &Vector2<$t>,
+            b: &Vector2<$t>,
+            c: &Vector2<$t>,
+        ) -> bool {
+            let area = (*b - *a).cross(&(*c - *a));
+            if area == 0.0 {
+                return false;
+            }
+            // The point is on the inner side of the three edges, whose sign follows the winding.
+            let edges = [
+                (*b - *a).cross(&(*p - *a)),
+                (*c - *b).cross(&(*p - *b)),
+                (*a - *c).cross(&(*p - *c)),
+            ];
+            edges.iter().all(|&edge| edge * area.signum() >= 0.0)
+        }
+
+        /// Splits the convex polygon `points` into a fan of triangles around its first point,
+        /// returned as indices of their corners in `points`. The corners of each triangle are
+        /// ordered counter-clockwise with the y axis up, clockwise with it down, whatever the
+        /// winding of `points`. Degenerate triangles, where corners are collinear, are skipped,
+        /// and fewer than 3 points give no triangle. The result is meaningless for concave polygons.
+        pub fn $triangulate_convex(points: &[Vector2<$t>]) -> Vec<[usize; 3]> {
+            let mut triangles = Vec::with_capacity(points.len().saturating_sub(2));
+            for i in 1..points.len().saturating_sub(1) {
+                let area = (points[i] - points[0]).cross(&(points[i + 1] - points[0]));
+                if area > 0.0 {
+                    triangles.push([0, i, i + 1]);
+                } else if area < 0.0 {
+                    triangles.push([0, i + 1, i]);
+                }
+            }
+            triangles
+        }
+
+        /// Returns the point of the triangle `a`, `b`, `c` closest to `p`, which is `p` itself
+        /// if it is inside the triangle. Degenerate triangles are treated as their edges.
+        pub fn $closest_point(
+            p: &Vector3<$t>,
+            a: &Vector3<$t>,
+            b: &Vector3<$t>,
+            c: &Vector3<$t>,
+        ) -> Vector3<$t> {
+            let ab = *b - *a;
+            let ac = *c - *a;
+            if ab.cross(&ac).norm_squared() == 0.0 {
+                return [
+                    $closest_point_on_segment(p, a, b),
+                    $closest_point_on_segment(p, b, c),
+                    $closest_point_on_segment(p, c, a),
+                ]
+                .into_iter()
+                .min_by(|x, y| (*x - *p).norm_squared().total_cmp(&(*y - *p).norm_squared()))
+                .unwrap();
+            }
+
+            // Finds the Voronoi region of the triangle `p` is in, from Ericson's
+            // "Real-Time Collision Detection", 5.1.5.
+            let ap = *p - *a;
+            let d1 = ab.dot(&ap);
+            let d2 = ac.dot(&ap);
+            if d1 <= 0.0 && d2 <= 0.0 {
+                return *a;
+            }
+
+            let bp = *p - *b;
+            let d3 = ab.dot(&bp);
+            let d4 = ac.dot(&bp);
+            if d3 >= 0.0 && d4 <= d3 {
+                return *b;
+            }
+
+            let vc = d1 * d4 - d3 * d2;
+            if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+                return *a + ab * (d1 / (d1 - d3));
+            }
+
+            let cp = *p - *c;
+            let d5 = ab.dot(&cp);
+            let d6 = ac.dot(&cp);
+            if d6 >= 0.0 && d5 <= d6 {
+                return *c;
+            }
+
+            let vb = d5 * d2 - d1 * d6;
+            if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+                return *a + ac * (d2 / (d2 - d6));
+            }
+
+            let va = d3 * d6 - d5 * d4;
+            if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+                return *b + (*c - *b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+            }
+
+            let denominator = va + vb + vc;
+            *a + ab * (vb / denominator) + ac * (vc / denominator)
+        }
+
+        /// Returns the point of the segment from `a` to `b` closest to `p`.
+        fn $closest_point_on_segment(
+            p: &Vector3<$t>,
+            a: &Vector3<$t>,
+            b: &Vector3<$t>,
+        ) -> Vector3<$t> {
+            let ab = *b - *a;
+            let length_squared = ab.norm_squared();
+            if length_squared == 0.0 {
+                return *a;
+            }
+            let t = ((*p - *a).dot(&ab) / length_squared).clamp(0.0, 1.0);
+            *a + ab * t
+        }
+
+        /// Intersects the ray from `origin` along `direction` with the triangle `a`, `b`, `c`,
+        /// using the Möller–Trumbore algorithm. Both faces are hit, and so are edges and corners.
+        /// Returns `None` if the ray misses, if the triangle is behind the origin, or if the ray
+        /// is parallel to the triangle or the triangle is degenerate.
+        pub fn $ray_intersection(
+            origin: &Vector3<$t>,
+            direction: &Vector3<$t>,
+            a: &Vector3<$t>,
+            b: &Vector3<$t>,
+            c: &Vector3<$t>,
+        ) -> Option<RayHit<$t>> {
+            let ab = *b - *a;
+            let ac = *c - *a;
+            let p = direction.cross(&ac);
+            let determinant = ab.dot(&p);
+            // The determinant is the cosine of the angle between the ray and the plane normal,
+            // scaled by the lengths of `direction`, `ab` and `ac`: compare it relative to them.
+            let scale = (ab.cross(&ac).norm_squared() * direction.norm_squared()).sqrt();
+            if determinant.abs() <= <$t>::EPSILON * scale {
+                return None;
+            }
+
+            let inverse_determinant = 1.0 / determinant;
+            let ao = *origin - *a;
+            let u = ao.dot(&p) * inverse_determinant;
+            if !(0.0..=1.0).contains(&u) {
+                return None;
+            }
+            let q = ao.cross(&ab);
+            let v = direction.dot(&q) * inverse_determinant;
+            if v < 0.0 || u + v > 1.0 {
+                return None;
+            }
+            let t = ac.dot(&q) * inverse_determinant;
+            if t < 0.0 {
+                return None;
+            }
+            Some(RayHit {
+                t,
+                barycentric: Vector3::new(1.0 - u - v, u, v),
+            })
+        }
+    )*)
+}
+
+impl_triangle! {
+    f32, area_f32, normal_f32, barycentric_f32, contains_point_2d_f32, triangulate_convex_f32,
+        closest_point_f32, closest_point_on_segment_f32, ray_intersection_f32;
+    f64, area_f64, normal_f64, barycentric_f64, contains_point_2d_f64, triangulate_convex_f64,
+        closest_point_f64, closest_point_on_segment_f64, ray_intersection_f64;
+}
@@ -17,18 +17,36 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::ops::{
     Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
 };
 
-use crate::math::{Number, SignedNumber};
+use crate::math::easing::{ease, Easing};
+use crate::math::{
+    f16_bits_to_f32, f32_to_f16_bits, Angle, IntegerNumber, Number, SignedInteger, SignedNumber,
+    Wrap, NORMALIZED_EPSILON,
+};
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Default)]
+#[repr(C)]
 pub struct Vector2<T: Number> {
     pub x: T,
     pub y: T,
 }
 
+impl<T: IntegerNumber> Eq for Vector2<T> {}
+
+implement_lexicographic_ord!(Vector2, x, y);
+
+impl<T: IntegerNumber> Hash for Vector2<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.y.hash(state);
+    }
+}
+
 impl<T: SignedNumber> Neg for Vector2<T> {
     type Output = Self;
 
@@ -107,6 +125,8 @@ implement_scalar_lhs_mul! {
     Vector2<f64>, f64
 }
 
+implement_vector_reductions!(Vector2);
+
 impl<T: Number> MulAssign<T> for Vector2<T> {
     #[inline]
     fn mul_assign(&mut self, rhs: T) {
@@ -186,25 +206,31 @@ impl<T: Number> IndexMut<usize> for Vector2<T> {
 }
 
 impl<T: Number> Vector2<T> {
+    /// A vector with all components set to zero.
+    pub const ZERO: Self = Self {
+        x: T::ZERO,
+        y: T::ZERO,
+    };
+
+    /// A vector with all components set to one.
+    pub const ONE: Self = Self {
+        x: T::ONE,
+        y: T::ONE,
+    };
+
     /// Creates a new `Vector2` with the given x and y components.
     pub const fn new(x: T, y: T) -> Self {
         Self { x, y }
     }
 
     /// Returns a default `Vector2` with both components set to zero.
-    pub fn zero() -> Self {
-        Self {
-            x: T::zero(),
-            y: T::zero(),
-        }
+    pub const fn zero() -> Self {
+        Self::ZERO
     }
 
     /// Returns a `Vector2` with both components set to one.
-    pub fn one() -> Self {
-        Self {
-            x: T::one(),
-            y: T::one(),
-        }
+    pub const fn one() -> Self {
+        Self::ONE
     }
 
     /// Returns the modulus (length) of the vector.
@@ -228,14 +254,36 @@ impl<T: Number> Vector2<T> {
 
     /// Returns the distance to another vector.
     pub fn distance_to(&self, other: &Vector2<T>) -> f64 {
-        let diff = *self - *other;
+        let diff = Self {
+            x: T::abs_diff(self.x, other.x),
+            y: T::abs_diff(self.y, other.y),
+        };
         let norm_squared: f64 = diff.norm_squared().as_double();
         f64::sqrt(norm_squared)
     }
 
+    /// Linearly interpolates between this vector and `other` by `t`.
+    /// `t = 0` returns this vector and `t = 1` returns `other`, values outside `[0, 1]` extrapolate.
+    pub fn lerp(&self, other: &Self, t: f64) -> Self {
+        let lerp = |a: T, b: T| {
+            let a: f64 = a.as_double();
+            T::from_double(a + (b.as_double() - a) * t)
+        };
+        Self {
+            x: lerp(self.x, other.x),
+            y: lerp(self.y, other.y),
+        }
+    }
+
+    /// Interpolates between this vector and `target` like `lerp`, by `t` eased by `kind`.
+    /// `t` is clamped to `[0, 1]`, but easings such as back or elastic may still overshoot.
+    pub fn ease_to(&self, target: &Self, kind: Easing, t: f64) -> Self {
+        self.lerp(target, ease(kind, t))
+    }
+
     /// Returns the taxicab distance (Manhattan distance) to another vector.
     pub fn taxicab_distance(&self, other: Vector2<T>) -> T {
-        T::abs(self.x - other.x) + T::abs(self.y - other.y)
+        T::abs_diff(self.x, other.x) + T::abs_diff(self.y, other.y)
     }
 
     /// Returns the dot product of this vector with another vector.
@@ -244,8 +292,9 @@ impl<T: Number> Vector2<T> {
     }
 
     /// Returns a normalized version of the vector.
-    /// If the vector is zero, it returns the original vector.
+    /// If the vector is zero, it returns the original vector, see `try_normalize` to detect it.
     pub fn normalize(&self) -> Self {
+        debug_assert!(self.is_finite(), "vector must be finite");
         let length = self.modulus();
         if length == 0.0 {
             return *self;
@@ -258,19 +307,117 @@ impl<T: Number> Vector2<T> {
         }
     }
 
+    /// Returns a normalized version of the vector, or `None` if the vector isn't finite, is zero
+    /// or is too short for the result to be normalized.
+    pub fn try_normalize(&self) -> Option<Self> {
+        if !self.is_finite() {
+            return None;
+        }
+        let normalized = self.normalize();
+        if normalized.is_normalized() {
+            Some(normalized)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a normalized version of the vector, or `fallback` if it can't be normalized.
+    pub fn normalize_or(&self, fallback: Self) -> Self {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    /// Returns a normalized version of the vector, or zero if it can't be normalized, like a
+    /// non-finite vector or one too short to have a direction.
+    pub fn normalize_or_zero(&self) -> Self {
+        self.normalize_or(Self::ZERO)
+    }
+
+    /// Returns whether every component is finite, neither infinite nor NaN.
+    /// Always true for integer vectors.
+    pub fn is_finite(&self) -> bool {
+        self.x.as_double().is_finite() && self.y.as_double().is_finite()
+    }
+
+    /// Checks if the vector is normalized (length is 1), with `|length² - 1|` within
+    /// `NORMALIZED_EPSILON`.
+    pub fn is_normalized(&self) -> bool {
+        self.is_normalized_within(NORMALIZED_EPSILON)
+    }
+
+    /// Checks if the vector is normalized (length is 1), with `|length² - 1|` within `epsilon`.
+    pub fn is_normalized_within(&self, epsilon: f64) -> bool {
+        let length_squared: f64 = self.norm_squared().as_double();
+        (length_squared - 1.0).abs() <= epsilon
+    }
+
     /// Rotates the vector around the origin by the given angle in radians.
     /// The rotation is counter-clockwise.
+    /// The components are rotated in `f64` and converted back, so integer vectors round once.
+    /// `f32` vectors can then differ in the last bit from `Matrix3x3::<f32>::make_rotation_2d`,
+    /// which `rotate_f32` matches by staying in `f32`.
+    /// Prefer `rotate_angle`, which takes an `Angle`.
     pub fn rotate(&self, rad: f64) -> Self {
         let cos = rad.cos();
         let sin = rad.sin();
         let x: f64 = self.x.as_double();
         let y: f64 = self.y.as_double();
         Self {
-            x: T::from_double(x * cos + y * sin),
+            x: T::from_double(x * cos - y * sin),
             y: T::from_double(x * sin + y * cos),
         }
     }
 
+    /// Same as `rotate`, taking an `Angle` instead of radians.
+    pub fn rotate_angle(&self, angle: Angle<f64>) -> Self {
+        self.rotate(angle.to_radians())
+    }
+
+    /// Rotates the vector around the `pivot` point by the given angle in radians.
+    /// The rotation is counter-clockwise. Computed in `f64` like `rotate`, see `rotate_around_f32`.
+    /// Prefer `rotate_around_angle`, which takes an `Angle`.
+    pub fn rotate_around(&self, pivot: &Vector2<T>, rad: f64) -> Self {
+        let cos = rad.cos();
+        let sin = rad.sin();
+        let pivot_x: f64 = pivot.x.as_double();
+        let pivot_y: f64 = pivot.y.as_double();
+        let x: f64 = self.x.as_double() - pivot_x;
+        let y: f64 = self.y.as_double() - pivot_y;
+        Self {
+            x: T::from_double(pivot_x + x * cos - y * sin),
+            y: T::from_double(pivot_y + x * sin + y * cos),
+        }
+    }
+
+    /// Same as `rotate_around`, taking an `Angle` instead of radians.
+    pub fn rotate_around_angle(&self, pivot: &Vector2<T>, angle: Angle<f64>) -> Self {
+        self.rotate_around(pivot, angle.to_radians())
+    }
+
+    /// Returns the angle of the vector in radians, measured counter-clockwise from the X axis.
+    /// The result is in the range `[-PI, PI]`.
+    pub fn angle(&self) -> f64 {
+        f64::atan2(self.y.as_double(), self.x.as_double())
+    }
+
+    /// Returns the signed angle in radians to rotate this vector counter-clockwise to
+    /// the direction of `other`. The result is in the range `[-PI, PI]`.
+    pub fn angle_to(&self, other: &Vector2<T>) -> f64 {
+        let x: f64 = self.x.as_double();
+        let y: f64 = self.y.as_double();
+        let other_x: f64 = other.x.as_double();
+        let other_y: f64 = other.y.as_double();
+        f64::atan2(x * other_y - y * other_x, x * other_x + y * other_y)
+    }
+
+    /// Creates a vector with the given length pointing at the angle in radians,
+    /// measured counter-clockwise from the X axis.
+    pub fn from_angle(rad: f64, length: f64) -> Self {
+        Self {
+            x: T::from_double(rad.cos() * length),
+            y: T::from_double(rad.sin() * length),
+        }
+    }
+
     pub const fn from_array(arr: [T; 2]) -> Self {
         Self {
             x: arr[0],
@@ -302,190 +449,155 @@ impl<T: Number> Vector2<T> {
 
     /// Returns a pointer to the vector's data.
     /// This is unsafe because it allows direct access to the vector's memory without bounds check.
+    ///
+    /// # Safety
+    /// Only the 2 components may be accessed through the pointer, while the vector is borrowed.
     pub const unsafe fn as_ptr(&self) -> *const T {
         &self.x as *const T
     }
 
     /// Returns a mutable pointer to the vector's data.
     /// This is unsafe because it allows direct access to the vector's memory without bounds check.
+    ///
+    /// # Safety
+    /// Only the 2 components may be accessed through the pointer, while the vector is borrowed.
     pub const unsafe fn as_mut_ptr(&mut self) -> *mut T {
         &mut self.x as *mut T
     }
 }
 
-/// Windows-specific implementation for Direct2D compatibility.
-
-#[cfg(target_os = "windows")]
-use windows::Win32::Graphics::Direct2D::Common::{
-    D2D_POINT_2F, D2D_POINT_2U, D2D_SIZE_F, D2D_SIZE_U, D2D_VECTOR_2F,
-};
-
-#[cfg(target_os = "windows")]
-impl Into<D2D_SIZE_F> for Vector2<f32> {
-    fn into(self) -> D2D_SIZE_F {
-        D2D_SIZE_F {
-            width: self.x,
-            height: self.y,
-        }
-    }
-}
-
-#[cfg(target_os = "windows")]
-impl<'a> Into<&'a D2D_SIZE_F> for &'a Vector2<f32> {
-    fn into(self) -> &'a D2D_SIZE_F {
-        unsafe { std::mem::transmute(self) }
-    }
-}
-
-#[cfg(target_os = "windows")]
-impl From<D2D_SIZE_F> for Vector2<f32> {
-    fn from(value: D2D_SIZE_F) -> Self {
+impl<T: Wrap> Vector2<T> {
+    /// Wraps each component into `[min, max)` of the matching components of `min` and `max`.
+    /// See `Wrap::wrap`.
+    pub fn wrap(&self, min: &Self, max: &Self) -> Self {
         Self {
-            x: value.width,
-            y: value.height,
+            x: self.x.wrap(min.x, max.x),
+            y: self.y.wrap(min.y, max.y),
         }
     }
 }
 
-#[cfg(target_os = "windows")]
-impl<'a> From<&'a D2D_SIZE_F> for &'a Vector2<f32> {
-    fn from(value: &'a D2D_SIZE_F) -> Self {
-        unsafe { std::mem::transmute(value) }
-    }
-}
-
-#[cfg(target_os = "windows")]
-impl Into<D2D_SIZE_U> for Vector2<u32> {
-    fn into(self) -> D2D_SIZE_U {
-        D2D_SIZE_U {
-            width: self.x,
-            height: self.y,
-        }
+impl<T: IntegerNumber> Vector2<T> {
+    /// Adds another vector component-wise, returning `None` if any component overflows.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add(other.x)?,
+            y: self.y.checked_add(other.y)?,
+        })
     }
-}
 
-#[cfg(target_os = "windows")]
-impl<'a> Into<&'a D2D_SIZE_U> for &'a Vector2<u32> {
-    fn into(self) -> &'a D2D_SIZE_U {
-        unsafe { std::mem::transmute(self) }
+    /// Subtracts another vector component-wise, returning `None` if any component overflows.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_sub(other.x)?,
+            y: self.y.checked_sub(other.y)?,
+        })
     }
-}
 
-#[cfg(target_os = "windows")]
-impl From<D2D_SIZE_U> for Vector2<u32> {
-    fn from(value: D2D_SIZE_U) -> Self {
+    /// Adds another vector component-wise, clamping each component at the numeric bounds.
+    pub fn saturating_add(&self, other: &Self) -> Self {
         Self {
-            x: value.width,
-            y: value.height,
+            x: self.x.saturating_add(other.x),
+            y: self.y.saturating_add(other.y),
         }
     }
-}
-
-#[cfg(target_os = "windows")]
-impl<'a> From<&'a D2D_SIZE_U> for &'a Vector2<u32> {
-    fn from(value: &'a D2D_SIZE_U) -> Self {
-        unsafe { std::mem::transmute(value) }
-    }
-}
 
-#[cfg(target_os = "windows")]
-impl Into<D2D_POINT_2F> for Vector2<f32> {
-    fn into(self) -> D2D_POINT_2F {
-        D2D_POINT_2F {
-            x: self.x,
-            y: self.y,
+    /// Subtracts another vector component-wise, clamping each component at the numeric bounds.
+    pub fn saturating_sub(&self, other: &Self) -> Self {
+        Self {
+            x: self.x.saturating_sub(other.x),
+            y: self.y.saturating_sub(other.y),
         }
     }
-}
 
-#[cfg(target_os = "windows")]
-impl<'a> Into<&'a D2D_POINT_2F> for &'a Vector2<f32> {
-    fn into(self) -> &'a D2D_POINT_2F {
-        unsafe { std::mem::transmute(self) }
+    /// Adds another vector component-wise, wrapping around at the numeric bounds.
+    pub fn wrapping_add(&self, other: &Self) -> Self {
+        Self {
+            x: self.x.wrapping_add(other.x),
+            y: self.y.wrapping_add(other.y),
+        }
     }
-}
 
-#[cfg(target_os = "windows")]
-impl From<D2D_POINT_2F> for Vector2<f32> {
-    fn from(value: D2D_POINT_2F) -> Self {
+    /// Subtracts another vector component-wise, wrapping around at the numeric bounds.
+    pub fn wrapping_sub(&self, other: &Self) -> Self {
         Self {
-            x: value.x,
-            y: value.y,
+            x: self.x.wrapping_sub(other.x),
+            y: self.y.wrapping_sub(other.y),
         }
     }
 }
 
-#[cfg(target_os = "windows")]
-impl<'a> From<&'a D2D_POINT_2F> for &'a Vector2<f32> {
-    fn from(value: &'a D2D_POINT_2F) -> Self {
-        unsafe { std::mem::transmute(value) }
+impl<T: SignedInteger> Vector2<T> {
+    /// Converts to a vector of the unsigned type with the same width,
+    /// returning `None` if any component is negative.
+    pub fn try_cast_to_unsigned(&self) -> Option<Vector2<T::Unsigned>> {
+        Some(Vector2 {
+            x: self.x.try_to_unsigned()?,
+            y: self.y.try_to_unsigned()?,
+        })
     }
 }
 
-#[cfg(target_os = "windows")]
-impl Into<D2D_POINT_2U> for Vector2<u32> {
-    fn into(self) -> D2D_POINT_2U {
-        D2D_POINT_2U {
-            x: self.x,
-            y: self.y,
-        }
+impl<T: SignedNumber> Vector2<T> {
+    /// Returns the 2D cross product of this vector with another vector.
+    /// This is the z component of the 3D cross product of both vectors on the XY plane,
+    /// it is positive when `other` is counter-clockwise from this vector.
+    /// Only available for signed types, like `Vector3::cross`.
+    pub fn cross(&self, other: &Vector2<T>) -> T {
+        self.x * other.y - self.y * other.x
     }
-}
 
-#[cfg(target_os = "windows")]
-impl<'a> Into<&'a D2D_POINT_2U> for &'a Vector2<u32> {
-    fn into(self) -> &'a D2D_POINT_2U {
-        unsafe { std::mem::transmute(self) }
+    /// Returns the vector rotated by 90 degrees counter-clockwise.
+    pub fn perpendicular(&self) -> Self {
+        Self {
+            x: -self.y,
+            y: self.x,
+        }
     }
 }
 
-#[cfg(target_os = "windows")]
-impl From<D2D_POINT_2U> for Vector2<u32> {
-    fn from(value: D2D_POINT_2U) -> Self {
+impl Vector2<f32> {
+    /// Rotates the vector around the origin by the given angle in radians, counter-clockwise.
+    /// Unlike `rotate`, computed in `f32` throughout, giving the same result as multiplying by
+    /// `Matrix3x3::<f32>::make_rotation_2d(rad)`.
+    /// Prefer `rotate_angle_f32`, which takes an `Angle`.
+    pub fn rotate_f32(&self, rad: f32) -> Self {
+        let cos = rad.cos();
+        let sin = rad.sin();
         Self {
-            x: value.x,
-            y: value.y,
+            x: self.x * cos - self.y * sin,
+            y: self.x * sin + self.y * cos,
         }
     }
-}
 
-#[cfg(target_os = "windows")]
-impl<'a> From<&'a D2D_POINT_2U> for &'a Vector2<u32> {
-    fn from(value: &'a D2D_POINT_2U) -> Self {
-        unsafe { std::mem::transmute(value) }
+    /// Same as `rotate_f32`, taking an `Angle` instead of radians.
+    pub fn rotate_angle_f32(&self, angle: Angle<f32>) -> Self {
+        self.rotate_f32(angle.to_radians())
     }
-}
 
-#[cfg(target_os = "windows")]
-impl Into<D2D_VECTOR_2F> for Vector2<f32> {
-    fn into(self) -> D2D_VECTOR_2F {
-        D2D_VECTOR_2F {
-            x: self.x,
-            y: self.y,
-        }
+    /// Rotates the vector around the `pivot` point by the given angle in radians,
+    /// counter-clockwise. Unlike `rotate_around`, computed in `f32` throughout.
+    /// Prefer `rotate_around_angle_f32`, which takes an `Angle`.
+    pub fn rotate_around_f32(&self, pivot: &Self, rad: f32) -> Self {
+        *pivot + (*self - *pivot).rotate_f32(rad)
     }
-}
 
-#[cfg(target_os = "windows")]
-impl<'a> Into<&'a D2D_VECTOR_2F> for &'a Vector2<u32> {
-    fn into(self) -> &'a D2D_VECTOR_2F {
-        unsafe { std::mem::transmute(self) }
+    /// Same as `rotate_around_f32`, taking an `Angle` instead of radians.
+    pub fn rotate_around_angle_f32(&self, pivot: &Self, angle: Angle<f32>) -> Self {
+        self.rotate_around_f32(pivot, angle.to_radians())
     }
-}
 
-#[cfg(target_os = "windows")]
-impl From<D2D_VECTOR_2F> for Vector2<f32> {
-    fn from(value: D2D_VECTOR_2F) -> Self {
-        Self {
-            x: value.x,
-            y: value.y,
-        }
+    /// Converts the components to half-precision float bits, rounding ties to even.
+    /// See `f32_to_f16_bits`.
+    pub fn to_f16_bits(&self) -> [u16; 2] {
+        [f32_to_f16_bits(self.x), f32_to_f16_bits(self.y)]
     }
-}
 
-#[cfg(target_os = "windows")]
-impl<'a> From<&'a D2D_VECTOR_2F> for &'a Vector2<f32> {
-    fn from(value: &'a D2D_VECTOR_2F) -> Self {
-        unsafe { std::mem::transmute(value) }
+    /// Creates a vector from half-precision float bits, as returned by `to_f16_bits`.
+    pub fn from_f16_bits(bits: [u16; 2]) -> Self {
+        Self {
+            x: f16_bits_to_f32(bits[0]),
+            y: f16_bits_to_f32(bits[1]),
+        }
     }
 }
@@ -17,11 +17,15 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::ops::{
     Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
 };
 
-use crate::math::number::{Number, SignedNumber};
+use crate::math::easing::{ease, Easing};
+use crate::math::number::{IntegerNumber, Number, SignedInteger, SignedNumber, Wrap};
+use crate::math::{f16_bits_to_f32, f32_to_f16_bits, Angle, NORMALIZED_EPSILON};
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Default)]
 #[repr(C)]
@@ -31,6 +35,18 @@ pub struct Vector3<T: Number> {
     pub z: T,
 }
 
+impl<T: IntegerNumber> Eq for Vector3<T> {}
+
+implement_lexicographic_ord!(Vector3, x, y, z);
+
+impl<T: IntegerNumber> Hash for Vector3<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.y.hash(state);
+        self.z.hash(state);
+    }
+}
+
 impl<T: SignedNumber> Neg for Vector3<T> {
     type Output = Self;
 
@@ -115,6 +131,8 @@ implement_scalar_lhs_mul! {
     Vector3<f64>, f64
 }
 
+implement_vector_reductions!(Vector3);
+
 impl<T: Number> MulAssign<T> for Vector3<T> {
     #[inline]
     fn mul_assign(&mut self, rhs: T) {
@@ -199,27 +217,33 @@ impl<T: Number> IndexMut<usize> for Vector3<T> {
 }
 
 impl<T: Number> Vector3<T> {
+    /// A vector with all components set to zero.
+    pub const ZERO: Self = Self {
+        x: T::ZERO,
+        y: T::ZERO,
+        z: T::ZERO,
+    };
+
+    /// A vector with all components set to one.
+    pub const ONE: Self = Self {
+        x: T::ONE,
+        y: T::ONE,
+        z: T::ONE,
+    };
+
     /// Creates a new `Vector3` with the given x, y, and z components.
     pub const fn new(x: T, y: T, z: T) -> Self {
         Self { x, y, z }
     }
 
     /// Returns a zero vector.
-    pub fn zero() -> Self {
-        Self {
-            x: T::zero(),
-            y: T::zero(),
-            z: T::zero(),
-        }
+    pub const fn zero() -> Self {
+        Self::ZERO
     }
 
     /// Returns a vector with all components set to one.
-    pub fn one() -> Self {
-        Self {
-            x: T::one(),
-            y: T::one(),
-            z: T::one(),
-        }
+    pub const fn one() -> Self {
+        Self::ONE
     }
 
     /// Returns the modulus (length) of the vector.
@@ -245,23 +269,58 @@ impl<T: Number> Vector3<T> {
     /// Returns the distance to another vector.
     /// This is the Euclidean distance between the two vectors.
     pub fn distance_to(&self, other: &Self) -> f64 {
-        let diff = *self - *other;
+        let diff = Self {
+            x: T::abs_diff(self.x, other.x),
+            y: T::abs_diff(self.y, other.y),
+            z: T::abs_diff(self.z, other.z),
+        };
         let norm_squared = diff.norm_squared().as_double();
         f64::sqrt(norm_squared)
     }
 
+    /// Linearly interpolates between this vector and `other` by `t`.
+    /// `t = 0` returns this vector and `t = 1` returns `other`, values outside `[0, 1]` extrapolate.
+    pub fn lerp(&self, other: &Self, t: f64) -> Self {
+        let lerp = |a: T, b: T| {
+            let a: f64 = a.as_double();
+            T::from_double(a + (b.as_double() - a) * t)
+        };
+        Self {
+            x: lerp(self.x, other.x),
+            y: lerp(self.y, other.y),
+            z: lerp(self.z, other.z),
+        }
+    }
+
+    /// Interpolates between this vector and `target` like `lerp`, by `t` eased by `kind`.
+    /// `t` is clamped to `[0, 1]`, but easings such as back or elastic may still overshoot.
+    pub fn ease_to(&self, target: &Self, kind: Easing, t: f64) -> Self {
+        self.lerp(target, ease(kind, t))
+    }
+
     /// Returns the taxicab distance (Manhattan distance) to another vector.
     pub fn taxicab_distance(&self, other: &Self) -> T {
-        T::abs(self.x - other.x) + T::abs(self.y - other.y) + T::abs(self.z - other.z)
+        T::abs_diff(self.x, other.x) + T::abs_diff(self.y, other.y) + T::abs_diff(self.z, other.z)
     }
 
-    /// Returns the cross product of this vector with another vector.
-    pub fn cross(&self, other: &Self) -> Self {
-        Self {
-            x: self.y * other.z - self.z * other.y,
-            y: self.z * other.x - self.x * other.z,
-            z: self.x * other.y - self.y * other.x,
-        }
+    /// Returns whether every component is finite, neither infinite nor NaN.
+    /// Always true for integer vectors.
+    pub fn is_finite(&self) -> bool {
+        self.x.as_double().is_finite()
+            && self.y.as_double().is_finite()
+            && self.z.as_double().is_finite()
+    }
+
+    /// Checks if the vector is normalized (length is 1), with `|length² - 1|` within
+    /// `NORMALIZED_EPSILON`.
+    pub fn is_normalized(&self) -> bool {
+        self.is_normalized_within(NORMALIZED_EPSILON)
+    }
+
+    /// Checks if the vector is normalized (length is 1), with `|length² - 1|` within `epsilon`.
+    pub fn is_normalized_within(&self, epsilon: f64) -> bool {
+        let length_squared: f64 = self.norm_squared().as_double();
+        (length_squared - 1.0).abs() <= epsilon
     }
 
     /// Returns the dot product of this vector with another vector.
@@ -302,19 +361,148 @@ impl<T: Number> Vector3<T> {
 
     /// Returns a pointer to the vector's data.
     /// This is unsafe because it allows direct access to the vector's memory without bounds check.
+    ///
+    /// # Safety
+    /// Only the 3 components may be accessed through the pointer, while the vector is borrowed.
     pub const unsafe fn as_ptr(&self) -> *const T {
         &self.x as *const T
     }
 
     /// Returns a mutable pointer to the vector's data.
     /// This is unsafe because it allows direct access to the vector's memory without bounds check.
+    ///
+    /// # Safety
+    /// Only the 3 components may be accessed through the pointer, while the vector is borrowed.
     pub const unsafe fn as_mut_ptr(&mut self) -> *mut T {
         &mut self.x as *mut T
     }
 }
 
+impl<T: SignedNumber> Vector3<T> {
+    /// Returns the cross product of this vector with another vector.
+    /// Only available for signed types, as its components are negative for most operands.
+    ///
+    /// ```compile_fail
+    /// use sky_labs::math::Vector3;
+    ///
+    /// Vector3::<u32>::new(0, 1, 0).cross(&Vector3::new(1, 0, 0));
+    /// ```
+    pub fn cross(&self, other: &Self) -> Self {
+        Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+}
+
+/// Shading operations, following the GLSL conventions: `incident` points toward the surface and
+/// `normal` away from it.
+impl<T: SignedNumber> Vector3<T> {
+    /// Reflects the incident vector `self` about the plane with the given normal, which must be
+    /// normalized. Like GLSL `reflect`, computes `self - 2 * dot(normal, self) * normal`.
+    pub fn reflect(&self, normal: &Self) -> Self {
+        debug_assert!(normal.is_normalized(), "`normal` must be normalized");
+        *self - *normal * ((T::ONE + T::ONE) * normal.dot(self))
+    }
+
+    /// Returns the normal `self` if it faces away from `incident` according to `reference`, that
+    /// is if `dot(reference, incident) < 0`, and `-self` otherwise. Like GLSL `faceforward`, used
+    /// to flip the normal of a surface seen from behind.
+    pub fn faceforward(&self, incident: &Self, reference: &Self) -> Self {
+        if reference.dot(incident) < T::ZERO {
+            *self
+        } else {
+            -*self
+        }
+    }
+}
+
+impl<T: Wrap> Vector3<T> {
+    /// Wraps each component into `[min, max)` of the matching components of `min` and `max`.
+    /// See `Wrap::wrap`.
+    pub fn wrap(&self, min: &Self, max: &Self) -> Self {
+        Self {
+            x: self.x.wrap(min.x, max.x),
+            y: self.y.wrap(min.y, max.y),
+            z: self.z.wrap(min.z, max.z),
+        }
+    }
+}
+
+impl<T: IntegerNumber> Vector3<T> {
+    /// Adds another vector component-wise, returning `None` if any component overflows.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add(other.x)?,
+            y: self.y.checked_add(other.y)?,
+            z: self.z.checked_add(other.z)?,
+        })
+    }
+
+    /// Subtracts another vector component-wise, returning `None` if any component overflows.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_sub(other.x)?,
+            y: self.y.checked_sub(other.y)?,
+            z: self.z.checked_sub(other.z)?,
+        })
+    }
+
+    /// Adds another vector component-wise, clamping each component at the numeric bounds.
+    pub fn saturating_add(&self, other: &Self) -> Self {
+        Self {
+            x: self.x.saturating_add(other.x),
+            y: self.y.saturating_add(other.y),
+            z: self.z.saturating_add(other.z),
+        }
+    }
+
+    /// Subtracts another vector component-wise, clamping each component at the numeric bounds.
+    pub fn saturating_sub(&self, other: &Self) -> Self {
+        Self {
+            x: self.x.saturating_sub(other.x),
+            y: self.y.saturating_sub(other.y),
+            z: self.z.saturating_sub(other.z),
+        }
+    }
+
+    /// Adds another vector component-wise, wrapping around at the numeric bounds.
+    pub fn wrapping_add(&self, other: &Self) -> Self {
+        Self {
+            x: self.x.wrapping_add(other.x),
+            y: self.y.wrapping_add(other.y),
+            z: self.z.wrapping_add(other.z),
+        }
+    }
+
+    /// Subtracts another vector component-wise, wrapping around at the numeric bounds.
+    pub fn wrapping_sub(&self, other: &Self) -> Self {
+        Self {
+            x: self.x.wrapping_sub(other.x),
+            y: self.y.wrapping_sub(other.y),
+            z: self.z.wrapping_sub(other.z),
+        }
+    }
+}
+
+impl<T: SignedInteger> Vector3<T> {
+    /// Converts to a vector of the unsigned type with the same width,
+    /// returning `None` if any component is negative.
+    pub fn try_cast_to_unsigned(&self) -> Option<Vector3<T::Unsigned>> {
+        Some(Vector3 {
+            x: self.x.try_to_unsigned()?,
+            y: self.y.try_to_unsigned()?,
+            z: self.z.try_to_unsigned()?,
+        })
+    }
+}
+
+/// The rotations of `f32` vectors are computed in `f32` throughout, giving the same results as
+/// multiplying by the `Matrix3x3::<f32>` rotation of the same angle.
 impl Vector3<f32> {
     /// Rotates the vector around the X axis by the given angle in radians.
+    /// Prefer `rotate_x_angle`, which takes an `Angle`.
     pub fn rotate_x(&self, rad: f32) -> Self {
         let cos = rad.cos();
         let sin = rad.sin();
@@ -327,20 +515,32 @@ impl Vector3<f32> {
         }
     }
 
+    /// Same as `rotate_x`, taking an `Angle` instead of radians.
+    pub fn rotate_x_angle(&self, angle: Angle<f32>) -> Self {
+        self.rotate_x(angle.to_radians())
+    }
+
     /// Rotates the vector around the Y axis by the given angle in radians.
+    /// Prefer `rotate_y_angle`, which takes an `Angle`.
     pub fn rotate_y(&self, rad: f32) -> Self {
         let cos = rad.cos();
         let sin = rad.sin();
         let x = self.x;
         let z = self.z;
         Self {
-            x: x * cos - z * sin,
+            x: x * cos + z * sin,
             y: self.y,
-            z: x * sin + z * cos,
+            z: z * cos - x * sin,
         }
     }
 
+    /// Same as `rotate_y`, taking an `Angle` instead of radians.
+    pub fn rotate_y_angle(&self, angle: Angle<f32>) -> Self {
+        self.rotate_y(angle.to_radians())
+    }
+
     /// Rotates the vector around the Z axis by the given angle in radians.
+    /// Prefer `rotate_z_angle`, which takes an `Angle`.
     pub fn rotate_z(&self, rad: f32) -> Self {
         let cos = rad.cos();
         let sin = rad.sin();
@@ -353,7 +553,13 @@ impl Vector3<f32> {
         }
     }
 
+    /// Same as `rotate_z`, taking an `Angle` instead of radians.
+    pub fn rotate_z_angle(&self, angle: Angle<f32>) -> Self {
+        self.rotate_z(angle.to_radians())
+    }
+
     /// Rotates the vector around a given axis by the specified angle in radians.
+    /// Prefer `rotate_angle`, which takes an `Angle`.
     pub fn rotate(&self, rad: f32, axis: &Self) -> Self {
         let parallel_part = *axis * self.dot(axis);
         let orthogonal_part = axis.cross(self);
@@ -365,9 +571,30 @@ impl Vector3<f32> {
         parallel_part + rejection * cos + orthogonal_part * sin
     }
 
+    /// Same as `rotate`, taking an `Angle` instead of radians.
+    pub fn rotate_angle(&self, angle: Angle<f32>, axis: &Self) -> Self {
+        self.rotate(angle.to_radians(), axis)
+    }
+
+    /// Refracts the incident vector `self` through the surface with the given normal, where `eta`
+    /// is the ratio of the refractive index on the incident side to the one on the other side.
+    /// Both vectors must be normalized. Returns `None` on total internal reflection, where GLSL
+    /// `refract` returns zero.
+    pub fn refract(&self, normal: &Self, eta: f32) -> Option<Self> {
+        debug_assert!(self.is_normalized(), "`self` must be normalized");
+        debug_assert!(normal.is_normalized(), "`normal` must be normalized");
+        let cos_incident = normal.dot(self);
+        let k = 1.0 - eta * eta * (1.0 - cos_incident * cos_incident);
+        if k < 0.0 {
+            return None;
+        }
+        Some(*self * eta - *normal * (eta * cos_incident + k.sqrt()))
+    }
+
     /// Returns a normalized version of this vector.
-    /// If the vector is zero, it returns the vector itself.
+    /// If the vector is zero, it returns the vector itself, see `try_normalize` to detect it.
     pub fn normalize(&self) -> Self {
+        debug_assert!(self.is_finite(), "vector must be finite");
         let length = self.modulus();
         if length == 0.0 {
             return *self;
@@ -376,16 +603,68 @@ impl Vector3<f32> {
         *self / length as f32
     }
 
-    /// Checks if the vector is normalized (length is 1).
-    pub fn is_normalized(&self) -> bool {
-        let length_squared = self.norm_squared();
-        let diff = (length_squared - 1.0).abs();
-        (diff * diff) <= f32::EPSILON
+    /// Returns a normalized version of the vector, or `None` if the vector isn't finite, is zero
+    /// or is too short for the result to be normalized.
+    pub fn try_normalize(&self) -> Option<Self> {
+        if !self.is_finite() {
+            return None;
+        }
+        let normalized = self.normalize();
+        if normalized.is_normalized() {
+            Some(normalized)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a normalized version of the vector, or `fallback` if it can't be normalized.
+    pub fn normalize_or(&self, fallback: Self) -> Self {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    /// Returns a normalized version of the vector, or zero if it can't be normalized, like a
+    /// non-finite vector or one too short to have a direction.
+    pub fn normalize_or_zero(&self) -> Self {
+        self.normalize_or(Self::ZERO)
+    }
+
+    /// Returns two unit vectors perpendicular to this one and to each other, such that
+    /// `(a, b, self)` is a right-handed orthonormal basis. Expects a normalized vector.
+    /// Uses the branchless method of Duff et al., which is stable at the poles.
+    pub fn any_orthonormal_pair(&self) -> (Self, Self) {
+        debug_assert!(self.is_normalized(), "vector must be normalized");
+        let sign = 1.0f32.copysign(self.z);
+        let a = -1.0 / (sign + self.z);
+        let b = self.x * self.y * a;
+        (
+            Self::new(1.0 + sign * self.x * self.x * a, sign * b, -sign * self.x),
+            Self::new(b, sign + self.y * self.y * a, -self.y),
+        )
+    }
+
+    /// Converts the components to half-precision float bits, rounding ties to even.
+    /// See `f32_to_f16_bits`.
+    pub fn to_f16_bits(&self) -> [u16; 3] {
+        [
+            f32_to_f16_bits(self.x),
+            f32_to_f16_bits(self.y),
+            f32_to_f16_bits(self.z),
+        ]
+    }
+
+    /// Creates a vector from half-precision float bits, as returned by `to_f16_bits`.
+    pub fn from_f16_bits(bits: [u16; 3]) -> Self {
+        Self {
+            x: f16_bits_to_f32(bits[0]),
+            y: f16_bits_to_f32(bits[1]),
+            z: f16_bits_to_f32(bits[2]),
+        }
     }
 }
 
 impl Vector3<f64> {
     /// Rotates the vector around the X axis by the given angle in radians.
+    /// Prefer `rotate_x_angle`, which takes an `Angle`.
     pub fn rotate_x(&self, rad: f64) -> Self {
         let cos = rad.cos();
         let sin = rad.sin();
@@ -398,20 +677,32 @@ impl Vector3<f64> {
         }
     }
 
+    /// Same as `rotate_x`, taking an `Angle` instead of radians.
+    pub fn rotate_x_angle(&self, angle: Angle<f64>) -> Self {
+        self.rotate_x(angle.to_radians())
+    }
+
     /// Rotates the vector around the Y axis by the given angle in radians.
+    /// Prefer `rotate_y_angle`, which takes an `Angle`.
     pub fn rotate_y(&self, rad: f64) -> Self {
         let cos = rad.cos();
         let sin = rad.sin();
         let x = self.x;
         let z = self.z;
         Self {
-            x: x * cos - z * sin,
+            x: x * cos + z * sin,
             y: self.y,
-            z: x * sin + z * cos,
+            z: z * cos - x * sin,
         }
     }
 
+    /// Same as `rotate_y`, taking an `Angle` instead of radians.
+    pub fn rotate_y_angle(&self, angle: Angle<f64>) -> Self {
+        self.rotate_y(angle.to_radians())
+    }
+
     /// Rotates the vector around the Z axis by the given angle in radians.
+    /// Prefer `rotate_z_angle`, which takes an `Angle`.
     pub fn rotate_z(&self, rad: f64) -> Self {
         let cos = rad.cos();
         let sin = rad.sin();
@@ -424,7 +715,13 @@ impl Vector3<f64> {
         }
     }
 
+    /// Same as `rotate_z`, taking an `Angle` instead of radians.
+    pub fn rotate_z_angle(&self, angle: Angle<f64>) -> Self {
+        self.rotate_z(angle.to_radians())
+    }
+
     /// Rotates the vector around a given axis by the specified angle in radians.
+    /// Prefer `rotate_angle`, which takes an `Angle`.
     pub fn rotate(&self, rad: f64, axis: &Self) -> Self {
         let parallel_part = *axis * self.dot(axis);
         let orthogonal_part = axis.cross(self);
@@ -436,9 +733,30 @@ impl Vector3<f64> {
         parallel_part + rejection * cos + orthogonal_part * sin
     }
 
+    /// Same as `rotate`, taking an `Angle` instead of radians.
+    pub fn rotate_angle(&self, angle: Angle<f64>, axis: &Self) -> Self {
+        self.rotate(angle.to_radians(), axis)
+    }
+
+    /// Refracts the incident vector `self` through the surface with the given normal, where `eta`
+    /// is the ratio of the refractive index on the incident side to the one on the other side.
+    /// Both vectors must be normalized. Returns `None` on total internal reflection, where GLSL
+    /// `refract` returns zero.
+    pub fn refract(&self, normal: &Self, eta: f64) -> Option<Self> {
+        debug_assert!(self.is_normalized(), "`self` must be normalized");
+        debug_assert!(normal.is_normalized(), "`normal` must be normalized");
+        let cos_incident = normal.dot(self);
+        let k = 1.0 - eta * eta * (1.0 - cos_incident * cos_incident);
+        if k < 0.0 {
+            return None;
+        }
+        Some(*self * eta - *normal * (eta * cos_incident + k.sqrt()))
+    }
+
     /// Returns a normalized version of this vector.
-    /// If the vector is zero, it returns the vector itself.
+    /// If the vector is zero, it returns the vector itself, see `try_normalize` to detect it.
     pub fn normalize(&self) -> Self {
+        debug_assert!(self.is_finite(), "vector must be finite");
         let length = self.modulus();
         if length == 0.0 {
             return *self;
@@ -447,51 +765,42 @@ impl Vector3<f64> {
         *self / length
     }
 
-    /// Checks if the vector is normalized (length is 1).
-    pub fn is_normalized(&self) -> bool {
-        let length_squared = self.norm_squared();
-        let diff = (length_squared - 1.0).abs();
-        (diff * diff) <= f64::EPSILON
-    }
-}
-
-// Windows-specific implementation for Direct2D compatibility.
-
-#[cfg(target_os = "windows")]
-use windows::Win32::Graphics::Direct2D::Common::D2D_VECTOR_3F;
-
-#[cfg(target_os = "windows")]
-impl Into<D2D_VECTOR_3F> for Vector3<f32> {
-    fn into(self) -> D2D_VECTOR_3F {
-        D2D_VECTOR_3F {
-            x: self.x,
-            y: self.y,
-            z: self.z,
+    /// Returns a normalized version of the vector, or `None` if the vector isn't finite, is zero
+    /// or is too short for the result to be normalized.
+    pub fn try_normalize(&self) -> Option<Self> {
+        if !self.is_finite() {
+            return None;
+        }
+        let normalized = self.normalize();
+        if normalized.is_normalized() {
+            Some(normalized)
+        } else {
+            None
         }
     }
-}
 
-#[cfg(target_os = "windows")]
-impl<'a> Into<&'a D2D_VECTOR_3F> for &'a Vector3<f32> {
-    fn into(self) -> &'a D2D_VECTOR_3F {
-        unsafe { std::mem::transmute(self) }
+    /// Returns a normalized version of the vector, or `fallback` if it can't be normalized.
+    pub fn normalize_or(&self, fallback: Self) -> Self {
+        self.try_normalize().unwrap_or(fallback)
     }
-}
 
-#[cfg(target_os = "windows")]
-impl From<D2D_VECTOR_3F> for Vector3<f32> {
-    fn from(value: D2D_VECTOR_3F) -> Self {
-        Self {
-            x: value.x,
-            y: value.y,
-            z: value.z,
-        }
+    /// Returns a normalized version of the vector, or zero if it can't be normalized, like a
+    /// non-finite vector or one too short to have a direction.
+    pub fn normalize_or_zero(&self) -> Self {
+        self.normalize_or(Self::ZERO)
     }
-}
 
-#[cfg(target_os = "windows")]
-impl<'a> From<&'a D2D_VECTOR_3F> for &'a Vector3<f32> {
-    fn from(value: &'a D2D_VECTOR_3F) -> Self {
-        unsafe { std::mem::transmute(value) }
+    /// Returns two unit vectors perpendicular to this one and to each other, such that
+    /// `(a, b, self)` is a right-handed orthonormal basis. Expects a normalized vector.
+    /// Uses the branchless method of Duff et al., which is stable at the poles.
+    pub fn any_orthonormal_pair(&self) -> (Self, Self) {
+        debug_assert!(self.is_normalized(), "vector must be normalized");
+        let sign = 1.0f64.copysign(self.z);
+        let a = -1.0 / (sign + self.z);
+        let b = self.x * self.y * a;
+        (
+            Self::new(1.0 + sign * self.x * self.x * a, sign * b, -sign * self.x),
+            Self::new(b, sign + self.y * self.y * a, -self.y),
+        )
     }
 }
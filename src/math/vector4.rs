@@ -17,11 +17,17 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::ops::{
     Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
 };
 
-use crate::math::{Number, SignedNumber, Vector3};
+use crate::math::easing::{ease, Easing};
+use crate::math::{
+    f16_bits_to_f32, f32_to_f16_bits, IntegerNumber, Number, SignedNumber, Vector3,
+    NORMALIZED_EPSILON,
+};
 
 /// A 4D vector with generic number type.
 /// It can be used for various mathematical operations such as addition, subtraction, multiplication, and division.
@@ -36,6 +42,19 @@ pub struct Vector4<T: Number> {
     pub w: T,
 }
 
+impl<T: IntegerNumber> Eq for Vector4<T> {}
+
+implement_lexicographic_ord!(Vector4, x, y, z, w);
+
+impl<T: IntegerNumber> Hash for Vector4<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.y.hash(state);
+        self.z.hash(state);
+        self.w.hash(state);
+    }
+}
+
 impl<T: SignedNumber> Neg for Vector4<T> {
     type Output = Self;
 
@@ -120,6 +139,8 @@ implement_scalar_lhs_mul! {
     Vector4<f64>, f64
 }
 
+implement_vector_reductions!(Vector4);
+
 impl<T: Number> MulAssign<T> for Vector4<T> {
     fn mul_assign(&mut self, rhs: T) {
         self.x *= rhs;
@@ -204,29 +225,35 @@ impl<T: Number> From<[T; 4]> for Vector4<T> {
 }
 
 impl<T: Number> Vector4<T> {
+    /// A vector with all components set to zero.
+    pub const ZERO: Self = Self {
+        x: T::ZERO,
+        y: T::ZERO,
+        z: T::ZERO,
+        w: T::ZERO,
+    };
+
+    /// A vector with all components set to one.
+    pub const ONE: Self = Self {
+        x: T::ONE,
+        y: T::ONE,
+        z: T::ONE,
+        w: T::ONE,
+    };
+
     /// Creates a new vector with the specified components.
     pub const fn new(x: T, y: T, z: T, w: T) -> Self {
         Self { x, y, z, w }
     }
 
     /// Creates a new vector with all components set to zero.
-    pub fn zero() -> Self {
-        Self {
-            x: T::zero(),
-            y: T::zero(),
-            z: T::zero(),
-            w: T::zero(),
-        }
+    pub const fn zero() -> Self {
+        Self::ZERO
     }
 
     /// Creates a new vector with all components set to one.
-    pub fn one() -> Self {
-        Self {
-            x: T::one(),
-            y: T::one(),
-            z: T::one(),
-            w: T::one(),
-        }
+    pub const fn one() -> Self {
+        Self::ONE
     }
 
     /// Converts a `Vector3` to a `Vector4` by adding the `w` component.
@@ -244,6 +271,131 @@ impl<T: Number> Vector4<T> {
         self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
     }
 
+    /// Returns the modulus (length) of the vector.
+    pub fn modulus(&self) -> f64 {
+        let norm_squared: f64 = self.norm_squared().as_double();
+        f64::sqrt(norm_squared)
+    }
+
+    /// Returns the magnitude (norm) of the vector, same as modulus().
+    pub fn magnitude(&self) -> f64 {
+        self.modulus()
+    }
+
+    /// Returns the squared norm of the vector.
+    /// This is useful for avoiding the square root operation when comparing distances.
+    pub fn norm_squared(&self) -> T {
+        self.dot(self)
+    }
+
+    /// Returns the distance to another vector.
+    /// This is the Euclidean distance between the two vectors.
+    pub fn distance_to(&self, other: &Self) -> f64 {
+        let diff = Self {
+            x: T::abs_diff(self.x, other.x),
+            y: T::abs_diff(self.y, other.y),
+            z: T::abs_diff(self.z, other.z),
+            w: T::abs_diff(self.w, other.w),
+        };
+        let norm_squared: f64 = diff.norm_squared().as_double();
+        f64::sqrt(norm_squared)
+    }
+
+    /// Linearly interpolates between this vector and `other` by `t`.
+    /// `t = 0` returns this vector and `t = 1` returns `other`, values outside `[0, 1]` extrapolate.
+    pub fn lerp(&self, other: &Self, t: f64) -> Self {
+        let lerp = |a: T, b: T| {
+            let a: f64 = a.as_double();
+            T::from_double(a + (b.as_double() - a) * t)
+        };
+        Self {
+            x: lerp(self.x, other.x),
+            y: lerp(self.y, other.y),
+            z: lerp(self.z, other.z),
+            w: lerp(self.w, other.w),
+        }
+    }
+
+    /// Interpolates between this vector and `target` like `lerp`, by `t` eased by `kind`.
+    /// `t` is clamped to `[0, 1]`, but easings such as back or elastic may still overshoot.
+    pub fn ease_to(&self, target: &Self, kind: Easing, t: f64) -> Self {
+        self.lerp(target, ease(kind, t))
+    }
+
+    /// Returns a normalized version of the vector.
+    /// If the vector is zero, it returns the vector itself, see `try_normalize` to detect it.
+    pub fn normalize(&self) -> Self {
+        debug_assert!(self.is_finite(), "vector must be finite");
+        let length = self.modulus();
+        if length == 0.0 {
+            return *self;
+        }
+        Self {
+            x: T::from_double(self.x.as_double() / length),
+            y: T::from_double(self.y.as_double() / length),
+            z: T::from_double(self.z.as_double() / length),
+            w: T::from_double(self.w.as_double() / length),
+        }
+    }
+
+    /// Returns a normalized version of the vector, or `None` if the vector isn't finite, is zero
+    /// or is too short for the result to be normalized.
+    pub fn try_normalize(&self) -> Option<Self> {
+        if !self.is_finite() {
+            return None;
+        }
+        let normalized = self.normalize();
+        if normalized.is_normalized() {
+            Some(normalized)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a normalized version of the vector, or `fallback` if it can't be normalized.
+    pub fn normalize_or(&self, fallback: Self) -> Self {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    /// Returns a normalized version of the vector, or zero if it can't be normalized, like a
+    /// non-finite vector or one too short to have a direction.
+    pub fn normalize_or_zero(&self) -> Self {
+        self.normalize_or(Self::ZERO)
+    }
+
+    /// Returns whether every component is finite, neither infinite nor NaN.
+    /// Always true for integer vectors.
+    pub fn is_finite(&self) -> bool {
+        self.x.as_double().is_finite()
+            && self.y.as_double().is_finite()
+            && self.z.as_double().is_finite()
+            && self.w.as_double().is_finite()
+    }
+
+    /// Checks if the vector is normalized (length is 1), with `|length² - 1|` within
+    /// `NORMALIZED_EPSILON`.
+    pub fn is_normalized(&self) -> bool {
+        self.is_normalized_within(NORMALIZED_EPSILON)
+    }
+
+    /// Checks if the vector is normalized (length is 1), with `|length² - 1|` within `epsilon`.
+    pub fn is_normalized_within(&self, epsilon: f64) -> bool {
+        let length_squared: f64 = self.norm_squared().as_double();
+        (length_squared - 1.0).abs() <= epsilon
+    }
+
+    /// Returns the `x`, `y` and `z` components divided by `w`, which is the perspective
+    /// divide of a homogeneous coordinate. `w` must not be zero.
+    pub fn to_vector3_homogeneous(&self) -> Vector3<T> {
+        debug_assert!(self.w != T::ZERO, "`w` must not be zero");
+        Vector3::new(self.x / self.w, self.y / self.w, self.z / self.w)
+    }
+
+    /// Returns the `x`, `y` and `z` components, dropping `w`.
+    pub fn truncate(&self) -> Vector3<T> {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
     pub const fn from_array(arr: [T; 4]) -> Self {
         Self {
             x: arr[0],
@@ -279,56 +431,42 @@ impl<T: Number> Vector4<T> {
 
     /// Returns a pointer to the vector's data.
     /// This is unsafe because it allows direct access to the vector's memory without bounds check.
+    ///
+    /// # Safety
+    /// Only the 4 components may be accessed through the pointer, while the vector is borrowed.
     pub const unsafe fn as_ptr(&self) -> *const T {
         &self.x as *const T
     }
 
     /// Returns a mutable pointer to the vector's data.
     /// This is unsafe because it allows direct access to the vector's memory without bounds check.
+    ///
+    /// # Safety
+    /// Only the 4 components may be accessed through the pointer, while the vector is borrowed.
     pub const unsafe fn as_mut_ptr(&mut self) -> *mut T {
         &mut self.x as *mut T
     }
 }
 
-// Windows-specific implementation for Direct2D compatibility
-
-#[cfg(target_os = "windows")]
-use windows::Win32::Graphics::Direct2D::Common::D2D_VECTOR_4F;
-
-#[cfg(target_os = "windows")]
-impl Into<D2D_VECTOR_4F> for Vector4<f32> {
-    fn into(self) -> D2D_VECTOR_4F {
-        D2D_VECTOR_4F {
-            x: self.x,
-            y: self.y,
-            z: self.z,
-            w: self.w,
-        }
+impl Vector4<f32> {
+    /// Converts the components to half-precision float bits, rounding ties to even.
+    /// See `f32_to_f16_bits`.
+    pub fn to_f16_bits(&self) -> [u16; 4] {
+        [
+            f32_to_f16_bits(self.x),
+            f32_to_f16_bits(self.y),
+            f32_to_f16_bits(self.z),
+            f32_to_f16_bits(self.w),
+        ]
     }
-}
 
-#[cfg(target_os = "windows")]
-impl<'a> Into<&'a D2D_VECTOR_4F> for &'a Vector4<f32> {
-    fn into(self) -> &'a D2D_VECTOR_4F {
-        unsafe { std::mem::transmute(self) }
-    }
-}
-
-#[cfg(target_os = "windows")]
-impl From<D2D_VECTOR_4F> for Vector4<f32> {
-    fn from(value: D2D_VECTOR_4F) -> Self {
+    /// Creates a vector from half-precision float bits, as returned by `to_f16_bits`.
+    pub fn from_f16_bits(bits: [u16; 4]) -> Self {
         Self {
-            x: value.x,
-            y: value.y,
-            z: value.z,
-            w: value.w,
+            x: f16_bits_to_f32(bits[0]),
+            y: f16_bits_to_f32(bits[1]),
+            z: f16_bits_to_f32(bits[2]),
+            w: f16_bits_to_f32(bits[3]),
         }
     }
 }
-
-#[cfg(target_os = "windows")]
-impl<'a> From<&'a D2D_VECTOR_4F> for &'a Vector4<f32> {
-    fn from(value: &'a D2D_VECTOR_4F) -> Self {
-        unsafe { std::mem::transmute(value) }
-    }
-}
@@ -0,0 +1,59 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! The types most programs using the crate need, to import them all at once:
+//!
+//! ```no_run
+//! use sky_labs::prelude::*;
+//!
+//! let window = Window::create();
+//! let renderer = DefaultRenderer::create_for_window(&window);
+//! let mut session = renderer.begin_draw();
+//! session.clear(&Color::from_rgba_hex(0x1E3A5FFF));
+//! let square = Rect {
+//!     x: 10.0,
+//!     y: 10.0,
+//!     width: 50.0,
+//!     height: 50.0,
+//! };
+//! session.draw_rectangle(&square, &Color::new(1.0, 0.0, 0.0, 1.0));
+//! renderer.end_draw(session);
+//! renderer.present();
+//! ```
+//!
+//! Only types and traits are re-exported, free functions such as `app::run` are left in their
+//! modules to keep glob imports from shadowing the names of the importing code.
+
+pub use crate::app::{AppConfig, Application};
+pub use crate::debug_overlay::DebugOverlay;
+pub use crate::events::gamepad::GamepadEvent;
+pub use crate::events::window::{
+    ButtonState, KeyEvent, MouseButton, MouseEvent, TextInputEvent, WindowEvent,
+};
+pub use crate::input::keyboard::{KeyState, VirtualKey};
+pub use crate::input::InputState;
+pub use crate::math::{
+    Angle, Matrix2x2, Matrix3x3, Matrix4x4, Rect, Size, Transform2D, Vector2, Vector3, Vector4,
+};
+pub use crate::renderer::{
+    Color, DefaultRenderer, DrawingSession, HorizontalAlignment, Renderer, TextFormat, TextLayout,
+    TextOverflow, TextWrapping, VerticalAlignment,
+};
+pub use crate::timer::StepTimer;
+pub use crate::window::{FullscreenMode, Window, WindowProcessResult};
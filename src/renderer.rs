@@ -17,21 +17,60 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
-use std::ops::Deref;
+pub mod geometry;
+pub mod software;
+pub mod testing;
 
-use crate::{
-    math::{Number, Rect, Size, Vector2},
-    win::renderer_d3d12::Direct3D12Renderer,
-    window::Window,
-};
+use std::any::Any;
+use std::fmt;
+use std::ops::Range;
+#[cfg(all(target_os = "windows", feature = "renderer-d3d12"))]
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
 
+use crate::math::easing::{ease, Easing};
+use crate::math::{Matrix4x4, Number, Rect, Size, Vector2, Vector3};
+#[cfg(all(target_os = "windows", feature = "renderer-d3d12"))]
+use crate::win::renderer_d3d12::Direct3D12Renderer;
 #[cfg(target_os = "windows")]
+use crate::window::Window;
+
+#[cfg(all(target_os = "windows", feature = "renderer-d3d12"))]
 pub struct DefaultRenderer(Direct3D12Renderer);
+#[cfg(all(target_os = "windows", feature = "renderer-d3d12"))]
 impl DefaultRenderer {
     pub fn create_for_window(window: &Window) -> Self {
         DefaultRenderer(Direct3D12Renderer::create_for_window(window))
     }
+
+    pub fn create_offscreen(size: Size<u32>) -> Result<Self, String> {
+        Direct3D12Renderer::create_offscreen(size).map(DefaultRenderer)
+    }
+
+    /// Creates a renderer for the window, see `Renderer::create_for_window`.
+    pub fn create_for_window_with_options(window: &Window, options: &RendererOptions) -> Self {
+        DefaultRenderer(Direct3D12Renderer::create_for_window_with_options(
+            window, options,
+        ))
+    }
+
+    /// Creates a renderer for the window, returning an error instead of panicking on failure.
+    pub fn try_create_for_window_with_options(
+        window: &Window,
+        options: &RendererOptions,
+    ) -> Result<Self, RendererError> {
+        Direct3D12Renderer::try_create_for_window_with_options(window, options).map(DefaultRenderer)
+    }
+
+    /// Creates an offscreen renderer, see `Renderer::create_offscreen`.
+    pub fn create_offscreen_with_options(
+        size: Size<u32>,
+        options: &RendererOptions,
+    ) -> Result<Self, RendererError> {
+        Direct3D12Renderer::create_offscreen_with_options(size, options).map(DefaultRenderer)
+    }
 }
+#[cfg(all(target_os = "windows", feature = "renderer-d3d12"))]
 impl Deref for DefaultRenderer {
     type Target = Direct3D12Renderer;
 
@@ -40,14 +79,464 @@ impl Deref for DefaultRenderer {
     }
 }
 
+#[cfg(all(target_os = "windows", feature = "renderer-d3d12"))]
+impl DerefMut for DefaultRenderer {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
 pub enum RendererType {
     Direct2D,
     Direct3D12,
 }
 
-pub struct TextFormat {}
+/// Adapter a renderer is created on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AdapterPreference {
+    /// The first hardware adapter supporting the renderer, falling back to WARP when none does.
+    #[default]
+    Hardware,
+    /// The WARP software rasterizer, available even on machines without a GPU.
+    Warp,
+}
+
+/// Format of the buffers a renderer draws into, set with `RendererOptions::format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BackBufferFormat {
+    /// 8 bits per channel, presented as sRGB. Colors are written as given, already sRGB
+    /// encoded, and blended in sRGB.
+    #[default]
+    Rgba8Unorm,
+    /// 8 bits per channel, presented as sRGB. Colors are converted to linear light and encoded
+    /// back to sRGB as they are written, so blending happens in linear light.
+    Rgba8UnormSrgb,
+    /// 16 bit float channels in the linear scRGB color space, for HDR displays. Colors are
+    /// converted to linear light, and channels above 1.0 are brighter than SDR white where the
+    /// display supports it. Falls back to `Rgba8Unorm` when the display can't present it.
+    Rgba16Float,
+}
+
+impl BackBufferFormat {
+    /// Whether `DrawingSession` colors are converted to linear light before being written.
+    pub fn takes_linear_colors(&self) -> bool {
+        !matches!(self, BackBufferFormat::Rgba8Unorm)
+    }
+
+    /// Whether the format can hold colors brighter than SDR white.
+    pub fn is_hdr(&self) -> bool {
+        matches!(self, BackBufferFormat::Rgba16Float)
+    }
+
+    /// Size of a pixel in the format, in bytes.
+    pub fn bytes_per_pixel(&self) -> usize {
+        match self {
+            BackBufferFormat::Rgba8Unorm | BackBufferFormat::Rgba8UnormSrgb => 4,
+            BackBufferFormat::Rgba16Float => 8,
+        }
+    }
+}
+
+/// Options of a renderer, set when it is created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RendererOptions {
+    /// Adapter the renderer draws with.
+    pub adapter: AdapterPreference,
+    /// Whether the render target has a depth buffer, which `DrawingSession::draw_mesh` tests and
+    /// writes the depth of meshes against. Without it, meshes are drawn in order.
+    pub depth: bool,
+    /// Number of buffers in the swap chain, between `MIN_BUFFER_COUNT` and `MAX_BUFFER_COUNT`.
+    /// A third buffer lets the CPU record a frame while two are queued, smoothing the frame pacing
+    /// at the cost of a frame of latency. `DEFAULT_BUFFER_COUNT` by default.
+    pub buffer_count: u32,
+    /// Whether a renderer created for a window observes it and resizes its buffers to follow
+    /// `WindowEvent::Resized` and `WindowEvent::DpiChanged`, at the `begin_draw` following the
+    /// event. Events are only observed as `Window::poll_event` takes them. Disable it to call
+    /// `resize` manually instead. Enabled by default.
+    pub auto_resize: bool,
+    /// Format of the back buffers. `Rgba8Unorm` by default.
+    pub format: BackBufferFormat,
+    /// Most frames the swap chain queues for presentation, between 1 and `MAX_FRAME_LATENCY`,
+    /// for `Renderer::wait_for_next_frame` to wait on. `None`, the default, keeps the latency
+    /// chosen by DXGI and makes `wait_for_next_frame` return right away. Offscreen renderers
+    /// present nothing and ignore it.
+    pub max_frame_latency: Option<u32>,
+}
+
+impl RendererOptions {
+    /// Buffer count of the default options, double buffering.
+    pub const DEFAULT_BUFFER_COUNT: u32 = 2;
+    /// Fewest buffers a flip model swap chain can have.
+    pub const MIN_BUFFER_COUNT: u32 = 2;
+    /// Most buffers a swap chain can have, `DXGI_MAX_SWAP_CHAIN_BUFFERS`.
+    pub const MAX_BUFFER_COUNT: u32 = 16;
+    /// Highest `max_frame_latency` a swap chain accepts, `DXGI_MAX_SWAP_CHAIN_BUFFERS`.
+    pub const MAX_FRAME_LATENCY: u32 = 16;
+
+    /// Checks that the options can create a renderer, returning `RendererError::Failed` with the
+    /// reason if they can't.
+    pub fn validate(&self) -> Result<(), RendererError> {
+        if !(Self::MIN_BUFFER_COUNT..=Self::MAX_BUFFER_COUNT).contains(&self.buffer_count) {
+            return Err(RendererError::Failed(format!(
+                "Buffer count must be between {} and {}, got {}.",
+                Self::MIN_BUFFER_COUNT,
+                Self::MAX_BUFFER_COUNT,
+                self.buffer_count
+            )));
+        }
+        if let Some(latency) = self.max_frame_latency {
+            if !(1..=Self::MAX_FRAME_LATENCY).contains(&latency) {
+                return Err(RendererError::Failed(format!(
+                    "Maximum frame latency must be between 1 and {}, got {}.",
+                    Self::MAX_FRAME_LATENCY,
+                    latency
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for RendererOptions {
+    fn default() -> Self {
+        Self {
+            adapter: AdapterPreference::default(),
+            depth: false,
+            buffer_count: Self::DEFAULT_BUFFER_COUNT,
+            auto_resize: true,
+            format: BackBufferFormat::default(),
+            max_frame_latency: None,
+        }
+    }
+}
+
+/// Device a renderer draws with, as returned by `Direct3D12Renderer::info`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RendererInfo {
+    /// Name of the adapter, as reported by its driver.
+    pub adapter_name: String,
+    /// Whether the device is the WARP software rasterizer, either because it was requested or
+    /// because no hardware adapter could be used.
+    pub is_warp: bool,
+    /// Highest Direct3D feature level supported by the device, as `(major, minor)`.
+    pub feature_level: (u8, u8),
+}
+
+/// Failure of a renderer operation that can be recovered from, such as
+/// `Direct3D12Renderer::recreate`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RendererError {
+    /// The GPU device was removed or reset, carrying the reason reported by the driver.
+    /// The renderer must be rebuilt with `Direct3D12Renderer::recreate`.
+    DeviceLost(String),
+    /// Any other failure, carrying its description.
+    Failed(String),
+}
+
+impl fmt::Display for RendererError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RendererError::DeviceLost(reason) => write!(f, "Device lost: {}", reason),
+            RendererError::Failed(description) => f.write_str(description),
+        }
+    }
+}
+
+impl std::error::Error for RendererError {}
+
+impl From<String> for RendererError {
+    fn from(description: String) -> Self {
+        RendererError::Failed(description)
+    }
+}
+
+/// Horizontal position of text within its layout rectangle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HorizontalAlignment {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// Vertical position of text within its layout rectangle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VerticalAlignment {
+    #[default]
+    Top,
+    Center,
+    Bottom,
+}
+
+/// What happens to text that doesn't fit its layout rectangle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextOverflow {
+    /// Text is drawn past the rectangle.
+    #[default]
+    Visible,
+    /// Text outside the rectangle is not drawn.
+    Clip,
+}
+
+/// Where text breaks into lines when it is wider than its layout rectangle.
+/// Line breaks in the text always start a new line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextWrapping {
+    /// Lines are never broken, and the text outside the rectangle is not drawn.
+    None,
+    /// Lines break between words, and within words too long to fit a line.
+    #[default]
+    Word,
+    /// Lines break after the last character fitting the rectangle.
+    Character,
+}
+
+/// Font loaded with `Renderer::load_font_from_bytes`, drawn by setting it as `TextFormat::font`.
+/// Clones share the loaded font, which is unloaded once the last of them is dropped.
+/// Fonts are only drawn by the kind of renderer that loaded them, others use their default font.
+#[derive(Clone)]
+pub struct FontHandle(Arc<dyn Any + Send + Sync>);
+
+impl FontHandle {
+    /// Wraps the font object of a renderer.
+    pub(crate) fn new<T: Any + Send + Sync>(font: T) -> Self {
+        Self(Arc::new(font))
+    }
+
+    /// Returns the font object of the renderer that loaded the font, if it is a `T`.
+    #[cfg_attr(
+        not(all(target_os = "windows", feature = "renderer-d3d12")),
+        allow(dead_code)
+    )]
+    pub(crate) fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.0.downcast_ref()
+    }
+}
+
+/// Handles are equal when they share the same loaded font.
+impl PartialEq for FontHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for FontHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FontHandle").finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextFormat {
+    pub horizontal_alignment: HorizontalAlignment,
+    pub vertical_alignment: VerticalAlignment,
+    pub overflow: TextOverflow,
+    pub wrapping: TextWrapping,
+    /// Multiplier of the default distance between lines.
+    pub line_spacing: f32,
+    /// Maximum number of lines drawn. Text with more lines ends its last line with an ellipsis.
+    pub max_lines: Option<u32>,
+    /// Draws a line under the text, in the color of the text above it.
+    pub underline: bool,
+    /// Draws a line through the middle of the text, in the color of the text under it.
+    pub strikethrough: bool,
+    /// Snaps glyphs to whole pixels, keeping small text crisp. Disabling it positions glyphs
+    /// more evenly, for text that is animated or scaled. The software renderer always snaps.
+    pub pixel_snapping: bool,
+    /// Font to draw with, or `None` for the default font of the renderer.
+    pub font: Option<FontHandle>,
+}
+
+impl TextFormat {
+    /// Whether text drawn with this format is clipped to its layout rectangle.
+    pub fn clips(&self) -> bool {
+        self.overflow == TextOverflow::Clip || self.wrapping == TextWrapping::None
+    }
+}
+
+impl Default for TextFormat {
+    fn default() -> Self {
+        Self {
+            horizontal_alignment: HorizontalAlignment::default(),
+            vertical_alignment: VerticalAlignment::default(),
+            overflow: TextOverflow::default(),
+            wrapping: TextWrapping::default(),
+            line_spacing: 1.0,
+            max_lines: None,
+            underline: false,
+            strikethrough: false,
+            pixel_snapping: true,
+            font: None,
+        }
+    }
+}
+
+/// Size of a text laid out with a `TextFormat`, as returned by `Renderer::measure_text`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TextMetrics {
+    /// Width of the widest line, ignoring trailing whitespace.
+    pub width: f32,
+    /// Width of the widest line, including trailing whitespace.
+    pub width_including_trailing_whitespace: f32,
+    /// Height of all lines together.
+    pub height: f32,
+    /// Number of lines after wrapping.
+    pub line_count: u32,
+}
+
+impl TextMetrics {
+    /// Size of the laid out text, ignoring trailing whitespace.
+    pub fn size(&self) -> Size<f32> {
+        Size::new(self.width, self.height)
+    }
+}
+
+/// Layout object of a renderer, kept by the `TextLayout`s it creates.
+pub(crate) trait TextLayoutBackend: Any {
+    /// Lays out `text` again, with the format and maximum size of the layout.
+    fn update_text(&mut self, text: &str, format: &TextFormat, max_size: Size<f32>);
+
+    #[cfg_attr(
+        not(all(target_os = "windows", feature = "renderer-d3d12")),
+        allow(dead_code)
+    )]
+    fn as_any(&self) -> &dyn Any;
+}
+
+/// Text laid out by `Renderer::create_text_layout`, to draw it with
+/// `DrawingSession::draw_text_layout` without laying it out again on every frame.
+/// Layouts are only reused by the kind of renderer that created them, others lay the text out
+/// on each draw like `draw_text`.
+pub struct TextLayout {
+    text: String,
+    format: TextFormat,
+    max_size: Size<f32>,
+    /// Layout object of the renderer, `None` for renderers laying text out on each draw.
+    backend: Option<Box<dyn TextLayoutBackend>>,
+}
+
+impl TextLayout {
+    pub(crate) fn new(
+        text: &str,
+        format: &TextFormat,
+        max_size: Size<f32>,
+        backend: Option<Box<dyn TextLayoutBackend>>,
+    ) -> Self {
+        Self {
+            text: text.to_string(),
+            format: format.clone(),
+            max_size,
+            backend,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn format(&self) -> &TextFormat {
+        &self.format
+    }
+
+    /// Size of the box the text is laid out in, positioned by the alignment of the format.
+    pub fn max_size(&self) -> Size<f32> {
+        self.max_size
+    }
+
+    /// Replaces the text of the layout, laying it out again only if it changed.
+    /// The text is copied into the buffer of the previous one, so text that is unchanged or
+    /// fits its capacity doesn't allocate, though the renderer may to lay changed text out.
+    pub fn update_text(&mut self, text: &str) {
+        if self.text == text {
+            return;
+        }
+        self.text.clear();
+        self.text.push_str(text);
+        if let Some(backend) = &mut self.backend {
+            backend.update_text(text, &self.format, self.max_size);
+        }
+    }
+
+    /// Returns the layout box with its top left corner at `origin`.
+    pub fn rect_at(&self, origin: &Vector2<f32>) -> Rect<f32> {
+        Rect {
+            x: origin.x,
+            y: origin.y,
+            width: self.max_size.width,
+            height: self.max_size.height,
+        }
+    }
+
+    /// Returns the layout object of the renderer that created the layout, if it is a `T`.
+    #[cfg_attr(
+        not(all(target_os = "windows", feature = "renderer-d3d12")),
+        allow(dead_code)
+    )]
+    pub(crate) fn backend<T: Any>(&self) -> Option<&T> {
+        self.backend.as_ref()?.as_any().downcast_ref()
+    }
+}
+
+impl fmt::Debug for TextLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TextLayout")
+            .field("text", &self.text)
+            .field("format", &self.format)
+            .field("max_size", &self.max_size)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Operations recorded in a frame, as returned by `Renderer::last_frame_stats`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FrameStats {
+    /// Number of draw calls submitted to the GPU.
+    pub draw_calls: u32,
+    /// Number of triangles drawn, including the quads of text glyphs.
+    pub triangles: u32,
+    /// Number of `draw_text` calls.
+    pub text_runs: u32,
+    /// Time the CPU waited for the GPU to release the frame before recording it, in milliseconds.
+    pub gpu_wait_ms: f64,
+}
+
+impl FrameStats {
+    /// Counts a draw call of `triangles` triangles.
+    pub fn record_draw_call(&mut self, triangles: u32) {
+        self.draw_calls += 1;
+        self.triangles += triangles;
+    }
+
+    /// Counts a `draw_text` call, its glyphs being counted with `record_draw_call`.
+    pub fn record_text_run(&mut self) {
+        self.text_runs += 1;
+    }
+}
+
+/// Color of the text drawn by `draw_text`, and by `draw_text_styled` outside of its spans.
+pub const DEFAULT_TEXT_COLOR: Color<f32> = Color {
+    r: 1.0,
+    g: 1.0,
+    b: 1.0,
+    a: 1.0,
+};
+
+/// Checks the spans given to `DrawingSession::draw_text_styled`, see its panics.
+pub(crate) fn check_text_spans(text: &str, spans: &[(Range<usize>, Color<f32>)]) {
+    for (range, _) in spans {
+        assert!(
+            text.get(range.clone()).is_some(),
+            "Span {:?} is not a range of characters of the text.",
+            range
+        );
+    }
+}
 
+/// RGBA color with channels in `[0, 1]`.
+/// Colors given to a `DrawingSession` are sRGB encoded, as they are displayed, and alpha is
+/// straight (not premultiplied). Convert with `to_linear` before doing math on the channels.
 #[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Color<T: Number> {
     pub r: T,
     pub g: T,
@@ -83,6 +572,65 @@ impl<T: Number> Color<T> {
         (r << 24) | (g << 16) | (b << 8) | a
     }
 
+    /// Decodes the sRGB encoded color channels into linear light, keeping alpha.
+    /// Uses the piecewise sRGB transfer function, not a plain 2.2 gamma.
+    pub fn to_linear(&self) -> Self {
+        self.map_color_channels(srgb_to_linear)
+    }
+
+    /// Encodes the linear light color channels as sRGB, keeping alpha.
+    pub fn to_srgb(&self) -> Self {
+        self.map_color_channels(linear_to_srgb)
+    }
+
+    /// Interpolates between this sRGB color and `other` by `t`, in linear space.
+    /// Blending sRGB encoded values directly darkens the middle of fades and gradients.
+    /// Alpha is interpolated as is.
+    pub fn lerp(&self, other: &Self, t: f64) -> Self {
+        let lerp = |a: T, b: T| {
+            let a: f64 = a.as_double();
+            a + (b.as_double() - a) * t
+        };
+        let from = self.to_linear();
+        let to = other.to_linear();
+        let linear = Color {
+            r: T::from_double(lerp(from.r, to.r)),
+            g: T::from_double(lerp(from.g, to.g)),
+            b: T::from_double(lerp(from.b, to.b)),
+            a: T::from_double(lerp(self.a, other.a)),
+        };
+        linear.to_srgb()
+    }
+
+    /// Interpolates between this sRGB color and `target` like `lerp`, by `t` eased by `kind`.
+    /// `t` is clamped to `[0, 1]`, but easings such as back or elastic may still overshoot.
+    pub fn ease_to(&self, target: &Self, kind: Easing, t: f64) -> Self {
+        self.lerp(target, ease(kind, t))
+    }
+
+    /// Returns the color with the color channels multiplied by alpha.
+    pub fn premultiply_alpha(&self) -> Self {
+        let a: f64 = self.a.as_double();
+        self.map_color_channels(|channel| channel * a)
+    }
+
+    /// Relative luminance of this sRGB color, from 0 for black to 1 for white (Rec. 709).
+    pub fn luminance(&self) -> f64 {
+        let linear = self.to_linear();
+        0.2126 * linear.r.as_double()
+            + 0.7152 * linear.g.as_double()
+            + 0.0722 * linear.b.as_double()
+    }
+
+    fn map_color_channels(&self, f: impl Fn(f64) -> f64) -> Self {
+        Color {
+            r: T::from_double(f(self.r.as_double())),
+            g: T::from_double(f(self.g.as_double())),
+            b: T::from_double(f(self.b.as_double())),
+            a: self.a,
+        }
+    }
+
     pub fn as_slice(&self) -> &[T; 4] {
         unsafe { std::mem::transmute(self) }
     }
@@ -92,38 +640,183 @@ impl<T: Number> Color<T> {
     }
 }
 
+#[cfg(feature = "rand")]
+impl<T: Number> Color<T> {
+    /// Returns an opaque color with uniformly random sRGB channels.
+    pub fn random_opaque<R: ::rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        let mut channel = || T::from_double(rng.gen::<f64>());
+        Color {
+            r: channel(),
+            g: channel(),
+            b: channel(),
+            a: T::from_double(1.0),
+        }
+    }
+}
+
+/// Decodes an sRGB encoded channel value into linear light.
+fn srgb_to_linear(value: f64) -> f64 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encodes a linear light channel value as sRGB.
+fn linear_to_srgb(value: f64) -> f64 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
 /// Drawing session to draw on a surface.
 /// Use Renderer::begin_draw to get a DrawingSession from the renderer in use.
 /// Call Renderer::end_draw to submit the changes to the surface.
+/// Colors are sRGB encoded with straight alpha, see `Color`. Render targets store them as given
+/// and blending happens on the encoded values.
+///
+/// Everything is drawn in the order of the calls, whatever the kind of primitive: a shape drawn
+/// after a text covers it, and the other way around. Backends may batch consecutive draws, but
+/// never reorder them, so interfaces can be drawn back to front. Only `draw_mesh` with a depth
+/// buffer can be hidden by what was drawn before it.
 pub trait DrawingSession {
     /// Clear the game window with the specified color
+    /// May be called any number of times in a session, the last call wins for what was drawn before it.
+    /// If not called, the previous contents of the surface are kept only if the swap effect preserves
+    /// them: the Direct3D12 renderer uses flip-discard, so the contents are undefined in that case.
     fn clear(&mut self, color: &Color<f32>);
 
-    /// Draw a text to the game window
-    fn draw_text(&mut self, text: &String, format: &TextFormat, coord: &Rect<f32>);
+    /// Draw a text to the game window, positioned within `coord` by the alignment of `format`
+    fn draw_text(&mut self, text: &str, format: &TextFormat, coord: &Rect<f32>);
+
+    /// Draw a text like `draw_text`, coloring each span of `spans`: a byte range of `text` and
+    /// its color. Text outside every span is drawn in `DEFAULT_TEXT_COLOR`, and the last span
+    /// wins where spans overlap.
+    ///
+    /// # Panics
+    /// Panics if a range is out of `text` or doesn't start and end on character boundaries.
+    fn draw_text_styled(
+        &mut self,
+        text: &str,
+        format: &TextFormat,
+        coord: &Rect<f32>,
+        spans: &[(Range<usize>, Color<f32>)],
+    );
+
+    /// Draw a text laid out by `Renderer::create_text_layout` in `color`, its layout box having
+    /// its top left corner at `origin`. Unlike `draw_text`, the text isn't laid out again, which
+    /// suits text drawn every frame such as counters.
+    fn draw_text_layout(&mut self, layout: &TextLayout, origin: &Vector2<f32>, color: &Color<f32>);
 
     /// Draw a triangle to the game window
     fn draw_triangle(&mut self, points: &[Vector2<f32>; 3], color: &Color<f32>);
 
-    /// Draw a rectangle to the game window
+    /// Draw a rectangle to the game window, `rect` being in pixels of the render target
     fn draw_rectangle(&mut self, rect: &Rect<f32>, color: &Color<f32>);
 
-    /// Draw a circle within bounds to the game window
+    /// Draw a rectangle with corners rounded by `corner_radius` to the game window.
+    /// The radius is clamped to half the smaller side, and a radius of 0 draws like `draw_rectangle`.
+    fn draw_rounded_rectangle(&mut self, rect: &Rect<f32>, corner_radius: f32, color: &Color<f32>);
+
+    /// Draw a line from `from` to `to` to the game window, in pixels of the render target.
+    /// The line is `thickness` pixels wide and its ends are flat, not extending past the points.
+    fn draw_line(
+        &mut self,
+        from: &Vector2<f32>,
+        to: &Vector2<f32>,
+        thickness: f32,
+        color: &Color<f32>,
+    );
+
+    /// Draw the convex polygon `points` to the game window, in pixels of the render target and
+    /// in either winding. Concave polygons aren't drawn correctly. Polygons of fewer than 3
+    /// points or without area draw nothing, and are a bug in debug builds for the former.
+    fn draw_polygon(&mut self, points: &[Vector2<f32>], color: &Color<f32>);
+
+    /// Draw the outline of the convex polygon `points` to the game window, `thickness` pixels
+    /// wide and centered on its edges, with mitered corners. Takes the same points as `draw_polygon`.
+    fn draw_polygon_outline(&mut self, points: &[Vector2<f32>], thickness: f32, color: &Color<f32>);
+
+    /// Draw the ellipse inscribed in `bounds` to the game window, like Direct2D's `FillEllipse`:
+    /// it touches the middle of each side, and is a circle only when `bounds` is a square.
+    /// Empty bounds draw nothing.
     fn draw_circle(&mut self, bounds: &Rect<f32>, color: &Color<f32>);
 
     /// Draw a circle centered at 'center' with given 'radius'
     fn draw_circle_centered_at(&mut self, center: &Vector2<f32>, radius: f32, color: &Color<f32>);
+
+    /// Sets how many segments circles and ellipses drawn afterwards are split into, or `None`
+    /// to adapt it to their radius, the default. A fixed count makes the drawn shape and the
+    /// statistics independent of size, at the cost of smoothness for large radii.
+    fn set_circle_segments(&mut self, segments: Option<u32>);
+
+    /// Sets the whole depth buffer to `value`, between 0 (nearest) and 1 (farthest).
+    /// Does nothing if the renderer has no depth buffer.
+    fn clear_depth(&mut self, value: f32);
+
+    /// Draw the triangle list `indices` of `vertices`, transformed by `transform` into clip space.
+    /// Points are kept where their depth is between 0 and 1 after the perspective divide, and
+    /// with a depth buffer, only where they are nearer than what was drawn there before.
+    /// Both faces of the triangles are drawn.
+    fn draw_mesh(
+        &mut self,
+        vertices: &[Vector3<f32>],
+        indices: &[u32],
+        transform: &Matrix4x4<f32>,
+        color: &Color<f32>,
+    );
+
+    /// Ends the batch of draws being built, for backends that batch them. Backends flush by
+    /// themselves wherever a batch would break the call order, such as when switching from
+    /// shapes to text, so drawing correctly never requires calling it.
+    /// Backends drawing every call as it is made have nothing to flush.
+    fn flush(&mut self) {}
+
+    /// Returns the statistics of the operations recorded so far in this session.
+    fn stats(&self) -> FrameStats;
 }
 
 pub trait Renderer<'a, T: 'a + DrawingSession> {
     /// Creates renderer for specified window
+    #[cfg(target_os = "windows")]
     fn create_for_window(window: &Window) -> Self
     where
         Self: Sized;
 
-    /// Returns the size of the render target
+    /// Creates renderer that draws into an offscreen target of the given size, in pixels
+    fn create_offscreen(size: Size<u32>) -> Result<Self, String>
+    where
+        Self: Sized;
+
+    /// Returns the size of the render target, in physical pixels.
+    /// Use `Window::logical_size` or `Window::scale_factor` to lay out in logical pixels.
     fn size(&'a self) -> Size<f32>;
 
+    /// Returns the scale between physical and logical pixels of the surface drawn to,
+    /// the same as `Window::scale_factor` for renderers drawing to a window.
+    /// Divide `size` by it to lay out in logical pixels.
+    fn scale_factor(&'a self) -> f32;
+
+    /// Measures `text` laid out with `format`, wrapping lines longer than `max_width`.
+    /// Use `f32::MAX` as `max_width` to measure without wrapping. Empty text measures as zero.
+    fn measure_text(&'a self, text: &str, format: &TextFormat, max_width: f32) -> TextMetrics;
+
+    /// Lays out `text` with `format` in a box of `max_size`, to draw it with
+    /// `DrawingSession::draw_text_layout` and change it with `TextLayout::update_text`.
+    fn create_text_layout(
+        &'a self,
+        text: &str,
+        format: &TextFormat,
+        max_size: Size<f32>,
+    ) -> TextLayout;
+
+    /// Loads a TrueType or OpenType font from the contents of its file, to draw text with it
+    /// through `TextFormat::font`. Fails if `data` isn't a font the renderer can use.
+    fn load_font_from_bytes(&'a self, data: &[u8]) -> Result<FontHandle, String>;
+
     /// Returns a drawing session to draw on the window
     fn begin_draw(&'a self) -> T;
 
@@ -131,4 +824,28 @@ pub trait Renderer<'a, T: 'a + DrawingSession> {
     /// This method should be called after all drawing operations are done
     /// to display the changes on the window
     fn end_draw(&'a self, drawing_session: T);
+
+    /// Returns the statistics of the last drawing session submitted with `end_draw`,
+    /// or zeroed statistics if no frame was drawn yet.
+    fn last_frame_stats(&'a self) -> FrameStats;
+
+    /// Returns the number of drawing sessions submitted with `end_draw` so far, which is the
+    /// index of the frame being drawn between `begin_draw` and `end_draw`. Use it for animation
+    /// or to cycle through per-frame data.
+    fn frame_index(&'a self) -> u64;
+
+    /// Returns the index of the back buffer drawn to, below the number of buffers the renderer
+    /// cycles through. It doesn't change between `begin_draw` and `end_draw`, so data kept per
+    /// back buffer can be indexed with it while the GPU still reads the data of the others.
+    fn back_buffer_index(&'a self) -> u32;
+
+    /// Returns whether the renderer presents HDR colors, which requires a `Rgba16Float` back
+    /// buffer the display accepts. Renderers fall back to SDR instead of failing when it doesn't.
+    fn is_hdr_enabled(&'a self) -> bool;
+
+    /// Blocks until the swap chain can queue another frame without exceeding
+    /// `RendererOptions::max_frame_latency`. Call it at the top of the game loop, before
+    /// sampling input, so the frame drawn with that input is displayed as soon as possible.
+    /// Returns right away for renderers created without a maximum frame latency.
+    fn wait_for_next_frame(&'a self);
 }
@@ -0,0 +1,290 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Tessellation of the shapes drawn by `DrawingSession` into triangle lists.
+//! These are pure functions shared by the backends that lack native shape drawing.
+
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+use crate::math::{triangle, Rect, Vector2};
+
+/// Maximum distance, in pixels, between a curve and the segments approximating it.
+pub const DEFAULT_TESSELLATION_TOLERANCE: f32 = 0.25;
+
+/// Fewest segments `circle_segment_count` and `ellipse_vertices` split a full turn into.
+pub const MIN_CIRCLE_SEGMENTS: u32 = 3;
+
+/// Most segments `circle_segment_count` splits a full turn into, however large the radius.
+pub const MAX_CIRCLE_SEGMENTS: u32 = 256;
+
+/// Longest a corner of `polygon_outline_vertices` reaches out, in halves of the thickness,
+/// like the default miter limit of Direct2D. Sharper corners are cut short.
+pub const MITER_LIMIT: f32 = 10.0;
+
+/// Returns the two triangles covering `rect`, as a triangle list.
+pub fn rectangle_vertices(rect: &Rect<f32>) -> Vec<Vector2<f32>> {
+    let mut vertices = Vec::with_capacity(6);
+    push_rectangle(&mut vertices, rect.x, rect.y, rect.width, rect.height);
+    vertices
+}
+
+/// Returns the triangles covering `rect` with corners rounded by `corner_radius`, as a triangle list.
+///
+/// The radius is clamped to half the smaller dimension of `rect`, so a radius at least that large
+/// turns the shorter sides into half circles. A radius of zero or less gives exactly
+/// `rectangle_vertices(rect)`. Each corner arc is a triangle fan split in as many segments as
+/// needed to stay within `tolerance` pixels of the true arc.
+pub fn rounded_rectangle_vertices(
+    rect: &Rect<f32>,
+    corner_radius: f32,
+    tolerance: f32,
+) -> Vec<Vector2<f32>> {
+    let radius = corner_radius.min(rect.width / 2.0).min(rect.height / 2.0);
+    if radius <= 0.0 {
+        return rectangle_vertices(rect);
+    }
+
+    let segments = arc_segment_count(radius, FRAC_PI_2, tolerance);
+    let mut vertices = Vec::with_capacity(18 + 4 * 3 * segments as usize);
+
+    // Full height band between the corners, then the two side bands between the corner arcs.
+    push_rectangle(
+        &mut vertices,
+        rect.x + radius,
+        rect.y,
+        rect.width - 2.0 * radius,
+        rect.height,
+    );
+    push_rectangle(
+        &mut vertices,
+        rect.x,
+        rect.y + radius,
+        radius,
+        rect.height - 2.0 * radius,
+    );
+    push_rectangle(
+        &mut vertices,
+        rect.x + rect.width - radius,
+        rect.y + radius,
+        radius,
+        rect.height - 2.0 * radius,
+    );
+
+    let left = rect.x + radius;
+    let right = rect.x + rect.width - radius;
+    let top = rect.y + radius;
+    let bottom = rect.y + rect.height - radius;
+    // Angles grow clockwise on screen, since y points down.
+    push_arc_fan(
+        &mut vertices,
+        Vector2::new(right, bottom),
+        radius,
+        0.0,
+        segments,
+    );
+    push_arc_fan(
+        &mut vertices,
+        Vector2::new(left, bottom),
+        radius,
+        FRAC_PI_2,
+        segments,
+    );
+    push_arc_fan(&mut vertices, Vector2::new(left, top), radius, PI, segments);
+    push_arc_fan(
+        &mut vertices,
+        Vector2::new(right, top),
+        radius,
+        3.0 * FRAC_PI_2,
+        segments,
+    );
+    vertices
+}
+
+/// Returns the triangles of the ellipse inscribed in `bounds`, as a triangle list.
+///
+/// The ellipse is a fan of `segments` triangles around its center, at least `MIN_CIRCLE_SEGMENTS`,
+/// with every outer vertex on the ellipse. Empty bounds have no triangles.
+pub fn ellipse_vertices(bounds: &Rect<f32>, segments: u32) -> Vec<Vector2<f32>> {
+    if bounds.width <= 0.0 || bounds.height <= 0.0 {
+        return Vec::new();
+    }
+    let segments = segments.max(MIN_CIRCLE_SEGMENTS);
+    let radius_x = bounds.width / 2.0;
+    let radius_y = bounds.height / 2.0;
+    let center = Vector2::new(bounds.x + radius_x, bounds.y + radius_y);
+    let point_at = |segment: u32| {
+        let angle = TAU * (segment % segments) as f32 / segments as f32;
+        Vector2::new(
+            center.x + radius_x * angle.cos(),
+            center.y + radius_y * angle.sin(),
+        )
+    };
+
+    let mut vertices = Vec::with_capacity(3 * segments as usize);
+    for segment in 0..segments {
+        vertices.extend_from_slice(&[center, point_at(segment), point_at(segment + 1)]);
+    }
+    vertices
+}
+
+/// Number of segments approximating a full circle of `radius` pixels within `tolerance` pixels,
+/// between `MIN_CIRCLE_SEGMENTS` and `MAX_CIRCLE_SEGMENTS`. Use the larger radius of an ellipse,
+/// where the segments are furthest from the curve.
+pub fn circle_segment_count(radius: f32, tolerance: f32) -> u32 {
+    arc_segment_count(radius, TAU, tolerance).clamp(MIN_CIRCLE_SEGMENTS, MAX_CIRCLE_SEGMENTS)
+}
+
+/// Returns the two triangles of a line from `from` to `to`, `thickness` wide with flat ends,
+/// as a triangle list. A line without length or thickness has no triangles.
+pub fn line_vertices(from: Vector2<f32>, to: Vector2<f32>, thickness: f32) -> Vec<Vector2<f32>> {
+    let direction = to - from;
+    let length = direction.magnitude() as f32;
+    if length == 0.0 || thickness <= 0.0 {
+        return Vec::new();
+    }
+    let offset = direction.perpendicular() * (thickness / 2.0 / length);
+    let (a, b) = (from + offset, to + offset);
+    let (c, d) = (to - offset, from - offset);
+    vec![a, b, c, a, c, d]
+}
+
+/// Returns the triangles covering the convex polygon `points`, as a triangle list wound like
+/// `rectangle_vertices` whatever the winding of `points`. Collinear points add no triangle, so a
+/// polygon without area has none.
+pub fn polygon_vertices(points: &[Vector2<f32>]) -> Vec<Vector2<f32>> {
+    debug_assert!(points.len() >= 3, "a polygon must have at least 3 points");
+    triangle::triangulate_convex_f32(points)
+        .into_iter()
+        .flat_map(|corners| corners.map(|i| points[i]))
+        .collect()
+}
+
+/// Returns the triangles of the outline of the convex polygon `points`, `thickness` wide and
+/// centered on its edges, as a triangle list wound like `rectangle_vertices`. Corners are mitered
+/// up to `MITER_LIMIT`, and a polygon without area or thickness has no triangles.
+pub fn polygon_outline_vertices(points: &[Vector2<f32>], thickness: f32) -> Vec<Vector2<f32>> {
+    debug_assert!(points.len() >= 3, "a polygon must have at least 3 points");
+    // Repeated points would make edges without a direction.
+    let mut corners: Vec<Vector2<f32>> = Vec::with_capacity(points.len());
+    for point in points {
+        if corners.last() != Some(point) {
+            corners.push(*point);
+        }
+    }
+    while corners.len() > 1 && corners.first() == corners.last() {
+        corners.pop();
+    }
+    let count = corners.len();
+    let next = |i: usize| corners[(i + 1) % count];
+    let double_area = (0..count).map(|i| corners[i].cross(&next(i))).sum::<f32>();
+    if count < 3 || double_area == 0.0 || thickness <= 0.0 {
+        return Vec::new();
+    }
+
+    // The outward normal of each edge is on its right for counter-clockwise polygons.
+    let normals: Vec<Vector2<f32>> = (0..count)
+        .map(|i| {
+            let direction = next(i) - corners[i];
+            let length = direction.magnitude() as f32;
+            Vector2::new(direction.y, -direction.x) * (double_area.signum() / length)
+        })
+        .collect();
+    let half_thickness = thickness / 2.0;
+    let miters: Vec<Vector2<f32>> = (0..count)
+        .map(|i| {
+            let (before, after) = (normals[(i + count - 1) % count], normals[i]);
+            let miter =
+                (before + after) * (half_thickness / (1.0 + before.dot(after)).max(f32::EPSILON));
+            let length = miter.magnitude() as f32;
+            let limit = MITER_LIMIT * half_thickness;
+            if length > limit {
+                miter * (limit / length)
+            } else {
+                miter
+            }
+        })
+        .collect();
+
+    let mut vertices = Vec::with_capacity(6 * count);
+    for i in 0..count {
+        let j = (i + 1) % count;
+        let (outer_from, outer_to) = (corners[i] + miters[i], corners[j] + miters[j]);
+        let (inner_from, inner_to) = (corners[i] - miters[i], corners[j] - miters[j]);
+        push_triangle(&mut vertices, [outer_from, outer_to, inner_to]);
+        push_triangle(&mut vertices, [outer_from, inner_to, inner_from]);
+    }
+    vertices
+}
+
+/// Number of segments approximating an arc of `radius` spanning `angle` radians,
+/// so that no segment is further than `tolerance` from the arc. Always at least one.
+pub fn arc_segment_count(radius: f32, angle: f32, tolerance: f32) -> u32 {
+    if radius <= tolerance {
+        return 1;
+    }
+    // A chord spanning `step` radians is at most radius * (1 - cos(step / 2)) away from the arc.
+    let step = 2.0 * (1.0 - tolerance / radius).acos();
+    (angle.abs() / step).ceil().max(1.0) as u32
+}
+
+/// Pushes the two triangles of a rectangle, skipping empty ones.
+fn push_rectangle(vertices: &mut Vec<Vector2<f32>>, x: f32, y: f32, width: f32, height: f32) {
+    if width <= 0.0 || height <= 0.0 {
+        return;
+    }
+    let top_left = Vector2::new(x, y);
+    let top_right = Vector2::new(x + width, y);
+    let bottom_left = Vector2::new(x, y + height);
+    let bottom_right = Vector2::new(x + width, y + height);
+    vertices.extend_from_slice(&[
+        top_left,
+        top_right,
+        bottom_right,
+        top_left,
+        bottom_right,
+        bottom_left,
+    ]);
+}
+
+/// Pushes a triangle wound like `rectangle_vertices`, skipping it if it has no area.
+fn push_triangle(vertices: &mut Vec<Vector2<f32>>, corners: [Vector2<f32>; 3]) {
+    for [a, b, c] in triangle::triangulate_convex_f32(&corners) {
+        vertices.extend_from_slice(&[corners[a], corners[b], corners[c]]);
+    }
+}
+
+/// Pushes a quarter circle fan around `center`, starting at `start_angle`.
+fn push_arc_fan(
+    vertices: &mut Vec<Vector2<f32>>,
+    center: Vector2<f32>,
+    radius: f32,
+    start_angle: f32,
+    segments: u32,
+) {
+    let point_at = |segment: u32| {
+        let angle = start_angle + FRAC_PI_2 * segment as f32 / segments as f32;
+        Vector2::new(
+            center.x + radius * angle.cos(),
+            center.y + radius * angle.sin(),
+        )
+    };
+    for segment in 0..segments {
+        vertices.extend_from_slice(&[center, point_at(segment), point_at(segment + 1)]);
+    }
+}
@@ -0,0 +1,781 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+//! Renderer drawing on the CPU into an RGBA8 framebuffer, without a GPU or a window.
+//! Meant for headless tests and CI, and as a fallback where no GPU backend exists yet.
+
+use std::borrow::Cow;
+use std::cell::{Cell, Ref, RefCell};
+use std::ops::Range;
+use std::path::Path;
+
+#[cfg(target_os = "windows")]
+use crate::window::Window;
+use crate::{
+    math::{triangle, Matrix4x4, Rect, Size, Vector2, Vector3, Vector4},
+    renderer::{
+        check_text_spans, geometry, Color, DrawingSession, FontHandle, FrameStats,
+        HorizontalAlignment, Renderer, TextFormat, TextLayout, TextMetrics, TextWrapping,
+        VerticalAlignment, DEFAULT_TEXT_COLOR,
+    },
+};
+
+/// Size of the cell taken by each character of the text fallback, in pixels.
+/// Characters other than whitespace are drawn as a block filling their cell, inset by a pixel.
+pub const GLYPH_CELL_SIZE: Size<f32> = Size {
+    width: 8.0,
+    height: 16.0,
+};
+
+/// First four bytes of the font files `load_font_from_bytes` accepts: TrueType outlines,
+/// OpenType (CFF) outlines, Apple TrueType and TrueType collections.
+const FONT_FILE_TAGS: [[u8; 4]; 4] = [[0, 1, 0, 0], *b"OTTO", *b"true", *b"ttcf"];
+
+/// Font loaded by the software renderer. Every font is drawn with the text fallback, so
+/// nothing but the fact it was loaded is kept.
+struct FallbackFont;
+
+/// Samples per pixel side used to compute the coverage of curved and slanted edges.
+const SUPERSAMPLING: u32 = 4;
+
+/// Renderer rasterizing on the CPU. Shapes are anti-aliased by their pixel coverage and blended
+/// over the framebuffer with straight alpha, on the sRGB encoded values like the GPU backends.
+/// It always has a depth buffer, as if created with `RendererOptions::depth`.
+pub struct SoftwareRenderer {
+    size: Size<u32>,
+    scale_factor: f32,
+    pixels: RefCell<Vec<u8>>,
+    /// Depth of each pixel, for `draw_mesh`.
+    depth: RefCell<Vec<f32>>,
+    last_stats: Cell<FrameStats>,
+    frame_index: Cell<u64>,
+}
+
+impl SoftwareRenderer {
+    /// Creates a renderer with a framebuffer of `size` pixels, cleared to transparent black,
+    /// and a depth buffer cleared to 1.
+    pub fn new(size: Size<u32>) -> Self {
+        let pixel_count = size.width as usize * size.height as usize;
+        Self {
+            size,
+            scale_factor: 1.0,
+            pixels: RefCell::new(vec![0; pixel_count * 4]),
+            depth: RefCell::new(vec![1.0; pixel_count]),
+            last_stats: Cell::new(FrameStats::default()),
+            frame_index: Cell::new(0),
+        }
+    }
+
+    /// Pixels drawn by the sessions submitted so far, as rows of RGBA8 values from the top left.
+    /// The borrow must be released before the next `end_draw`.
+    pub fn framebuffer(&self) -> Ref<'_, [u8]> {
+        Ref::map(self.pixels.borrow(), |pixels| pixels.as_slice())
+    }
+
+    /// Returns the RGBA8 value of the pixel at `x`, `y`.
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        assert!(x < self.size.width && y < self.size.height);
+        let offset = (y as usize * self.size.width as usize + x as usize) * 4;
+        let pixels = self.pixels.borrow();
+        [
+            pixels[offset],
+            pixels[offset + 1],
+            pixels[offset + 2],
+            pixels[offset + 3],
+        ]
+    }
+
+    /// Writes the framebuffer to `path` as a binary PPM image, dropping alpha.
+    /// Handy to look at what a failing test drew.
+    pub fn save_ppm(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let header = format!("P6\n{} {}\n255\n", self.size.width, self.size.height);
+        let mut data = header.into_bytes();
+        data.extend(
+            self.pixels
+                .borrow()
+                .chunks_exact(4)
+                .flat_map(|pixel| [pixel[0], pixel[1], pixel[2]]),
+        );
+        std::fs::write(path, data).map_err(|e| e.to_string())
+    }
+}
+
+impl<'a> Renderer<'a, SoftwareDrawingSession> for SoftwareRenderer {
+    /// Creates a renderer the size of the window's client area. Nothing is shown on the window.
+    /// Creates a renderer of the size of the window. It doesn't present to the window, and keeps
+    /// the size and scale factor the window had at creation.
+    #[cfg(target_os = "windows")]
+    fn create_for_window(window: &Window) -> Self {
+        Self {
+            scale_factor: window.scale_factor(),
+            ..Self::new(window.size())
+        }
+    }
+
+    fn create_offscreen(size: Size<u32>) -> Result<Self, String> {
+        Ok(Self::new(size))
+    }
+
+    fn size(&'a self) -> Size<f32> {
+        self.size.to_f32()
+    }
+
+    fn scale_factor(&'a self) -> f32 {
+        self.scale_factor
+    }
+
+    /// Measures the text fallback, where every character takes a `GLYPH_CELL_SIZE` cell and
+    /// lines are `GLYPH_CELL_SIZE` high, times `format.line_spacing`.
+    fn measure_text(&'a self, text: &str, format: &TextFormat, max_width: f32) -> TextMetrics {
+        if text.is_empty() {
+            return TextMetrics::default();
+        }
+        let lines = layout_lines(text, format, max_width);
+        let widest = |width_of: fn(&str) -> usize| {
+            lines
+                .iter()
+                .map(|(_, line)| width_of(line))
+                .max()
+                .unwrap_or(0) as f32
+                * GLYPH_CELL_SIZE.width
+        };
+        TextMetrics {
+            width: widest(|line| line.trim_end().chars().count()),
+            width_including_trailing_whitespace: widest(|line| line.chars().count()),
+            height: lines.len() as f32 * line_height(format),
+            line_count: lines.len() as u32,
+        }
+    }
+
+    /// The text fallback is cheap to lay out, so the layout keeps no more than the text, which
+    /// is laid out again on each draw.
+    fn create_text_layout(
+        &'a self,
+        text: &str,
+        format: &TextFormat,
+        max_size: Size<f32>,
+    ) -> TextLayout {
+        TextLayout::new(text, format, max_size, None)
+    }
+
+    /// Checks `data` starts like a font file, without parsing its tables. The font is drawn and
+    /// measured with the text fallback like every other font.
+    fn load_font_from_bytes(&'a self, data: &[u8]) -> Result<FontHandle, String> {
+        match data.get(..4) {
+            Some(tag) if FONT_FILE_TAGS.iter().any(|known| known == tag) => {
+                Ok(FontHandle::new(FallbackFont))
+            }
+            _ => Err(String::from(
+                "Font data is not a TrueType or OpenType font.",
+            )),
+        }
+    }
+
+    /// Returns a session drawing over the current framebuffer contents.
+    fn begin_draw(&'a self) -> SoftwareDrawingSession {
+        SoftwareDrawingSession {
+            size: self.size,
+            pixels: self.pixels.borrow().clone(),
+            depth: self.depth.borrow().clone(),
+            stats: FrameStats::default(),
+            circle_segments: None,
+        }
+    }
+
+    fn end_draw(&'a self, drawing_session: SoftwareDrawingSession) {
+        *self.pixels.borrow_mut() = drawing_session.pixels;
+        *self.depth.borrow_mut() = drawing_session.depth;
+        self.last_stats.set(drawing_session.stats);
+        self.frame_index.set(self.frame_index.get() + 1);
+    }
+
+    fn last_frame_stats(&'a self) -> FrameStats {
+        self.last_stats.get()
+    }
+
+    fn frame_index(&'a self) -> u64 {
+        self.frame_index.get()
+    }
+
+    /// The framebuffer is the only buffer, so the index is always 0.
+    fn back_buffer_index(&'a self) -> u32 {
+        0
+    }
+
+    /// The framebuffer is RGBA8, so never.
+    fn is_hdr_enabled(&'a self) -> bool {
+        false
+    }
+
+    /// Nothing is presented, so there is never a frame to wait for.
+    fn wait_for_next_frame(&'a self) {}
+}
+
+/// Drawing session of the `SoftwareRenderer`, drawing each call into its own copy of the
+/// framebuffer as it is made.
+/// Statistics count the triangles a tessellating backend would draw for each shape.
+pub struct SoftwareDrawingSession {
+    size: Size<u32>,
+    pixels: Vec<u8>,
+    depth: Vec<f32>,
+    stats: FrameStats,
+    circle_segments: Option<u32>,
+}
+
+impl DrawingSession for SoftwareDrawingSession {
+    fn clear(&mut self, color: &Color<f32>) {
+        let value = color.as_slice().map(to_unorm);
+        for pixel in self.pixels.chunks_exact_mut(4) {
+            pixel.copy_from_slice(&value);
+        }
+    }
+
+    /// Draws the text fallback, a `GLYPH_CELL_SIZE` block per character, in white.
+    fn draw_text(&mut self, text: &str, format: &TextFormat, coord: &Rect<f32>) {
+        self.draw_text_styled(text, format, coord, &[]);
+    }
+
+    /// Draws the text fallback, each block in the color of the span of its character.
+    /// Underlines are drawn on the last row of the cells and strikethroughs on their middle
+    /// row, one pixel thick.
+    fn draw_text_styled(
+        &mut self,
+        text: &str,
+        format: &TextFormat,
+        coord: &Rect<f32>,
+        spans: &[(Range<usize>, Color<f32>)],
+    ) {
+        check_text_spans(text, spans);
+        self.stats.record_text_run();
+        let lines = layout_lines(text, format, coord.width);
+        let line_height = line_height(format);
+        let text_height = lines.len() as f32 * line_height;
+        let top = coord.y
+            + match format.vertical_alignment {
+                VerticalAlignment::Top => 0.0,
+                VerticalAlignment::Center => (coord.height - text_height) / 2.0,
+                VerticalAlignment::Bottom => coord.height - text_height,
+            };
+        let clip = format.clips().then_some(*coord);
+
+        // Offsets of the underline and strikethrough from the top of a line, one pixel thick.
+        let decorations: Vec<f32> = [
+            (format.underline, GLYPH_CELL_SIZE.height - 1.0),
+            (format.strikethrough, (GLYPH_CELL_SIZE.height / 2.0).floor()),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, offset)| enabled.then_some(offset))
+        .collect();
+
+        let mut quads = 0;
+        for (row, (start, line)) in lines.iter().enumerate() {
+            let line_width = line.trim_end().chars().count() as f32 * GLYPH_CELL_SIZE.width;
+            let left = coord.x
+                + match format.horizontal_alignment {
+                    HorizontalAlignment::Left => 0.0,
+                    HorizontalAlignment::Center => (coord.width - line_width) / 2.0,
+                    HorizontalAlignment::Right => coord.width - line_width,
+                };
+            let y = top + row as f32 * line_height;
+            // Decorations span the line up to its trailing whitespace, like DirectWrite.
+            let decorated_characters = line.trim_end().chars().count();
+            if decorated_characters > 0 {
+                quads += decorations.len() as u32;
+            }
+            for (column, (index, character)) in line.char_indices().enumerate() {
+                let x = left + column as f32 * GLYPH_CELL_SIZE.width;
+                let color = spans
+                    .iter()
+                    .rfind(|(range, _)| range.contains(&(start + index)))
+                    .map_or(DEFAULT_TEXT_COLOR, |(_, color)| *color);
+                if column < decorated_characters {
+                    for offset in &decorations {
+                        let stroke = Rect {
+                            x,
+                            y: y + offset,
+                            width: GLYPH_CELL_SIZE.width,
+                            height: 1.0,
+                        };
+                        self.fill_clipped_rectangle(&stroke, clip.as_ref(), &color);
+                    }
+                }
+                if character.is_whitespace() {
+                    continue;
+                }
+                let block = Rect {
+                    x: x + 1.0,
+                    y: y + 1.0,
+                    width: GLYPH_CELL_SIZE.width - 2.0,
+                    height: GLYPH_CELL_SIZE.height - 2.0,
+                };
+                self.fill_clipped_rectangle(&block, clip.as_ref(), &color);
+                quads += 1;
+            }
+        }
+        self.record_triangles(2 * quads);
+    }
+
+    /// Draws a triangle given in clip space, like the Direct3D12 renderer.
+    /// Draws the text fallback of the layout, every block in `color`.
+    fn draw_text_layout(&mut self, layout: &TextLayout, origin: &Vector2<f32>, color: &Color<f32>) {
+        let spans = [(0..layout.text().len(), *color)];
+        self.draw_text_styled(
+            layout.text(),
+            layout.format(),
+            &layout.rect_at(origin),
+            &spans,
+        );
+    }
+
+    fn draw_triangle(&mut self, points: &[Vector2<f32>; 3], color: &Color<f32>) {
+        let width = self.size.width as f32;
+        let height = self.size.height as f32;
+        let [a, b, c] =
+            points.map(|p| Vector2::new((p.x + 1.0) / 2.0 * width, (1.0 - p.y) / 2.0 * height));
+        let edge = |from: Vector2<f32>, to: Vector2<f32>, x: f32, y: f32| {
+            (to.x - from.x) * (y - from.y) - (to.y - from.y) * (x - from.x)
+        };
+        self.stats.record_draw_call(1);
+        if edge(a, b, c.x, c.y) == 0.0 {
+            return;
+        }
+        let bounds = (
+            a.x.min(b.x).min(c.x),
+            a.y.min(b.y).min(c.y),
+            a.x.max(b.x).max(c.x),
+            a.y.max(b.y).max(c.y),
+        );
+        self.fill_shape(bounds, color, |x, y| {
+            let edges = [edge(a, b, x, y), edge(b, c, x, y), edge(c, a, x, y)];
+            edges.iter().all(|e| *e >= 0.0) || edges.iter().all(|e| *e <= 0.0)
+        });
+    }
+
+    fn draw_rectangle(&mut self, rect: &Rect<f32>, color: &Color<f32>) {
+        self.record_triangles(geometry::rectangle_vertices(rect).len() as u32 / 3);
+        self.fill_rectangle(rect, color);
+    }
+
+    fn draw_rounded_rectangle(&mut self, rect: &Rect<f32>, corner_radius: f32, color: &Color<f32>) {
+        let vertices = geometry::rounded_rectangle_vertices(
+            rect,
+            corner_radius,
+            geometry::DEFAULT_TESSELLATION_TOLERANCE,
+        );
+        self.record_triangles(vertices.len() as u32 / 3);
+
+        let radius = corner_radius.min(rect.width / 2.0).min(rect.height / 2.0);
+        if radius <= 0.0 {
+            self.fill_rectangle(rect, color);
+            return;
+        }
+        let (left, top) = (rect.x + radius, rect.y + radius);
+        let (right, bottom) = (rect.x + rect.width - radius, rect.y + rect.height - radius);
+        let bounds = (rect.x, rect.y, rect.x + rect.width, rect.y + rect.height);
+        self.fill_shape(bounds, color, |x, y| {
+            let dx = x - x.clamp(left, right);
+            let dy = y - y.clamp(top, bottom);
+            dx * dx + dy * dy <= radius * radius
+        });
+    }
+
+    fn draw_line(
+        &mut self,
+        from: &Vector2<f32>,
+        to: &Vector2<f32>,
+        thickness: f32,
+        color: &Color<f32>,
+    ) {
+        let vertices = geometry::line_vertices(*from, *to, thickness);
+        if vertices.is_empty() {
+            return;
+        }
+        self.record_triangles(vertices.len() as u32 / 3);
+
+        let (from, direction) = (*from, *to - *from);
+        let length_squared = direction.norm_squared();
+        let length = length_squared.sqrt();
+        let half_thickness = thickness / 2.0;
+        let bounds = vertices.iter().fold(
+            (f32::MAX, f32::MAX, f32::MIN, f32::MIN),
+            |(left, top, right, bottom), v| {
+                (left.min(v.x), top.min(v.y), right.max(v.x), bottom.max(v.y))
+            },
+        );
+        self.fill_shape(bounds, color, |x, y| {
+            let offset = Vector2::new(x, y) - from;
+            let along = offset.dot(direction) / length_squared;
+            let across = offset.cross(&direction).abs() / length;
+            (0.0..=1.0).contains(&along) && across <= half_thickness
+        });
+    }
+
+    fn draw_polygon(&mut self, points: &[Vector2<f32>], color: &Color<f32>) {
+        self.fill_triangles(&geometry::polygon_vertices(points), color);
+    }
+
+    fn draw_polygon_outline(
+        &mut self,
+        points: &[Vector2<f32>],
+        thickness: f32,
+        color: &Color<f32>,
+    ) {
+        self.fill_triangles(
+            &geometry::polygon_outline_vertices(points, thickness),
+            color,
+        );
+    }
+
+    /// Draws the ellipse inscribed in `bounds`, a circle when `bounds` is a square.
+    fn draw_circle(&mut self, bounds: &Rect<f32>, color: &Color<f32>) {
+        let radius_x = bounds.width / 2.0;
+        let radius_y = bounds.height / 2.0;
+        if radius_x <= 0.0 || radius_y <= 0.0 {
+            return;
+        }
+        let segments = self.circle_segments.unwrap_or_else(|| {
+            geometry::circle_segment_count(
+                radius_x.max(radius_y),
+                geometry::DEFAULT_TESSELLATION_TOLERANCE,
+            )
+        });
+        self.record_triangles(segments.max(geometry::MIN_CIRCLE_SEGMENTS));
+
+        let center_x = bounds.x + radius_x;
+        let center_y = bounds.y + radius_y;
+        let area = (
+            bounds.x,
+            bounds.y,
+            bounds.x + bounds.width,
+            bounds.y + bounds.height,
+        );
+        self.fill_shape(area, color, |x, y| {
+            let dx = (x - center_x) / radius_x;
+            let dy = (y - center_y) / radius_y;
+            dx * dx + dy * dy <= 1.0
+        });
+    }
+
+    fn draw_circle_centered_at(&mut self, center: &Vector2<f32>, radius: f32, color: &Color<f32>) {
+        let bounds = Rect {
+            x: center.x - radius,
+            y: center.y - radius,
+            width: 2.0 * radius,
+            height: 2.0 * radius,
+        };
+        self.draw_circle(&bounds, color);
+    }
+
+    /// Only changes the statistics, since this renderer fills exact ellipses.
+    fn set_circle_segments(&mut self, segments: Option<u32>) {
+        self.circle_segments = segments;
+    }
+
+    fn clear_depth(&mut self, value: f32) {
+        self.depth.fill(value);
+    }
+
+    /// Draws the pixels whose center is covered by a triangle, without anti-aliasing since the
+    /// depth is tested per pixel. Triangles with a vertex behind the eye, where `w` is not
+    /// positive, are skipped rather than clipped.
+    fn draw_mesh(
+        &mut self,
+        vertices: &[Vector3<f32>],
+        indices: &[u32],
+        transform: &Matrix4x4<f32>,
+        color: &Color<f32>,
+    ) {
+        let width = self.size.width as f32;
+        let height = self.size.height as f32;
+        let projected: Vec<Option<Vector3<f32>>> = vertices
+            .iter()
+            .map(|vertex| {
+                let clip = *transform * Vector4::new(vertex.x, vertex.y, vertex.z, 1.0);
+                (clip.w > 0.0).then(|| {
+                    Vector3::new(
+                        (clip.x / clip.w + 1.0) / 2.0 * width,
+                        (1.0 - clip.y / clip.w) / 2.0 * height,
+                        clip.z / clip.w,
+                    )
+                })
+            })
+            .collect();
+
+        let triangles = indices.chunks_exact(3);
+        self.record_triangles(triangles.len() as u32);
+        for triangle in triangles {
+            let [a, b, c] = [0, 1, 2].map(|corner| projected[triangle[corner] as usize]);
+            if let (Some(a), Some(b), Some(c)) = (a, b, c) {
+                self.fill_depth_tested_triangle(a, b, c, color);
+            }
+        }
+    }
+
+    fn stats(&self) -> FrameStats {
+        self.stats
+    }
+}
+
+impl SoftwareDrawingSession {
+    /// Fills the part of `rect` inside `clip`, or all of it without a clip rectangle.
+    fn fill_clipped_rectangle(
+        &mut self,
+        rect: &Rect<f32>,
+        clip: Option<&Rect<f32>>,
+        color: &Color<f32>,
+    ) {
+        let rect = match clip {
+            Some(clip) => intersect(rect, clip),
+            None => Some(*rect),
+        };
+        if let Some(rect) = rect {
+            self.fill_rectangle(&rect, color);
+        }
+    }
+
+    /// Counts a draw call of `triangles` triangles, none for shapes that are empty.
+    fn record_triangles(&mut self, triangles: u32) {
+        if triangles > 0 {
+            self.stats.record_draw_call(triangles);
+        }
+    }
+
+    /// Fills the triangle list `vertices`, in pixels, as a single draw call. Pixels covered by
+    /// several triangles are blended once.
+    fn fill_triangles(&mut self, vertices: &[Vector2<f32>], color: &Color<f32>) {
+        if vertices.is_empty() {
+            return;
+        }
+        self.record_triangles(vertices.len() as u32 / 3);
+        let bounds = vertices.iter().fold(
+            (f32::MAX, f32::MAX, f32::MIN, f32::MIN),
+            |(left, top, right, bottom), v| {
+                (left.min(v.x), top.min(v.y), right.max(v.x), bottom.max(v.y))
+            },
+        );
+        self.fill_shape(bounds, color, |x, y| {
+            let point = Vector2::new(x, y);
+            vertices
+                .chunks_exact(3)
+                .any(|t| triangle::contains_point_2d_f32(&point, &t[0], &t[1], &t[2]))
+        });
+    }
+
+    /// Fills `rect` using the exact area of each pixel it covers.
+    fn fill_rectangle(&mut self, rect: &Rect<f32>, color: &Color<f32>) {
+        let (left, top) = (rect.x, rect.y);
+        let (right, bottom) = (rect.x + rect.width, rect.y + rect.height);
+        self.for_each_pixel((left, top, right, bottom), color, |x, y| {
+            let covered_x = (right.min(x + 1.0) - left.max(x)).max(0.0);
+            let covered_y = (bottom.min(y + 1.0) - top.max(y)).max(0.0);
+            covered_x * covered_y
+        });
+    }
+
+    /// Fills the pixels within `bounds` (left, top, right, bottom) by the fraction of
+    /// `SUPERSAMPLING`² samples for which `inside` holds.
+    fn fill_shape(
+        &mut self,
+        bounds: (f32, f32, f32, f32),
+        color: &Color<f32>,
+        inside: impl Fn(f32, f32) -> bool,
+    ) {
+        let step = 1.0 / SUPERSAMPLING as f32;
+        let total = (SUPERSAMPLING * SUPERSAMPLING) as f32;
+        self.for_each_pixel(bounds, color, |x, y| {
+            let mut covered = 0;
+            for sample_y in 0..SUPERSAMPLING {
+                for sample_x in 0..SUPERSAMPLING {
+                    let sx = x + (sample_x as f32 + 0.5) * step;
+                    let sy = y + (sample_y as f32 + 0.5) * step;
+                    if inside(sx, sy) {
+                        covered += 1;
+                    }
+                }
+            }
+            covered as f32 / total
+        });
+    }
+
+    /// Blends `color` over every pixel touching `bounds`, weighted by the coverage of the
+    /// pixel with the given top left corner.
+    fn for_each_pixel(
+        &mut self,
+        (left, top, right, bottom): (f32, f32, f32, f32),
+        color: &Color<f32>,
+        coverage: impl Fn(f32, f32) -> f32,
+    ) {
+        let width = self.size.width as f32;
+        let height = self.size.height as f32;
+        let x_range = left.max(0.0).floor() as u32..right.min(width).ceil().max(0.0) as u32;
+        let y_range = top.max(0.0).floor() as u32..bottom.min(height).ceil().max(0.0) as u32;
+        for y in y_range {
+            for x in x_range.clone() {
+                let coverage = coverage(x as f32, y as f32);
+                if coverage > 0.0 {
+                    self.blend_pixel(x, y, color, coverage);
+                }
+            }
+        }
+    }
+
+    /// Blends `color` over the pixels whose center is inside the triangle of pixel positions
+    /// `a`, `b`, `c`, where its depth, interpolated from their `z`, is between 0 and 1 and lower
+    /// than the depth buffer. The depth buffer is updated with the depth of the blended pixels.
+    fn fill_depth_tested_triangle(
+        &mut self,
+        a: Vector3<f32>,
+        b: Vector3<f32>,
+        c: Vector3<f32>,
+        color: &Color<f32>,
+    ) {
+        let edge = |from: Vector3<f32>, to: Vector3<f32>, x: f32, y: f32| {
+            (to.x - from.x) * (y - from.y) - (to.y - from.y) * (x - from.x)
+        };
+        let area = edge(a, b, c.x, c.y);
+        if area == 0.0 {
+            return;
+        }
+        let width = self.size.width as f32;
+        let height = self.size.height as f32;
+        let left = a.x.min(b.x).min(c.x).max(0.0).floor() as u32;
+        let top = a.y.min(b.y).min(c.y).max(0.0).floor() as u32;
+        let right = a.x.max(b.x).max(c.x).min(width).ceil().max(0.0) as u32;
+        let bottom = a.y.max(b.y).max(c.y).min(height).ceil().max(0.0) as u32;
+        for y in top..bottom {
+            for x in left..right {
+                let (center_x, center_y) = (x as f32 + 0.5, y as f32 + 0.5);
+                // Dividing by the signed area makes the weights positive inside either winding.
+                let weight_a = edge(b, c, center_x, center_y) / area;
+                let weight_b = edge(c, a, center_x, center_y) / area;
+                let weight_c = edge(a, b, center_x, center_y) / area;
+                if weight_a < 0.0 || weight_b < 0.0 || weight_c < 0.0 {
+                    continue;
+                }
+                let depth = weight_a * a.z + weight_b * b.z + weight_c * c.z;
+                let index = y as usize * self.size.width as usize + x as usize;
+                if (0.0..=1.0).contains(&depth) && depth < self.depth[index] {
+                    self.depth[index] = depth;
+                    self.blend_pixel(x, y, color, 1.0);
+                }
+            }
+        }
+    }
+
+    /// Blends `color` over a pixel with `coverage` scaling its alpha.
+    fn blend_pixel(&mut self, x: u32, y: u32, color: &Color<f32>, coverage: f32) {
+        let offset = (y as usize * self.size.width as usize + x as usize) * 4;
+        let pixel = &mut self.pixels[offset..offset + 4];
+        let alpha = color.a * coverage;
+        let source = [color.r, color.g, color.b];
+        for (channel, source) in pixel.iter_mut().zip(source) {
+            let destination = *channel as f32 / 255.0;
+            *channel = to_unorm(source * alpha + destination * (1.0 - alpha));
+        }
+        let destination_alpha = pixel[3] as f32 / 255.0;
+        pixel[3] = to_unorm(alpha + destination_alpha * (1.0 - alpha));
+    }
+}
+
+/// Converts a channel in `[0, 1]` to its 8 bits value, clamping values outside.
+fn to_unorm(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Height of a line of the text fallback.
+fn line_height(format: &TextFormat) -> f32 {
+    GLYPH_CELL_SIZE.height * format.line_spacing
+}
+
+/// Splits `text` into lines at line breaks and where `format.wrapping` allows before
+/// `max_width`, each with the byte offset of its start in `text`. Lines past
+/// `format.max_lines` are left out, and the last line kept then ends with an ellipsis.
+fn layout_lines<'t>(
+    text: &'t str,
+    format: &TextFormat,
+    max_width: f32,
+) -> Vec<(usize, Cow<'t, str>)> {
+    let max_characters = match format.wrapping {
+        TextWrapping::None => usize::MAX,
+        TextWrapping::Word | TextWrapping::Character => {
+            ((max_width / GLYPH_CELL_SIZE.width) as usize).max(1)
+        }
+    };
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for line in text.split('\n') {
+        let mut rest = line;
+        let mut offset = start;
+        while let Some(split) = line_break(rest, format.wrapping, max_characters) {
+            lines.push((offset, Cow::Borrowed(&rest[..split])));
+            rest = &rest[split..];
+            offset += split;
+        }
+        lines.push((offset, Cow::Borrowed(rest)));
+        start += line.len() + 1;
+    }
+
+    if let Some(max_lines) = format.max_lines {
+        if lines.len() > max_lines as usize {
+            lines.truncate(max_lines as usize);
+            if let Some((_, last)) = lines.last_mut() {
+                let ellipsis = last
+                    .trim_end()
+                    .chars()
+                    .take(max_characters - 1)
+                    .chain(['…'])
+                    .collect();
+                *last = Cow::Owned(ellipsis);
+            }
+        }
+    }
+    lines
+}
+
+/// Byte offset where the first line of `text` ends, or `None` if it fits `max_characters`.
+/// Whitespace at the break stays on the line, past its last character.
+fn line_break(text: &str, wrapping: TextWrapping, max_characters: usize) -> Option<usize> {
+    let (overflow, character) = text.trim_end().char_indices().nth(max_characters)?;
+    let end = match wrapping {
+        TextWrapping::Word if !character.is_whitespace() => text[..overflow]
+            .rfind(char::is_whitespace)
+            .filter(|&space| !text[..space].trim_end().is_empty())
+            // Words longer than a line break where they overflow it.
+            .unwrap_or(overflow),
+        _ => overflow,
+    };
+    let rest = &text[end..];
+    Some(end + rest.len() - rest.trim_start().len())
+}
+
+/// Part of `rect` within `clip`, if any.
+fn intersect(rect: &Rect<f32>, clip: &Rect<f32>) -> Option<Rect<f32>> {
+    let left = rect.x.max(clip.x);
+    let top = rect.y.max(clip.y);
+    let right = (rect.x + rect.width).min(clip.x + clip.width);
+    let bottom = (rect.y + rect.height).min(clip.y + clip.height);
+    if right <= left || bottom <= top {
+        return None;
+    }
+    Some(Rect {
+        x: left,
+        y: top,
+        width: right - left,
+        height: bottom - top,
+    })
+}
@@ -0,0 +1,334 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Drawing session recording what was drawn instead of drawing it.
+//! Meant for tests of drawing logic that assert on the shapes drawn rather than on pixels.
+
+use std::ops::Range;
+
+use crate::{
+    math::{Matrix4x4, Rect, Vector2, Vector3},
+    renderer::{
+        check_text_spans, geometry, Color, DrawingSession, FrameStats, TextFormat, TextLayout,
+    },
+};
+
+/// Call to a `DrawingSession`, with all of its parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DrawCommand {
+    Clear {
+        color: Color<f32>,
+    },
+    /// Text and its colored spans, empty for `draw_text`. `draw_text_layout` records the text
+    /// of the layout in its layout box, colored by a single span.
+    Text {
+        text: String,
+        format: TextFormat,
+        coord: Rect<f32>,
+        spans: Vec<(Range<usize>, Color<f32>)>,
+    },
+    Triangle {
+        points: [Vector2<f32>; 3],
+        color: Color<f32>,
+    },
+    Rect {
+        rect: Rect<f32>,
+        color: Color<f32>,
+    },
+    RoundedRect {
+        rect: Rect<f32>,
+        corner_radius: f32,
+        color: Color<f32>,
+    },
+    Line {
+        from: Vector2<f32>,
+        to: Vector2<f32>,
+        thickness: f32,
+        color: Color<f32>,
+    },
+    Polygon {
+        points: Vec<Vector2<f32>>,
+        color: Color<f32>,
+    },
+    PolygonOutline {
+        points: Vec<Vector2<f32>>,
+        thickness: f32,
+        color: Color<f32>,
+    },
+    /// Circle within `bounds`. `draw_circle_centered_at` records its bounds too.
+    Circle {
+        bounds: Rect<f32>,
+        color: Color<f32>,
+    },
+    ClearDepth {
+        value: f32,
+    },
+    Mesh {
+        vertices: Vec<Vector3<f32>>,
+        indices: Vec<u32>,
+        transform: Matrix4x4<f32>,
+        color: Color<f32>,
+    },
+}
+
+/// Kind of a `DrawCommand`, to count or filter commands regardless of their parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DrawCommandKind {
+    Clear,
+    Text,
+    Triangle,
+    Rect,
+    RoundedRect,
+    Line,
+    Polygon,
+    PolygonOutline,
+    Circle,
+    ClearDepth,
+    Mesh,
+}
+
+impl DrawCommand {
+    pub fn kind(&self) -> DrawCommandKind {
+        match self {
+            DrawCommand::Clear { .. } => DrawCommandKind::Clear,
+            DrawCommand::Text { .. } => DrawCommandKind::Text,
+            DrawCommand::Triangle { .. } => DrawCommandKind::Triangle,
+            DrawCommand::Rect { .. } => DrawCommandKind::Rect,
+            DrawCommand::RoundedRect { .. } => DrawCommandKind::RoundedRect,
+            DrawCommand::Line { .. } => DrawCommandKind::Line,
+            DrawCommand::Polygon { .. } => DrawCommandKind::Polygon,
+            DrawCommand::PolygonOutline { .. } => DrawCommandKind::PolygonOutline,
+            DrawCommand::Circle { .. } => DrawCommandKind::Circle,
+            DrawCommand::ClearDepth { .. } => DrawCommandKind::ClearDepth,
+            DrawCommand::Mesh { .. } => DrawCommandKind::Mesh,
+        }
+    }
+}
+
+/// Drawing session appending every call to a list of `DrawCommand`s, in order.
+/// `flush` isn't recorded, as it doesn't change what is drawn.
+/// Statistics count the triangles a tessellating backend would draw for each shape.
+#[derive(Debug, Default)]
+pub struct RecordingSession {
+    commands: Vec<DrawCommand>,
+    stats: FrameStats,
+    circle_segments: Option<u32>,
+}
+
+impl RecordingSession {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the commands recorded so far, in the order they were drawn.
+    pub fn commands(&self) -> &[DrawCommand] {
+        &self.commands
+    }
+
+    /// Returns the commands recorded so far of the given kind, in the order they were drawn.
+    pub fn commands_of(&self, kind: DrawCommandKind) -> impl Iterator<Item = &DrawCommand> {
+        self.commands
+            .iter()
+            .filter(move |command| command.kind() == kind)
+    }
+
+    /// Returns the number of commands recorded so far of the given kind.
+    pub fn count_of(&self, kind: DrawCommandKind) -> usize {
+        self.commands_of(kind).count()
+    }
+
+    /// Returns the first `Text` command whose text contains `pattern`, if any.
+    pub fn find_text_containing(&self, pattern: &str) -> Option<&DrawCommand> {
+        self.commands.iter().find(
+            |command| matches!(command, DrawCommand::Text { text, .. } if text.contains(pattern)),
+        )
+    }
+
+    /// Removes the recorded commands and resets the statistics.
+    pub fn clear_commands(&mut self) {
+        self.commands.clear();
+        self.stats = FrameStats::default();
+    }
+
+    /// Counts a draw call of `triangles` triangles, none for shapes that are empty.
+    fn record_triangles(&mut self, triangles: usize) {
+        if triangles > 0 {
+            self.stats.record_draw_call(triangles as u32);
+        }
+    }
+}
+
+impl DrawingSession for RecordingSession {
+    fn clear(&mut self, color: &Color<f32>) {
+        self.commands.push(DrawCommand::Clear { color: *color });
+    }
+
+    fn draw_text(&mut self, text: &str, format: &TextFormat, coord: &Rect<f32>) {
+        self.draw_text_styled(text, format, coord, &[]);
+    }
+
+    fn draw_text_styled(
+        &mut self,
+        text: &str,
+        format: &TextFormat,
+        coord: &Rect<f32>,
+        spans: &[(Range<usize>, Color<f32>)],
+    ) {
+        check_text_spans(text, spans);
+        self.stats.record_text_run();
+        let glyphs = text.chars().filter(|c| !c.is_whitespace()).count();
+        self.record_triangles(2 * glyphs);
+        self.commands.push(DrawCommand::Text {
+            text: text.to_string(),
+            format: format.clone(),
+            coord: *coord,
+            spans: spans.to_vec(),
+        });
+    }
+
+    fn draw_text_layout(&mut self, layout: &TextLayout, origin: &Vector2<f32>, color: &Color<f32>) {
+        let spans = [(0..layout.text().len(), *color)];
+        self.draw_text_styled(
+            layout.text(),
+            layout.format(),
+            &layout.rect_at(origin),
+            &spans,
+        );
+    }
+
+    fn draw_triangle(&mut self, points: &[Vector2<f32>; 3], color: &Color<f32>) {
+        self.stats.record_draw_call(1);
+        self.commands.push(DrawCommand::Triangle {
+            points: *points,
+            color: *color,
+        });
+    }
+
+    fn draw_rectangle(&mut self, rect: &Rect<f32>, color: &Color<f32>) {
+        self.record_triangles(geometry::rectangle_vertices(rect).len() / 3);
+        self.commands.push(DrawCommand::Rect {
+            rect: *rect,
+            color: *color,
+        });
+    }
+
+    fn draw_rounded_rectangle(&mut self, rect: &Rect<f32>, corner_radius: f32, color: &Color<f32>) {
+        let vertices = geometry::rounded_rectangle_vertices(
+            rect,
+            corner_radius,
+            geometry::DEFAULT_TESSELLATION_TOLERANCE,
+        );
+        self.record_triangles(vertices.len() / 3);
+        self.commands.push(DrawCommand::RoundedRect {
+            rect: *rect,
+            corner_radius,
+            color: *color,
+        });
+    }
+
+    fn draw_line(
+        &mut self,
+        from: &Vector2<f32>,
+        to: &Vector2<f32>,
+        thickness: f32,
+        color: &Color<f32>,
+    ) {
+        self.record_triangles(geometry::line_vertices(*from, *to, thickness).len() / 3);
+        self.commands.push(DrawCommand::Line {
+            from: *from,
+            to: *to,
+            thickness,
+            color: *color,
+        });
+    }
+
+    fn draw_polygon(&mut self, points: &[Vector2<f32>], color: &Color<f32>) {
+        self.record_triangles(geometry::polygon_vertices(points).len() / 3);
+        self.commands.push(DrawCommand::Polygon {
+            points: points.to_vec(),
+            color: *color,
+        });
+    }
+
+    fn draw_polygon_outline(
+        &mut self,
+        points: &[Vector2<f32>],
+        thickness: f32,
+        color: &Color<f32>,
+    ) {
+        self.record_triangles(geometry::polygon_outline_vertices(points, thickness).len() / 3);
+        self.commands.push(DrawCommand::PolygonOutline {
+            points: points.to_vec(),
+            thickness,
+            color: *color,
+        });
+    }
+
+    fn draw_circle(&mut self, bounds: &Rect<f32>, color: &Color<f32>) {
+        let radius = bounds.width.max(bounds.height) / 2.0;
+        if bounds.width > 0.0 && bounds.height > 0.0 {
+            let segments = self.circle_segments.unwrap_or_else(|| {
+                geometry::circle_segment_count(radius, geometry::DEFAULT_TESSELLATION_TOLERANCE)
+            });
+            self.record_triangles(segments.max(geometry::MIN_CIRCLE_SEGMENTS) as usize);
+        }
+        self.commands.push(DrawCommand::Circle {
+            bounds: *bounds,
+            color: *color,
+        });
+    }
+
+    fn draw_circle_centered_at(&mut self, center: &Vector2<f32>, radius: f32, color: &Color<f32>) {
+        let bounds = Rect {
+            x: center.x - radius,
+            y: center.y - radius,
+            width: 2.0 * radius,
+            height: 2.0 * radius,
+        };
+        self.draw_circle(&bounds, color);
+    }
+
+    fn set_circle_segments(&mut self, segments: Option<u32>) {
+        self.circle_segments = segments;
+    }
+
+    fn clear_depth(&mut self, value: f32) {
+        self.commands.push(DrawCommand::ClearDepth { value });
+    }
+
+    fn draw_mesh(
+        &mut self,
+        vertices: &[Vector3<f32>],
+        indices: &[u32],
+        transform: &Matrix4x4<f32>,
+        color: &Color<f32>,
+    ) {
+        self.record_triangles(indices.len() / 3);
+        self.commands.push(DrawCommand::Mesh {
+            vertices: vertices.to_vec(),
+            indices: indices.to_vec(),
+            transform: *transform,
+            color: *color,
+        });
+    }
+
+    fn stats(&self) -> FrameStats {
+        self.stats
+    }
+}
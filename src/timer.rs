@@ -17,68 +17,170 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+pub mod frame_time_stats;
+#[cfg(feature = "renderer")]
 pub mod framerate_counter;
 pub mod performance_counter;
 
-pub use self::{framerate_counter::FramerateCounter, performance_counter::PerformanceCounter};
+#[cfg(feature = "renderer")]
+pub use self::framerate_counter::FramerateCounter;
+pub use self::{frame_time_stats::FrameTimeStats, performance_counter::PerformanceCounter};
 
 /// A timer that can be used to measure time between frames.
-/// Call `tick` to update the timer and call the update function at the start of each frame.
-/// 
+/// Call `tick` at the start of each frame, then read the time elapsed since the previous one.
+///
+/// Gameplay time is scaled by `time_scale` and stops while the timer is paused, for pause menus
+/// and slow motion. The `real_` variants measure wall clock time regardless, for animations of
+/// the user interface that should keep running. Pausing and scaling apply to the whole time
+/// between two ticks, as they are at the second one.
+///
 /// # Example
-/// ```
+/// ```no_run
 /// use sky_labs::timer::StepTimer;
-/// 
+///
 /// let mut timer = StepTimer::new();
 /// loop {
-///     timer = timer.tick(|timer| {
-///         println!("Elapsed time: {} seconds", timer.elapsed_seconds());
-///         // Do something
-///     });
+///     timer.tick();
+///     println!("Elapsed time: {} seconds", timer.elapsed_seconds());
+///     // Do something
 /// }
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct StepTimer {
+    /// Time of the last tick.
     pub current_time: PerformanceCounter,
+    /// Time of the tick before the last one.
     pub last_time: PerformanceCounter,
+    /// Time the timer was created at.
+    start_time: PerformanceCounter,
+    /// Gameplay time elapsed between the last two ticks.
+    elapsed: PerformanceCounter,
+    /// Gameplay time elapsed since the timer was created.
+    total_elapsed: PerformanceCounter,
+    time_scale: f64,
+    paused: bool,
 }
 
 impl StepTimer {
-    /// Creates a new StepTimer.
+    /// Creates a new StepTimer, started now.
     pub fn new() -> Self {
         PerformanceCounter::init();
+        Self::starting_at(PerformanceCounter::now())
+    }
+
+    /// Creates a timer started at `now`, to tick it with `tick_at` and counters of one's own,
+    /// as tests do. Unlike `new`, doesn't initialize `PerformanceCounter`.
+    pub fn starting_at(now: PerformanceCounter) -> Self {
         StepTimer {
-            current_time: PerformanceCounter::default(),
-            last_time: PerformanceCounter::default(),
+            current_time: now,
+            last_time: now,
+            start_time: now,
+            elapsed: PerformanceCounter::new(),
+            total_elapsed: PerformanceCounter::new(),
+            time_scale: 1.0,
+            paused: false,
         }
     }
 
-    /// Updates the timer and calls the update function.
-    pub fn tick<F>(&self, f_update: F) -> Self
-    where
-        F: Fn(&Self) -> (),
-    {
-        let now = PerformanceCounter::now();
-        let new_timer = StepTimer {
-            current_time: now,
-            last_time: self.current_time,
+    /// Updates the timer to the current time.
+    pub fn tick(&mut self) {
+        self.tick_at(PerformanceCounter::now());
+    }
+
+    /// Updates the timer to the time `now`, which must not be earlier than the last tick.
+    pub fn tick_at(&mut self, now: PerformanceCounter) {
+        self.last_time = self.current_time;
+        self.current_time = now;
+        self.elapsed = if self.paused {
+            PerformanceCounter::new()
+        } else {
+            let real_ticks = self.real_elapsed().ticks() as f64;
+            PerformanceCounter::from_ticks((real_ticks * self.time_scale).round() as u64)
         };
-        f_update(&new_timer);
-        new_timer
+        self.total_elapsed = self.total_elapsed + self.elapsed;
+    }
+
+    /// Stops gameplay time: ticks have no elapsed time until `resume` is called.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Restarts gameplay time after a `pause`.
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Multiplier of gameplay time, 1 by default.
+    pub fn time_scale(&self) -> f64 {
+        self.time_scale
+    }
+
+    /// Sets the multiplier of gameplay time, below 1 for slow motion and above 1 to speed it up.
+    ///
+    /// # Panics
+    /// Panics if `time_scale` is negative or not finite.
+    pub fn set_time_scale(&mut self, time_scale: f64) {
+        assert!(
+            time_scale.is_finite() && time_scale >= 0.0,
+            "Time scale must be finite and not negative."
+        );
+        self.time_scale = time_scale;
     }
 
-    /// Returns the time elapsed since the last tick.
+    /// Returns the gameplay time elapsed since the last tick: scaled, and zero while paused.
     pub fn elapsed(&self) -> PerformanceCounter {
-        self.current_time - self.last_time
+        self.elapsed
     }
 
-    /// Returns the time elapsed since the last tick in seconds.
+    /// Returns the gameplay time elapsed since the last tick in seconds.
     pub fn elapsed_seconds(&self) -> f64 {
-        (self.current_time - self.last_time).total_seconds()
+        self.elapsed.total_seconds()
     }
 
-    /// Returns the time elapsed since the last tick in milliseconds.
+    /// Returns the gameplay time elapsed since the last tick in milliseconds.
     pub fn elapsed_ms(&self) -> f64 {
-        (self.current_time - self.last_time).total_milliseconds()
+        self.elapsed.total_milliseconds()
+    }
+
+    /// Returns the wall clock time elapsed since the last tick, ignoring pause and time scale.
+    pub fn real_elapsed(&self) -> PerformanceCounter {
+        self.current_time - self.last_time
+    }
+
+    /// Returns the wall clock time elapsed since the last tick in seconds.
+    pub fn real_elapsed_seconds(&self) -> f64 {
+        self.real_elapsed().total_seconds()
+    }
+
+    /// Returns the gameplay time elapsed from the creation of the timer to the last tick.
+    pub fn total_elapsed(&self) -> PerformanceCounter {
+        self.total_elapsed
+    }
+
+    /// Returns the gameplay time elapsed from the creation of the timer to the last tick
+    /// in seconds.
+    pub fn total_elapsed_seconds(&self) -> f64 {
+        self.total_elapsed.total_seconds()
+    }
+
+    /// Returns the wall clock time elapsed from the creation of the timer to the last tick.
+    pub fn total_real_elapsed(&self) -> PerformanceCounter {
+        self.current_time - self.start_time
+    }
+
+    /// Returns the wall clock time elapsed from the creation of the timer to the last tick
+    /// in seconds.
+    pub fn total_real_elapsed_seconds(&self) -> f64 {
+        self.total_real_elapsed().total_seconds()
+    }
+}
+
+impl Default for StepTimer {
+    fn default() -> Self {
+        Self::new()
     }
 }
@@ -0,0 +1,162 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use super::performance_counter::PerformanceCounter;
+
+/// Number of frames `FrameTimeStats::default` keeps, four seconds at 60 frames per second.
+pub const DEFAULT_FRAME_TIME_CAPACITY: usize = 240;
+
+/// Times of the last frames, to find the stutters an average framerate hides.
+/// Both buffers are allocated by `new`, so recording frames never allocates.
+#[derive(Debug, Clone)]
+pub struct FrameTimeStats {
+    /// Ring buffer of frame times in milliseconds, the oldest at `next` once it is full.
+    frame_times: Box<[f64]>,
+    /// Index the next frame time is written to.
+    next: usize,
+    /// Number of frames recorded, at the start of both buffers.
+    len: usize,
+    /// The same frame times in ascending order, for percentiles.
+    sorted: Box<[f64]>,
+}
+
+impl FrameTimeStats {
+    /// Creates statistics over the last `capacity` frames.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is 0.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "Frame time capacity must be at least 1.");
+        Self {
+            frame_times: vec![0.0; capacity].into_boxed_slice(),
+            next: 0,
+            len: 0,
+            sorted: vec![0.0; capacity].into_boxed_slice(),
+        }
+    }
+
+    /// Number of frames kept, the oldest being dropped past it.
+    pub fn capacity(&self) -> usize {
+        self.frame_times.len()
+    }
+
+    /// Number of frames recorded, up to `capacity`.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Records the time of a frame, from the elapsed time of a `StepTimer` for instance.
+    pub fn record(&mut self, frame_time: PerformanceCounter) {
+        self.record_ms(frame_time.total_milliseconds());
+    }
+
+    /// Records the time of a frame in milliseconds, dropping the oldest one if full.
+    pub fn record_ms(&mut self, frame_time_ms: f64) {
+        let mut sorted_len = self.len;
+        if self.len == self.capacity() {
+            let oldest = self.frame_times[self.next];
+            let index = self.sorted[..sorted_len].partition_point(|time| *time < oldest);
+            self.sorted.copy_within(index + 1..sorted_len, index);
+            sorted_len -= 1;
+        } else {
+            self.len += 1;
+        }
+        self.frame_times[self.next] = frame_time_ms;
+        self.next = (self.next + 1) % self.capacity();
+
+        let index = self.sorted[..sorted_len].partition_point(|time| *time < frame_time_ms);
+        self.sorted.copy_within(index..sorted_len, index + 1);
+        self.sorted[index] = frame_time_ms;
+    }
+
+    /// Forgets every recorded frame.
+    pub fn clear(&mut self) {
+        self.next = 0;
+        self.len = 0;
+    }
+
+    /// Returns the recorded frame times in milliseconds, from the oldest to the latest.
+    pub fn frame_times_ms(&self) -> impl Iterator<Item = f64> + '_ {
+        let (latest, oldest) = self.frame_times[..self.len].split_at(self.next);
+        oldest.iter().chain(latest).copied()
+    }
+
+    /// Average frame time in milliseconds, or 0 if no frame was recorded.
+    pub fn average_ms(&self) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        self.sorted().iter().sum::<f64>() / self.len as f64
+    }
+
+    /// Frame time in milliseconds that `p` percent of the frames don't exceed, `p` being
+    /// clamped to `[0, 100]`. Interpolates linearly between the two nearest frame times, so
+    /// the 99th percentile of 59 frames of 16 ms and one of 100 ms is 16 + 0.41 * 84 = 50.44.
+    /// Returns 0 if no frame was recorded.
+    pub fn percentile_ms(&self, p: f32) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        let sorted = self.sorted();
+        let rank = p.clamp(0.0, 100.0) as f64 * (sorted.len() - 1) as f64 / 100.0;
+        let below = rank.floor() as usize;
+        let (low, high) = (sorted[below], sorted[rank.ceil() as usize]);
+        low + (high - low) * (rank - below as f64)
+    }
+
+    /// Longest frame time in milliseconds, or 0 if no frame was recorded.
+    pub fn worst_ms(&self) -> f64 {
+        self.sorted().last().copied().unwrap_or(0.0)
+    }
+
+    /// Counts the frames in each bucket delimited by the ascending frame times `buckets`, in
+    /// milliseconds: the first count is of the frames shorter than `buckets[0]`, the one at `i`
+    /// of those from `buckets[i - 1]` up to `buckets[i]` excluded, and the last one of those of
+    /// `buckets[buckets.len() - 1]` or longer. There is one more count than bucket edges,
+    /// each one the height of a bar for an overlay drawn with `draw_rectangle`.
+    ///
+    /// # Panics
+    /// Panics if `buckets` isn't in ascending order.
+    pub fn histogram(&self, buckets: &[f64]) -> Vec<u32> {
+        assert!(
+            buckets.windows(2).all(|edges| edges[0] < edges[1]),
+            "Histogram buckets must be in ascending order."
+        );
+        let mut counts = vec![0; buckets.len() + 1];
+        for time in self.sorted() {
+            counts[buckets.partition_point(|edge| edge <= time)] += 1;
+        }
+        counts
+    }
+
+    /// Recorded frame times in ascending order.
+    fn sorted(&self) -> &[f64] {
+        &self.sorted[..self.len]
+    }
+}
+
+impl Default for FrameTimeStats {
+    fn default() -> Self {
+        Self::new(DEFAULT_FRAME_TIME_CAPACITY)
+    }
+}
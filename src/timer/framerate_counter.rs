@@ -17,108 +17,75 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
-use windows::{
-    core::w,
-    Win32::Graphics::{
-        Direct2D::{
-            Common::{D2D1_COLOR_F, D2D_RECT_F},
-            D2D1_DRAW_TEXT_OPTIONS_NONE,
-        },
-        DirectWrite::{
-            DWriteCreateFactory, IDWriteFactory, IDWriteTextFormat, DWRITE_FACTORY_TYPE_SHARED,
-            DWRITE_FONT_STRETCH_NORMAL, DWRITE_FONT_STYLE_NORMAL, DWRITE_FONT_WEIGHT,
-            DWRITE_MEASURING_MODE_NATURAL,
-        },
-    },
+use std::fmt::Write;
+
+use super::{frame_time_stats::FrameTimeStats, performance_counter::PerformanceCounter};
+use crate::math::{Size, Vector2};
+use crate::renderer::{Color, DrawingSession, Renderer, TextFormat, TextLayout};
+
+/// Color the frames per second are drawn in.
+const TEXT_COLOR: Color<f32> = Color {
+    r: 0.0,
+    g: 1.0,
+    b: 0.0,
+    a: 1.0,
 };
 
-use super::performance_counter::PerformanceCounter;
-use crate::events::Event;
+/// Size of the box the frames per second are laid out in, at the top left corner.
+const TEXT_SIZE: Size<f32> = Size {
+    width: 80.0,
+    height: 40.0,
+};
 
 #[derive(Default)]
 pub struct FramerateCounter {
     frames_this_second: u32,
     time: PerformanceCounter,
     pub frames_per_second: u32,
-    render_text_format: Option<IDWriteTextFormat>,
+    /// Times of the last frames, for the stutters `frames_per_second` averages out.
+    pub frame_times: FrameTimeStats,
+    /// Buffer `frames_per_second` is formatted into for drawing.
+    text: String,
+    /// Layout of the drawn text, created by the first `draw`.
+    text_layout: Option<TextLayout>,
 }
 
 impl FramerateCounter {
-    pub(super) fn new() -> Self {
-        FramerateCounter {
-            frames_this_second: 0,
-            time: PerformanceCounter::default(),
-            frames_per_second: 0,
-            render_text_format: None,
-        }
-    }
-
-    pub(super) fn tick(&self, delta: PerformanceCounter) -> Self {
+    pub(crate) fn tick(&mut self, delta: PerformanceCounter) {
+        self.frame_times.record(delta);
         let now = self.time + delta;
-        let (frames_this_second, frames_per_second) =
-            if now.ticks >= PerformanceCounter::frequency() {
-                (0, self.frames_this_second)
-            } else {
-                (self.frames_this_second + 1, self.frames_per_second)
-            };
-        FramerateCounter {
-            frames_this_second,
-            time: PerformanceCounter {
-                ticks: now.ticks % PerformanceCounter::frequency(),
-            },
-            frames_per_second,
-            render_text_format: self.render_text_format.clone(),
-        }
-    }
-    
-    fn render(&self, render_target: &windows::Win32::Graphics::Direct2D::ID2D1RenderTarget) {
-        unsafe {
-            let frames_per_second = format!("{}", self.frames_per_second);
-            let mut u16_string: Vec<u16> = frames_per_second.encode_utf16().collect();
-            u16_string.push(0);
-            let layout_rect = D2D_RECT_F {
-                top: 0.0,
-                left: 0.0,
-                right: 80.0,
-                bottom: 40.0,
-            };
-            let green = D2D1_COLOR_F {
-                r: 0.0,
-                g: 1.0,
-                b: 0.0,
-                a: 1.0,
-            };
-            let brush = render_target.CreateSolidColorBrush(&green, None).unwrap();
-            render_target.DrawText(
-                &u16_string,
-                self.render_text_format.as_ref().unwrap(),
-                &layout_rect,
-                &brush,
-                D2D1_DRAW_TEXT_OPTIONS_NONE,
-                DWRITE_MEASURING_MODE_NATURAL,
-            )
+        if now.ticks >= PerformanceCounter::frequency() {
+            self.frames_per_second = self.frames_this_second;
+            self.frames_this_second = 0;
+        } else {
+            self.frames_this_second += 1;
         }
+        self.time = PerformanceCounter {
+            ticks: now.ticks % PerformanceCounter::frequency(),
+        };
     }
 
-    fn create_resources(
+    /// Draws the frames per second at the top left corner of `drawing_session`. The text is
+    /// formatted into the same buffer every frame and only laid out again when it changes, so
+    /// drawing doesn't allocate while the count is steady.
+    pub fn draw<'a, T: 'a + DrawingSession, R: Renderer<'a, T>>(
         &mut self,
-        _render_target: &windows::Win32::Graphics::Direct2D::ID2D1RenderTarget,
+        renderer: &'a R,
+        drawing_session: &mut T,
     ) {
-        unsafe {
-            let dwrite_factory: IDWriteFactory =
-                DWriteCreateFactory(DWRITE_FACTORY_TYPE_SHARED).unwrap();
-            let text_format = dwrite_factory
-                .CreateTextFormat(
-                    w!("Consolas"),
-                    None,
-                    DWRITE_FONT_WEIGHT(400),
-                    DWRITE_FONT_STYLE_NORMAL,
-                    DWRITE_FONT_STRETCH_NORMAL,
-                    40.0,
-                    w!("en-us"),
-                )
-                .unwrap();
-            self.render_text_format = Some(text_format);
-        }
+        self.text.clear();
+        write!(self.text, "{}", self.frames_per_second).unwrap();
+        let text_layout = match &mut self.text_layout {
+            Some(text_layout) => {
+                text_layout.update_text(&self.text);
+                text_layout
+            }
+            None => self.text_layout.insert(renderer.create_text_layout(
+                &self.text,
+                &TextFormat::default(),
+                TEXT_SIZE,
+            )),
+        };
+        drawing_session.draw_text_layout(text_layout, &Vector2::ZERO, &TEXT_COLOR);
     }
 }
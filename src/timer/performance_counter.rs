@@ -18,7 +18,11 @@
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 use std::ops::{Add, Sub};
+use std::time::Duration;
+#[cfg(not(target_os = "windows"))]
+use std::{sync::OnceLock, time::Instant};
 
+#[cfg(target_os = "windows")]
 use windows::Win32::System::Performance::{QueryPerformanceCounter, QueryPerformanceFrequency};
 
 static mut FREQUENCY: u64 = 0;
@@ -38,6 +42,7 @@ static mut FREQUENCY: u64 = 0;
 /// ```
 /// # Notes
 /// The performance counter is based on the Windows API QueryPerformanceCounter and QueryPerformanceFrequency.
+/// Other platforms count nanoseconds since the first use of the counter, with `std::time::Instant`.
 /// The performance counter is not thread-safe.
 /// The performance counter should not be used to display the current time to the user.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Default, Clone, Copy)]
@@ -49,9 +54,7 @@ impl PerformanceCounter {
     /// Initializes the performance counter module. Must be called before using the performance counter.
     pub fn init() {
         unsafe {
-            let mut frequency = 0i64;
-            QueryPerformanceFrequency(&mut frequency).unwrap();
-            FREQUENCY = frequency as u64;
+            FREQUENCY = query_frequency();
         }
     }
 
@@ -60,13 +63,21 @@ impl PerformanceCounter {
         PerformanceCounter { ticks: 0 }
     }
 
+    /// Creates a performance counter of `ticks` ticks, `frequency` of them making a second.
+    pub const fn from_ticks(ticks: u64) -> Self {
+        PerformanceCounter { ticks }
+    }
+
+    /// Returns the number of ticks of the performance counter.
+    pub const fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
     /// Creates a new performance counter with the current time.
     pub fn now() -> Self {
-        let mut qpc: i64 = 0;
-        unsafe {
-            QueryPerformanceCounter(&mut qpc).unwrap();
+        PerformanceCounter {
+            ticks: query_counter(),
         }
-        PerformanceCounter { ticks: qpc as u64 }
     }
 
     /// Returns the frequency of the performance counter
@@ -98,6 +109,15 @@ impl PerformanceCounter {
         }
     }
 
+    /// Returns the time elapsed from `earlier` to `self`, or zero if `earlier` is later.
+    pub fn duration_since(&self, earlier: &PerformanceCounter) -> Duration {
+        let ticks = self.ticks.saturating_sub(earlier.ticks);
+        let frequency = Self::frequency();
+        debug_assert!(frequency != 0, "PerformanceCounter::init() must be called before using the performance counter.");
+        let nanoseconds = (ticks % frequency) as u128 * 1_000_000_000 / frequency as u128;
+        Duration::new(ticks / frequency, nanoseconds as u32)
+    }
+
     /// Returns the milliseconds component of the performance counter
     pub fn milliseconds(&self) -> u64 {
         unsafe {
@@ -107,6 +127,37 @@ impl PerformanceCounter {
     }
 }
 
+#[cfg(target_os = "windows")]
+fn query_frequency() -> u64 {
+    let mut frequency = 0i64;
+    unsafe {
+        QueryPerformanceFrequency(&mut frequency).unwrap();
+    }
+    frequency as u64
+}
+
+#[cfg(target_os = "windows")]
+fn query_counter() -> u64 {
+    let mut qpc: i64 = 0;
+    unsafe {
+        QueryPerformanceCounter(&mut qpc).unwrap();
+    }
+    qpc as u64
+}
+
+/// Ticks are nanoseconds on the platforms without a performance counter backend.
+#[cfg(not(target_os = "windows"))]
+fn query_frequency() -> u64 {
+    1_000_000_000
+}
+
+/// Nanoseconds elapsed since the first call, which is the epoch of the counter.
+#[cfg(not(target_os = "windows"))]
+fn query_counter() -> u64 {
+    static EPOCH: OnceLock<Instant> = OnceLock::new();
+    EPOCH.get_or_init(Instant::now).elapsed().as_nanos() as u64
+}
+
 impl Add for PerformanceCounter {
     type Output = Self;
 
@@ -0,0 +1,107 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use std::cell::RefCell;
+use std::marker::PhantomData;
+
+use windows::Win32::{
+    Foundation::RPC_E_CHANGED_MODE,
+    System::Com::{
+        CoInitializeEx, CoUninitialize, COINIT, COINIT_APARTMENTTHREADED, COINIT_MULTITHREADED,
+    },
+};
+
+thread_local! {
+    /// Apartment joined by the windows created on this thread, left when the thread exits.
+    static WINDOW_APARTMENT: RefCell<Option<ComApartment>> = const { RefCell::new(None) };
+}
+
+/// Keeps COM initialized on the current thread while alive.
+///
+/// COM is initialized per thread, in either a multithreaded or a single-threaded apartment,
+/// and the first initialization of a thread decides its apartment. Creating a guard on a thread
+/// already in another apartment succeeds without changing it, see `is_initialized`.
+///
+/// Windows initialize COM as multithreaded on their thread if it wasn't already. Direct2D
+/// factories are created single-threaded and DirectWrite doesn't depend on the apartment, so
+/// rendering works in either apartment. Hosts that need a single-threaded apartment, e.g. for
+/// OLE drag and drop or shell dialogs, should create a guard with `single_threaded` before
+/// creating the first window on that thread.
+pub struct ComApartment {
+    /// Whether this guard holds an initialization that must be balanced by `CoUninitialize`.
+    initialized: bool,
+    /// COM initialization belongs to the thread that made it.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl ComApartment {
+    /// Joins the multithreaded apartment on the current thread.
+    pub fn multithreaded() -> Result<Self, String> {
+        Self::initialize(COINIT_MULTITHREADED)
+    }
+
+    /// Initializes a single-threaded apartment on the current thread.
+    pub fn single_threaded() -> Result<Self, String> {
+        Self::initialize(COINIT_APARTMENTTHREADED)
+    }
+
+    /// Whether this guard initialized COM, which is uninitialized again when it is dropped.
+    /// This is `false` when the thread was already in a different apartment.
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn initialize(apartment: COINIT) -> Result<Self, String> {
+        // Both S_OK and S_FALSE (already initialized in the same apartment) must be balanced
+        // by CoUninitialize. RPC_E_CHANGED_MODE leaves the existing apartment untouched.
+        let result = unsafe { CoInitializeEx(None, apartment) };
+        if result.is_ok() {
+            Ok(Self {
+                initialized: true,
+                _not_send: PhantomData,
+            })
+        } else if result == RPC_E_CHANGED_MODE {
+            Ok(Self {
+                initialized: false,
+                _not_send: PhantomData,
+            })
+        } else {
+            Err(result.message())
+        }
+    }
+}
+
+impl Drop for ComApartment {
+    fn drop(&mut self) {
+        if self.initialized {
+            unsafe { CoUninitialize() };
+        }
+    }
+}
+
+/// Makes sure COM is initialized on the current thread, once per thread.
+pub(super) fn ensure_window_apartment() -> Result<(), String> {
+    WINDOW_APARTMENT.with(|apartment| {
+        let mut apartment = apartment.borrow_mut();
+        if apartment.is_none() {
+            *apartment = Some(ComApartment::multithreaded()?);
+        }
+        Ok(())
+    })
+}
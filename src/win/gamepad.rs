@@ -0,0 +1,68 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! XInput backend of `input::gamepad`.
+
+use windows::Win32::Foundation::{ERROR_SUCCESS, WIN32_ERROR};
+use windows::Win32::UI::Input::XboxController::*;
+
+use crate::input::gamepad::{apply_radial_dead_zone, GamepadButtons, GamepadState, MAX_GAMEPADS};
+use crate::math::Vector2;
+
+// `input::gamepad` defines its constants without XInput, to build on every platform.
+const _: () = assert!(MAX_GAMEPADS == XUSER_MAX_COUNT);
+
+/// Reads the state of the gamepad of player `index`, or `None` if it isn't connected.
+pub(crate) fn read_gamepad(index: u32, dead_zone: f32) -> Option<GamepadState> {
+    let mut state = XINPUT_STATE::default();
+    let result = unsafe { XInputGetState(index, &mut state) };
+    (result == ERROR_SUCCESS.0).then(|| from_xinput(&state.Gamepad, dead_zone))
+}
+
+/// Sets the speed of the motors of the gamepad of player `index`.
+pub(crate) fn write_vibration(index: u32, left: u16, right: u16) -> Result<(), String> {
+    let vibration = XINPUT_VIBRATION {
+        wLeftMotorSpeed: left,
+        wRightMotorSpeed: right,
+    };
+    let result = unsafe { XInputSetState(index, &vibration) };
+    if result == ERROR_SUCCESS.0 {
+        Ok(())
+    } else {
+        Err(WIN32_ERROR(result).to_hresult().message())
+    }
+}
+
+fn from_xinput(gamepad: &XINPUT_GAMEPAD, dead_zone: f32) -> GamepadState {
+    let stick = |x: i16, y: i16| {
+        apply_radial_dead_zone(Vector2::new(axis_value(x), axis_value(y)), dead_zone)
+    };
+    GamepadState {
+        buttons: GamepadButtons::from_bits_truncate(gamepad.wButtons.0),
+        left_stick: stick(gamepad.sThumbLX, gamepad.sThumbLY),
+        right_stick: stick(gamepad.sThumbRX, gamepad.sThumbRY),
+        left_trigger: gamepad.bLeftTrigger as f32 / u8::MAX as f32,
+        right_trigger: gamepad.bRightTrigger as f32 / u8::MAX as f32,
+    }
+}
+
+/// Maps a stick axis to `[-1, 1]`. The negative range has one more value, clamped to -1.
+fn axis_value(value: i16) -> f32 {
+    (value as f32 / i16::MAX as f32).max(-1.0)
+}
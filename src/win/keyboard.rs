@@ -17,3 +17,17 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+use windows::Win32::UI::Input::KeyboardAndMouse::GetAsyncKeyState;
+
+use crate::input::keyboard::{KeyState, VirtualKey};
+
+/// Keyboard backend of `input::keyboard::get_key_state`.
+pub(crate) fn async_key_state(key: VirtualKey) -> KeyState {
+    unsafe {
+        match GetAsyncKeyState(key.0 as i32) {
+            i16::MIN..=-1 => KeyState::Pressed,
+            0 => KeyState::Released,
+            1.. => KeyState::WasPressed,
+        }
+    }
+}
@@ -17,5 +17,11 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+mod com;
+pub(super) mod gamepad;
+pub(super) mod keyboard;
 pub(super) mod window;
-pub(super) mod renderer_d3d12;
\ No newline at end of file
+#[cfg(feature = "renderer-d3d12")]
+pub(super) mod renderer_d3d12;
+
+pub use com::ComApartment;
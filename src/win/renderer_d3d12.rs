@@ -20,66 +20,482 @@
 #[cfg(debug_assertions)]
 mod debug;
 mod drawing_session;
+mod glyph_atlas;
+mod pipeline;
 mod text;
 
-use std::{mem::ManuallyDrop, sync::Mutex};
+use std::{
+    cell::{Cell, RefCell},
+    mem::ManuallyDrop,
+    rc::{Rc, Weak},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
 
-use crate::{math::Size, renderer::*, window::Window};
+use crate::{
+    events::{renderer::RendererEvent, window::WindowEvent, Observer},
+    math::Size,
+    renderer::*,
+    window::{NativeWindowHandle, Window, DEFAULT_DPI},
+};
 
 use drawing_session::Direct3D12DrawingSession;
-use windows::{
-    core::s,
-    Win32::{
-        Foundation::{BOOL, HANDLE, WAIT_OBJECT_0},
-        Graphics::{
-            Direct3D::{
-                Fxc::{D3DCompile, D3DCOMPILE_DEBUG, D3DCOMPILE_SKIP_OPTIMIZATION},
-                *,
-            },
-            Direct3D12::*,
-            Dxgi::{Common::*, *},
-        },
-        System::Threading::{CreateEventW, WaitForSingleObject},
+use pipeline::{PipelineKind, PipelineRegistry};
+use text::TextResources;
+use windows::Win32::{
+    Foundation::{CloseHandle, HANDLE, WAIT_OBJECT_0},
+    Graphics::{
+        Direct3D::*,
+        Direct3D12::*,
+        Dxgi::{Common::*, *},
     },
+    System::Threading::{CreateEventW, WaitForSingleObject},
+    UI::HiDpi::GetDpiForWindow,
 };
 use windows_core::Interface;
 
-/// Number of frames in the swap chain
-const FRAME_COUNT: u32 = 2;
+/// Format of the depth buffer of renderers created with `RendererOptions::depth`.
+const DEPTH_FORMAT: DXGI_FORMAT = DXGI_FORMAT_D32_FLOAT;
+
+/// Lowest feature level a device must support to be used by the renderer.
+/// The pipelines only need shader model 5.1 and root signature 1.0, which every Direct3D12
+/// device supports, so nothing above 11_0 is required.
+const MINIMUM_FEATURE_LEVEL: D3D_FEATURE_LEVEL = D3D_FEATURE_LEVEL_11_0;
+
+/// Format of the swap chain buffers of `format`. Flip model swap chains can't have sRGB
+/// buffers, so those of `Rgba8UnormSrgb` are UNORM and only their views are sRGB.
+fn buffer_format(format: BackBufferFormat) -> DXGI_FORMAT {
+    match format {
+        BackBufferFormat::Rgba8Unorm | BackBufferFormat::Rgba8UnormSrgb => {
+            DXGI_FORMAT_R8G8B8A8_UNORM
+        }
+        BackBufferFormat::Rgba16Float => DXGI_FORMAT_R16G16B16A16_FLOAT,
+    }
+}
+
+/// Format of the render target views, offscreen targets and pipelines of `format`.
+fn view_format(format: BackBufferFormat) -> DXGI_FORMAT {
+    match format {
+        BackBufferFormat::Rgba8Unorm => DXGI_FORMAT_R8G8B8A8_UNORM,
+        BackBufferFormat::Rgba8UnormSrgb => DXGI_FORMAT_R8G8B8A8_UNORM_SRGB,
+        BackBufferFormat::Rgba16Float => DXGI_FORMAT_R16G16B16A16_FLOAT,
+    }
+}
+
+/// Color space buffers of `format` are presented in: sRGB for 8 bit buffers, and scRGB, linear
+/// with the same Rec.709 primaries, for float buffers.
+fn color_space(format: BackBufferFormat) -> DXGI_COLOR_SPACE_TYPE {
+    match format {
+        BackBufferFormat::Rgba8Unorm | BackBufferFormat::Rgba8UnormSrgb => {
+            DXGI_COLOR_SPACE_RGB_FULL_G22_NONE_P709
+        }
+        BackBufferFormat::Rgba16Float => DXGI_COLOR_SPACE_RGB_FULL_G10_NONE_P709,
+    }
+}
 
 /// Direct3D12 Renderer
+///
+/// The device can be removed while the renderer is in use, for instance by a driver update, a GPU
+/// reset or the window moving to another adapter. `present` then reports
+/// `RendererEvent::DeviceLost` through `poll_event` instead of panicking, and nothing can be
+/// drawn until `recreate` rebuilds the device and every resource created on it: drawing with a
+/// lost device panics. `TextLayout`s and `FontHandle`s are DirectWrite objects, independent of
+/// the device, so they stay valid and don't need to be created again.
 pub struct Direct3D12Renderer {
     rtv_descriptor_size: u32,
-    pipeline_state: ID3D12PipelineState, // TODO: move out of here
-    command_allocator: ID3D12CommandAllocator,
-    render_target_views: [ID3D12Resource; FRAME_COUNT as usize],
+    /// Command allocator of each back buffer, `RendererOptions::buffer_count` of them.
+    command_allocators: Vec<ID3D12CommandAllocator>,
+    /// Render target of each back buffer, the same offscreen target for all of them when
+    /// drawing offscreen. Replaced when the buffers are resized.
+    render_target_views: RefCell<Vec<ID3D12Resource>>,
     rtv_descriptor_heap: ID3D12DescriptorHeap,
-    swap_chain: IDXGISwapChain3,
+    /// Depth buffer of the render targets, `None` unless created with `RendererOptions::depth`.
+    depth_buffer: RefCell<Option<DepthBuffer>>,
+    /// Swap chain presenting to a window, `None` for offscreen renderers.
+    swap_chain: Option<IDXGISwapChain3>,
+    /// Window the swap chain presents to, `None` for offscreen renderers.
+    window_handle: Option<NativeWindowHandle>,
+    /// Signaled when the swap chain can queue another frame, `None` unless created with
+    /// `RendererOptions::max_frame_latency`. Closed by `shutdown`.
+    frame_latency_waitable: Option<HANDLE>,
+    /// Size of the render targets, in physical pixels. Updated when the buffers are resized.
+    size: Cell<Size<u32>>,
+    /// Physical pixels per device independent pixel of the window, as of the last `resize`.
+    /// Always 1.0 for offscreen renderers.
+    dpi_scale: Cell<f32>,
+    /// Resize observed on the window, applied by the next `begin_draw`. `None` for offscreen
+    /// renderers and without `RendererOptions::auto_resize`.
+    pending_resize: Option<Rc<RefCell<PendingResize>>>,
+    /// Buffer the offscreen render target is copied into at `end_draw`, `None` for window renderers.
+    readback_buffer: Option<ID3D12Resource>,
     command_queue: ID3D12CommandQueue,
     frame_fence: ID3D12Fence,
     frame_event: HANDLE,
-    fence_value: Mutex<u64>,
+    frame_state: Mutex<FrameState>,
+    /// Root signatures and pipeline states, created on first use.
+    pipelines: PipelineRegistry,
+    /// Glyph atlas used to draw text.
+    text_resources: TextResources,
     device: ID3D12Device,
+    info: RendererInfo,
+    /// Options the renderer was created with, to create it again in `recreate`.
+    options: RendererOptions,
+    /// Format of the back buffers, `options.format` unless the swap chain fell back to
+    /// `Rgba8Unorm`.
+    format: BackBufferFormat,
+    /// Set once `shutdown` released the resources that aren't dropped with the renderer.
+    is_shut_down: bool,
+    /// Reports the objects left alive, after every other field was dropped.
+    _live_objects: LiveObjectsReport,
+}
+
+/// Depth texture shared by every frame, with the heap of its depth stencil view.
+struct DepthBuffer {
+    /// Only referenced by the view, kept alive with it.
+    _texture: ID3D12Resource,
+    dsv_descriptor_heap: ID3D12DescriptorHeap,
+}
+
+impl DepthBuffer {
+    fn dsv_handle(&self) -> D3D12_CPU_DESCRIPTOR_HANDLE {
+        unsafe { self.dsv_descriptor_heap.GetCPUDescriptorHandleForHeapStart() }
+    }
+}
+
+/// Resize requested by the events of the window the renderer presents to, observed on the
+/// window so the application doesn't have to forward them.
+#[derive(Debug, Default)]
+struct PendingResize {
+    /// Size of the last `WindowEvent::Resized` not applied yet.
+    size: Option<Size<u32>>,
+    /// Whether a `WindowEvent::DpiChanged` wasn't applied yet.
+    dpi_changed: bool,
+}
+
+impl PendingResize {
+    /// Takes the size to resize buffers of `current` size to, `current` itself when only the DPI
+    /// changed, or `None` if nothing happened since the last call.
+    fn take(&mut self, current: Size<u32>) -> Option<Size<u32>> {
+        let dpi_changed = std::mem::take(&mut self.dpi_changed);
+        match self.size.take() {
+            Some(size) => Some(size),
+            None if dpi_changed => Some(current),
+            None => None,
+        }
+    }
+}
+
+/// Only records the events: they can arrive while a frame is being drawn, which must keep the
+/// buffers it started with.
+impl Observer<WindowEvent> for PendingResize {
+    fn on_event(&mut self, event: &WindowEvent) {
+        match event {
+            WindowEvent::Resized(size) => self.size = Some(*size),
+            WindowEvent::DpiChanged(_) => self.dpi_changed = true,
+            _ => {}
+        }
+    }
+}
+
+/// Reports the live DXGI objects when dropped, in debug builds.
+/// Kept as the last field of the renderer so its COM objects were released before.
+struct LiveObjectsReport;
+
+impl Drop for LiveObjectsReport {
+    fn drop(&mut self) {
+        #[cfg(debug_assertions)]
+        debug::report_live_objects();
+    }
+}
+
+/// Fence values signaled after the work submitted for each frame.
+#[derive(Debug)]
+struct FrameFenceValues {
+    last_signaled: u64,
+    frames: Vec<u64>,
+}
+
+impl FrameFenceValues {
+    /// Creates the fence values of `buffer_count` back buffers, none of them pending.
+    fn new(buffer_count: usize) -> Self {
+        Self {
+            last_signaled: 0,
+            frames: vec![0; buffer_count],
+        }
+    }
+
+    /// Returns the next fence value to signal, recording it as pending for `frame_index`.
+    fn next(&mut self, frame_index: usize) -> u64 {
+        self.last_signaled += 1;
+        self.frames[frame_index] = self.last_signaled;
+        self.last_signaled
+    }
+
+    /// Value the fence must reach before the resources of `frame_index` can be reused.
+    fn pending(&self, frame_index: usize) -> u64 {
+        self.frames[frame_index]
+    }
+}
+
+struct FrameState {
+    /// Index of the back buffer being rendered to.
+    index: usize,
+    /// Number of frames submitted with `end_draw`.
+    frame_count: u64,
+    fence_values: FrameFenceValues,
+    /// Resources referenced by submitted command lists, kept alive until their frame completes.
+    retained_resources: Vec<Vec<ID3D12Resource>>,
+    /// Time spent waiting on the fence for the frame being recorded.
+    gpu_wait: Duration,
+    last_stats: FrameStats,
+    device_loss: DeviceLoss,
+}
+
+/// Removal of the device, reported once by `poll_event`.
+#[derive(Debug, Default)]
+struct DeviceLoss {
+    /// Reason the device was removed, `None` while it is usable.
+    reason: Option<String>,
+    /// Whether the removal still has to be reported by `poll_event`.
+    unreported: bool,
+}
+
+impl DeviceLoss {
+    /// Records that the device was removed for `reason`, keeping the first reason if it was
+    /// already recorded, and returns the matching error.
+    fn record(&mut self, reason: String) -> RendererError {
+        if self.reason.is_none() {
+            self.reason = Some(reason);
+            self.unreported = true;
+        }
+        RendererError::DeviceLost(self.reason.clone().unwrap_or_default())
+    }
+
+    fn is_lost(&self) -> bool {
+        self.reason.is_some()
+    }
+
+    fn poll_event(&mut self) -> Option<RendererEvent> {
+        if !self.unreported {
+            return None;
+        }
+        self.unreported = false;
+        self.reason.clone().map(RendererEvent::DeviceLost)
+    }
+}
+
+/// Device status, behind a trait so the handling of a removed device can be tested without one.
+trait DeviceStatus {
+    /// Returns the reason the device was removed, or `None` if it is still usable.
+    fn removed_reason(&self) -> Option<String>;
+}
+
+impl DeviceStatus for ID3D12Device {
+    fn removed_reason(&self) -> Option<String> {
+        unsafe { self.GetDeviceRemovedReason() }
+            .err()
+            .map(|e| e.to_string())
+    }
+}
+
+/// Turns the failure of a call on `device` into a `RendererError`, recording the removal of the
+/// device in `loss` if it was the cause.
+fn classify_device_error(
+    error: &impl std::fmt::Display,
+    device: &impl DeviceStatus,
+    loss: &mut DeviceLoss,
+) -> RendererError {
+    match device.removed_reason() {
+        Some(reason) => loss.record(reason),
+        None => RendererError::Failed(error.to_string()),
+    }
 }
 
 impl<'a> Renderer<'a, Direct3D12DrawingSession<'a>> for Direct3D12Renderer {
     /// Creates renderer that draws directly into the specified window
     /// Since the renderer is a essential part of the application, it will panic if it fails to create.
     fn create_for_window(window: &Window) -> Self {
+        Self::create_for_window_with_options(window, &RendererOptions::default())
+    }
+
+    /// Creates renderer that draws into a texture instead of a window.
+    /// The contents of the last frame can be read back with `read_pixels`.
+    fn create_offscreen(size: Size<u32>) -> Result<Self, String> {
+        Self::create_offscreen_with_options(size, &RendererOptions::default())
+            .map_err(|e| e.to_string())
+    }
+
+    /// Returns the size of the render targets, in physical pixels, as of the last `resize`.
+    fn size(&'a self) -> Size<f32> {
+        self.size.get().to_f32()
+    }
+
+    /// Returns the scale factor of the window the renderer presents to, from its DPI as of the
+    /// last `resize`. Offscreen renderers have a scale factor of 1.0.
+    fn scale_factor(&'a self) -> f32 {
+        self.dpi_scale.get()
+    }
+
+    fn measure_text(&'a self, text: &str, format: &TextFormat, max_width: f32) -> TextMetrics {
+        text::measure_text(text, format, max_width).expect("Failed to measure text.")
+    }
+
+    /// Lays the text out with DirectWrite, keeping the layout until `TextLayout::update_text`
+    /// changes the text.
+    fn create_text_layout(
+        &'a self,
+        text: &str,
+        format: &TextFormat,
+        max_size: Size<f32>,
+    ) -> TextLayout {
+        let layout = text::DirectWriteTextLayout::create(text, format, max_size)
+            .expect("Failed to lay out text.");
+        TextLayout::new(text, format, max_size, Some(Box::new(layout)))
+    }
+
+    /// Loads the font with DirectWrite into a font collection of its own, which the renderer
+    /// looks its family up in when laying out text.
+    fn load_font_from_bytes(&'a self, data: &[u8]) -> Result<FontHandle, String> {
+        match text::load_font(data) {
+            Ok(font) => Ok(FontHandle::new(font)),
+            Err(e) => Err(format!("Unable to load font: {}", e)),
+        }
+    }
+
+    /// Resizes the buffers first if the window was resized since the previous frame, with
+    /// `RendererOptions::auto_resize`.
+    fn begin_draw(&'a self) -> Direct3D12DrawingSession<'a> {
+        self.apply_pending_resize();
+        {
+            // Normally a no-op since `present` already waited, but guards against drawing twice
+            // on the same frame while the GPU still uses its command allocator.
+            let mut state = self.frame_state.lock().unwrap();
+            assert!(
+                !state.device_loss.is_lost(),
+                "Device lost, call `recreate` before drawing."
+            );
+            state.gpu_wait += self.wait_for_fence_value(state.fence_values.pending(state.index));
+            state.retained_resources[state.index].clear();
+            if let Err(e) = unsafe { self.command_allocators[state.index].Reset() } {
+                match self.device_error(&mut state, &e) {
+                    RendererError::DeviceLost(reason) => {
+                        panic!("Device lost, call `recreate` before drawing: {}", reason)
+                    }
+                    RendererError::Failed(e) => panic!("Failed to reset Command Allocator: {}", e),
+                }
+            }
+        }
+        Direct3D12DrawingSession::new(&self)
+    }
+
+    fn end_draw(&'a self, drawing_session: Direct3D12DrawingSession<'a>) {
+        unsafe {
+            match &self.readback_buffer {
+                Some(readback_buffer) => {
+                    self.copy_to_readback_buffer(&drawing_session.command_list, readback_buffer)
+                }
+                None => drawing_session
+                    .command_list
+                    .ResourceBarrier(&[transition_barrier(
+                        &self.current_frame(),
+                        D3D12_RESOURCE_STATE_RENDER_TARGET,
+                        D3D12_RESOURCE_STATE_PRESENT,
+                    )]),
+            }
+            drawing_session
+                .command_list
+                .Close()
+                .expect("Failed to close Command List.");
+        }
+
+        unsafe {
+            self.command_queue
+                .ExecuteCommandLists(&[Some(drawing_session.command_list.cast().unwrap())])
+        };
+
         #[cfg(debug_assertions)]
-        debug::init();
+        debug::dump_debug_messages(&self.device);
 
-        let device = create_d3d_device().unwrap();
+        let mut state = self.frame_state.lock().unwrap();
+        self.signal_frame(&mut state);
+        let index = state.index;
+        state.retained_resources[index].extend(drawing_session.resources);
+        state.frame_count += 1;
+        state.last_stats = FrameStats {
+            gpu_wait_ms: std::mem::take(&mut state.gpu_wait).as_secs_f64() * 1000.0,
+            ..drawing_session.stats
+        };
+    }
 
-        let frame_fence = unsafe { device.CreateFence(0, D3D12_FENCE_FLAG_NONE) }.unwrap();
+    fn last_frame_stats(&'a self) -> FrameStats {
+        self.frame_state.lock().unwrap().last_stats
+    }
 
-        let frame_event = unsafe { CreateEventW(None, false, false, None) }.unwrap();
+    fn frame_index(&'a self) -> u64 {
+        self.frame_state.lock().unwrap().frame_count
+    }
 
-        let command_queue = create_command_queue(&device).unwrap();
+    /// Returns the index of the swap chain buffer drawn to, below `RendererOptions::buffer_count`,
+    /// which moves to the next buffer on `present`. Offscreen renderers only draw to buffer 0.
+    fn back_buffer_index(&'a self) -> u32 {
+        self.current_frame_index() as u32
+    }
 
-        let swap_chain = create_swap_chain(&window, &command_queue).unwrap();
+    /// Returns whether the back buffers are `Rgba16Float`, presented as scRGB for window
+    /// renderers. `false` when the swap chain fell back to `Rgba8Unorm`.
+    fn is_hdr_enabled(&'a self) -> bool {
+        self.format.is_hdr()
+    }
 
-        let rtv_descriptor_heap = create_rtv_descriptor_heap(&device).unwrap();
+    /// Waits on the frame latency waitable object of the swap chain, see
+    /// `try_wait_for_next_frame`, giving up after a second so a removed device can't block the
+    /// game loop.
+    fn wait_for_next_frame(&'a self) {
+        if !self.try_wait_for_next_frame(Duration::from_secs(1)) {
+            log::warn!("Timeout waiting for the next frame.");
+        }
+    }
+}
+
+impl Drop for Direct3D12Renderer {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+impl Direct3D12Renderer {
+    /// Creates renderer that draws directly into the specified window, with the given options.
+    /// Since the renderer is a essential part of the application, it will panic if it fails to create.
+    pub fn create_for_window_with_options(window: &Window, options: &RendererOptions) -> Self {
+        Self::try_create_for_window_with_options(window, options).unwrap()
+    }
+
+    /// Creates renderer that draws directly into the specified window, with the given options,
+    /// returning an error instead of panicking if it fails to create, for instance because the
+    /// options are invalid.
+    pub fn try_create_for_window_with_options(
+        window: &Window,
+        options: &RendererOptions,
+    ) -> Result<Self, RendererError> {
+        options.validate()?;
+
+        #[cfg(debug_assertions)]
+        debug::init();
+
+        let (device, info) = create_d3d_device(options.adapter)?;
+
+        let command_queue = create_command_queue(&device)?;
+
+        let swap_chain = create_swap_chain(
+            &window,
+            &command_queue,
+            options.buffer_count,
+            options.format,
+            options.max_frame_latency,
+        )?;
+        let format = set_color_space(&swap_chain, options.format)?;
+
+        let rtv_descriptor_heap = create_rtv_descriptor_heap(&device, options.buffer_count)?;
         let rtv_descriptor_size =
             unsafe { device.GetDescriptorHandleIncrementSize(D3D12_DESCRIPTOR_HEAP_TYPE_RTV) };
 
@@ -88,101 +504,352 @@ impl<'a> Renderer<'a, Direct3D12DrawingSession<'a>> for Direct3D12Renderer {
             &rtv_descriptor_heap,
             rtv_descriptor_size,
             &swap_chain,
+            view_format(format),
+            options.buffer_count,
         );
 
-        let command_allocator = create_command_allocator(&device).unwrap();
+        let mut renderer = Self::create_with_render_targets(
+            device,
+            info,
+            options,
+            format,
+            command_queue,
+            Some((swap_chain, window.native_window_handle())),
+            None,
+            rtv_descriptor_heap,
+            render_target_views,
+        )?;
+        renderer.dpi_scale.set(dpi_scale(window.dpi()));
+        if options.max_frame_latency.is_some() {
+            let swap_chain = renderer.swap_chain.as_ref().unwrap();
+            renderer.frame_latency_waitable =
+                Some(unsafe { swap_chain.GetFrameLatencyWaitableObject() });
+        }
+        if options.auto_resize {
+            let pending_resize = Rc::new(RefCell::new(PendingResize::default()));
+            let observer: Weak<RefCell<dyn Observer<WindowEvent>>> = Rc::downgrade(&pending_resize);
+            window.add_observer(observer);
+            renderer.pending_resize = Some(pending_resize);
+        }
+        Ok(renderer)
+    }
 
-        let pipeline_state = compile_shaders(&device).unwrap();
+    /// Creates renderer that draws into a texture instead of a window, with the given options.
+    /// The contents of the last frame can be read back with `read_pixels`.
+    pub fn create_offscreen_with_options(
+        size: Size<u32>,
+        options: &RendererOptions,
+    ) -> Result<Self, RendererError> {
+        options.validate()?;
 
-        Self {
+        #[cfg(debug_assertions)]
+        debug::init();
+
+        let (device, info) = create_d3d_device(options.adapter)?;
+
+        let command_queue = create_command_queue(&device)?;
+
+        let rtv_descriptor_heap = create_rtv_descriptor_heap(&device, options.buffer_count)?;
+        let rtv_descriptor_size =
+            unsafe { device.GetDescriptorHandleIncrementSize(D3D12_DESCRIPTOR_HEAP_TYPE_RTV) };
+
+        let render_target = create_offscreen_render_target(&device, size, options.format)?;
+        let render_target_views = create_offscreen_render_target_views(
+            &device,
+            &rtv_descriptor_heap,
+            rtv_descriptor_size,
+            &render_target,
+            options.buffer_count,
+        );
+
+        let readback_buffer = create_readback_buffer(
+            &device,
+            readback_row_pitch(size.width, options.format) as u64 * size.height as u64,
+        )?;
+
+        Self::create_with_render_targets(
             device,
+            info,
+            options,
+            options.format,
             command_queue,
-            swap_chain,
+            None,
+            Some(readback_buffer),
             rtv_descriptor_heap,
-            rtv_descriptor_size,
             render_target_views,
-            command_allocator,
-            pipeline_state,
-            frame_fence,
-            frame_event,
-            fence_value: Mutex::new(0),
-        }
+        )
+        .map_err(RendererError::from)
     }
 
-    /// Returns the size of the final draw size
-    fn size(&'a self) -> Size<f32> {
-        let result = unsafe { self.swap_chain.GetDesc1() };
-        match result {
-            Ok(desc) => Size::<f32> {
-                width: desc.Width as f32,
-                height: desc.Height as f32,
-            },
-            Err(e) => {
-                println!("RendererD3D12::size() error: {}", e);
-                Size::<f32>::default()
-            }
+    fn create_with_render_targets(
+        device: ID3D12Device,
+        info: RendererInfo,
+        options: &RendererOptions,
+        format: BackBufferFormat,
+        command_queue: ID3D12CommandQueue,
+        swap_chain: Option<(IDXGISwapChain3, NativeWindowHandle)>,
+        readback_buffer: Option<ID3D12Resource>,
+        rtv_descriptor_heap: ID3D12DescriptorHeap,
+        render_target_views: Vec<ID3D12Resource>,
+    ) -> Result<Self, String> {
+        let (swap_chain, window_handle) = swap_chain.unzip();
+        let desc = unsafe { render_target_views[0].GetDesc() };
+        let size = Size::new(desc.Width as u32, desc.Height);
+
+        let rtv_descriptor_size =
+            unsafe { device.GetDescriptorHandleIncrementSize(D3D12_DESCRIPTOR_HEAP_TYPE_RTV) };
+
+        #[cfg(debug_assertions)]
+        debug::set_message_filter(&device, log::Level::Warn);
+
+        let frame_fence = match unsafe { device.CreateFence(0, D3D12_FENCE_FLAG_NONE) } {
+            Ok(fence) => fence,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        let frame_event = match unsafe { CreateEventW(None, false, false, None) } {
+            Ok(event) => event,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        let buffer_count = render_target_views.len();
+        let mut command_allocators = Vec::with_capacity(buffer_count);
+        for _ in 0..buffer_count {
+            command_allocators.push(create_command_allocator(&device)?);
         }
-    }
 
-    fn begin_draw(&'a self) -> Direct3D12DrawingSession<'a> {
-        unsafe {
-            self.command_allocator
-                .Reset()
-                .expect("Failed to reset Command Allocator.")
+        let frame_state = FrameState {
+            index: match &swap_chain {
+                Some(swap_chain) => (unsafe { swap_chain.GetCurrentBackBufferIndex() }) as usize,
+                None => 0,
+            },
+            frame_count: 0,
+            fence_values: FrameFenceValues::new(buffer_count),
+            retained_resources: (0..buffer_count).map(|_| Vec::new()).collect(),
+            gpu_wait: Duration::ZERO,
+            last_stats: FrameStats::default(),
+            device_loss: DeviceLoss::default(),
         };
-        Direct3D12DrawingSession::new(&self)
+
+        // The solid color and text pipelines are used by nearly every frame, create them upfront
+        // so shader errors surface when the renderer is created rather than mid-frame.
+        let (depth_buffer, depth_format) = if options.depth {
+            (Some(create_depth_buffer(&device, size)?), DEPTH_FORMAT)
+        } else {
+            (None, DXGI_FORMAT_UNKNOWN)
+        };
+        let pipelines = PipelineRegistry::new(&device, view_format(format), depth_format);
+        pipelines.get_or_create(PipelineKind::SolidColor)?;
+        pipelines.get_or_create(PipelineKind::Text)?;
+        let text_resources = TextResources::create(&device)?;
+
+        Ok(Self {
+            device,
+            info,
+            options: *options,
+            format,
+            command_queue,
+            swap_chain,
+            window_handle,
+            frame_latency_waitable: None,
+            size: Cell::new(size),
+            dpi_scale: Cell::new(1.0),
+            pending_resize: None,
+            readback_buffer,
+            rtv_descriptor_heap,
+            rtv_descriptor_size,
+            render_target_views: RefCell::new(render_target_views),
+            depth_buffer: RefCell::new(depth_buffer),
+            command_allocators,
+            pipelines,
+            frame_fence,
+            frame_event,
+            frame_state: Mutex::new(frame_state),
+            text_resources,
+            is_shut_down: false,
+            _live_objects: LiveObjectsReport,
+        })
     }
 
-    fn end_draw(&'a self, drawing_session: Direct3D12DrawingSession<'a>) {
-        let current_frame_back_buffer = self.current_frame().clone();
-        let transition_barrier_desc = D3D12_RESOURCE_TRANSITION_BARRIER {
-            pResource: ManuallyDrop::new(Some(current_frame_back_buffer)),
-            Subresource: D3D12_RESOURCE_BARRIER_ALL_SUBRESOURCES,
-            StateBefore: D3D12_RESOURCE_STATE_RENDER_TARGET,
-            StateAfter: D3D12_RESOURCE_STATE_PRESENT,
+    /// Records the copy of the offscreen render target into the readback buffer,
+    /// leaving the render target in the state expected by the next `begin_draw`.
+    unsafe fn copy_to_readback_buffer(
+        &self,
+        command_list: &ID3D12GraphicsCommandList,
+        readback_buffer: &ID3D12Resource,
+    ) {
+        let render_target = self.current_frame();
+        let desc = render_target.GetDesc();
+        command_list.ResourceBarrier(&[transition_barrier(
+            &render_target,
+            D3D12_RESOURCE_STATE_RENDER_TARGET,
+            D3D12_RESOURCE_STATE_COPY_SOURCE,
+        )]);
+
+        let destination = D3D12_TEXTURE_COPY_LOCATION {
+            pResource: ManuallyDrop::new(Some(readback_buffer.clone())),
+            Type: D3D12_TEXTURE_COPY_TYPE_PLACED_FOOTPRINT,
+            Anonymous: D3D12_TEXTURE_COPY_LOCATION_0 {
+                PlacedFootprint: D3D12_PLACED_SUBRESOURCE_FOOTPRINT {
+                    Offset: 0,
+                    Footprint: D3D12_SUBRESOURCE_FOOTPRINT {
+                        Format: desc.Format,
+                        Width: desc.Width as u32,
+                        Height: desc.Height,
+                        Depth: 1,
+                        RowPitch: readback_row_pitch(desc.Width as u32, self.format),
+                    },
+                },
+            },
         };
-        let barrier = D3D12_RESOURCE_BARRIER {
-            Type: D3D12_RESOURCE_BARRIER_TYPE_TRANSITION,
-            Flags: D3D12_RESOURCE_BARRIER_FLAG_NONE,
-            Anonymous: D3D12_RESOURCE_BARRIER_0 {
-                Transition: ManuallyDrop::new(transition_barrier_desc),
+        let source = D3D12_TEXTURE_COPY_LOCATION {
+            pResource: ManuallyDrop::new(Some(render_target.clone())),
+            Type: D3D12_TEXTURE_COPY_TYPE_SUBRESOURCE_INDEX,
+            Anonymous: D3D12_TEXTURE_COPY_LOCATION_0 {
+                SubresourceIndex: 0,
             },
         };
+        command_list.CopyTextureRegion(&destination, 0, 0, 0, &source, None);
+        ManuallyDrop::into_inner(destination.pResource);
+        ManuallyDrop::into_inner(source.pResource);
+
+        command_list.ResourceBarrier(&[transition_barrier(
+            &render_target,
+            D3D12_RESOURCE_STATE_COPY_SOURCE,
+            D3D12_RESOURCE_STATE_PRESENT,
+        )]);
+    }
+
+    /// Returns the pixels of the last frame drawn by an offscreen renderer, as tightly packed
+    /// rows from top to bottom in the format of its back buffer: RGBA8 values, or four little
+    /// endian half floats per pixel for `BackBufferFormat::Rgba16Float`.
+    /// Panics if the renderer draws to a window.
+    pub fn read_pixels(&self) -> Vec<u8> {
+        let readback_buffer = self
+            .readback_buffer
+            .as_ref()
+            .expect("read_pixels is only available for offscreen renderers.");
+        self.wait_for_gpu();
+
+        let desc = unsafe { self.current_frame().GetDesc() };
+        let width = desc.Width as usize;
+        let height = desc.Height as usize;
+        let row_pitch = readback_row_pitch(desc.Width as u32, self.format) as usize;
+        let read_range = D3D12_RANGE {
+            Begin: 0,
+            End: row_pitch * height,
+        };
 
+        let mut data: *mut std::ffi::c_void = std::ptr::null_mut();
         unsafe {
-            drawing_session.command_list.ResourceBarrier(&[barrier]);
-            drawing_session
-                .command_list
-                .Close()
-                .expect("Failed to close Command List.");
+            readback_buffer
+                .Map(0, Some(&read_range), Some(&mut data))
+                .expect("Failed to map readback buffer.");
+            let mapped = std::slice::from_raw_parts(data as *const u8, row_pitch * height);
+            let row_size = width * self.format.bytes_per_pixel();
+            let pixels = pack_rows(mapped, row_pitch, row_size, height);
+            // Nothing was written, so pass an empty written range.
+            readback_buffer.Unmap(0, Some(&D3D12_RANGE::default()));
+            pixels
         }
+    }
 
-        unsafe {
-            self.command_queue
-                .ExecuteCommandLists(&[Some(drawing_session.command_list.cast().unwrap())])
-        };
+    /// Waits for the GPU to finish the submitted frames and releases the resources retained for
+    /// them. The renderer must not be drawn with afterwards. Called when the renderer is dropped,
+    /// and does nothing when called again.
+    pub fn shutdown(&mut self) {
+        if self.is_shut_down {
+            return;
+        }
+        self.wait_for_gpu();
+        #[cfg(debug_assertions)]
+        debug::dump_debug_messages(&self.device);
+        if let Err(e) = unsafe { CloseHandle(self.frame_event) } {
+            log::warn!("Unable to close the frame event: {}", e);
+        }
+        if let Some(waitable) = self.frame_latency_waitable.take() {
+            if let Err(e) = unsafe { CloseHandle(waitable) } {
+                log::warn!("Unable to close the frame latency waitable object: {}", e);
+            }
+        }
+        self.is_shut_down = true;
+    }
 
-        // core::mem::forget(drawing_session);
+    /// Forwards only the debug layer messages at least as severe as `level` to the `log` crate,
+    /// `Warn` by default. Only has an effect in debug builds, where the debug layer is enabled.
+    pub fn set_debug_message_level(&self, level: log::Level) {
+        #[cfg(debug_assertions)]
+        debug::set_message_filter(&self.device, level);
+        #[cfg(not(debug_assertions))]
+        let _ = level;
+    }
 
-        self.wait_for_frame();
+    /// Makes the debug layer break into the debugger when it reports an error.
+    /// Only has an effect in debug builds, where the debug layer is enabled.
+    pub fn set_break_on_debug_error(&self, enabled: bool) {
+        #[cfg(debug_assertions)]
+        debug::set_break_on_error(&self.device, enabled);
+        #[cfg(not(debug_assertions))]
+        let _ = enabled;
     }
-}
 
-impl Drop for Direct3D12Renderer {
-    fn drop(&mut self) {
-        // Wait for the GPU to finish executing the command list before releasing resources.
-        self.wait_for_frame();
+    /// Returns the adapter and feature level the renderer was created with.
+    pub fn info(&self) -> &RendererInfo {
+        &self.info
+    }
+
+    /// Takes the next event of the renderer, `RendererEvent::DeviceLost` once after the device
+    /// was removed.
+    pub fn poll_event(&self) -> Option<RendererEvent> {
+        self.frame_state.lock().unwrap().device_loss.poll_event()
+    }
+
+    /// Whether the device was removed, in which case the renderer must be rebuilt with `recreate`
+    /// before drawing again.
+    pub fn is_device_lost(&self) -> bool {
+        self.frame_state.lock().unwrap().device_loss.is_lost()
+    }
+
+    /// Rebuilds the device, command queue, swap chain, descriptor heaps and pipelines with the
+    /// options the renderer was created with, typically after `RendererEvent::DeviceLost`.
+    /// Window renderers create their swap chain for `window`, offscreen renderers ignore it
+    /// and create a render target of the same size. The frame index starts over from 0.
+    /// The previous renderer is shut down first, as a window only takes one flip model swap
+    /// chain. On failure it stays shut down, and `recreate` can be tried again later, for
+    /// instance while the driver is still being updated.
+    pub fn recreate(&mut self, window: &Window) -> Result<(), RendererError> {
+        // Waits for the frames in flight, which a removed device reports as completed right away.
+        self.shutdown();
+        // The back buffers hold the swap chain, which must be released before creating another
+        // one for the window.
+        self.render_target_views.borrow_mut().clear();
+        self.swap_chain = None;
+        let renderer = match self.window_handle {
+            Some(_) => Self::try_create_for_window_with_options(window, &self.options)?,
+            None => Self::create_offscreen_with_options(self.size.get(), &self.options)?,
+        };
+        *self = renderer;
+        Ok(())
+    }
+
+    /// Classifies the failure of a call on the device, see `classify_device_error`.
+    fn device_error(&self, state: &mut FrameState, error: &windows_core::Error) -> RendererError {
+        let error = classify_device_error(error, &self.device, &mut state.device_loss);
+        #[cfg(debug_assertions)]
+        if let RendererError::DeviceLost(_) = error {
+            debug::dump_debug_messages(&self.device);
+        }
+        error
     }
-}
 
-impl Direct3D12Renderer {
     pub(self) fn create_command_list(&self) -> Result<ID3D12GraphicsCommandList, String> {
+        let pipeline = self.pipelines.get_or_create(PipelineKind::SolidColor)?;
         match unsafe {
             self.device.CreateCommandList(
                 0,
                 D3D12_COMMAND_LIST_TYPE_DIRECT,
-                &self.command_allocator,
-                &self.pipeline_state,
+                &self.command_allocators[self.current_frame_index()],
+                &pipeline.pipeline_state,
             )
         } {
             Ok(list) => Ok(list),
@@ -194,11 +861,17 @@ impl Direct3D12Renderer {
         #[cfg(debug_assertions)]
         debug_assert!(debug::check_present_state(
             &self.command_queue,
-            self.current_frame()
+            &self.current_frame()
         ));
 
-        match unsafe {
-            self.swap_chain
+        // Offscreen renderers have nothing to present, the frame is read back with `read_pixels`.
+        let swap_chain = match &self.swap_chain {
+            Some(swap_chain) => swap_chain,
+            None => return,
+        };
+
+        if let Err(e) = unsafe {
+            swap_chain
                 .Present1(
                     1,
                     DXGI_PRESENT::default(),
@@ -206,73 +879,316 @@ impl Direct3D12Renderer {
                 )
                 .ok()
         } {
-            Ok(_) => {}
-            Err(e) => match e.code() {
-                DXGI_ERROR_DEVICE_REMOVED => {
-                    let reason = unsafe { self.device.GetDeviceRemovedReason() }.unwrap_err();
+            let mut state = self.frame_state.lock().unwrap();
+            match self.device_error(&mut state, &e) {
+                RendererError::DeviceLost(reason) => {
+                    log::error!("Device lost: {}", reason);
+                    return;
+                }
+                RendererError::Failed(e) => panic!("Unable to present swap chain: {}", e),
+            }
+        }
 
-                    #[cfg(debug_assertions)]
-                    debug::dump_debug_messages(&self.device);
+        self.move_to_next_frame(swap_chain);
+    }
 
-                    panic!("Device removed: {}", reason.to_string())
-                }
-                _ => {
-                    panic!("Unable to present swap chain: {}", e.to_string())
-                }
-            },
+    /// Resizes the swap chain buffers to the given size in physical pixels, and picks up the
+    /// current DPI of the window.
+    /// Should be called whenever the window reports a `WindowEvent::Resized` or
+    /// `WindowEvent::DpiChanged`, unless the renderer was created with
+    /// `RendererOptions::auto_resize`.
+    pub fn resize(&mut self, size: Size<u32>) {
+        self.resize_buffers_to(size);
+    }
+
+    /// Applies the resize observed on the window since the last call, unless the device was lost:
+    /// the renderer created by `recreate` starts with the size of the window anyway.
+    fn apply_pending_resize(&self) {
+        let Some(pending_resize) = &self.pending_resize else {
+            return;
         };
+        if self.is_device_lost() {
+            return;
+        }
+        let size = pending_resize.borrow_mut().take(self.size.get());
+        if let Some(size) = size {
+            self.resize_buffers_to(size);
+        }
     }
 
-    pub fn wait_for_frame(&self) {
+    /// Implements `resize`, only called outside of drawing sessions, which hold the back buffers.
+    fn resize_buffers_to(&self, size: Size<u32>) {
+        if let Some(hwnd) = self.window_handle {
+            self.dpi_scale
+                .set(dpi_scale(unsafe { GetDpiForWindow(hwnd) }));
+        }
+        if !needs_resize(self.size.get(), size) {
+            return;
+        }
+        let swap_chain = match &self.swap_chain {
+            Some(swap_chain) => swap_chain.clone(),
+            None => panic!("Offscreen renderers can't be resized."),
+        };
+        self.wait_for_gpu();
+        // All references to the back buffers must be released before resizing them.
+        let mut render_target_views = self.render_target_views.borrow_mut();
+        render_target_views.clear();
+        let mut current_size = self.size.get();
+        let result = resize_buffers(&swap_chain, &mut current_size, size);
+        self.size.set(current_size);
+        *render_target_views = create_render_target_views(
+            &self.device,
+            &self.rtv_descriptor_heap,
+            self.rtv_descriptor_size,
+            &swap_chain,
+            view_format(self.format),
+            self.options.buffer_count,
+        );
+        if let Err(e) = result {
+            panic!("Unable to resize swap chain: {}", e);
+        }
+        self.frame_state.lock().unwrap().index =
+            unsafe { swap_chain.GetCurrentBackBufferIndex() } as usize;
+        let mut depth_buffer = self.depth_buffer.borrow_mut();
+        if depth_buffer.is_some() {
+            *depth_buffer = Some(
+                create_depth_buffer(&self.device, current_size)
+                    .expect("Unable to resize depth buffer."),
+            );
+        }
+    }
+
+    /// Waits until the swap chain can queue another frame without exceeding
+    /// `RendererOptions::max_frame_latency`, for up to `timeout`. Returns whether it can, always
+    /// true for renderers created without a maximum frame latency and offscreen renderers.
+    /// Each successful wait lets one frame be queued, so wait once per `present`.
+    ///
+    /// `present` synchronizes with the vertical blank, so a queued frame is only taken off the
+    /// queue once it is displayed: with a latency of 1, the wait ends when the previous frame
+    /// reaches the screen, and the input sampled right after it is shown on the next refresh.
+    /// The swap chain allows tearing but `present` never tears, so frames aren't displayed
+    /// sooner by skipping the vertical blank.
+    pub fn try_wait_for_next_frame(&self, timeout: Duration) -> bool {
+        let Some(waitable) = self.frame_latency_waitable else {
+            return true;
+        };
+        let milliseconds = timeout.as_millis().min(u32::MAX as u128 - 1) as u32;
+        unsafe { WaitForSingleObject(waitable, milliseconds) == WAIT_OBJECT_0 }
+    }
+
+    /// Format of the back buffers, see `Renderer::is_hdr_enabled`.
+    pub fn format(&self) -> BackBufferFormat {
+        self.format
+    }
+
+    /// Blocks until the GPU finished all work submitted so far.
+    pub fn wait_for_gpu(&self) {
+        let mut state = self.frame_state.lock().unwrap();
+        let fence_value = self.signal_frame(&mut state);
+        self.wait_for_fence_value(fence_value);
+        for resources in state.retained_resources.iter_mut() {
+            resources.clear();
+        }
+    }
+
+    /// Moves to the back buffer DXGI will render next, waiting until the GPU is done
+    /// with the work previously submitted for it. Called after presenting.
+    fn move_to_next_frame(&self, swap_chain: &IDXGISwapChain3) {
+        let mut state = self.frame_state.lock().unwrap();
+        let index = unsafe { swap_chain.GetCurrentBackBufferIndex() } as usize;
+        state.gpu_wait += self.wait_for_fence_value(state.fence_values.pending(index));
+        state.retained_resources[index].clear();
+        state.index = index;
+    }
+
+    /// Signals the fence on the command queue for the current frame, returning the signaled value.
+    fn signal_frame(&self, state: &mut FrameState) -> u64 {
+        let fence_value = state.fence_values.next(state.index);
+        if let Err(e) = unsafe { self.command_queue.Signal(&self.frame_fence, fence_value) } {
+            // The fence of a removed device reports every value as completed, so waiting on
+            // `fence_value` returns right away.
+            if let RendererError::Failed(e) = self.device_error(state, &e) {
+                panic!("Unable to signal fence: {}", e);
+            }
+        }
+        fence_value
+    }
+
+    /// Blocks until the fence reaches `fence_value`, returning how long it waited.
+    fn wait_for_fence_value(&self, fence_value: u64) -> Duration {
         unsafe {
-            let mut lock = self.fence_value.lock().unwrap();
-            let fence_value = *lock;
-            match self
-                .command_queue
-                .Signal(&self.frame_fence, fence_value)
-                .err()
-            {
-                Some(e) => panic!("Unable to signal fence for wait: {}", e.to_string()),
-                None => {}
-            };
-            match self
+            if self.frame_fence.GetCompletedValue() >= fence_value {
+                return Duration::ZERO;
+            }
+            let start = Instant::now();
+            if let Err(e) = self
                 .frame_fence
                 .SetEventOnCompletion(fence_value, self.frame_event)
-                .err()
             {
-                Some(e) => panic!("Unable to set event on completion: {}", e.to_string()),
-                None => {}
-            };
+                panic!("Unable to set event on completion: {}", e.to_string());
+            }
             if WaitForSingleObject(self.frame_event, 1000) != WAIT_OBJECT_0 {
                 panic!("Timeout waiting for fence to signal");
             }
-            *lock += 1;
+            start.elapsed()
         }
     }
 
-    pub(crate) fn current_frame(&self) -> &ID3D12Resource {
+    pub(crate) fn current_frame(&self) -> ID3D12Resource {
         let index = self.current_frame_index();
-        &self.render_target_views[index]
+        self.render_target_views.borrow()[index].clone()
+    }
+
+    /// Returns `color` as written to the back buffers: decoded to linear light, unless they take
+    /// sRGB encoded colors as given.
+    pub(crate) fn target_color(&self, color: &Color<f32>) -> Color<f32> {
+        if self.format.takes_linear_colors() {
+            color.to_linear()
+        } else {
+            *color
+        }
     }
 
     pub(crate) fn current_frame_index(&self) -> usize {
-        unsafe { self.swap_chain.GetCurrentBackBufferIndex() as usize }
+        self.frame_state.lock().unwrap().index
+    }
+}
+
+/// Buffers of a swap chain, behind a trait so the size tracking can be tested without a device.
+trait SwapChainBuffers {
+    /// Resizes all buffers, which must not be referenced anymore, keeping their format.
+    fn resize_buffers(&self, size: Size<u32>) -> Result<(), String>;
+}
+
+impl SwapChainBuffers for IDXGISwapChain3 {
+    fn resize_buffers(&self, size: Size<u32>) -> Result<(), String> {
+        let flags = swap_chain_flags(self)?;
+        unsafe {
+            // A count of 0 keeps the number of buffers the swap chain was created with.
+            self.ResizeBuffers(0, size.width, size.height, DXGI_FORMAT_UNKNOWN, flags)
+        }
+        .map_err(|e| e.to_string())
+    }
+}
+
+/// Whether buffers of `current` size must be resized to `requested`.
+/// A minimized window reports an empty client area, in which case the current buffers are kept.
+fn needs_resize(current: Size<u32>, requested: Size<u32>) -> bool {
+    requested.width > 0 && requested.height > 0 && requested != current
+}
+
+/// Resizes the buffers of `swap_chain`, updating `current_size` only if they were resized.
+fn resize_buffers(
+    swap_chain: &impl SwapChainBuffers,
+    current_size: &mut Size<u32>,
+    size: Size<u32>,
+) -> Result<(), String> {
+    swap_chain.resize_buffers(size)?;
+    *current_size = size;
+    Ok(())
+}
+
+/// Scale between physical and device independent pixels at `dpi`. `GetDpiForWindow` returns 0
+/// for destroyed windows, which are given the default scale of 1.0.
+fn dpi_scale(dpi: u32) -> f32 {
+    if dpi > 0 {
+        dpi as f32 / DEFAULT_DPI as f32
+    } else {
+        1.0
     }
 }
 
-/// Creates the D3D device to be used throughout application for resource loading
-/// panics if fail because the application can't run without it.
-fn create_d3d_device() -> Result<ID3D12Device, String> {
+/// Creates the D3D device to be used throughout application for resource loading.
+/// Hardware adapters are tried first, in the order DXGI enumerates them, falling back to WARP
+/// when none of them supports `MINIMUM_FEATURE_LEVEL`.
+fn create_d3d_device(adapter: AdapterPreference) -> Result<(ID3D12Device, RendererInfo), String> {
+    // The factory is only used to enumerate adapters, the debug flag isn't needed here.
+    let factory: IDXGIFactory4 =
+        unsafe { CreateDXGIFactory2(DXGI_CREATE_FACTORY_FLAGS::default()) }
+            .map_err(|e| e.to_string())?;
+
+    if adapter == AdapterPreference::Hardware {
+        if let Some(device) = create_hardware_device(&factory) {
+            return Ok(device);
+        }
+    }
+
+    let warp_adapter: IDXGIAdapter1 =
+        unsafe { factory.EnumWarpAdapter() }.map_err(|e| e.to_string())?;
+    create_device_on_adapter(&warp_adapter, true)
+}
+
+/// Creates a device on the first hardware adapter supporting `MINIMUM_FEATURE_LEVEL`.
+fn create_hardware_device(factory: &IDXGIFactory4) -> Option<(ID3D12Device, RendererInfo)> {
+    // EnumAdapters1 fails with DXGI_ERROR_NOT_FOUND past the last adapter.
+    (0..)
+        .map_while(|index| unsafe { factory.EnumAdapters1(index) }.ok())
+        .filter(|adapter| match unsafe { adapter.GetDesc1() } {
+            Ok(desc) => (desc.Flags & DXGI_ADAPTER_FLAG_SOFTWARE.0 as u32) == 0,
+            Err(_) => false,
+        })
+        .find_map(|adapter| create_device_on_adapter(&adapter, false).ok())
+}
+
+fn create_device_on_adapter(
+    adapter: &IDXGIAdapter1,
+    is_warp: bool,
+) -> Result<(ID3D12Device, RendererInfo), String> {
     let mut device: Option<ID3D12Device> = None;
+    unsafe { D3D12CreateDevice(adapter, MINIMUM_FEATURE_LEVEL, &mut device) }
+        .map_err(|e| e.to_string())?;
+    let device = device.unwrap();
 
-    let result = unsafe { D3D12CreateDevice(None, D3D_FEATURE_LEVEL_12_0, &mut device) };
+    let desc = unsafe { adapter.GetDesc1() }.map_err(|e| e.to_string())?;
+    let info = RendererInfo {
+        adapter_name: adapter_name(&desc.Description),
+        is_warp,
+        feature_level: feature_level_version(max_feature_level(&device)),
+    };
+    Ok((device, info))
+}
 
+/// Highest feature level supported by `device`, at least `MINIMUM_FEATURE_LEVEL`.
+fn max_feature_level(device: &ID3D12Device) -> D3D_FEATURE_LEVEL {
+    let levels = [
+        D3D_FEATURE_LEVEL_11_0,
+        D3D_FEATURE_LEVEL_11_1,
+        D3D_FEATURE_LEVEL_12_0,
+        D3D_FEATURE_LEVEL_12_1,
+        D3D_FEATURE_LEVEL_12_2,
+    ];
+    let mut data = D3D12_FEATURE_DATA_FEATURE_LEVELS {
+        NumFeatureLevels: levels.len() as u32,
+        pFeatureLevelsRequested: levels.as_ptr(),
+        MaxSupportedFeatureLevel: MINIMUM_FEATURE_LEVEL,
+    };
+    let result = unsafe {
+        device.CheckFeatureSupport(
+            D3D12_FEATURE_FEATURE_LEVELS,
+            &mut data as *mut _ as *mut std::ffi::c_void,
+            std::mem::size_of_val(&data) as u32,
+        )
+    };
     match result {
-        Ok(_) => Ok(device.unwrap()),
-        Err(e) => Err(e.to_string()),
+        Ok(_) => data.MaxSupportedFeatureLevel,
+        Err(_) => MINIMUM_FEATURE_LEVEL,
     }
 }
 
+/// Splits a feature level such as `D3D_FEATURE_LEVEL_12_1` (0xc100) into `(12, 1)`.
+fn feature_level_version(level: D3D_FEATURE_LEVEL) -> (u8, u8) {
+    (((level.0 >> 12) & 0xf) as u8, ((level.0 >> 8) & 0xf) as u8)
+}
+
+/// Decodes the nul terminated adapter description of a `DXGI_ADAPTER_DESC1`.
+fn adapter_name(description: &[u16]) -> String {
+    let length = description
+        .iter()
+        .position(|&c| c == 0)
+        .unwrap_or(description.len());
+    String::from_utf16_lossy(&description[..length])
+}
+
 /// Creates the D3D12 Command Queue for the given device.
 fn create_command_queue(device: &ID3D12Device) -> Result<ID3D12CommandQueue, String> {
     let desc = D3D12_COMMAND_QUEUE_DESC::default();
@@ -286,21 +1202,29 @@ fn create_command_queue(device: &ID3D12Device) -> Result<ID3D12CommandQueue, Str
 }
 
 /// Calls DXGI to create a Swap Chain for the given Window.
-/// note: using double-buffer, flip-discard.
+/// note: using `buffer_count` buffers of `format`, flip-discard. With a `max_frame_latency`,
+/// the swap chain has a frame latency waitable object and queues at most that many frames.
 fn create_swap_chain(
     window: &Window,
     command_queue: &ID3D12CommandQueue,
+    buffer_count: u32,
+    format: BackBufferFormat,
+    max_frame_latency: Option<u32>,
 ) -> Result<IDXGISwapChain3, String> {
+    let mut flags = DXGI_SWAP_CHAIN_FLAG_ALLOW_TEARING.0;
+    if max_frame_latency.is_some() {
+        flags |= DXGI_SWAP_CHAIN_FLAG_FRAME_LATENCY_WAITABLE_OBJECT.0;
+    }
     let desc = DXGI_SWAP_CHAIN_DESC1 {
         BufferUsage: DXGI_USAGE_RENDER_TARGET_OUTPUT,
         SwapEffect: DXGI_SWAP_EFFECT_FLIP_DISCARD,
-        Format: DXGI_FORMAT_R8G8B8A8_UNORM,
-        BufferCount: FRAME_COUNT,
+        Format: buffer_format(format),
+        BufferCount: buffer_count,
         SampleDesc: DXGI_SAMPLE_DESC {
             Count: 1,
             Quality: 0,
         },
-        Flags: DXGI_SWAP_CHAIN_FLAG_ALLOW_TEARING.0 as u32,
+        Flags: flags as u32,
         ..Default::default()
     };
 
@@ -317,22 +1241,75 @@ fn create_swap_chain(
             None,
         )
     };
+
+    // Fullscreen is controlled through Window::set_fullscreen, not by DXGI.
+    if let Err(e) = unsafe {
+        factory.MakeWindowAssociation(window.native_window_handle(), DXGI_MWA_NO_ALT_ENTER)
+    } {
+        return Err(e.to_string());
+    }
     let swap_chain = match result {
         Ok(r) => r,
         Err(e) => return Err(e.to_string()),
     };
 
-    match swap_chain.cast::<IDXGISwapChain3>() {
-        Ok(swap_chain) => Ok(swap_chain),
-        Err(e) => Err(e.to_string()),
+    let swap_chain = match swap_chain.cast::<IDXGISwapChain3>() {
+        Ok(swap_chain) => swap_chain,
+        Err(e) => return Err(e.to_string()),
+    };
+    if let Some(latency) = max_frame_latency {
+        unsafe { swap_chain.SetMaximumFrameLatency(latency) }.map_err(|e| e.to_string())?;
+    }
+    Ok(swap_chain)
+}
+
+/// Flags `swap_chain` was created with, which `ResizeBuffers` must be given again.
+fn swap_chain_flags(swap_chain: &IDXGISwapChain3) -> Result<DXGI_SWAP_CHAIN_FLAG, String> {
+    let desc = unsafe { swap_chain.GetDesc1() }.map_err(|e| e.to_string())?;
+    Ok(DXGI_SWAP_CHAIN_FLAG(desc.Flags as i32))
+}
+
+/// Presents `swap_chain`, created with buffers of `format`, in the color space of `format`,
+/// returning the format of its buffers. Buffers the display can't present, such as float
+/// buffers on some SDR displays, are switched to `Rgba8Unorm` rather than failing.
+fn set_color_space(
+    swap_chain: &IDXGISwapChain3,
+    format: BackBufferFormat,
+) -> Result<BackBufferFormat, String> {
+    if supports_color_space(swap_chain, color_space(format)) {
+        unsafe { swap_chain.SetColorSpace1(color_space(format)) }.map_err(|e| e.to_string())?;
+        return Ok(format);
+    }
+    // Swap chains present in sRGB until told otherwise.
+    let fallback = BackBufferFormat::Rgba8Unorm;
+    if buffer_format(format) != buffer_format(fallback) {
+        log::warn!(
+            "{:?} back buffers can't be presented, using Rgba8Unorm.",
+            format
+        );
+        // Sizes of 0 take the size of the client area, which the buffers were created with.
+        // No view references them yet.
+        let flags = swap_chain_flags(swap_chain)?;
+        unsafe { swap_chain.ResizeBuffers(0, 0, 0, buffer_format(fallback), flags) }
+            .map_err(|e| e.to_string())?;
     }
+    Ok(fallback)
 }
 
-/// Creates a Render Target View (RTV) Descriptor Heap on a device
-fn create_rtv_descriptor_heap(device: &ID3D12Device) -> Result<ID3D12DescriptorHeap, String> {
+/// Whether `swap_chain` can present buffers in `color_space`.
+fn supports_color_space(swap_chain: &IDXGISwapChain3, color_space: DXGI_COLOR_SPACE_TYPE) -> bool {
+    let support = unsafe { swap_chain.CheckColorSpaceSupport(color_space) }.unwrap_or(0);
+    support & DXGI_SWAP_CHAIN_COLOR_SPACE_SUPPORT_FLAG_PRESENT.0 as u32 != 0
+}
+
+/// Creates a Render Target View (RTV) Descriptor Heap on a device, with a view per buffer
+fn create_rtv_descriptor_heap(
+    device: &ID3D12Device,
+    buffer_count: u32,
+) -> Result<ID3D12DescriptorHeap, String> {
     let desc = D3D12_DESCRIPTOR_HEAP_DESC {
         Type: D3D12_DESCRIPTOR_HEAP_TYPE_RTV,
-        NumDescriptors: FRAME_COUNT,
+        NumDescriptors: buffer_count,
         ..Default::default()
     };
     let result = unsafe { device.CreateDescriptorHeap(&desc) };
@@ -342,215 +1319,423 @@ fn create_rtv_descriptor_heap(device: &ID3D12Device) -> Result<ID3D12DescriptorH
     }
 }
 
-/// Creates the Render Target View for each of the Swap Chain buffers (we're using `FRAME_COUNT = 2` buffers)
+/// Creates the Render Target View of `format` for each of the `buffer_count` Swap Chain buffers
 fn create_render_target_views(
     device: &ID3D12Device,
     descriptor_heap: &ID3D12DescriptorHeap,
     descriptor_size: u32,
     swap_chain: &IDXGISwapChain1,
-) -> [ID3D12Resource; FRAME_COUNT as usize] {
-    // Prefer static allocated array, to maintain memory locallity. Can't do it without using the uninitialized unsafe operation.
-    let mut buffers_array =
-        std::mem::MaybeUninit::<[ID3D12Resource; FRAME_COUNT as usize]>::uninit();
-    let mut ptr = buffers_array.as_mut_ptr() as *mut ID3D12Resource;
+    format: DXGI_FORMAT,
+    buffer_count: u32,
+) -> Vec<ID3D12Resource> {
+    let desc = D3D12_RENDER_TARGET_VIEW_DESC {
+        Format: format,
+        ViewDimension: D3D12_RTV_DIMENSION_TEXTURE2D,
+        Anonymous: D3D12_RENDER_TARGET_VIEW_DESC_0 {
+            Texture2D: D3D12_TEX2D_RTV::default(),
+        },
+    };
     let mut handle = unsafe { descriptor_heap.GetCPUDescriptorHandleForHeapStart() };
-
-    unsafe {
-        for idx in 0..FRAME_COUNT {
+    (0..buffer_count)
+        .map(|idx| unsafe {
             let buffer: ID3D12Resource = swap_chain.GetBuffer(idx).unwrap();
-            device.CreateRenderTargetView(&buffer, None, handle);
+            device.CreateRenderTargetView(&buffer, Some(&desc), handle);
             handle.ptr += descriptor_size as usize;
-            ptr.write(buffer);
-            ptr = ptr.add(1);
-        }
-        buffers_array.assume_init()
-    }
+            buffer
+        })
+        .collect()
 }
 
-fn create_command_allocator(device: &ID3D12Device) -> Result<ID3D12CommandAllocator, String> {
-    let result = unsafe { device.CreateCommandAllocator(D3D12_COMMAND_LIST_TYPE_DIRECT) };
-    match result {
-        Ok(r) => Ok(r),
-        Err(e) => Err(e.to_string()),
-    }
+/// Creates the texture of `format` an offscreen renderer draws into.
+fn create_offscreen_render_target(
+    device: &ID3D12Device,
+    size: Size<u32>,
+    format: BackBufferFormat,
+) -> Result<ID3D12Resource, String> {
+    let heap_properties = D3D12_HEAP_PROPERTIES {
+        Type: D3D12_HEAP_TYPE_DEFAULT,
+        ..Default::default()
+    };
+    let desc = D3D12_RESOURCE_DESC {
+        Dimension: D3D12_RESOURCE_DIMENSION_TEXTURE2D,
+        Width: size.width as u64,
+        Height: size.height,
+        DepthOrArraySize: 1,
+        MipLevels: 1,
+        Format: view_format(format),
+        SampleDesc: DXGI_SAMPLE_DESC {
+            Count: 1,
+            Quality: 0,
+        },
+        Layout: D3D12_TEXTURE_LAYOUT_UNKNOWN,
+        Flags: D3D12_RESOURCE_FLAG_ALLOW_RENDER_TARGET,
+        ..Default::default()
+    };
+    // Created in the same state as swap chain buffers (PRESENT == COMMON),
+    // so drawing sessions transition both the same way.
+    create_committed_resource(device, &heap_properties, &desc, D3D12_RESOURCE_STATE_PRESENT)
 }
 
-fn compile_shaders(device: &ID3D12Device) -> Result<ID3D12PipelineState, String> {
-    // TODO: refactor this to reduce function size and complexity and actually return a Result object, instead of relying on the expect() method.
-    let root_signature = get_root_signature(device)?;
-
-    // let vertex_shader_bytecode = include_bytes!("renderer_d3d12/shaders/simple2d/vs.fxc");
-    // let pixel_shader_bytecode = include_bytes!("renderer_d3d12/shaders/simple2d/ps.fxc");
-
-    let vertex_shader_bytecode = compile_from_content(
-        include_bytes!("renderer_d3d12/shaders/simple2d/vs_2d_screen_position.hlsl"),
-        "VSMain\0",
-        "vs_5_1\0",
-    )?;
-    let pixel_shader_bytecode = compile_from_content(
-        include_bytes!("renderer_d3d12/shaders/simple2d/ps_2d_simple_color.hlsl"),
-        "PSMain\0",
-        "ps_5_1\0",
-    )?;
-
-    let pipeline_state_description = D3D12_GRAPHICS_PIPELINE_STATE_DESC {
-        pRootSignature: ManuallyDrop::new(Some(root_signature)), // Root Signature
-        VS: D3D12_SHADER_BYTECODE {
-            pShaderBytecode: unsafe { vertex_shader_bytecode.GetBufferPointer() },
-            BytecodeLength: unsafe { vertex_shader_bytecode.GetBufferSize() },
-        }, // Vertex Shader
-        PS: D3D12_SHADER_BYTECODE {
-            pShaderBytecode: unsafe { pixel_shader_bytecode.GetBufferPointer() },
-            BytecodeLength: unsafe { pixel_shader_bytecode.GetBufferSize() },
-        }, // Pixel Shader
-        DS: D3D12_SHADER_BYTECODE::default(),                    // Domain Shader
-        HS: D3D12_SHADER_BYTECODE::default(),                    // Hull Shader
-        GS: D3D12_SHADER_BYTECODE::default(),                    // Geometry Shader
-        StreamOutput: D3D12_STREAM_OUTPUT_DESC::default(),
-        BlendState: get_default_blend_state(),
-        RasterizerState: D3D12_RASTERIZER_DESC {
-            FillMode: D3D12_FILL_MODE_SOLID,
-            CullMode: D3D12_CULL_MODE_BACK,
-            FrontCounterClockwise: false.into(),
-            DepthBias: D3D12_DEFAULT_DEPTH_BIAS,
-            DepthBiasClamp: D3D12_DEFAULT_DEPTH_BIAS_CLAMP,
-            SlopeScaledDepthBias: D3D12_DEFAULT_SLOPE_SCALED_DEPTH_BIAS,
-            DepthClipEnable: true.into(),
-            MultisampleEnable: false.into(),
-            AntialiasedLineEnable: false.into(),
-            ForcedSampleCount: 0,
-            ConservativeRaster: D3D12_CONSERVATIVE_RASTERIZATION_MODE_OFF,
-        },
-        InputLayout: D3D12_INPUT_LAYOUT_DESC {
-            pInputElementDescs: [D3D12_INPUT_ELEMENT_DESC {
-                SemanticName: s!("POSITION"),
-                SemanticIndex: 0,
-                Format: DXGI_FORMAT_R32G32_FLOAT,
-                InputSlot: 0,
-                AlignedByteOffset: 0,
-                InputSlotClass: D3D12_INPUT_CLASSIFICATION_PER_VERTEX_DATA,
-                InstanceDataStepRate: 0,
-            }]
-            .as_ptr(),
-            NumElements: 1,
-        },
-        SampleMask: u32::MAX,
-        NumRenderTargets: 1,
-        RTVFormats: [
-            DXGI_FORMAT_R8G8B8A8_UNORM,
-            DXGI_FORMAT_UNKNOWN,
-            DXGI_FORMAT_UNKNOWN,
-            DXGI_FORMAT_UNKNOWN,
-            DXGI_FORMAT_UNKNOWN,
-            DXGI_FORMAT_UNKNOWN,
-            DXGI_FORMAT_UNKNOWN,
-            DXGI_FORMAT_UNKNOWN,
-        ],
-        Flags: D3D12_PIPELINE_STATE_FLAG_NONE,
-        PrimitiveTopologyType: D3D12_PRIMITIVE_TOPOLOGY_TYPE_TRIANGLE,
-        DepthStencilState: D3D12_DEPTH_STENCIL_DESC::default(),
+/// Creates a `DEPTH_FORMAT` texture of `size` pixels, in the DEPTH_WRITE state, and its view.
+fn create_depth_buffer(device: &ID3D12Device, size: Size<u32>) -> Result<DepthBuffer, String> {
+    let heap_properties = D3D12_HEAP_PROPERTIES {
+        Type: D3D12_HEAP_TYPE_DEFAULT,
+        ..Default::default()
+    };
+    let desc = D3D12_RESOURCE_DESC {
+        Dimension: D3D12_RESOURCE_DIMENSION_TEXTURE2D,
+        Width: size.width as u64,
+        Height: size.height,
+        DepthOrArraySize: 1,
+        MipLevels: 1,
+        Format: DEPTH_FORMAT,
         SampleDesc: DXGI_SAMPLE_DESC {
             Count: 1,
             Quality: 0,
         },
+        Layout: D3D12_TEXTURE_LAYOUT_UNKNOWN,
+        Flags: D3D12_RESOURCE_FLAG_ALLOW_DEPTH_STENCIL | D3D12_RESOURCE_FLAG_DENY_SHADER_RESOURCE,
         ..Default::default()
     };
-    match unsafe { device.CreateGraphicsPipelineState(&pipeline_state_description) } {
-        Ok(pso) => Ok(pso),
-        Err(e) => return Err(e.to_string()),
+    let texture = create_committed_resource(
+        device,
+        &heap_properties,
+        &desc,
+        D3D12_RESOURCE_STATE_DEPTH_WRITE,
+    )?;
+
+    let dsv_descriptor_heap: ID3D12DescriptorHeap = unsafe {
+        device.CreateDescriptorHeap(&D3D12_DESCRIPTOR_HEAP_DESC {
+            Type: D3D12_DESCRIPTOR_HEAP_TYPE_DSV,
+            NumDescriptors: 1,
+            ..Default::default()
+        })
+    }
+    .map_err(|e| e.to_string())?;
+    unsafe {
+        device.CreateDepthStencilView(
+            &texture,
+            None,
+            dsv_descriptor_heap.GetCPUDescriptorHandleForHeapStart(),
+        );
     }
+    Ok(DepthBuffer {
+        _texture: texture,
+        dsv_descriptor_heap,
+    })
 }
 
-fn get_root_signature(device: &ID3D12Device) -> Result<ID3D12RootSignature, String> {
-    let root_signature_desc = D3D12_ROOT_SIGNATURE_DESC {
-        NumParameters: 0,
-        pParameters: std::ptr::null(),
-        NumStaticSamplers: 0,
-        pStaticSamplers: std::ptr::null(),
-        Flags: D3D12_ROOT_SIGNATURE_FLAG_ALLOW_INPUT_ASSEMBLER_INPUT_LAYOUT,
+/// Creates a CPU readable buffer of `size` bytes to copy render targets into.
+fn create_readback_buffer(device: &ID3D12Device, size: u64) -> Result<ID3D12Resource, String> {
+    let heap_properties = D3D12_HEAP_PROPERTIES {
+        Type: D3D12_HEAP_TYPE_READBACK,
+        ..Default::default()
     };
-
-    let mut root_signature_blob: Option<ID3DBlob> = None;
-    let mut error_blob: Option<ID3DBlob> = None;
-
-    let result = unsafe {
-        D3D12SerializeRootSignature(
-            &root_signature_desc,
-            D3D_ROOT_SIGNATURE_VERSION_1,
-            &mut root_signature_blob,
-            Some(&mut error_blob),
-        )
+    let desc = D3D12_RESOURCE_DESC {
+        Dimension: D3D12_RESOURCE_DIMENSION_BUFFER,
+        Width: size,
+        Height: 1,
+        DepthOrArraySize: 1,
+        MipLevels: 1,
+        Format: DXGI_FORMAT_UNKNOWN,
+        SampleDesc: DXGI_SAMPLE_DESC {
+            Count: 1,
+            Quality: 0,
+        },
+        Layout: D3D12_TEXTURE_LAYOUT_ROW_MAJOR,
+        Flags: D3D12_RESOURCE_FLAG_NONE,
+        ..Default::default()
     };
-    match result {
-        Ok(_) => {}
-        Err(e) => return Err(e.to_string()),
-    }
+    create_committed_resource(device, &heap_properties, &desc, D3D12_RESOURCE_STATE_COPY_DEST)
+}
 
-    let root_signature_blob = root_signature_blob.unwrap();
+fn create_committed_resource(
+    device: &ID3D12Device,
+    heap_properties: &D3D12_HEAP_PROPERTIES,
+    desc: &D3D12_RESOURCE_DESC,
+    initial_state: D3D12_RESOURCE_STATES,
+) -> Result<ID3D12Resource, String> {
+    let mut resource: Option<ID3D12Resource> = None;
     let result = unsafe {
-        device.CreateRootSignature(
-            0,
-            std::slice::from_raw_parts(
-                std::mem::transmute(root_signature_blob.GetBufferPointer()),
-                root_signature_blob.GetBufferSize(),
-            ),
+        device.CreateCommittedResource(
+            heap_properties,
+            D3D12_HEAP_FLAG_NONE,
+            desc,
+            initial_state,
+            None,
+            &mut resource,
         )
     };
     match result {
-        Ok(rs) => Ok(rs),
+        Ok(_) => Ok(resource.unwrap()),
         Err(e) => Err(e.to_string()),
     }
 }
 
-fn compile_from_content(
-    include_bytes: &[u8],
-    entry_point: &'static str,
-    profile: &'static str,
-) -> Result<ID3DBlob, String> {
-    let mut ppcode: Option<ID3DBlob> = None;
-    let mut pperrormsgs: Option<ID3DBlob> = None;
-    let entry_point = windows_core::PCSTR::from_raw(entry_point.as_ptr());
-    let profile = windows_core::PCSTR::from_raw(profile.as_ptr());
-    let result = unsafe {
-        D3DCompile(
-            include_bytes.as_ptr() as *const std::ffi::c_void,
-            include_bytes.len(),
-            None,
-            None,
-            None,
-            entry_point,
-            profile,
-            0,
-            0,
-            &mut ppcode,
-            Some(&mut pperrormsgs),
-        )
-    };
+/// Creates a Render Target View for each frame, all pointing to the same offscreen render target.
+fn create_offscreen_render_target_views(
+    device: &ID3D12Device,
+    descriptor_heap: &ID3D12DescriptorHeap,
+    descriptor_size: u32,
+    render_target: &ID3D12Resource,
+    buffer_count: u32,
+) -> Vec<ID3D12Resource> {
+    let mut handle = unsafe { descriptor_heap.GetCPUDescriptorHandleForHeapStart() };
+    (0..buffer_count)
+        .map(|_| {
+            unsafe { device.CreateRenderTargetView(render_target, None, handle) };
+            handle.ptr += descriptor_size as usize;
+            render_target.clone()
+        })
+        .collect()
+}
+
+/// Bytes per row of a texture of `width` pixels of `format` copied into a buffer,
+/// which must be aligned to D3D12_TEXTURE_DATA_PITCH_ALIGNMENT.
+fn readback_row_pitch(width: u32, format: BackBufferFormat) -> u32 {
+    let alignment = D3D12_TEXTURE_DATA_PITCH_ALIGNMENT;
+    (width * format.bytes_per_pixel() as u32).div_ceil(alignment) * alignment
+}
+
+/// Removes the padding at the end of each of the `height` rows of `data`.
+fn pack_rows(data: &[u8], row_pitch: usize, row_size: usize, height: usize) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity(row_size * height);
+    for row in data.chunks(row_pitch).take(height) {
+        pixels.extend_from_slice(&row[..row_size]);
+    }
+    pixels
+}
+
+fn transition_barrier(
+    resource: &ID3D12Resource,
+    state_before: D3D12_RESOURCE_STATES,
+    state_after: D3D12_RESOURCE_STATES,
+) -> D3D12_RESOURCE_BARRIER {
+    D3D12_RESOURCE_BARRIER {
+        Type: D3D12_RESOURCE_BARRIER_TYPE_TRANSITION,
+        Flags: D3D12_RESOURCE_BARRIER_FLAG_NONE,
+        Anonymous: D3D12_RESOURCE_BARRIER_0 {
+            Transition: ManuallyDrop::new(D3D12_RESOURCE_TRANSITION_BARRIER {
+                pResource: ManuallyDrop::new(Some(resource.clone())),
+                Subresource: D3D12_RESOURCE_BARRIER_ALL_SUBRESOURCES,
+                StateBefore: state_before,
+                StateAfter: state_after,
+            }),
+        },
+    }
+}
+
+fn create_command_allocator(device: &ID3D12Device) -> Result<ID3D12CommandAllocator, String> {
+    let result = unsafe { device.CreateCommandAllocator(D3D12_COMMAND_LIST_TYPE_DIRECT) };
     match result {
-        Ok(_) => Ok(ppcode.unwrap()),
+        Ok(r) => Ok(r),
         Err(e) => Err(e.to_string()),
     }
 }
 
-const fn get_default_blend_state() -> D3D12_BLEND_DESC {
-    D3D12_BLEND_DESC {
-        AlphaToCoverageEnable: BOOL(0),
-        IndependentBlendEnable: BOOL(0),
-        RenderTarget: [get_default_render_target_blend(); 8],
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    /// Swap chain recording the sizes it was resized to, failing when `fail` is set.
+    #[derive(Default)]
+    struct MockSwapChain {
+        fail: bool,
+        resized_to: RefCell<Vec<Size<u32>>>,
     }
-}
 
-const fn get_default_render_target_blend() -> D3D12_RENDER_TARGET_BLEND_DESC {
-    D3D12_RENDER_TARGET_BLEND_DESC {
-        BlendEnable: BOOL(0),
-        LogicOpEnable: BOOL(0),
-        SrcBlend: D3D12_BLEND_ONE,
-        DestBlend: D3D12_BLEND_ZERO,
-        BlendOp: D3D12_BLEND_OP_ADD,
-        SrcBlendAlpha: D3D12_BLEND_ONE,
-        DestBlendAlpha: D3D12_BLEND_ZERO,
-        BlendOpAlpha: D3D12_BLEND_OP_ADD,
-        LogicOp: D3D12_LOGIC_OP_NOOP,
-        RenderTargetWriteMask: D3D12_COLOR_WRITE_ENABLE_ALL.0 as u8,
+    impl SwapChainBuffers for MockSwapChain {
+        fn resize_buffers(&self, size: Size<u32>) -> Result<(), String> {
+            if self.fail {
+                return Err(String::from("device removed"));
+            }
+            self.resized_to.borrow_mut().push(size);
+            Ok(())
+        }
+    }
+
+    /// Device that is removed for `removed_reason` when set.
+    #[derive(Default)]
+    struct MockDevice {
+        removed_reason: Option<String>,
+    }
+
+    impl DeviceStatus for MockDevice {
+        fn removed_reason(&self) -> Option<String> {
+            self.removed_reason.clone()
+        }
+    }
+
+    #[test]
+    fn test_device_error_without_removal() {
+        let mut loss = DeviceLoss::default();
+        let error = classify_device_error(&"out of memory", &MockDevice::default(), &mut loss);
+        assert_eq!(error, RendererError::Failed(String::from("out of memory")));
+        assert!(!loss.is_lost());
+        assert_eq!(loss.poll_event(), None);
+    }
+
+    #[test]
+    fn test_device_lost_is_reported_once() {
+        let mut device = MockDevice {
+            removed_reason: Some(String::from("hung")),
+        };
+        let mut loss = DeviceLoss::default();
+        assert_eq!(
+            classify_device_error(&"present failed", &device, &mut loss),
+            RendererError::DeviceLost(String::from("hung"))
+        );
+        // Later failures keep the first reason and don't report the removal again.
+        device.removed_reason = Some(String::from("reset"));
+        assert_eq!(
+            classify_device_error(&"signal failed", &device, &mut loss),
+            RendererError::DeviceLost(String::from("hung"))
+        );
+        assert!(loss.is_lost());
+        assert_eq!(
+            loss.poll_event(),
+            Some(RendererEvent::DeviceLost(String::from("hung")))
+        );
+        assert_eq!(loss.poll_event(), None);
+        assert!(loss.is_lost());
+    }
+
+    #[test]
+    fn test_dpi_scale() {
+        assert_eq!(dpi_scale(96), 1.0);
+        assert_eq!(dpi_scale(120), 1.25);
+        assert_eq!(dpi_scale(144), 1.5);
+        assert_eq!(dpi_scale(192), 2.0);
+        assert_eq!(dpi_scale(0), 1.0);
+    }
+
+    #[test]
+    fn test_needs_resize() {
+        let current = Size::new(800, 600);
+        assert!(needs_resize(current, Size::new(1024, 768)));
+        assert!(needs_resize(current, Size::new(800, 601)));
+        assert!(!needs_resize(current, current));
+        // Minimized windows report an empty client area.
+        assert!(!needs_resize(current, Size::new(0, 0)));
+        assert!(!needs_resize(current, Size::new(0, 600)));
+    }
+
+    #[test]
+    fn test_pending_resize_waits_for_next_frame() {
+        let current = Size::new(800, 600);
+        let mut pending = PendingResize::default();
+        // `begin_draw` of the first frame finds nothing to apply.
+        assert_eq!(pending.take(current), None);
+        // The window is resized twice while the frame is drawn, only the last size is applied
+        // by the `begin_draw` of the next frame.
+        pending.on_event(&WindowEvent::Resized(Size::new(1024, 768)));
+        pending.on_event(&WindowEvent::FocusLost);
+        pending.on_event(&WindowEvent::Resized(Size::new(1280, 720)));
+        assert_eq!(pending.take(current), Some(Size::new(1280, 720)));
+        assert_eq!(pending.take(current), None);
+    }
+
+    #[test]
+    fn test_pending_resize_after_dpi_change() {
+        let current = Size::new(800, 600);
+        let mut pending = PendingResize::default();
+        // The buffers keep their size, the scale factor is picked up again.
+        pending.on_event(&WindowEvent::DpiChanged(1.5));
+        assert_eq!(pending.take(current), Some(current));
+        assert_eq!(pending.take(current), None);
+
+        pending.on_event(&WindowEvent::DpiChanged(2.0));
+        pending.on_event(&WindowEvent::Resized(Size::new(1600, 1200)));
+        assert_eq!(pending.take(current), Some(Size::new(1600, 1200)));
+        assert_eq!(pending.take(current), None);
+    }
+
+    #[test]
+    fn test_resize_buffers_updates_size() {
+        let swap_chain = MockSwapChain::default();
+        let mut size = Size::new(800, 600);
+        assert_eq!(
+            resize_buffers(&swap_chain, &mut size, Size::new(1024, 768)),
+            Ok(())
+        );
+        assert_eq!(size, Size::new(1024, 768));
+        assert_eq!(*swap_chain.resized_to.borrow(), vec![Size::new(1024, 768)]);
+    }
+
+    #[test]
+    fn test_resize_buffers_keeps_size_on_failure() {
+        let swap_chain = MockSwapChain {
+            fail: true,
+            ..Default::default()
+        };
+        let mut size = Size::new(800, 600);
+        assert!(resize_buffers(&swap_chain, &mut size, Size::new(1024, 768)).is_err());
+        assert_eq!(size, Size::new(800, 600));
+    }
+
+    #[test]
+    fn test_frame_fence_values_are_monotonic() {
+        for buffer_count in [2, 3] {
+            let mut fence_values = FrameFenceValues::new(buffer_count);
+            let mut last = 0;
+            for frame in 0..10 {
+                let value = fence_values.next(frame % buffer_count);
+                assert!(value > last);
+                last = value;
+            }
+        }
+    }
+
+    #[test]
+    fn test_readback_row_pitch() {
+        let format = BackBufferFormat::Rgba8Unorm;
+        assert_eq!(readback_row_pitch(1, format), 256);
+        assert_eq!(readback_row_pitch(64, format), 256);
+        assert_eq!(readback_row_pitch(65, format), 512);
+        assert_eq!(readback_row_pitch(800, format), 3328);
+        let format = BackBufferFormat::Rgba16Float;
+        assert_eq!(readback_row_pitch(32, format), 256);
+        assert_eq!(readback_row_pitch(33, format), 512);
+    }
+
+    #[test]
+    fn test_feature_level_version() {
+        assert_eq!(feature_level_version(D3D_FEATURE_LEVEL_11_0), (11, 0));
+        assert_eq!(feature_level_version(D3D_FEATURE_LEVEL_11_1), (11, 1));
+        assert_eq!(feature_level_version(D3D_FEATURE_LEVEL_12_2), (12, 2));
+    }
+
+    #[test]
+    fn test_adapter_name_stops_at_nul() {
+        let mut description = [0u16; 128];
+        for (i, c) in "Microsoft Basic Render Driver".encode_utf16().enumerate() {
+            description[i] = c;
+        }
+        assert_eq!(adapter_name(&description), "Microsoft Basic Render Driver");
+        assert_eq!(adapter_name(&[0x41, 0x42]), "AB");
+    }
+
+    #[test]
+    fn test_pack_rows() {
+        let data = [1, 2, 0, 0, 3, 4, 0, 0, 5, 6];
+        assert_eq!(pack_rows(&data, 4, 2, 3), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(pack_rows(&data, 4, 2, 2), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_frame_fence_values_pending_per_frame() {
+        let mut fence_values = FrameFenceValues::new(2);
+        assert_eq!(fence_values.pending(0), 0);
+        assert_eq!(fence_values.pending(1), 0);
+
+        let first = fence_values.next(0);
+        let second = fence_values.next(1);
+        assert_eq!(fence_values.pending(0), first);
+        assert_eq!(fence_values.pending(1), second);
+
+        let third = fence_values.next(0);
+        assert_eq!(fence_values.pending(0), third);
+        assert_eq!(fence_values.pending(1), second);
     }
 }
@@ -0,0 +1,236 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Direct3D12 debug layer support, only compiled in debug builds.
+//!
+//! The debug layer validates every call made on the device and stores its findings in the info
+//! queue of the device, which are forwarded to the `log` crate by `dump_debug_messages`.
+
+use std::sync::Once;
+
+use windows::{
+    core::Interface,
+    Win32::Graphics::{
+        Direct3D12::*,
+        Dxgi::{DXGIGetDebugInterface1, IDXGIDebug1, DXGI_DEBUG_ALL, DXGI_DEBUG_RLO_DETAIL},
+    },
+};
+
+static ENABLE_DEBUG_LAYER: Once = Once::new();
+
+/// Enables the debug layer, which must happen before the first device is created.
+/// Does nothing if the Graphics Tools optional feature isn't installed.
+pub(super) fn init() {
+    ENABLE_DEBUG_LAYER.call_once(|| {
+        let mut debug: Option<ID3D12Debug> = None;
+        match unsafe { D3D12GetDebugInterface(&mut debug) } {
+            Ok(_) => unsafe { debug.unwrap().EnableDebugLayer() },
+            Err(e) => log::warn!("Direct3D12 debug layer unavailable: {}", e),
+        }
+    });
+}
+
+/// Logs and removes the messages stored in the info queue of `device`.
+pub(super) fn dump_debug_messages(device: &ID3D12Device) {
+    let info_queue = match device.cast::<ID3D12InfoQueue>() {
+        Ok(info_queue) => info_queue,
+        Err(_) => return,
+    };
+    unsafe {
+        for index in 0..info_queue.GetNumStoredMessages() {
+            let mut length = 0;
+            if info_queue.GetMessage(index, None, &mut length).is_err() {
+                continue;
+            }
+            // D3D12_MESSAGE is followed by its description, allocate with its alignment.
+            let mut buffer = vec![0u64; (length + 7) / 8];
+            let message = buffer.as_mut_ptr() as *mut D3D12_MESSAGE;
+            if info_queue
+                .GetMessage(index, Some(message), &mut length)
+                .is_err()
+            {
+                continue;
+            }
+            let message = &*message;
+            let description = std::slice::from_raw_parts(
+                message.pDescription as *const u8,
+                message.DescriptionByteLength.saturating_sub(1),
+            );
+            log::log!(
+                message_level(message.Severity),
+                "D3D12 {:?}: {}",
+                message.ID,
+                String::from_utf8_lossy(description)
+            );
+        }
+        info_queue.ClearStoredMessages();
+    }
+}
+
+/// Stores only the messages at least as severe as `level` in the info queue of `device`.
+pub(super) fn set_message_filter(device: &ID3D12Device, level: log::Level) {
+    let info_queue = match device.cast::<ID3D12InfoQueue>() {
+        Ok(info_queue) => info_queue,
+        Err(_) => return,
+    };
+    let mut denied: Vec<D3D12_MESSAGE_SEVERITY> = [
+        D3D12_MESSAGE_SEVERITY_CORRUPTION,
+        D3D12_MESSAGE_SEVERITY_ERROR,
+        D3D12_MESSAGE_SEVERITY_WARNING,
+        D3D12_MESSAGE_SEVERITY_INFO,
+        D3D12_MESSAGE_SEVERITY_MESSAGE,
+    ]
+    .into_iter()
+    .filter(|&severity| message_level(severity) > level)
+    .collect();
+    let mut filter = D3D12_INFO_QUEUE_FILTER::default();
+    filter.DenyList.NumSeverities = denied.len() as u32;
+    filter.DenyList.pSeverityList = denied.as_mut_ptr();
+    unsafe {
+        info_queue.ClearStorageFilter();
+        if let Err(e) = info_queue.PushStorageFilter(&filter) {
+            log::warn!("Unable to filter Direct3D12 debug messages: {}", e);
+        }
+    }
+}
+
+/// Makes the debug layer break into the debugger when it reports an error or a corruption.
+pub(super) fn set_break_on_error(device: &ID3D12Device, enabled: bool) {
+    let info_queue = match device.cast::<ID3D12InfoQueue>() {
+        Ok(info_queue) => info_queue,
+        Err(_) => return,
+    };
+    for severity in [
+        D3D12_MESSAGE_SEVERITY_CORRUPTION,
+        D3D12_MESSAGE_SEVERITY_ERROR,
+    ] {
+        if let Err(e) = unsafe { info_queue.SetBreakOnSeverity(severity, enabled) } {
+            log::warn!("Unable to break on Direct3D12 {:?}: {}", severity, e);
+        }
+    }
+}
+
+/// Prints the DXGI and Direct3D objects still alive to the debugger output.
+/// Objects of renderers that weren't dropped yet are reported too.
+pub(super) fn report_live_objects() {
+    if let Ok(debug) = unsafe { DXGIGetDebugInterface1::<IDXGIDebug1>(0) } {
+        if let Err(e) = unsafe { debug.ReportLiveObjects(DXGI_DEBUG_ALL, DXGI_DEBUG_RLO_DETAIL) } {
+            log::warn!("Unable to report live DXGI objects: {}", e);
+        }
+    }
+}
+
+/// Whether the back buffer is in the PRESENT state once the work submitted to `command_queue`
+/// completes. Always true when the debug layer isn't enabled.
+pub(super) fn check_present_state(
+    command_queue: &ID3D12CommandQueue,
+    back_buffer: &ID3D12Resource,
+) -> bool {
+    match command_queue.cast::<ID3D12DebugCommandQueue>() {
+        Ok(debug_queue) => unsafe {
+            debug_queue
+                .AssertResourceState(
+                    back_buffer,
+                    D3D12_RESOURCE_BARRIER_ALL_SUBRESOURCES,
+                    D3D12_RESOURCE_STATE_PRESENT.0 as u32,
+                )
+                .as_bool()
+        },
+        Err(_) => true,
+    }
+}
+
+/// Whether `render_target` is in the RENDER_TARGET state at this point of `command_list`.
+pub(super) fn check_render_target_state(
+    command_list: &ID3D12GraphicsCommandList,
+    render_target: &ID3D12Resource,
+) -> bool {
+    check_resource_state(
+        command_list,
+        render_target,
+        D3D12_RESOURCE_STATE_RENDER_TARGET,
+    )
+}
+
+/// Whether `vertex_buffer`, an upload heap buffer, can be read at this point of `command_list`.
+pub(super) fn check_vertex_buffer_state(
+    command_list: &ID3D12GraphicsCommandList,
+    vertex_buffer: &ID3D12Resource,
+) -> bool {
+    check_resource_state(
+        command_list,
+        vertex_buffer,
+        D3D12_RESOURCE_STATE_GENERIC_READ,
+    )
+}
+
+fn check_resource_state(
+    command_list: &ID3D12GraphicsCommandList,
+    resource: &ID3D12Resource,
+    state: D3D12_RESOURCE_STATES,
+) -> bool {
+    match command_list.cast::<ID3D12DebugCommandList>() {
+        Ok(debug_list) => unsafe {
+            debug_list
+                .AssertResourceState(
+                    resource,
+                    D3D12_RESOURCE_BARRIER_ALL_SUBRESOURCES,
+                    state.0 as u32,
+                )
+                .as_bool()
+        },
+        Err(_) => true,
+    }
+}
+
+/// Log level debug messages of `severity` are forwarded with.
+fn message_level(severity: D3D12_MESSAGE_SEVERITY) -> log::Level {
+    match severity {
+        D3D12_MESSAGE_SEVERITY_CORRUPTION | D3D12_MESSAGE_SEVERITY_ERROR => log::Level::Error,
+        D3D12_MESSAGE_SEVERITY_WARNING => log::Level::Warn,
+        D3D12_MESSAGE_SEVERITY_INFO => log::Level::Info,
+        _ => log::Level::Debug,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_level() {
+        assert_eq!(
+            message_level(D3D12_MESSAGE_SEVERITY_CORRUPTION),
+            log::Level::Error
+        );
+        assert_eq!(
+            message_level(D3D12_MESSAGE_SEVERITY_ERROR),
+            log::Level::Error
+        );
+        assert_eq!(
+            message_level(D3D12_MESSAGE_SEVERITY_WARNING),
+            log::Level::Warn
+        );
+        assert_eq!(message_level(D3D12_MESSAGE_SEVERITY_INFO), log::Level::Info);
+        assert_eq!(
+            message_level(D3D12_MESSAGE_SEVERITY_MESSAGE),
+            log::Level::Debug
+        );
+    }
+}
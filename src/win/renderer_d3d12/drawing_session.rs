@@ -18,6 +18,7 @@
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 use std::mem::ManuallyDrop;
+use std::ops::Range;
 
 use windows::{
     core::Interface,
@@ -32,20 +33,35 @@ use windows::{
 };
 
 use crate::{
-    math::{Rect, Vector2},
-    renderer::{Color, DrawingSession, Renderer, TextFormat},
+    math::{Matrix4x4, Rect, Vector2, Vector3},
+    renderer::{
+        check_text_spans, geometry, Color, DrawingSession, FrameStats, Renderer, TextFormat,
+        TextLayout,
+    },
 };
 
-use super::{text::Direct3D12TextRenderer, Direct3D12Renderer};
+use super::{
+    pipeline::{PipelineKind, PipelineState, MESH_CONSTANT_COUNT},
+    text::{Direct3D12TextRenderer, DirectWriteTextLayout, GlyphBatch},
+    Direct3D12Renderer,
+};
 
+/// Records every draw into the command list as it is made, so draws are rendered in call order
+/// and there is no batch to flush. Text switches to the text pipeline and back for each run.
 pub struct Direct3D12DrawingSession<'a> {
     renderer: &'a Direct3D12Renderer,
     pub(super) command_list: ID3D12GraphicsCommandList,
-    resources: Vec<ID3D12Resource>,
+    /// Pipeline shapes are drawn with, restored after drawing text.
+    solid_color: &'a PipelineState,
+    /// Resources used by the command list, retained by the renderer until the frame completes.
+    pub(super) resources: Vec<ID3D12Resource>,
+    pub(super) stats: FrameStats,
+    /// Segments of the circles drawn, or `None` to adapt them to the radius.
+    circle_segments: Option<u32>,
 }
 
 impl<'a> DrawingSession for Direct3D12DrawingSession<'a> {
-    /// Clear the game window with the given color
+    /// Clear the game window with the given color, using ClearRenderTargetView on the current back buffer
     fn clear(&mut self, color: &Color<f32>) {
         #[cfg(debug_assertions)]
         debug_assert!(super::debug::check_render_target_state(
@@ -53,39 +69,272 @@ impl<'a> DrawingSession for Direct3D12DrawingSession<'a> {
             &self.renderer.current_frame(),
         ));
 
+        let color = self.renderer.target_color(color);
+        unsafe {
+            let mut rtv_handle = self
+                .renderer
+                .rtv_descriptor_heap
+                .GetCPUDescriptorHandleForHeapStart();
+            rtv_handle.ptr +=
+                self.renderer.rtv_descriptor_size as usize * self.renderer.current_frame_index();
+            self.command_list
+                .ClearRenderTargetView(rtv_handle, color.as_slice(), None);
+        }
+    }
+
+    /// Draw a text to the game window
+    fn draw_text(&mut self, text: &str, format: &TextFormat, rect: &Rect<f32>) {
+        self.draw_text_styled(text, format, rect, &[]);
+    }
+
+    /// Draw a text to the game window, coloring its spans with DirectWrite drawing effects
+    fn draw_text_styled(
+        &mut self,
+        text: &str,
+        format: &TextFormat,
+        rect: &Rect<f32>,
+        spans: &[(Range<usize>, Color<f32>)],
+    ) {
+        check_text_spans(text, spans);
+        self.draw_glyphs(format, rect, |text_renderer| {
+            text_renderer.render_text(text, format, rect, spans)
+        });
+    }
+
+    /// Draw a text layout, reusing the DirectWrite layout of those created by this renderer
+    fn draw_text_layout(&mut self, layout: &TextLayout, origin: &Vector2<f32>, color: &Color<f32>) {
+        let rect = layout.rect_at(origin);
+        match layout.backend::<DirectWriteTextLayout>() {
+            Some(cached) => self.draw_glyphs(layout.format(), &rect, |text_renderer| {
+                text_renderer.render_cached_layout(cached, layout.format(), &rect, color)
+            }),
+            None => {
+                let spans = [(0..layout.text().len(), *color)];
+                self.draw_text_styled(layout.text(), layout.format(), &rect, &spans);
+            }
+        }
+    }
+
+    fn draw_triangle(&mut self, points: &[Vector2<f32>; 3], color: &Color<f32>) {
+        self.draw_vertices(points);
+    }
+
+    /// Draw a rectangle to the game window
+    fn draw_rectangle(&mut self, rect: &Rect<f32>, color: &Color<f32>) {
+        let vertices = geometry::rectangle_vertices(rect);
+        self.draw_vertices(&self.pixels_to_clip_space(vertices));
+    }
+
+    /// Draw a rectangle with rounded corners to the game window
+    fn draw_rounded_rectangle(&mut self, rect: &Rect<f32>, corner_radius: f32, color: &Color<f32>) {
+        let vertices = geometry::rounded_rectangle_vertices(
+            rect,
+            corner_radius,
+            geometry::DEFAULT_TESSELLATION_TOLERANCE,
+        );
+        self.draw_vertices(&self.pixels_to_clip_space(vertices));
+    }
+
+    /// Draw a line to the game window
+    fn draw_line(
+        &mut self,
+        from: &Vector2<f32>,
+        to: &Vector2<f32>,
+        thickness: f32,
+        color: &Color<f32>,
+    ) {
+        let vertices = geometry::line_vertices(*from, *to, thickness);
+        self.draw_vertices(&self.pixels_to_clip_space(vertices));
+    }
+
+    /// Draw a convex polygon to the game window, as a triangle fan
+    fn draw_polygon(&mut self, points: &[Vector2<f32>], color: &Color<f32>) {
+        let vertices = geometry::polygon_vertices(points);
+        self.draw_vertices(&self.pixels_to_clip_space(vertices));
+    }
+
+    /// Draw the outline of a convex polygon to the game window, as a quad per edge
+    fn draw_polygon_outline(
+        &mut self,
+        points: &[Vector2<f32>],
+        thickness: f32,
+        color: &Color<f32>,
+    ) {
+        let vertices = geometry::polygon_outline_vertices(points, thickness);
+        self.draw_vertices(&self.pixels_to_clip_space(vertices));
+    }
+
+    /// Draw the ellipse inscribed in `bounds` to the game window, as a triangle fan with
+    /// as many segments as its larger radius needs, unless set by `set_circle_segments`
+    fn draw_circle(&mut self, bounds: &Rect<f32>, color: &Color<f32>) {
+        let segments = self.circle_segments.unwrap_or_else(|| {
+            geometry::circle_segment_count(
+                bounds.width.max(bounds.height) / 2.0,
+                geometry::DEFAULT_TESSELLATION_TOLERANCE,
+            )
+        });
+        let vertices = geometry::ellipse_vertices(bounds, segments);
+        self.draw_vertices(&self.pixels_to_clip_space(vertices));
+    }
 
+    /// Draw a circle centered at 'center' with given 'radius'
+    fn draw_circle_centered_at(&mut self, center: &Vector2<f32>, radius: f32, color: &Color<f32>) {
+        let bounds = Rect {
+            x: center.x - radius,
+            y: center.y - radius,
+            width: 2.0 * radius,
+            height: 2.0 * radius,
+        };
+        self.draw_circle(&bounds, color);
+    }
 
+    fn set_circle_segments(&mut self, segments: Option<u32>) {
+        self.circle_segments = segments;
+    }
+
+    fn clear_depth(&mut self, value: f32) {
+        if let Some(depth_buffer) = &*self.renderer.depth_buffer.borrow() {
+            unsafe {
+                self.command_list.ClearDepthStencilView(
+                    depth_buffer.dsv_handle(),
+                    D3D12_CLEAR_FLAG_DEPTH,
+                    value,
+                    0,
+                    None,
+                );
+            }
+        }
+    }
+
+    fn draw_mesh(
+        &mut self,
+        vertices: &[Vector3<f32>],
+        indices: &[u32],
+        transform: &Matrix4x4<f32>,
+        color: &Color<f32>,
+    ) {
+        let index_count = indices.len() as u32 / 3 * 3;
+        if vertices.is_empty() || index_count == 0 {
+            return;
+        }
+        assert!(
+            indices
+                .iter()
+                .all(|&index| (index as usize) < vertices.len()),
+            "Mesh indices must be lower than the number of vertices."
+        );
+
+        let vertex_buffer = load_upload_buffer(self.renderer, vertices);
+        let index_buffer = load_upload_buffer(self.renderer, indices);
+
+        let mut constants = [0.0f32; MESH_CONSTANT_COUNT as usize];
+        constants[..16].copy_from_slice(&transform.to_array_column_major());
+        constants[16..].copy_from_slice(self.renderer.target_color(color).as_slice());
+
+        self.renderer
+            .pipelines
+            .get_or_create(PipelineKind::Mesh)
+            .expect("failed to create mesh pipeline")
+            .bind(&self.command_list);
         unsafe {
-            let mut rtv_handle = self.renderer.rtv_descriptor_heap.GetCPUDescriptorHandleForHeapStart();
-            rtv_handle.ptr 
-                += self.renderer.rtv_descriptor_size as usize * self.renderer.current_frame_index();
-            self.command_list.ClearRenderTargetView(
-                rtv_handle,
-                color.as_slice(),
-                None,
+            self.command_list.SetGraphicsRoot32BitConstants(
+                0,
+                MESH_CONSTANT_COUNT,
+                constants.as_ptr() as *const std::ffi::c_void,
+                0,
             );
+            self.command_list
+                .IASetPrimitiveTopology(D3D_PRIMITIVE_TOPOLOGY_TRIANGLELIST);
+            self.command_list.IASetVertexBuffers(
+                0,
+                Some(&[D3D12_VERTEX_BUFFER_VIEW {
+                    BufferLocation: vertex_buffer.GetGPUVirtualAddress(),
+                    SizeInBytes: std::mem::size_of_val(vertices) as u32,
+                    StrideInBytes: std::mem::size_of::<Vector3<f32>>() as u32,
+                }]),
+            );
+            let index_buffer_view = D3D12_INDEX_BUFFER_VIEW {
+                BufferLocation: index_buffer.GetGPUVirtualAddress(),
+                SizeInBytes: std::mem::size_of_val(indices) as u32,
+                Format: DXGI_FORMAT_R32_UINT,
+            };
+            self.command_list
+                .IASetIndexBuffer(Some(&index_buffer_view as *const _));
+            self.command_list
+                .DrawIndexedInstanced(index_count, 1, 0, 0, 0);
         }
+        self.stats.record_draw_call(index_count / 3);
+        self.solid_color.bind(&self.command_list);
+
+        self.resources.push(vertex_buffer);
+        self.resources.push(index_buffer);
     }
 
-    /// Draw a text to the game window
-    fn draw_text(&mut self, text: &String, format: &TextFormat, rect: &Rect<f32>) {
+    fn stats(&self) -> FrameStats {
+        self.stats
+    }
+}
+
+impl<'a> Direct3D12DrawingSession<'a> {
+    /// Records the glyphs returned by `render` for a text in `rect`, clipped to it if `format`
+    /// clips, and counts them as a text run.
+    fn draw_glyphs(
+        &mut self,
+        format: &TextFormat,
+        rect: &Rect<f32>,
+        render: impl FnOnce(Direct3D12TextRenderer) -> windows::core::Result<GlyphBatch>,
+    ) {
+        let clip = format.clips();
+        if clip {
+            unsafe {
+                self.command_list.RSSetScissorRects(&[RECT {
+                    left: rect.x.floor() as i32,
+                    top: rect.y.floor() as i32,
+                    right: (rect.x + rect.width).ceil() as i32,
+                    bottom: (rect.y + rect.height).ceil() as i32,
+                }]);
+            }
+        }
+
         let text_renderer = Direct3D12TextRenderer::create_for_renderer(&self.renderer);
-        text_renderer.render_text(text, format, rect).unwrap();
+        let glyph_batch = render(text_renderer).unwrap();
+        let triangles = self
+            .renderer
+            .text_resources
+            .record_batch(
+                self.renderer,
+                &self.command_list,
+                glyph_batch,
+                &mut self.resources,
+            )
+            .expect("failed to record text draw");
+        self.stats.record_text_run();
+        if triangles > 0 {
+            self.stats.record_draw_call(triangles);
+        }
+        self.solid_color.bind(&self.command_list);
+
+        if clip {
+            unsafe {
+                self.command_list
+                    .RSSetScissorRects(&[full_scissor_rect(self.renderer)]);
+            }
+        }
     }
 
-    fn draw_triangle(&mut self, points: &[Vector2<f32>; 3], color: &Color<f32>) {
+    /// Draws a triangle list given in clip space.
+    fn draw_vertices(&mut self, vertices: &[Vector2<f32>]) {
+        if vertices.is_empty() {
+            return;
+        }
+
         #[cfg(debug_assertions)]
         debug_assert!(super::debug::check_render_target_state(
             &self.command_list,
             &self.renderer.current_frame(),
         ));
 
-        let rtv_handle = unsafe {
-            self.renderer
-                .rtv_descriptor_heap
-                .GetCPUDescriptorHandleForHeapStart()
-        };
-        let vertex_buffer_heap = load_triangle_buffer(&self.renderer, points);
+        let vertex_buffer_heap = load_upload_buffer(&self.renderer, vertices);
 
         #[cfg(debug_assertions)]
         debug_assert!(super::debug::check_vertex_buffer_state(
@@ -95,8 +344,8 @@ impl<'a> DrawingSession for Direct3D12DrawingSession<'a> {
 
         let vertex_buffer_view = D3D12_VERTEX_BUFFER_VIEW {
             BufferLocation: unsafe { vertex_buffer_heap.GetGPUVirtualAddress() },
-            SizeInBytes: 24u32, // TODO: Fix this
-            StrideInBytes: 8u32, // TODO: Fix this
+            SizeInBytes: std::mem::size_of_val(vertices) as u32,
+            StrideInBytes: std::mem::size_of::<Vector2<f32>>() as u32,
         };
         unsafe {
             self.command_list
@@ -105,31 +354,25 @@ impl<'a> DrawingSession for Direct3D12DrawingSession<'a> {
             self.command_list
                 .IASetVertexBuffers(0, Some(&[vertex_buffer_view]));
 
-            self.command_list.DrawInstanced(3, 1, 0, 0);
+            self.command_list
+                .DrawInstanced(vertices.len() as u32, 1, 0, 0);
         }
+        self.stats.record_draw_call(vertices.len() as u32 / 3);
 
         // Add the vertex buffer to the list of resources to be released
-        self.resources
-            .push(vertex_buffer_heap);
+        self.resources.push(vertex_buffer_heap);
     }
 
-    /// Draw a rectangle to the game window
-    fn draw_rectangle(&mut self, rect: &Rect<f32>, color: &Color<f32>) {
-        todo!()
-    }
-
-    /// Draw a circle within bounds to the game window
-    fn draw_circle(&mut self, bounds: &Rect<f32>, color: &Color<f32>) {
-        todo!()
-    }
-
-    /// Draw a circle centered at 'center' with given 'radius'
-    fn draw_circle_centered_at(&mut self, center: &Vector2<f32>, radius: f32, color: &Color<f32>) {
-        todo!()
+    /// Converts points in render target pixels to clip space.
+    fn pixels_to_clip_space(&self, mut points: Vec<Vector2<f32>>) -> Vec<Vector2<f32>> {
+        let size = self.renderer.size();
+        for point in points.iter_mut() {
+            point.x = point.x / size.width * 2.0 - 1.0;
+            point.y = 1.0 - point.y / size.height * 2.0;
+        }
+        points
     }
-}
 
-impl<'a> Direct3D12DrawingSession<'a> {
     pub fn new(renderer: &'a Direct3D12Renderer) -> Self {
         let command_list = match renderer.create_command_list() {
             Ok(c) => c,
@@ -140,12 +383,13 @@ impl<'a> Direct3D12DrawingSession<'a> {
                 panic!("Failed to create command list: {}", e);
             }
         };
-        
-        unsafe {
-            let root_signature =
-                get_root_signature(&renderer.device).expect("failed to create root signature");
-            command_list.SetGraphicsRootSignature(&root_signature);
 
+        let solid_color = renderer
+            .pipelines
+            .get_or_create(PipelineKind::SolidColor)
+            .expect("failed to create solid color pipeline");
+        solid_color.bind(&command_list);
+        unsafe {
             command_list.RSSetViewports(&[D3D12_VIEWPORT {
                 TopLeftX: 0.0,
                 TopLeftY: 0.0,
@@ -155,14 +399,9 @@ impl<'a> Direct3D12DrawingSession<'a> {
                 MaxDepth: 1.0,
             }]);
 
-            command_list.RSSetScissorRects(&[RECT {
-                left: 0,
-                top: 0,
-                right: renderer.size().width as i32,
-                bottom: renderer.size().height as i32,
-            }]);
+            command_list.RSSetScissorRects(&[full_scissor_rect(renderer)]);
 
-            let current_frame_back_buffer = renderer.current_frame().clone();
+            let current_frame_back_buffer = renderer.current_frame();
             let transition_barrier = D3D12_RESOURCE_TRANSITION_BARRIER {
                 pResource: ManuallyDrop::new(Some(current_frame_back_buffer)),
                 Subresource: D3D12_RESOURCE_BARRIER_ALL_SUBRESOURCES,
@@ -184,20 +423,43 @@ impl<'a> Direct3D12DrawingSession<'a> {
                 .GetCPUDescriptorHandleForHeapStart();
             rtv_handle.ptr +=
                 renderer.rtv_descriptor_size as usize * renderer.current_frame_index();
-            command_list.OMSetRenderTargets(1, Some([rtv_handle].as_ptr()), false, None);
+            let dsv_handle = renderer
+                .depth_buffer
+                .borrow()
+                .as_ref()
+                .map(|depth_buffer| depth_buffer.dsv_handle());
+            command_list.OMSetRenderTargets(
+                1,
+                Some([rtv_handle].as_ptr()),
+                false,
+                dsv_handle.as_ref().map(|handle| handle as *const _),
+            );
         };
         Direct3D12DrawingSession {
             renderer,
             command_list,
+            solid_color,
             resources: Vec::new(),
+            stats: FrameStats::default(),
+            circle_segments: None,
         }
     }
 }
 
-fn load_triangle_buffer(
-    renderer: &Direct3D12Renderer,
-    triangle_vertices: &[Vector2<f32>; 3],
-) -> ID3D12Resource {
+/// Scissor rectangle covering the whole render target.
+fn full_scissor_rect(renderer: &Direct3D12Renderer) -> RECT {
+    let size = renderer.size();
+    Rect {
+        x: 0,
+        y: 0,
+        width: size.width as i32,
+        height: size.height as i32,
+    }
+    .into()
+}
+
+/// Creates an upload heap buffer holding a copy of `vertices`, to read vertices or indices from.
+fn load_upload_buffer<T: Copy>(renderer: &Direct3D12Renderer, vertices: &[T]) -> ID3D12Resource {
     let device = &renderer.device;
     let heap_properties = D3D12_HEAP_PROPERTIES {
         Type: D3D12_HEAP_TYPE_UPLOAD,
@@ -209,7 +471,7 @@ fn load_triangle_buffer(
     let resource_desc = D3D12_RESOURCE_DESC {
         Dimension: D3D12_RESOURCE_DIMENSION_BUFFER,
         Alignment: 0,
-        Width: std::mem::size_of_val(triangle_vertices) as u64,
+        Width: std::mem::size_of_val(vertices) as u64,
         Height: 1,
         DepthOrArraySize: 1,
         MipLevels: 1,
@@ -235,7 +497,7 @@ fn load_triangle_buffer(
     };
     let resource = match result {
         Ok(_) => resource.unwrap(),
-        Err(e) => panic!("Failed to create vertex buffer: {}", e.to_string()),
+        Err(e) => panic!("Failed to create upload buffer: {}", e.to_string()),
     };
 
     let no_read_range = D3D12_RANGE::default();
@@ -244,59 +506,15 @@ fn load_triangle_buffer(
     match result {
         Ok(_) => unsafe {
             std::ptr::copy(
-                triangle_vertices
-                    .as_ptr() as *const std::ffi::c_void,
+                vertices.as_ptr() as *const std::ffi::c_void,
                 data,
-                std::mem::size_of_val(triangle_vertices),
+                std::mem::size_of_val(vertices),
             );
 
             resource.Unmap(0, None);
 
-            renderer.wait_for_frame();
-
             resource
         },
-        Err(e) => panic!("Failed to map vertex buffer: {}", e.to_string()),
-    }
-}
-
-fn get_root_signature(device: &ID3D12Device) -> Result<ID3D12RootSignature, String> {
-    let root_signature_desc = D3D12_ROOT_SIGNATURE_DESC {
-        NumParameters: 0,
-        pParameters: std::ptr::null(),
-        NumStaticSamplers: 0,
-        pStaticSamplers: std::ptr::null(),
-        Flags: D3D12_ROOT_SIGNATURE_FLAG_ALLOW_INPUT_ASSEMBLER_INPUT_LAYOUT,
-    };
-
-    let mut root_signature_blob: Option<ID3DBlob> = None;
-    let mut error_blob: Option<ID3DBlob> = None;
-
-    let result = unsafe {
-        D3D12SerializeRootSignature(
-            &root_signature_desc,
-            D3D_ROOT_SIGNATURE_VERSION_1,
-            &mut root_signature_blob,
-            Some(&mut error_blob),
-        )
-    };
-    match result {
-        Ok(_) => {}
-        Err(e) => return Err(e.to_string()),
-    }
-
-    let root_signature_blob = root_signature_blob.unwrap();
-    let result = unsafe {
-        device.CreateRootSignature(
-            0,
-            std::slice::from_raw_parts(
-                std::mem::transmute(root_signature_blob.GetBufferPointer()),
-                root_signature_blob.GetBufferSize(),
-            ),
-        )
-    };
-    match result {
-        Ok(rs) => Ok(rs),
-        Err(e) => Err(e.to_string()),
+        Err(e) => panic!("Failed to map upload buffer: {}", e.to_string()),
     }
 }
@@ -0,0 +1,283 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Space between glyphs in the atlas, so sampling one never reads its neighbours.
+const PADDING: u32 = 1;
+
+/// Area of the atlas texture holding a glyph, in texels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(super) struct AtlasRegion {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Horizontal strip of the atlas, filled left to right with glyphs up to its height.
+#[derive(Debug)]
+struct Shelf {
+    y: u32,
+    height: u32,
+    cursor_x: u32,
+    last_used: u64,
+}
+
+#[derive(Debug)]
+struct Entry<V> {
+    region: AtlasRegion,
+    /// Index of the shelf holding the glyph, `None` for empty glyphs which take no space.
+    shelf: Option<usize>,
+    value: V,
+}
+
+/// Allocates glyph bitmaps in a fixed size texture using shelf packing.
+/// When full, the least recently used shelf not used by the current batch is evicted.
+#[derive(Debug)]
+pub(super) struct GlyphAtlas<K, V> {
+    width: u32,
+    height: u32,
+    shelves: Vec<Shelf>,
+    entries: HashMap<K, Entry<V>>,
+    batch: u64,
+}
+
+impl<K: Hash + Eq, V> GlyphAtlas<K, V> {
+    pub fn new(width: u32, height: u32) -> Self {
+        GlyphAtlas {
+            width,
+            height,
+            shelves: Vec::new(),
+            entries: HashMap::new(),
+            batch: 1,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Starts a new batch. Glyphs used by the current batch are never evicted.
+    pub fn begin_batch(&mut self) {
+        self.batch += 1;
+    }
+
+    /// Looks up a glyph, marking it as used by the current batch.
+    pub fn get(&mut self, key: &K) -> Option<(AtlasRegion, &V)> {
+        let entry = self.entries.get(key)?;
+        if let Some(shelf) = entry.shelf {
+            self.shelves[shelf].last_used = self.batch;
+        }
+        Some((entry.region, &entry.value))
+    }
+
+    /// Allocates space for a `width` x `height` glyph, evicting old glyphs if needed.
+    /// Returns `None` if the glyph doesn't fit even after eviction.
+    pub fn insert(&mut self, key: K, width: u32, height: u32, value: V) -> Option<AtlasRegion> {
+        if width == 0 || height == 0 {
+            let region = AtlasRegion::default();
+            let entry = Entry {
+                region,
+                shelf: None,
+                value,
+            };
+            self.entries.insert(key, entry);
+            return Some(region);
+        }
+
+        let padded_width = width + PADDING;
+        let padded_height = height + PADDING;
+        if padded_width > self.width || padded_height > self.height {
+            return None;
+        }
+
+        let shelf = match self.find_shelf(padded_width, padded_height) {
+            Some(shelf) => shelf,
+            None => self
+                .add_shelf(padded_height)
+                .or_else(|| self.evict_shelf(padded_height))?,
+        };
+
+        let shelf_ref = &mut self.shelves[shelf];
+        let region = AtlasRegion {
+            x: shelf_ref.cursor_x,
+            y: shelf_ref.y,
+            width,
+            height,
+        };
+        shelf_ref.cursor_x += padded_width;
+        shelf_ref.last_used = self.batch;
+
+        let entry = Entry {
+            region,
+            shelf: Some(shelf),
+            value,
+        };
+        self.entries.insert(key, entry);
+        Some(region)
+    }
+
+    /// Finds the shortest shelf tall enough with room left for the glyph.
+    fn find_shelf(&self, width: u32, height: u32) -> Option<usize> {
+        self.shelves
+            .iter()
+            .enumerate()
+            .filter(|(_, shelf)| shelf.height >= height && shelf.cursor_x + width <= self.width)
+            .min_by_key(|(_, shelf)| shelf.height)
+            .map(|(index, _)| index)
+    }
+
+    /// Opens a new shelf below the existing ones, if there is space left.
+    fn add_shelf(&mut self, height: u32) -> Option<usize> {
+        let y = self
+            .shelves
+            .last()
+            .map_or(0, |shelf| shelf.y + shelf.height);
+        if y + height > self.height {
+            return None;
+        }
+        self.shelves.push(Shelf {
+            y,
+            height,
+            cursor_x: 0,
+            last_used: self.batch,
+        });
+        Some(self.shelves.len() - 1)
+    }
+
+    /// Empties the least recently used shelf tall enough for the glyph.
+    fn evict_shelf(&mut self, height: u32) -> Option<usize> {
+        let batch = self.batch;
+        let shelf = self
+            .shelves
+            .iter()
+            .enumerate()
+            .filter(|(_, shelf)| shelf.height >= height && shelf.last_used < batch)
+            .min_by_key(|(_, shelf)| (shelf.last_used, shelf.height))
+            .map(|(index, _)| index)?;
+        self.entries.retain(|_, entry| entry.shelf != Some(shelf));
+        self.shelves[shelf].cursor_x = 0;
+        Some(shelf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glyph_atlas_insert_and_get() {
+        let mut atlas = GlyphAtlas::new(64, 64);
+        let a = atlas.insert('a', 10, 12, 1).unwrap();
+        let b = atlas.insert('b', 8, 12, 2).unwrap();
+        assert_eq!(
+            a,
+            AtlasRegion {
+                x: 0,
+                y: 0,
+                width: 10,
+                height: 12
+            }
+        );
+        assert_eq!(
+            b,
+            AtlasRegion {
+                x: 11,
+                y: 0,
+                width: 8,
+                height: 12
+            }
+        );
+        assert_eq!(atlas.get(&'a'), Some((a, &1)));
+        assert_eq!(atlas.get(&'b'), Some((b, &2)));
+        assert_eq!(atlas.get(&'c'), None);
+    }
+
+    #[test]
+    fn test_glyph_atlas_opens_shelves() {
+        let mut atlas = GlyphAtlas::new(32, 64);
+        let a = atlas.insert('a', 20, 10, ()).unwrap();
+        let b = atlas.insert('b', 20, 10, ()).unwrap();
+        let c = atlas.insert('c', 5, 20, ()).unwrap();
+        assert_eq!((a.x, a.y), (0, 0));
+        assert_eq!((b.x, b.y), (0, 11));
+        assert_eq!((c.x, c.y), (0, 22));
+        // A short glyph reuses the room left on the first shelf.
+        let d = atlas.insert('d', 5, 5, ()).unwrap();
+        assert_eq!((d.x, d.y), (21, 0));
+    }
+
+    #[test]
+    fn test_glyph_atlas_empty_glyph_takes_no_space() {
+        let mut atlas = GlyphAtlas::new(16, 16);
+        assert_eq!(atlas.insert(' ', 0, 0, ()), Some(AtlasRegion::default()));
+        let a = atlas.insert('a', 15, 15, ()).unwrap();
+        assert_eq!((a.x, a.y), (0, 0));
+        assert!(atlas.get(&' ').is_some());
+    }
+
+    #[test]
+    fn test_glyph_atlas_too_big() {
+        let mut atlas = GlyphAtlas::new(16, 16);
+        assert_eq!(atlas.insert('a', 16, 4, ()), None);
+        assert_eq!(atlas.insert('a', 4, 16, ()), None);
+        assert_eq!(atlas.get(&'a'), None);
+    }
+
+    #[test]
+    fn test_glyph_atlas_evicts_least_recently_used_shelf() {
+        let mut atlas = GlyphAtlas::new(16, 33);
+        atlas.insert('a', 15, 10, ()).unwrap();
+        atlas.begin_batch();
+        atlas.insert('b', 15, 10, ()).unwrap();
+        atlas.begin_batch();
+        atlas.insert('c', 15, 10, ()).unwrap();
+        atlas.begin_batch();
+        atlas.get(&'a');
+
+        // 'b' is the least recently used, its shelf is reused.
+        let d = atlas.insert('d', 15, 10, ()).unwrap();
+        assert_eq!((d.x, d.y), (0, 11));
+        assert!(atlas.get(&'b').is_none());
+        assert!(atlas.get(&'a').is_some());
+        assert!(atlas.get(&'c').is_some());
+    }
+
+    #[test]
+    fn test_glyph_atlas_keeps_glyphs_of_current_batch() {
+        let mut atlas = GlyphAtlas::new(16, 22);
+        atlas.insert('a', 15, 10, ()).unwrap();
+        atlas.insert('b', 15, 10, ()).unwrap();
+        assert_eq!(atlas.insert('c', 15, 10, ()), None);
+        assert!(atlas.get(&'a').is_some());
+        assert!(atlas.get(&'b').is_some());
+
+        atlas.begin_batch();
+        atlas.get(&'b');
+        let c = atlas.insert('c', 15, 10, ()).unwrap();
+        assert_eq!((c.x, c.y), (0, 0));
+        assert!(atlas.get(&'a').is_none());
+    }
+}
@@ -0,0 +1,710 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Root signatures and pipeline state objects of the Direct3D12 renderer.
+//!
+//! Every primitive is drawn with one of the [`PipelineKind`]s. Their root signature layouts are
+//! described as plain data by [`PipelineKind::layout`], and the pipelines are created on first use
+//! by the [`PipelineRegistry`] of the renderer, from HLSL sources embedded in the binary.
+
+use std::{mem::ManuallyDrop, sync::OnceLock};
+
+use windows::{
+    core::{s, PCSTR},
+    Win32::{
+        Foundation::BOOL,
+        Graphics::{
+            Direct3D::{
+                Fxc::{D3DCompile, D3DCOMPILE_DEBUG, D3DCOMPILE_SKIP_OPTIMIZATION},
+                *,
+            },
+            Direct3D12::*,
+            Dxgi::Common::*,
+        },
+    },
+};
+
+use super::text::TEXT_CONSTANT_COUNT;
+
+/// Flags the embedded shaders are compiled with. Debug builds keep the debug information
+/// and skip optimizations so the shaders can be stepped through in PIX.
+#[cfg(debug_assertions)]
+const SHADER_COMPILE_FLAGS: u32 = D3DCOMPILE_DEBUG | D3DCOMPILE_SKIP_OPTIMIZATION;
+#[cfg(not(debug_assertions))]
+const SHADER_COMPILE_FLAGS: u32 = 0;
+
+/// Number of 32-bit values in the `QuadConstants` buffer of the textured quad shaders.
+pub(super) const QUAD_CONSTANT_COUNT: u32 = 6;
+
+/// Number of 32-bit values in the `MeshConstants` buffer of the mesh shaders:
+/// the column-major transform followed by the color.
+pub(super) const MESH_CONSTANT_COUNT: u32 = 20;
+
+/// Pipelines the renderer draws with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(super) enum PipelineKind {
+    /// Triangles in clip space filled with a solid color.
+    SolidColor,
+    /// Quads in pixels sampling a texture, multiplied by a tint color.
+    TexturedQuad,
+    /// Glyph quads in pixels sampling the coverage of the glyph atlas.
+    Text,
+    /// Indexed triangles in 3D transformed into clip space, tested against the depth buffer
+    /// when the renderer has one.
+    Mesh,
+}
+
+impl PipelineKind {
+    pub const ALL: [PipelineKind; 4] = [
+        PipelineKind::SolidColor,
+        PipelineKind::TexturedQuad,
+        PipelineKind::Text,
+        PipelineKind::Mesh,
+    ];
+
+    /// Root signature layout of the pipeline, matching the registers declared by its shaders.
+    pub fn layout(self) -> PipelineLayout {
+        match self {
+            PipelineKind::SolidColor => PipelineLayout {
+                parameters: &[],
+                samplers: &[],
+            },
+            PipelineKind::TexturedQuad => PipelineLayout {
+                parameters: &[
+                    RootParameter::ShaderResources {
+                        register: 0,
+                        count: 1,
+                        visibility: D3D12_SHADER_VISIBILITY_PIXEL,
+                    },
+                    RootParameter::Constants {
+                        register: 0,
+                        count: QUAD_CONSTANT_COUNT,
+                        visibility: D3D12_SHADER_VISIBILITY_ALL,
+                    },
+                ],
+                samplers: &[D3D12_FILTER_MIN_MAG_MIP_LINEAR],
+            },
+            PipelineKind::Text => PipelineLayout {
+                parameters: &[
+                    RootParameter::ShaderResources {
+                        register: 0,
+                        count: 1,
+                        visibility: D3D12_SHADER_VISIBILITY_PIXEL,
+                    },
+                    RootParameter::Constants {
+                        register: 0,
+                        count: TEXT_CONSTANT_COUNT,
+                        visibility: D3D12_SHADER_VISIBILITY_ALL,
+                    },
+                ],
+                samplers: &[D3D12_FILTER_MIN_MAG_MIP_POINT],
+            },
+            PipelineKind::Mesh => PipelineLayout {
+                parameters: &[RootParameter::Constants {
+                    register: 0,
+                    count: MESH_CONSTANT_COUNT,
+                    visibility: D3D12_SHADER_VISIBILITY_ALL,
+                }],
+                samplers: &[],
+            },
+        }
+    }
+
+    /// Vertex and pixel shader sources of the pipeline.
+    fn shaders(self) -> (&'static [u8], &'static [u8]) {
+        match self {
+            PipelineKind::SolidColor => (
+                include_bytes!("shaders/simple2d/vs_2d_screen_position.hlsl"),
+                include_bytes!("shaders/simple2d/ps_2d_simple_color.hlsl"),
+            ),
+            PipelineKind::TexturedQuad => (
+                include_bytes!("shaders/textured/vs_textured_quad.hlsl"),
+                include_bytes!("shaders/textured/ps_textured_quad.hlsl"),
+            ),
+            PipelineKind::Text => (
+                include_bytes!("shaders/text/vs_text_glyph.hlsl"),
+                include_bytes!("shaders/text/ps_text_glyph.hlsl"),
+            ),
+            PipelineKind::Mesh => (
+                include_bytes!("shaders/mesh/vs_mesh.hlsl"),
+                include_bytes!("shaders/mesh/ps_mesh.hlsl"),
+            ),
+        }
+    }
+
+    /// Whether the pipeline blends its output over the render target using the source alpha.
+    fn is_alpha_blended(self) -> bool {
+        self != PipelineKind::SolidColor
+    }
+}
+
+/// Root parameter of a pipeline layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum RootParameter {
+    /// `count` 32-bit root constants bound to register `b{register}`.
+    Constants {
+        register: u32,
+        count: u32,
+        visibility: D3D12_SHADER_VISIBILITY,
+    },
+    /// Descriptor table of `count` shader resource views starting at register `t{register}`.
+    ShaderResources {
+        register: u32,
+        count: u32,
+        visibility: D3D12_SHADER_VISIBILITY,
+    },
+}
+
+/// Root signature layout of a pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct PipelineLayout {
+    /// Root parameters, in the order of their root parameter indices.
+    pub parameters: &'static [RootParameter],
+    /// Filters of the static samplers, bound to registers `s0` onwards, visible to the pixel shader.
+    pub samplers: &'static [D3D12_FILTER],
+}
+
+/// Root signature and pipeline state object of a `PipelineKind`.
+pub(super) struct PipelineState {
+    pub root_signature: ID3D12RootSignature,
+    pub pipeline_state: ID3D12PipelineState,
+}
+
+impl PipelineState {
+    /// Binds the pipeline and its root signature to `command_list`.
+    pub fn bind(&self, command_list: &ID3D12GraphicsCommandList) {
+        unsafe {
+            command_list.SetPipelineState(&self.pipeline_state);
+            command_list.SetGraphicsRootSignature(&self.root_signature);
+        }
+    }
+}
+
+/// Pipelines of a device, created on first use.
+pub(super) struct PipelineRegistry {
+    device: ID3D12Device,
+    /// Format of the render target views the pipelines draw into.
+    render_target_format: DXGI_FORMAT,
+    /// Format of the depth buffer bound with the render target, `DXGI_FORMAT_UNKNOWN` for none.
+    depth_format: DXGI_FORMAT,
+    pipelines: [OnceLock<PipelineState>; PipelineKind::ALL.len()],
+}
+
+impl PipelineRegistry {
+    pub fn new(
+        device: &ID3D12Device,
+        render_target_format: DXGI_FORMAT,
+        depth_format: DXGI_FORMAT,
+    ) -> Self {
+        Self {
+            device: device.clone(),
+            render_target_format,
+            depth_format,
+            pipelines: Default::default(),
+        }
+    }
+
+    /// Returns the pipeline of `kind`, creating it if it wasn't used yet.
+    pub fn get_or_create(&self, kind: PipelineKind) -> Result<&PipelineState, String> {
+        let cell = &self.pipelines[kind as usize];
+        if let Some(pipeline) = cell.get() {
+            return Ok(pipeline);
+        }
+        let pipeline = create_pipeline(
+            &self.device,
+            kind,
+            self.render_target_format,
+            self.depth_format,
+        )?;
+        // Another thread may have won the race, in which case its pipeline is kept.
+        let _ = cell.set(pipeline);
+        Ok(cell.get().unwrap())
+    }
+
+    /// Drops every pipeline and creates them again on `device` as they are used.
+    /// Pipelines can't outlive their device, so this must be called once a device that was
+    /// removed has been recreated.
+    pub fn reset(&mut self, device: &ID3D12Device) {
+        self.device = device.clone();
+        self.pipelines = Default::default();
+    }
+}
+
+fn create_pipeline(
+    device: &ID3D12Device,
+    kind: PipelineKind,
+    render_target_format: DXGI_FORMAT,
+    depth_format: DXGI_FORMAT,
+) -> Result<PipelineState, String> {
+    let root_signature = create_layout_root_signature(device, &kind.layout())?;
+    let pipeline_state = create_pipeline_state(
+        device,
+        kind,
+        render_target_format,
+        depth_format,
+        &root_signature,
+    )?;
+    Ok(PipelineState {
+        root_signature,
+        pipeline_state,
+    })
+}
+
+fn create_layout_root_signature(
+    device: &ID3D12Device,
+    layout: &PipelineLayout,
+) -> Result<ID3D12RootSignature, String> {
+    // The ranges must not move once the parameters point into them.
+    let ranges: Vec<D3D12_DESCRIPTOR_RANGE> = layout
+        .parameters
+        .iter()
+        .map(|parameter| match *parameter {
+            RootParameter::ShaderResources {
+                register, count, ..
+            } => D3D12_DESCRIPTOR_RANGE {
+                RangeType: D3D12_DESCRIPTOR_RANGE_TYPE_SRV,
+                NumDescriptors: count,
+                BaseShaderRegister: register,
+                RegisterSpace: 0,
+                OffsetInDescriptorsFromTableStart: 0,
+            },
+            RootParameter::Constants { .. } => D3D12_DESCRIPTOR_RANGE::default(),
+        })
+        .collect();
+    let parameters: Vec<D3D12_ROOT_PARAMETER> = layout
+        .parameters
+        .iter()
+        .zip(&ranges)
+        .map(|(parameter, range)| match *parameter {
+            RootParameter::Constants {
+                register,
+                count,
+                visibility,
+            } => D3D12_ROOT_PARAMETER {
+                ParameterType: D3D12_ROOT_PARAMETER_TYPE_32BIT_CONSTANTS,
+                Anonymous: D3D12_ROOT_PARAMETER_0 {
+                    Constants: D3D12_ROOT_CONSTANTS {
+                        ShaderRegister: register,
+                        RegisterSpace: 0,
+                        Num32BitValues: count,
+                    },
+                },
+                ShaderVisibility: visibility,
+            },
+            RootParameter::ShaderResources { visibility, .. } => D3D12_ROOT_PARAMETER {
+                ParameterType: D3D12_ROOT_PARAMETER_TYPE_DESCRIPTOR_TABLE,
+                Anonymous: D3D12_ROOT_PARAMETER_0 {
+                    DescriptorTable: D3D12_ROOT_DESCRIPTOR_TABLE {
+                        NumDescriptorRanges: 1,
+                        pDescriptorRanges: range,
+                    },
+                },
+                ShaderVisibility: visibility,
+            },
+        })
+        .collect();
+    let samplers: Vec<D3D12_STATIC_SAMPLER_DESC> = layout
+        .samplers
+        .iter()
+        .zip(0..)
+        .map(|(&filter, register)| D3D12_STATIC_SAMPLER_DESC {
+            Filter: filter,
+            AddressU: D3D12_TEXTURE_ADDRESS_MODE_CLAMP,
+            AddressV: D3D12_TEXTURE_ADDRESS_MODE_CLAMP,
+            AddressW: D3D12_TEXTURE_ADDRESS_MODE_CLAMP,
+            MipLODBias: 0.0,
+            MaxAnisotropy: 0,
+            ComparisonFunc: D3D12_COMPARISON_FUNC_NEVER,
+            BorderColor: D3D12_STATIC_BORDER_COLOR_TRANSPARENT_BLACK,
+            MinLOD: 0.0,
+            MaxLOD: D3D12_FLOAT32_MAX,
+            ShaderRegister: register,
+            RegisterSpace: 0,
+            ShaderVisibility: D3D12_SHADER_VISIBILITY_PIXEL,
+        })
+        .collect();
+
+    let root_signature_desc = D3D12_ROOT_SIGNATURE_DESC {
+        NumParameters: parameters.len() as u32,
+        pParameters: if parameters.is_empty() {
+            std::ptr::null()
+        } else {
+            parameters.as_ptr()
+        },
+        NumStaticSamplers: samplers.len() as u32,
+        pStaticSamplers: if samplers.is_empty() {
+            std::ptr::null()
+        } else {
+            samplers.as_ptr()
+        },
+        Flags: D3D12_ROOT_SIGNATURE_FLAG_ALLOW_INPUT_ASSEMBLER_INPUT_LAYOUT,
+    };
+    create_root_signature(device, &root_signature_desc)
+}
+
+/// Serializes `root_signature_desc` and creates the root signature it describes.
+fn create_root_signature(
+    device: &ID3D12Device,
+    root_signature_desc: &D3D12_ROOT_SIGNATURE_DESC,
+) -> Result<ID3D12RootSignature, String> {
+    let mut root_signature_blob: Option<ID3DBlob> = None;
+    let mut error_blob: Option<ID3DBlob> = None;
+
+    let result = unsafe {
+        D3D12SerializeRootSignature(
+            root_signature_desc,
+            D3D_ROOT_SIGNATURE_VERSION_1,
+            &mut root_signature_blob,
+            Some(&mut error_blob),
+        )
+    };
+    if let Err(e) = result {
+        return Err(blob_message(error_blob).unwrap_or_else(|| e.to_string()));
+    }
+
+    let root_signature_blob = root_signature_blob.unwrap();
+    let result = unsafe {
+        device.CreateRootSignature(
+            0,
+            std::slice::from_raw_parts(
+                root_signature_blob.GetBufferPointer() as *const u8,
+                root_signature_blob.GetBufferSize(),
+            ),
+        )
+    };
+    result.map_err(|e| e.to_string())
+}
+
+fn create_pipeline_state(
+    device: &ID3D12Device,
+    kind: PipelineKind,
+    render_target_format: DXGI_FORMAT,
+    depth_format: DXGI_FORMAT,
+    root_signature: &ID3D12RootSignature,
+) -> Result<ID3D12PipelineState, String> {
+    let (vertex_shader, pixel_shader) = kind.shaders();
+    let vertex_shader_bytecode = compile_from_content(vertex_shader, "VSMain\0", "vs_5_1\0")?;
+    let pixel_shader_bytecode = compile_from_content(pixel_shader, "PSMain\0", "ps_5_1\0")?;
+
+    let position = D3D12_INPUT_ELEMENT_DESC {
+        SemanticName: s!("POSITION"),
+        SemanticIndex: 0,
+        Format: DXGI_FORMAT_R32G32_FLOAT,
+        InputSlot: 0,
+        AlignedByteOffset: 0,
+        InputSlotClass: D3D12_INPUT_CLASSIFICATION_PER_VERTEX_DATA,
+        InstanceDataStepRate: 0,
+    };
+    let texcoord = D3D12_INPUT_ELEMENT_DESC {
+        SemanticName: s!("TEXCOORD"),
+        AlignedByteOffset: 8,
+        ..position
+    };
+    let color = D3D12_INPUT_ELEMENT_DESC {
+        SemanticName: s!("COLOR"),
+        Format: DXGI_FORMAT_R32G32B32A32_FLOAT,
+        AlignedByteOffset: 16,
+        ..position
+    };
+    let position_3d = D3D12_INPUT_ELEMENT_DESC {
+        Format: DXGI_FORMAT_R32G32B32_FLOAT,
+        ..position
+    };
+    let input_elements: &[D3D12_INPUT_ELEMENT_DESC] = match kind {
+        PipelineKind::SolidColor => &[position],
+        PipelineKind::TexturedQuad => &[position, texcoord],
+        PipelineKind::Text => &[position, texcoord, color],
+        PipelineKind::Mesh => &[position_3d],
+    };
+
+    // Every pipeline must declare the depth buffer bound with the render target,
+    // even those that don't use it.
+    let depth_stencil_state = match kind {
+        PipelineKind::Mesh if depth_format != DXGI_FORMAT_UNKNOWN => D3D12_DEPTH_STENCIL_DESC {
+            DepthEnable: true.into(),
+            DepthWriteMask: D3D12_DEPTH_WRITE_MASK_ALL,
+            DepthFunc: D3D12_COMPARISON_FUNC_LESS,
+            ..Default::default()
+        },
+        _ => D3D12_DEPTH_STENCIL_DESC::default(),
+    };
+
+    let mut blend_state = get_default_blend_state();
+    if kind.is_alpha_blended() {
+        blend_state.RenderTarget[0] = D3D12_RENDER_TARGET_BLEND_DESC {
+            BlendEnable: true.into(),
+            LogicOpEnable: false.into(),
+            SrcBlend: D3D12_BLEND_SRC_ALPHA,
+            DestBlend: D3D12_BLEND_INV_SRC_ALPHA,
+            BlendOp: D3D12_BLEND_OP_ADD,
+            SrcBlendAlpha: D3D12_BLEND_ONE,
+            DestBlendAlpha: D3D12_BLEND_INV_SRC_ALPHA,
+            BlendOpAlpha: D3D12_BLEND_OP_ADD,
+            LogicOp: D3D12_LOGIC_OP_NOOP,
+            RenderTargetWriteMask: D3D12_COLOR_WRITE_ENABLE_ALL.0 as u8,
+        };
+    }
+
+    let pipeline_state_description = D3D12_GRAPHICS_PIPELINE_STATE_DESC {
+        pRootSignature: ManuallyDrop::new(Some(root_signature.clone())),
+        VS: D3D12_SHADER_BYTECODE {
+            pShaderBytecode: unsafe { vertex_shader_bytecode.GetBufferPointer() },
+            BytecodeLength: unsafe { vertex_shader_bytecode.GetBufferSize() },
+        },
+        PS: D3D12_SHADER_BYTECODE {
+            pShaderBytecode: unsafe { pixel_shader_bytecode.GetBufferPointer() },
+            BytecodeLength: unsafe { pixel_shader_bytecode.GetBufferSize() },
+        },
+        BlendState: blend_state,
+        RasterizerState: D3D12_RASTERIZER_DESC {
+            FillMode: D3D12_FILL_MODE_SOLID,
+            // Quads are built in pixels, where the y axis points down and flips their winding.
+            CullMode: match kind {
+                PipelineKind::SolidColor => D3D12_CULL_MODE_BACK,
+                PipelineKind::TexturedQuad | PipelineKind::Text | PipelineKind::Mesh => {
+                    D3D12_CULL_MODE_NONE
+                }
+            },
+            FrontCounterClockwise: false.into(),
+            DepthBias: D3D12_DEFAULT_DEPTH_BIAS,
+            DepthBiasClamp: D3D12_DEFAULT_DEPTH_BIAS_CLAMP,
+            SlopeScaledDepthBias: D3D12_DEFAULT_SLOPE_SCALED_DEPTH_BIAS,
+            DepthClipEnable: true.into(),
+            MultisampleEnable: false.into(),
+            AntialiasedLineEnable: false.into(),
+            ForcedSampleCount: 0,
+            ConservativeRaster: D3D12_CONSERVATIVE_RASTERIZATION_MODE_OFF,
+        },
+        InputLayout: D3D12_INPUT_LAYOUT_DESC {
+            pInputElementDescs: input_elements.as_ptr(),
+            NumElements: input_elements.len() as u32,
+        },
+        SampleMask: u32::MAX,
+        NumRenderTargets: 1,
+        RTVFormats: [
+            render_target_format,
+            DXGI_FORMAT_UNKNOWN,
+            DXGI_FORMAT_UNKNOWN,
+            DXGI_FORMAT_UNKNOWN,
+            DXGI_FORMAT_UNKNOWN,
+            DXGI_FORMAT_UNKNOWN,
+            DXGI_FORMAT_UNKNOWN,
+            DXGI_FORMAT_UNKNOWN,
+        ],
+        Flags: D3D12_PIPELINE_STATE_FLAG_NONE,
+        PrimitiveTopologyType: D3D12_PRIMITIVE_TOPOLOGY_TYPE_TRIANGLE,
+        DepthStencilState: depth_stencil_state,
+        DSVFormat: depth_format,
+        SampleDesc: DXGI_SAMPLE_DESC {
+            Count: 1,
+            Quality: 0,
+        },
+        ..Default::default()
+    };
+    let result = unsafe { device.CreateGraphicsPipelineState(&pipeline_state_description) };
+    ManuallyDrop::into_inner(pipeline_state_description.pRootSignature);
+    result.map_err(|e| e.to_string())
+}
+
+/// Compiles the HLSL in `content`. `entry_point` and `profile` must be nul terminated.
+fn compile_from_content(
+    content: &[u8],
+    entry_point: &'static str,
+    profile: &'static str,
+) -> Result<ID3DBlob, String> {
+    let mut ppcode: Option<ID3DBlob> = None;
+    let mut pperrormsgs: Option<ID3DBlob> = None;
+    let entry_point = PCSTR::from_raw(entry_point.as_ptr());
+    let profile = PCSTR::from_raw(profile.as_ptr());
+    let result = unsafe {
+        D3DCompile(
+            content.as_ptr() as *const std::ffi::c_void,
+            content.len(),
+            None,
+            None,
+            None,
+            entry_point,
+            profile,
+            SHADER_COMPILE_FLAGS,
+            0,
+            &mut ppcode,
+            Some(&mut pperrormsgs),
+        )
+    };
+    match result {
+        Ok(_) => Ok(ppcode.unwrap()),
+        Err(e) => Err(blob_message(pperrormsgs).unwrap_or_else(|| e.to_string())),
+    }
+}
+
+/// Text of an error blob returned by the shader compiler or the root signature serializer.
+fn blob_message(blob: Option<ID3DBlob>) -> Option<String> {
+    let blob = blob?;
+    let message = unsafe {
+        std::slice::from_raw_parts(blob.GetBufferPointer() as *const u8, blob.GetBufferSize())
+    };
+    Some(
+        String::from_utf8_lossy(message)
+            .trim_end_matches('\0')
+            .trim_end()
+            .to_string(),
+    )
+}
+
+const fn get_default_blend_state() -> D3D12_BLEND_DESC {
+    D3D12_BLEND_DESC {
+        AlphaToCoverageEnable: BOOL(0),
+        IndependentBlendEnable: BOOL(0),
+        RenderTarget: [get_default_render_target_blend(); 8],
+    }
+}
+
+const fn get_default_render_target_blend() -> D3D12_RENDER_TARGET_BLEND_DESC {
+    D3D12_RENDER_TARGET_BLEND_DESC {
+        BlendEnable: BOOL(0),
+        LogicOpEnable: BOOL(0),
+        SrcBlend: D3D12_BLEND_ONE,
+        DestBlend: D3D12_BLEND_ZERO,
+        BlendOp: D3D12_BLEND_OP_ADD,
+        SrcBlendAlpha: D3D12_BLEND_ONE,
+        DestBlendAlpha: D3D12_BLEND_ZERO,
+        BlendOpAlpha: D3D12_BLEND_OP_ADD,
+        LogicOp: D3D12_LOGIC_OP_NOOP,
+        RenderTargetWriteMask: D3D12_COLOR_WRITE_ENABLE_ALL.0 as u8,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::AdapterPreference;
+
+    /// Creates a device on the WARP software adapter, available on every Windows machine.
+    fn create_warp_device() -> ID3D12Device {
+        super::super::create_d3d_device(AdapterPreference::Warp)
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn test_pipeline_kinds_are_indexed_in_order() {
+        for (index, kind) in PipelineKind::ALL.into_iter().enumerate() {
+            assert_eq!(kind as usize, index);
+        }
+    }
+
+    #[test]
+    fn test_solid_color_layout_is_empty() {
+        let layout = PipelineKind::SolidColor.layout();
+        assert!(layout.parameters.is_empty());
+        assert!(layout.samplers.is_empty());
+    }
+
+    #[test]
+    fn test_mesh_layout_holds_transform_and_color() {
+        let layout = PipelineKind::Mesh.layout();
+        assert_eq!(
+            layout.parameters,
+            &[RootParameter::Constants {
+                register: 0,
+                count: 16 + 4,
+                visibility: D3D12_SHADER_VISIBILITY_ALL,
+            }]
+        );
+        assert!(layout.samplers.is_empty());
+    }
+
+    #[test]
+    fn test_textured_layouts_bind_texture_then_constants() {
+        for (kind, constant_count, filter) in [
+            (
+                PipelineKind::TexturedQuad,
+                QUAD_CONSTANT_COUNT,
+                D3D12_FILTER_MIN_MAG_MIP_LINEAR,
+            ),
+            (
+                PipelineKind::Text,
+                TEXT_CONSTANT_COUNT,
+                D3D12_FILTER_MIN_MAG_MIP_POINT,
+            ),
+        ] {
+            let layout = kind.layout();
+            assert_eq!(
+                layout.parameters,
+                &[
+                    RootParameter::ShaderResources {
+                        register: 0,
+                        count: 1,
+                        visibility: D3D12_SHADER_VISIBILITY_PIXEL,
+                    },
+                    RootParameter::Constants {
+                        register: 0,
+                        count: constant_count,
+                        visibility: D3D12_SHADER_VISIBILITY_ALL,
+                    },
+                ]
+            );
+            assert_eq!(layout.samplers, &[filter]);
+        }
+    }
+
+    #[test]
+    fn test_pipelines_create_on_warp_device() {
+        let device = create_warp_device();
+        let render_target_formats = [
+            DXGI_FORMAT_R8G8B8A8_UNORM,
+            DXGI_FORMAT_R8G8B8A8_UNORM_SRGB,
+            DXGI_FORMAT_R16G16B16A16_FLOAT,
+        ];
+        for render_target_format in render_target_formats {
+            for depth_format in [DXGI_FORMAT_UNKNOWN, DXGI_FORMAT_D32_FLOAT] {
+                let registry = PipelineRegistry::new(&device, render_target_format, depth_format);
+                for kind in PipelineKind::ALL {
+                    let first = registry.get_or_create(kind).unwrap() as *const PipelineState;
+                    let second = registry.get_or_create(kind).unwrap() as *const PipelineState;
+                    assert_eq!(first, second);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_pipeline_registry_reset_recreates_pipelines() {
+        let device = create_warp_device();
+        let mut registry =
+            PipelineRegistry::new(&device, DXGI_FORMAT_R8G8B8A8_UNORM, DXGI_FORMAT_UNKNOWN);
+        let before = registry
+            .get_or_create(PipelineKind::Text)
+            .unwrap()
+            .pipeline_state
+            .clone();
+        registry.reset(&create_warp_device());
+        let after = &registry
+            .get_or_create(PipelineKind::Text)
+            .unwrap()
+            .pipeline_state;
+        assert_ne!(&before, after);
+    }
+
+    #[test]
+    fn test_compile_error_reports_compiler_message() {
+        let error = compile_from_content(b"float4 PSMain( {", "PSMain\0", "ps_5_1\0").unwrap_err();
+        assert!(error.contains("error"), "{}", error);
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+cbuffer QuadConstants : register(b0) {
+    float4 tint;
+    float2 viewport_size;
+};
+
+struct VSInput {
+    float2 position : POSITION;
+    float2 uv : TEXCOORD;
+};
+
+struct VSOutput {
+    float4 position : SV_Position;
+    float2 uv : TEXCOORD;
+};
+
+// Positions are in pixels from the top-left corner of the render target.
+VSOutput VSMain(VSInput input) {
+    VSOutput output;
+    float2 ndc = input.position / viewport_size * float2(2.0, -2.0) + float2(-1.0, 1.0);
+    output.position = float4(ndc, 0.0, 1.0);
+    output.uv = input.uv;
+    return output;
+}
@@ -17,68 +17,938 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+use std::{cell::RefCell, collections::HashMap, mem::ManuallyDrop, ops::Range, sync::Mutex};
+
 use windows::core::*;
 use windows::Win32::Foundation::{BOOL, *};
-use windows::Win32::Graphics::{Direct3D12::*, DirectWrite::*};
+use windows::Win32::Graphics::{
+    Direct3D::D3D_PRIMITIVE_TOPOLOGY_TRIANGLELIST, Direct3D12::*, DirectWrite::*, Dxgi::Common::*,
+};
 use windows::Win32::System::Com::*;
-use windows::Win32::UI::HiDpi::GetDpiForWindow;
 use windows_implement::implement;
 
 use crate::math::*;
 use crate::renderer::*;
 
-use super::TextFormat;
+use super::glyph_atlas::{AtlasRegion, GlyphAtlas};
+use super::pipeline::PipelineKind;
+use super::{TextFormat, TextMetrics};
+
+/// Transform leaving layout coordinates unchanged.
+const IDENTITY_TRANSFORM: DWRITE_MATRIX = DWRITE_MATRIX {
+    m11: 1.0,
+    m12: 0.0,
+    m21: 0.0,
+    m22: 1.0,
+    dx: 0.0,
+    dy: 0.0,
+};
+
+/// Width and height of the glyph atlas texture, in texels.
+const GLYPH_ATLAS_SIZE: u32 = 1024;
+
+/// Number of 32-bit values in the `TextConstants` buffer of the glyph shaders.
+pub(super) const TEXT_CONSTANT_COUNT: u32 = 2;
+
+/// Glyphs are cached per font face, em size and glyph index.
+type GlyphKey = (usize, u32, u16);
+
+/// Offset of a glyph bitmap from the glyph origin on the baseline, in pixels.
+type GlyphOffset = (i32, i32);
+
+/// Key of the fully covered texel underlines and strikethroughs sample. No font face is at
+/// address 0, so it can't collide with a glyph.
+const SOLID_GLYPH_KEY: GlyphKey = (0, 0, 0);
+
+/// Vertex of a glyph quad, as read by `vs_text_glyph.hlsl`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct GlyphVertex {
+    /// Position in render target pixels.
+    position: [f32; 2],
+    /// Normalized coordinates in the glyph atlas.
+    uv: [f32; 2],
+    /// Color of the glyph, multiplied by its coverage.
+    color: [f32; 4],
+}
+
+/// Coverage of a newly rasterized glyph, waiting to be copied into its atlas region.
+struct GlyphUpload {
+    region: AtlasRegion,
+    coverage: Vec<u8>,
+}
+
+/// Glyph quads and atlas uploads produced by laying out one piece of text.
+#[derive(Default)]
+pub(super) struct GlyphBatch {
+    vertices: Vec<GlyphVertex>,
+    uploads: Vec<GlyphUpload>,
+}
+
+/// State shared with the text renderer through the drawing context pointer of
+/// `IDWriteTextLayout::Draw`.
+struct TextDrawContext {
+    batch: RefCell<GlyphBatch>,
+    /// Color of the glyph runs without a `ColorDrawingEffect`.
+    default_color: [f32; 4],
+    /// Whether DirectWrite snaps glyph origins to whole pixels, from `TextFormat::pixel_snapping`.
+    pixel_snapping: bool,
+    /// Transform from layout coordinates to render target pixels.
+    transform: DWRITE_MATRIX,
+}
 
-const GLYPH_METRIC_STEP_SIZE: usize = 128;
-const USER_DEFAULT_SCREEN_DPI: u32 = 96;
+/// Drawing effect coloring a range of a text layout, set with
+/// `IDWriteTextLayout::SetDrawingEffect`. DirectWrite hands it back as the
+/// `clientdrawingeffect` of the glyph runs in that range.
+#[implement()]
+struct ColorDrawingEffect {
+    color: [f32; 4],
+}
+
+/// Color of a glyph run with the drawing effect `effect`: the color of a `ColorDrawingEffect`,
+/// or `default_color` for runs without one.
+fn drawing_effect_color(effect: Option<&IUnknown>, default_color: [f32; 4]) -> [f32; 4] {
+    effect
+        .and_then(|effect| effect.cast_object_ref::<ColorDrawingEffect>().ok())
+        .map_or(default_color, |effect| effect.color)
+}
+
+/// Converts the byte range `range` of `text` to the range of UTF-16 code units DirectWrite
+/// positions are counted in.
+fn utf16_text_range(text: &str, range: &Range<usize>) -> DWRITE_TEXT_RANGE {
+    DWRITE_TEXT_RANGE {
+        startPosition: text[..range.start].encode_utf16().count() as u32,
+        length: text[range.clone()].encode_utf16().count() as u32,
+    }
+}
+
+/// GPU objects shared by every text draw of a renderer.
+pub(super) struct TextResources {
+    /// Single channel texture holding the coverage of every cached glyph.
+    atlas_texture: ID3D12Resource,
+    srv_descriptor_heap: ID3D12DescriptorHeap,
+    atlas: Mutex<GlyphAtlas<GlyphKey, GlyphOffset>>,
+    /// Font faces referenced by the atlas keys, kept alive so their addresses aren't reused.
+    font_faces: Mutex<HashMap<usize, IDWriteFontFace>>,
+}
+
+impl TextResources {
+    pub fn create(device: &ID3D12Device) -> std::result::Result<Self, String> {
+        let heap_properties = D3D12_HEAP_PROPERTIES {
+            Type: D3D12_HEAP_TYPE_DEFAULT,
+            ..Default::default()
+        };
+        let desc = D3D12_RESOURCE_DESC {
+            Dimension: D3D12_RESOURCE_DIMENSION_TEXTURE2D,
+            Width: GLYPH_ATLAS_SIZE as u64,
+            Height: GLYPH_ATLAS_SIZE,
+            DepthOrArraySize: 1,
+            MipLevels: 1,
+            Format: DXGI_FORMAT_R8_UNORM,
+            SampleDesc: DXGI_SAMPLE_DESC {
+                Count: 1,
+                Quality: 0,
+            },
+            Layout: D3D12_TEXTURE_LAYOUT_UNKNOWN,
+            Flags: D3D12_RESOURCE_FLAG_NONE,
+            ..Default::default()
+        };
+        let atlas_texture = super::create_committed_resource(
+            device,
+            &heap_properties,
+            &desc,
+            D3D12_RESOURCE_STATE_PIXEL_SHADER_RESOURCE,
+        )?;
+
+        let srv_descriptor_heap: ID3D12DescriptorHeap = unsafe {
+            device.CreateDescriptorHeap(&D3D12_DESCRIPTOR_HEAP_DESC {
+                Type: D3D12_DESCRIPTOR_HEAP_TYPE_CBV_SRV_UAV,
+                NumDescriptors: 1,
+                Flags: D3D12_DESCRIPTOR_HEAP_FLAG_SHADER_VISIBLE,
+                NodeMask: 0,
+            })
+        }
+        .map_err(|e| e.to_string())?;
+        unsafe {
+            device.CreateShaderResourceView(
+                &atlas_texture,
+                None,
+                srv_descriptor_heap.GetCPUDescriptorHandleForHeapStart(),
+            );
+        }
+
+        Ok(Self {
+            atlas_texture,
+            srv_descriptor_heap,
+            atlas: Mutex::new(GlyphAtlas::new(GLYPH_ATLAS_SIZE, GLYPH_ATLAS_SIZE)),
+            font_faces: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Records the atlas uploads and the draw of the glyph quads in `batch`, returning
+    /// the number of triangles drawn. Leaves the text pipeline and root signature bound
+    /// to `command_list`. Buffers used by the command list are pushed into `resources`.
+    pub fn record_batch(
+        &self,
+        renderer: &super::Direct3D12Renderer,
+        command_list: &ID3D12GraphicsCommandList,
+        batch: GlyphBatch,
+        resources: &mut Vec<ID3D12Resource>,
+    ) -> std::result::Result<u32, String> {
+        if !batch.uploads.is_empty() {
+            let upload_buffer = self.record_uploads(renderer, command_list, &batch.uploads)?;
+            resources.push(upload_buffer);
+        }
+        if batch.vertices.is_empty() {
+            return Ok(0);
+        }
+
+        let vertex_data = unsafe {
+            std::slice::from_raw_parts(
+                batch.vertices.as_ptr() as *const u8,
+                std::mem::size_of_val(batch.vertices.as_slice()),
+            )
+        };
+        let vertex_buffer = create_upload_buffer(&renderer.device, vertex_data.len() as u64)?;
+        write_upload_buffer(&vertex_buffer, |data| data.copy_from_slice(vertex_data))?;
+
+        let size = renderer.size();
+        let constants: [f32; TEXT_CONSTANT_COUNT as usize] = [size.width, size.height];
+        renderer
+            .pipelines
+            .get_or_create(PipelineKind::Text)?
+            .bind(command_list);
+        unsafe {
+            command_list.SetDescriptorHeaps(&[Some(self.srv_descriptor_heap.clone())]);
+            command_list.SetGraphicsRootDescriptorTable(
+                0,
+                self.srv_descriptor_heap
+                    .GetGPUDescriptorHandleForHeapStart(),
+            );
+            command_list.SetGraphicsRoot32BitConstants(
+                1,
+                TEXT_CONSTANT_COUNT,
+                constants.as_ptr() as *const core::ffi::c_void,
+                0,
+            );
+            command_list.IASetPrimitiveTopology(D3D_PRIMITIVE_TOPOLOGY_TRIANGLELIST);
+            command_list.IASetVertexBuffers(
+                0,
+                Some(&[D3D12_VERTEX_BUFFER_VIEW {
+                    BufferLocation: vertex_buffer.GetGPUVirtualAddress(),
+                    SizeInBytes: vertex_data.len() as u32,
+                    StrideInBytes: std::mem::size_of::<GlyphVertex>() as u32,
+                }]),
+            );
+            command_list.DrawInstanced(batch.vertices.len() as u32, 1, 0, 0);
+        }
+        resources.push(vertex_buffer);
+        Ok(batch.vertices.len() as u32 / 3)
+    }
+
+    /// Records the copy of newly rasterized glyphs into the atlas texture,
+    /// returning the upload buffer the copies read from.
+    fn record_uploads(
+        &self,
+        renderer: &super::Direct3D12Renderer,
+        command_list: &ID3D12GraphicsCommandList,
+        uploads: &[GlyphUpload],
+    ) -> std::result::Result<ID3D12Resource, String> {
+        let (placements, upload_size) = upload_placements(
+            uploads
+                .iter()
+                .map(|upload| (upload.region.width, upload.region.height)),
+        );
+        let upload_buffer = create_upload_buffer(&renderer.device, upload_size)?;
+        write_upload_buffer(&upload_buffer, |data| {
+            for (upload, placement) in uploads.iter().zip(&placements) {
+                let width = upload.region.width as usize;
+                for (row, coverage) in upload.coverage.chunks_exact(width).enumerate() {
+                    let start = placement.offset as usize + row * placement.row_pitch as usize;
+                    data[start..start + width].copy_from_slice(coverage);
+                }
+            }
+        })?;
+
+        unsafe {
+            command_list.ResourceBarrier(&[super::transition_barrier(
+                &self.atlas_texture,
+                D3D12_RESOURCE_STATE_PIXEL_SHADER_RESOURCE,
+                D3D12_RESOURCE_STATE_COPY_DEST,
+            )]);
+            for (upload, placement) in uploads.iter().zip(&placements) {
+                let destination = D3D12_TEXTURE_COPY_LOCATION {
+                    pResource: ManuallyDrop::new(Some(self.atlas_texture.clone())),
+                    Type: D3D12_TEXTURE_COPY_TYPE_SUBRESOURCE_INDEX,
+                    Anonymous: D3D12_TEXTURE_COPY_LOCATION_0 {
+                        SubresourceIndex: 0,
+                    },
+                };
+                let source = D3D12_TEXTURE_COPY_LOCATION {
+                    pResource: ManuallyDrop::new(Some(upload_buffer.clone())),
+                    Type: D3D12_TEXTURE_COPY_TYPE_PLACED_FOOTPRINT,
+                    Anonymous: D3D12_TEXTURE_COPY_LOCATION_0 {
+                        PlacedFootprint: D3D12_PLACED_SUBRESOURCE_FOOTPRINT {
+                            Offset: placement.offset,
+                            Footprint: D3D12_SUBRESOURCE_FOOTPRINT {
+                                Format: DXGI_FORMAT_R8_UNORM,
+                                Width: upload.region.width,
+                                Height: upload.region.height,
+                                Depth: 1,
+                                RowPitch: placement.row_pitch,
+                            },
+                        },
+                    },
+                };
+                command_list.CopyTextureRegion(
+                    &destination,
+                    upload.region.x,
+                    upload.region.y,
+                    0,
+                    &source,
+                    None,
+                );
+                ManuallyDrop::into_inner(destination.pResource);
+                ManuallyDrop::into_inner(source.pResource);
+            }
+            command_list.ResourceBarrier(&[super::transition_barrier(
+                &self.atlas_texture,
+                D3D12_RESOURCE_STATE_COPY_DEST,
+                D3D12_RESOURCE_STATE_PIXEL_SHADER_RESOURCE,
+            )]);
+        }
+        Ok(upload_buffer)
+    }
+}
+
+fn create_upload_buffer(
+    device: &ID3D12Device,
+    size: u64,
+) -> std::result::Result<ID3D12Resource, String> {
+    let heap_properties = D3D12_HEAP_PROPERTIES {
+        Type: D3D12_HEAP_TYPE_UPLOAD,
+        ..Default::default()
+    };
+    let desc = D3D12_RESOURCE_DESC {
+        Dimension: D3D12_RESOURCE_DIMENSION_BUFFER,
+        Width: size,
+        Height: 1,
+        DepthOrArraySize: 1,
+        MipLevels: 1,
+        Format: DXGI_FORMAT_UNKNOWN,
+        SampleDesc: DXGI_SAMPLE_DESC {
+            Count: 1,
+            Quality: 0,
+        },
+        Layout: D3D12_TEXTURE_LAYOUT_ROW_MAJOR,
+        Flags: D3D12_RESOURCE_FLAG_NONE,
+        ..Default::default()
+    };
+    super::create_committed_resource(
+        device,
+        &heap_properties,
+        &desc,
+        D3D12_RESOURCE_STATE_GENERIC_READ,
+    )
+}
+
+/// Maps `buffer` and lets `write` fill its whole contents.
+fn write_upload_buffer(
+    buffer: &ID3D12Resource,
+    write: impl FnOnce(&mut [u8]),
+) -> std::result::Result<(), String> {
+    let size = unsafe { buffer.GetDesc() }.Width as usize;
+    let no_read_range = D3D12_RANGE::default();
+    let mut data: *mut core::ffi::c_void = std::ptr::null_mut();
+    unsafe { buffer.Map(0, Some(&no_read_range), Some(&mut data)) }.map_err(|e| e.to_string())?;
+    write(unsafe { std::slice::from_raw_parts_mut(data as *mut u8, size) });
+    unsafe { buffer.Unmap(0, None) };
+    Ok(())
+}
+
+/// Where a glyph bitmap is placed in the upload buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct UploadPlacement {
+    offset: u64,
+    row_pitch: u32,
+}
+
+/// Lays out bitmaps of the given sizes in one upload buffer, respecting the
+/// D3D12 placement and row pitch alignments. Returns the placements and the buffer size.
+fn upload_placements(sizes: impl Iterator<Item = (u32, u32)>) -> (Vec<UploadPlacement>, u64) {
+    let mut placements = Vec::new();
+    let mut size = 0u64;
+    for (width, height) in sizes {
+        let row_pitch = align(width as u64, D3D12_TEXTURE_DATA_PITCH_ALIGNMENT as u64) as u32;
+        let offset = align(size, D3D12_TEXTURE_DATA_PLACEMENT_ALIGNMENT as u64);
+        size = offset + row_pitch as u64 * height as u64;
+        placements.push(UploadPlacement { offset, row_pitch });
+    }
+    (placements, size)
+}
+
+fn align(value: u64, alignment: u64) -> u64 {
+    value.div_ceil(alignment) * alignment
+}
+
+/// Appends the two triangles of a glyph quad whose top left corner is at `(left, top)`.
+fn push_glyph_quad(
+    vertices: &mut Vec<GlyphVertex>,
+    left: f32,
+    top: f32,
+    region: &AtlasRegion,
+    atlas_size: Size<u32>,
+    color: [f32; 4],
+) {
+    let right = left + region.width as f32;
+    let bottom = top + region.height as f32;
+    let u0 = region.x as f32 / atlas_size.width as f32;
+    let v0 = region.y as f32 / atlas_size.height as f32;
+    let u1 = (region.x + region.width) as f32 / atlas_size.width as f32;
+    let v1 = (region.y + region.height) as f32 / atlas_size.height as f32;
+
+    let top_left = GlyphVertex {
+        position: [left, top],
+        uv: [u0, v0],
+        color,
+    };
+    let top_right = GlyphVertex {
+        position: [right, top],
+        uv: [u1, v0],
+        color,
+    };
+    let bottom_left = GlyphVertex {
+        position: [left, bottom],
+        uv: [u0, v1],
+        color,
+    };
+    let bottom_right = GlyphVertex {
+        position: [right, bottom],
+        uv: [u1, v1],
+        color,
+    };
+    vertices.extend_from_slice(&[
+        top_left,
+        top_right,
+        bottom_left,
+        bottom_left,
+        top_right,
+        bottom_right,
+    ]);
+}
+
+/// Rectangle of an underline or strikethrough `width` by `thickness` pixels, starting at
+/// `(x, y)` and extending left of `x` for right to left text. Its top is snapped to a pixel
+/// and it is at least a pixel thick, so thin lines stay crisp. Layouts are in render target
+/// pixels, so the metrics DirectWrite gives need no DPI scaling.
+fn decoration_rect(
+    x: f32,
+    y: f32,
+    width: f32,
+    thickness: f32,
+    reading_direction: DWRITE_READING_DIRECTION,
+) -> Rect<f32> {
+    let left = if reading_direction == DWRITE_READING_DIRECTION_RIGHT_TO_LEFT {
+        x - width
+    } else {
+        x
+    };
+    Rect {
+        x: left,
+        y: y.round(),
+        width,
+        height: thickness.round().max(1.0),
+    }
+}
+
+/// Appends the two triangles of a quad covering `rect`, every vertex sampling the center of the
+/// single texel `region` so the whole quad gets its coverage.
+fn push_solid_quad(
+    vertices: &mut Vec<GlyphVertex>,
+    rect: &Rect<f32>,
+    region: &AtlasRegion,
+    atlas_size: Size<u32>,
+    color: [f32; 4],
+) {
+    let uv = [
+        (region.x as f32 + 0.5) / atlas_size.width as f32,
+        (region.y as f32 + 0.5) / atlas_size.height as f32,
+    ];
+    let vertex = |x: f32, y: f32| GlyphVertex {
+        position: [x, y],
+        uv,
+        color,
+    };
+    let (left, top) = (rect.x, rect.y);
+    let (right, bottom) = (rect.x + rect.width, rect.y + rect.height);
+    vertices.extend_from_slice(&[
+        vertex(left, top),
+        vertex(right, top),
+        vertex(left, bottom),
+        vertex(left, bottom),
+        vertex(right, top),
+        vertex(right, bottom),
+    ]);
+}
+
+/// Averages the three ClearType subpixel values of each texel into one coverage value.
+fn cleartype_to_coverage(cleartype: &[u8]) -> Vec<u8> {
+    cleartype
+        .chunks_exact(3)
+        .map(|texel| ((texel[0] as u16 + texel[1] as u16 + texel[2] as u16) / 3) as u8)
+        .collect()
+}
 
 // #[implement(IDWriteTextRenderer1)]
 #[implement(IDWriteTextRenderer1)]
 pub(super) struct Direct3D12TextRenderer<'a> {
     renderer: &'a super::Direct3D12Renderer,
     factory: IDWriteFactory,
-    text_format: IDWriteTextFormat,
 }
 
 impl<'a> Direct3D12TextRenderer<'a> {
     pub fn create_for_renderer(renderer: &'a super::Direct3D12Renderer) -> Self {
         let factory: IDWriteFactory =
             unsafe { DWriteCreateFactory(DWRITE_FACTORY_TYPE_SHARED).unwrap() };
-        let text_format = unsafe {
-            factory
-                .CreateTextFormat(
-                    w!("Segoe UI"),
-                    None,
-                    DWRITE_FONT_WEIGHT_REGULAR,
-                    DWRITE_FONT_STYLE_NORMAL,
-                    DWRITE_FONT_STRETCH_NORMAL,
-                    14.0,
-                    w!("en-us"),
-                )
-                .unwrap()
-        };
-        Self {
-            renderer,
-            factory,
-            text_format,
+        Self { renderer, factory }
+    }
+
+    /// Lays out `text` in `rect`, returning the glyph quads to draw and the glyphs
+    /// to upload into the atlas. Glyphs in the byte ranges of `spans` take their color,
+    /// the others `DEFAULT_TEXT_COLOR`.
+    pub fn render_text(
+        self,
+        text: &str,
+        format: &TextFormat,
+        rect: &Rect<f32>,
+        spans: &[(Range<usize>, Color<f32>)],
+    ) -> Result<GlyphBatch> {
+        let text_format = create_text_format(&self.factory, format)?;
+        let utf16_text: Vec<u16> = text.encode_utf16().collect();
+        let (text_layout, visible_height) = create_text_layout(
+            &self.factory,
+            &text_format,
+            &utf16_text,
+            format,
+            rect.width,
+            rect.height,
+        )?;
+        for (range, color) in spans {
+            let effect: IUnknown = ColorDrawingEffect {
+                color: *self.renderer.target_color(color).as_slice(),
+            }
+            .into();
+            unsafe { text_layout.SetDrawingEffect(&effect, utf16_text_range(text, range))? };
         }
+        self.draw_layout(
+            &text_layout,
+            visible_height,
+            format,
+            rect,
+            *self.renderer.target_color(&DEFAULT_TEXT_COLOR).as_slice(),
+        )
     }
 
-    pub fn render_text(self, text: &String, format: &TextFormat, rect: &Rect<f32>) -> Result<()> {
-        let windows_str = HSTRING::from(text);
-        let text_layout = unsafe {
-            self.factory
-                .CreateTextLayout(&windows_str, &self.text_format, rect.width, rect.height)
-                .unwrap()
+    /// Returns the glyph quads to draw the already laid out `layout` in `color`, its layout box
+    /// being `rect`, and the glyphs to upload into the atlas.
+    pub fn render_cached_layout(
+        self,
+        layout: &DirectWriteTextLayout,
+        format: &TextFormat,
+        rect: &Rect<f32>,
+        color: &Color<f32>,
+    ) -> Result<GlyphBatch> {
+        self.draw_layout(
+            &layout.text_layout,
+            layout.visible_height,
+            format,
+            rect,
+            *self.renderer.target_color(color).as_slice(),
+        )
+    }
+
+    /// Draws `text_layout` in `rect` with this text renderer, glyph runs without a drawing
+    /// effect taking `default_color`. `visible_height` is the height of the lines left by
+    /// `format.max_lines`, as returned by `create_text_layout`.
+    fn draw_layout(
+        self,
+        text_layout: &IDWriteTextLayout,
+        visible_height: Option<f32>,
+        format: &TextFormat,
+        rect: &Rect<f32>,
+        default_color: [f32; 4],
+    ) -> Result<GlyphBatch> {
+        // A layout cut short by `max_lines` is only as tall as its visible lines,
+        // so it is aligned within `rect` here rather than by DirectWrite.
+        let free_height = visible_height.map_or(0.0, |height| (rect.height - height).max(0.0));
+        let y = rect.y
+            + match format.vertical_alignment {
+                VerticalAlignment::Top => 0.0,
+                VerticalAlignment::Center => free_height / 2.0,
+                VerticalAlignment::Bottom => free_height,
+            };
+        self.renderer
+            .text_resources
+            .atlas
+            .lock()
+            .unwrap()
+            .begin_batch();
+        let context = TextDrawContext {
+            batch: RefCell::new(GlyphBatch::default()),
+            default_color,
+            pixel_snapping: format.pixel_snapping,
+            // Text is drawn without a transform until the renderer has one.
+            transform: IDENTITY_TRANSFORM,
         };
         let text_renderer: IDWriteTextRenderer1 = self.into();
         unsafe {
-            text_layout
-                .Draw(None, &text_renderer, rect.x, rect.y)
-                .unwrap()
+            text_layout.Draw(
+                Some(&context as *const TextDrawContext as *const core::ffi::c_void),
+                &text_renderer,
+                rect.x,
+                y,
+            )?
         };
-        Ok(())
+        Ok(context.batch.into_inner())
+    }
+
+    /// Appends a solid quad covering `rect`, for an underline or strikethrough.
+    fn push_decoration(&self, context: &TextDrawContext, rect: &Rect<f32>, color: [f32; 4]) {
+        let text_resources = &self.renderer.text_resources;
+        let mut atlas = text_resources.atlas.lock().unwrap();
+        let mut batch = context.batch.borrow_mut();
+        let region = match atlas.get(&SOLID_GLYPH_KEY) {
+            Some((region, _)) => region,
+            None => {
+                let Some(region) = atlas.insert(SOLID_GLYPH_KEY, 1, 1, (0, 0)) else {
+                    return;
+                };
+                batch.uploads.push(GlyphUpload {
+                    region,
+                    coverage: vec![u8::MAX],
+                });
+                region
+            }
+        };
+        let atlas_size = Size {
+            width: atlas.width(),
+            height: atlas.height(),
+        };
+        push_solid_quad(&mut batch.vertices, rect, &region, atlas_size, color);
+    }
+
+    /// Rasterizes one glyph of `glyphrun` with DirectWrite, returning the offset
+    /// of its bitmap from the glyph origin, its size and its coverage.
+    fn rasterize_glyph(
+        &self,
+        glyphrun: &DWRITE_GLYPH_RUN,
+        glyph_index: u16,
+        measuringmode: DWRITE_MEASURING_MODE,
+    ) -> Result<(GlyphOffset, u32, u32, Vec<u8>)> {
+        let advance = 0.0f32;
+        let mut single_glyph_run = unsafe { std::ptr::read(glyphrun) };
+        single_glyph_run.glyphCount = 1;
+        single_glyph_run.glyphIndices = &glyph_index;
+        single_glyph_run.glyphAdvances = &advance;
+        single_glyph_run.glyphOffsets = std::ptr::null();
+
+        let analysis = unsafe {
+            self.factory.CreateGlyphRunAnalysis(
+                &single_glyph_run,
+                1.0,
+                None,
+                DWRITE_RENDERING_MODE_NATURAL,
+                measuringmode,
+                0.0,
+                0.0,
+            )?
+        };
+        let bounds = unsafe { analysis.GetAlphaTextureBounds(DWRITE_TEXTURE_CLEARTYPE_3x1)? };
+        let width = (bounds.right - bounds.left).max(0) as u32;
+        let height = (bounds.bottom - bounds.top).max(0) as u32;
+        if width == 0 || height == 0 {
+            return Ok(((0, 0), 0, 0, Vec::new()));
+        }
+
+        let mut cleartype = vec![0u8; width as usize * height as usize * 3];
+        unsafe {
+            analysis.CreateAlphaTexture(DWRITE_TEXTURE_CLEARTYPE_3x1, &bounds, &mut cleartype)?
+        };
+        Ok((
+            (bounds.left, bounds.top),
+            width,
+            height,
+            cleartype_to_coverage(&cleartype),
+        ))
+    }
+}
+
+/// Font loaded from memory by `load_font`, kept in a `FontHandle`.
+pub(super) struct DirectWriteFont {
+    /// Collection holding the font, where its family is looked up by name.
+    collection: IDWriteFontCollection1,
+    /// Null terminated name of the first family of the font file.
+    family_name: Vec<u16>,
+    /// Loader of the font file. Declared last so it is unregistered after the collection
+    /// using it was released.
+    _loader: RegisteredFontLoader,
+}
+
+/// In-memory font file loader registered with a DirectWrite factory, unregistered when dropped.
+struct RegisteredFontLoader {
+    factory: IDWriteFactory5,
+    loader: IDWriteInMemoryFontFileLoader,
+}
+
+impl RegisteredFontLoader {
+    fn register(factory: &IDWriteFactory5) -> Result<Self> {
+        let loader = unsafe { factory.CreateInMemoryFontFileLoader()? };
+        unsafe { factory.RegisterFontFileLoader(&loader)? };
+        Ok(Self {
+            factory: factory.clone(),
+            loader,
+        })
+    }
+}
+
+impl Drop for RegisteredFontLoader {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = self.factory.UnregisterFontFileLoader(&self.loader);
+        }
+    }
+}
+
+/// Loads the font file `data` into a font collection of its own. DirectWrite keeps a copy of
+/// the data, so it doesn't need to outlive the call.
+pub(super) fn load_font(data: &[u8]) -> Result<DirectWriteFont> {
+    let factory: IDWriteFactory5 = unsafe { DWriteCreateFactory(DWRITE_FACTORY_TYPE_SHARED)? };
+    // Unregistered when dropped, should any of the steps below fail.
+    let loader = RegisteredFontLoader::register(&factory)?;
+    let file = unsafe {
+        loader.loader.CreateInMemoryFontFileReference(
+            &factory,
+            data.as_ptr() as *const core::ffi::c_void,
+            data.len() as u32,
+            None,
+        )?
+    };
+    let mut is_supported = FALSE;
+    let mut file_type = DWRITE_FONT_FILE_TYPE_UNKNOWN;
+    let mut face_count = 0;
+    unsafe { file.Analyze(&mut is_supported, &mut file_type, None, &mut face_count)? };
+    if !is_supported.as_bool() {
+        return Err(Error::new(
+            DWRITE_E_FILEFORMAT,
+            "Font data is not a supported font.",
+        ));
+    }
+
+    let collection = unsafe {
+        let builder = factory.CreateFontSetBuilder()?;
+        builder.AddFontFile(&file)?;
+        factory.CreateFontCollectionFromFontSet(&builder.CreateFontSet()?)?
+    };
+    let names = unsafe { collection.GetFontFamily(0)?.GetFamilyNames()? };
+    let mut family_name = vec![0; unsafe { names.GetStringLength(0)? } as usize + 1];
+    unsafe { names.GetString(0, &mut family_name)? };
+    Ok(DirectWriteFont {
+        collection,
+        family_name,
+        _loader: loader,
+    })
+}
+
+/// Creates the DirectWrite text format used to lay out text with the font of `format`. Fonts
+/// not loaded by this renderer are replaced by the default font.
+fn create_text_format(factory: &IDWriteFactory, format: &TextFormat) -> Result<IDWriteTextFormat> {
+    let font = format
+        .font
+        .as_ref()
+        .and_then(|font| font.downcast_ref::<DirectWriteFont>());
+    let (family_name, collection) = match font {
+        Some(font) => (
+            PCWSTR(font.family_name.as_ptr()),
+            Some(&*font.collection as &IDWriteFontCollection),
+        ),
+        None => (w!("Segoe UI"), None),
+    };
+    unsafe {
+        factory.CreateTextFormat(
+            family_name,
+            collection,
+            DWRITE_FONT_WEIGHT_REGULAR,
+            DWRITE_FONT_STYLE_NORMAL,
+            DWRITE_FONT_STRETCH_NORMAL,
+            14.0,
+            w!("en-us"),
+        )
+    }
+}
+
+/// Layout of a `TextLayout` created by the Direct3D12 renderer, laid out again by DirectWrite
+/// only when its text changes.
+pub(super) struct DirectWriteTextLayout {
+    factory: IDWriteFactory,
+    text_format: IDWriteTextFormat,
+    text_layout: IDWriteTextLayout,
+    /// Height of the lines left by `TextFormat::max_lines`, as returned by `create_text_layout`.
+    visible_height: Option<f32>,
+    /// UTF-16 code units of the text, reused when it changes.
+    utf16_text: Vec<u16>,
+}
+
+impl DirectWriteTextLayout {
+    pub fn create(text: &str, format: &TextFormat, max_size: Size<f32>) -> Result<Self> {
+        let factory: IDWriteFactory = unsafe { DWriteCreateFactory(DWRITE_FACTORY_TYPE_SHARED)? };
+        let text_format = create_text_format(&factory, format)?;
+        let utf16_text: Vec<u16> = text.encode_utf16().collect();
+        let (text_layout, visible_height) = create_text_layout(
+            &factory,
+            &text_format,
+            &utf16_text,
+            format,
+            max_size.width,
+            max_size.height,
+        )?;
+        Ok(Self {
+            factory,
+            text_format,
+            text_layout,
+            visible_height,
+            utf16_text,
+        })
+    }
+}
+
+impl TextLayoutBackend for DirectWriteTextLayout {
+    /// Creates a new DirectWrite layout, which can't change its text, reusing the text format.
+    fn update_text(&mut self, text: &str, format: &TextFormat, max_size: Size<f32>) {
+        self.utf16_text.clear();
+        self.utf16_text.extend(text.encode_utf16());
+        let (text_layout, visible_height) = create_text_layout(
+            &self.factory,
+            &self.text_format,
+            &self.utf16_text,
+            format,
+            max_size.width,
+            max_size.height,
+        )
+        .expect("Failed to lay out text.");
+        self.text_layout = text_layout;
+        self.visible_height = visible_height;
     }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+/// Creates the layout of the UTF-16 `text` in a `width` by `height` box, applying the alignment,
+/// wrapping, line spacing and line limit of `format`. When `format.max_lines` cuts the
+/// text short, also returns the height of the visible lines.
+fn create_text_layout(
+    factory: &IDWriteFactory,
+    text_format: &IDWriteTextFormat,
+    text: &[u16],
+    format: &TextFormat,
+    width: f32,
+    height: f32,
+) -> Result<(IDWriteTextLayout, Option<f32>)> {
+    let text_layout = unsafe { factory.CreateTextLayout(&text, text_format, width, height)? };
+    unsafe {
+        text_layout.SetTextAlignment(match format.horizontal_alignment {
+            HorizontalAlignment::Left => DWRITE_TEXT_ALIGNMENT_LEADING,
+            HorizontalAlignment::Center => DWRITE_TEXT_ALIGNMENT_CENTER,
+            HorizontalAlignment::Right => DWRITE_TEXT_ALIGNMENT_TRAILING,
+        })?;
+        text_layout.SetParagraphAlignment(match format.vertical_alignment {
+            VerticalAlignment::Top => DWRITE_PARAGRAPH_ALIGNMENT_NEAR,
+            VerticalAlignment::Center => DWRITE_PARAGRAPH_ALIGNMENT_CENTER,
+            VerticalAlignment::Bottom => DWRITE_PARAGRAPH_ALIGNMENT_FAR,
+        })?;
+        text_layout.SetWordWrapping(match format.wrapping {
+            TextWrapping::None => DWRITE_WORD_WRAPPING_NO_WRAP,
+            TextWrapping::Word => DWRITE_WORD_WRAPPING_EMERGENCY_BREAK,
+            TextWrapping::Character => DWRITE_WORD_WRAPPING_CHARACTER,
+        })?;
+        // Proportional spacing scales the default line height and baseline of the font.
+        text_layout.SetLineSpacing(
+            DWRITE_LINE_SPACING_METHOD_PROPORTIONAL,
+            format.line_spacing,
+            format.line_spacing,
+        )?;
+        let whole_text = DWRITE_TEXT_RANGE {
+            startPosition: 0,
+            length: text.len() as u32,
+        };
+        text_layout.SetUnderline(format.underline, whole_text)?;
+        text_layout.SetStrikethrough(format.strikethrough, whole_text)?;
+    }
+
+    let Some(max_lines) = format.max_lines else {
+        return Ok((text_layout, None));
+    };
+    let mut metrics = DWRITE_TEXT_METRICS::default();
+    unsafe { text_layout.GetMetrics(&mut metrics)? };
+    if metrics.lineCount <= max_lines {
+        return Ok((text_layout, None));
+    }
+
+    let mut line_metrics = vec![DWRITE_LINE_METRICS::default(); metrics.lineCount as usize];
+    let mut line_count = 0;
+    unsafe { text_layout.GetLineMetrics(Some(&mut line_metrics), &mut line_count)? };
+    let visible_height: f32 = line_metrics[..max_lines as usize]
+        .iter()
+        .map(|line| line.height)
+        .sum();
+    // Lines past the maximum height are trimmed, and the last visible line ends with
+    // an ellipsis.
+    unsafe {
+        let trimming_sign = factory.CreateEllipsisTrimmingSign(text_format)?;
+        text_layout.SetTrimming(
+            &DWRITE_TRIMMING {
+                granularity: DWRITE_TRIMMING_GRANULARITY_CHARACTER,
+                delimiter: 0,
+                delimiterCount: 0,
+            },
+            &trimming_sign,
+        )?;
+        text_layout.SetMaxHeight(visible_height.min(height))?;
+    }
+    Ok((text_layout, Some(visible_height)))
+}
+
+/// Measures `text` with DirectWrite, which doesn't need a render target.
+pub(super) fn measure_text(text: &str, format: &TextFormat, max_width: f32) -> Result<TextMetrics> {
+    if text.is_empty() {
+        return Ok(TextMetrics::default());
+    }
+
+    let factory: IDWriteFactory = unsafe { DWriteCreateFactory(DWRITE_FACTORY_TYPE_SHARED)? };
+    let text_format = create_text_format(&factory, format)?;
+    let utf16_text: Vec<u16> = text.encode_utf16().collect();
+    let (text_layout, visible_height) = create_text_layout(
+        &factory,
+        &text_format,
+        &utf16_text,
+        format,
+        max_width,
+        f32::MAX,
+    )?;
+
+    let mut metrics = DWRITE_TEXT_METRICS::default();
+    unsafe { text_layout.GetMetrics(&mut metrics)? };
+    let (height, line_count) = match visible_height {
+        Some(height) => (height, format.max_lines.unwrap_or(metrics.lineCount)),
+        None => (metrics.height, metrics.lineCount),
+    };
+    Ok(TextMetrics {
+        width: metrics.width,
+        width_including_trailing_whitespace: metrics.widthIncludingTrailingWhitespace,
+        height,
+        line_count,
+    })
 }
 
 impl<'a> IDWriteTextRenderer_Impl for Direct3D12TextRenderer_Impl<'a> {
@@ -170,59 +1040,106 @@ impl<'a> IDWriteTextRenderer_Impl for Direct3D12TextRenderer_Impl<'a> {
 impl<'a> IDWriteTextRenderer1_Impl for Direct3D12TextRenderer_Impl<'a> {
     fn DrawGlyphRun(
         &self,
-        _clientdrawingcontext: *const core::ffi::c_void,
+        clientdrawingcontext: *const core::ffi::c_void,
         baselineoriginx: f32,
         baselineoriginy: f32,
         orientationangle: DWRITE_GLYPH_ORIENTATION_ANGLE,
         measuringmode: DWRITE_MEASURING_MODE,
         glyphrun: *const DWRITE_GLYPH_RUN,
-        glyphrundescription: *const DWRITE_GLYPH_RUN_DESCRIPTION,
+        _glyphrundescription: *const DWRITE_GLYPH_RUN_DESCRIPTION,
         clientdrawingeffect: Ref<IUnknown>,
     ) -> Result<()> {
         if orientationangle != DWRITE_GLYPH_ORIENTATION_ANGLE_0_DEGREES {
             return Err(Error::from_hresult(E_NOTIMPL));
         }
-        let glyphrun = unsafe { glyphrun.read() };
+        let context = unsafe { (clientdrawingcontext as *const TextDrawContext).as_ref() };
+        let glyphrun = unsafe { glyphrun.as_ref() };
+        let (context, glyphrun) = match (context, glyphrun) {
+            (Some(c), Some(g)) => (c, g),
+            _ => return Err(Error::from_hresult(E_POINTER)),
+        };
+        let color = drawing_effect_color(clientdrawingeffect.as_ref(), context.default_color);
         let fontface = match glyphrun.fontFace.as_ref() {
             Some(f) => f,
             None => return Err(Error::from_hresult(E_POINTER)),
         };
 
-        let mut glyphmetrics: [DWRITE_GLYPH_METRICS; GLYPH_METRIC_STEP_SIZE] =
-            [Default::default(); GLYPH_METRIC_STEP_SIZE];
-        let glyph_count = glyphrun.glyphCount;
-        let mut offset_x = baselineoriginx;
-        let offset_y = baselineoriginy;
-        while glyph_count > 0 {
-            let step_glyph_count = GLYPH_METRIC_STEP_SIZE.min(glyph_count as usize);
-            unsafe {
-                fontface.GetDesignGlyphMetrics(
-                    glyphrun.glyphIndices,
-                    step_glyph_count as u32,
-                    glyphmetrics.as_mut_ptr(),
-                    glyphrun.isSideways.as_bool(),
-                )?;
+        let glyph_count = glyphrun.glyphCount as usize;
+        if glyph_count == 0 {
+            return Ok(());
+        }
+        let indices = unsafe { std::slice::from_raw_parts(glyphrun.glyphIndices, glyph_count) };
+        let advances = (!glyphrun.glyphAdvances.is_null())
+            .then(|| unsafe { std::slice::from_raw_parts(glyphrun.glyphAdvances, glyph_count) });
+        let offsets = (!glyphrun.glyphOffsets.is_null())
+            .then(|| unsafe { std::slice::from_raw_parts(glyphrun.glyphOffsets, glyph_count) });
+        // Odd bidi levels are laid out right to left, from the baseline origin.
+        let direction = if glyphrun.bidiLevel % 2 == 1 {
+            -1.0
+        } else {
+            1.0
+        };
+
+        let text_resources = &self.renderer.text_resources;
+        let mut atlas = text_resources.atlas.lock().unwrap();
+        let atlas_size = Size {
+            width: atlas.width(),
+            height: atlas.height(),
+        };
+        let mut batch = context.batch.borrow_mut();
+        let mut pen_x = baselineoriginx;
+        for (i, &glyph_index) in indices.iter().enumerate() {
+            let advance = advances.map_or(0.0, |a| a[i]);
+            let mut x = if direction < 0.0 {
+                pen_x - advance
+            } else {
+                pen_x
+            };
+            let mut y = baselineoriginy;
+            if let Some(offsets) = offsets {
+                x += direction * offsets[i].advanceOffset;
+                y -= offsets[i].ascenderOffset;
             }
+            pen_x += direction * advance;
 
-            for metric in glyphmetrics[0..step_glyph_count].iter() {
-                let rect = Rect::<f32> {
-                    x: offset_x + metric.leftSideBearing as f32,
-                    y: offset_y + metric.verticalOriginY as f32 + metric.topSideBearing as f32,
-                    width: (metric.advanceWidth as i32
-                        - metric.leftSideBearing
-                        - metric.rightSideBearing) as f32,
-                    height: (metric.advanceHeight as i32
-                        - metric.topSideBearing
-                        - metric.bottomSideBearing) as f32,
-                };
-                let color: Color<f32> = Color {
-                    r: 0.5,
-                    g: 0.5,
-                    b: 0.5,
-                    a: 1.0,
-                };
-                offset_x += metric.advanceWidth as f32;
+            let key = (
+                fontface.as_raw() as usize,
+                glyphrun.fontEmSize.to_bits(),
+                glyph_index,
+            );
+            let (region, offset) = match atlas.get(&key) {
+                Some((region, offset)) => (region, *offset),
+                None => {
+                    let (offset, width, height, coverage) =
+                        self.rasterize_glyph(glyphrun, glyph_index, measuringmode)?;
+                    // Glyphs that don't fit while the atlas is full of this text are skipped.
+                    let Some(region) = atlas.insert(key, width, height, offset) else {
+                        continue;
+                    };
+                    text_resources
+                        .font_faces
+                        .lock()
+                        .unwrap()
+                        .entry(key.0)
+                        .or_insert_with(|| fontface.clone());
+                    if !coverage.is_empty() {
+                        batch.uploads.push(GlyphUpload { region, coverage });
+                    }
+                    (region, offset)
+                }
+            };
+            if region.width == 0 || region.height == 0 {
+                continue;
             }
+
+            push_glyph_quad(
+                &mut batch.vertices,
+                x.round() + offset.0 as f32,
+                y.round() + offset.1 as f32,
+                &region,
+                atlas_size,
+                color,
+            );
         }
 
         Ok(())
@@ -230,75 +1147,115 @@ impl<'a> IDWriteTextRenderer1_Impl for Direct3D12TextRenderer_Impl<'a> {
 
     fn DrawUnderline(
         &self,
-        _clientdrawingcontext: *const core::ffi::c_void,
+        clientdrawingcontext: *const core::ffi::c_void,
         baselineoriginx: f32,
         baselineoriginy: f32,
         orientationangle: DWRITE_GLYPH_ORIENTATION_ANGLE,
         underline: *const DWRITE_UNDERLINE,
         clientdrawingeffect: Ref<IUnknown>,
     ) -> Result<()> {
-        todo!()
+        if orientationangle != DWRITE_GLYPH_ORIENTATION_ANGLE_0_DEGREES {
+            return Err(Error::from_hresult(E_NOTIMPL));
+        }
+        let context = unsafe { (clientdrawingcontext as *const TextDrawContext).as_ref() };
+        let underline = unsafe { underline.as_ref() };
+        let (context, underline) = match (context, underline) {
+            (Some(c), Some(u)) => (c, u),
+            _ => return Err(Error::from_hresult(E_POINTER)),
+        };
+        // The offset is from the baseline, positive below it.
+        let rect = decoration_rect(
+            baselineoriginx,
+            baselineoriginy + underline.offset,
+            underline.width,
+            underline.thickness,
+            underline.readingDirection,
+        );
+        let color = drawing_effect_color(clientdrawingeffect.as_ref(), context.default_color);
+        self.push_decoration(context, &rect, color);
+        Ok(())
     }
 
     fn DrawStrikethrough(
         &self,
-        _clientdrawingcontext: *const core::ffi::c_void,
+        clientdrawingcontext: *const core::ffi::c_void,
         baselineoriginx: f32,
         baselineoriginy: f32,
         orientationangle: DWRITE_GLYPH_ORIENTATION_ANGLE,
         strikethrough: *const DWRITE_STRIKETHROUGH,
         clientdrawingeffect: Ref<IUnknown>,
     ) -> Result<()> {
-        todo!()
+        if orientationangle != DWRITE_GLYPH_ORIENTATION_ANGLE_0_DEGREES {
+            return Err(Error::from_hresult(E_NOTIMPL));
+        }
+        let context = unsafe { (clientdrawingcontext as *const TextDrawContext).as_ref() };
+        let strikethrough = unsafe { strikethrough.as_ref() };
+        let (context, strikethrough) = match (context, strikethrough) {
+            (Some(c), Some(s)) => (c, s),
+            _ => return Err(Error::from_hresult(E_POINTER)),
+        };
+        // The offset is from the baseline, negative above it.
+        let rect = decoration_rect(
+            baselineoriginx,
+            baselineoriginy + strikethrough.offset,
+            strikethrough.width,
+            strikethrough.thickness,
+            strikethrough.readingDirection,
+        );
+        let color = drawing_effect_color(clientdrawingeffect.as_ref(), context.default_color);
+        self.push_decoration(context, &rect, color);
+        Ok(())
     }
 
+    /// Inline objects can't be added to the layouts drawn here, so none are expected.
     fn DrawInlineObject(
         &self,
         _clientdrawingcontext: *const core::ffi::c_void,
-        originx: f32,
-        originy: f32,
-        orientationangle: DWRITE_GLYPH_ORIENTATION_ANGLE,
-        inlineobject: Ref<IDWriteInlineObject>,
-        issideways: BOOL,
-        isrighttoleft: BOOL,
-        clientdrawingeffect: Ref<IUnknown>,
+        _originx: f32,
+        _originy: f32,
+        _orientationangle: DWRITE_GLYPH_ORIENTATION_ANGLE,
+        _inlineobject: Ref<IDWriteInlineObject>,
+        _issideways: BOOL,
+        _isrighttoleft: BOOL,
+        _clientdrawingeffect: Ref<IUnknown>,
     ) -> Result<()> {
-        todo!()
+        Err(Error::from_hresult(E_NOTIMPL))
     }
 }
 
 impl<'a> IDWritePixelSnapping_Impl for Direct3D12TextRenderer_Impl<'a> {
     fn IsPixelSnappingDisabled(
         &self,
-        _clientdrawingcontext: *const core::ffi::c_void,
+        clientdrawingcontext: *const core::ffi::c_void,
     ) -> Result<BOOL> {
-        Ok(TRUE)
+        let context = unsafe { (clientdrawingcontext as *const TextDrawContext).as_ref() };
+        match context {
+            Some(context) => Ok((!context.pixel_snapping).into()),
+            None => Err(Error::from_hresult(E_POINTER)),
+        }
     }
 
     fn GetCurrentTransform(
         &self,
-        _clientdrawingcontext: *const core::ffi::c_void,
+        clientdrawingcontext: *const core::ffi::c_void,
         transform: *mut DWRITE_MATRIX,
     ) -> Result<()> {
+        let context = unsafe { (clientdrawingcontext as *const TextDrawContext).as_ref() };
         let transform = unsafe { transform.as_mut() };
-        let transform = match transform {
-            Some(t) => t,
-            None => return Err(Error::from_hresult(E_POINTER)),
-        };
-        transform.dx = 0.0;
-        transform.dy = 0.0;
-        transform.m11 = 1.0;
-        transform.m12 = 0.0;
-        transform.m21 = 0.0;
-        transform.m22 = 1.0;
-        Ok(())
+        match (context, transform) {
+            (Some(context), Some(transform)) => {
+                *transform = context.transform;
+                Ok(())
+            }
+            _ => Err(Error::from_hresult(E_POINTER)),
+        }
     }
 
+    /// Returns the DPI scale cached by the renderer rather than querying the window of the swap
+    /// chain, which swap chains not created for an HWND don't have.
     fn GetPixelsPerDip(&self, _clientdrawingcontext: *const core::ffi::c_void) -> Result<f32> {
         // ref: https://learn.microsoft.com/en-us/windows/win32/learnwin32/dpi-and-device-independent-pixels
-        let hwnd = unsafe { self.renderer.swap_chain.GetHwnd()? };
-        let dpi = unsafe { GetDpiForWindow(hwnd) };
-        Ok(dpi as f32 / USER_DEFAULT_SCREEN_DPI as f32)
+        Ok(self.renderer.dpi_scale.get())
     }
 }
 
@@ -349,3 +1306,284 @@ impl<'a> IDWritePixelSnapping_Impl for Direct3D12TextRenderer_Impl<'a> {
 
 //     const INNER_OFFSET_IN_POINTERS: usize = 0usize;
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upload_placements_are_aligned() {
+        let (placements, size) = upload_placements([(10, 3), (300, 2), (1, 1)].into_iter());
+        assert_eq!(
+            placements,
+            vec![
+                UploadPlacement {
+                    offset: 0,
+                    row_pitch: 256
+                },
+                UploadPlacement {
+                    offset: 1024,
+                    row_pitch: 512
+                },
+                UploadPlacement {
+                    offset: 2048,
+                    row_pitch: 256
+                },
+            ]
+        );
+        assert_eq!(size, 2048 + 256);
+    }
+
+    #[test]
+    fn test_push_glyph_quad() {
+        let mut vertices = Vec::new();
+        let region = AtlasRegion {
+            x: 4,
+            y: 8,
+            width: 4,
+            height: 8,
+        };
+        push_glyph_quad(
+            &mut vertices,
+            10.0,
+            20.0,
+            &region,
+            Size {
+                width: 16,
+                height: 32,
+            },
+            [1.0, 0.5, 0.0, 1.0],
+        );
+        assert_eq!(vertices.len(), 6);
+        assert_eq!(
+            vertices[0],
+            GlyphVertex {
+                position: [10.0, 20.0],
+                uv: [0.25, 0.25],
+                color: [1.0, 0.5, 0.0, 1.0],
+            }
+        );
+        assert_eq!(
+            vertices[5],
+            GlyphVertex {
+                position: [14.0, 28.0],
+                uv: [0.5, 0.5],
+                color: [1.0, 0.5, 0.0, 1.0],
+            }
+        );
+    }
+
+    #[test]
+    fn test_push_solid_quad() {
+        let mut vertices = Vec::new();
+        let region = AtlasRegion {
+            x: 2,
+            y: 4,
+            width: 1,
+            height: 1,
+        };
+        let rect = Rect {
+            x: 10.0,
+            y: 20.0,
+            width: 30.0,
+            height: 2.0,
+        };
+        let size = Size {
+            width: 16,
+            height: 32,
+        };
+        push_solid_quad(&mut vertices, &rect, &region, size, [1.0; 4]);
+        assert_eq!(vertices.len(), 6);
+        assert_eq!(vertices[0].position, [10.0, 20.0]);
+        assert_eq!(vertices[5].position, [40.0, 22.0]);
+        // Every vertex samples the center of the texel.
+        assert!(vertices.iter().all(|v| v.uv == [2.5 / 16.0, 4.5 / 32.0]));
+    }
+
+    #[test]
+    fn test_decoration_rect() {
+        let rect = decoration_rect(5.0, 20.4, 30.0, 0.3, DWRITE_READING_DIRECTION_LEFT_TO_RIGHT);
+        assert_eq!(
+            rect,
+            Rect {
+                x: 5.0,
+                y: 20.0,
+                width: 30.0,
+                height: 1.0,
+            }
+        );
+        let rect = decoration_rect(
+            40.0,
+            20.6,
+            30.0,
+            2.2,
+            DWRITE_READING_DIRECTION_RIGHT_TO_LEFT,
+        );
+        assert_eq!(
+            rect,
+            Rect {
+                x: 10.0,
+                y: 21.0,
+                width: 30.0,
+                height: 2.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_utf16_text_range() {
+        let range = utf16_text_range("ab", &(1..2));
+        assert_eq!((range.startPosition, range.length), (1, 1));
+        // 'é' takes two bytes and one code unit, '𝄞' four bytes and two code units.
+        let text = "é𝄞x";
+        let range = utf16_text_range(text, &(2..6));
+        assert_eq!((range.startPosition, range.length), (1, 2));
+        let range = utf16_text_range(text, &(6..7));
+        assert_eq!((range.startPosition, range.length), (3, 1));
+    }
+
+    #[test]
+    fn test_drawing_effect_color() {
+        let default_color = [1.0, 1.0, 1.0, 1.0];
+        let red = [1.0, 0.0, 0.0, 1.0];
+        let effect: IUnknown = ColorDrawingEffect { color: red }.into();
+        assert_eq!(drawing_effect_color(Some(&effect), default_color), red);
+        assert_eq!(drawing_effect_color(None, default_color), default_color);
+
+        // Effects set by other code fall back to the default color.
+        let factory: IDWriteFactory =
+            unsafe { DWriteCreateFactory(DWRITE_FACTORY_TYPE_SHARED).unwrap() };
+        let other: IUnknown = factory.cast().unwrap();
+        assert_eq!(
+            drawing_effect_color(Some(&other), default_color),
+            default_color
+        );
+    }
+
+    #[test]
+    fn test_render_text_colors_glyph_runs_of_spans() {
+        let options = RendererOptions {
+            adapter: AdapterPreference::Warp,
+            ..Default::default()
+        };
+        let renderer = super::super::Direct3D12Renderer::create_offscreen_with_options(
+            Size::new(128, 32),
+            &options,
+        )
+        .unwrap();
+        let red = Color::new(1.0, 0.0, 0.0, 1.0);
+        let batch = Direct3D12TextRenderer::create_for_renderer(&renderer)
+            .render_text(
+                "ab",
+                &TextFormat::default(),
+                &Rect {
+                    x: 0.0,
+                    y: 0.0,
+                    width: 128.0,
+                    height: 32.0,
+                },
+                &[(1..2, red)],
+            )
+            .unwrap();
+
+        // One quad of six vertices per glyph, in text order.
+        let colors: Vec<[f32; 4]> = batch
+            .vertices
+            .chunks_exact(6)
+            .map(|quad| quad[0].color)
+            .collect();
+        assert_eq!(
+            colors,
+            vec![*DEFAULT_TEXT_COLOR.as_slice(), *red.as_slice()]
+        );
+    }
+
+    #[test]
+    fn test_render_text_draws_underline_and_strikethrough() {
+        let options = RendererOptions {
+            adapter: AdapterPreference::Warp,
+            ..Default::default()
+        };
+        let renderer = super::super::Direct3D12Renderer::create_offscreen_with_options(
+            Size::new(128, 32),
+            &options,
+        )
+        .unwrap();
+        let rect = Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 128.0,
+            height: 32.0,
+        };
+        let quad_count = |format: &TextFormat| {
+            let batch = Direct3D12TextRenderer::create_for_renderer(&renderer)
+                .render_text("ab", format, &rect, &[])
+                .unwrap();
+            batch.vertices.len() / 6
+        };
+        let plain = quad_count(&TextFormat::default());
+        let underlined = quad_count(&TextFormat {
+            underline: true,
+            ..Default::default()
+        });
+        let both = quad_count(&TextFormat {
+            underline: true,
+            strikethrough: true,
+            ..Default::default()
+        });
+        // One line of text gets one quad per decoration.
+        assert_eq!(underlined, plain + 1);
+        assert_eq!(both, plain + 2);
+    }
+
+    #[test]
+    fn test_pixel_snapping_follows_draw_context() {
+        let options = RendererOptions {
+            adapter: AdapterPreference::Warp,
+            ..Default::default()
+        };
+        let renderer = super::super::Direct3D12Renderer::create_offscreen_with_options(
+            Size::new(16, 16),
+            &options,
+        )
+        .unwrap();
+        let text_renderer: IDWriteTextRenderer1 =
+            Direct3D12TextRenderer::create_for_renderer(&renderer).into();
+        let mut context = TextDrawContext {
+            batch: RefCell::new(GlyphBatch::default()),
+            default_color: [1.0; 4],
+            pixel_snapping: true,
+            transform: DWRITE_MATRIX {
+                dx: 3.0,
+                ..IDENTITY_TRANSFORM
+            },
+        };
+        let pointer = Some(&context as *const TextDrawContext as *const core::ffi::c_void);
+        unsafe {
+            assert_eq!(
+                text_renderer.IsPixelSnappingDisabled(pointer).unwrap(),
+                FALSE
+            );
+            // Offscreen renderers have no window and draw at the default DPI.
+            assert_eq!(text_renderer.GetPixelsPerDip(pointer).unwrap(), 1.0);
+            let mut transform = IDENTITY_TRANSFORM;
+            text_renderer
+                .GetCurrentTransform(pointer, &mut transform)
+                .unwrap();
+            assert_eq!(transform, context.transform);
+        }
+
+        context.pixel_snapping = false;
+        let pointer = Some(&context as *const TextDrawContext as *const core::ffi::c_void);
+        let disabled = unsafe { text_renderer.IsPixelSnappingDisabled(pointer).unwrap() };
+        assert_eq!(disabled, TRUE);
+    }
+
+    #[test]
+    fn test_cleartype_to_coverage() {
+        assert_eq!(
+            cleartype_to_coverage(&[0, 0, 0, 255, 255, 255, 30, 60, 90]),
+            vec![0, 255, 60]
+        );
+    }
+}
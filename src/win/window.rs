@@ -19,51 +19,74 @@
 
 pub use windows::Win32::Foundation::HWND as NativeWindowHandle;
 
+use std::collections::VecDeque;
+use std::ffi::c_void;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
 use windows::{
     core::w,
     Win32::{
-        Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, WPARAM},
-        System::{
-            Com::{CoInitializeEx, COINIT_MULTITHREADED},
-            LibraryLoader::GetModuleHandleW,
+        Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, POINT, RECT, WAIT_FAILED, WPARAM},
+        Graphics::Gdi::{
+            ClientToScreen, GetMonitorInfoW, MonitorFromWindow, MONITORINFO,
+            MONITOR_DEFAULTTONEAREST,
+        },
+        System::{LibraryLoader::GetModuleHandleW, Threading::INFINITE},
+        UI::{
+            HiDpi::{
+                AdjustWindowRectExForDpi, GetDpiForWindow, SetProcessDpiAwarenessContext,
+                DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2,
+            },
+            Input::KeyboardAndMouse::SetCursorPos,
+            WindowsAndMessaging::*,
         },
-        UI::WindowsAndMessaging::*,
     },
 };
 use windows_core::PCWSTR;
 
+use super::com::ensure_window_apartment;
 use crate::{
-    math::Size,
-    window::{NativeWindow, WindowProcessResult},
+    events::window::{ButtonState, KeyEvent, MouseButton, MouseEvent, TextInputEvent},
+    math::{Rect, Size, Vector2},
+    timer::PerformanceCounter,
+    window::{FullscreenMode, NativeWindow, WindowEvent, WindowProcessResult},
 };
 
 const WINDOW_CLASS_NAME: PCWSTR = w!("snake_main_wnd");
 
+/// Number of windows using `WINDOW_CLASS_NAME`, the class is registered while non-zero.
+static WINDOW_CLASS_USERS: Mutex<usize> = Mutex::new(0);
+
 pub struct Win32Window {
     window_handle: HWND,
+    /// State shared with the window procedure, allocated by `Box::into_raw` so its address stays
+    /// valid while the window is moved, and freed by `drop` once the window is destroyed. Both
+    /// sides only reach it through this pointer, see `state`.
+    state: *mut WindowState,
     size: Size<u32>,
+    dpi: u32,
+    fullscreen_mode: FullscreenMode,
+    /// Placement to restore when leaving fullscreen.
+    windowed_placement: Option<WINDOWPLACEMENT>,
+    cursor_visible: bool,
+    /// Screen rectangle the cursor is clipped to, if confined.
+    cursor_clip: Option<RECT>,
 }
 
 impl NativeWindow for Win32Window {
     fn create() -> Self {
         ensure_single_instance();
         unsafe {
-            CoInitializeEx(None, COINIT_MULTITHREADED).unwrap();
+            ensure_window_apartment().expect("Could not initialize COM for the window thread.");
+            // Fails if the awareness was already set (e.g. by a manifest), which is fine.
+            let _ = SetProcessDpiAwarenessContext(DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2);
             let hinstance = GetModuleHandleW(None).unwrap();
             debug_assert!(!hinstance.is_invalid());
 
-            let wndclass = WNDCLASSW {
-                style: CS_DBLCLKS,
-                hInstance: HINSTANCE::from(hinstance),
-                hCursor: LoadCursorW(None, IDC_ARROW).unwrap(),
-                lpszClassName: WINDOW_CLASS_NAME,
-                lpfnWndProc: Some(Self::static_window_procedure),
-                ..Default::default()
-            };
-
-            let atom = RegisterClassW(&wndclass);
-            debug_assert!(atom != 0);
+            acquire_window_class(hinstance.into());
 
+            let state = Box::into_raw(Box::new(WindowState::new()));
             let hwnd = CreateWindowExW(
                 WINDOW_EX_STYLE::default(),
                 WINDOW_CLASS_NAME,
@@ -76,13 +99,26 @@ impl NativeWindow for Win32Window {
                 None,
                 None,
                 Some(hinstance.into()),
-                None,
-            )
-            .expect("Could not create Window for game.");
+                Some(state as *const c_void),
+            );
+            let hwnd = match hwnd {
+                Ok(hwnd) => hwnd,
+                Err(e) => {
+                    // No window procedure kept the pointer, so the state is still ours to free.
+                    drop(Box::from_raw(state));
+                    panic!("Could not create Window for game: {}", e);
+                }
+            };
 
             Self {
                 window_handle: hwnd,
-                size: Size::default(),
+                state,
+                size: client_size(hwnd),
+                dpi: GetDpiForWindow(hwnd),
+                fullscreen_mode: FullscreenMode::Windowed,
+                windowed_placement: None,
+                cursor_visible: true,
+                cursor_clip: None,
             }
         }
     }
@@ -91,33 +127,209 @@ impl NativeWindow for Win32Window {
         self.size
     }
 
+    fn set_size(&mut self, size: Size<u32>) {
+        let hwnd = self.window_handle;
+        let mut rect = RECT {
+            left: 0,
+            top: 0,
+            right: size.width as i32,
+            bottom: size.height as i32,
+        };
+        unsafe {
+            let style = WINDOW_STYLE(GetWindowLongPtrW(hwnd, GWL_STYLE) as u32);
+            let ex_style = WINDOW_EX_STYLE(GetWindowLongPtrW(hwnd, GWL_EXSTYLE) as u32);
+            if AdjustWindowRectExForDpi(&mut rect, style, false, ex_style, self.dpi).is_ok() {
+                let _ = SetWindowPos(
+                    hwnd,
+                    None,
+                    0,
+                    0,
+                    rect.right - rect.left,
+                    rect.bottom - rect.top,
+                    SWP_NOMOVE | SWP_NOZORDER | SWP_NOACTIVATE,
+                );
+            }
+        }
+        self.update_metrics();
+    }
+
+    fn is_focused(&self) -> bool {
+        unsafe { GetForegroundWindow() == self.window_handle }
+    }
+
+    fn is_minimized(&self) -> bool {
+        self.state().minimized
+    }
+
+    fn dpi(&self) -> u32 {
+        self.dpi
+    }
+
+    fn poll_event(&mut self) -> Option<WindowEvent> {
+        self.state_mut().events.pop_front()
+    }
+
+    fn fullscreen_mode(&self) -> FullscreenMode {
+        self.fullscreen_mode
+    }
+
+    fn set_fullscreen(&mut self, mode: FullscreenMode) {
+        if mode == self.fullscreen_mode {
+            return;
+        }
+        let hwnd = self.window_handle;
+        unsafe {
+            let style = GetWindowLongPtrW(hwnd, GWL_STYLE) as u32;
+            match mode {
+                FullscreenMode::Borderless => {
+                    let mut placement = WINDOWPLACEMENT {
+                        length: std::mem::size_of::<WINDOWPLACEMENT>() as u32,
+                        ..Default::default()
+                    };
+                    if GetWindowPlacement(hwnd, &mut placement).is_ok() {
+                        self.windowed_placement = Some(placement);
+                    }
+
+                    let monitor = MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST);
+                    let mut monitor_info = MONITORINFO {
+                        cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+                        ..Default::default()
+                    };
+                    if !GetMonitorInfoW(monitor, &mut monitor_info).as_bool() {
+                        return;
+                    }
+                    let bounds = monitor_info.rcMonitor;
+                    SetWindowLongPtrW(hwnd, GWL_STYLE, (style & !WS_OVERLAPPEDWINDOW.0) as isize);
+                    let _ = SetWindowPos(
+                        hwnd,
+                        Some(HWND_TOP),
+                        bounds.left,
+                        bounds.top,
+                        bounds.right - bounds.left,
+                        bounds.bottom - bounds.top,
+                        SWP_NOOWNERZORDER | SWP_FRAMECHANGED,
+                    );
+                }
+                FullscreenMode::Windowed => {
+                    SetWindowLongPtrW(hwnd, GWL_STYLE, (style | WS_OVERLAPPEDWINDOW.0) as isize);
+                    if let Some(placement) = self.windowed_placement.take() {
+                        let _ = SetWindowPlacement(hwnd, &placement);
+                    }
+                    let _ = SetWindowPos(
+                        hwnd,
+                        None,
+                        0,
+                        0,
+                        0,
+                        0,
+                        SWP_NOMOVE
+                            | SWP_NOSIZE
+                            | SWP_NOZORDER
+                            | SWP_NOOWNERZORDER
+                            | SWP_FRAMECHANGED,
+                    );
+                }
+            }
+        }
+        self.fullscreen_mode = mode;
+        self.update_metrics();
+    }
+
+    fn set_title(&mut self, title: &str) {
+        let title = to_wide_null(title);
+        unsafe {
+            let _ = SetWindowTextW(self.window_handle, PCWSTR(title.as_ptr()));
+        }
+    }
+
+    fn set_cursor_visible(&mut self, visible: bool) {
+        // ShowCursor keeps a display counter, only call it on changes so calls don't stack.
+        if visible != self.cursor_visible {
+            unsafe { ShowCursor(visible) };
+            self.cursor_visible = visible;
+        }
+    }
+
+    fn set_cursor_position(&mut self, position: Vector2<i32>) {
+        let mut point = POINT {
+            x: position.x,
+            y: position.y,
+        };
+        unsafe {
+            if ClientToScreen(self.window_handle, &mut point).as_bool() {
+                let _ = SetCursorPos(point.x, point.y);
+            }
+        }
+    }
+
+    fn confine_cursor(&mut self, confine: bool) {
+        if confine {
+            self.update_cursor_clip();
+        } else if self.cursor_clip.take().is_some() {
+            unsafe {
+                let _ = ClipCursor(None);
+            }
+        }
+    }
+
+    fn set_text_input_enabled(&mut self, enabled: bool) {
+        let state = self.state_mut();
+        state.text_input_enabled = enabled;
+        state.high_surrogate = None;
+    }
+
     fn handle(&self) -> NativeWindowHandle {
         self.window_handle
     }
 
-    fn process_until_end(&mut self) {
+    fn process_until_end(&mut self) -> WindowProcessResult {
         let mut message = MSG::default();
-        unsafe {
-            while GetMessageW(&mut message, None, 0, 0).as_bool() {
-                let _ = TranslateMessage(&message);
-                DispatchMessageW(&message);
+        loop {
+            // GetMessageW returns 0 on WM_QUIT and -1 on failure.
+            match unsafe { GetMessageW(&mut message, None, 0, 0) }.0 {
+                0 => return WindowProcessResult::Exit,
+                -1 => {
+                    return WindowProcessResult::Error(windows_core::Error::from_win32().message())
+                }
+                _ => self.dispatch(&message),
             }
         }
     }
 
     fn process_message_if_available(&mut self) -> WindowProcessResult {
         let mut message = MSG::default();
-        unsafe {
-            if PeekMessageW(&mut message, None, 0, 0, PM_REMOVE).as_bool() {
-                if message.message == WM_QUIT {
-                    WindowProcessResult::Exit
-                } else {
-                    let _ = TranslateMessage(&message);
-                    DispatchMessageW(&message);
-                    WindowProcessResult::Ok
-                }
-            } else {
-                WindowProcessResult::Ok
+        if !unsafe { PeekMessageW(&mut message, None, 0, 0, PM_REMOVE) }.as_bool() {
+            return WindowProcessResult::Idle;
+        }
+        if message.message == WM_QUIT {
+            return WindowProcessResult::Exit;
+        }
+        self.dispatch(&message);
+        WindowProcessResult::Processed
+    }
+
+    /// Waits with `MsgWaitForMultipleObjectsEx`, which also returns for messages already in
+    /// the queue. Messages sent by other threads are handled within the wait, which then goes
+    /// on until a posted message arrives or the timeout expires.
+    fn wait_message(&mut self, timeout: Option<Duration>) -> WindowProcessResult {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        loop {
+            match self.process_message_if_available() {
+                WindowProcessResult::Idle => {}
+                result => return result,
+            }
+            let milliseconds = match deadline {
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(remaining) if !remaining.is_zero() => wait_milliseconds(remaining),
+                    _ => return WindowProcessResult::Idle,
+                },
+                None => INFINITE,
+            };
+            let wait = unsafe {
+                MsgWaitForMultipleObjectsEx(None, milliseconds, QS_ALLINPUT, MWMO_INPUTAVAILABLE)
+            };
+            if wait == WAIT_FAILED {
+                return WindowProcessResult::Error(windows_core::Error::from_win32().message());
             }
         }
     }
@@ -125,18 +337,95 @@ impl NativeWindow for Win32Window {
 
 impl Drop for Win32Window {
     fn drop(&mut self) {
-        // Destroys the window and wait for it to end itself.
+        self.set_cursor_visible(true);
+        self.confine_cursor(false);
+
         unsafe {
-            let _ = DestroyWindow(self.window_handle);
-            let mut message = MSG::default();
-            if PeekMessageW(&mut message, None, 0, 0, PM_NOREMOVE).as_bool() {
-                self.process_until_end();
+            // DestroyWindow sends WM_DESTROY and WM_NCDESTROY before returning, so there is
+            // nothing left to wait for. It fails if the window was already closed.
+            if DestroyWindow(self.window_handle).is_ok() {
+                // Discard the WM_QUIT posted by WM_DESTROY, so loops of other windows on
+                // this thread keep running.
+                let mut message = MSG::default();
+                let _ = PeekMessageW(&mut message, None, WM_QUIT, WM_QUIT, PM_REMOVE);
+            }
+            // WM_NCDESTROY took the state away from the window procedure.
+            drop(Box::from_raw(self.state));
+            if let Ok(hinstance) = GetModuleHandleW(None) {
+                release_window_class(hinstance.into());
             }
         }
     }
 }
 
 impl Win32Window {
+    /// Returns the state shared with the window procedure. The window procedure only runs
+    /// within the Win32 calls that send messages to the window, such as `DispatchMessageW` or
+    /// `SetWindowPos`, so the reference must be dropped before making any of them.
+    fn state(&self) -> &WindowState {
+        unsafe { &*self.state }
+    }
+
+    /// Mutable access to the state shared with the window procedure, see `state`.
+    fn state_mut(&mut self) -> &mut WindowState {
+        unsafe { &mut *self.state }
+    }
+
+    fn dispatch(&mut self, message: &MSG) {
+        unsafe {
+            // TranslateMessage only reports whether a character message was generated.
+            let _ = TranslateMessage(message);
+            DispatchMessageW(message);
+        }
+        self.update_metrics();
+    }
+
+    /// Picks up DPI and size changes handled by the window procedure, refreshing the stored
+    /// values and queueing a `WindowEvent` for each change.
+    fn update_metrics(&mut self) {
+        let dpi = unsafe { GetDpiForWindow(self.window_handle) };
+        if dpi != 0 && dpi != self.dpi {
+            self.dpi = dpi;
+            let scale_factor = self.scale_factor();
+            self.state_mut()
+                .events
+                .push_back(WindowEvent::DpiChanged(scale_factor));
+        }
+        let size = client_size(self.window_handle);
+        if size != self.size {
+            self.size = size;
+            self.state_mut()
+                .events
+                .push_back(WindowEvent::Resized(size));
+        }
+        if self.cursor_clip.is_some() {
+            self.update_cursor_clip();
+        }
+    }
+
+    /// Clips the cursor to the current client area, if it moved since the last clip.
+    fn update_cursor_clip(&mut self) {
+        let mut client = RECT::default();
+        let mut origin = POINT::default();
+        unsafe {
+            if GetClientRect(self.window_handle, &mut client).is_err()
+                || !ClientToScreen(self.window_handle, &mut origin).as_bool()
+            {
+                return;
+            }
+        }
+        let clip = client_rect_to_screen(client, origin);
+        if self.cursor_clip != Some(clip) {
+            unsafe {
+                let _ = ClipCursor(Some(&clip as *const RECT));
+            }
+            self.cursor_clip = Some(clip);
+        }
+    }
+
+    /// Forwards messages to the `WindowState` of the window, stored in `GWLP_USERDATA`.
+    /// Messages without a state, or not handled by it, go to `DefWindowProcW`. WM_DPICHANGED is
+    /// handled here, as the `SetWindowPos` it calls sends messages to this procedure again.
     extern "system" fn static_window_procedure(
         window: HWND,
         message: u32,
@@ -144,14 +433,299 @@ impl Win32Window {
         lparam: LPARAM,
     ) -> LRESULT {
         unsafe {
-            match message {
-                WM_DESTROY => {
-                    PostQuitMessage(0);
-                    LRESULT(0)
+            if message == WM_DPICHANGED {
+                // lparam points to the size and position suggested for the new DPI.
+                let suggested = &*(lparam.0 as *const RECT);
+                let _ = SetWindowPos(
+                    window,
+                    None,
+                    suggested.left,
+                    suggested.top,
+                    suggested.right - suggested.left,
+                    suggested.bottom - suggested.top,
+                    SWP_NOZORDER | SWP_NOACTIVATE,
+                );
+                return LRESULT(0);
+            }
+            let previous = GetWindowLongPtrW(window, GWLP_USERDATA);
+            let mut user_data = previous;
+            let state = track_window_state(&mut user_data, message, lparam);
+            if user_data != previous {
+                SetWindowLongPtrW(window, GWLP_USERDATA, user_data);
+            }
+            state
+                .and_then(|state| (*state).handle_message(message, wparam, lparam))
+                .unwrap_or_else(|| DefWindowProcW(window, message, wparam, lparam))
+        }
+    }
+}
+
+/// Per window state reachable from the window procedure.
+struct WindowState {
+    events: VecDeque<WindowEvent>,
+    /// Whether WM_CHAR and WM_UNICHAR messages queue `WindowEvent::Text`.
+    text_input_enabled: bool,
+    /// First half of a surrogate pair, sent in its own WM_CHAR before the second half.
+    high_surrogate: Option<u16>,
+    /// Whether the last WM_SIZE minimized the window.
+    minimized: bool,
+}
+
+impl WindowState {
+    fn new() -> Self {
+        Self {
+            events: VecDeque::new(),
+            text_input_enabled: false,
+            high_surrogate: None,
+            minimized: false,
+        }
+    }
+
+    /// Handles a message sent to the window, returning `None` to fall back to `DefWindowProcW`.
+    /// Messages that translate to a `WindowEvent` queue it and still get the default processing.
+    /// Nothing here may send messages to the window, which would reach the state while it is
+    /// borrowed.
+    fn handle_message(&mut self, message: u32, wparam: WPARAM, lparam: LPARAM) -> Option<LRESULT> {
+        let timestamp = PerformanceCounter::now();
+        if let Some(event) = translate_message(message, wparam, lparam, timestamp) {
+            self.events.push_back(event);
+        }
+        match message {
+            WM_CHAR => {
+                self.push_utf16_character(wparam.0 as u16, timestamp);
+                Some(LRESULT(0))
+            }
+            WM_UNICHAR => {
+                // Answering TRUE to UNICODE_NOCHAR tells the sender WM_UNICHAR is supported.
+                if wparam.0 as u32 == UNICODE_NOCHAR {
+                    return Some(LRESULT(1));
+                }
+                if let Some(character) = char::from_u32(wparam.0 as u32) {
+                    self.push_character(character, timestamp);
                 }
-                _ => DefWindowProcW(window, message, wparam, lparam),
+                Some(LRESULT(0))
             }
+            WM_DESTROY => {
+                unsafe { PostQuitMessage(0) };
+                Some(LRESULT(0))
+            }
+            WM_SIZE => {
+                match wparam.0 as u32 {
+                    SIZE_MINIMIZED => self.set_minimized(true),
+                    SIZE_RESTORED | SIZE_MAXIMIZED => self.set_minimized(false),
+                    // Sent about other windows being maximized or restored.
+                    _ => {}
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Queues `Minimized` or `Restored` if the window changed between the two.
+    fn set_minimized(&mut self, minimized: bool) {
+        if minimized != self.minimized {
+            self.minimized = minimized;
+            self.events.push_back(if minimized {
+                WindowEvent::Minimized
+            } else {
+                WindowEvent::Restored
+            });
+        }
+    }
+
+    /// Decodes a UTF-16 code unit from WM_CHAR, pairing surrogates sent in separate messages.
+    /// A surrogate without its other half is dropped.
+    fn push_utf16_character(&mut self, unit: u16, timestamp: PerformanceCounter) {
+        match (self.high_surrogate.take(), unit) {
+            (_, 0xD800..=0xDBFF) => self.high_surrogate = Some(unit),
+            (Some(high), 0xDC00..=0xDFFF) => {
+                if let Some(Ok(character)) = char::decode_utf16([high, unit]).next() {
+                    self.push_character(character, timestamp);
+                }
+            }
+            (None, 0xDC00..=0xDFFF) => {}
+            (_, unit) => {
+                if let Some(character) = char::from_u32(unit as u32) {
+                    self.push_character(character, timestamp);
+                }
+            }
+        }
+    }
+
+    /// Queues a typed character if text input is enabled. Control characters are left to the
+    /// key events.
+    fn push_character(&mut self, character: char, timestamp: PerformanceCounter) {
+        if self.text_input_enabled && !character.is_control() {
+            self.events.push_back(WindowEvent::Text(TextInputEvent {
+                character,
+                timestamp,
+            }));
+        }
+    }
+}
+
+/// Translates a window message to the `WindowEvent` it reports, if any, with input events
+/// stamped with `timestamp`. Resizes and DPI changes are picked up by
+/// `Win32Window::update_metrics` instead.
+fn translate_message(
+    message: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+    timestamp: PerformanceCounter,
+) -> Option<WindowEvent> {
+    let key = |state: ButtonState| {
+        WindowEvent::Key(KeyEvent {
+            key: wparam.0 as u16,
+            state,
+            // Bit 30 holds the previous key state, set for auto-repeats.
+            repeat: state == ButtonState::Pressed && lparam.0 & (1 << 30) != 0,
+            timestamp,
+        })
+    };
+    let button = |button: MouseButton, state: ButtonState| {
+        WindowEvent::Mouse(MouseEvent::Button {
+            button,
+            state,
+            position: lparam_position(lparam),
+            timestamp,
+        })
+    };
+    let x_button = || match (wparam.0 >> 16) as u16 {
+        XBUTTON1 => MouseButton::X1,
+        _ => MouseButton::X2,
+    };
+    let event = match message {
+        WM_MOVE => WindowEvent::Moved(lparam_position(lparam)),
+        WM_SETFOCUS => WindowEvent::FocusGained,
+        WM_KILLFOCUS => WindowEvent::FocusLost,
+        WM_CLOSE => WindowEvent::CloseRequested,
+        WM_KEYDOWN | WM_SYSKEYDOWN => key(ButtonState::Pressed),
+        WM_KEYUP | WM_SYSKEYUP => key(ButtonState::Released),
+        WM_MOUSEMOVE => WindowEvent::Mouse(MouseEvent::Moved {
+            position: lparam_position(lparam),
+            timestamp,
+        }),
+        WM_LBUTTONDOWN | WM_LBUTTONDBLCLK => button(MouseButton::Left, ButtonState::Pressed),
+        WM_LBUTTONUP => button(MouseButton::Left, ButtonState::Released),
+        WM_RBUTTONDOWN | WM_RBUTTONDBLCLK => button(MouseButton::Right, ButtonState::Pressed),
+        WM_RBUTTONUP => button(MouseButton::Right, ButtonState::Released),
+        WM_MBUTTONDOWN | WM_MBUTTONDBLCLK => button(MouseButton::Middle, ButtonState::Pressed),
+        WM_MBUTTONUP => button(MouseButton::Middle, ButtonState::Released),
+        WM_XBUTTONDOWN | WM_XBUTTONDBLCLK => button(x_button(), ButtonState::Pressed),
+        WM_XBUTTONUP => button(x_button(), ButtonState::Released),
+        WM_MOUSEWHEEL => {
+            let delta = (wparam.0 >> 16) as u16 as i16;
+            WindowEvent::Mouse(MouseEvent::Wheel {
+                delta: delta as f32 / WHEEL_DELTA as f32,
+                timestamp,
+            })
+        }
+        _ => return None,
+    };
+    Some(event)
+}
+
+/// Milliseconds to wait for `remaining` with `MsgWaitForMultipleObjectsEx`, rounded up so the
+/// wait doesn't end just before the deadline, and kept below `INFINITE`.
+fn wait_milliseconds(remaining: Duration) -> u32 {
+    let milliseconds = remaining.as_nanos().div_ceil(1_000_000);
+    milliseconds.min(INFINITE as u128 - 1) as u32
+}
+
+/// Position packed in an `LPARAM` as two signed 16 bit values, x in the low word.
+/// Coordinates can be negative, e.g. on monitors left of the primary one.
+fn lparam_position(lparam: LPARAM) -> Vector2<i32> {
+    let x = lparam.0 as u16 as i16;
+    let y = (lparam.0 >> 16) as u16 as i16;
+    Vector2::new(x as i32, y as i32)
+}
+
+/// Updates the `GWLP_USERDATA` value of a window for `message` and returns the state the
+/// message belongs to. The state pointer given to `CreateWindowExW` is stored on WM_NCCREATE
+/// and cleared on WM_NCDESTROY, the last message a window receives, which is still forwarded.
+/// Messages sent before WM_NCCREATE, such as WM_GETMINMAXINFO, have no state.
+///
+/// # Safety
+/// For WM_NCCREATE, `lparam` must point to the `CREATESTRUCTW` of the window.
+unsafe fn track_window_state(
+    user_data: &mut isize,
+    message: u32,
+    lparam: LPARAM,
+) -> Option<*mut WindowState> {
+    if message == WM_NCCREATE {
+        let create_struct = &*(lparam.0 as *const CREATESTRUCTW);
+        *user_data = create_struct.lpCreateParams as isize;
+    }
+    let state = *user_data as *mut WindowState;
+    if message == WM_NCDESTROY {
+        *user_data = 0;
+    }
+    if state.is_null() {
+        None
+    } else {
+        Some(state)
+    }
+}
+
+/// Registers the window class if no window is using it yet.
+fn acquire_window_class(hinstance: HINSTANCE) {
+    let mut users = WINDOW_CLASS_USERS.lock().unwrap();
+    if *users == 0 {
+        unsafe {
+            let wndclass = WNDCLASSW {
+                style: CS_DBLCLKS,
+                hInstance: hinstance,
+                hCursor: LoadCursorW(None, IDC_ARROW).unwrap(),
+                lpszClassName: WINDOW_CLASS_NAME,
+                lpfnWndProc: Some(Win32Window::static_window_procedure),
+                ..Default::default()
+            };
+
+            let atom = RegisterClassW(&wndclass);
+            debug_assert!(atom != 0);
+        }
+    }
+    *users += 1;
+}
+
+/// Unregisters the window class once the last window using it is gone,
+/// so that windows can be created again later in the same process.
+fn release_window_class(hinstance: HINSTANCE) {
+    let mut users = WINDOW_CLASS_USERS.lock().unwrap();
+    debug_assert!(*users > 0);
+    *users -= 1;
+    if *users == 0 {
+        unsafe {
+            let _ = UnregisterClassW(WINDOW_CLASS_NAME, Some(hinstance));
+        }
+    }
+}
+
+/// Size of the client area of the window, in physical pixels.
+fn client_size(window: HWND) -> Size<u32> {
+    let mut rect = RECT::default();
+    match unsafe { GetClientRect(window, &mut rect) } {
+        Ok(_) => {
+            let rect = Rect::<i32>::from(rect);
+            Size::new(rect.width as u32, rect.height as u32)
         }
+        Err(_) => Size::default(),
+    }
+}
+
+/// Encodes `text` as a null terminated UTF-16 string, as expected by the wide Win32 functions.
+fn to_wide_null(text: &str) -> Vec<u16> {
+    text.encode_utf16().chain(std::iter::once(0)).collect()
+}
+
+/// Offsets a client rectangle by the screen position of the client area origin.
+fn client_rect_to_screen(client: RECT, origin: POINT) -> RECT {
+    RECT {
+        left: origin.x + client.left,
+        top: origin.y + client.top,
+        right: origin.x + client.right,
+        bottom: origin.y + client.bottom,
     }
 }
 
@@ -162,3 +736,314 @@ fn ensure_single_instance() {
             .unwrap();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::Event;
+    use windows::Win32::UI::Input::KeyboardAndMouse::VK_SPACE;
+
+    #[test]
+    fn test_to_wide_null() {
+        assert_eq!(to_wide_null(""), vec![0]);
+        assert_eq!(to_wide_null("sky"), vec![0x73, 0x6B, 0x79, 0]);
+        assert_eq!(to_wide_null("céu"), vec![0x63, 0xE9, 0x75, 0]);
+        // Characters outside the BMP are encoded as surrogate pairs.
+        assert_eq!(to_wide_null("🚀"), vec![0xD83D, 0xDE80, 0]);
+    }
+
+    #[test]
+    fn test_track_window_state_lifecycle() {
+        let mut state = Box::new(WindowState::new());
+        let state_pointer: *mut WindowState = &mut *state;
+        let create_struct = CREATESTRUCTW {
+            lpCreateParams: state_pointer as *mut c_void,
+            ..Default::default()
+        };
+        let create_lparam = LPARAM(&create_struct as *const CREATESTRUCTW as isize);
+
+        let mut user_data = 0;
+        unsafe {
+            // Sent before WM_NCCREATE, the state is not known yet.
+            assert_eq!(
+                track_window_state(&mut user_data, WM_GETMINMAXINFO, LPARAM(0)),
+                None
+            );
+            assert_eq!(user_data, 0);
+
+            let state = track_window_state(&mut user_data, WM_NCCREATE, create_lparam);
+            assert_eq!(state, Some(state_pointer));
+            assert_eq!(user_data, state_pointer as isize);
+
+            for message in [WM_CREATE, WM_SIZE, WM_DESTROY] {
+                let state = track_window_state(&mut user_data, message, LPARAM(0));
+                assert_eq!(state, Some(state_pointer));
+            }
+
+            // WM_NCDESTROY is still forwarded, then the pointer is forgotten.
+            let state = track_window_state(&mut user_data, WM_NCDESTROY, LPARAM(0));
+            assert_eq!(state, Some(state_pointer));
+            assert_eq!(user_data, 0);
+            assert_eq!(track_window_state(&mut user_data, WM_SIZE, LPARAM(0)), None);
+        }
+    }
+
+    #[test]
+    fn test_window_state_handle_message() {
+        let mut state = WindowState::new();
+        assert_eq!(state.handle_message(WM_SIZE, WPARAM(0), LPARAM(0)), None);
+        assert_eq!(state.handle_message(WM_KEYDOWN, WPARAM(0), LPARAM(0)), None);
+    }
+
+    #[test]
+    fn test_window_state_tracks_minimized() {
+        let mut state = WindowState::new();
+        for kind in [
+            SIZE_RESTORED,
+            SIZE_MINIMIZED,
+            SIZE_MINIMIZED,
+            SIZE_MAXSHOW,
+            SIZE_MAXIMIZED,
+            SIZE_RESTORED,
+            SIZE_MINIMIZED,
+        ] {
+            // Sizes still get the default processing.
+            let result = state.handle_message(WM_SIZE, WPARAM(kind as usize), LPARAM(0));
+            assert_eq!(result, None);
+        }
+        assert!(state.minimized);
+        assert_eq!(
+            state.events,
+            [
+                WindowEvent::Minimized,
+                WindowEvent::Restored,
+                WindowEvent::Minimized
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wait_milliseconds() {
+        assert_eq!(wait_milliseconds(Duration::from_nanos(1)), 1);
+        assert_eq!(wait_milliseconds(Duration::from_millis(100)), 100);
+        assert_eq!(wait_milliseconds(Duration::from_micros(100_001)), 101);
+        assert_eq!(wait_milliseconds(Duration::MAX), INFINITE - 1);
+    }
+
+    /// Packs a client position into an `LPARAM` like mouse messages do.
+    fn position_lparam(x: i16, y: i16) -> LPARAM {
+        LPARAM((((y as u16 as u32) << 16) | x as u16 as u32) as isize)
+    }
+
+    #[test]
+    fn test_window_state_handle_message_queues_events() {
+        let mut state = WindowState::new();
+        let messages = [
+            (WM_SETFOCUS, WPARAM(0), LPARAM(0)),
+            (WM_MOVE, WPARAM(0), position_lparam(-1920, 40)),
+            (WM_KEYDOWN, WPARAM(VK_SPACE.0 as usize), LPARAM(1)),
+            (WM_KEYDOWN, WPARAM(VK_SPACE.0 as usize), LPARAM(1 << 30 | 1)),
+            (WM_KEYUP, WPARAM(VK_SPACE.0 as usize), LPARAM(3 << 30 | 1)),
+            (WM_MOUSEMOVE, WPARAM(0), position_lparam(10, 20)),
+            (WM_LBUTTONDOWN, WPARAM(0), position_lparam(10, 20)),
+            (WM_XBUTTONUP, WPARAM(2 << 16), position_lparam(-5, 3)),
+            (
+                WM_MOUSEWHEEL,
+                WPARAM((-120i16 as u16 as usize) << 16),
+                LPARAM(0),
+            ),
+            (WM_KILLFOCUS, WPARAM(0), LPARAM(0)),
+            (WM_CLOSE, WPARAM(0), LPARAM(0)),
+        ];
+        for (message, wparam, lparam) in messages {
+            // Events don't replace the default processing.
+            assert_eq!(state.handle_message(message, wparam, lparam), None);
+        }
+
+        let timestamp = PerformanceCounter::default();
+        let space = |state, repeat| {
+            WindowEvent::Key(KeyEvent {
+                key: VK_SPACE.0,
+                state,
+                repeat,
+                timestamp,
+            })
+        };
+        let expected = [
+            WindowEvent::FocusGained,
+            WindowEvent::Moved(Vector2::new(-1920, 40)),
+            space(ButtonState::Pressed, false),
+            space(ButtonState::Pressed, true),
+            space(ButtonState::Released, false),
+            WindowEvent::Mouse(MouseEvent::Moved {
+                position: Vector2::new(10, 20),
+                timestamp,
+            }),
+            WindowEvent::Mouse(MouseEvent::Button {
+                button: MouseButton::Left,
+                state: ButtonState::Pressed,
+                position: Vector2::new(10, 20),
+                timestamp,
+            }),
+            WindowEvent::Mouse(MouseEvent::Button {
+                button: MouseButton::X2,
+                state: ButtonState::Released,
+                position: Vector2::new(-5, 3),
+                timestamp,
+            }),
+            WindowEvent::Mouse(MouseEvent::Wheel {
+                delta: -1.0,
+                timestamp,
+            }),
+            WindowEvent::FocusLost,
+            WindowEvent::CloseRequested,
+        ];
+        let events: Vec<_> = state.events.into_iter().map(without_timestamp).collect();
+        assert_eq!(events, expected);
+    }
+
+    /// Copy of `event` with a zero timestamp, to compare it with events built by tests.
+    fn without_timestamp(event: WindowEvent) -> WindowEvent {
+        let zero = PerformanceCounter::default();
+        match event {
+            WindowEvent::Key(event) => WindowEvent::Key(KeyEvent {
+                timestamp: zero,
+                ..event
+            }),
+            WindowEvent::Text(event) => WindowEvent::Text(TextInputEvent {
+                timestamp: zero,
+                ..event
+            }),
+            WindowEvent::Mouse(MouseEvent::Moved { position, .. }) => {
+                WindowEvent::Mouse(MouseEvent::Moved {
+                    position,
+                    timestamp: zero,
+                })
+            }
+            WindowEvent::Mouse(MouseEvent::Button {
+                button,
+                state,
+                position,
+                ..
+            }) => WindowEvent::Mouse(MouseEvent::Button {
+                button,
+                state,
+                position,
+                timestamp: zero,
+            }),
+            WindowEvent::Mouse(MouseEvent::Wheel { delta, .. }) => {
+                WindowEvent::Mouse(MouseEvent::Wheel {
+                    delta,
+                    timestamp: zero,
+                })
+            }
+            event => event,
+        }
+    }
+
+    #[test]
+    fn test_window_state_handle_message_timestamps_are_monotonic() {
+        let mut state = WindowState::new();
+        state.text_input_enabled = true;
+        let before = PerformanceCounter::now();
+        for i in 0..1000 {
+            let (message, wparam, lparam) = match i % 4 {
+                0 => (WM_KEYDOWN, WPARAM(VK_SPACE.0 as usize), LPARAM(1)),
+                1 => (WM_CHAR, WPARAM(0x61), LPARAM(1)),
+                2 => (WM_MOUSEMOVE, WPARAM(0), position_lparam(i as i16, 0)),
+                _ => (WM_KEYUP, WPARAM(VK_SPACE.0 as usize), LPARAM(3 << 30 | 1)),
+            };
+            state.handle_message(message, wparam, lparam);
+        }
+        let after = PerformanceCounter::now();
+
+        let timestamps: Vec<_> = state
+            .events
+            .iter()
+            .map(|event| event.timestamp().expect("input events are timestamped"))
+            .collect();
+        assert_eq!(timestamps.len(), 1000);
+        assert!(timestamps.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(before <= timestamps[0] && timestamps[999] <= after);
+    }
+
+    #[test]
+    fn test_window_state_handle_message_decodes_text_input() {
+        let mut state = WindowState::new();
+        let characters = |state: &mut WindowState, units: &[u16]| {
+            for &unit in units {
+                let result = state.handle_message(WM_CHAR, WPARAM(unit as usize), LPARAM(1));
+                assert_eq!(result, Some(LRESULT(0)));
+            }
+            state
+                .events
+                .drain(..)
+                .map(|event| match event {
+                    WindowEvent::Text(text) => text.character,
+                    event => panic!("unexpected event {event:?}"),
+                })
+                .collect::<String>()
+        };
+
+        // Text input is disabled by default.
+        assert_eq!(characters(&mut state, &[0x61]), "");
+
+        state.text_input_enabled = true;
+        // Backspace, tab and enter are left to key events, and the emoji arrives as a surrogate
+        // pair in two messages.
+        let units = [0x53, 0xE9, 0x08, 0x09, 0x0D, 0xD83D, 0xDE00, 0x21];
+        assert_eq!(characters(&mut state, &units), "Sé😀!");
+
+        // Surrogates without their other half are dropped.
+        assert_eq!(characters(&mut state, &[0xDE00, 0x61, 0xD83D, 0x62]), "ab");
+
+        // WM_UNICHAR carries whole code points, and reports being supported.
+        let no_character = WPARAM(UNICODE_NOCHAR as usize);
+        assert_eq!(
+            state.handle_message(WM_UNICHAR, no_character, LPARAM(0)),
+            Some(LRESULT(1))
+        );
+        assert!(state.events.is_empty());
+        assert_eq!(
+            state.handle_message(WM_UNICHAR, WPARAM(0x1F680), LPARAM(0)),
+            Some(LRESULT(0))
+        );
+        let rocket = state.events.pop_front().map(without_timestamp);
+        let expected = TextInputEvent {
+            character: '🚀',
+            timestamp: PerformanceCounter::default(),
+        };
+        assert_eq!(rocket, Some(WindowEvent::Text(expected)));
+    }
+
+    #[test]
+    fn test_translate_message_ignores_other_messages() {
+        let timestamp = PerformanceCounter::now();
+        assert_eq!(
+            translate_message(WM_PAINT, WPARAM(0), LPARAM(0), timestamp),
+            None
+        );
+        assert_eq!(
+            translate_message(WM_CHAR, WPARAM(0x61), LPARAM(1), timestamp),
+            None
+        );
+    }
+
+    #[test]
+    fn test_client_rect_to_screen() {
+        let client = RECT {
+            left: 0,
+            top: 0,
+            right: 800,
+            bottom: 600,
+        };
+        let origin = POINT { x: 100, y: -20 };
+        let expected = RECT {
+            left: 100,
+            top: -20,
+            right: 900,
+            bottom: 580,
+        };
+        assert_eq!(client_rect_to_screen(client, origin), expected);
+    }
+}
@@ -17,31 +17,106 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+#[cfg(target_os = "windows")]
+use std::cell::RefCell;
+#[cfg(target_os = "windows")]
 use std::ops::{Deref, DerefMut};
+#[cfg(target_os = "windows")]
+use std::rc::Weak;
+use std::time::Duration;
+
+#[cfg(target_os = "windows")]
+use super::events::{Observable, Observer};
+use super::math::{Size, Vector2};
 
-use super::math::Size;
+pub use super::events::window::WindowEvent;
 
 #[cfg(target_os = "windows")]
-use super::win::window::{NativeWindowHandle, Win32Window};
+pub use super::win::window::NativeWindowHandle;
+#[cfg(target_os = "windows")]
+use super::win::window::Win32Window;
+
+/// DPI that Windows maps to a scale factor of 1.0.
+pub const DEFAULT_DPI: u32 = 96;
 
 pub trait NativeWindow: Sized {
     fn create() -> Self;
+    /// Size of the client area, in physical pixels.
     fn size(&self) -> Size<u32>;
+    /// Resizes the window so that its client area has the given size, in physical pixels.
+    fn set_size(&mut self, size: Size<u32>);
+    /// Whether the window is the foreground window receiving input.
+    fn is_focused(&self) -> bool;
+    /// Whether the window is minimized, from its `Minimized` event to its `Restored` event.
+    fn is_minimized(&self) -> bool;
+    /// Dots per inch of the monitor the window currently is on.
+    fn dpi(&self) -> u32;
+    /// Scale between physical pixels and logical (device independent) pixels.
+    fn scale_factor(&self) -> f32 {
+        self.dpi() as f32 / DEFAULT_DPI as f32
+    }
+    /// Takes the oldest window event not yet consumed, if any.
+    fn poll_event(&mut self) -> Option<WindowEvent>;
+    fn fullscreen_mode(&self) -> FullscreenMode;
+    fn set_fullscreen(&mut self, mode: FullscreenMode);
+    fn set_title(&mut self, title: &str);
+    fn set_cursor_visible(&mut self, visible: bool);
+    /// Moves the cursor to a position in client coordinates.
+    fn set_cursor_position(&mut self, position: Vector2<i32>);
+    /// Keeps the cursor within the client area while enabled.
+    fn confine_cursor(&mut self, confine: bool);
+    /// Whether typed characters are reported as `WindowEvent::Text`, disabled by default.
+    fn set_text_input_enabled(&mut self, enabled: bool);
+    #[cfg(target_os = "windows")]
     fn handle(&self) -> NativeWindowHandle;
-    fn process_until_end(&mut self);
+    /// Blocks processing messages until the window is closed, returning `Exit`,
+    /// or `Error` if messages could not be retrieved.
+    fn process_until_end(&mut self) -> WindowProcessResult;
+    /// Processes at most one pending message, without blocking.
     fn process_message_if_available(&mut self) -> WindowProcessResult;
+    /// Processes at most one message like `process_message_if_available`, blocking until one
+    /// arrives if none is pending. Returns `Idle` once `timeout` expired without a message, or
+    /// never returns `Idle` without a timeout.
+    fn wait_message(&mut self, timeout: Option<Duration>) -> WindowProcessResult;
+
+    /// Processes all pending messages, returning `Idle` once the queue is empty,
+    /// or as soon as a message yields `Exit` or `Error`.
+    fn pump_messages(&mut self) -> WindowProcessResult {
+        loop {
+            match self.process_message_if_available() {
+                WindowProcessResult::Processed => continue,
+                result => return result,
+            }
+        }
+    }
 }
 
-#[derive(PartialEq, Eq)]
+/// Outcome of processing the window message queue.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum WindowProcessResult {
-    Ok,
-    Skip,
+    /// A message was processed, more may be pending.
+    Processed,
+    /// There were no pending messages.
+    Idle,
+    /// The window was closed and the application should exit.
     Exit,
-    Error(String), // TODO Add error info
+    /// Processing messages failed, with the system error message.
+    Error(String),
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FullscreenMode {
+    /// Regular window with title bar and borders.
+    #[default]
+    Windowed,
+    /// Window without decorations covering the whole monitor it is on.
+    Borderless,
+}
+
+#[cfg(target_os = "windows")]
 struct WindowGeneric<TNativeWindow: NativeWindow>(TNativeWindow);
 
+#[cfg(target_os = "windows")]
 impl<T: NativeWindow> Deref for WindowGeneric<T> {
     type Target = T;
 
@@ -50,38 +125,167 @@ impl<T: NativeWindow> Deref for WindowGeneric<T> {
     }
 }
 
+#[cfg(target_os = "windows")]
 impl<T: NativeWindow> DerefMut for WindowGeneric<T> {    
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
     }
 }
 
+/// Observers registered on a window, dropped ones being removed as events are dispatched.
+#[cfg(target_os = "windows")]
+type WindowObservers = Vec<Weak<RefCell<dyn Observer<WindowEvent>>>>;
+
 #[cfg(target_os = "windows")]
 pub struct Window {
     window_generic: WindowGeneric<Win32Window>,
+    /// Notified of each event taken with `poll_event`. Behind a `RefCell` so renderers can
+    /// register while being created for a shared window.
+    observers: RefCell<WindowObservers>,
 }
 
+#[cfg(target_os = "windows")]
 impl Window {
     pub fn create() -> Self {
         Self {
-            #[cfg(target_os = "windows")]
             window_generic: WindowGeneric::<Win32Window>(Win32Window::create()),
+            observers: RefCell::new(Vec::new()),
         }
     }
 
+    /// Size of the client area, in physical pixels.
     pub fn size(&self) -> Size<u32> {
         self.window_generic.size()
     }
 
-    pub fn process_until_end(&mut self) {
-        self.window_generic.process_until_end();
+    /// Resizes the window so that its client area has the given size, in physical pixels.
+    /// Queues a `WindowEvent::Resized` if the size changed.
+    pub fn set_size(&mut self, size: Size<u32>) {
+        self.window_generic.set_size(size);
+    }
+
+    pub fn is_focused(&self) -> bool {
+        self.window_generic.is_focused()
+    }
+
+    /// Whether the window is minimized. Game loops can wait for messages with `wait_message`
+    /// meanwhile, instead of drawing frames no one sees as fast as they can.
+    pub fn is_minimized(&self) -> bool {
+        self.window_generic.is_minimized()
+    }
+
+    /// Size of the client area, in logical pixels (physical size divided by the scale factor).
+    pub fn logical_size(&self) -> Size<f32> {
+        self.size().to_f32() / self.scale_factor()
+    }
+
+    pub fn dpi(&self) -> u32 {
+        self.window_generic.dpi()
+    }
+
+    pub fn scale_factor(&self) -> f32 {
+        self.window_generic.scale_factor()
+    }
+
+    /// Takes the oldest window event not yet consumed, if any, after notifying the observers
+    /// registered on the window of it.
+    pub fn poll_event(&mut self) -> Option<WindowEvent> {
+        let event = self.window_generic.poll_event()?;
+        self.observers
+            .get_mut()
+            .retain(|observer| match observer.upgrade() {
+                Some(observer) => {
+                    observer.borrow_mut().on_event(&event);
+                    true
+                }
+                None => false,
+            });
+        Some(event)
+    }
+
+    pub fn fullscreen_mode(&self) -> FullscreenMode {
+        self.window_generic.fullscreen_mode()
+    }
+
+    /// Switches between windowed and borderless fullscreen.
+    /// Returning to windowed restores the placement the window had before going fullscreen.
+    /// Queues a `WindowEvent::Resized`, renderers without `RendererOptions::auto_resize` must then
+    /// be resized by the caller.
+    pub fn set_fullscreen(&mut self, mode: FullscreenMode) {
+        self.window_generic.set_fullscreen(mode);
+    }
+
+    pub fn process_until_end(&mut self) -> WindowProcessResult {
+        self.window_generic.process_until_end()
     }
 
     pub fn process_message_if_available(&mut self) -> WindowProcessResult {
         self.window_generic.process_message_if_available()
     }
 
+    /// Processes one message, waiting up to `timeout` for it if none is pending, or forever
+    /// without a timeout. Returns `Idle` if the timeout expired first.
+    /// Like the other ways of processing messages, returns `Exit` once when the window closes.
+    pub fn wait_message(&mut self, timeout: Option<Duration>) -> WindowProcessResult {
+        self.window_generic.wait_message(timeout)
+    }
+
+    /// Processes every pending message, see `NativeWindow::pump_messages`.
+    /// Prefer this over `process_message_if_available` in game loops, so queued input
+    /// is not spread over several frames.
+    pub fn pump_messages(&mut self) -> WindowProcessResult {
+        self.window_generic.pump_messages()
+    }
+
+    pub fn set_title(&mut self, title: &str) {
+        self.window_generic.set_title(title);
+    }
+
+    /// Shows or hides the cursor while it is over the window.
+    /// Repeated calls with the same value have no additional effect.
+    pub fn set_cursor_visible(&mut self, visible: bool) {
+        self.window_generic.set_cursor_visible(visible);
+    }
+
+    /// Moves the cursor to a position in client coordinates, in physical pixels.
+    pub fn set_cursor_position(&mut self, position: Vector2<i32>) {
+        self.window_generic.set_cursor_position(position);
+    }
+
+    /// Keeps the cursor within the client area while enabled, following the window as it moves
+    /// or is resized. Useful together with a hidden cursor for mouse look.
+    pub fn confine_cursor(&mut self, confine: bool) {
+        self.window_generic.confine_cursor(confine);
+    }
+
+    /// Switches text entry on or off, e.g. while a chat box or a name field has focus.
+    /// While enabled, typed characters are queued as `WindowEvent::Text`, in addition to the
+    /// `WindowEvent::Key` events that are always queued.
+    pub fn set_text_input_enabled(&mut self, enabled: bool) {
+        self.window_generic.set_text_input_enabled(enabled);
+    }
+
     pub fn native_window_handle(&self) -> NativeWindowHandle {
         self.window_generic.handle()
     }
+
+    /// Registers `observer` through a shared reference, for renderers created for the window.
+    pub(crate) fn add_observer(&self, observer: Weak<RefCell<dyn Observer<WindowEvent>>>) {
+        self.observers.borrow_mut().push(observer);
+    }
+}
+
+/// Observers are notified from `Window::poll_event`, in the order they were registered, and
+/// are dropped from the window once nothing else holds them.
+#[cfg(target_os = "windows")]
+impl Observable<WindowEvent> for Window {
+    fn regiter(&mut self, observer: Weak<RefCell<dyn Observer<WindowEvent>>>) {
+        self.observers.get_mut().push(observer);
+    }
+
+    fn unregister(&mut self, observer: Weak<RefCell<dyn Observer<WindowEvent>>>) {
+        self.observers
+            .get_mut()
+            .retain(|registered| !registered.ptr_eq(&observer));
+    }
 }
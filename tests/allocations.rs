@@ -0,0 +1,188 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Checks that the batch transforms of `math`, text layout updates, frame time statistics and
+//! the rows of the debug overlay don't allocate once their buffers exist.
+//! Lives in its own test binary, as it replaces the global allocator.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use sky_labs::debug_overlay::DebugOverlay;
+use sky_labs::math::{Matrix3x3, Matrix4x4, Size, Vector2, Vector4};
+use sky_labs::renderer::software::SoftwareRenderer;
+use sky_labs::renderer::{Renderer, TextFormat};
+use sky_labs::timer::FrameTimeStats;
+
+/// Allocator counting the allocations of each thread, so tests running in parallel don't
+/// see each other's allocations.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const POINT_COUNT: usize = 10_000;
+const FRAME_COUNT: usize = 60;
+
+/// Returns the number of allocations made by `f` on the current thread.
+fn count_allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn test_transform_into_does_not_allocate() {
+    let points: Vec<_> = (0..POINT_COUNT)
+        .map(|i| Vector4::new(i as f32, -(i as f32), 0.5, 1.0))
+        .collect();
+    let mut transformed = vec![Vector4::default(); POINT_COUNT];
+
+    let allocations = count_allocations(|| {
+        for frame in 0..FRAME_COUNT {
+            let transform = Matrix4x4::<f32>::make_translation(frame as f32, 0.0, 0.0);
+            transform.transform_into(&points, &mut transformed);
+        }
+    });
+    assert_eq!(allocations, 0);
+    assert_eq!(
+        transformed[1],
+        Vector4::new(1.0 + (FRAME_COUNT - 1) as f32, -1.0, 0.5, 1.0)
+    );
+}
+
+#[test]
+fn test_transform_points_2d_into_does_not_allocate() {
+    let points: Vec<_> = (0..POINT_COUNT)
+        .map(|i| Vector2::new(i as f32, 1.0))
+        .collect();
+    let mut transformed = vec![Vector2::default(); POINT_COUNT];
+
+    let allocations = count_allocations(|| {
+        for frame in 0..FRAME_COUNT {
+            let transform = Matrix3x3::<f32>::make_translation_2d(0.0, frame as f32);
+            transform.transform_points_2d_into(&points, &mut transformed);
+        }
+    });
+    assert_eq!(allocations, 0);
+    assert_eq!(transformed[2], Vector2::new(2.0, FRAME_COUNT as f32));
+}
+
+#[test]
+fn test_text_layout_update_text_does_not_allocate() {
+    let renderer = SoftwareRenderer::new(Size::new(1, 1));
+    let mut layout =
+        renderer.create_text_layout("60", &TextFormat::default(), Size::new(80.0, 40.0));
+    let mut text = String::with_capacity(8);
+
+    // A framerate counter formatting its count into a buffer, as `FramerateCounter::draw` does.
+    let allocations = count_allocations(|| {
+        for frame in 0..FRAME_COUNT {
+            use std::fmt::Write;
+            text.clear();
+            write!(text, "{}", 58 + frame % 3).unwrap();
+            layout.update_text(&text);
+        }
+    });
+    assert_eq!(allocations, 0);
+    assert_eq!(layout.text(), "60");
+
+    // Formatting a new string every frame, as drawing with `draw_text` requires.
+    let allocations = count_allocations(|| {
+        for frame in 0..FRAME_COUNT {
+            let text = format!("{}", 58 + frame % 3);
+            assert_eq!(text.len(), 2);
+        }
+    });
+    assert_eq!(allocations, FRAME_COUNT);
+}
+
+#[test]
+fn test_frame_time_stats_record_does_not_allocate() {
+    let mut stats = FrameTimeStats::default();
+    let allocations = count_allocations(|| {
+        for frame in 0..4 * stats.capacity() {
+            stats.record_ms(16.0 + (frame % 7) as f64);
+            assert!(stats.percentile_ms(99.0) <= stats.worst_ms());
+        }
+    });
+    assert_eq!(allocations, 0);
+    assert_eq!(stats.worst_ms(), 22.0);
+}
+
+#[test]
+fn test_debug_overlay_rows_do_not_allocate() {
+    let mut overlay = DebugOverlay::new();
+    let collect = |overlay: &mut DebugOverlay, frame: usize| {
+        overlay.begin_frame(1.0 / 60.0);
+        overlay.line("Debug");
+        overlay.value("Entities", (frame % 100) as f64);
+        overlay.vector2("Position", Vector2::new(frame as f32, -1.5));
+        overlay.graph("Frame time", 16.0 + (frame % 3) as f32);
+    };
+    // The first frame allocates the row buffers, as long as the longest rows to come, and the
+    // values of the graph.
+    collect(&mut overlay, 99);
+
+    let allocations = count_allocations(|| {
+        for frame in 0..FRAME_COUNT {
+            collect(&mut overlay, frame);
+        }
+    });
+    assert_eq!(allocations, 0);
+
+    // Hidden, the rows aren't even formatted.
+    overlay.visible = false;
+    let allocations = count_allocations(|| {
+        for frame in 0..FRAME_COUNT {
+            collect(&mut overlay, frame);
+            overlay.line("Not collected");
+        }
+    });
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn test_count_allocations_sees_allocations() {
+    let allocations = count_allocations(|| {
+        let points = vec![Vector2::new(1.0f32, 2.0); POINT_COUNT];
+        assert_eq!(points.len(), POINT_COUNT);
+    });
+    assert_eq!(allocations, 1);
+}
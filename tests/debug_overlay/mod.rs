@@ -0,0 +1,172 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::debug_overlay::*;
+use sky_labs::math::{Rect, Vector2};
+use sky_labs::renderer::testing::{DrawCommand, DrawCommandKind, RecordingSession};
+use sky_labs::renderer::DrawingSession;
+
+/// Returns the texts drawn and their layout rectangles, in the order they were drawn.
+fn texts(session: &RecordingSession) -> Vec<(String, Rect<f32>)> {
+    session
+        .commands_of(DrawCommandKind::Text)
+        .map(|command| match command {
+            DrawCommand::Text { text, coord, .. } => (text.clone(), *coord),
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// Returns the rectangles drawn, in the order they were drawn.
+fn rects(session: &RecordingSession) -> Vec<Rect<f32>> {
+    session
+        .commands_of(DrawCommandKind::Rect)
+        .map(|command| match command {
+            DrawCommand::Rect { rect, .. } => *rect,
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_debug_overlay_stacks_rows() {
+    let mut overlay = DebugOverlay::new();
+    overlay.position = Vector2::new(10.0, 20.0);
+    overlay.begin_frame(1.0 / 60.0);
+    overlay.line("Debug");
+    overlay.value("Entities", 42.0);
+    overlay.vector2("Position", Vector2::new(1.0, -2.5));
+
+    let mut session = RecordingSession::new();
+    overlay.draw(&mut session);
+
+    // The background first, so the rows are drawn over it.
+    assert_eq!(session.commands()[0].kind(), DrawCommandKind::Rect);
+    let background = rects(&session)[0];
+    assert_eq!((background.x, background.y), (10.0, 20.0));
+    assert_eq!(background.width, OVERLAY_WIDTH);
+
+    let texts = texts(&session);
+    let lines: Vec<&str> = texts.iter().map(|(text, _)| text.as_str()).collect();
+    assert!(lines[0].ends_with(" fps"), "{lines:?}");
+    assert_eq!(
+        lines[1..],
+        ["Debug", "Entities: 42", "Position: (1.00, -2.50)"]
+    );
+    for pair in texts.windows(2) {
+        let (above, below) = (pair[0].1, pair[1].1);
+        assert_eq!(below.x, above.x);
+        assert_eq!(below.y, above.y + LINE_HEIGHT);
+    }
+    let (_, last) = texts[texts.len() - 1];
+    assert!(background.x < texts[0].1.x && texts[0].1.y > background.y);
+    assert!(last.y + last.height < background.y + background.height);
+}
+
+#[test]
+fn test_debug_overlay_rows_of_a_single_frame() {
+    let mut overlay = DebugOverlay::new();
+    overlay.begin_frame(1.0 / 60.0);
+    overlay.line("Previous frame");
+    overlay.begin_frame(1.0 / 60.0);
+    overlay.value("Ratio", 0.5);
+
+    let mut session = RecordingSession::new();
+    overlay.draw(&mut session);
+    let texts = texts(&session);
+    assert_eq!(texts.len(), 2);
+    assert_eq!(texts[1].0, "Ratio: 0.500");
+}
+
+#[test]
+fn test_debug_overlay_graph_scales_to_max_value() {
+    let mut overlay = DebugOverlay::new();
+    for value in [1.0, 2.0, 4.0, -1.0, 2.0] {
+        overlay.begin_frame(1.0 / 60.0);
+        overlay.graph("Load", value);
+    }
+    overlay.line("Below");
+
+    let mut session = RecordingSession::new();
+    overlay.draw(&mut session);
+    let texts = texts(&session);
+    assert_eq!(texts[1].0, "Load: 2.00");
+    // The row after the graph leaves room for its bars.
+    assert_eq!(texts[2].1.y, texts[1].1.y + LINE_HEIGHT + GRAPH_HEIGHT);
+
+    // One bar per positive value after the background, from the oldest, the largest one
+    // being `GRAPH_HEIGHT` high and all of them standing on the same line.
+    let bars = &rects(&session)[1..];
+    let heights: Vec<f32> = bars.iter().map(|bar| bar.height).collect();
+    let unit = GRAPH_HEIGHT / 4.0;
+    assert_eq!(heights, [unit, 2.0 * unit, GRAPH_HEIGHT, 2.0 * unit]);
+    let bottom = bars[0].y + bars[0].height;
+    assert!(bars.iter().all(|bar| bar.y + bar.height == bottom));
+    assert!(bars.windows(2).all(|pair| pair[0].x < pair[1].x));
+    assert_eq!(bottom, texts[1].1.y + LINE_HEIGHT + GRAPH_HEIGHT);
+}
+
+#[test]
+fn test_debug_overlay_graph_keeps_last_values() {
+    let mut overlay = DebugOverlay::new();
+    for value in 0..GRAPH_CAPACITY + 10 {
+        overlay.begin_frame(1.0 / 60.0);
+        overlay.graph("Count", value as f32);
+    }
+    let mut session = RecordingSession::new();
+    overlay.draw(&mut session);
+
+    // The oldest values are dropped, and the latest is the largest one on the right.
+    let bars = &rects(&session)[1..];
+    assert_eq!(bars.len(), GRAPH_CAPACITY);
+    let last = bars[bars.len() - 1];
+    assert_eq!(last.height, GRAPH_HEIGHT);
+    assert!(bars.windows(2).all(|pair| pair[0].height < pair[1].height));
+}
+
+#[test]
+fn test_debug_overlay_hidden() {
+    let mut overlay = DebugOverlay::new();
+    overlay.visible = false;
+    overlay.begin_frame(1.0 / 60.0);
+    overlay.line("Debug");
+    overlay.graph("Load", 1.0);
+
+    let mut session = RecordingSession::new();
+    overlay.draw(&mut session);
+    assert!(session.commands().is_empty());
+
+    // Rows collected while hidden aren't drawn once shown.
+    overlay.visible = true;
+    overlay.draw(&mut session);
+    assert!(session.commands().is_empty());
+    overlay.begin_frame(1.0 / 60.0);
+    overlay.draw(&mut session);
+    assert_eq!(session.count_of(DrawCommandKind::Text), 1);
+}
+
+#[test]
+fn test_debug_overlay_draws_through_dyn_session() {
+    let mut overlay = DebugOverlay::new();
+    overlay.begin_frame(1.0 / 60.0);
+    let mut recording = RecordingSession::new();
+    let session: &mut dyn DrawingSession = &mut recording;
+    overlay.draw(session);
+    assert_eq!(recording.count_of(DrawCommandKind::Text), 1);
+}
@@ -0,0 +1,97 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use std::thread;
+
+use sky_labs::events::{Event, SyncEventQueue};
+
+#[derive(Debug, PartialEq)]
+struct Work {
+    producer: usize,
+    sequence: usize,
+}
+
+impl Event for Work {}
+
+#[test]
+fn test_sync_event_queue_delivers_from_threads() {
+    const PRODUCERS: usize = 4;
+    const EVENTS: usize = 1000;
+
+    let mut queue = SyncEventQueue::new();
+    let producers: Vec<_> = (0..PRODUCERS)
+        .map(|producer| {
+            let sender = queue.sender();
+            thread::spawn(move || {
+                for sequence in 0..EVENTS {
+                    assert!(sender.emit(Work { producer, sequence }));
+                }
+            })
+        })
+        .collect();
+
+    let mut next = [0; PRODUCERS];
+    let mut check = |work: Work| {
+        assert_eq!(work.sequence, next[work.producer], "out of order");
+        next[work.producer] += 1;
+    };
+    while producers.iter().any(|producer| !producer.is_finished()) {
+        queue.drain(&mut check);
+    }
+    for producer in producers {
+        producer.join().unwrap();
+    }
+    queue.drain(&mut check);
+    assert_eq!(next, [EVENTS; PRODUCERS]);
+
+    queue.drain(|_| panic!("events must be delivered once"));
+}
+
+#[test]
+fn test_sync_event_queue_defers_events_emitted_while_draining() {
+    let mut queue = SyncEventQueue::new();
+    let sender = queue.sender();
+    sender.emit(Work {
+        producer: 0,
+        sequence: 0,
+    });
+
+    let mut drained = Vec::new();
+    queue.drain(|work| {
+        sender.emit(Work {
+            producer: 0,
+            sequence: work.sequence + 1,
+        });
+        drained.push(work.sequence);
+    });
+    assert_eq!(drained, [0]);
+    queue.drain(|work| drained.push(work.sequence));
+    assert_eq!(drained, [0, 1]);
+}
+
+#[test]
+fn test_event_sender_after_queue_dropped() {
+    let queue = SyncEventQueue::new();
+    let sender = queue.sender().clone();
+    drop(queue);
+    assert!(!sender.emit(Work {
+        producer: 0,
+        sequence: 0,
+    }));
+}
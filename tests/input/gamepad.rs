@@ -0,0 +1,120 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::input::gamepad::*;
+use sky_labs::math::Vector2;
+
+fn assert_near(actual: Vector2<f32>, expected: Vector2<f32>) {
+    assert!(
+        (actual.x - expected.x).abs() < 1e-5 && (actual.y - expected.y).abs() < 1e-5,
+        "{actual:?} is not {expected:?}"
+    );
+}
+
+#[test]
+fn test_radial_dead_zone_inside_is_zero() {
+    let zero = Vector2::new(0.0, 0.0);
+    assert_eq!(apply_radial_dead_zone(zero, 0.2), zero);
+    assert_eq!(apply_radial_dead_zone(Vector2::new(0.19, 0.0), 0.2), zero);
+    assert_eq!(apply_radial_dead_zone(Vector2::new(0.0, -0.2), 0.2), zero);
+    // Per axis, both components are within the dead zone, but the position is past its radius.
+    let diagonal = apply_radial_dead_zone(Vector2::new(0.18, 0.18), 0.2);
+    assert!(diagonal.x > 0.0 && diagonal.y > 0.0);
+}
+
+#[test]
+fn test_radial_dead_zone_rescales_the_rest() {
+    // The dead zone edge maps to 0 and the rim to 1, linearly in between.
+    assert_near(
+        apply_radial_dead_zone(Vector2::new(0.6, 0.0), 0.2),
+        Vector2::new(0.5, 0.0),
+    );
+    assert_near(
+        apply_radial_dead_zone(Vector2::new(0.0, -1.0), 0.2),
+        Vector2::new(0.0, -1.0),
+    );
+
+    // The direction is kept.
+    let stick = apply_radial_dead_zone(Vector2::new(0.3, -0.4), 0.25);
+    assert_near(stick, Vector2::new(0.6 / 3.0, -0.8 / 3.0));
+
+    // Corners of the square range are clamped to the unit circle.
+    let corner = apply_radial_dead_zone(Vector2::new(1.0, 1.0), 0.2);
+    assert_near(corner, Vector2::new(0.5f32.sqrt(), 0.5f32.sqrt()));
+}
+
+#[test]
+fn test_radial_dead_zone_without_dead_zone() {
+    let stick = Vector2::new(0.01, -0.7);
+    assert_near(apply_radial_dead_zone(stick, 0.0), stick);
+}
+
+#[test]
+fn test_gamepad_buttons() {
+    let buttons = GamepadButtons::A | GamepadButtons::DPAD_UP;
+    assert!(buttons.contains(GamepadButtons::A));
+    assert!(!buttons.contains(GamepadButtons::A | GamepadButtons::B));
+    assert!(buttons.intersects(GamepadButtons::A | GamepadButtons::B));
+    assert_eq!(buttons & GamepadButtons::DPAD_UP, GamepadButtons::DPAD_UP);
+    assert!(GamepadButtons::empty().is_empty());
+    assert!(GamepadButtons::default().is_empty());
+
+    let mut pressed = GamepadButtons::empty();
+    pressed |= GamepadButtons::START;
+    assert_eq!(pressed.bits(), 0x0010);
+    // Bits without a button are dropped.
+    assert_eq!(
+        GamepadButtons::from_bits_truncate(0x1000 | 0x0400),
+        GamepadButtons::A
+    );
+}
+
+#[test]
+fn test_gamepad_new() {
+    let gamepad = Gamepad::new(3);
+    assert_eq!(gamepad.index(), 3);
+    assert!(!gamepad.is_connected());
+    assert_eq!(gamepad.state(), &GamepadState::default());
+    assert_eq!(gamepad.dead_zone(), DEFAULT_DEAD_ZONE);
+
+    let indices: Vec<u32> = Gamepad::all().iter().map(Gamepad::index).collect();
+    assert_eq!(indices, [0, 1, 2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn test_gamepad_new_index_out_of_range() {
+    Gamepad::new(MAX_GAMEPADS);
+}
+
+#[test]
+#[should_panic]
+fn test_gamepad_set_dead_zone_out_of_range() {
+    Gamepad::new(0).set_dead_zone(1.0);
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+fn test_gamepad_without_backend() {
+    let mut gamepad = Gamepad::new(0);
+    assert_eq!(gamepad.update(), None);
+    assert!(!gamepad.is_connected());
+    assert_eq!(*gamepad.state(), GamepadState::default());
+    assert!(gamepad.set_vibration(1.0, 0.5).is_err());
+}
@@ -0,0 +1,100 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::input::keyboard::*;
+use sky_labs::input::InputState;
+
+#[test]
+fn test_virtual_key_from_ascii() {
+    assert_eq!(VirtualKey::from_ascii(b'A'), Some(VirtualKey(0x41)));
+    assert_eq!(VirtualKey::from_ascii(b'z'), Some(VirtualKey(0x5A)));
+    assert_eq!(VirtualKey::from_ascii(b'0'), Some(VirtualKey(0x30)));
+    assert_eq!(VirtualKey::from_ascii(b' '), None);
+    assert_eq!(VirtualKey::from_ascii(b'-'), None);
+}
+
+#[test]
+fn test_input_state_keys() {
+    let mut state = InputState::default();
+    state.set_key_state(VirtualKey::LEFT, KeyState::Pressed);
+    state.set_key_state(VirtualKey::F11, KeyState::WasPressed);
+    // Keys are released while the window isn't focused.
+    assert_eq!(state.key_state(VirtualKey::LEFT), KeyState::Released);
+
+    state.set_focused(true);
+    assert!(state.is_key_down(VirtualKey::LEFT));
+    assert!(!state.is_key_down(VirtualKey::F11));
+    assert_eq!(state.key_state(VirtualKey::F11), KeyState::WasPressed);
+    assert_eq!(state.key_state(VirtualKey::RIGHT), KeyState::Released);
+    // Codes past the key table are never pressed.
+    assert_eq!(state.key_state(VirtualKey(0x1FF)), KeyState::Released);
+}
+
+#[cfg(target_os = "windows")]
+#[test]
+fn test_virtual_key_matches_win32() {
+    use windows::Win32::UI::Input::KeyboardAndMouse::*;
+
+    let keys = [
+        (VirtualKey::BACK, VK_BACK),
+        (VirtualKey::TAB, VK_TAB),
+        (VirtualKey::RETURN, VK_RETURN),
+        (VirtualKey::SHIFT, VK_SHIFT),
+        (VirtualKey::CONTROL, VK_CONTROL),
+        (VirtualKey::MENU, VK_MENU),
+        (VirtualKey::PAUSE, VK_PAUSE),
+        (VirtualKey::ESCAPE, VK_ESCAPE),
+        (VirtualKey::SPACE, VK_SPACE),
+        (VirtualKey::PRIOR, VK_PRIOR),
+        (VirtualKey::NEXT, VK_NEXT),
+        (VirtualKey::END, VK_END),
+        (VirtualKey::HOME, VK_HOME),
+        (VirtualKey::LEFT, VK_LEFT),
+        (VirtualKey::UP, VK_UP),
+        (VirtualKey::RIGHT, VK_RIGHT),
+        (VirtualKey::DOWN, VK_DOWN),
+        (VirtualKey::INSERT, VK_INSERT),
+        (VirtualKey::DELETE, VK_DELETE),
+        (VirtualKey::F1, VK_F1),
+        (VirtualKey::F12, VK_F12),
+    ];
+    for (key, win32) in keys {
+        assert_eq!(key, VirtualKey::from(win32));
+        assert_eq!(VIRTUAL_KEY::from(key), win32);
+    }
+    assert_eq!(
+        VirtualKey::from_ascii(b'q').map(VIRTUAL_KEY::from),
+        Some(VK_Q)
+    );
+
+    let mut state = InputState::default();
+    state.set_focused(true);
+    state.set_key_state(VK_SPACE, KeyState::Pressed);
+    assert!(state.is_key_down(VirtualKey::SPACE));
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+fn test_get_key_state_without_backend() {
+    assert_eq!(get_key_state(VirtualKey::SPACE), KeyState::Released);
+    assert_eq!(
+        InputState::new(true).key_state(VirtualKey::SPACE),
+        KeyState::Released
+    );
+}
@@ -0,0 +1,156 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use std::path::PathBuf;
+
+use sky_labs::input::gamepad::{GamepadButtons, GamepadState};
+use sky_labs::input::keyboard::{KeyState, VirtualKey};
+use sky_labs::input::recorder::*;
+use sky_labs::input::{InputFrame, InputSource, InputState};
+use sky_labs::math::Vector2;
+
+/// Path of a recording in the temporary directory, unique to the test.
+fn recording_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("sky_labs_{name}_{}.skyinput", std::process::id()))
+}
+
+/// Frames of a short session: walking left, jumping, then picking up a gamepad.
+fn synthetic_frames() -> Vec<InputFrame> {
+    let mut frames = Vec::new();
+    let mut state = InputState::default();
+    state.set_focused(true);
+    for frame in 0..60 {
+        state.set_key_state(VirtualKey::LEFT, KeyState::Pressed);
+        state.set_key_state(
+            VirtualKey::SPACE,
+            match frame % 20 {
+                0 => KeyState::WasPressed,
+                1..=4 => KeyState::Pressed,
+                _ => KeyState::Released,
+            },
+        );
+        if frame == 30 {
+            state.set_key_state(VirtualKey::LEFT, KeyState::Released);
+            state.set_focused(false);
+        }
+        if frame >= 40 {
+            state.set_focused(true);
+            state.set_gamepad(
+                2,
+                Some(GamepadState {
+                    buttons: GamepadButtons::A | GamepadButtons::DPAD_LEFT,
+                    left_stick: Vector2::new(-0.25, frame as f32 / 60.0),
+                    right_stick: Vector2::new(0.0, -1.0),
+                    left_trigger: 0.5,
+                    right_trigger: 1.0 / 3.0,
+                }),
+            );
+        }
+        frames.push(InputFrame {
+            dt: 1.0 / 60.0 + frame as f64 * 1e-6,
+            state,
+        });
+    }
+    frames
+}
+
+fn replay(player: &mut InputPlayer) -> Vec<InputFrame> {
+    std::iter::from_fn(|| player.next_frame(true, 0.0)).collect()
+}
+
+/// Bytes of a recording of `frames`.
+fn encode(frames: &[InputFrame]) -> Vec<u8> {
+    let path = recording_path(&format!("encode_{}", frames.len()));
+    let mut recorder = InputRecorder::start(&path).unwrap();
+    for frame in frames {
+        recorder.record(frame).unwrap();
+    }
+    recorder.stop().unwrap();
+    let bytes = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    bytes
+}
+
+#[test]
+fn test_input_recording_round_trip() {
+    let path = recording_path("round_trip");
+    let frames = synthetic_frames();
+    let mut recorder = InputRecorder::start(&path).unwrap();
+    for frame in &frames {
+        recorder.record(frame).unwrap();
+    }
+    recorder.stop().unwrap();
+
+    let mut player = InputPlayer::from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(player.remaining_frames(), frames.len());
+    assert_eq!(replay(&mut player), frames);
+    assert_eq!(player.next_frame(true, 0.0), None);
+
+    // Keys are released while unfocused, like live input.
+    let unfocused = &frames[35].state;
+    assert!(!unfocused.is_key_down(VirtualKey::SPACE));
+    assert!(frames[41].state.is_key_down(VirtualKey::SPACE));
+    assert_eq!(
+        frames[0].state.key_state(VirtualKey(b'A' as u16)),
+        KeyState::Released
+    );
+    assert!(frames[39].state.gamepad(2).is_none());
+    assert_eq!(frames[40].state.gamepad(2).unwrap().left_trigger, 0.5);
+}
+
+#[test]
+fn test_recording_input_records_the_frames_it_passes() {
+    let path = recording_path("recording_input");
+    let frames = synthetic_frames();
+    let mut source = InputPlayer::from_bytes(&encode(&frames)).unwrap();
+    let mut recording = RecordingInput::new(&mut source, InputRecorder::start(&path).unwrap());
+    let passed: Vec<_> = std::iter::from_fn(|| recording.next_frame(true, 0.0)).collect();
+    recording.stop().unwrap();
+    assert_eq!(passed, frames);
+
+    let mut player = InputPlayer::from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(replay(&mut player), frames);
+}
+
+#[test]
+fn test_input_player_rejects_invalid_recordings() {
+    let bytes = encode(&synthetic_frames()[..2]);
+    assert!(InputPlayer::from_bytes(&bytes).is_ok());
+
+    let empty = encode(&[]);
+    assert_eq!(
+        InputPlayer::from_bytes(&empty).unwrap().remaining_frames(),
+        0
+    );
+
+    assert!(InputPlayer::from_bytes(b"").is_err());
+    assert!(InputPlayer::from_bytes(b"NOTINPUT\x01\x00").is_err());
+
+    // Recordings of another format version fail instead of being misread.
+    let mut other_version = bytes.clone();
+    other_version[8..10].copy_from_slice(&(FORMAT_VERSION + 1).to_le_bytes());
+    let error = InputPlayer::from_bytes(&other_version).err().unwrap();
+    assert!(error.contains("version"), "{error}");
+
+    let truncated = &bytes[..bytes.len() - 1];
+    assert!(InputPlayer::from_bytes(truncated).is_err());
+    assert!(InputPlayer::from_file(recording_path("missing")).is_err());
+}
@@ -0,0 +1,156 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use std::f32::consts as f32_consts;
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI, TAU};
+
+use sky_labs::math::{Angle, Matrix2x2, Matrix3x3, Matrix4x4, Vector2, Vector3};
+
+#[test]
+fn test_angle_round_degrees_are_exact() {
+    assert_eq!(Angle::degrees(180.0f64).to_radians(), PI);
+    assert_eq!(Angle::degrees(90.0f64).to_radians(), FRAC_PI_2);
+    assert_eq!(Angle::degrees(45.0f64).to_radians(), FRAC_PI_4);
+    assert_eq!(Angle::degrees(360.0f64).to_radians(), TAU);
+    assert_eq!(Angle::degrees(-180.0f64).to_radians(), -PI);
+    assert_eq!(Angle::degrees(180.0f32).to_radians(), f32_consts::PI);
+    assert_eq!(Angle::degrees(90.0f32).to_radians(), f32_consts::FRAC_PI_2);
+    assert_eq!(Angle::radians(PI).to_degrees(), 180.0);
+    assert_eq!(Angle::radians(FRAC_PI_4).to_degrees(), 45.0);
+    assert_eq!(Angle::radians(f32_consts::PI).to_degrees(), 180.0);
+}
+
+#[test]
+fn test_angle_construction_paths_agree() {
+    for step in -720..=720 {
+        let degrees = step as f64 * 0.5;
+        let from_degrees = Angle::degrees(degrees);
+        let from_radians = Angle::radians(degrees.to_radians());
+        assert!(
+            (from_degrees.to_radians() - from_radians.to_radians()).abs() <= 1e-12,
+            "{degrees} degrees"
+        );
+        assert!(
+            (from_radians.to_degrees() - degrees).abs() <= 1e-9,
+            "{degrees} degrees"
+        );
+        assert!(
+            (from_degrees.to_degrees() - degrees).abs() <= 1e-9,
+            "{degrees} degrees"
+        );
+
+        let degrees = degrees as f32;
+        let from_degrees = Angle::degrees(degrees);
+        let from_radians = Angle::radians(degrees.to_radians());
+        assert!(
+            (from_degrees.to_radians() - from_radians.to_radians()).abs() <= 1e-5,
+            "{degrees} degrees"
+        );
+    }
+}
+
+#[test]
+fn test_angle_arithmetic() {
+    let a = Angle::degrees(30.0f64);
+    let b = Angle::degrees(60.0f64);
+    assert!(((a + b).to_degrees() - 90.0).abs() < 1e-12);
+    assert!(((b - a).to_degrees() - 30.0).abs() < 1e-12);
+    assert_eq!((-a).to_radians(), -a.to_radians());
+    assert_eq!((a * 2.0).to_radians(), a.to_radians() * 2.0);
+    assert_eq!(2.0 * a, a * 2.0);
+    assert_eq!((b / 2.0).to_radians(), b.to_radians() / 2.0);
+
+    let mut c = a;
+    c += b;
+    c -= a;
+    assert_eq!(c, a + b - a);
+    c *= 3.0;
+    c /= 3.0;
+    assert!((c.to_radians() - b.to_radians()).abs() < 1e-12);
+    assert!(a < b);
+    assert_eq!(Angle::<f32>::default().to_radians(), 0.0);
+}
+
+#[test]
+fn test_angle_trigonometry() {
+    let angle = Angle::degrees(30.0f64);
+    assert!((angle.sin() - 0.5).abs() < 1e-12);
+    assert!((angle.cos() - 3.0f64.sqrt() / 2.0).abs() < 1e-12);
+    assert!((Angle::degrees(45.0f64).tan() - 1.0).abs() < 1e-12);
+    assert_eq!(angle.sin_cos(), (angle.sin(), angle.cos()));
+    assert!((Angle::degrees(90.0f32).sin() - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_angle_normalized() {
+    assert_eq!(Angle::degrees(90.0f64).normalized(), Angle::degrees(90.0));
+    assert_eq!(Angle::degrees(360.0f64).normalized().to_radians(), 0.0);
+    assert!((Angle::degrees(-90.0f64).normalized().to_degrees() - 270.0).abs() < 1e-9);
+    assert!((Angle::degrees(750.0f64).normalized().to_degrees() - 30.0).abs() < 1e-9);
+    for step in -100..100 {
+        let radians = Angle::radians(step as f32 * 0.37).normalized().to_radians();
+        assert!((0.0..f32_consts::TAU).contains(&radians), "{radians}");
+    }
+}
+
+#[test]
+fn test_angle_rotation_variants_match_radians() {
+    let angle = Angle::degrees(30.0f32);
+    let rad = angle.to_radians();
+    let axis = Vector3::new(1.0f32, 2.0, 3.0);
+    assert_eq!(
+        Matrix2x2::<f32>::make_rotation_angle(angle),
+        Matrix2x2::<f32>::make_rotation(rad)
+    );
+    assert_eq!(
+        Matrix3x3::<f32>::make_rotation_x_angle(angle),
+        Matrix3x3::<f32>::make_rotation_x(rad)
+    );
+    assert_eq!(
+        Matrix3x3::<f32>::make_rotation_angle(angle, &axis),
+        Matrix3x3::<f32>::make_rotation(rad, &axis)
+    );
+    assert_eq!(
+        Matrix3x3::<f32>::make_rotation_2d_angle(angle),
+        Matrix3x3::<f32>::make_rotation_2d(rad)
+    );
+    assert_eq!(
+        Matrix4x4::<f32>::make_rotation_z_angle(angle),
+        Matrix4x4::<f32>::make_rotation_z(rad)
+    );
+    assert_eq!(
+        Matrix4x4::<f32>::make_rotation_normalized_angle(angle, &axis.normalize()),
+        Matrix4x4::<f32>::make_rotation_normalized(rad, &axis.normalize())
+    );
+
+    let v = Vector3::new(1.0f32, 0.0, 0.0);
+    assert_eq!(v.rotate_y_angle(angle), v.rotate_y(rad));
+    assert_eq!(
+        v.rotate_angle(angle, &axis.normalize()),
+        v.rotate(rad, &axis.normalize())
+    );
+
+    let angle = Angle::degrees(90.0f64);
+    let w = Vector2::new(1.0f64, 0.0);
+    assert_eq!(w.rotate_angle(angle), w.rotate(FRAC_PI_2));
+    assert_eq!(
+        w.rotate_around_angle(&Vector2::new(1.0, 1.0), angle),
+        w.rotate_around(&Vector2::new(1.0, 1.0), FRAC_PI_2)
+    );
+}
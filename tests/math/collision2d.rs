@@ -0,0 +1,266 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::math::collision2d::*;
+use sky_labs::math::{Rect, Vector2};
+
+fn v(x: f32, y: f32) -> Vector2<f32> {
+    Vector2::new(x, y)
+}
+
+/// The rectangle from (0, 0) to (10, 4).
+const RECT: Rect<f32> = Rect {
+    x: 0.0,
+    y: 0.0,
+    width: 10.0,
+    height: 4.0,
+};
+
+#[test]
+fn test_point_in_circle() {
+    for (point, inside) in [
+        (v(1.0, 1.0), true),
+        (v(3.0, 2.0), true), // On the edge
+        (v(4.0, 4.0), false),
+        (v(1.0, -1.0), false),
+    ] {
+        assert_eq!(
+            point_in_circle(point, v(1.0, 2.0), 2.0),
+            inside,
+            "{point:?}"
+        );
+    }
+}
+
+#[test]
+fn test_circle_intersects_circle() {
+    for (center, radius, hit) in [
+        (v(3.0, 0.0), 2.0, true),
+        (v(4.0, 0.0), 2.0, true), // Tangent
+        (v(4.1, 0.0), 2.0, false),
+        (v(0.5, 0.0), 0.1, true), // Inside
+        (v(3.0, 3.0), 2.0, false),
+    ] {
+        assert_eq!(
+            circle_intersects_circle(v(0.0, 0.0), 2.0, center, radius),
+            hit,
+            "{center:?} {radius}"
+        );
+    }
+}
+
+#[test]
+fn test_circle_intersects_rect() {
+    for (center, radius, hit) in [
+        (v(5.0, 2.0), 1.0, true),   // Inside
+        (v(5.0, 2.0), 100.0, true), // Containing the rectangle
+        (v(12.0, 2.0), 2.0, true),  // Tangent to the right edge
+        (v(12.1, 2.0), 2.0, false),
+        (v(5.0, -0.5), 1.0, true), // Across the top edge
+        (v(13.0, 8.0), 5.0, true), // Tangent to the bottom right corner
+        (v(13.0, 8.0), 4.9, false),
+        (v(-1.0, -1.0), 1.0, false), // Beside a corner, within the extended edges
+    ] {
+        assert_eq!(
+            circle_intersects_rect(center, radius, &RECT),
+            hit,
+            "{center:?} {radius}"
+        );
+    }
+}
+
+#[test]
+fn test_segment_intersects_rect() {
+    for (from, to, hit) in [
+        (v(1.0, 1.0), v(2.0, 2.0), true),    // Inside
+        (v(-5.0, 2.0), v(15.0, 2.0), true),  // Through
+        (v(-5.0, 2.0), v(0.0, 2.0), true),   // Ending on an edge
+        (v(-5.0, 2.0), v(-0.1, 2.0), false), // Stopping short
+        (v(-1.0, 5.0), v(5.0, -1.0), true),  // Across a corner
+        (v(-2.0, 1.0), v(1.0, -2.0), false), // Beside a corner
+        (v(-5.0, 4.0), v(15.0, 4.0), true),  // Along an edge
+        (v(5.0, 2.0), v(5.0, 2.0), true),    // A point inside
+        (v(5.0, 6.0), v(5.0, 6.0), false),   // A point outside
+    ] {
+        assert_eq!(
+            segment_intersects_rect(from, to, &RECT),
+            hit,
+            "{from:?} {to:?}"
+        );
+        assert_eq!(
+            segment_intersects_rect(to, from, &RECT),
+            hit,
+            "{to:?} {from:?}"
+        );
+    }
+}
+
+#[test]
+fn test_segment_intersects_segment() {
+    let cases = [
+        // Crossing in the middle.
+        (
+            v(0.0, 0.0),
+            v(4.0, 4.0),
+            v(0.0, 4.0),
+            v(4.0, 0.0),
+            Some(v(2.0, 2.0)),
+        ),
+        // Ending on the other segment.
+        (
+            v(0.0, 0.0),
+            v(2.0, 2.0),
+            v(0.0, 4.0),
+            v(4.0, 0.0),
+            Some(v(2.0, 2.0)),
+        ),
+        // Lines crossing beyond the end of a segment.
+        (v(0.0, 0.0), v(1.0, 1.0), v(0.0, 4.0), v(4.0, 0.0), None),
+        // Parallel.
+        (v(0.0, 0.0), v(4.0, 0.0), v(0.0, 1.0), v(4.0, 1.0), None),
+        // Collinear and overlapping: the overlap point closest to the start of the first.
+        (
+            v(0.0, 0.0),
+            v(4.0, 0.0),
+            v(6.0, 0.0),
+            v(2.0, 0.0),
+            Some(v(2.0, 0.0)),
+        ),
+        (
+            v(3.0, 0.0),
+            v(0.0, 0.0),
+            v(-1.0, 0.0),
+            v(1.0, 0.0),
+            Some(v(1.0, 0.0)),
+        ),
+        (
+            v(0.0, 0.0),
+            v(4.0, 0.0),
+            v(-1.0, 0.0),
+            v(5.0, 0.0),
+            Some(v(0.0, 0.0)),
+        ),
+        // Collinear and touching end to end, or apart.
+        (
+            v(0.0, 0.0),
+            v(2.0, 0.0),
+            v(2.0, 0.0),
+            v(3.0, 0.0),
+            Some(v(2.0, 0.0)),
+        ),
+        (v(0.0, 0.0), v(2.0, 0.0), v(3.0, 0.0), v(4.0, 0.0), None),
+        // Points.
+        (
+            v(1.0, 1.0),
+            v(1.0, 1.0),
+            v(0.0, 0.0),
+            v(2.0, 2.0),
+            Some(v(1.0, 1.0)),
+        ),
+        (v(1.0, 1.0), v(1.0, 1.0), v(0.0, 0.0), v(2.0, 0.0), None),
+        (
+            v(0.0, 0.0),
+            v(2.0, 2.0),
+            v(1.0, 1.0),
+            v(1.0, 1.0),
+            Some(v(1.0, 1.0)),
+        ),
+        (
+            v(1.0, 1.0),
+            v(1.0, 1.0),
+            v(1.0, 1.0),
+            v(1.0, 1.0),
+            Some(v(1.0, 1.0)),
+        ),
+        (v(1.0, 1.0), v(1.0, 1.0), v(2.0, 1.0), v(2.0, 1.0), None),
+    ];
+    for (a_from, a_to, b_from, b_to, expected) in cases {
+        assert_eq!(
+            segment_intersects_segment(a_from, a_to, b_from, b_to),
+            expected,
+            "{a_from:?} {a_to:?} {b_from:?} {b_to:?}"
+        );
+    }
+}
+
+#[test]
+fn test_swept_circle_intersects_rect() {
+    let cases = [
+        // Moving straight at the left edge, touching it after 3 of the 6 units.
+        (v(-4.0, 2.0), v(6.0, 0.0), Some(0.5)),
+        // Stopping short of it, or moving away.
+        (v(-4.0, 2.0), v(2.0, 0.0), None),
+        (v(-4.0, 2.0), v(-6.0, 0.0), None),
+        // Already overlapping.
+        (v(-0.5, 2.0), v(-6.0, 0.0), Some(0.0)),
+        // Not moving.
+        (v(-4.0, 2.0), v(0.0, 0.0), None),
+        // Hitting the top edge from above.
+        (v(5.0, -11.0), v(0.0, 20.0), Some(0.5)),
+        // Moving diagonally at the bottom right corner, touching it at a distance of 1.
+        (
+            v(10.0 + 3.0, 4.0 + 3.0),
+            v(-6.0, -6.0),
+            Some((3.0 - std::f32::consts::FRAC_1_SQRT_2) / 6.0),
+        ),
+        // Passing by the top right corner, closer than the radius on each axis at (10.8, -0.8)
+        // but never touching it.
+        (v(8.8, -2.8), v(4.0, 4.0), None),
+        // Sliding along the top edge at exactly the radius counts as touching.
+        (v(-5.0, -1.0), v(20.0, 0.0), Some(0.25)),
+    ];
+    for (center, velocity, expected) in cases {
+        let time = swept_circle_intersects_rect(center, 1.0, velocity, &RECT);
+        match (time, expected) {
+            (Some(time), Some(expected)) => {
+                assert!(
+                    (time - expected).abs() < 1e-5,
+                    "{center:?} {velocity:?}: {time}"
+                )
+            }
+            _ => assert_eq!(time, expected, "{center:?} {velocity:?}"),
+        }
+        if let Some(time) = time {
+            // The circle touches the rectangle at the time of impact.
+            let center = center + velocity * time;
+            assert!(circle_intersects_rect(center, 1.0 + 1e-4, &RECT));
+        }
+    }
+}
+
+#[test]
+fn test_swept_circle_does_not_tunnel() {
+    // A fast ball crossing a thin paddle in one step: both positions are clear of the paddle,
+    // so only the swept test sees the collision.
+    let paddle = Rect {
+        x: 50.0,
+        y: 0.0,
+        width: 2.0,
+        height: 40.0,
+    };
+    let (start, velocity, radius) = (v(40.0, 20.0), v(30.0, 2.0), 1.5);
+    assert!(!circle_intersects_rect(start, radius, &paddle));
+    assert!(!circle_intersects_rect(start + velocity, radius, &paddle));
+
+    let time = swept_circle_intersects_rect(start, radius, velocity, &paddle).unwrap();
+    let impact = start + velocity * time;
+    assert!((impact.x - (50.0 - radius)).abs() < 1e-4);
+    assert!(swept_circle_intersects_rect(start, radius, v(30.0, 40.0), &paddle).is_some());
+    assert!(swept_circle_intersects_rect(start, radius, v(-30.0, 2.0), &paddle).is_none());
+}
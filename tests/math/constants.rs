@@ -0,0 +1,67 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::math::{Matrix3x3, Matrix4x4, Vector2, Vector3, Vector4};
+
+static VECTOR2_TABLE: [Vector2<i32>; 4] = [
+    Vector2::ZERO,
+    Vector2::ONE,
+    Vector2::zero(),
+    Vector2::new(1, -1),
+];
+
+static VECTOR3_TABLE: [Vector3<f32>; 3] =
+    [Vector3::ZERO, Vector3::ONE, Vector3::new(1.0, 2.0, 3.0)];
+
+static VECTOR4_TABLE: [Vector4<u64>; 3] = [Vector4::ZERO, Vector4::one(), Vector4::new(1, 2, 3, 4)];
+
+static MATRIX3X3_TABLE: [Matrix3x3<f64>; 3] = [
+    Matrix3x3::IDENTITY,
+    Matrix3x3::ZERO,
+    Matrix3x3::from_mat([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]),
+];
+
+static TRANSFORM: Matrix4x4<f32> = Matrix4x4::IDENTITY;
+
+const MATRIX4X4_TABLE: [Matrix4x4<i32>; 3] = [
+    Matrix4x4::identity(),
+    Matrix4x4::zero(),
+    Matrix4x4::new([Vector4::ONE, Vector4::ZERO, Vector4::ONE, Vector4::ZERO]),
+];
+
+#[test]
+fn test_vector_constants() {
+    assert_eq!(VECTOR2_TABLE[0], Vector2::new(0, 0));
+    assert_eq!(VECTOR2_TABLE[1], Vector2::new(1, 1));
+    assert_eq!(VECTOR2_TABLE[2], Vector2::new(0, 0));
+    assert_eq!(VECTOR3_TABLE[0], Vector3::new(0.0, 0.0, 0.0));
+    assert_eq!(VECTOR3_TABLE[1], Vector3::new(1.0, 1.0, 1.0));
+    assert_eq!(VECTOR4_TABLE[0], Vector4::new(0, 0, 0, 0));
+    assert_eq!(VECTOR4_TABLE[1], Vector4::new(1, 1, 1, 1));
+}
+
+#[test]
+fn test_matrix_constants() {
+    assert_eq!(MATRIX3X3_TABLE[0], Matrix3x3::<f64>::identity());
+    assert_eq!(MATRIX3X3_TABLE[1], Matrix3x3::<f64>::zero());
+    assert_eq!(TRANSFORM, Matrix4x4::<f32>::identity());
+    assert_eq!(MATRIX4X4_TABLE[0], Matrix4x4::<i32>::IDENTITY);
+    assert_eq!(MATRIX4X4_TABLE[1], Matrix4x4::<i32>::ZERO);
+    assert_eq!(MATRIX4X4_TABLE[2][0], Vector4::ONE);
+}
@@ -0,0 +1,205 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::math::curve::*;
+use sky_labs::math::{Vector2, Vector3};
+
+fn assert_near<P: CurvePoint>(result: P, expected: P, tolerance: f64) {
+    let distance = result.distance(&expected);
+    assert!(distance <= tolerance, "off by {distance}");
+}
+
+#[test]
+fn test_bezier_interpolates_endpoints() {
+    let (p0, p1, p2, p3) = (
+        Vector2::new(0.0f32, 0.0),
+        Vector2::new(1.0f32, 3.0),
+        Vector2::new(4.0f32, -2.0),
+        Vector2::new(5.0f32, 1.0),
+    );
+    assert_eq!(bezier(p0, p1, p2, p3, 0.0), p0);
+    assert_eq!(bezier(p0, p1, p2, p3, 1.0), p3);
+    assert_eq!(quadratic_bezier(p0, p1, p2, 0.0), p0);
+    assert_eq!(quadratic_bezier(p0, p1, p2, 1.0), p2);
+
+    // Control points on a line spaced evenly move along it at a uniform speed.
+    let line = |x: f64| Vector3::new(x, 2.0 * x, -x);
+    let point = bezier(line(0.0), line(1.0), line(2.0), line(3.0), 0.25);
+    assert_near(point, line(0.75), 1e-12);
+    let point = quadratic_bezier(line(0.0), line(1.0), line(2.0), 0.25);
+    assert_near(point, line(0.5), 1e-12);
+}
+
+#[test]
+fn test_bezier_tangent() {
+    let (p0, p1, p2, p3) = (
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 2.0, 0.0),
+        Vector3::new(3.0, 2.0, 1.0),
+        Vector3::new(4.0, 0.0, 1.0),
+    );
+    // The analytic derivatives at the ends point to the nearest control point.
+    assert_near(bezier_tangent(p0, p1, p2, p3, 0.0), (p1 - p0) * 3.0, 1e-12);
+    assert_near(bezier_tangent(p0, p1, p2, p3, 1.0), (p3 - p2) * 3.0, 1e-12);
+    assert_near(
+        quadratic_bezier_tangent(p0, p1, p2, 0.0),
+        (p1 - p0) * 2.0,
+        1e-12,
+    );
+    assert_near(
+        quadratic_bezier_tangent(p0, p1, p2, 1.0),
+        (p2 - p1) * 2.0,
+        1e-12,
+    );
+
+    // And match finite differences in between.
+    let h = 1e-6;
+    for t in [0.2, 0.5, 0.9] {
+        let difference =
+            (bezier(p0, p1, p2, p3, t + h) - bezier(p0, p1, p2, p3, t - h)) * (0.5 / h);
+        assert_near(bezier_tangent(p0, p1, p2, p3, t), difference, 1e-6);
+        let difference =
+            (quadratic_bezier(p0, p1, p2, t + h) - quadratic_bezier(p0, p1, p2, t - h)) * (0.5 / h);
+        assert_near(quadratic_bezier_tangent(p0, p1, p2, t), difference, 1e-6);
+    }
+}
+
+#[test]
+fn test_catmull_rom_interpolates_endpoints() {
+    let (p0, p1, p2, p3) = (
+        Vector2::new(0.0f64, 0.0),
+        Vector2::new(1.0, 4.0),
+        Vector2::new(5.0, 4.0),
+        Vector2::new(6.0, 0.0),
+    );
+    assert_near(catmull_rom(p0, p1, p2, p3, 0.0), p1, 1e-12);
+    assert_near(catmull_rom(p0, p1, p2, p3, 1.0), p2, 1e-12);
+
+    // Symmetric points give a symmetric segment, level in its middle.
+    let middle = catmull_rom(p0, p1, p2, p3, 0.5);
+    assert!((middle.x - 3.0).abs() < 1e-12 && middle.y > 4.0);
+    assert!(catmull_rom_tangent(p0, p1, p2, p3, 0.5).y.abs() < 1e-12);
+
+    // A segment between coincident points stays there.
+    assert_eq!(catmull_rom(p0, p1, p1, p3, 0.5), p1);
+}
+
+#[test]
+fn test_catmull_rom_tangent() {
+    let (p0, p1, p2, p3) = (
+        Vector3::new(-1.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(4.0, 1.0, 2.0),
+        Vector3::new(5.0, 9.0, 2.0),
+    );
+    let h = 1e-6;
+    for t in [0.0, 0.3, 0.7, 1.0] {
+        let difference =
+            (catmull_rom(p0, p1, p2, p3, t + h) - catmull_rom(p0, p1, p2, p3, t - h)) * (0.5 / h);
+        assert_near(catmull_rom_tangent(p0, p1, p2, p3, t), difference, 1e-6);
+    }
+
+    // A duplicated endpoint gives the tangent of its neighbor mirrored through it.
+    let mirrored = p1 * 2.0 - p2;
+    assert_near(
+        catmull_rom_tangent(p1, p1, p2, p3, 0.0),
+        catmull_rom_tangent(mirrored, p1, p2, p3, 0.0),
+        1e-12,
+    );
+    assert_near(catmull_rom_tangent(p1, p1, p2, p2, 0.5), p2 - p1, 1e-12);
+}
+
+#[test]
+fn test_catmull_rom_spline() {
+    let points = [
+        Vector2::new(0.0f32, 0.0),
+        Vector2::new(2.0f32, 1.0),
+        Vector2::new(3.0f32, 5.0),
+        Vector2::new(7.0f32, 5.0),
+    ];
+    // Passes through every point, at equal steps of `t`.
+    for (i, point) in points.iter().enumerate() {
+        assert_near(catmull_rom_spline(&points, i as f32 / 3.0), *point, 1e-5);
+    }
+    assert_eq!(catmull_rom_spline(&points, -1.0), points[0]);
+    assert_near(catmull_rom_spline(&points, 2.0), points[3], 1e-5);
+
+    // The end segments duplicate the endpoints.
+    assert_eq!(
+        catmull_rom_segment(&points, 0),
+        [points[0], points[0], points[1], points[2]]
+    );
+    assert_eq!(catmull_rom_segment(&points, 1), points);
+    assert_eq!(
+        catmull_rom_segment(&points, 2),
+        [points[1], points[2], points[3], points[3]]
+    );
+    let [p0, p1, p2, p3] = catmull_rom_segment(&points, 2);
+    assert_near(
+        catmull_rom_spline(&points, 5.0 / 6.0),
+        catmull_rom(p0, p1, p2, p3, 0.5),
+        1e-5,
+    );
+
+    assert_eq!(catmull_rom_spline(&points[..1], 0.5), points[0]);
+}
+
+#[test]
+#[should_panic]
+fn test_catmull_rom_segment_out_of_points() {
+    let points = [Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0)];
+    catmull_rom_segment(&points, 1);
+}
+
+#[test]
+fn test_arc_length_parameterize() {
+    // A straight line at a varying speed: equal distances map to uneven steps of `t`.
+    let (p0, p1, p2, p3) = (
+        Vector2::new(0.0f64, 0.0),
+        Vector2::new(0.1, 0.0),
+        Vector2::new(0.2, 0.0),
+        Vector2::new(10.0, 0.0),
+    );
+    let curve = |t| bezier(p0, p1, p2, p3, t);
+    let table = arc_length_parameterize(curve, 256);
+    assert!((table.length() - 10.0).abs() < 1e-9);
+    assert_eq!(table.t_at_distance(0.0), 0.0);
+    assert_eq!(table.t_at_distance(10.0), 1.0);
+    assert_eq!(table.t_at_distance(-1.0), 0.0);
+    assert_eq!(table.t_at_distance(20.0), 1.0);
+
+    let mut previous = 0.0;
+    for step in 1..=100 {
+        let distance = step as f64 / 10.0;
+        let t = table.t_at_distance(distance);
+        assert!(t >= previous, "t goes back at {distance}");
+        previous = t;
+        assert!(
+            (curve(t).x - distance).abs() < 1e-3,
+            "{} at {distance}",
+            curve(t).x
+        );
+    }
+
+    // A curve that stops in place keeps mapping distances to increasing `t`.
+    let table = arc_length_parameterize(|t: f32| Vector3::new(t.min(0.5), 0.0, 0.0), 10);
+    assert_eq!(table.length(), 0.5);
+    assert_eq!(table.t_at_distance(0.25), 0.25);
+    assert!(table.t_at_distance(0.5) <= 0.5);
+}
@@ -0,0 +1,172 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::math::{Matrix3x3, Matrix4x4, Rect, Size, Vector2};
+use windows::Foundation::Numerics::Matrix3x2;
+use windows::Win32::Foundation::RECT;
+use windows::Win32::Graphics::Direct2D::Common::{D2D_MATRIX_3X2_F, D2D_RECT_F};
+use windows::Win32::Graphics::DirectWrite::DWRITE_MATRIX;
+use windows::Win32::Graphics::Dxgi::DXGI_SWAP_CHAIN_DESC1;
+
+fn assert_near_3x3(result: &Matrix3x3<f32>, expected: &Matrix3x3<f32>) {
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!(
+                (result[(i, j)] - expected[(i, j)]).abs() <= f32::EPSILON,
+                "{result:?} != {expected:?}"
+            );
+        }
+    }
+}
+
+fn translated_rotation() -> Matrix3x3<f32> {
+    Matrix3x3::make_translation_2d(5.0, -3.0) * Matrix3x3::make_rotation_2d(0.7)
+}
+
+#[test]
+fn test_matrix3x3_to_d2d_matrix() {
+    let matrix = translated_rotation();
+    let d2d: D2D_MATRIX_3X2_F = matrix.into();
+
+    // Direct2D transforms row vectors, so the point must land in the same place.
+    let point = Vector2::new(2.0, 1.0);
+    let fields = unsafe { d2d.Anonymous.Anonymous1 };
+    let transformed = Vector2::new(
+        point.x * fields.m11 + point.y * fields.m21 + fields.dx,
+        point.x * fields.m12 + point.y * fields.m22 + fields.dy,
+    );
+    assert!((transformed - matrix * point).magnitude() < 1e-5);
+    assert_eq!(fields.dx, 5.0);
+    assert_eq!(fields.dy, -3.0);
+
+    assert_near_3x3(&Matrix3x3::from(d2d), &matrix);
+}
+
+#[test]
+fn test_matrix3x3_to_numerics_matrix() {
+    let matrix = translated_rotation();
+    let numerics: Matrix3x2 = matrix.into();
+    assert_eq!(numerics.M31, 5.0);
+    assert_eq!(numerics.M32, -3.0);
+    assert_eq!(numerics.M12, matrix[1][0]);
+    assert_near_3x3(&Matrix3x3::from(numerics), &matrix);
+}
+
+#[test]
+fn test_matrix3x3_to_dwrite_matrix() {
+    let matrix = translated_rotation();
+    let dwrite: DWRITE_MATRIX = matrix.into();
+    assert_eq!(dwrite.dx, 5.0);
+    assert_eq!(dwrite.dy, -3.0);
+    assert_eq!(dwrite.m12, matrix[1][0]);
+    assert_eq!(dwrite.m21, matrix[0][1]);
+    assert_near_3x3(&Matrix3x3::from(dwrite), &matrix);
+
+    let identity: DWRITE_MATRIX = Matrix3x3::identity().into();
+    assert_eq!(
+        identity,
+        DWRITE_MATRIX {
+            m11: 1.0,
+            m12: 0.0,
+            m21: 0.0,
+            m22: 1.0,
+            dx: 0.0,
+            dy: 0.0,
+        }
+    );
+}
+
+#[test]
+fn test_matrix4x4_to_dwrite_matrix() {
+    let matrix = Matrix4x4::make_translation(5.0, -3.0, 0.0) * Matrix4x4::make_rotation_z(0.7);
+    let dwrite: DWRITE_MATRIX = matrix.into();
+    assert_eq!(dwrite.dx, 5.0);
+    assert_eq!(dwrite.dy, -3.0);
+    assert_near_3x3(&Matrix3x3::from(dwrite), &translated_rotation());
+
+    let round_trip = Matrix4x4::from(dwrite);
+    for i in 0..4 {
+        for j in 0..4 {
+            assert!(
+                (round_trip[(i, j)] - matrix[(i, j)]).abs() <= f32::EPSILON,
+                "{round_trip:?} != {matrix:?}"
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn test_projective_matrix3x3_to_dwrite_matrix() {
+    let mut matrix = Matrix3x3::<f32>::identity();
+    matrix[2][0] = 0.5;
+    let _: DWRITE_MATRIX = matrix.into();
+}
+
+#[test]
+fn test_rect_to_d2d_rect() {
+    let rect = Rect {
+        x: -10.0,
+        y: 20.5,
+        width: 30.0,
+        height: 40.0,
+    };
+    let d2d: D2D_RECT_F = rect.into();
+    assert_eq!(d2d.left, -10.0);
+    assert_eq!(d2d.top, 20.5);
+    assert_eq!(d2d.right, 20.0);
+    assert_eq!(d2d.bottom, 60.5);
+    assert_eq!(Rect::from(d2d), rect);
+}
+
+#[test]
+fn test_rect_to_win32_rect() {
+    let rect = Rect {
+        x: -800,
+        y: -600,
+        width: 1024,
+        height: 768,
+    };
+    let win32: RECT = rect.into();
+    assert_eq!(
+        win32,
+        RECT {
+            left: -800,
+            top: -600,
+            right: 224,
+            bottom: 168,
+        }
+    );
+    assert_eq!(Rect::from(win32), rect);
+
+    let empty = RECT::default();
+    assert_eq!(Rect::from(empty), Rect::default());
+}
+
+#[test]
+fn test_size_to_swap_chain_desc() {
+    let desc = Size::new(1280, 720).to_swap_chain_desc(DXGI_SWAP_CHAIN_DESC1 {
+        BufferCount: 2,
+        ..Default::default()
+    });
+    assert_eq!((desc.Width, desc.Height), (1280, 720));
+    assert_eq!(desc.BufferCount, 2);
+    assert_eq!(Size::from(&desc), Size::new(1280, 720));
+}
@@ -0,0 +1,154 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::math::easing::*;
+use sky_labs::math::{Vector2, Vector3};
+
+const BACK: Easing = Easing::BackOut {
+    overshoot: DEFAULT_BACK_OVERSHOOT,
+};
+
+/// Every easing, and whether it only increases.
+const ALL: [(Easing, bool); 25] = [
+    (Easing::Linear, true),
+    (Easing::QuadIn, true),
+    (Easing::QuadOut, true),
+    (Easing::QuadInOut, true),
+    (Easing::CubicIn, true),
+    (Easing::CubicOut, true),
+    (Easing::CubicInOut, true),
+    (Easing::QuartIn, true),
+    (Easing::QuartOut, true),
+    (Easing::QuartInOut, true),
+    (Easing::SineIn, true),
+    (Easing::SineOut, true),
+    (Easing::SineInOut, true),
+    (Easing::ExpoIn, true),
+    (Easing::ExpoOut, true),
+    (Easing::ExpoInOut, true),
+    (
+        Easing::BackIn {
+            overshoot: DEFAULT_BACK_OVERSHOOT,
+        },
+        false,
+    ),
+    (BACK, false),
+    (Easing::BackInOut { overshoot: 3.0 }, false),
+    (Easing::BounceIn, false),
+    (Easing::BounceOut, false),
+    (Easing::BounceInOut, false),
+    (Easing::ElasticIn, false),
+    (Easing::ElasticOut, false),
+    (Easing::ElasticInOut, false),
+];
+
+#[test]
+fn test_easing_endpoints_are_exact() {
+    for (kind, _) in ALL {
+        assert_eq!(ease(kind, 0.0), 0.0, "{kind:?}");
+        assert_eq!(ease(kind, 1.0), 1.0, "{kind:?}");
+        // Progress outside the animation is clamped.
+        assert_eq!(ease(kind, -0.5), 0.0, "{kind:?}");
+        assert_eq!(ease(kind, 3.0), 1.0, "{kind:?}");
+    }
+}
+
+#[test]
+fn test_easing_monotonic() {
+    for (kind, _) in ALL.into_iter().filter(|(_, monotonic)| *monotonic) {
+        let mut previous = 0.0;
+        for step in 1..=1000 {
+            let value = ease(kind, step as f64 / 1000.0);
+            assert!(value >= previous, "{kind:?} decreases at step {step}");
+            assert!(value <= 1.0, "{kind:?} overshoots at step {step}");
+            previous = value;
+        }
+    }
+}
+
+#[test]
+fn test_easing_in_out_symmetry() {
+    for kind in [
+        Easing::QuadInOut,
+        Easing::CubicInOut,
+        Easing::QuartInOut,
+        Easing::SineInOut,
+        Easing::ExpoInOut,
+        Easing::BackInOut { overshoot: 3.0 },
+        Easing::BounceInOut,
+        Easing::ElasticInOut,
+    ] {
+        assert!((ease(kind, 0.5) - 0.5).abs() < 1e-12, "{kind:?}");
+        for t in [0.1, 0.3, 0.45] {
+            let mirrored = 1.0 - ease(kind, 1.0 - t);
+            assert!((ease(kind, t) - mirrored).abs() < 1e-12, "{kind:?} at {t}");
+        }
+    }
+}
+
+#[test]
+fn test_easing_functions() {
+    assert_eq!(linear(0.25), 0.25);
+    assert_eq!(quad_in(0.5), 0.25);
+    assert_eq!(quad_out(0.5), 0.75);
+    assert_eq!(cubic_in(0.5), 0.125);
+    assert_eq!(cubic_in_out(0.25), 0.0625);
+    assert_eq!(quart_in(0.5), 0.0625);
+    assert!((sine_out(1.0 / 3.0) - 0.5).abs() < 1e-12);
+    assert!((expo_in(0.9) - 0.5).abs() < 1e-12);
+    assert_eq!(ease(Easing::default(), 0.75), 0.75);
+
+    // The back easings go past their target, further with more overshoot.
+    assert!(back_in(0.2) < 0.0);
+    assert!(back_out(0.8) > 1.0);
+    assert!(back_out_with_overshoot(0.8, 3.0) > back_out(0.8));
+    assert_eq!(back_in_with_overshoot(0.5, 0.0), cubic_in(0.5));
+    assert_eq!(ease(BACK, 0.8), back_out(0.8));
+
+    // The bounce reaches the target at the end of each fall.
+    assert!((bounce_out(1.0 / 2.75) - 1.0).abs() < 1e-12);
+    assert!(bounce_out(1.5 / 2.75) < 1.0);
+    assert!((bounce_in(0.25) - (1.0 - bounce_out(0.75))).abs() < 1e-12);
+
+    // The elastic oscillates around the target after snapping past it.
+    assert!(elastic_out(0.1) > 1.0);
+    assert!((0..100).any(|step| elastic_out(step as f64 / 100.0) < 1.0 && step > 10));
+    assert!(elastic_in(0.9) < 0.0);
+}
+
+#[test]
+fn test_vector_ease_to() {
+    let from = Vector2::new(0.0, 10.0);
+    let to = Vector2::new(4.0, 2.0);
+    assert_eq!(
+        from.ease_to(&to, Easing::QuadIn, 0.5),
+        Vector2::new(1.0, 8.0)
+    );
+    assert_eq!(from.ease_to(&to, Easing::QuadIn, 2.0), to);
+    assert_eq!(from.ease_to(&to, Easing::QuadIn, -1.0), from);
+
+    let from = Vector3::new(0.0f32, 0.0, 0.0);
+    let to = Vector3::new(8.0f32, -8.0, 4.0);
+    assert_eq!(
+        from.ease_to(&to, Easing::CubicIn, 0.5),
+        Vector3::new(1.0, -1.0, 0.5)
+    );
+    // Overshooting easings extrapolate past the target.
+    assert!(from.ease_to(&to, BACK, 0.8).x > 8.0);
+}
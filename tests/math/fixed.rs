@@ -0,0 +1,148 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::math::{Abs, AsDouble, Fixed32, FromDouble, Number, Vector2, Vector3};
+
+fn fixed(value: f64) -> Fixed32 {
+    Fixed32::from_double(value)
+}
+
+#[test]
+fn test_fixed32_conversions() {
+    assert_eq!(Fixed32::ONE.to_bits(), 65536);
+    assert_eq!(Fixed32::from_int(-3).as_double(), -3.0);
+    assert_eq!(fixed(1.5).to_bits(), 98304);
+    assert_eq!(Fixed32::from_bits(98304).as_double(), 1.5);
+    assert_eq!(fixed(-1.5).to_int(), -2);
+    assert_eq!(fixed(1.75).to_int(), 1);
+    assert_eq!(Fixed32::EPSILON.as_double(), 1.0 / 65536.0);
+    assert_eq!(fixed(f64::NAN), Fixed32::ZERO);
+}
+
+#[test]
+fn test_fixed32_identities() {
+    let values = [
+        fixed(0.0),
+        fixed(1.0),
+        fixed(-2.25),
+        fixed(123.456),
+        fixed(-0.001),
+    ];
+    for &a in &values {
+        assert_eq!(a + Fixed32::ZERO, a);
+        assert_eq!(a - a, Fixed32::ZERO);
+        assert_eq!(a * Fixed32::ONE, a);
+        assert_eq!(a / Fixed32::ONE, a);
+        assert_eq!(a * Fixed32::ZERO, Fixed32::ZERO);
+        assert_eq!(-(-a), a);
+        assert_eq!(a.abs(), if a < Fixed32::ZERO { -a } else { a });
+        for &b in &values {
+            assert_eq!(a + b, b + a);
+            assert_eq!(a * b, b * a);
+            assert_eq!(a + b - b, a);
+        }
+    }
+}
+
+#[test]
+fn test_fixed32_assign_operators() {
+    let mut value = fixed(2.0);
+    value += fixed(1.0);
+    value *= fixed(4.0);
+    value -= fixed(2.0);
+    value /= fixed(5.0);
+    assert_eq!(value, fixed(2.0));
+}
+
+#[test]
+fn test_fixed32_saturates() {
+    assert_eq!(Fixed32::MAX + Fixed32::EPSILON, Fixed32::MAX);
+    assert_eq!(Fixed32::MIN - Fixed32::EPSILON, Fixed32::MIN);
+    assert_eq!(fixed(30000.0) * fixed(2.0), Fixed32::MAX);
+    assert_eq!(fixed(30000.0) * fixed(-2.0), Fixed32::MIN);
+    assert_eq!(fixed(30000.0) / fixed(0.5), Fixed32::MAX);
+    assert_eq!(-Fixed32::MIN, Fixed32::MAX);
+    assert_eq!(Fixed32::MIN.abs(), Fixed32::MAX);
+    assert_eq!(Fixed32::from_int(40000), Fixed32::MAX);
+    assert_eq!(fixed(-1e9), Fixed32::MIN);
+}
+
+#[test]
+fn test_fixed32_checked() {
+    assert_eq!(Fixed32::MAX.checked_add(Fixed32::EPSILON), None);
+    assert_eq!(Fixed32::MIN.checked_sub(Fixed32::EPSILON), None);
+    assert_eq!(fixed(30000.0).checked_mul(fixed(2.0)), None);
+    assert_eq!(fixed(1.0).checked_div(Fixed32::ZERO), None);
+    assert_eq!(fixed(1.5).checked_add(fixed(2.0)), Some(fixed(3.5)));
+    assert_eq!(fixed(1.5).checked_mul(fixed(2.0)), Some(fixed(3.0)));
+    assert_eq!(fixed(3.0).checked_div(fixed(2.0)), Some(fixed(1.5)));
+}
+
+#[test]
+#[should_panic]
+fn test_fixed32_divide_by_zero_panics() {
+    let _ = fixed(1.0) / Fixed32::ZERO;
+}
+
+#[test]
+fn test_fixed32_matches_f64() {
+    let step = Fixed32::EPSILON.as_double();
+    let values = [0.5, -3.125, 7.3, -0.07, 100.01, 1.0 / 3.0];
+    for &a in &values {
+        for &b in &values {
+            let (fa, fb) = (fixed(a), fixed(b));
+            let (a, b) = (fa.as_double(), fb.as_double());
+            assert!(((fa + fb).as_double() - (a + b)).abs() <= step);
+            assert!(((fa - fb).as_double() - (a - b)).abs() <= step);
+            assert!(((fa * fb).as_double() - a * b).abs() <= step);
+            assert!(((fa / fb).as_double() - a / b).abs() <= step);
+        }
+    }
+}
+
+#[test]
+fn test_fixed32_sqrt() {
+    assert_eq!(fixed(4.0).sqrt(), fixed(2.0));
+    assert_eq!(fixed(0.25).sqrt(), fixed(0.5));
+    assert_eq!(Fixed32::ZERO.sqrt(), Fixed32::ZERO);
+    for &value in &[2.0, 0.1, 12345.678, Fixed32::MAX.as_double()] {
+        let root = fixed(value).sqrt().as_double();
+        assert!((root - fixed(value).as_double().sqrt()).abs() <= Fixed32::EPSILON.as_double());
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_fixed32_sqrt_negative_panics() {
+    let _ = fixed(-1.0).sqrt();
+}
+
+#[test]
+fn test_fixed32_vectors() {
+    let a = Vector2::new(fixed(3.0), fixed(4.0));
+    assert_eq!(a.fixed_magnitude(), fixed(5.0));
+    assert_eq!(a + a, Vector2::new(fixed(6.0), fixed(8.0)));
+    let b = Vector3::new(fixed(2.0), fixed(-3.0), fixed(6.0));
+    assert_eq!(b.fixed_magnitude(), fixed(7.0));
+    assert_eq!(b.dot(&b), fixed(49.0));
+    assert_eq!(
+        Vector2::new(Fixed32::MAX, Fixed32::MAX).fixed_magnitude(),
+        Fixed32::MAX
+    );
+}
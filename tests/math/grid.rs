@@ -0,0 +1,211 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::math::grid::Grid;
+use sky_labs::math::{Direction, Rect, Size, Vector2};
+
+fn c(x: i32, y: i32) -> Vector2<i32> {
+    Vector2::new(x, y)
+}
+
+/// A grid of 4 columns and 3 rows of 10x20 cells starting at (-20, -30).
+fn grid() -> Grid {
+    Grid::new(
+        Size::new(10.0, 20.0),
+        Vector2::new(-20.0, -30.0),
+        Vector2::new(4, 3),
+    )
+}
+
+fn rect(x: f32, y: f32, width: f32, height: f32) -> Rect<f32> {
+    Rect {
+        x,
+        y,
+        width,
+        height,
+    }
+}
+
+#[test]
+fn test_direction_offset() {
+    assert!(Direction::North.offset() == c(0, -1));
+    assert!(Direction::East.offset() == c(1, 0));
+    assert!(Direction::South.offset() == c(0, 1));
+    assert!(Direction::West.offset() == c(-1, 0));
+    for direction in Direction::ALL {
+        assert_eq!(direction.opposite().opposite(), direction);
+        assert!(direction.offset() + direction.opposite().offset() == c(0, 0));
+    }
+}
+
+#[test]
+fn test_grid_cell_to_world() {
+    let grid = grid();
+    assert_eq!(grid.cell_to_world(c(0, 0)), rect(-20.0, -30.0, 10.0, 20.0));
+    assert_eq!(grid.cell_to_world(c(3, 2)), rect(10.0, 10.0, 10.0, 20.0));
+    assert_eq!(grid.cell_to_world(c(-1, 0)), rect(-30.0, -30.0, 10.0, 20.0));
+    assert!(grid.cell_center(c(1, 1)) == Vector2::new(-5.0, 0.0));
+}
+
+#[test]
+fn test_grid_world_to_cell() {
+    let grid = grid();
+    for (point, cell) in [
+        (Vector2::new(-20.0, -30.0), Some(c(0, 0))), // Top left corner
+        (Vector2::new(-15.0, -25.0), Some(c(0, 0))),
+        (Vector2::new(-10.0, -10.0), Some(c(1, 1))), // Boundaries belong to the next cell
+        (Vector2::new(-0.5, -0.5), Some(c(1, 1))),   // Negative coordinates
+        (Vector2::new(19.999, 29.999), Some(c(3, 2))),
+        (Vector2::new(20.0, 0.0), None), // Right edge
+        (Vector2::new(0.0, 30.0), None), // Bottom edge
+        (Vector2::new(-20.001, 0.0), None),
+        (Vector2::new(0.0, -30.001), None),
+        (Vector2::new(f32::NAN, 0.0), None),
+        (Vector2::new(0.0, f32::NAN), None),
+        (Vector2::new(f32::INFINITY, 0.0), None),
+        (Vector2::new(0.0, f32::NEG_INFINITY), None),
+    ] {
+        assert!(
+            grid.world_to_cell(point) == cell,
+            "{:?}",
+            (point.x, point.y)
+        );
+    }
+    for x in 0..4 {
+        for y in 0..3 {
+            assert!(grid.world_to_cell(grid.cell_center(c(x, y))) == Some(c(x, y)));
+        }
+    }
+}
+
+#[test]
+fn test_grid_cells_in_rect_non_finite() {
+    let grid = grid();
+    for area in [
+        rect(f32::NAN, 0.0, 10.0, 10.0),
+        rect(0.0, f32::NAN, 10.0, 10.0),
+        rect(f32::NEG_INFINITY, 0.0, 10.0, 10.0),
+        rect(0.0, f32::INFINITY, 10.0, 10.0),
+        rect(0.0, 0.0, f32::INFINITY, 10.0),
+        rect(0.0, 0.0, 10.0, f32::NAN),
+    ] {
+        assert_eq!(grid.cells_in_rect(&area).count(), 0, "{:?}", area);
+    }
+}
+
+#[test]
+fn test_grid_contains() {
+    let grid = grid();
+    assert!(grid.contains(c(0, 0)));
+    assert!(grid.contains(c(3, 2)));
+    assert!(!grid.contains(c(4, 2)));
+    assert!(!grid.contains(c(3, 3)));
+    assert!(!grid.contains(c(-1, 0)));
+    assert!(!grid.contains(c(i32::MIN, i32::MAX)));
+}
+
+#[test]
+fn test_grid_neighbors() {
+    let grid = grid();
+    let neighbors = |cell, diagonals| -> Vec<_> {
+        grid.neighbors(cell, diagonals)
+            .map(|n: Vector2<i32>| (n.x, n.y))
+            .collect()
+    };
+    assert_eq!(neighbors(c(1, 1), false), [(1, 0), (2, 1), (1, 2), (0, 1)]);
+    assert_eq!(
+        neighbors(c(1, 1), true),
+        [
+            (1, 0),
+            (2, 1),
+            (1, 2),
+            (0, 1),
+            (2, 0),
+            (2, 2),
+            (0, 2),
+            (0, 0)
+        ]
+    );
+    assert_eq!(neighbors(c(0, 0), false), [(1, 0), (0, 1)]);
+    assert_eq!(neighbors(c(3, 2), true), [(3, 1), (2, 2), (2, 1)]);
+    assert_eq!(neighbors(c(i32::MAX, 0), true), []);
+    assert!(grid.neighbor(c(0, 0), Direction::North).is_none());
+    assert!(grid.neighbor(c(0, 0), Direction::East) == Some(c(1, 0)));
+}
+
+#[test]
+fn test_grid_cells_in_rect() {
+    let grid = grid();
+    let cells = |area: Rect<f32>| -> Vec<_> {
+        grid.cells_in_rect(&area)
+            .map(|n: Vector2<i32>| (n.x, n.y))
+            .collect()
+    };
+    assert_eq!(
+        cells(rect(-15.0, -25.0, 10.0, 20.0)),
+        [(0, 0), (1, 0), (0, 1), (1, 1)]
+    );
+    // Touching the edges of the next cells doesn't overlap them
+    assert_eq!(cells(rect(-20.0, -30.0, 10.0, 20.0)), [(0, 0)]);
+    // Clipped to the grid
+    assert_eq!(
+        cells(rect(5.0, 5.0, 100.0, 100.0)),
+        [(2, 1), (3, 1), (2, 2), (3, 2)]
+    );
+    assert_eq!(cells(rect(-100.0, -100.0, 1000.0, 1000.0)).len(), 12);
+    assert_eq!(cells(rect(20.0, 0.0, 5.0, 5.0)), []);
+    assert_eq!(cells(rect(-30.0, -30.0, 10.0, 20.0)), []);
+    assert_eq!(cells(rect(-15.0, -25.0, 0.0, 20.0)), []);
+}
+
+#[test]
+fn test_grid_wrapping() {
+    let grid = grid();
+    assert!(grid.wrap_cell(c(4, 3)) == c(0, 0));
+    assert!(grid.wrap_cell(c(-1, -1)) == c(3, 2));
+    assert!(grid.wrap_cell(c(-9, 7)) == c(3, 1));
+    assert!(grid.neighbor_wrapping(c(3, 1), Direction::East) == c(0, 1));
+    assert!(grid.neighbor_wrapping(c(0, 1), Direction::West) == c(3, 1));
+    assert!(grid.neighbor_wrapping(c(1, 0), Direction::North) == c(1, 2));
+    assert!(grid.neighbor_wrapping(c(1, 2), Direction::South) == c(1, 0));
+    assert!(grid.neighbor_wrapping(c(i32::MAX, 0), Direction::East) == c(0, 0));
+    let neighbors: Vec<_> = grid
+        .neighbors_wrapping(c(0, 0), true)
+        .map(|n| (n.x, n.y))
+        .collect();
+    assert_eq!(
+        neighbors,
+        [
+            (0, 2),
+            (1, 0),
+            (0, 1),
+            (3, 0),
+            (1, 2),
+            (1, 1),
+            (3, 1),
+            (3, 2)
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_grid_wrapping_empty() {
+    Grid::new(Size::new(1.0, 1.0), Vector2::ZERO, Vector2::new(0, 3)).wrap_cell(c(0, 0));
+}
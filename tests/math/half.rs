@@ -0,0 +1,125 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::math::{f16_bits_to_f32, f32_to_f16_bits, Vector2, Vector3, Vector4};
+
+const F16_INFINITY: u16 = 0x7c00;
+const F16_MAX: u16 = 0x7bff;
+const F16_MIN_POSITIVE_SUBNORMAL: u16 = 0x0001;
+const F16_MIN_POSITIVE_NORMAL: u16 = 0x0400;
+
+#[test]
+fn test_f16_known_values() {
+    assert_eq!(f32_to_f16_bits(0.0), 0x0000);
+    assert_eq!(f32_to_f16_bits(-0.0), 0x8000);
+    assert_eq!(f32_to_f16_bits(1.0), 0x3c00);
+    assert_eq!(f32_to_f16_bits(-2.0), 0xc000);
+    assert_eq!(f32_to_f16_bits(0.5), 0x3800);
+    assert_eq!(f32_to_f16_bits(65504.0), F16_MAX);
+    assert_eq!(f16_bits_to_f32(0x3555), 0.333_251_95);
+}
+
+#[test]
+fn test_f16_overflow_is_infinity() {
+    assert_eq!(f32_to_f16_bits(65536.0), F16_INFINITY);
+    assert_eq!(f32_to_f16_bits(1e10), F16_INFINITY);
+    assert_eq!(f32_to_f16_bits(-1e10), 0x8000 | F16_INFINITY);
+    assert_eq!(f32_to_f16_bits(f32::INFINITY), F16_INFINITY);
+    assert_eq!(f32_to_f16_bits(f32::NEG_INFINITY), 0xfc00);
+    // 65520 is halfway between the largest half and 2^16, and rounds to even: infinity.
+    assert_eq!(f32_to_f16_bits(65519.0), F16_MAX);
+    assert_eq!(f32_to_f16_bits(65520.0), F16_INFINITY);
+    assert_eq!(f16_bits_to_f32(F16_INFINITY), f32::INFINITY);
+}
+
+#[test]
+fn test_f16_nan() {
+    let bits = f32_to_f16_bits(f32::NAN);
+    assert_eq!(bits & 0x7c00, 0x7c00);
+    assert_ne!(bits & 0x03ff, 0);
+    assert!(f16_bits_to_f32(bits).is_nan());
+    // A payload only in the low bits must not turn into infinity.
+    let bits = f32_to_f16_bits(f32::from_bits(0x7f80_0001));
+    assert!(f16_bits_to_f32(bits).is_nan());
+}
+
+#[test]
+fn test_f16_subnormals() {
+    let min_subnormal = 2f32.powi(-24);
+    assert_eq!(f32_to_f16_bits(min_subnormal), F16_MIN_POSITIVE_SUBNORMAL);
+    assert_eq!(f16_bits_to_f32(F16_MIN_POSITIVE_SUBNORMAL), min_subnormal);
+    assert_eq!(f32_to_f16_bits(3.0 * min_subnormal), 0x0003);
+    assert_eq!(f32_to_f16_bits(-5.0 * min_subnormal), 0x8005);
+    assert_eq!(f32_to_f16_bits(2f32.powi(-14)), F16_MIN_POSITIVE_NORMAL);
+    // Halfway below the smallest subnormal rounds to even, which is zero.
+    assert_eq!(f32_to_f16_bits(min_subnormal / 2.0), 0x0000);
+    assert_eq!(
+        f32_to_f16_bits(min_subnormal * 0.75),
+        F16_MIN_POSITIVE_SUBNORMAL
+    );
+    assert_eq!(f32_to_f16_bits(1.5 * min_subnormal), 0x0002);
+    assert_eq!(f32_to_f16_bits(2.5 * min_subnormal), 0x0002);
+    assert_eq!(f32_to_f16_bits(1e-10), 0x0000);
+    assert_eq!(f32_to_f16_bits(f32::from_bits(1)), 0x0000);
+    // The largest subnormal rounds up into the smallest normal.
+    assert_eq!(
+        f32_to_f16_bits(2f32.powi(-14) - min_subnormal / 4.0),
+        F16_MIN_POSITIVE_NORMAL
+    );
+}
+
+#[test]
+fn test_f16_rounds_to_nearest_even() {
+    let ulp_at_one = 2f32.powi(-10);
+    assert_eq!(f32_to_f16_bits(1.0 + ulp_at_one / 2.0), 0x3c00);
+    assert_eq!(f32_to_f16_bits(1.0 + 1.5 * ulp_at_one), 0x3c02);
+    assert_eq!(f32_to_f16_bits(1.0 + ulp_at_one * 0.51), 0x3c01);
+    assert_eq!(f32_to_f16_bits(1.0 + ulp_at_one * 0.49), 0x3c00);
+    // A carry out of the mantissa moves to the next power of two.
+    assert_eq!(f32_to_f16_bits(2.0 - ulp_at_one / 4.0), 0x4000);
+}
+
+#[test]
+fn test_f16_exact_round_trip() {
+    for bits in 0..=u16::MAX {
+        let value = f16_bits_to_f32(bits);
+        if value.is_nan() {
+            continue;
+        }
+        assert_eq!(f32_to_f16_bits(value), bits, "bits {:#06x}", bits);
+    }
+}
+
+#[test]
+fn test_vector_f16_bits() {
+    let v2 = Vector2::new(1.0f32, -0.5);
+    assert_eq!(v2.to_f16_bits(), [0x3c00, 0xb800]);
+    assert_eq!(Vector2::from_f16_bits(v2.to_f16_bits()), v2);
+
+    let v3 = Vector3::new(0.25f32, 2.0, 1e6);
+    assert_eq!(v3.to_f16_bits(), [0x3400, 0x4000, F16_INFINITY]);
+    assert_eq!(
+        Vector3::from_f16_bits([0x3400, 0x4000, 0x0001]),
+        Vector3::new(0.25, 2.0, 2f32.powi(-24))
+    );
+
+    let v4 = Vector4::new(1.0f32, 2.0, 3.0, 4.0);
+    assert_eq!(v4.to_f16_bits(), [0x3c00, 0x4000, 0x4200, 0x4400]);
+    assert_eq!(Vector4::from_f16_bits(v4.to_f16_bits()), v4);
+}
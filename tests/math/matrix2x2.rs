@@ -0,0 +1,334 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::math::Matrix2x2;
+use sky_labs::math::Vector2;
+
+macro_rules! assert_eq_mat {
+    ($type:ty, $res:expr, $exp:expr) => {
+        let eps = <$type>::EPSILON;
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!(
+                    ($res[i][j] - $exp[i][j]).abs() <= eps,
+                    "{}[{},{}] = {}, {}[{},{}] = {}",
+                    stringify!($res),
+                    i,
+                    j,
+                    $res[i][j],
+                    stringify!($exp),
+                    i,
+                    j,
+                    $exp[i][j]
+                );
+            }
+        }
+    };
+}
+
+macro_rules! test_matrix2x2_identity {
+    ($type:ty) => {
+        let identity = Matrix2x2::<$type>::identity();
+        assert_eq!(identity[(0, 0)], 1 as $type);
+        assert_eq!(identity[(1, 1)], 1 as $type);
+        assert_eq!(identity[(0, 1)], 0 as $type);
+        assert_eq!(identity[(1, 0)], 0 as $type);
+        assert_eq!(identity, Matrix2x2::IDENTITY);
+        assert_eq!(Matrix2x2::<$type>::zero(), Matrix2x2::ZERO);
+        assert_eq!(
+            Matrix2x2::<$type>::one().to_array(),
+            [1 as $type, 1 as $type, 1 as $type, 1 as $type]
+        );
+    };
+}
+
+macro_rules! test_matrix2x2_addition {
+    ($type:ty) => {
+        let a = Matrix2x2::<$type>::from_mat([[1 as $type, 2 as $type], [3 as $type, 4 as $type]]);
+        let b = Matrix2x2::<$type>::from_mat([[4 as $type, 3 as $type], [2 as $type, 1 as $type]]);
+        let result = a + b;
+        assert_eq!(result, Matrix2x2::from([5 as $type; 4]));
+        assert_eq!(result - b, a);
+        assert_eq!(-a + a, Matrix2x2::zero());
+
+        let mut sum = a;
+        sum += &b;
+        assert_eq!(sum, result);
+    };
+}
+
+macro_rules! test_matrix2x2_multiplication {
+    ($type:ty) => {
+        let a = Matrix2x2::<$type>::from_mat([[1 as $type, 2 as $type], [3 as $type, 4 as $type]]);
+        let b = Matrix2x2::<$type>::from_mat([[5 as $type, 6 as $type], [7 as $type, 8 as $type]]);
+        let expected =
+            Matrix2x2::<$type>::from_mat([[19 as $type, 22 as $type], [43 as $type, 50 as $type]]);
+        assert_eq!(a * b, expected);
+        assert_eq!(&a * &b, expected);
+        assert_eq!(a * Matrix2x2::identity(), a);
+
+        // Scalars multiply every element, from either side.
+        let doubled =
+            Matrix2x2::<$type>::from_mat([[2 as $type, 4 as $type], [6 as $type, 8 as $type]]);
+        assert_eq!(a * (2 as $type), doubled);
+        assert_eq!((2 as $type) * a, doubled);
+        assert_eq!(doubled / (2 as $type), a);
+        let mut scaled = a;
+        scaled *= 2 as $type;
+        assert_eq!(scaled, doubled);
+        scaled /= 2 as $type;
+        assert_eq!(scaled, a);
+
+        // Vectors are columns on the right and rows on the left.
+        let v = Vector2::new(1 as $type, -1 as $type);
+        assert_eq!(a * v, Vector2::new(-1 as $type, -1 as $type));
+        assert_eq!(v * a, Vector2::new(-2 as $type, -2 as $type));
+        assert_eq!(&v * &a, a.transpose() * v);
+    };
+}
+
+macro_rules! test_matrix2x2_transpose {
+    ($type:ty) => {
+        let m = Matrix2x2::<$type>::from_mat([[1 as $type, 2 as $type], [3 as $type, 4 as $type]]);
+        let expected =
+            Matrix2x2::<$type>::from_mat([[1 as $type, 3 as $type], [2 as $type, 4 as $type]]);
+        assert_eq!(m.transpose(), expected);
+        assert_eq!(m.transpose().transpose(), m);
+    };
+}
+
+macro_rules! test_matrix2x2_determinant {
+    ($type:ty) => {
+        let m = Matrix2x2::<$type>::from_mat([[3 as $type, 8 as $type], [4 as $type, 6 as $type]]);
+        assert_eq!(m.determinant(), -14 as $type);
+        assert_eq!(Matrix2x2::<$type>::identity().determinant(), 1 as $type);
+    };
+}
+
+macro_rules! test_matrix2x2_inverse_known_matrix {
+    ($type:ty, $eps:expr) => {
+        let m = Matrix2x2::<$type>::from_mat([[4 as $type, 7 as $type], [2 as $type, 6 as $type]]);
+        let inv = m.inverse().unwrap();
+        let expected = Matrix2x2::<$type>::from_mat([[0.6, -0.7], [-0.2, 0.4]]);
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!((inv[(i, j)] - expected[(i, j)]).abs() < $eps);
+            }
+        }
+        let prod = m * inv;
+        for i in 0..2 {
+            for j in 0..2 {
+                let expected = if i == j { 1 as $type } else { 0 as $type };
+                assert!(
+                    (prod[(i, j)] - expected).abs() < $eps,
+                    "prod[{},{}] = {}",
+                    i,
+                    j,
+                    prod[(i, j)]
+                );
+            }
+        }
+    };
+}
+
+macro_rules! test_matrix2x2_inverse_non_invertible {
+    ($type:ty) => {
+        let m = Matrix2x2::<$type>::from_mat([[1 as $type, 2 as $type], [2 as $type, 4 as $type]]);
+        assert!(m.inverse().is_none());
+        assert!(Matrix2x2::<$type>::zero().inverse().is_none());
+    };
+}
+
+#[test]
+fn test_matrix2x2_identity_all_types() {
+    test_matrix2x2_identity!(i32);
+    test_matrix2x2_identity!(i64);
+    test_matrix2x2_identity!(f32);
+    test_matrix2x2_identity!(f64);
+}
+
+#[test]
+fn test_matrix2x2_addition_all_types() {
+    test_matrix2x2_addition!(i32);
+    test_matrix2x2_addition!(i64);
+    test_matrix2x2_addition!(f32);
+    test_matrix2x2_addition!(f64);
+}
+
+#[test]
+fn test_matrix2x2_multiplication_all_types() {
+    test_matrix2x2_multiplication!(i32);
+    test_matrix2x2_multiplication!(i64);
+    test_matrix2x2_multiplication!(f32);
+    test_matrix2x2_multiplication!(f64);
+}
+
+#[test]
+fn test_matrix2x2_transpose_all_types() {
+    test_matrix2x2_transpose!(i32);
+    test_matrix2x2_transpose!(i64);
+    test_matrix2x2_transpose!(f32);
+    test_matrix2x2_transpose!(f64);
+}
+
+#[test]
+fn test_matrix2x2_determinant_all_types() {
+    test_matrix2x2_determinant!(i32);
+    test_matrix2x2_determinant!(i64);
+    test_matrix2x2_determinant!(f32);
+    test_matrix2x2_determinant!(f64);
+}
+
+#[test]
+fn test_matrix2x2_inverse_identity() {
+    assert_eq!(
+        Matrix2x2::<i32>::identity().inverse(),
+        Some(Matrix2x2::identity())
+    );
+    assert_eq!(
+        Matrix2x2::<f64>::identity().inverse(),
+        Some(Matrix2x2::identity())
+    );
+}
+
+#[test]
+fn test_matrix2x2_inverse_known_matrix_all_types() {
+    test_matrix2x2_inverse_known_matrix!(f32, 1e-6);
+    test_matrix2x2_inverse_known_matrix!(f64, 1e-12);
+    // Integer types are not tested here because their inverse may not exist or be exact
+}
+
+#[test]
+fn test_matrix2x2_inverse_non_invertible_all_types() {
+    test_matrix2x2_inverse_non_invertible!(i32);
+    test_matrix2x2_inverse_non_invertible!(f32);
+    test_matrix2x2_inverse_non_invertible!(f64);
+}
+
+#[test]
+fn test_matrix2x2_make_rotation() {
+    let quarter = Matrix2x2::<f64>::make_rotation(std::f64::consts::FRAC_PI_2);
+    assert_eq_mat!(
+        f64,
+        quarter,
+        Matrix2x2::<f64>::from_mat([[0.0, -1.0], [1.0, 0.0]])
+    );
+    let v = quarter * Vector2::new(1.0, 0.0);
+    assert!((v - Vector2::new(0.0, 1.0)).magnitude() < 1e-12);
+
+    let half = Matrix2x2::<f32>::make_rotation(std::f32::consts::PI);
+    assert_eq_mat!(
+        f32,
+        half,
+        Matrix2x2::<f32>::from_mat([[-1.0, 0.0], [0.0, -1.0]])
+    );
+
+    // Matches `Vector2::rotate`, and a rotation is orthogonal and doesn't scale.
+    let angle = 0.7f64;
+    let rotation = Matrix2x2::<f64>::make_rotation(angle);
+    let v = Vector2::new(3.0, -2.0);
+    assert!((rotation * v - v.rotate(angle)).magnitude() < 1e-12);
+    assert_eq_mat!(
+        f64,
+        rotation * rotation.transpose(),
+        Matrix2x2::<f64>::identity()
+    );
+    assert!((rotation.determinant() - 1.0).abs() < 1e-12);
+    assert_eq_mat!(
+        f64,
+        rotation.inverse().unwrap(),
+        Matrix2x2::<f64>::make_rotation(-angle)
+    );
+}
+
+#[test]
+fn test_matrix2x2_make_scaling() {
+    let scaling = Matrix2x2::<f32>::make_scaling(2.0, -3.0);
+    assert_eq!(scaling * Vector2::new(1.0, 1.0), Vector2::new(2.0, -3.0));
+    assert_eq!(scaling.determinant(), -6.0);
+}
+
+#[test]
+fn test_matrix2x2_indexing() {
+    let mut m = Matrix2x2::<i32>::from_array([1, 2, 3, 4]);
+    assert_eq!(m[0], Vector2::new(1, 2));
+    assert_eq!(m[1][0], 3);
+    assert_eq!(m[(1, 1)], 4);
+    m[(0, 1)] = 5;
+    m[1].x = 6;
+    assert_eq!(m.to_mat(), [[1, 5], [6, 4]]);
+    m.rows_mut()[1] = Vector2::new(7, 8);
+    assert_eq!(m.rows(), &[Vector2::new(1, 5), Vector2::new(7, 8)]);
+}
+
+#[test]
+fn test_matrix2x2_conversions() {
+    let m = Matrix2x2::<f32>::from_mat([[1.0, 2.0], [3.0, 4.0]]);
+    assert_eq!(m.to_array(), [1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(m.flatten(), m.to_array());
+    assert_eq!(m.as_slice(), &[1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(Matrix2x2::from_array(m.to_array()), m);
+    assert_eq!(Matrix2x2::from([[1.0, 2.0], [3.0, 4.0]]), m);
+    assert_eq!(Matrix2x2::from(&[1.0, 2.0, 3.0, 4.0, 5.0][..]), m);
+    assert_eq!(
+        Matrix2x2::new([Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)]),
+        m
+    );
+
+    let mut m = m;
+    m.as_mut_slice()[3] = 8.0;
+    assert_eq!(m[(1, 1)], 8.0);
+}
+
+#[test]
+fn test_matrix2x2_transform_into() {
+    let scale = Matrix2x2::<i32>::from_mat([[2, 0], [0, 3]]);
+    let vectors = [Vector2::new(1, 1), Vector2::new(-1, 2)];
+    let mut out = [Vector2::default(); 2];
+    scale.transform_into(&vectors, &mut out);
+    assert_eq!(out, [Vector2::new(2, 3), Vector2::new(-2, 6)]);
+
+    let mut in_place = vectors;
+    scale.transform_in_place(&mut in_place);
+    assert_eq!(in_place, out);
+}
+
+#[test]
+fn test_matrix2x2_solve_system() {
+    // 2x + y = 5, x - y = 1 has the solution x = 2, y = 1.
+    let m = Matrix2x2::<f64>::from_mat([[2.0, 1.0], [1.0, -1.0]]);
+    let solution = m.inverse().unwrap() * Vector2::new(5.0, 1.0);
+    assert!((solution - Vector2::new(2.0, 1.0)).magnitude() < 1e-12);
+}
+
+#[test]
+fn test_matrix2x2_inverse_checked() {
+    let nearly_singular = Matrix2x2::<f64>::from_mat([[1.0, 2.0], [2.0, 4.0 + 1e-10]]);
+    assert!(nearly_singular.inverse().is_some());
+    assert!(nearly_singular.inverse_checked(1e-6).is_none());
+    assert!(nearly_singular.condition_estimate() > 1e10);
+
+    let well_conditioned = Matrix2x2::<f64>::from_mat([[2.0, 1.0], [1.0, -1.0]]);
+    assert_eq!(
+        well_conditioned.inverse_checked(1e-6),
+        well_conditioned.inverse()
+    );
+    assert!(well_conditioned.inverse_checked(1e-6).is_some());
+    assert!((Matrix2x2::<f64>::make_rotation(0.4).condition_estimate() - 2.0).abs() < 1e-12);
+}
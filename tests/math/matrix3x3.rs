@@ -18,6 +18,7 @@
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 use sky_labs::math::Matrix3x3;
+use sky_labs::math::Vector2;
 use sky_labs::math::Vector3;
 
 macro_rules! assert_eq_mat {
@@ -251,6 +252,25 @@ fn test_matrix3x3_transpose_all_types() {
     test_matrix3x3_transpose!(f64);
 }
 
+#[test]
+fn test_matrix3x3_columns() {
+    let m = Matrix3x3::from_mat([[1, 2, 3], [4, 5, 6], [7, 8, 10]]);
+    assert_eq!(m.column(0), Vector3::new(1, 4, 7));
+    assert_eq!(m.column(2), Vector3::new(3, 6, 10));
+    assert_eq!(Matrix3x3::from_columns(m.columns()), m);
+    let transpose = m.transpose();
+    for i in 0..3 {
+        assert_eq!(transpose.column(i), m.rows()[i]);
+    }
+
+    let mut m = m;
+    m.set_column(1, Vector3::new(-1, -2, -3));
+    assert_eq!(
+        m,
+        Matrix3x3::from_mat([[1, -1, 3], [4, -2, 6], [7, -3, 10]])
+    );
+}
+
 #[test]
 fn test_matrix3x3_determinant_all_types() {
     test_matrix3x3_determinant!(i32, 0);
@@ -378,17 +398,63 @@ fn test_matrix3x3_make_rotation_y() {
 fn test_matrix3x3_make_rotation() {
     // Rotate over Z axis by 90 degrees (π/2 radians)
     let angle = std::f64::consts::FRAC_PI_2;
-    let rot = Matrix3x3::<f64>::make_rotation(angle, &Vector3::new(0.0, 0.0, -1.0));
+    let rot = Matrix3x3::<f64>::make_rotation(angle, &Vector3::new(0.0, 0.0, 1.0));
     let expected = Matrix3x3::<f64>::from_mat([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
     assert_eq_mat!(f64, rot, expected);
 
     // Rotate over Z axis by 90 degrees (π/2 radians)
     let angle = std::f32::consts::FRAC_PI_2;
-    let rot = Matrix3x3::<f32>::make_rotation(angle, &Vector3::new(0.0, 0.0, -1.0));
+    let rot = Matrix3x3::<f32>::make_rotation(angle, &Vector3::new(0.0, 0.0, 1.0));
     let expected = Matrix3x3::<f32>::from_mat([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
     assert_eq_mat!(f32, rot, expected);
 }
 
+#[test]
+fn test_matrix3x3_make_rotation_basis_axis() {
+    for step in -8..=8 {
+        let angle = step as f64 * std::f64::consts::FRAC_PI_8;
+        let rot = Matrix3x3::<f64>::make_rotation(angle, &Vector3::new(1.0, 0.0, 0.0));
+        assert_eq_mat!(f64, rot, Matrix3x3::<f64>::make_rotation_x(angle));
+        let rot = Matrix3x3::<f64>::make_rotation(angle, &Vector3::new(0.0, 1.0, 0.0));
+        assert_eq_mat!(f64, rot, Matrix3x3::<f64>::make_rotation_y(angle));
+        let rot = Matrix3x3::<f64>::make_rotation(angle, &Vector3::new(0.0, 0.0, 1.0));
+        assert_eq_mat!(f64, rot, Matrix3x3::<f64>::make_rotation_z(angle));
+
+        let angle = angle as f32;
+        let rot = Matrix3x3::<f32>::make_rotation(angle, &Vector3::new(1.0, 0.0, 0.0));
+        assert_eq_mat!(f32, rot, Matrix3x3::<f32>::make_rotation_x(angle));
+        let rot = Matrix3x3::<f32>::make_rotation(angle, &Vector3::new(0.0, 1.0, 0.0));
+        assert_eq_mat!(f32, rot, Matrix3x3::<f32>::make_rotation_y(angle));
+        let rot = Matrix3x3::<f32>::make_rotation(angle, &Vector3::new(0.0, 0.0, 1.0));
+        assert_eq_mat!(f32, rot, Matrix3x3::<f32>::make_rotation_z(angle));
+    }
+}
+
+#[test]
+fn test_matrix3x3_make_rotation_cross_precision() {
+    let axis_f64 = Vector3::<f64>::new(1.0, 2.0, 3.0).normalize();
+    let axis_f32 = Vector3::<f32>::new(1.0, 2.0, 3.0).normalize();
+    for step in -8..=8 {
+        let angle = step as f64 * std::f64::consts::FRAC_PI_8;
+        let rot_f64 = Matrix3x3::<f64>::make_rotation(angle, &axis_f64);
+        let rot_f32 = Matrix3x3::<f32>::make_rotation(angle as f32, &axis_f32);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!(
+                    (rot_f64[i][j] - rot_f32[i][j] as f64).abs() < 1e-6,
+                    "f64[{},{}] = {}, f32[{},{}] = {}",
+                    i,
+                    j,
+                    rot_f64[i][j],
+                    i,
+                    j,
+                    rot_f32[i][j]
+                );
+            }
+        }
+    }
+}
+
 #[test]
 fn test_matrix3x3_make_scaling() {
     let scale = Matrix3x3::<f32>::make_scaling(2.0, 3.0, 1.0);
@@ -396,6 +462,133 @@ fn test_matrix3x3_make_scaling() {
     assert_eq!(scale, expected);
 }
 
+#[test]
+fn test_matrix3x3_make_scaling_v() {
+    assert_eq!(
+        Matrix3x3::<f32>::make_scaling_v(&Vector3::new(2.0, 3.0, 1.0)),
+        Matrix3x3::<f32>::make_scaling(2.0, 3.0, 1.0)
+    );
+    assert_eq!(
+        Matrix3x3::<f64>::make_scaling_v(&Vector3::new(-1.0, 0.5, 4.0)),
+        Matrix3x3::<f64>::make_scaling(-1.0, 0.5, 4.0)
+    );
+}
+
+#[test]
+fn test_matrix3x3_make_scaling_2d() {
+    let scale = Matrix3x3::<f32>::make_scaling_2d(2.0, 3.0);
+    let expected = Matrix3x3::<f32>::from_mat([[2.0, 0.0, 0.0], [0.0, 3.0, 0.0], [0.0, 0.0, 1.0]]);
+    assert_eq!(scale, expected);
+    assert_eq!(
+        scale.transform_point_2d(Vector2::new(1.0, 1.0)),
+        Vector2::new(2.0, 3.0)
+    );
+
+    let scale = Matrix3x3::<f64>::make_scaling_2d(2.0, 3.0);
+    let expected = Matrix3x3::<f64>::from_mat([[2.0, 0.0, 0.0], [0.0, 3.0, 0.0], [0.0, 0.0, 1.0]]);
+    assert_eq!(scale, expected);
+    assert_eq!(
+        scale.transform_point_2d(Vector2::new(1.0, 1.0)),
+        Vector2::new(2.0, 3.0)
+    );
+}
+
+#[test]
+fn test_matrix3x3_make_translation_2d() {
+    let translation = Matrix3x3::<f32>::make_translation_2d(4.0, -2.0);
+    let expected = Matrix3x3::<f32>::from_mat([[1.0, 0.0, 4.0], [0.0, 1.0, -2.0], [0.0, 0.0, 1.0]]);
+    assert_eq!(translation, expected);
+    assert_eq!(
+        translation.transform_point_2d(Vector2::new(1.0, 1.0)),
+        Vector2::new(5.0, -1.0)
+    );
+    // Directions are not affected by translation
+    assert_eq!(
+        translation.transform_vector_2d(Vector2::new(1.0, 1.0)),
+        Vector2::new(1.0, 1.0)
+    );
+
+    let translation = Matrix3x3::<f64>::make_translation_2d(4.0, -2.0);
+    assert_eq!(
+        translation.transform_point_2d(Vector2::new(1.0, 1.0)),
+        Vector2::new(5.0, -1.0)
+    );
+    assert_eq!(
+        translation.transform_vector_2d(Vector2::new(1.0, 1.0)),
+        Vector2::new(1.0, 1.0)
+    );
+}
+
+#[test]
+fn test_matrix3x3_make_rotation_2d() {
+    // Rotate (1, 0) by 90 degrees counter-clockwise
+    let rot = Matrix3x3::<f64>::make_rotation_2d(std::f64::consts::FRAC_PI_2);
+    let expected = Matrix3x3::<f64>::from_mat([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+    assert_eq_mat!(f64, rot, expected);
+    let p = rot.transform_point_2d(Vector2::new(1.0, 0.0));
+    assert!((p.x - 0.0).abs() <= f64::EPSILON && (p.y - 1.0).abs() <= f64::EPSILON);
+
+    let rot = Matrix3x3::<f32>::make_rotation_2d(std::f32::consts::FRAC_PI_2);
+    let expected = Matrix3x3::<f32>::from_mat([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+    assert_eq_mat!(f32, rot, expected);
+    let p = rot.transform_point_2d(Vector2::new(1.0, 0.0));
+    assert!((p.x - 0.0).abs() <= f32::EPSILON && (p.y - 1.0).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn test_matrix3x3_make_skew_2d() {
+    let rad = std::f64::consts::FRAC_PI_4; // 45 degrees
+    let skew = Matrix3x3::<f64>::make_skew_2d(rad, 0.0);
+    let expected = Matrix3x3::<f64>::from_mat([[1.0, 1.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+    assert_eq_mat!(f64, skew, expected);
+
+    let rad = std::f32::consts::FRAC_PI_4; // 45 degrees
+    let skew = Matrix3x3::<f32>::make_skew_2d(0.0, rad);
+    let expected = Matrix3x3::<f32>::from_mat([[1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+    assert_eq_mat!(f32, skew, expected);
+}
+
+#[test]
+fn test_matrix3x3_compose_2d() {
+    // Scale first, then rotate, then translate
+    let transform = Matrix3x3::<f64>::make_translation_2d(10.0, 20.0)
+        * Matrix3x3::<f64>::make_rotation_2d(std::f64::consts::FRAC_PI_2)
+        * Matrix3x3::<f64>::make_scaling_2d(2.0, 2.0);
+    let p = transform.transform_point_2d(Vector2::new(1.0, 0.0));
+    assert!((p.x - 10.0).abs() < 1e-12);
+    assert!((p.y - 22.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_matrix3x3_make_scaling_axis() {
+    // Scaling along X only affects the X component
+    let scale = Matrix3x3::<f64>::make_scaling_axis(&Vector3::new(1.0, 0.0, 0.0), 2.0);
+    assert_eq!(
+        scale * Vector3::new(1.0, 2.0, 3.0),
+        Vector3::new(2.0, 2.0, 3.0)
+    );
+    let scale = Matrix3x3::<f32>::make_scaling_axis(&Vector3::new(1.0, 0.0, 0.0), 2.0);
+    assert_eq!(
+        scale * Vector3::new(1.0, 2.0, 3.0),
+        Vector3::new(2.0, 2.0, 3.0)
+    );
+
+    // Scaling along a diagonal doubles the parallel component and keeps the perpendicular one
+    let axis = Vector3::<f64>::new(1.0, 1.0, 0.0).normalize();
+    let scale = Matrix3x3::<f64>::make_scaling_axis(&axis, 2.0);
+    let perpendicular = Vector3::new(1.0, -1.0, 5.0);
+    assert!((scale * perpendicular - perpendicular).magnitude() < 1e-12);
+    let parallel = Vector3::new(3.0, 3.0, 0.0);
+    assert!((scale * parallel - parallel * 2.0).magnitude() < 1e-12);
+
+    let axis = Vector3::<f32>::new(1.0, 1.0, 0.0).normalize();
+    let scale = Matrix3x3::<f32>::make_scaling_axis(&axis, 2.0);
+    let perpendicular = Vector3::new(1.0, -1.0, 5.0);
+    assert!((scale * perpendicular - perpendicular).magnitude() < 1e-6);
+    let parallel = Vector3::new(3.0, 3.0, 0.0);
+    assert!((scale * parallel - parallel * 2.0).magnitude() < 1e-6);
+}
+
 #[test]
 fn test_matrix3x3_make_reflection_f64() {
     // Reflect over yz-plane (x-axis)
@@ -528,6 +721,44 @@ fn test_matrix3x3_make_skew_pivot_not_perpendicular_f64() {
     let _skew = Matrix3x3::<f64>::make_skew(rad, &direction, &pivot); // Pivot must be perpendicular to direction
 }
 
+#[test]
+fn test_matrix3x3_make_skew_nearly_perpendicular() {
+    // Vectors built by rotations are only perpendicular up to rounding
+    let direction = Vector3::new(0.0, 0.0, 1.0);
+    let pivot = Vector3::new(1.0, 0.5, 1e-9);
+    let rad = std::f64::consts::FRAC_PI_4; // 45 degrees
+    let skew = Matrix3x3::<f64>::make_skew(rad, &direction, &pivot);
+    assert!((skew[2][0] - 1.0).abs() <= 1e-12);
+    assert!((skew[2][1] - 0.5).abs() <= 1e-12);
+    assert!((skew[2][2] - 1.0).abs() <= 1e-8);
+
+    let direction = Vector3::new(0.0, 0.0, 1.0);
+    let pivot = Vector3::new(1.0, 0.5, 1e-9);
+    let rad = std::f32::consts::FRAC_PI_4; // 45 degrees
+    let skew = Matrix3x3::<f32>::make_skew(rad, &direction, &pivot);
+    let expected = Matrix3x3::<f32>::make_skew(rad, &direction, &Vector3::new(1.0, 0.5, 0.0));
+    assert_eq_mat!(f32, skew, expected);
+}
+
+#[test]
+fn test_matrix3x3_try_make_skew() {
+    let direction = Vector3::new(0.0, 0.0, 1.0);
+    let pivot = Vector3::new(1.0, 0.5, 0.0);
+    let rad = std::f64::consts::FRAC_PI_4; // 45 degrees
+    assert_eq!(
+        Matrix3x3::<f64>::try_make_skew(rad, &direction, &pivot),
+        Some(Matrix3x3::<f64>::make_skew(rad, &direction, &pivot))
+    );
+    assert_eq!(Matrix3x3::<f64>::try_make_skew(rad, &Vector3::new(0.0, 0.0, 0.0), &pivot), None);
+    assert_eq!(Matrix3x3::<f64>::try_make_skew(rad, &Vector3::new(0.0, 0.0, 10.0), &pivot), None);
+    assert_eq!(Matrix3x3::<f64>::try_make_skew(rad, &direction, &Vector3::new(0.0, 0.0, 0.0)), None);
+    assert_eq!(Matrix3x3::<f64>::try_make_skew(rad, &direction, &Vector3::new(1.0, 0.5, 1.0)), None);
+    assert_eq!(
+        Matrix3x3::<f32>::try_make_skew(0.5, &Vector3::new(0.0, 0.0, 1.0), &Vector3::new(1.0, 0.5, 1e-3)),
+        None
+    );
+}
+
 #[test]
 #[should_panic]
 fn test_matrix3x3_make_skew_zero_direction_f32() {
@@ -563,3 +794,224 @@ fn test_matrix3x3_make_skew_pivot_not_perpendicular_f32() {
     let rad = std::f32::consts::FRAC_PI_4; // 45 degrees
     let _skew = Matrix3x3::<f32>::make_skew(rad, &direction, &pivot); // Pivot must be perpendicular to direction
 }
+
+#[test]
+fn test_matrix3x3_from_basis_maps_axes() {
+    let x = Vector3::new(0.0f64, 1.0, 0.0);
+    let y = Vector3::new(-1.0f64, 0.0, 0.0);
+    let z = Vector3::new(0.0f64, 0.0, 1.0);
+    let m = Matrix3x3::<f64>::from_basis(&x, &y, &z);
+    assert_eq!(m * Vector3::new(1.0, 0.0, 0.0), x);
+    assert_eq!(m * Vector3::new(0.0, 1.0, 0.0), y);
+    assert_eq!(m * Vector3::new(0.0, 0.0, 1.0), z);
+    assert_eq_mat!(
+        f64,
+        m,
+        Matrix3x3::<f64>::make_rotation_z(std::f64::consts::FRAC_PI_2)
+    );
+}
+
+#[test]
+fn test_matrix3x3_look_rotation() {
+    let forward = Vector3::new(1.0f64, 0.0, 1.0);
+    let up = Vector3::new(0.0f64, 2.0, 0.0);
+    let m = Matrix3x3::<f64>::look_rotation(&forward, &up);
+    let z = m * Vector3::new(0.0, 0.0, 1.0);
+    let y = m * Vector3::new(0.0, 1.0, 0.0);
+    assert!((z - forward.normalize()).magnitude() < 1e-12);
+    assert!((y - Vector3::new(0.0, 1.0, 0.0)).magnitude() < 1e-12);
+    assert!((m.determinant() - 1.0).abs() < 1e-12);
+    assert_eq_mat!(f64, m * m.transpose(), Matrix3x3::<f64>::identity());
+}
+
+#[test]
+fn test_matrix3x3_look_rotation_parallel_up() {
+    for forward in [
+        Vector3::new(0.0f32, 1.0, 0.0),
+        Vector3::new(0.0f32, -3.0, 0.0),
+    ] {
+        let m = Matrix3x3::<f32>::look_rotation(&forward, &Vector3::new(0.0, 1.0, 0.0));
+        let z = m * Vector3::new(0.0, 0.0, 1.0);
+        assert!((z - forward.normalize()).magnitude() < 1e-6);
+        assert!((m.determinant() - 1.0).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_matrix3x3_transform_into() {
+    let scale = Matrix3x3::<i32>::from_mat([[2, 0, 0], [0, 3, 0], [0, 0, 4]]);
+    let vectors = [Vector3::new(1, 1, 1), Vector3::new(-1, 2, 0)];
+    let mut out = [Vector3::default(); 2];
+    scale.transform_into(&vectors, &mut out);
+    assert_eq!(out, [Vector3::new(2, 3, 4), Vector3::new(-2, 6, 0)]);
+
+    let mut in_place = vectors;
+    scale.transform_in_place(&mut in_place);
+    assert_eq!(in_place, out);
+}
+
+#[test]
+fn test_matrix3x3_transform_2d_into() {
+    let translation = Matrix3x3::<f32>::make_translation_2d(4.0, -2.0);
+    let points = [Vector2::new(1.0, 1.0), Vector2::new(0.0, 0.0)];
+    let mut out = [Vector2::default(); 2];
+    translation.transform_points_2d_into(&points, &mut out);
+    assert_eq!(out, [Vector2::new(5.0, -1.0), Vector2::new(4.0, -2.0)]);
+    translation.transform_vectors_2d_into(&points, &mut out);
+    assert_eq!(out, points);
+
+    let translation = Matrix3x3::<f64>::make_translation_2d(4.0, -2.0);
+    let mut out = [Vector2::default(); 1];
+    translation.transform_points_2d_into(&[Vector2::new(1.0, 1.0)], &mut out);
+    assert_eq!(out, [Vector2::new(5.0, -1.0)]);
+}
+
+#[test]
+#[should_panic]
+fn test_matrix3x3_transform_points_2d_into_length_mismatch() {
+    let points = [Vector2::<f32>::default(); 2];
+    let mut out = [Vector2::default(); 3];
+    Matrix3x3::<f32>::identity().transform_points_2d_into(&points, &mut out);
+}
+
+#[test]
+fn test_matrix3x3_make_rotation_normalizes_axis() {
+    let angle = std::f64::consts::FRAC_PI_3;
+    let rot = Matrix3x3::<f64>::make_rotation(angle, &Vector3::new(0.0, 0.0, 5.0));
+    assert_eq_mat!(f64, rot, Matrix3x3::<f64>::make_rotation_z(angle));
+
+    let rot = Matrix3x3::<f64>::make_rotation(angle, &Vector3::new(2.0, -4.0, 6.0));
+    let axis = Vector3::<f64>::new(1.0, -2.0, 3.0).normalize();
+    let expected = Matrix3x3::<f64>::make_rotation_normalized(angle, &axis);
+    assert_eq_mat!(f64, rot, expected);
+    // A rotation is orthogonal and doesn't scale.
+    assert_eq_mat!(f64, rot * rot.transpose(), Matrix3x3::<f64>::identity());
+    assert!((rot.determinant() - 1.0).abs() < 1e-12);
+
+    let angle = angle as f32;
+    let rot = Matrix3x3::<f32>::make_rotation(angle, &Vector3::new(0.0, 0.0, 0.25));
+    assert_eq_mat!(f32, rot, Matrix3x3::<f32>::make_rotation_z(angle));
+    let rot = Matrix3x3::<f32>::make_rotation(angle, &Vector3::new(2.0, -4.0, 6.0));
+    let product = rot * rot.transpose();
+    for i in 0..3 {
+        for j in 0..3 {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((product[i][j] - expected).abs() < 1e-6);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_matrix3x3_make_rotation_zero_axis() {
+    let _rot = Matrix3x3::<f32>::make_rotation(1.0, &Vector3::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+#[should_panic]
+fn test_matrix3x3_make_rotation_normalized_not_normalized() {
+    let _rot = Matrix3x3::<f64>::make_rotation_normalized(1.0, &Vector3::new(0.0, 0.0, 2.0));
+}
+
+#[test]
+fn test_matrix3x3_trace_and_symmetry() {
+    assert_eq!(Matrix3x3::<i32>::identity().trace(), 3);
+    assert_eq!(Matrix3x3::<f64>::identity().trace(), 3.0);
+    let mat = Matrix3x3::<i32>::from_mat([[1, 2, 3], [2, 5, 6], [3, 6, 9]]);
+    assert_eq!(mat.trace(), 15);
+    assert!(mat.is_symmetric(0));
+    let mat = Matrix3x3::<f32>::from_mat([[1.0, 2.0, 3.0], [2.5, 5.0, 6.0], [3.0, 6.0, 9.0]]);
+    assert!(!mat.is_symmetric(0.1));
+    assert!(mat.is_symmetric(0.5));
+}
+
+#[test]
+fn test_matrix3x3_frobenius_norm() {
+    assert!((Matrix3x3::<f64>::identity().frobenius_norm() - 3.0f64.sqrt()).abs() < 1e-12);
+    let mat = Matrix3x3::<f32>::from_mat([[1.0, -2.0, 2.0], [0.0, 4.0, 0.0], [0.0, 0.0, 0.0]]);
+    assert_eq!(mat.frobenius_norm(), 5.0);
+}
+
+#[test]
+fn test_matrix3x3_is_orthogonal() {
+    let rot = Matrix3x3::<f32>::make_rotation(0.7, &Vector3::new(1.0, 2.0, -3.0));
+    assert!(rot.is_orthogonal(1e-6));
+    assert!(Matrix3x3::<f32>::make_scaling(-1.0, 1.0, 1.0).is_orthogonal(0.0));
+    assert!(!Matrix3x3::<f32>::make_scaling(2.0, 1.0, 1.0).is_orthogonal(1e-6));
+}
+
+#[test]
+fn test_matrix3x3_orthonormalize() {
+    let rot = Matrix3x3::<f64>::make_rotation(1.2, &Vector3::new(-1.0, 0.5, 2.0));
+    let mut perturbed = rot;
+    perturbed[0][1] += 1e-3;
+    perturbed[2][0] -= 2e-3;
+    perturbed[1][1] *= 1.01;
+    assert!(!perturbed.is_orthogonal(1e-6));
+
+    let fixed = perturbed.orthonormalize().unwrap();
+    assert!(fixed.is_orthogonal(1e-6));
+    assert!((fixed.determinant() - 1.0).abs() < 1e-6);
+    // The result stays close to the rotation and keeps the direction of the first column.
+    assert!((fixed - rot).frobenius_norm() < 1e-2);
+    let first = Vector3::new(perturbed[0][0], perturbed[1][0], perturbed[2][0]).normalize();
+    for (i, component) in [first.x, first.y, first.z].into_iter().enumerate() {
+        assert!((fixed[i][0] - component).abs() < 1e-12);
+    }
+
+    let rot = Matrix3x3::<f32>::make_rotation_y(0.3);
+    assert_eq_mat!(f32, rot.orthonormalize().unwrap(), rot);
+}
+
+#[test]
+fn test_matrix3x3_orthonormalize_singular() {
+    let mat = Matrix3x3::<f32>::from_mat([[1.0, 2.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, 0.0]]);
+    assert!(mat.orthonormalize().is_none());
+    assert!(Matrix3x3::<f64>::zero().orthonormalize().is_none());
+}
+
+#[test]
+fn test_matrix3x3_inverse_checked_nearly_singular() {
+    // The third row is the second one doubled minus the first, up to rounding-sized noise.
+    let mat =
+        Matrix3x3::<f64>::from_mat([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0 + 1e-9]]);
+    let inverse = mat.inverse().unwrap();
+    assert!(inverse.frobenius_norm() > 1e8);
+    assert!(mat.inverse_checked(1e-6).is_none());
+    assert!(mat.condition_estimate() > 1e9);
+    assert!(Matrix3x3::<f64>::zero().inverse_checked(1e-6).is_none());
+    assert_eq!(Matrix3x3::<f64>::zero().condition_estimate(), f64::INFINITY);
+}
+
+#[test]
+fn test_matrix3x3_inverse_checked_well_conditioned() {
+    let mat = Matrix3x3::<f64>::from_mat([[2.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 4.0]]);
+    assert_eq!(mat.inverse_checked(1e-6), mat.inverse());
+    assert!(mat.inverse_checked(1e-6).is_some());
+    assert!(mat.condition_estimate() < 10.0);
+    // Rotations have the smallest condition number, the dimension.
+    let rot = Matrix3x3::<f64>::make_rotation(0.7, &Vector3::new(1.0, 2.0, -3.0));
+    assert!((rot.condition_estimate() - 3.0).abs() < 1e-9);
+    assert!(rot.inverse_checked(1.0 - 1e-9).is_some());
+    // The test doesn't depend on the scale: a tiny scaling is still well conditioned.
+    let tiny = Matrix3x3::<f32>::make_scaling(1e-6, 1e-6, 1e-6);
+    assert!(tiny.inverse_checked(0.5).is_some());
+    assert!((tiny.condition_estimate() - 3.0).abs() < 1e-5);
+}
+
+#[test]
+fn test_matrix3x3_mul_vector2_is_point() {
+    let translation = Matrix3x3::<f32>::make_translation_2d(4.0, -2.0);
+    let point = Vector2::new(1.0, 1.0);
+    assert_eq!(translation * point, Vector2::new(5.0, -1.0));
+    // Directions aren't moved by the translation.
+    assert_eq!(translation.transform_vector_2d(point), point);
+
+    let transform =
+        Matrix3x3::<f64>::make_rotation_2d(0.8) * Matrix3x3::<f64>::make_scaling_2d(2.0, 3.0);
+    let point = Vector2::new(-1.5, 0.25);
+    assert_eq!(transform * point, transform.transform_point_2d(point));
+
+    let mat = Matrix3x3::<i32>::from_mat([[2, 0, 1], [0, 3, -1], [0, 0, 1]]);
+    assert_eq!(mat * Vector2::new(2, 2), Vector2::new(5, 5));
+}
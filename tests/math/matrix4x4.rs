@@ -17,8 +17,11 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+use sky_labs::math::Angle;
 use sky_labs::math::Matrix4x4;
+use sky_labs::math::MatrixLayout;
 use sky_labs::math::Vector3;
+use sky_labs::math::Vector4;
 
 macro_rules! assert_eq_mat {
     ($type:ty, $res:expr, $exp:expr) => {
@@ -272,6 +275,28 @@ fn test_matrix4x4_transpose_all_types() {
     test_matrix4x4_transpose!(f64);
 }
 
+#[test]
+fn test_matrix4x4_columns() {
+    let m = Matrix4x4::from_mat([
+        [1, 2, 3, 4],
+        [5, 6, 7, 8],
+        [9, 10, 11, 12],
+        [13, 14, 15, 16],
+    ]);
+    assert_eq!(m.column(0), Vector4::new(1, 5, 9, 13));
+    assert_eq!(m.column(3), Vector4::new(4, 8, 12, 16));
+    assert_eq!(Matrix4x4::from_columns(m.columns()), m);
+    let transpose = m.transpose();
+    for i in 0..4 {
+        assert_eq!(transpose.column(i), m.rows()[i]);
+    }
+
+    let mut m = m;
+    m.set_column(3, Vector4::new(0, 0, 0, 1));
+    assert_eq!(m.translation(), Vector3::new(0, 0, 0));
+    assert_eq!(m[3], Vector4::new(13, 14, 15, 1));
+}
+
 #[test]
 fn test_matrix4x4_determinant_all_types() {
     test_matrix4x4_determinant!(i32, 0);
@@ -465,7 +490,7 @@ fn test_matrix4x4_make_rotation_y() {
 fn test_matrix4x4_make_rotation() {
     // Rotate over Z axis by 90 degrees (π/2 radians)
     let angle = std::f64::consts::FRAC_PI_2;
-    let rot = Matrix4x4::<f64>::make_rotation(angle, &Vector3::new(0.0, 0.0, -1.0));
+    let rot = Matrix4x4::<f64>::make_rotation(angle, &Vector3::new(0.0, 0.0, 1.0));
     let expected = Matrix4x4::<f64>::from_mat([
         [0.0, -1.0, 0.0, 0.0],
         [1.0, 0.0, 0.0, 0.0],
@@ -476,7 +501,7 @@ fn test_matrix4x4_make_rotation() {
 
     // Rotate over Z axis by 90 degrees (π/2 radians)
     let angle = std::f32::consts::FRAC_PI_2;
-    let rot = Matrix4x4::<f32>::make_rotation(angle, &Vector3::new(0.0, 0.0, -1.0));
+    let rot = Matrix4x4::<f32>::make_rotation(angle, &Vector3::new(0.0, 0.0, 1.0));
     let expected = Matrix4x4::<f32>::from_mat([
         [0.0, -1.0, 0.0, 0.0],
         [1.0, 0.0, 0.0, 0.0],
@@ -486,6 +511,52 @@ fn test_matrix4x4_make_rotation() {
     assert_eq_mat!(f32, rot, expected);
 }
 
+#[test]
+fn test_matrix4x4_make_rotation_basis_axis() {
+    for step in -8..=8 {
+        let angle = step as f64 * std::f64::consts::FRAC_PI_8;
+        let rot = Matrix4x4::<f64>::make_rotation(angle, &Vector3::new(1.0, 0.0, 0.0));
+        assert_eq_mat!(f64, rot, Matrix4x4::<f64>::make_rotation_x(angle));
+        let rot = Matrix4x4::<f64>::make_rotation(angle, &Vector3::new(0.0, 1.0, 0.0));
+        assert_eq_mat!(f64, rot, Matrix4x4::<f64>::make_rotation_y(angle));
+        let rot = Matrix4x4::<f64>::make_rotation(angle, &Vector3::new(0.0, 0.0, 1.0));
+        assert_eq_mat!(f64, rot, Matrix4x4::<f64>::make_rotation_z(angle));
+
+        let angle = angle as f32;
+        let rot = Matrix4x4::<f32>::make_rotation(angle, &Vector3::new(1.0, 0.0, 0.0));
+        assert_eq_mat!(f32, rot, Matrix4x4::<f32>::make_rotation_x(angle));
+        let rot = Matrix4x4::<f32>::make_rotation(angle, &Vector3::new(0.0, 1.0, 0.0));
+        assert_eq_mat!(f32, rot, Matrix4x4::<f32>::make_rotation_y(angle));
+        let rot = Matrix4x4::<f32>::make_rotation(angle, &Vector3::new(0.0, 0.0, 1.0));
+        assert_eq_mat!(f32, rot, Matrix4x4::<f32>::make_rotation_z(angle));
+    }
+}
+
+#[test]
+fn test_matrix4x4_make_rotation_cross_precision() {
+    let axis_f64 = Vector3::<f64>::new(1.0, 2.0, 3.0).normalize();
+    let axis_f32 = Vector3::<f32>::new(1.0, 2.0, 3.0).normalize();
+    for step in -8..=8 {
+        let angle = step as f64 * std::f64::consts::FRAC_PI_8;
+        let rot_f64 = Matrix4x4::<f64>::make_rotation(angle, &axis_f64);
+        let rot_f32 = Matrix4x4::<f32>::make_rotation(angle as f32, &axis_f32);
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!(
+                    (rot_f64[i][j] - rot_f32[i][j] as f64).abs() < 1e-6,
+                    "f64[{},{}] = {}, f32[{},{}] = {}",
+                    i,
+                    j,
+                    rot_f64[i][j],
+                    i,
+                    j,
+                    rot_f32[i][j]
+                );
+            }
+        }
+    }
+}
+
 #[test]
 fn test_matrix4x4_make_scaling() {
     let scale = Matrix4x4::<f64>::make_scaling(2.0, 3.0, 1.0);
@@ -507,6 +578,88 @@ fn test_matrix4x4_make_scaling() {
     assert_eq!(scale, expected);
 }
 
+#[test]
+fn test_matrix4x4_make_translation_v_and_scaling_v() {
+    let v = Vector3::new(2.0, -3.0, 0.5);
+    assert_eq!(
+        Matrix4x4::<f32>::make_translation_v(&v),
+        Matrix4x4::<f32>::make_translation(2.0, -3.0, 0.5)
+    );
+    assert_eq!(
+        Matrix4x4::<f32>::make_scaling_v(&v),
+        Matrix4x4::<f32>::make_scaling(2.0, -3.0, 0.5)
+    );
+    let v = Vector3::new(2.0f64, -3.0, 0.5);
+    assert_eq!(
+        Matrix4x4::<f64>::make_translation_v(&v),
+        Matrix4x4::<f64>::make_translation(2.0, -3.0, 0.5)
+    );
+    assert_eq!(
+        Matrix4x4::<f64>::make_scaling_v(&v),
+        Matrix4x4::<f64>::make_scaling(2.0, -3.0, 0.5)
+    );
+}
+
+#[test]
+fn test_matrix4x4_builder_chain() {
+    let translation = Vector3::new(1.0, 2.0, 3.0);
+    let scale = Vector3::new(2.0, 0.5, 4.0);
+    let chain = Matrix4x4::<f32>::identity()
+        .translate(&translation)
+        .rotate_z(0.5)
+        .scale_by(&scale);
+    let product = Matrix4x4::<f32>::make_translation_v(&translation)
+        * Matrix4x4::<f32>::make_rotation_z(0.5)
+        * Matrix4x4::<f32>::make_scaling_v(&scale);
+    assert_eq_mat!(f32, chain, product);
+
+    // Points are scaled, then rotated, then translated.
+    let point = Vector3::new(1.0, 1.0, 1.0);
+    let expected = Matrix4x4::<f32>::make_rotation_z(0.5) * Vector3::new(2.0, 0.5, 4.0);
+    let transformed = chain * point;
+    assert!((transformed - (expected + translation)).magnitude() < 1e-5);
+
+    let chain = Matrix4x4::<f64>::make_translation(0.0, 1.0, 0.0)
+        .rotate_x(0.25)
+        .rotate_y_angle(Angle::degrees(30.0))
+        .scale_by(&Vector3::new(3.0, 3.0, 3.0));
+    let product = Matrix4x4::<f64>::make_translation(0.0, 1.0, 0.0)
+        * Matrix4x4::<f64>::make_rotation_x(0.25)
+        * Matrix4x4::<f64>::make_rotation_y(30f64.to_radians())
+        * Matrix4x4::<f64>::make_scaling(3.0, 3.0, 3.0);
+    assert_eq_mat!(f64, chain, product);
+    assert_eq!(
+        Matrix4x4::<f32>::identity().rotate_z_angle(Angle::degrees(90.0)),
+        Matrix4x4::<f32>::identity().rotate_z(std::f32::consts::FRAC_PI_2)
+    );
+}
+
+#[test]
+fn test_matrix4x4_make_scaling_axis() {
+    // Scaling along X only affects the X component
+    let scale = Matrix4x4::<f64>::make_scaling_axis(&Vector3::new(1.0, 0.0, 0.0), 2.0);
+    let v = Vector4::new(1.0, 2.0, 3.0, 1.0);
+    assert_eq!(scale * v, Vector4::new(2.0, 2.0, 3.0, 1.0));
+    let scale = Matrix4x4::<f32>::make_scaling_axis(&Vector3::new(1.0, 0.0, 0.0), 2.0);
+    let v = Vector4::new(1.0, 2.0, 3.0, 1.0);
+    assert_eq!(scale * v, Vector4::new(2.0, 2.0, 3.0, 1.0));
+
+    // Scaling along a diagonal doubles the parallel component and keeps the perpendicular one
+    let axis = Vector3::<f64>::new(1.0, 1.0, 0.0).normalize();
+    let scale = Matrix4x4::<f64>::make_scaling_axis(&axis, 2.0);
+    let perpendicular = Vector4::new(1.0, -1.0, 5.0, 1.0);
+    let result = scale * perpendicular;
+    for i in 0..4 {
+        assert!((result[i] - perpendicular[i]).abs() < 1e-12);
+    }
+    let parallel = Vector4::new(3.0, 3.0, 0.0, 1.0);
+    let result = scale * parallel;
+    let expected = Vector4::new(6.0, 6.0, 0.0, 1.0);
+    for i in 0..4 {
+        assert!((result[i] - expected[i]).abs() < 1e-12);
+    }
+}
+
 #[test]
 fn test_matrix4x4_make_reflection_f64() {
     // Reflect over yz-plane (x-axis)
@@ -673,6 +826,44 @@ fn test_matrix4x4_make_skew_pivot_not_perpendicular_f64() {
     let _skew = Matrix4x4::<f64>::make_skew(rad, &direction, &pivot); // Pivot must be perpendicular to direction
 }
 
+#[test]
+fn test_matrix4x4_make_skew_nearly_perpendicular() {
+    // Vectors built by rotations are only perpendicular up to rounding
+    let direction = Vector3::new(0.0, 0.0, 1.0);
+    let pivot = Vector3::new(1.0, 0.5, 1e-9);
+    let rad = std::f64::consts::FRAC_PI_4; // 45 degrees
+    let skew = Matrix4x4::<f64>::make_skew(rad, &direction, &pivot);
+    assert!((skew[2][0] - 1.0).abs() <= 1e-12);
+    assert!((skew[2][1] - 0.5).abs() <= 1e-12);
+    assert!((skew[2][2] - 1.0).abs() <= 1e-8);
+
+    let direction = Vector3::new(0.0, 0.0, 1.0);
+    let pivot = Vector3::new(1.0, 0.5, 1e-9);
+    let rad = std::f32::consts::FRAC_PI_4; // 45 degrees
+    let skew = Matrix4x4::<f32>::make_skew(rad, &direction, &pivot);
+    let expected = Matrix4x4::<f32>::make_skew(rad, &direction, &Vector3::new(1.0, 0.5, 0.0));
+    assert_eq_mat!(f32, skew, expected);
+}
+
+#[test]
+fn test_matrix4x4_try_make_skew() {
+    let direction = Vector3::new(0.0, 0.0, 1.0);
+    let pivot = Vector3::new(1.0, 0.5, 0.0);
+    let rad = std::f64::consts::FRAC_PI_4; // 45 degrees
+    assert_eq!(
+        Matrix4x4::<f64>::try_make_skew(rad, &direction, &pivot),
+        Some(Matrix4x4::<f64>::make_skew(rad, &direction, &pivot))
+    );
+    assert_eq!(Matrix4x4::<f64>::try_make_skew(rad, &Vector3::new(0.0, 0.0, 0.0), &pivot), None);
+    assert_eq!(Matrix4x4::<f64>::try_make_skew(rad, &Vector3::new(0.0, 0.0, 10.0), &pivot), None);
+    assert_eq!(Matrix4x4::<f64>::try_make_skew(rad, &direction, &Vector3::new(0.0, 0.0, 0.0)), None);
+    assert_eq!(Matrix4x4::<f64>::try_make_skew(rad, &direction, &Vector3::new(1.0, 0.5, 1.0)), None);
+    assert_eq!(
+        Matrix4x4::<f32>::try_make_skew(0.5, &Vector3::new(0.0, 0.0, 1.0), &Vector3::new(1.0, 0.5, 1e-3)),
+        None
+    );
+}
+
 #[test]
 #[should_panic]
 fn test_matrix4x4_make_skew_zero_direction_f32() {
@@ -708,3 +899,254 @@ fn test_matrix4x4_make_skew_pivot_not_perpendicular_f32() {
     let rad = std::f32::consts::FRAC_PI_4; // 45 degrees
     let _skew = Matrix4x4::<f32>::make_skew(rad, &direction, &pivot); // Pivot must be perpendicular to direction
 }
+
+#[test]
+fn test_matrix4x4_transform_into() {
+    let translation = Matrix4x4::<f32>::make_translation(1.0, 2.0, 3.0);
+    let vectors = [Vector4::new(0.0, 0.0, 0.0, 1.0), Vector4::new(1.0, 1.0, 1.0, 0.0)];
+    let mut out = [Vector4::default(); 2];
+    translation.transform_into(&vectors, &mut out);
+    assert_eq!(out, [Vector4::new(1.0, 2.0, 3.0, 1.0), Vector4::new(1.0, 1.0, 1.0, 0.0)]);
+    assert_eq!(out[0], translation * vectors[0]);
+
+    let mut in_place = vectors;
+    translation.transform_in_place(&mut in_place);
+    assert_eq!(in_place, out);
+}
+
+#[test]
+#[should_panic]
+fn test_matrix4x4_transform_into_length_mismatch() {
+    let vectors = [Vector4::<f32>::default(); 3];
+    let mut out = [Vector4::default(); 2];
+    Matrix4x4::<f32>::identity().transform_into(&vectors, &mut out);
+}
+
+#[test]
+fn test_matrix4x4_column_major_array() {
+    let translation = Matrix4x4::<f32>::make_translation(5.0, 6.0, 7.0);
+    let row_major = translation.to_array();
+    assert_eq!([row_major[3], row_major[7], row_major[11]], [5.0, 6.0, 7.0]);
+
+    let column_major = translation.to_array_column_major();
+    assert_eq!(&column_major[12..15], &[5.0, 6.0, 7.0]);
+    assert_eq!(column_major[15], 1.0);
+    assert_eq!(Matrix4x4::from_array_column_major(column_major), translation);
+
+    let m = Matrix4x4::<i32>::from_array(std::array::from_fn(|i| i as i32));
+    assert_eq!(m.to_array_column_major()[..4], [0, 4, 8, 12]);
+    assert_eq!(Matrix4x4::from_array_column_major(m.to_array()), m.transpose());
+}
+
+#[test]
+fn test_matrix4x4_as_gpu_bytes() {
+    let translation = Matrix4x4::<f32>::make_translation(5.0, 6.0, 7.0);
+    let element = |bytes: &[u8; 64], index: usize| {
+        f32::from_le_bytes(bytes[4 * index..4 * index + 4].try_into().unwrap())
+    };
+
+    let bytes = translation.as_gpu_bytes(MatrixLayout::ColumnMajor);
+    assert_eq!(element(&bytes, 12), 5.0);
+    assert_eq!(element(&bytes, 13), 6.0);
+    assert_eq!(element(&bytes, 14), 7.0);
+    assert_eq!(element(&bytes, 3), 0.0);
+
+    let bytes = translation.as_gpu_bytes(MatrixLayout::RowMajor);
+    assert_eq!(element(&bytes, 3), 5.0);
+    assert_eq!(element(&bytes, 12), 0.0);
+    let elements: Vec<f32> = (0..16).map(|i| element(&bytes, i)).collect();
+    assert_eq!(elements, translation.to_array());
+}
+
+#[test]
+fn test_matrix4x4_make_rotation_normalizes_axis() {
+    let angle = std::f64::consts::FRAC_PI_3;
+    let rot = Matrix4x4::<f64>::make_rotation(angle, &Vector3::new(0.0, 0.0, 5.0));
+    assert_eq_mat!(f64, rot, Matrix4x4::<f64>::make_rotation_z(angle));
+
+    let rot = Matrix4x4::<f64>::make_rotation(angle, &Vector3::new(2.0, -4.0, 6.0));
+    let axis = Vector3::<f64>::new(1.0, -2.0, 3.0).normalize();
+    let expected = Matrix4x4::<f64>::make_rotation_normalized(angle, &axis);
+    assert_eq_mat!(f64, rot, expected);
+    // A rotation is orthogonal and doesn't scale.
+    assert_eq_mat!(f64, rot * rot.transpose(), Matrix4x4::<f64>::identity());
+    assert!((rot.determinant() - 1.0).abs() < 1e-12);
+
+    let angle = angle as f32;
+    let rot = Matrix4x4::<f32>::make_rotation(angle, &Vector3::new(0.0, 0.0, 0.25));
+    assert_eq_mat!(f32, rot, Matrix4x4::<f32>::make_rotation_z(angle));
+    let rot = Matrix4x4::<f32>::make_rotation(angle, &Vector3::new(2.0, -4.0, 6.0));
+    let product = rot * rot.transpose();
+    for i in 0..4 {
+        for j in 0..4 {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((product[i][j] - expected).abs() < 1e-6);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_matrix4x4_make_rotation_zero_axis() {
+    let _rot = Matrix4x4::<f32>::make_rotation(1.0, &Vector3::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+#[should_panic]
+fn test_matrix4x4_make_rotation_normalized_not_normalized() {
+    let _rot = Matrix4x4::<f64>::make_rotation_normalized(1.0, &Vector3::new(0.0, 0.0, 2.0));
+}
+
+#[test]
+fn test_matrix4x4_transform_accessors() {
+    let translation = Matrix4x4::<f64>::make_translation(1.0, 2.0, 3.0);
+    let rotation = Matrix4x4::<f64>::make_rotation_z(std::f64::consts::FRAC_PI_2);
+    let scaling = Matrix4x4::<f64>::make_scaling(2.0, 3.0, 4.0);
+    let mut transform = translation * rotation * scaling;
+
+    assert_eq!(transform.translation(), Vector3::new(1.0, 2.0, 3.0));
+    // The X-axis is scaled by 2 then rotated onto the Y-axis.
+    let close = |a: Vector3<f64>, b: Vector3<f64>| a.distance_to(&b) < 1e-12;
+    assert!(close(transform.basis_x(), Vector3::new(0.0, 2.0, 0.0)));
+    assert!(close(transform.basis_y(), Vector3::new(-3.0, 0.0, 0.0)));
+    assert!(close(transform.basis_z(), Vector3::new(0.0, 0.0, 4.0)));
+    assert!(close(transform.scale(), Vector3::new(2.0, 3.0, 4.0)));
+    assert!(transform.is_affine());
+    assert!(!transform.is_identity(1e-6));
+
+    transform.set_translation(&Vector3::new(-5.0, 0.0, 5.0));
+    assert_eq!(transform.translation(), Vector3::new(-5.0, 0.0, 5.0));
+    assert!(close(transform.basis_x(), Vector3::new(0.0, 2.0, 0.0)));
+
+    // The sign of a negative scale is not recoverable from the basis lengths.
+    let mirrored = Matrix4x4::<f32>::make_scaling(-2.0, 1.0, 1.0);
+    assert_eq!(mirrored.scale(), Vector3::new(2.0, 1.0, 1.0));
+    assert_eq!(mirrored.basis_x(), Vector3::new(-2.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_matrix4x4_is_affine_and_identity() {
+    assert!(Matrix4x4::<f32>::identity().is_identity(0.0));
+    let almost = Matrix4x4::<f32>::make_translation(1e-4, 0.0, 0.0);
+    assert!(almost.is_identity(1e-3));
+    assert!(!almost.is_identity(1e-5));
+
+    let projection = Matrix4x4::<f32>::from_mat([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+    ]);
+    assert!(!projection.is_affine());
+    assert!(Matrix4x4::<i32>::identity().is_affine());
+}
+
+#[test]
+fn test_matrix4x4_display() {
+    let translation = Matrix4x4::<f32>::make_translation(1.0, 2.5, -3.0);
+    assert_eq!(
+        format!("{:.1}", translation),
+        "[1.0, 0.0, 0.0, 1.0]\n\
+         [0.0, 1.0, 0.0, 2.5]\n\
+         [0.0, 0.0, 1.0, -3.0]\n\
+         [0.0, 0.0, 0.0, 1.0]"
+    );
+    assert_eq!(
+        Matrix4x4::<i32>::identity().to_string(),
+        "[1, 0, 0, 0]\n[0, 1, 0, 0]\n[0, 0, 1, 0]\n[0, 0, 0, 1]"
+    );
+}
+
+#[test]
+fn test_matrix4x4_trace_and_symmetry() {
+    assert_eq!(Matrix4x4::<i32>::identity().trace(), 4);
+    assert_eq!(Matrix4x4::<f32>::identity().trace(), 4.0);
+    let translation = Matrix4x4::<f32>::make_translation(1.0, 2.0, 3.0);
+    assert_eq!(translation.trace(), 4.0);
+    assert!(!translation.is_symmetric(1e-6));
+    assert!(translation.transpose().transpose().is_symmetric(3.0));
+    assert!(Matrix4x4::<f64>::make_scaling(1.0, 2.0, 3.0).is_symmetric(0.0));
+}
+
+#[test]
+fn test_matrix4x4_frobenius_norm() {
+    assert_eq!(Matrix4x4::<f32>::identity().frobenius_norm(), 2.0);
+    let scale = Matrix4x4::<f64>::make_scaling(3.0, 4.0, 0.0);
+    assert!((scale.frobenius_norm() - 26.0f64.sqrt()).abs() < 1e-12);
+}
+
+#[test]
+fn test_matrix4x4_orthonormalize() {
+    let rot = Matrix4x4::<f64>::make_rotation(0.9, &Vector3::new(3.0, -1.0, 1.0));
+    assert!(rot.is_orthogonal(1e-12));
+    let mut perturbed = rot * Matrix4x4::<f64>::make_scaling(1.02, 0.99, 1.0);
+    perturbed[1][2] += 2e-3;
+    perturbed[0][3] = 5.0;
+    assert!(!perturbed.is_orthogonal(1e-6));
+
+    let fixed = perturbed.orthonormalize().unwrap();
+    assert!((fixed.determinant() - 1.0).abs() < 1e-6);
+    // The translation is kept, so only the rotation part is orthogonal.
+    assert_eq!(fixed[0][3], 5.0);
+    assert!(!fixed.is_orthogonal(1e-6));
+    let mut rotation = fixed;
+    rotation[0][3] = 0.0;
+    assert!(rotation.is_orthogonal(1e-6));
+
+    let singular = Matrix4x4::<f32>::make_scaling(1.0, 0.0, 1.0);
+    assert!(singular.orthonormalize().is_none());
+}
+
+#[test]
+fn test_matrix4x4_inverse_checked() {
+    let nearly_singular = Matrix4x4::<f64>::from_mat([
+        [1.0, 2.0, 3.0, 0.0],
+        [4.0, 5.0, 6.0, 0.0],
+        [7.0, 8.0, 9.0 + 1e-9, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+    assert!(nearly_singular.inverse().is_some());
+    assert!(nearly_singular.inverse_checked(1e-6).is_none());
+    assert!(nearly_singular.condition_estimate() > 1e9);
+
+    let transform = Matrix4x4::<f64>::make_translation(1.0, -2.0, 3.0)
+        * Matrix4x4::<f64>::make_rotation(0.3, &Vector3::new(0.0, 1.0, 0.0));
+    assert_eq!(transform.inverse_checked(1e-6), transform.inverse());
+    assert!(transform.inverse_checked(1e-6).is_some());
+    assert!(transform.condition_estimate() < 100.0);
+    assert!((Matrix4x4::<f32>::identity().condition_estimate() - 4.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_matrix4x4_mul_vector3_is_point() {
+    let translation = Matrix4x4::<f32>::make_translation(1.0, 2.5, -3.0);
+    let v = Vector3::new(2.0, -1.0, 0.5);
+    assert_eq!(translation * v, Vector3::new(3.0, 1.5, -2.5));
+    // Directions aren't moved by the translation.
+    assert_eq!(translation.transform_direction(v), v);
+
+    // Same as multiplying the point with w = 1, or the direction with w = 0, and dropping w.
+    let transform = translation
+        * Matrix4x4::<f32>::make_rotation(0.6, &Vector3::new(1.0, 1.0, 0.0))
+        * Matrix4x4::<f32>::make_scaling(2.0, 1.0, 0.5);
+    let point = transform * Vector4::new(v.x, v.y, v.z, 1.0);
+    assert_eq!(transform * v, Vector3::new(point.x, point.y, point.z));
+    let direction = transform * Vector4::new(v.x, v.y, v.z, 0.0);
+    assert_eq!(
+        transform.transform_direction(v),
+        Vector3::new(direction.x, direction.y, direction.z)
+    );
+}
+
+#[test]
+fn test_matrix4x4_is_finite() {
+    assert!(Matrix4x4::<f32>::identity().is_finite());
+    assert!(Matrix4x4::<i32>::identity().is_finite());
+    let mut mat = Matrix4x4::<f64>::make_translation(1.0, 2.0, 3.0);
+    mat[2][3] = f64::NAN;
+    assert!(!mat.is_finite());
+    mat[2][3] = f64::INFINITY;
+    assert!(!mat.is_finite());
+    // Non-finite elements spread through products.
+    assert!(!(Matrix4x4::<f64>::identity() * mat).is_finite());
+}
@@ -0,0 +1,156 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::math::{Matrix2x2, Matrix3x3, Matrix4x4, Vector2, Vector3, Vector4};
+
+#[test]
+fn test_mint_vectors() {
+    let v: mint::Vector2<f32> = Vector2::new(1.0, 2.0).into();
+    assert_eq!(v, mint::Vector2 { x: 1.0, y: 2.0 });
+    assert_eq!(Vector2::from(v), Vector2::new(1.0, 2.0));
+
+    let v: mint::Vector3<i32> = Vector3::new(1, -2, 3).into();
+    assert_eq!(v, mint::Vector3 { x: 1, y: -2, z: 3 });
+    assert_eq!(Vector3::from(v), Vector3::new(1, -2, 3));
+
+    let v: mint::Vector4<f64> = Vector4::new(1.0, 2.0, 3.0, 4.0).into();
+    assert_eq!(
+        v,
+        mint::Vector4 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+            w: 4.0
+        }
+    );
+    assert_eq!(Vector4::from(v), Vector4::new(1.0, 2.0, 3.0, 4.0));
+}
+
+#[test]
+fn test_mint_matrix2x2() {
+    let m = Matrix2x2::<f64>::from_mat([[1.0, 2.0], [3.0, 4.0]]);
+
+    let rows: mint::RowMatrix2<f64> = m.into();
+    assert_eq!(rows.y, mint::Vector2 { x: 3.0, y: 4.0 });
+    assert_eq!(Matrix2x2::from(rows), m);
+
+    let columns: mint::ColumnMatrix2<f64> = m.into();
+    assert_eq!(columns.x, mint::Vector2 { x: 1.0, y: 3.0 });
+    assert_eq!(Matrix2x2::from(columns), m);
+}
+
+#[test]
+fn test_mint_matrix3x3() {
+    let m = Matrix3x3::<f32>::from_mat([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+    let rows: mint::RowMatrix3<f32> = m.into();
+    assert_eq!(
+        rows.x,
+        mint::Vector3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0
+        }
+    );
+    assert_eq!(
+        rows.z,
+        mint::Vector3 {
+            x: 7.0,
+            y: 8.0,
+            z: 9.0
+        }
+    );
+    assert_eq!(Matrix3x3::from(rows), m);
+
+    // Column-major types hold the columns, so the same matrix reads transposed.
+    let columns: mint::ColumnMatrix3<f32> = m.into();
+    assert_eq!(
+        columns.x,
+        mint::Vector3 {
+            x: 1.0,
+            y: 4.0,
+            z: 7.0
+        }
+    );
+    assert_eq!(
+        columns.z,
+        mint::Vector3 {
+            x: 3.0,
+            y: 6.0,
+            z: 9.0
+        }
+    );
+    assert_eq!(Matrix3x3::from(columns), m);
+    assert_eq!(mint::RowMatrix3::from(columns), rows);
+}
+
+#[test]
+fn test_mint_matrix4x4() {
+    let translation = Matrix4x4::<f64>::make_translation(1.0, 2.0, 3.0);
+
+    let rows: mint::RowMatrix4<f64> = translation.into();
+    assert_eq!(
+        rows.x,
+        mint::Vector4 {
+            x: 1.0,
+            y: 0.0,
+            z: 0.0,
+            w: 1.0
+        }
+    );
+    assert_eq!(Matrix4x4::from(rows), translation);
+
+    // The translation ends up in the last column, the `w` member of a column-major matrix.
+    let columns: mint::ColumnMatrix4<f64> = translation.into();
+    assert_eq!(
+        columns.w,
+        mint::Vector4 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+            w: 1.0
+        }
+    );
+    assert_eq!(Matrix4x4::from(columns), translation);
+}
+
+#[test]
+fn test_mint_into_mint() {
+    fn to_mint<T: mint::IntoMint>(value: T) -> T::MintType {
+        value.into()
+    }
+
+    assert_eq!(
+        to_mint(Vector3::new(1.0f32, 2.0, 3.0)),
+        mint::Vector3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0
+        }
+    );
+    let rows: mint::RowMatrix3<f32> = to_mint(Matrix3x3::<f32>::identity());
+    assert_eq!(
+        rows.y,
+        mint::Vector3 {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0
+        }
+    );
+}
@@ -17,6 +17,31 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+mod angle;
+mod collision2d;
+mod constants;
+mod curve;
+#[cfg(all(target_os = "windows", feature = "renderer-d3d12"))]
+mod d2d;
+mod easing;
+mod fixed;
+mod grid;
+mod half;
+mod matrix2x2;
 mod matrix3x3;
+mod matrix4x4;
+#[cfg(feature = "mint")]
+mod mint;
+mod operators;
+mod perspective;
+mod properties;
+#[cfg(feature = "rand")]
+mod rand;
+mod rect;
+mod size;
+mod transform2d;
+mod triangle;
 mod vector2;
 mod vector3;
+mod vector4;
+mod wrap;
@@ -0,0 +1,215 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Compile tests for the operators of the vector types and `Size`, with every combination of
+//! value and reference operands, and scalars on either side of a multiplication.
+
+use sky_labs::math::{Size, Vector2, Vector3, Vector4};
+
+/// Checks `+`, `-`, `*`, `/` and the assignment operators of a vector type for a scalar type.
+macro_rules! test_vector_operators {
+    ($name:ident, $vector:ident, $t:ty, ($($a:expr),+), ($($b:expr),+), $scalar:expr) => {
+        #[test]
+        fn $name() {
+            let a = $vector::<$t>::new($($a),+);
+            let b = $vector::<$t>::new($($b),+);
+            let s: $t = $scalar;
+            let sum = $vector::new($($a + $b),+);
+            let difference = $vector::new($($a - $b),+);
+            let product = $vector::new($($a * $scalar),+);
+            let quotient = $vector::new($($a / $scalar),+);
+
+            assert_eq!(a + b, sum);
+            assert_eq!(&a + b, sum);
+            assert_eq!(a + &b, sum);
+            assert_eq!(&a + &b, sum);
+
+            assert_eq!(a - b, difference);
+            assert_eq!(&a - b, difference);
+            assert_eq!(a - &b, difference);
+            assert_eq!(&a - &b, difference);
+
+            assert_eq!(a * s, product);
+            assert_eq!(&a * s, product);
+            assert_eq!(a * &s, product);
+            assert_eq!(&a * &s, product);
+            assert_eq!(s * a, product);
+            assert_eq!(&s * a, product);
+            assert_eq!(s * &a, product);
+            assert_eq!(&s * &a, product);
+
+            assert_eq!(a / s, quotient);
+            assert_eq!(&a / s, quotient);
+            assert_eq!(a / &s, quotient);
+            assert_eq!(&a / &s, quotient);
+
+            let mut c = a;
+            c += b;
+            c -= &b;
+            c *= s;
+            c /= &s;
+            c *= &s;
+            c /= s;
+            c += &b;
+            c -= b;
+            assert_eq!(c, a);
+        }
+    };
+}
+
+test_vector_operators!(test_vector2_operators_u32, Vector2, u32, (8, 6), (2, 4), 2);
+test_vector_operators!(test_vector2_operators_u64, Vector2, u64, (8, 6), (2, 4), 2);
+test_vector_operators!(test_vector2_operators_i32, Vector2, i32, (8, -6), (2, 4), 2);
+test_vector_operators!(test_vector2_operators_i64, Vector2, i64, (8, -6), (2, 4), 2);
+test_vector_operators!(
+    test_vector2_operators_f32,
+    Vector2,
+    f32,
+    (1.0, -6.0),
+    (2.0, 4.0),
+    0.5
+);
+test_vector_operators!(
+    test_vector2_operators_f64,
+    Vector2,
+    f64,
+    (1.0, -6.0),
+    (2.0, 4.0),
+    0.5
+);
+
+test_vector_operators!(
+    test_vector3_operators_u32,
+    Vector3,
+    u32,
+    (8, 6, 4),
+    (2, 4, 2),
+    2
+);
+test_vector_operators!(
+    test_vector3_operators_u64,
+    Vector3,
+    u64,
+    (8, 6, 4),
+    (2, 4, 2),
+    2
+);
+test_vector_operators!(
+    test_vector3_operators_i32,
+    Vector3,
+    i32,
+    (8, -6, 4),
+    (2, 4, 2),
+    2
+);
+test_vector_operators!(
+    test_vector3_operators_i64,
+    Vector3,
+    i64,
+    (8, -6, 4),
+    (2, 4, 2),
+    2
+);
+test_vector_operators!(
+    test_vector3_operators_f32,
+    Vector3,
+    f32,
+    (1.0, -6.0, 3.0),
+    (2.0, 4.0, 0.5),
+    0.5
+);
+test_vector_operators!(
+    test_vector3_operators_f64,
+    Vector3,
+    f64,
+    (1.0, -6.0, 3.0),
+    (2.0, 4.0, 0.5),
+    0.5
+);
+
+test_vector_operators!(
+    test_vector4_operators_u32,
+    Vector4,
+    u32,
+    (8, 6, 4, 2),
+    (2, 4, 2, 0),
+    2
+);
+test_vector_operators!(
+    test_vector4_operators_f64,
+    Vector4,
+    f64,
+    (1.0, -6.0, 3.0, 1.0),
+    (2.0, 4.0, 0.5, 0.0),
+    0.5
+);
+
+#[test]
+fn test_vector_negation() {
+    let v = Vector3::new(1, -2, 3);
+    assert_eq!(-v, Vector3::new(-1, 2, -3));
+    assert_eq!(-&v, Vector3::new(-1, 2, -3));
+    let v = Vector2::new(1.0, -2.0);
+    assert_eq!(-&v, Vector2::new(-1.0, 2.0));
+    let v = Vector4::new(1.0, -2.0, 0.0, 4.0);
+    assert_eq!(-&v, Vector4::new(-1.0, 2.0, -0.0, -4.0));
+}
+
+/// Checks the scalar operators of `Size` for a scalar type.
+macro_rules! test_size_operators {
+    ($name:ident, $t:ty, ($width:expr, $height:expr), $scalar:expr) => {
+        #[test]
+        fn $name() {
+            let size = Size::<$t>::new($width, $height);
+            let s: $t = $scalar;
+            let product = Size::new($width * $scalar, $height * $scalar);
+            let quotient = Size::new($width / $scalar, $height / $scalar);
+
+            assert_eq!(size * s, product);
+            assert_eq!(&size * s, product);
+            assert_eq!(size * &s, product);
+            assert_eq!(&size * &s, product);
+            assert_eq!(s * size, product);
+            assert_eq!(&s * size, product);
+            assert_eq!(s * &size, product);
+            assert_eq!(&s * &size, product);
+
+            assert_eq!(size / s, quotient);
+            assert_eq!(&size / s, quotient);
+            assert_eq!(size / &s, quotient);
+            assert_eq!(&size / &s, quotient);
+
+            let mut scaled = size;
+            scaled *= s;
+            assert_eq!(scaled, product);
+            scaled /= &s;
+            assert_eq!(scaled, size);
+            scaled *= &s;
+            scaled /= s;
+            assert_eq!(scaled, size);
+        }
+    };
+}
+
+test_size_operators!(test_size_operators_u32, u32, (800, 600), 2);
+test_size_operators!(test_size_operators_u64, u64, (800, 600), 2);
+test_size_operators!(test_size_operators_i32, i32, (800, -600), 2);
+test_size_operators!(test_size_operators_i64, i64, (800, -600), 2);
+test_size_operators!(test_size_operators_f32, f32, (1280.0, 720.0), 1.5);
+test_size_operators!(test_size_operators_f64, f64, (1280.0, 720.0), 1.5);
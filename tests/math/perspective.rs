@@ -0,0 +1,152 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::math::{DepthRange, Matrix4x4, Vector4};
+
+const NEAR: f32 = 0.5;
+const FAR: f32 = 100.0;
+const DISTANT: f32 = 1e6;
+
+/// Depth after the perspective divide of a point on the view axis at distance `z`.
+fn depth_at(projection: &Matrix4x4<f32>, z: f32) -> f32 {
+    let projected = *projection * Vector4::new(0.0, 0.0, z, 1.0);
+    assert_eq!(projected.w, z);
+    projected.z / projected.w
+}
+
+#[test]
+fn test_perspective_zero_to_one() {
+    let fov = std::f32::consts::FRAC_PI_2;
+    let projection =
+        Matrix4x4::<f32>::make_perspective(fov, 16.0 / 9.0, NEAR, FAR, DepthRange::ZeroToOne);
+    let near = depth_at(&projection, NEAR);
+    let twice_near = depth_at(&projection, 2.0 * NEAR);
+    let far = depth_at(&projection, FAR);
+    assert!(near.abs() < 1e-6);
+    assert!((far - 1.0).abs() < 1e-6);
+    assert!(near < twice_near && twice_near < far);
+    // Points past the far plane are clipped.
+    assert!(depth_at(&projection, DISTANT) > 1.0);
+}
+
+#[test]
+fn test_perspective_negative_one_to_one() {
+    let fov = std::f32::consts::FRAC_PI_2;
+    let projection =
+        Matrix4x4::<f32>::make_perspective(fov, 1.0, NEAR, FAR, DepthRange::NegativeOneToOne);
+    let near = depth_at(&projection, NEAR);
+    let twice_near = depth_at(&projection, 2.0 * NEAR);
+    let far = depth_at(&projection, FAR);
+    assert!((near + 1.0).abs() < 1e-5);
+    assert!((far - 1.0).abs() < 1e-5);
+    assert!(near < twice_near && twice_near < far);
+}
+
+#[test]
+fn test_perspective_reversed_z() {
+    let fov = std::f32::consts::FRAC_PI_2;
+    for (depth_range, lowest) in [
+        (DepthRange::ZeroToOne, 0.0),
+        (DepthRange::NegativeOneToOne, -1.0),
+    ] {
+        let projection = Matrix4x4::<f32>::make_perspective_reversed_z(fov, 1.0, NEAR, depth_range);
+        let near = depth_at(&projection, NEAR);
+        let twice_near = depth_at(&projection, 2.0 * NEAR);
+        let distant = depth_at(&projection, DISTANT);
+        assert_eq!(near, 1.0);
+        assert!(near > twice_near && twice_near > distant);
+        // Nothing is clipped by a far plane, the depth only approaches the lowest one.
+        assert!(distant > lowest && distant - lowest < 1e-5);
+    }
+    let projection =
+        Matrix4x4::<f32>::make_perspective_reversed_z(fov, 1.0, NEAR, DepthRange::ZeroToOne);
+    assert_eq!(depth_at(&projection, 2.0 * NEAR), 0.5);
+}
+
+#[test]
+fn test_perspective_f64_matches_f32() {
+    let fov = 1.2;
+    let projection =
+        Matrix4x4::<f64>::make_perspective(fov, 1.5, 0.1, 10.0, DepthRange::NegativeOneToOne);
+    let expected = Matrix4x4::<f32>::make_perspective(
+        fov as f32,
+        1.5,
+        0.1,
+        10.0,
+        DepthRange::NegativeOneToOne,
+    );
+    let reversed =
+        Matrix4x4::<f64>::make_perspective_reversed_z(fov, 1.5, 0.1, DepthRange::ZeroToOne);
+    let reversed_expected =
+        Matrix4x4::<f32>::make_perspective_reversed_z(fov as f32, 1.5, 0.1, DepthRange::ZeroToOne);
+    for i in 0..4 {
+        for j in 0..4 {
+            assert!((projection[i][j] as f32 - expected[i][j]).abs() < 1e-5);
+            assert!((reversed[i][j] as f32 - reversed_expected[i][j]).abs() < 1e-5);
+        }
+    }
+}
+
+#[test]
+fn test_orthographic() {
+    for (depth_range, lowest) in [
+        (DepthRange::ZeroToOne, 0.0),
+        (DepthRange::NegativeOneToOne, -1.0),
+    ] {
+        let projection = Matrix4x4::<f32>::make_orthographic(8.0, 4.0, NEAR, FAR, depth_range);
+        let corner = projection * Vector4::new(4.0, -2.0, NEAR, 1.0);
+        assert_eq!(corner, Vector4::new(1.0, -1.0, lowest, 1.0));
+        let far = projection * Vector4::new(-4.0, 2.0, FAR, 1.0);
+        assert_eq!(far, Vector4::new(-1.0, 1.0, 1.0, 1.0));
+        // Depth is linear in distance.
+        let middle = projection * Vector4::new(0.0, 0.0, (NEAR + FAR) / 2.0, 1.0);
+        assert!((middle.z - (lowest + 1.0) / 2.0).abs() < 1e-6);
+    }
+    let projection = Matrix4x4::<f64>::make_orthographic(2.0, 2.0, 1.0, 3.0, DepthRange::ZeroToOne);
+    assert_eq!(
+        projection * Vector4::new(1.0, 1.0, 2.0, 1.0),
+        Vector4::new(1.0, 1.0, 0.5, 1.0)
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_perspective_functions_match_constructors() {
+    use sky_labs::math::{
+        perspective_f32, perspective_f64, perspective_reversed_z_f32, perspective_reversed_z_f64,
+    };
+
+    let depth_range = DepthRange::NegativeOneToOne;
+    assert_eq!(
+        perspective_f32(1.2, 1.5, NEAR, FAR, depth_range),
+        Matrix4x4::<f32>::make_perspective(1.2, 1.5, NEAR, FAR, depth_range)
+    );
+    assert_eq!(
+        perspective_f64(1.2, 1.5, 0.5, 100.0, depth_range),
+        Matrix4x4::<f64>::make_perspective(1.2, 1.5, 0.5, 100.0, depth_range)
+    );
+    assert_eq!(
+        perspective_reversed_z_f32(1.2, 1.5, NEAR, depth_range),
+        Matrix4x4::<f32>::make_perspective_reversed_z(1.2, 1.5, NEAR, depth_range)
+    );
+    assert_eq!(
+        perspective_reversed_z_f64(1.2, 1.5, 0.5, depth_range),
+        Matrix4x4::<f64>::make_perspective_reversed_z(1.2, 1.5, 0.5, depth_range)
+    );
+}
@@ -0,0 +1,158 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Algebraic identities checked on random inputs. Matrices are sampled with entries in
+//! `[-4, 4]` and resampled when they are badly conditioned, so the identities hold within an
+//! epsilon scaled by the magnitude of the values compared.
+
+use proptest::prelude::*;
+use sky_labs::math::{Matrix3x3, Matrix4x4, Vector3, Vector4};
+
+const EPSILON: f64 = 1e-9;
+
+/// Cases per property, low enough for the suite to stay fast in CI.
+fn config() -> ProptestConfig {
+    ProptestConfig::with_cases(64)
+}
+
+/// Whether `a` and `b` are equal within `EPSILON` scaled by their largest magnitude.
+fn close(a: &[f64], b: &[f64]) -> bool {
+    let scale = a.iter().chain(b).fold(1.0f64, |max, x| max.max(x.abs()));
+    a.iter()
+        .zip(b)
+        .all(|(a, b)| (a - b).abs() <= EPSILON * scale)
+}
+
+/// Hadamard ratio of a matrix given by its rows: `|det|` over the product of the row lengths.
+/// It is 1 for orthogonal rows and tends to 0 as the matrix gets close to singular.
+fn hadamard_ratio(determinant: f64, rows: impl Iterator<Item = f64>) -> f64 {
+    determinant.abs() / rows.product::<f64>()
+}
+
+fn matrix3x3() -> impl Strategy<Value = Matrix3x3<f64>> {
+    prop::array::uniform9(-4.0f64..4.0)
+        .prop_map(Matrix3x3::from_array)
+        .prop_filter("badly conditioned", |m| {
+            hadamard_ratio(m.determinant(), m.rows().iter().map(|row| row.magnitude())) > 0.1
+        })
+}
+
+fn matrix4x4() -> impl Strategy<Value = Matrix4x4<f64>> {
+    prop::array::uniform16(-4.0f64..4.0)
+        .prop_map(Matrix4x4::from_array)
+        .prop_filter("badly conditioned", |m| {
+            hadamard_ratio(m.determinant(), m.rows().iter().map(|row| row.magnitude())) > 0.1
+        })
+}
+
+fn vector3() -> impl Strategy<Value = Vector3<f64>> {
+    prop::array::uniform3(-100.0f64..100.0).prop_map(Vector3::from_array)
+}
+
+macro_rules! matrix_properties {
+    ($($module:ident, $matrix:ident, $strategy:ident;)*) => ($(
+        mod $module {
+            use super::*;
+
+            proptest! {
+                #![proptest_config(config())]
+
+                #[test]
+                fn transpose_of_product(a in $strategy(), b in $strategy()) {
+                    let left = (a * b).transpose();
+                    let right = b.transpose() * a.transpose();
+                    prop_assert!(close(&left.to_array(), &right.to_array()));
+                }
+
+                #[test]
+                fn determinant_of_product(a in $strategy(), b in $strategy()) {
+                    let product = (a * b).determinant();
+                    let expected = a.determinant() * b.determinant();
+                    prop_assert!(close(&[product], &[expected]), "{} != {}", product, expected);
+                }
+
+                #[test]
+                fn product_with_inverse(a in $strategy()) {
+                    let inverse = a.inverse().unwrap();
+                    let identity = $matrix::identity().to_array();
+                    prop_assert!(close(&(a * inverse).to_array(), &identity));
+                    prop_assert!(close(&(inverse * a).to_array(), &identity));
+                }
+
+                #[test]
+                fn inverse_of_inverse(a in $strategy()) {
+                    let twice = a.inverse().unwrap().inverse().unwrap();
+                    prop_assert!(close(&twice.to_array(), &a.to_array()));
+                }
+            }
+        }
+    )*)
+}
+
+matrix_properties! {
+    matrix3x3_properties, Matrix3x3, matrix3x3;
+    matrix4x4_properties, Matrix4x4, matrix4x4;
+}
+
+proptest! {
+    #![proptest_config(config())]
+
+    #[test]
+    fn cross_product_is_perpendicular(a in vector3(), b in vector3()) {
+        let cross = a.cross(&b);
+        let scale = a.magnitude() * a.magnitude() * b.magnitude();
+        prop_assert!(close(&[cross.dot(&a) / scale.max(1.0)], &[0.0]));
+        prop_assert!(close(&[cross.dot(&b) / scale.max(1.0)], &[0.0]));
+    }
+
+    #[test]
+    fn cross_product_length(a in vector3(), b in vector3()) {
+        prop_assume!(a.magnitude() > 1e-3 && b.magnitude() > 1e-3);
+        let cos = (a.dot(&b) / (a.magnitude() * b.magnitude())).clamp(-1.0, 1.0);
+        let sin = cos.acos().sin();
+        let expected = a.magnitude() * b.magnitude() * sin;
+        // The angle is ill-conditioned for nearly parallel vectors, compare squared lengths
+        // relative to |a|²|b|² instead of the lengths themselves.
+        let scale = (a.magnitude() * b.magnitude()).powi(2);
+        let length_squared = a.cross(&b).norm_squared() / scale;
+        prop_assert!(
+            (length_squared - expected * expected / scale).abs() <= 1e-6,
+            "{} != {}",
+            length_squared,
+            expected * expected / scale
+        );
+    }
+
+    #[test]
+    fn rotation_preserves_length(
+        v in vector3(),
+        axis in vector3(),
+        angle in -10.0f64..10.0,
+    ) {
+        prop_assume!(axis.magnitude() > 1e-3);
+        let length = v.magnitude();
+        let rotated = Matrix3x3::<f64>::make_rotation(angle, &axis) * v;
+        prop_assert!(close(&[rotated.magnitude()], &[length]));
+
+        let rotation = Matrix4x4::<f64>::make_rotation(angle, &axis);
+        let rotated = rotation * Vector4::new(v.x, v.y, v.z, 0.0);
+        let rotated = Vector3::new(rotated.x, rotated.y, rotated.z);
+        prop_assert!(close(&[rotated.magnitude()], &[length]));
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use sky_labs::math::{Matrix3x3, Rect, Vector2, Vector3};
+#[cfg(feature = "renderer")]
+use sky_labs::renderer::Color;
+
+const SAMPLES: usize = 10_000;
+
+fn rng() -> StdRng {
+    StdRng::seed_from_u64(0x5EED)
+}
+
+#[test]
+fn test_random_standard_vectors() {
+    let mut rng = rng();
+    for _ in 0..SAMPLES {
+        let v: Vector3<f64> = rng.gen();
+        assert!([v.x, v.y, v.z].iter().all(|c| (0.0..1.0).contains(c)));
+    }
+}
+
+#[test]
+fn test_random_points_in_rect() {
+    let mut rng = rng();
+    let rect = Rect {
+        x: -2.0f32,
+        y: 1.0,
+        width: 4.0,
+        height: 2.0,
+    };
+    let mut sum = Vector2::new(0.0, 0.0);
+    for _ in 0..SAMPLES {
+        let p: Vector2<f32> = rng.sample(rect);
+        assert!((-2.0..2.0).contains(&p.x));
+        assert!((1.0..3.0).contains(&p.y));
+        sum += p;
+    }
+    let mean = sum / SAMPLES as f32;
+    assert!(mean.distance_to(&Vector2::new(0.0, 2.0)) < 0.05);
+}
+
+#[test]
+fn test_random_points_in_box() {
+    let mut rng = rng();
+    let min = Vector3::new(-1.0, 0.0, 10.0);
+    let max = Vector3::new(1.0, 2.0, 20.0);
+    for _ in 0..SAMPLES {
+        let p = Vector3::<f64>::random_in_box(&mut rng, &min, &max);
+        assert!((-1.0..1.0).contains(&p.x));
+        assert!((0.0..2.0).contains(&p.y));
+        assert!((10.0..20.0).contains(&p.z));
+    }
+}
+
+#[test]
+fn test_random_unit_vectors() {
+    let mut rng = rng();
+    let mut sum = Vector2::new(0.0, 0.0);
+    for _ in 0..SAMPLES {
+        let v = Vector2::<f64>::random_unit(&mut rng);
+        assert!((v.magnitude() - 1.0).abs() < 1e-9);
+        sum += v;
+    }
+    assert!((sum / SAMPLES as f64).magnitude() < 0.05);
+
+    let mut sum = Vector3::new(0.0, 0.0, 0.0);
+    let mut upper_hemisphere = 0;
+    for _ in 0..SAMPLES {
+        let v = Vector3::<f32>::random_unit(&mut rng);
+        assert!((v.magnitude() - 1.0).abs() < 1e-5);
+        sum += v;
+        upper_hemisphere += (v.z > 0.0) as usize;
+    }
+    assert!((sum / SAMPLES as f32).magnitude() < 0.05);
+    assert!((upper_hemisphere as f64 / SAMPLES as f64 - 0.5).abs() < 0.02);
+}
+
+#[test]
+fn test_random_rotations() {
+    let mut rng = rng();
+    let mut sum = Vector3::new(0.0, 0.0, 0.0);
+    for _ in 0..SAMPLES {
+        let rotation = Matrix3x3::<f64>::random_rotation(&mut rng);
+        assert!((rotation.determinant() - 1.0).abs() < 1e-9);
+        let identity = rotation * rotation.transpose();
+        for (row, expected) in identity.rows().iter().zip(Matrix3x3::identity().rows()) {
+            assert!(row.distance_to(expected) < 1e-9);
+        }
+
+        let v = rotation * Vector3::new(0.0, 0.0, 1.0);
+        sum += v;
+    }
+    // Uniform rotations send a fixed direction uniformly over the sphere.
+    assert!((sum / SAMPLES as f64).magnitude() < 0.05);
+}
+
+#[test]
+#[cfg(feature = "renderer")]
+fn test_random_opaque_color() {
+    let mut rng = rng();
+    for _ in 0..SAMPLES {
+        let color = Color::<f32>::random_opaque(&mut rng);
+        assert_eq!(color.a, 1.0);
+        assert!([color.r, color.g, color.b]
+            .iter()
+            .all(|c| (0.0..=1.0).contains(c)));
+    }
+}
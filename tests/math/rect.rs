@@ -0,0 +1,71 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::math::{Number, Rect};
+
+#[test]
+fn test_rect_to_f32() {
+    let rect = Rect {
+        x: -800i32,
+        y: 600,
+        width: 1024,
+        height: 768,
+    };
+    assert_eq!(
+        rect.to_f32(),
+        Rect {
+            x: -800.0,
+            y: 600.0,
+            width: 1024.0,
+            height: 768.0,
+        }
+    );
+    let rect = Rect {
+        x: 1u32,
+        y: 2,
+        width: 3,
+        height: 4,
+    };
+    assert_eq!(rect.to_f32().to_u32_round(), rect);
+}
+
+#[test]
+fn test_rect_to_integer_rounding() {
+    let rect = Rect {
+        x: -0.5f32,
+        y: 0.5,
+        width: 10.5,
+        height: 19.4,
+    };
+    fn rounded<T: Number>(x: T, y: T, width: T, height: T) -> Rect<T> {
+        Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+    assert_eq!(rect.to_i32_round(), rounded(-1, 1, 11, 19));
+    assert_eq!(rect.to_i32_floor(), rounded(-1, 0, 10, 19));
+    assert_eq!(rect.to_i32_ceil(), rounded(0, 1, 11, 20));
+    // Unsigned rectangles clamp negative positions to 0.
+    assert_eq!(rect.to_u32_round(), rounded(0, 1, 11, 19));
+    assert_eq!(rect.to_u32_floor(), rounded(0, 0, 10, 19));
+    assert_eq!(rect.to_u32_ceil(), rounded(0, 1, 11, 20));
+}
@@ -0,0 +1,74 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::math::Size;
+
+#[test]
+fn test_size_to_f32() {
+    assert_eq!(Size::new(1280u32, 720).to_f32(), Size::new(1280.0, 720.0));
+    // Beyond 2^24, not every integer has an `f32`.
+    assert_eq!(
+        Size::new(16_777_217u32, 0).to_f32(),
+        Size::new(16_777_216.0, 0.0)
+    );
+}
+
+#[test]
+fn test_size_to_u32_rounding() {
+    let size = Size::new(0.5f32, 2.5);
+    assert_eq!(size.to_u32_round(), Size::new(1, 3));
+    assert_eq!(size.to_u32_floor(), Size::new(0, 2));
+    assert_eq!(size.to_u32_ceil(), Size::new(1, 3));
+
+    let size = Size::new(1279.4f32, 719.6);
+    assert_eq!(size.to_u32_round(), Size::new(1279, 720));
+    assert_eq!(size.to_u32_floor(), Size::new(1279, 719));
+    assert_eq!(size.to_u32_ceil(), Size::new(1280, 720));
+
+    // Whole sides are kept by every rounding, and round-trip.
+    let whole = Size::new(800u32, 600);
+    assert_eq!(whole.to_f32().to_u32_round(), whole);
+    assert_eq!(whole.to_f32().to_u32_floor(), whole);
+    assert_eq!(whole.to_f32().to_u32_ceil(), whole);
+}
+
+#[test]
+fn test_size_to_u32_out_of_range() {
+    assert_eq!(Size::new(-0.5f32, -3.0).to_u32_round(), Size::new(0, 0));
+    assert_eq!(Size::new(-0.5f32, -3.0).to_u32_ceil(), Size::new(0, 0));
+    assert_eq!(
+        Size::new(f32::NAN, 1e10).to_u32_floor(),
+        Size::new(0, u32::MAX)
+    );
+}
+
+#[test]
+fn test_size_from_lossless() {
+    assert_eq!(
+        Size::<f64>::from(Size::new(u32::MAX, 1)),
+        Size::new(u32::MAX as f64, 1.0)
+    );
+    assert_eq!(Size::<i64>::from(Size::new(-2i32, 3)), Size::new(-2i64, 3));
+    assert_eq!(
+        Size::<f64>::from(Size::new(0.1f32, 0.5)),
+        Size::new(0.1f32 as f64, 0.5)
+    );
+    let size: Size<u64> = Size::new(1920u32, 1080).into();
+    assert_eq!(size, Size::new(1920, 1080));
+}
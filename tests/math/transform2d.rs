@@ -0,0 +1,157 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use sky_labs::math::{wrap_angle_radians, Matrix3x3, Transform2D, Vector2};
+
+fn assert_near(result: Vector2<f32>, expected: Vector2<f32>) {
+    assert!(
+        (result - expected).magnitude() < 1e-4,
+        "{result:?} != {expected:?}"
+    );
+}
+
+fn assert_same_transform(result: &Transform2D, expected: &Transform2D) {
+    assert_near(result.position, expected.position);
+    assert!(wrap_angle_radians(result.rotation - expected.rotation).abs() < 1e-5);
+    assert_near(result.scale, expected.scale);
+}
+
+#[test]
+fn test_transform2d_identity() {
+    let p = Vector2::new(3.0, -4.0);
+    assert_eq!(Transform2D::default(), Transform2D::IDENTITY);
+    assert_eq!(Transform2D::IDENTITY.transform_point(p), p);
+    assert_eq!(Transform2D::IDENTITY.to_matrix3(), Matrix3x3::identity());
+}
+
+#[test]
+fn test_transform2d_transform_point_and_vector() {
+    let transform = Transform2D::new(Vector2::new(10.0, 20.0), FRAC_PI_2, Vector2::new(2.0, 3.0));
+    // Scaled to (2, 3), rotated to (-3, 2), then translated.
+    assert_near(
+        transform.transform_point(Vector2::new(1.0, 1.0)),
+        Vector2::new(7.0, 22.0),
+    );
+    assert_near(
+        transform.transform_vector(Vector2::new(1.0, 1.0)),
+        Vector2::new(-3.0, 2.0),
+    );
+
+    let matrix = transform.to_matrix3();
+    for p in [Vector2::new(1.0, 1.0), Vector2::new(-5.0, 0.5)] {
+        assert_near(matrix.transform_point_2d(p), transform.transform_point(p));
+        assert_near(matrix.transform_vector_2d(p), transform.transform_vector(p));
+    }
+}
+
+#[test]
+fn test_transform2d_from_matrix3() {
+    let transform = Transform2D::new(Vector2::new(-1.0, 5.0), 2.5, Vector2::new(0.5, 4.0));
+    let decomposed = Transform2D::from_matrix3(&transform.to_matrix3()).unwrap();
+    assert_same_transform(&decomposed, &transform);
+
+    // A mirrored matrix keeps its mirroring on the Y axis.
+    let mirrored = Matrix3x3::<f32>::make_scaling_2d(-2.0, 1.0);
+    let decomposed = Transform2D::from_matrix3(&mirrored).unwrap();
+    assert_near(decomposed.scale, Vector2::new(2.0, -1.0));
+    let p = Vector2::new(1.0, 2.0);
+    assert_near(
+        decomposed.transform_point(p),
+        mirrored.transform_point_2d(p),
+    );
+
+    assert_eq!(
+        Transform2D::from_matrix3(&Matrix3x3::<f32>::make_skew_2d(0.5, 0.0)),
+        None
+    );
+    assert_eq!(
+        Transform2D::from_matrix3(&Matrix3x3::<f32>::make_scaling_2d(0.0, 1.0)),
+        None
+    );
+    let mut projective = Matrix3x3::<f32>::identity();
+    projective[2][0] = 0.5;
+    assert_eq!(Transform2D::from_matrix3(&projective), None);
+}
+
+#[test]
+fn test_transform2d_then_and_inverse_round_trip() {
+    let child = Transform2D::new(Vector2::new(1.0, 2.0), 0.75, Vector2::new(1.5, 0.5));
+    let parent = Transform2D::new(Vector2::new(-3.0, 4.0), -1.25, Vector2::new(2.0, 2.0));
+    let combined = child.then(&parent);
+    // A different scale on each axis with a rotation can't be inverted without a skew.
+    assert_eq!(combined.inverse(), None);
+
+    let uniform_child = Transform2D {
+        scale: Vector2::new(1.5, 1.5),
+        ..child
+    };
+    let uniform = uniform_child.then(&parent);
+    let inverse = uniform.inverse().unwrap();
+    for p in [
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, -1.0),
+        Vector2::new(10.0, 3.5),
+    ] {
+        assert_near(
+            combined.transform_point(p),
+            parent.transform_point(child.transform_point(p)),
+        );
+        assert_near(
+            combined.to_matrix3().transform_point_2d(p),
+            (parent.to_matrix3() * child.to_matrix3()).transform_point_2d(p),
+        );
+        assert_near(inverse.transform_point(uniform.transform_point(p)), p);
+        assert_near(uniform.transform_point(inverse.transform_point(p)), p);
+    }
+
+    // Without rotation, the inverse undoes a different scale on each axis.
+    let stretched = Transform2D::new(Vector2::new(3.0, 1.0), 0.0, Vector2::new(2.0, 0.5));
+    let inverse = stretched.inverse().unwrap();
+    assert_same_transform(&stretched.then(&inverse), &Transform2D::IDENTITY);
+    let flat = Transform2D::new(Vector2::new(0.0, 0.0), 0.0, Vector2::new(0.0, 1.0));
+    assert_eq!(flat.inverse(), None);
+}
+
+#[test]
+fn test_transform2d_lerp() {
+    let from = Transform2D::new(
+        Vector2::new(0.0, 0.0),
+        350f32.to_radians(),
+        Vector2::new(1.0, 1.0),
+    );
+    let to = Transform2D::new(
+        Vector2::new(10.0, -4.0),
+        10f32.to_radians(),
+        Vector2::new(3.0, 2.0),
+    );
+
+    let middle = from.lerp(&to, 0.5);
+    assert_near(middle.position, Vector2::new(5.0, -2.0));
+    assert_near(middle.scale, Vector2::new(2.0, 1.5));
+    // Through 0 degrees, not 180.
+    assert!(wrap_angle_radians(middle.rotation).abs() < 1e-5);
+    assert!(wrap_angle_radians(from.lerp(&to, 0.25).rotation + 5f32.to_radians()).abs() < 1e-5);
+
+    assert_same_transform(&from.lerp(&to, 0.0), &from);
+    assert_same_transform(&from.lerp(&to, 1.0), &to);
+    let half_turn = Transform2D::new(Vector2::new(0.0, 0.0), PI, Vector2::new(1.0, 1.0));
+    assert!((Transform2D::IDENTITY.lerp(&half_turn, 0.5).rotation.abs() - FRAC_PI_2).abs() < 1e-5);
+}
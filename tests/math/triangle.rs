@@ -0,0 +1,328 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::math::triangle::*;
+use sky_labs::math::{Vector2, Vector3};
+
+fn assert_near(result: Vector3<f64>, expected: Vector3<f64>) {
+    assert!(
+        (result - expected).magnitude() < 1e-12,
+        "{result:?} != {expected:?}"
+    );
+}
+
+const A: Vector3<f64> = Vector3::new(0.0, 0.0, 0.0);
+const B: Vector3<f64> = Vector3::new(4.0, 0.0, 0.0);
+const C: Vector3<f64> = Vector3::new(0.0, 3.0, 0.0);
+
+#[test]
+fn test_triangle_area() {
+    assert_eq!(area_f64(&A, &B, &C), 6.0);
+    assert_eq!(area_f64(&A, &C, &B), 6.0);
+    assert_eq!(area_f64(&A, &B, &(B * 2.0)), 0.0);
+    assert_eq!(
+        area_f32(
+            &Vector3::new(1.0, 1.0, 1.0),
+            &Vector3::new(1.0, 3.0, 1.0),
+            &Vector3::new(1.0, 1.0, 5.0)
+        ),
+        4.0
+    );
+}
+
+#[test]
+fn test_triangle_normal() {
+    assert_eq!(normal_f64(&A, &B, &C), Some(Vector3::new(0.0, 0.0, 1.0)));
+    assert_eq!(normal_f64(&A, &C, &B), Some(Vector3::new(0.0, 0.0, -1.0)));
+    assert_eq!(
+        normal_f32(
+            &Vector3::new(0.0, 0.0, 0.0),
+            &Vector3::new(0.0, 0.0, 2.0),
+            &Vector3::new(2.0, 0.0, 0.0)
+        ),
+        Some(Vector3::new(0.0, 1.0, 0.0))
+    );
+
+    // Collinear and coincident corners have no normal.
+    assert_eq!(normal_f64(&A, &B, &(B * 3.0)), None);
+    assert_eq!(normal_f64(&B, &B, &B), None);
+    assert_eq!(
+        normal_f32(
+            &Vector3::new(0.0, 0.0, 0.0),
+            &Vector3::new(1.0, 1.0, 1.0),
+            &Vector3::new(2.0, 2.0, 2.0)
+        ),
+        None
+    );
+}
+
+#[test]
+fn test_triangle_barycentric() {
+    assert_near(
+        barycentric_f64(&A, &A, &B, &C).unwrap(),
+        Vector3::new(1.0, 0.0, 0.0),
+    );
+    assert_near(
+        barycentric_f64(&B, &A, &B, &C).unwrap(),
+        Vector3::new(0.0, 1.0, 0.0),
+    );
+    assert_near(
+        barycentric_f64(&C, &A, &B, &C).unwrap(),
+        Vector3::new(0.0, 0.0, 1.0),
+    );
+
+    // The centroid weighs each corner equally, and a point on an edge has a zero weight.
+    let centroid = (A + B + C) / 3.0;
+    let third = 1.0 / 3.0;
+    assert_near(
+        barycentric_f64(&centroid, &A, &B, &C).unwrap(),
+        Vector3::new(third, third, third),
+    );
+    let on_edge = Vector3::new(2.0, 1.5, 0.0);
+    assert_near(
+        barycentric_f64(&on_edge, &A, &B, &C).unwrap(),
+        Vector3::new(0.0, 0.5, 0.5),
+    );
+
+    // Points outside have negative weights, and points off the plane are projected onto it.
+    let outside = barycentric_f64(&Vector3::new(-4.0, 0.0, 0.0), &A, &B, &C).unwrap();
+    assert_near(outside, Vector3::new(2.0, -1.0, 0.0));
+    assert_near(
+        barycentric_f64(&Vector3::new(2.0, 1.5, 7.0), &A, &B, &C).unwrap(),
+        Vector3::new(0.0, 0.5, 0.5),
+    );
+
+    // The weighted sum of the corners gives the point back.
+    let p = Vector3::new(1.0f32, 0.5, 0.0);
+    let (a, b, c) = (
+        Vector3::new(0.0f32, 0.0, 0.0),
+        Vector3::new(3.0f32, 0.0, 0.0),
+        Vector3::new(0.0f32, 2.0, 0.0),
+    );
+    let weights = barycentric_f32(&p, &a, &b, &c).unwrap();
+    let q = a * weights.x + b * weights.y + c * weights.z;
+    assert!((q - p).magnitude() < 1e-6);
+
+    assert_eq!(barycentric_f64(&A, &A, &B, &(B * 2.0)), None);
+}
+
+#[test]
+fn test_triangle_contains_point_2d() {
+    let a = Vector2::new(0.0, 0.0);
+    let b = Vector2::new(4.0, 0.0);
+    let c = Vector2::new(0.0, 4.0);
+    for (p, inside) in [
+        (Vector2::new(1.0, 1.0), true),
+        (Vector2::new(2.0, 2.0), true), // On the hypotenuse
+        (Vector2::new(2.0, 0.0), true), // On an edge
+        (Vector2::new(4.0, 0.0), true), // On a corner
+        (Vector2::new(2.5, 2.0), false),
+        (Vector2::new(-0.5, 1.0), false),
+        (Vector2::new(5.0, 0.0), false), // On the line of an edge, past the corner
+    ] {
+        assert_eq!(contains_point_2d_f64(&p, &a, &b, &c), inside, "{p:?}");
+        // The winding doesn't matter.
+        assert_eq!(contains_point_2d_f64(&p, &a, &c, &b), inside, "{p:?}");
+    }
+
+    // A degenerate triangle contains nothing, even the points of its segment.
+    let d = Vector2::new(8.0f32, 0.0);
+    let (a, b) = (Vector2::new(0.0f32, 0.0), Vector2::new(4.0f32, 0.0));
+    assert!(!contains_point_2d_f32(&Vector2::new(2.0, 0.0), &a, &b, &d));
+    assert!(!contains_point_2d_f32(&a, &a, &a, &a));
+
+    // A sliver still contains the points inside it.
+    let sliver = [
+        Vector2::new(0.0f32, 0.0),
+        Vector2::new(1000.0f32, 0.0),
+        Vector2::new(1000.0f32, 0.01),
+    ];
+    let [a, b, c] = sliver;
+    assert!(contains_point_2d_f32(
+        &Vector2::new(900.0, 0.005),
+        &a,
+        &b,
+        &c
+    ));
+    assert!(!contains_point_2d_f32(
+        &Vector2::new(100.0, 0.005),
+        &a,
+        &b,
+        &c
+    ));
+}
+
+/// Sum of the signed areas of `triangles`, positive for counter-clockwise ones.
+fn fan_area(points: &[Vector2<f64>], triangles: &[[usize; 3]]) -> f64 {
+    triangles
+        .iter()
+        .map(|&[a, b, c]| (points[b] - points[a]).cross(&(points[c] - points[a])) / 2.0)
+        .sum()
+}
+
+#[test]
+fn test_triangulate_convex_square() {
+    let square = [
+        Vector2::new(0.0, 0.0),
+        Vector2::new(2.0, 0.0),
+        Vector2::new(2.0, 2.0),
+        Vector2::new(0.0, 2.0),
+    ];
+    let triangles = triangulate_convex_f64(&square);
+    assert_eq!(triangles, vec![[0, 1, 2], [0, 2, 3]]);
+    assert_eq!(fan_area(&square, &triangles), 4.0);
+
+    let mut reversed = square;
+    reversed.reverse();
+    let triangles = triangulate_convex_f64(&reversed);
+    assert_eq!(triangles, vec![[0, 2, 1], [0, 3, 2]]);
+    assert_eq!(fan_area(&reversed, &triangles), 4.0);
+}
+
+#[test]
+fn test_triangulate_convex_hexagon() {
+    let hexagon: Vec<_> = (0..6)
+        .map(|i| {
+            let angle = std::f64::consts::PI / 3.0 * i as f64;
+            Vector2::new(angle.cos(), angle.sin())
+        })
+        .collect();
+    let triangles = triangulate_convex_f64(&hexagon);
+    assert_eq!(triangles.len(), 4);
+    let expected = 3.0 * 3.0f64.sqrt() / 2.0;
+    assert!((fan_area(&hexagon, &triangles) - expected).abs() < 1e-12);
+
+    let clockwise: Vec<_> = hexagon.iter().rev().copied().collect();
+    let triangles = triangulate_convex_f64(&clockwise);
+    assert!((fan_area(&clockwise, &triangles) - expected).abs() < 1e-12);
+}
+
+#[test]
+fn test_triangulate_convex_collinear() {
+    // The first three points are on the bottom edge: their triangle has no area.
+    let points = [
+        Vector2::new(0.0f32, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(2.0, 0.0),
+        Vector2::new(2.0, 2.0),
+        Vector2::new(0.0, 2.0),
+    ];
+    assert_eq!(triangulate_convex_f32(&points), vec![[0, 2, 3], [0, 3, 4]]);
+
+    let line = [
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 1.0),
+        Vector2::new(2.0, 2.0),
+    ];
+    assert!(triangulate_convex_f64(&line).is_empty());
+    assert!(triangulate_convex_f64(&line[..2]).is_empty());
+    assert!(triangulate_convex_f64(&[]).is_empty());
+}
+
+#[test]
+fn test_triangle_closest_point() {
+    // Points in each Voronoi region: inside, past each corner and past each edge.
+    for (p, expected) in [
+        (Vector3::new(1.0, 1.0, 5.0), Vector3::new(1.0, 1.0, 0.0)),
+        (Vector3::new(-1.0, -1.0, 0.0), A),
+        (Vector3::new(6.0, -1.0, 0.0), B),
+        (Vector3::new(-1.0, 5.0, 2.0), C),
+        (Vector3::new(2.0, -3.0, 0.0), Vector3::new(2.0, 0.0, 0.0)),
+        (Vector3::new(-2.0, 1.0, 1.0), Vector3::new(0.0, 1.0, 0.0)),
+        (Vector3::new(4.0, 3.0, 0.0), Vector3::new(2.56, 1.08, 0.0)),
+    ] {
+        assert_near(closest_point_f64(&p, &A, &B, &C), expected);
+    }
+
+    // A point on the triangle is its own closest point.
+    let on_edge = Vector3::new(2.0, 1.5, 0.0);
+    assert_near(closest_point_f64(&on_edge, &A, &B, &C), on_edge);
+
+    // Degenerate triangles are treated as segments, and coincident corners as a point.
+    let far = B * 2.0;
+    assert_near(
+        closest_point_f64(&Vector3::new(6.0, 2.0, 0.0), &A, &B, &far),
+        Vector3::new(6.0, 0.0, 0.0),
+    );
+    assert_near(
+        closest_point_f64(&Vector3::new(9.0, 1.0, 0.0), &A, &far, &B),
+        far,
+    );
+    assert_near(closest_point_f64(&C, &B, &B, &B), B);
+
+    let p = closest_point_f32(
+        &Vector3::new(1.0, 1.0, 1.0),
+        &Vector3::new(0.0, 0.0, 0.0),
+        &Vector3::new(2.0, 0.0, 0.0),
+        &Vector3::new(0.0, 2.0, 0.0),
+    );
+    assert_eq!(p, Vector3::new(1.0, 1.0, 0.0));
+}
+
+#[test]
+fn test_triangle_ray_intersection() {
+    let down = Vector3::new(0.0, 0.0, -1.0);
+    let hit = ray_intersection_f64(&Vector3::new(1.0, 1.0, 5.0), &down, &A, &B, &C).unwrap();
+    assert_eq!(hit.t, 5.0);
+    assert_near(
+        hit.barycentric,
+        barycentric_f64(&Vector3::new(1.0, 1.0, 0.0), &A, &B, &C).unwrap(),
+    );
+
+    // Both faces are hit, and `t` is in multiples of the direction.
+    let up = Vector3::new(0.0, 0.0, 2.0);
+    let hit = ray_intersection_f64(&Vector3::new(1.0, 1.0, -4.0), &up, &A, &B, &C).unwrap();
+    assert_eq!(hit.t, 2.0);
+
+    // Edges and corners are hit.
+    let hit = ray_intersection_f64(&Vector3::new(2.0, 1.5, 1.0), &down, &A, &B, &C).unwrap();
+    assert_near(hit.barycentric, Vector3::new(0.0, 0.5, 0.5));
+    let hit = ray_intersection_f64(&Vector3::new(4.0, 0.0, 1.0), &down, &A, &B, &C).unwrap();
+    assert_near(hit.barycentric, Vector3::new(0.0, 1.0, 0.0));
+
+    // Misses beside the triangle, behind the origin, parallel rays and degenerate triangles.
+    assert_eq!(
+        ray_intersection_f64(&Vector3::new(3.0, 3.0, 1.0), &down, &A, &B, &C),
+        None
+    );
+    assert_eq!(
+        ray_intersection_f64(&Vector3::new(1.0, 1.0, -1.0), &down, &A, &B, &C),
+        None
+    );
+    let along = Vector3::new(1.0, 0.0, 0.0);
+    assert_eq!(
+        ray_intersection_f64(&Vector3::new(-1.0, 1.0, 0.0), &along, &A, &B, &C),
+        None
+    );
+    assert_eq!(
+        ray_intersection_f64(&Vector3::new(1.0, 0.0, 1.0), &down, &A, &B, &(B * 2.0)),
+        None
+    );
+
+    let hit = ray_intersection_f32(
+        &Vector3::new(0.5, 0.5, 1.0),
+        &Vector3::new(0.0, 0.0, -1.0),
+        &Vector3::new(0.0, 0.0, 0.0),
+        &Vector3::new(2.0, 0.0, 0.0),
+        &Vector3::new(0.0, 2.0, 0.0),
+    )
+    .unwrap();
+    assert_eq!(hit.t, 1.0);
+    assert_eq!(hit.barycentric, Vector3::new(0.5, 0.25, 0.25));
+}
@@ -17,7 +17,9 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
-use sky_labs::math::Vector2;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use sky_labs::math::{Angle, Matrix3x3, Size, Vector2};
 
 #[test]
 fn test_vector2_addition_f32() {
@@ -268,6 +270,57 @@ fn test_vector2_normalize_f64() {
     assert_eq!(result, Vector2::new(0.6f64, 0.8f64));
 }
 
+#[test]
+fn test_vector2_try_normalize() {
+    let v = Vector2::new(3.0f64, 4.0f64);
+    assert_eq!(v.try_normalize(), Some(Vector2::new(0.6f64, 0.8f64)));
+    assert!(v.try_normalize().unwrap().is_normalized());
+    assert_eq!(Vector2::<f32>::ZERO.try_normalize(), None);
+    assert_eq!(Vector2::new(1e-30f32, 0.0).try_normalize(), None); // length² underflows
+    assert_eq!(Vector2::new(f64::INFINITY, 0.0).try_normalize(), None);
+
+    let fallback = Vector2::new(1.0f32, 0.0);
+    assert_eq!(Vector2::<f32>::ZERO.normalize_or(fallback), fallback);
+    assert_eq!(
+        Vector2::new(0.0f32, 2.0).normalize_or(fallback),
+        Vector2::new(0.0, 1.0)
+    );
+}
+
+#[test]
+fn test_vector2_non_finite() {
+    assert!(Vector2::new(1.0f32, -2.0).is_finite());
+    assert!(Vector2::new(i32::MAX, i32::MIN).is_finite());
+    assert!(!Vector2::new(f32::NAN, 0.0).is_finite());
+    assert!(!Vector2::new(0.0, f64::NEG_INFINITY).is_finite());
+
+    assert_eq!(Vector2::new(f32::NAN, 1.0).try_normalize(), None);
+    assert_eq!(
+        Vector2::new(f64::INFINITY, 1.0).normalize_or_zero(),
+        Vector2::ZERO
+    );
+    assert_eq!(Vector2::<f32>::ZERO.normalize_or_zero(), Vector2::ZERO);
+    assert_eq!(
+        Vector2::new(0.0f32, -3.0).normalize_or_zero(),
+        Vector2::new(0.0, -1.0)
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "vector must be finite")]
+fn test_vector2_normalize_nan() {
+    Vector2::new(f64::NAN, 1.0).normalize();
+}
+
+#[test]
+fn test_vector2_is_normalized_within() {
+    assert!(!Vector2::new(3.0f64, 4.0f64).is_normalized());
+    assert!(Vector2::new(0.6f32, 0.8f32).is_normalized());
+    assert!(!Vector2::new(1.01f64, 0.0).is_normalized());
+    assert!(Vector2::new(1.01f64, 0.0).is_normalized_within(0.1));
+}
+
 #[test]
 fn test_vector2_dot_product_f32() {
     let v1 = Vector2::new(1.0f32, 2.0f32);
@@ -539,3 +592,462 @@ fn test_vector2_as_mut_ptr_u64() {
     }
     assert_eq!(v, Vector2::new(5u64, 7u64));
 }
+
+#[test]
+fn test_vector2_checked_arithmetic_u32() {
+    let v = Vector2::new(u32::MAX, 1u32);
+    assert_eq!(v.checked_add(&Vector2::new(1u32, 0u32)), None);
+    assert_eq!(
+        v.checked_add(&Vector2::new(0u32, 1u32)),
+        Some(Vector2::new(u32::MAX, 2u32))
+    );
+    let v = Vector2::new(u32::MIN, 1u32);
+    assert_eq!(v.checked_sub(&Vector2::new(0u32, 2u32)), None);
+    assert_eq!(v.checked_sub(&Vector2::new(1u32, 0u32)), None);
+}
+
+#[test]
+fn test_vector2_saturating_arithmetic_u32() {
+    let v = Vector2::new(u32::MAX, u32::MIN);
+    assert_eq!(
+        v.saturating_add(&Vector2::new(1u32, 0u32)),
+        Vector2::new(u32::MAX, u32::MIN)
+    );
+    assert_eq!(
+        v.saturating_sub(&Vector2::new(0u32, 1u32)),
+        Vector2::new(u32::MAX, u32::MIN)
+    );
+}
+
+#[test]
+fn test_vector2_wrapping_arithmetic_u32() {
+    let v = Vector2::new(u32::MAX, u32::MIN);
+    assert_eq!(
+        v.wrapping_add(&Vector2::new(1u32, 0u32)),
+        Vector2::new(u32::MIN, u32::MIN)
+    );
+    assert_eq!(
+        v.wrapping_sub(&Vector2::new(0u32, 1u32)),
+        Vector2::new(u32::MAX, u32::MAX)
+    );
+}
+
+#[test]
+fn test_vector2_checked_arithmetic_u64() {
+    let v = Vector2::new(u64::MAX, 1u64);
+    assert_eq!(v.checked_add(&Vector2::new(1u64, 0u64)), None);
+    assert_eq!(
+        v.checked_add(&Vector2::new(0u64, 1u64)),
+        Some(Vector2::new(u64::MAX, 2u64))
+    );
+    let v = Vector2::new(u64::MIN, 1u64);
+    assert_eq!(v.checked_sub(&Vector2::new(0u64, 2u64)), None);
+    assert_eq!(v.checked_sub(&Vector2::new(1u64, 0u64)), None);
+}
+
+#[test]
+fn test_vector2_saturating_arithmetic_u64() {
+    let v = Vector2::new(u64::MAX, u64::MIN);
+    assert_eq!(
+        v.saturating_add(&Vector2::new(1u64, 0u64)),
+        Vector2::new(u64::MAX, u64::MIN)
+    );
+    assert_eq!(
+        v.saturating_sub(&Vector2::new(0u64, 1u64)),
+        Vector2::new(u64::MAX, u64::MIN)
+    );
+}
+
+#[test]
+fn test_vector2_wrapping_arithmetic_u64() {
+    let v = Vector2::new(u64::MAX, u64::MIN);
+    assert_eq!(
+        v.wrapping_add(&Vector2::new(1u64, 0u64)),
+        Vector2::new(u64::MIN, u64::MIN)
+    );
+    assert_eq!(
+        v.wrapping_sub(&Vector2::new(0u64, 1u64)),
+        Vector2::new(u64::MAX, u64::MAX)
+    );
+}
+
+#[test]
+fn test_vector2_checked_arithmetic_i32() {
+    let v = Vector2::new(i32::MAX, 1i32);
+    assert_eq!(v.checked_add(&Vector2::new(1i32, 0i32)), None);
+    assert_eq!(
+        v.checked_add(&Vector2::new(0i32, 1i32)),
+        Some(Vector2::new(i32::MAX, 2i32))
+    );
+    let v = Vector2::new(i32::MIN, 1i32);
+    assert_eq!(
+        v.checked_sub(&Vector2::new(0i32, 2i32)),
+        Some(Vector2::new(i32::MIN, -1i32))
+    );
+    assert_eq!(v.checked_sub(&Vector2::new(1i32, 0i32)), None);
+}
+
+#[test]
+fn test_vector2_saturating_arithmetic_i32() {
+    let v = Vector2::new(i32::MAX, i32::MIN);
+    assert_eq!(
+        v.saturating_add(&Vector2::new(1i32, 0i32)),
+        Vector2::new(i32::MAX, i32::MIN)
+    );
+    assert_eq!(
+        v.saturating_sub(&Vector2::new(0i32, 1i32)),
+        Vector2::new(i32::MAX, i32::MIN)
+    );
+}
+
+#[test]
+fn test_vector2_wrapping_arithmetic_i32() {
+    let v = Vector2::new(i32::MAX, i32::MIN);
+    assert_eq!(
+        v.wrapping_add(&Vector2::new(1i32, 0i32)),
+        Vector2::new(i32::MIN, i32::MIN)
+    );
+    assert_eq!(
+        v.wrapping_sub(&Vector2::new(0i32, 1i32)),
+        Vector2::new(i32::MAX, i32::MAX)
+    );
+}
+
+#[test]
+fn test_vector2_checked_arithmetic_i64() {
+    let v = Vector2::new(i64::MAX, 1i64);
+    assert_eq!(v.checked_add(&Vector2::new(1i64, 0i64)), None);
+    assert_eq!(
+        v.checked_add(&Vector2::new(0i64, 1i64)),
+        Some(Vector2::new(i64::MAX, 2i64))
+    );
+    let v = Vector2::new(i64::MIN, 1i64);
+    assert_eq!(
+        v.checked_sub(&Vector2::new(0i64, 2i64)),
+        Some(Vector2::new(i64::MIN, -1i64))
+    );
+    assert_eq!(v.checked_sub(&Vector2::new(1i64, 0i64)), None);
+}
+
+#[test]
+fn test_vector2_saturating_arithmetic_i64() {
+    let v = Vector2::new(i64::MAX, i64::MIN);
+    assert_eq!(
+        v.saturating_add(&Vector2::new(1i64, 0i64)),
+        Vector2::new(i64::MAX, i64::MIN)
+    );
+    assert_eq!(
+        v.saturating_sub(&Vector2::new(0i64, 1i64)),
+        Vector2::new(i64::MAX, i64::MIN)
+    );
+}
+
+#[test]
+fn test_vector2_wrapping_arithmetic_i64() {
+    let v = Vector2::new(i64::MAX, i64::MIN);
+    assert_eq!(
+        v.wrapping_add(&Vector2::new(1i64, 0i64)),
+        Vector2::new(i64::MIN, i64::MIN)
+    );
+    assert_eq!(
+        v.wrapping_sub(&Vector2::new(0i64, 1i64)),
+        Vector2::new(i64::MAX, i64::MAX)
+    );
+}
+
+#[test]
+fn test_vector2_try_cast_to_unsigned_i32() {
+    let v = Vector2::new(0i32, i32::MAX);
+    assert_eq!(
+        v.try_cast_to_unsigned(),
+        Some(Vector2::new(0u32, i32::MAX as u32))
+    );
+    assert_eq!(Vector2::new(-1i32, 1i32).try_cast_to_unsigned(), None);
+    assert_eq!(Vector2::new(1i32, -1i32).try_cast_to_unsigned(), None);
+}
+
+#[test]
+fn test_vector2_try_cast_to_unsigned_i64() {
+    let v = Vector2::new(0i64, i64::MAX);
+    assert_eq!(
+        v.try_cast_to_unsigned(),
+        Some(Vector2::new(0u64, i64::MAX as u64))
+    );
+    assert_eq!(Vector2::new(-1i64, 1i64).try_cast_to_unsigned(), None);
+    assert_eq!(Vector2::new(1i64, -1i64).try_cast_to_unsigned(), None);
+}
+
+#[test]
+fn test_vector2_rotate_f32() {
+    let v = Vector2::new(1.0f32, 0.0f32);
+    let result = v.rotate(std::f64::consts::FRAC_PI_2);
+    assert!((result.x - 0.0).abs() < 1e-6);
+    assert!((result.y - 1.0).abs() < 1e-6);
+
+    let v = Vector2::new(0.0f32, 1.0f32);
+    let result = v.rotate(std::f64::consts::FRAC_PI_2);
+    assert!((result.x + 1.0).abs() < 1e-6);
+    assert!((result.y - 0.0).abs() < 1e-6);
+
+    // Rotation preserves the length of the vector
+    let v = Vector2::new(3.0f32, 4.0f32);
+    let result = v.rotate(0.3);
+    assert!((result.magnitude() - 5.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_vector2_rotate_around_f32() {
+    let v = Vector2::new(2.0f32, 1.0f32);
+    let pivot = Vector2::new(1.0f32, 1.0f32);
+    let result = v.rotate_around(&pivot, std::f64::consts::FRAC_PI_2);
+    assert!((result.x - 1.0).abs() < 1e-6);
+    assert!((result.y - 2.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_vector2_rotate_f32_native() {
+    let v = Vector2::new(1.0f32, 0.0f32);
+    let result = v.rotate_f32(std::f32::consts::FRAC_PI_2);
+    assert!((result.x - 0.0).abs() < 1e-6);
+    assert!((result.y - 1.0).abs() < 1e-6);
+    assert_eq!(v.rotate_angle_f32(Angle::radians(0.3)), v.rotate_f32(0.3));
+
+    let pivot = Vector2::new(1.0f32, 1.0f32);
+    let result =
+        Vector2::new(2.0f32, 1.0f32).rotate_around_f32(&pivot, std::f32::consts::FRAC_PI_2);
+    assert!((result.x - 1.0).abs() < 1e-6);
+    assert!((result.y - 2.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_vector2_rotate_f32_matches_matrix_exactly() {
+    let vectors = [
+        Vector2::new(1.0f32, 2.0f32),
+        Vector2::new(-3.7f32, 0.1f32),
+        Vector2::new(1234.5f32, -0.001f32),
+    ];
+    for v in vectors {
+        for step in -16..=16 {
+            let rad = step as f32 * 0.37;
+            let result = v.rotate_f32(rad);
+            let expected = Matrix3x3::<f32>::make_rotation_2d(rad) * v;
+            assert_eq!(result, expected, "rotate_f32({})", rad);
+        }
+    }
+}
+
+#[test]
+fn test_vector2_cross_f32() {
+    let v1 = Vector2::new(1.0f32, 0.0f32);
+    let v2 = Vector2::new(0.0f32, 1.0f32);
+    assert_eq!(v1.cross(&v2), 1.0f32);
+    assert_eq!(v2.cross(&v1), -1.0f32);
+    assert_eq!(v1.cross(&v1), 0.0f32);
+}
+
+#[test]
+fn test_vector2_perpendicular_f32() {
+    let v = Vector2::new(1.0f32, 2.0f32);
+    assert_eq!(v.perpendicular(), Vector2::new(-2.0f32, 1.0f32));
+    assert_eq!(v.dot(v.perpendicular()), 0.0f32);
+}
+
+#[test]
+fn test_vector2_angle_f32() {
+    assert_eq!(Vector2::new(1.0f32, 0.0f32).angle(), 0.0);
+    let angle = Vector2::new(0.0f32, 1.0f32).angle();
+    assert!((angle - std::f64::consts::FRAC_PI_2).abs() < 1e-6);
+    let angle = Vector2::new(-1.0f32, -1.0f32).angle();
+    assert!((angle + 3.0 * std::f64::consts::FRAC_PI_4).abs() < 1e-6);
+}
+
+#[test]
+fn test_vector2_angle_to_f32() {
+    let v1 = Vector2::new(1.0f32, 0.0f32);
+    let v2 = Vector2::new(0.0f32, 2.0f32);
+    assert!((v1.angle_to(&v2) - std::f64::consts::FRAC_PI_2).abs() < 1e-6);
+    assert!((v2.angle_to(&v1) + std::f64::consts::FRAC_PI_2).abs() < 1e-6);
+}
+
+#[test]
+fn test_vector2_from_angle_f32() {
+    let v = Vector2::<f32>::from_angle(std::f64::consts::FRAC_PI_2, 2.0);
+    assert!((v.x - 0.0).abs() < 1e-6);
+    assert!((v.y - 2.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_vector2_rotate_f64() {
+    let v = Vector2::new(1.0f64, 0.0f64);
+    let result = v.rotate(std::f64::consts::FRAC_PI_2);
+    assert!((result.x - 0.0).abs() < 1e-12);
+    assert!((result.y - 1.0).abs() < 1e-12);
+
+    let v = Vector2::new(0.0f64, 1.0f64);
+    let result = v.rotate(std::f64::consts::FRAC_PI_2);
+    assert!((result.x + 1.0).abs() < 1e-12);
+    assert!((result.y - 0.0).abs() < 1e-12);
+
+    // Rotation preserves the length of the vector
+    let v = Vector2::new(3.0f64, 4.0f64);
+    let result = v.rotate(0.3);
+    assert!((result.magnitude() - 5.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_vector2_rotate_around_f64() {
+    let v = Vector2::new(2.0f64, 1.0f64);
+    let pivot = Vector2::new(1.0f64, 1.0f64);
+    let result = v.rotate_around(&pivot, std::f64::consts::FRAC_PI_2);
+    assert!((result.x - 1.0).abs() < 1e-12);
+    assert!((result.y - 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_vector2_cross_f64() {
+    let v1 = Vector2::new(1.0f64, 0.0f64);
+    let v2 = Vector2::new(0.0f64, 1.0f64);
+    assert_eq!(v1.cross(&v2), 1.0f64);
+    assert_eq!(v2.cross(&v1), -1.0f64);
+    assert_eq!(v1.cross(&v1), 0.0f64);
+}
+
+#[test]
+fn test_vector2_perpendicular_f64() {
+    let v = Vector2::new(1.0f64, 2.0f64);
+    assert_eq!(v.perpendicular(), Vector2::new(-2.0f64, 1.0f64));
+    assert_eq!(v.dot(v.perpendicular()), 0.0f64);
+}
+
+#[test]
+fn test_vector2_angle_f64() {
+    assert_eq!(Vector2::new(1.0f64, 0.0f64).angle(), 0.0);
+    let angle = Vector2::new(0.0f64, 1.0f64).angle();
+    assert!((angle - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    let angle = Vector2::new(-1.0f64, -1.0f64).angle();
+    assert!((angle + 3.0 * std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+}
+
+#[test]
+fn test_vector2_angle_to_f64() {
+    let v1 = Vector2::new(1.0f64, 0.0f64);
+    let v2 = Vector2::new(0.0f64, 2.0f64);
+    assert!((v1.angle_to(&v2) - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    assert!((v2.angle_to(&v1) + std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+}
+
+#[test]
+fn test_vector2_from_angle_f64() {
+    let v = Vector2::<f64>::from_angle(std::f64::consts::FRAC_PI_2, 2.0);
+    assert!((v.x - 0.0).abs() < 1e-12);
+    assert!((v.y - 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_vector2_cross_i32() {
+    let v1 = Vector2::new(2i32, 3i32);
+    let v2 = Vector2::new(4i32, 5i32);
+    assert_eq!(v1.cross(&v2), -2i32);
+    assert_eq!(v1.perpendicular(), Vector2::new(-3i32, 2i32));
+}
+
+#[test]
+fn test_vector2_distance_u32() {
+    let v1 = Vector2::new(1u32, 6u32);
+    let v2 = Vector2::new(4u32, 2u32);
+    assert_eq!(v1.distance_to(&v2), 5.0);
+    assert_eq!(v2.distance_to(&v1), 5.0);
+    assert_eq!(v1.taxicab_distance(v2), 7);
+    assert_eq!(v2.taxicab_distance(v1), 7);
+}
+
+#[test]
+fn test_vector2_hash_map_key() {
+    let mut grid = HashMap::new();
+    for x in -2..=2 {
+        for y in -2..=2 {
+            grid.insert(Vector2::<i32>::new(x, y), x * 10 + y);
+        }
+    }
+    assert_eq!(grid.len(), 25);
+    assert_eq!(grid.get(&Vector2::new(-2, 1)), Some(&-19));
+    assert_eq!(grid.get(&Vector2::new(0, 0)), Some(&0));
+    assert_eq!(grid.get(&Vector2::new(3, 0)), None);
+
+    grid.insert(Vector2::new(-2, 1), 7);
+    assert_eq!(grid.len(), 25);
+    assert_eq!(grid[&Vector2::new(-2, 1)], 7);
+}
+
+#[test]
+fn test_vector2_ord() {
+    let set: BTreeSet<Vector2<u32>> = [
+        Vector2::new(1, 0),
+        Vector2::new(0, 2),
+        Vector2::new(0, 1),
+        Vector2::new(1, 0),
+    ]
+    .into_iter()
+    .collect();
+    let ordered: Vec<_> = set.into_iter().collect();
+    assert_eq!(
+        ordered,
+        vec![Vector2::new(0, 1), Vector2::new(0, 2), Vector2::new(1, 0)]
+    );
+}
+
+#[test]
+fn test_size_hash_and_ord() {
+    let mut sizes = HashSet::new();
+    assert!(sizes.insert(Size::<u32>::new(800, 600)));
+    assert!(sizes.insert(Size::<u32>::new(1920, 1080)));
+    assert!(!sizes.insert(Size::<u32>::new(800, 600)));
+    assert_eq!(sizes.len(), 2);
+
+    let smallest = sizes.iter().min().unwrap();
+    assert_eq!(*smallest, Size::new(800, 600));
+}
+
+#[test]
+fn test_vector2_wrap() {
+    let min = Vector2::new(0, -10);
+    let max = Vector2::new(10, 10);
+    assert_eq!(Vector2::new(12, -11).wrap(&min, &max), Vector2::new(2, 9));
+    assert_eq!(Vector2::new(5, 5).wrap(&min, &max), Vector2::new(5, 5));
+
+    let min = Vector2::new(0.0f32, 0.0);
+    let max = Vector2::new(1.0f32, 2.0);
+    assert_eq!(
+        Vector2::new(-0.25f32, 5.0).wrap(&min, &max),
+        Vector2::new(0.75, 1.0)
+    );
+}
+
+#[test]
+fn test_vector2_reductions() {
+    let v = Vector2::new(3, -7);
+    assert_eq!(v.min_element(), -7);
+    assert_eq!(v.max_element(), 3);
+    assert_eq!(v.abs_max_element(), 7);
+    assert_eq!(v.sum(), -4);
+    assert_eq!(v.product(), -21);
+    assert_eq!(v.argmin(), 1);
+    assert_eq!(v.argmax(), 0);
+
+    let ties = Vector2::new(2u32, 2);
+    assert_eq!(ties.argmin(), 0);
+    assert_eq!(ties.argmax(), 0);
+}
+
+#[test]
+fn test_vector2_lerp() {
+    let v1 = Vector2::new(0.0f64, 2.0);
+    let v2 = Vector2::new(4.0, -2.0);
+    assert_eq!(v1.lerp(&v2, 0.0), v1);
+    assert_eq!(v1.lerp(&v2, 1.0), v2);
+    assert_eq!(v1.lerp(&v2, 0.25), Vector2::new(1.0, 1.0));
+    assert_eq!(
+        Vector2::new(0i32, 0i32).lerp(&Vector2::new(10, 10), 0.5),
+        Vector2::new(5, 5)
+    );
+}
@@ -17,8 +17,10 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+use std::collections::{BTreeSet, HashSet};
 use std::f64;
 
+use sky_labs::math::Matrix3x3;
 use sky_labs::math::Vector3;
 
 macro_rules! test_vector3_new {
@@ -122,6 +124,7 @@ macro_rules! test_vector3_distance {
         let expected = 5.0; // sqrt((4-1)^2 + (6-2)^2 + (3-3)^2)
         let result = v1.distance_to(&v2);
         assert!((result - expected).abs() < 1e-6);
+        assert!((v2.distance_to(&v1) - expected).abs() < 1e-6);
     };
 }
 
@@ -147,6 +150,27 @@ macro_rules! test_vector3_rotate {
     };
 }
 
+macro_rules! test_vector3_rotate_matches_matrix {
+    ($type:ty, $rot:ident, $make_rot:ident) => {
+        let v = Vector3::<$type>::new(1 as $type, 2 as $type, 3 as $type);
+        for step in -8..=8 {
+            let angle = step as $type * std::f64::consts::FRAC_PI_8 as $type;
+            let result = v.$rot(angle);
+            let expected = Matrix3x3::<$type>::$make_rot(angle) * v;
+            assert!(
+                (result - expected).magnitude() < 1e-5,
+                "{}({}) = {:?}, expected {:?}",
+                stringify!($rot),
+                angle,
+                result,
+                expected
+            );
+            // Rotations preserve the length of the vector
+            assert!((result.magnitude() - v.magnitude()).abs() < 1e-5);
+        }
+    };
+}
+
 macro_rules! test_vector3_normalize {
     ($type:ty) => {
         let v = Vector3::<$type>::new(3 as $type, 4 as $type, 0 as $type);
@@ -160,6 +184,32 @@ macro_rules! test_vector3_normalize {
     };
 }
 
+macro_rules! test_vector3_try_normalize {
+    ($type:ty) => {
+        let v = Vector3::<$type>::new(3 as $type, 4 as $type, 0 as $type);
+        assert_eq!(v.try_normalize(), Some(v.normalize()));
+        assert_eq!(Vector3::<$type>::ZERO.try_normalize(), None);
+        assert_eq!(
+            Vector3::<$type>::new(<$type>::NAN, 0.0, 0.0).try_normalize(),
+            None
+        );
+
+        let fallback = Vector3::<$type>::new(0 as $type, 0 as $type, 1 as $type);
+        assert_eq!(Vector3::<$type>::ZERO.normalize_or(fallback), fallback);
+        assert_eq!(v.normalize_or(fallback), v.normalize());
+    };
+}
+
+macro_rules! test_vector3_is_normalized_within {
+    ($type:ty) => {
+        let v = Vector3::<$type>::new(1.0001 as $type, 0 as $type, 0 as $type);
+        assert!(!v.is_normalized());
+        assert!(v.is_normalized_within(1e-3));
+        assert!(!Vector3::<$type>::new(2 as $type, 0 as $type, 0 as $type).is_normalized_within(1.0));
+        assert!(Vector3::<$type>::new(1 as $type, 0 as $type, 0 as $type).is_normalized_within(0.0));
+    };
+}
+
 macro_rules! test_vector3_as_slice {
     ($type:ty) => {
         let v = Vector3::<$type>::new(1 as $type, 2 as $type, 3 as $type);
@@ -216,6 +266,45 @@ macro_rules! test_vector3_as_mut_ptr {
     };
 }
 
+macro_rules! test_vector3_checked_arithmetic {
+    ($type:ty) => {
+        let max = Vector3::<$type>::new(<$type>::MAX, 1 as $type, 2 as $type);
+        let min = Vector3::<$type>::new(<$type>::MIN, 1 as $type, 2 as $type);
+        let one_x = Vector3::<$type>::new(1 as $type, 0 as $type, 0 as $type);
+        let one_z = Vector3::<$type>::new(0 as $type, 0 as $type, 1 as $type);
+        assert_eq!(max.checked_add(&one_x), None);
+        assert_eq!(
+            max.checked_add(&one_z),
+            Some(Vector3::<$type>::new(<$type>::MAX, 1 as $type, 3 as $type))
+        );
+        assert_eq!(min.checked_sub(&one_x), None);
+        assert_eq!(
+            min.checked_sub(&one_z),
+            Some(Vector3::<$type>::new(<$type>::MIN, 1 as $type, 1 as $type))
+        );
+    };
+}
+
+macro_rules! test_vector3_saturating_arithmetic {
+    ($type:ty) => {
+        let max = Vector3::<$type>::new(<$type>::MAX, 1 as $type, 2 as $type);
+        let min = Vector3::<$type>::new(<$type>::MIN, 1 as $type, 2 as $type);
+        let one_x = Vector3::<$type>::new(1 as $type, 0 as $type, 0 as $type);
+        assert_eq!(max.saturating_add(&one_x), max);
+        assert_eq!(min.saturating_sub(&one_x), min);
+    };
+}
+
+macro_rules! test_vector3_wrapping_arithmetic {
+    ($type:ty) => {
+        let max = Vector3::<$type>::new(<$type>::MAX, 1 as $type, 2 as $type);
+        let min = Vector3::<$type>::new(<$type>::MIN, 1 as $type, 2 as $type);
+        let one_x = Vector3::<$type>::new(1 as $type, 0 as $type, 0 as $type);
+        assert_eq!(max.wrapping_add(&one_x), min);
+        assert_eq!(min.wrapping_sub(&one_x), max);
+    };
+}
+
 #[test]
 fn test_vector3_new() {
     test_vector3_new!(f32);
@@ -341,7 +430,6 @@ fn test_vector3_distance() {
     test_vector3_distance!(f64);
     test_vector3_distance!(i32);
     test_vector3_distance!(i64);
-    // TODO: handle overflow on unsigned types
     test_vector3_distance!(u32);
     test_vector3_distance!(u64);
 }
@@ -351,9 +439,19 @@ fn test_vector3_cross() {
     test_vector3_cross!(f32);
     test_vector3_cross!(f64);
     test_vector3_cross!(i32);
-    test_vector3_cross!(u32);
     test_vector3_cross!(i64);
-    test_vector3_cross!(u64);
+}
+
+#[test]
+fn test_vector3_taxicab_distance_unsigned() {
+    // Both orderings subtract a larger component from a smaller one somewhere.
+    let v1 = Vector3::new(1u32, 6u32, 3u32);
+    let v2 = Vector3::new(4u32, 2u32, 3u32);
+    assert_eq!(v1.taxicab_distance(&v2), 7);
+    assert_eq!(v2.taxicab_distance(&v1), 7);
+    let origin = Vector3::new(0u64, 0, 0);
+    assert_eq!(origin.taxicab_distance(&Vector3::new(1, 2, 3)), 6);
+    assert_eq!(origin.distance_to(&Vector3::new(0, 3, 4)), 5.0);
 }
 
 #[test]
@@ -364,8 +462,8 @@ fn test_vector3_rotate_x_90_deg() {
 
 #[test]
 fn test_vector3_rotate_y_90_deg() {
-    test_vector3_rotate!(f32, rotate_y, std::f32::consts::FRAC_PI_2, Vector3::new(-3.0, 2.0, 1.0));
-    test_vector3_rotate!(f64, rotate_y, std::f64::consts::FRAC_PI_2, Vector3::new(-3.0, 2.0, 1.0));
+    test_vector3_rotate!(f32, rotate_y, std::f32::consts::FRAC_PI_2, Vector3::new(3.0, 2.0, -1.0));
+    test_vector3_rotate!(f64, rotate_y, std::f64::consts::FRAC_PI_2, Vector3::new(3.0, 2.0, -1.0));
 }
 
 #[test]
@@ -374,6 +472,27 @@ fn test_vector3_rotate_z_90_deg() {
     test_vector3_rotate!(f64, rotate_z, std::f64::consts::FRAC_PI_2, Vector3::new(-2.0, 1.0, 3.0));
 }
 
+#[test]
+fn test_vector3_rotate_matches_matrix() {
+    test_vector3_rotate_matches_matrix!(f32, rotate_x, make_rotation_x);
+    test_vector3_rotate_matches_matrix!(f32, rotate_y, make_rotation_y);
+    test_vector3_rotate_matches_matrix!(f32, rotate_z, make_rotation_z);
+    test_vector3_rotate_matches_matrix!(f64, rotate_x, make_rotation_x);
+    test_vector3_rotate_matches_matrix!(f64, rotate_y, make_rotation_y);
+    test_vector3_rotate_matches_matrix!(f64, rotate_z, make_rotation_z);
+}
+
+#[test]
+fn test_vector3_rotate_f32_matches_matrix_exactly() {
+    let v = Vector3::new(1.0f32, -2.5f32, 0.3f32);
+    for step in -16..=16 {
+        let rad = step as f32 * 0.37;
+        assert_eq!(v.rotate_x(rad), Matrix3x3::<f32>::make_rotation_x(rad) * v);
+        assert_eq!(v.rotate_y(rad), Matrix3x3::<f32>::make_rotation_y(rad) * v);
+        assert_eq!(v.rotate_z(rad), Matrix3x3::<f32>::make_rotation_z(rad) * v);
+    }
+}
+
 #[test]
 fn test_vector3_rotate_axis_90_deg() {
     // for f32
@@ -434,6 +553,49 @@ fn test_vector3_normalize() {
     test_vector3_normalize!(f64);
 }
 
+#[test]
+fn test_vector3_try_normalize() {
+    test_vector3_try_normalize!(f32);
+    test_vector3_try_normalize!(f64);
+}
+
+#[test]
+fn test_vector3_non_finite() {
+    assert!(Vector3::new(1.0f32, -2.0, 3.0).is_finite());
+    assert!(Vector3::new(1u32, 2, 3).is_finite());
+    // A cross product overflowing to infinity.
+    let cross =
+        Vector3::new(f32::MAX, f32::MAX, 0.0).cross(&Vector3::new(f32::MAX, -f32::MAX, 0.0));
+    assert!(!cross.is_finite());
+    assert!(!Vector3::new(0.0, f64::NAN, 0.0).is_finite());
+
+    assert_eq!(cross.try_normalize(), None);
+    assert_eq!(cross.normalize_or_zero(), Vector3::ZERO);
+    assert_eq!(
+        Vector3::new(f64::NAN, 0.0, 1.0).normalize_or_zero(),
+        Vector3::ZERO
+    );
+    assert_eq!(Vector3::<f32>::ZERO.normalize_or_zero(), Vector3::ZERO);
+    assert_eq!(
+        Vector3::new(0.0f64, 0.0, 2.0).normalize_or_zero(),
+        Vector3::new(0.0, 0.0, 1.0)
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "vector must be finite")]
+fn test_vector3_normalize_infinite() {
+    Vector3::new(f32::INFINITY, 0.0, 0.0).normalize();
+}
+
+#[test]
+fn test_vector3_is_normalized_within() {
+    test_vector3_is_normalized_within!(f32);
+    test_vector3_is_normalized_within!(f64);
+    assert!(Vector3::new(0, 1, 0).is_normalized());
+}
+
 #[test]
 fn test_vector3_as_slice() {
     test_vector3_as_slice!(f32);
@@ -473,3 +635,231 @@ fn test_vector3_as_mut_ptr_f32() {
     test_vector3_as_mut_ptr!(u32);
     test_vector3_as_mut_ptr!(u64);
 }
+
+#[test]
+fn test_vector3_checked_arithmetic() {
+    test_vector3_checked_arithmetic!(i32);
+    test_vector3_checked_arithmetic!(i64);
+    test_vector3_checked_arithmetic!(u32);
+    test_vector3_checked_arithmetic!(u64);
+}
+
+#[test]
+fn test_vector3_saturating_arithmetic() {
+    test_vector3_saturating_arithmetic!(i32);
+    test_vector3_saturating_arithmetic!(i64);
+    test_vector3_saturating_arithmetic!(u32);
+    test_vector3_saturating_arithmetic!(u64);
+}
+
+#[test]
+fn test_vector3_wrapping_arithmetic() {
+    test_vector3_wrapping_arithmetic!(i32);
+    test_vector3_wrapping_arithmetic!(i64);
+    test_vector3_wrapping_arithmetic!(u32);
+    test_vector3_wrapping_arithmetic!(u64);
+}
+
+#[test]
+fn test_vector3_try_cast_to_unsigned() {
+    let v = Vector3::new(0i32, 1i32, i32::MAX);
+    assert_eq!(
+        v.try_cast_to_unsigned(),
+        Some(Vector3::new(0u32, 1u32, i32::MAX as u32))
+    );
+    assert_eq!(Vector3::new(1i32, -1i32, 1i32).try_cast_to_unsigned(), None);
+
+    let v = Vector3::new(0i64, 1i64, i64::MAX);
+    assert_eq!(
+        v.try_cast_to_unsigned(),
+        Some(Vector3::new(0u64, 1u64, i64::MAX as u64))
+    );
+    assert_eq!(Vector3::new(1i64, 1i64, -1i64).try_cast_to_unsigned(), None);
+}
+
+macro_rules! test_vector3_hash_and_ord {
+    ($type:ty) => {
+        let mut set = HashSet::new();
+        assert!(set.insert(Vector3::<$type>::new(1, 2, 3)));
+        assert!(set.insert(Vector3::<$type>::new(3, 2, 1)));
+        assert!(!set.insert(Vector3::<$type>::new(1, 2, 3)));
+        assert!(set.contains(&Vector3::new(3, 2, 1)));
+        assert_eq!(set.len(), 2);
+
+        let ordered: Vec<_> = [
+            Vector3::<$type>::new(1, 0, 0),
+            Vector3::<$type>::new(0, 1, 1),
+            Vector3::<$type>::new(0, 1, 0),
+        ]
+        .into_iter()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+        assert_eq!(
+            ordered,
+            vec![
+                Vector3::new(0, 1, 0),
+                Vector3::new(0, 1, 1),
+                Vector3::new(1, 0, 0)
+            ]
+        );
+    };
+}
+
+#[test]
+fn test_vector3_hash_and_ord() {
+    test_vector3_hash_and_ord!(i32);
+    test_vector3_hash_and_ord!(i64);
+    test_vector3_hash_and_ord!(u32);
+    test_vector3_hash_and_ord!(u64);
+}
+
+#[test]
+fn test_vector3_wrap() {
+    let min = Vector3::new(0u32, 0, 5);
+    let max = Vector3::new(4u32, 8, 10);
+    assert_eq!(
+        Vector3::new(9u32, 8, 2).wrap(&min, &max),
+        Vector3::new(1, 0, 7)
+    );
+
+    let min = Vector3::new(-1.0f64, -1.0, -1.0);
+    let max = Vector3::new(1.0f64, 1.0, 1.0);
+    assert_eq!(
+        Vector3::new(1.5f64, -1.5, 0.5).wrap(&min, &max),
+        Vector3::new(-0.5, 0.5, 0.5)
+    );
+}
+
+#[test]
+fn test_vector3_reductions() {
+    let v = Vector3::new(1.5f32, -4.0, 2.0);
+    assert_eq!(v.min_element(), -4.0);
+    assert_eq!(v.max_element(), 2.0);
+    assert_eq!(v.abs_max_element(), 4.0);
+    assert_eq!(v.sum(), -0.5);
+    assert_eq!(v.product(), -12.0);
+    assert_eq!(v.argmin(), 1);
+    assert_eq!(v.argmax(), 2);
+}
+
+#[test]
+fn test_vector3_reductions_propagate_nan() {
+    let v = Vector3::new(1.0f64, f64::NAN, -3.0);
+    assert!(v.min_element().is_nan());
+    assert!(v.max_element().is_nan());
+    assert!(v.abs_max_element().is_nan());
+    assert!(v.sum().is_nan());
+    assert_eq!(v.argmin(), 1);
+    assert_eq!(v.argmax(), 1);
+
+    let first = Vector3::new(f64::NAN, 5.0, -5.0);
+    assert_eq!(first.argmax(), 0);
+    assert_eq!(first.argmin(), 0);
+}
+
+/// Unit directions spread over the sphere, including both poles and points close to them.
+fn sphere_directions() -> Vec<Vector3<f64>> {
+    let mut directions = vec![
+        Vector3::new(0.0, 0.0, 1.0),
+        Vector3::new(0.0, 0.0, -1.0),
+        Vector3::new(1e-7f64, 0.0, -1.0).normalize(),
+        Vector3::new(0.0f64, -1e-7, 1.0).normalize(),
+    ];
+    for i in 0..16 {
+        let polar = std::f64::consts::PI * (i as f64 + 0.5) / 16.0;
+        for j in 0..32 {
+            let azimuth = 2.0 * std::f64::consts::PI * j as f64 / 32.0;
+            directions.push(Vector3::new(
+                polar.sin() * azimuth.cos(),
+                polar.sin() * azimuth.sin(),
+                polar.cos(),
+            ));
+        }
+    }
+    directions
+}
+
+#[test]
+fn test_vector3_any_orthonormal_pair() {
+    for n in sphere_directions() {
+        let (a, b) = n.any_orthonormal_pair();
+        assert!((a.magnitude() - 1.0).abs() < 1e-9, "{:?}", n);
+        assert!((b.magnitude() - 1.0).abs() < 1e-9, "{:?}", n);
+        assert!(a.dot(&b).abs() < 1e-9, "{:?}", n);
+        assert!(a.dot(&n).abs() < 1e-9, "{:?}", n);
+        assert!(b.dot(&n).abs() < 1e-9, "{:?}", n);
+        let determinant = Matrix3x3::<f64>::from_basis(&a, &b, &n).determinant();
+        assert!((determinant - 1.0).abs() < 1e-9, "{:?}", n);
+    }
+}
+
+#[test]
+fn test_vector3_any_orthonormal_pair_f32() {
+    for n in sphere_directions() {
+        let n = Vector3::new(n.x as f32, n.y as f32, n.z as f32).normalize();
+        let (a, b) = n.any_orthonormal_pair();
+        assert!((a.magnitude() - 1.0).abs() < 1e-5);
+        assert!((b.magnitude() - 1.0).abs() < 1e-5);
+        assert!(a.dot(&b).abs() < 1e-5);
+        assert!(a.dot(&n).abs() < 1e-5);
+        assert!(b.dot(&n).abs() < 1e-5);
+        assert!((Matrix3x3::<f32>::from_basis(&a, &b, &n).determinant() - 1.0).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn test_vector3_lerp() {
+    let v1 = Vector3::new(0.0f64, 2.0, 2.0);
+    let v2 = Vector3::new(4.0, -2.0, -2.0);
+    assert_eq!(v1.lerp(&v2, 0.0), v1);
+    assert_eq!(v1.lerp(&v2, 1.0), v2);
+    assert_eq!(v1.lerp(&v2, 0.25), Vector3::new(1.0, 1.0, 1.0));
+    let v = Vector3::new(0i32, 0, 0).lerp(&Vector3::new(10, 10, 10), 0.5);
+    assert_eq!(v, Vector3::new(5, 5, 5));
+}
+
+#[test]
+fn test_vector3_reflect() {
+    let up = Vector3::new(0.0f32, 1.0, 0.0);
+    let incident = Vector3::new(1.0f32, -2.0, 3.0);
+    assert_eq!(incident.reflect(&up), Vector3::new(1.0, 2.0, 3.0));
+    let incident = Vector3::new(0.5f64, -0.5, 0.0);
+    assert_eq!(
+        incident.reflect(&Vector3::new(0.0, 1.0, 0.0)),
+        Vector3::new(0.5, 0.5, 0.0)
+    );
+}
+
+#[test]
+fn test_vector3_refract() {
+    let normal = Vector3::new(0.0f64, 1.0, 0.0);
+    let incident = Vector3::new(1.0f64, -1.0, 0.0).normalize();
+    assert_eq!(incident.refract(&normal, 1.0), Some(incident));
+
+    // From glass into air, bends away from the normal: sin(out) = 1.5 * sin(in).
+    let incident = Vector3::new(0.5f64, -(0.75f64).sqrt(), 0.0);
+    let refracted = incident.refract(&normal, 1.5).unwrap();
+    assert!((refracted.x - 0.75).abs() < 1e-12);
+    assert!((refracted.magnitude() - 1.0).abs() < 1e-12);
+    assert!(refracted.y < 0.0);
+
+    // Grazing at 60 degrees is beyond the critical angle of about 41.8 degrees.
+    let grazing = Vector3::new((0.75f32).sqrt(), -0.5, 0.0);
+    assert_eq!(grazing.refract(&Vector3::new(0.0, 1.0, 0.0), 1.5), None);
+    let incident = Vector3::new(0.6f32, -0.8, 0.0);
+    assert_eq!(
+        incident.refract(&Vector3::new(0.0, 1.0, 0.0), 1.0),
+        Some(incident)
+    );
+}
+
+#[test]
+fn test_vector3_faceforward() {
+    let normal = Vector3::new(0.0f32, 1.0, 0.0);
+    let from_above = Vector3::new(0.0f32, -1.0, 0.0);
+    assert_eq!(normal.faceforward(&from_above, &normal), normal);
+    assert_eq!(normal.faceforward(&-from_above, &normal), -normal);
+    let z = Vector3::new(0, 0, 1);
+    assert_eq!(z.faceforward(&z, &z), Vector3::new(0, 0, -1));
+}
@@ -17,6 +17,8 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+use std::collections::{BTreeSet, HashSet};
+
 use sky_labs::math::{Vector3, Vector4};
 
 macro_rules! test_vector4_new {
@@ -228,6 +230,66 @@ macro_rules! test_vector4_from_vector3 {
     };
 }
 
+macro_rules! test_vector4_magnitude_and_normalize {
+    ($type:ty) => {
+        let v = Vector4::<$type>::new(1 as $type, 2 as $type, 2 as $type, 4 as $type);
+        assert_eq!(v.norm_squared(), 25 as $type);
+        assert_eq!(v.magnitude(), 5.0);
+        assert_eq!(v.modulus(), 5.0);
+        assert!(!v.is_normalized());
+
+        let result = v.normalize();
+        assert!(result.is_normalized());
+        assert!((result.x - 0.2 as $type).abs() < 1e-6);
+        assert!((result.y - 0.4 as $type).abs() < 1e-6);
+        assert!((result.z - 0.4 as $type).abs() < 1e-6);
+        assert!((result.w - 0.8 as $type).abs() < 1e-6);
+        assert_eq!(v.try_normalize(), Some(result));
+        assert_eq!(Vector4::<$type>::ZERO.normalize(), Vector4::<$type>::ZERO);
+        assert_eq!(Vector4::<$type>::ZERO.try_normalize(), None);
+        assert_eq!(Vector4::<$type>::ZERO.normalize_or(result), result);
+        assert!(Vector4::<$type>::new(1.0001 as $type, 0.0, 0.0, 0.0).is_normalized_within(1e-3));
+    };
+}
+
+macro_rules! test_vector4_distance {
+    ($type:ty) => {
+        let v1 = Vector4::<$type>::new(1 as $type, 2 as $type, 3 as $type, 4 as $type);
+        let v2 = Vector4::<$type>::new(2 as $type, 4 as $type, 5 as $type, 8 as $type);
+        let expected = 5.0; // sqrt((2-1)^2 + (4-2)^2 + (5-3)^2 + (8-4)^2)
+        let result = v1.distance_to(&v2);
+        assert!((result - expected).abs() < 1e-6);
+        assert!((v2.distance_to(&v1) - expected).abs() < 1e-6);
+    };
+}
+
+macro_rules! test_vector4_lerp {
+    ($type:ty) => {
+        let v1 = Vector4::<$type>::new(0 as $type, 2 as $type, 4 as $type, 8 as $type);
+        let v2 = Vector4::<$type>::new(4 as $type, 4 as $type, 0 as $type, 16 as $type);
+        assert_eq!(v1.lerp(&v2, 0.0), v1);
+        assert_eq!(v1.lerp(&v2, 1.0), v2);
+        assert_eq!(
+            v1.lerp(&v2, 0.5),
+            Vector4::<$type>::new(2 as $type, 3 as $type, 2 as $type, 12 as $type)
+        );
+    };
+}
+
+macro_rules! test_vector4_homogeneous {
+    ($type:ty) => {
+        let v = Vector4::<$type>::new(2 as $type, 4 as $type, 6 as $type, 2 as $type);
+        assert_eq!(
+            v.to_vector3_homogeneous(),
+            Vector3::<$type>::new(1 as $type, 2 as $type, 3 as $type)
+        );
+        assert_eq!(
+            v.truncate(),
+            Vector3::<$type>::new(2 as $type, 4 as $type, 6 as $type)
+        );
+    };
+}
+
 #[test]
 fn test_vector4_new() {
     test_vector4_new!(f32);
@@ -424,3 +486,117 @@ fn test_vector4_from_vector3() {
     test_vector4_from_vector3!(i64);
     test_vector4_from_vector3!(u64);
 }
+
+macro_rules! test_vector4_hash_and_ord {
+    ($type:ty) => {
+        let mut set = HashSet::new();
+        assert!(set.insert(Vector4::<$type>::new(1, 2, 3, 4)));
+        assert!(!set.insert(Vector4::<$type>::new(1, 2, 3, 4)));
+        assert!(set.contains(&Vector4::new(1, 2, 3, 4)));
+
+        let set: BTreeSet<_> = [
+            Vector4::<$type>::new(0, 0, 1, 0),
+            Vector4::<$type>::new(0, 0, 0, 1),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.first(), Some(&Vector4::new(0, 0, 0, 1)));
+        assert_eq!(set.last(), Some(&Vector4::new(0, 0, 1, 0)));
+    };
+}
+
+#[test]
+fn test_vector4_hash_and_ord() {
+    test_vector4_hash_and_ord!(i32);
+    test_vector4_hash_and_ord!(i64);
+    test_vector4_hash_and_ord!(u32);
+    test_vector4_hash_and_ord!(u64);
+}
+
+#[test]
+fn test_vector4_reductions() {
+    let v = Vector4::new(2i64, -3, 5, -5);
+    assert_eq!(v.min_element(), -5);
+    assert_eq!(v.max_element(), 5);
+    assert_eq!(v.abs_max_element(), 5);
+    assert_eq!(v.sum(), -1);
+    assert_eq!(v.product(), 150);
+    assert_eq!(v.argmin(), 3);
+    assert_eq!(v.argmax(), 2);
+
+    let unsigned = Vector4::new(4u64, 9, 1, 9);
+    assert_eq!(unsigned.argmax(), 1);
+    assert_eq!(unsigned.abs_max_element(), 9);
+    assert_eq!(unsigned.product(), 324);
+}
+
+#[test]
+fn test_vector4_magnitude_and_normalize() {
+    test_vector4_magnitude_and_normalize!(f32);
+    test_vector4_magnitude_and_normalize!(f64);
+    assert_eq!(Vector4::new(0i32, 3, 0, 4).magnitude(), 5.0);
+}
+
+#[test]
+fn test_vector4_distance() {
+    test_vector4_distance!(f32);
+    test_vector4_distance!(f64);
+    test_vector4_distance!(i32);
+    test_vector4_distance!(i64);
+    test_vector4_distance!(u32);
+    test_vector4_distance!(u64);
+}
+
+#[test]
+fn test_vector4_lerp() {
+    test_vector4_lerp!(f32);
+    test_vector4_lerp!(f64);
+    test_vector4_lerp!(i32);
+    test_vector4_lerp!(u64);
+    let v = Vector4::new(0.0f64, 0.0, 0.0, 1.0);
+    let extrapolated = v.lerp(&Vector4::new(1.0, 0.0, 0.0, 1.0), 2.0);
+    assert_eq!(extrapolated, Vector4::new(2.0, 0.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_vector4_homogeneous() {
+    test_vector4_homogeneous!(f32);
+    test_vector4_homogeneous!(f64);
+    test_vector4_homogeneous!(i32);
+    test_vector4_homogeneous!(u32);
+
+    // A point through a perspective projection keeps its direction after the divide
+    let clip = Vector4::new(0.5f32, -0.25, 1.5, 2.0);
+    assert_eq!(
+        clip.to_vector3_homogeneous(),
+        Vector3::new(0.25, -0.125, 0.75)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_vector4_homogeneous_zero_w() {
+    let v = Vector4::new(1.0f64, 2.0, 3.0, 0.0);
+    let _point = v.to_vector3_homogeneous(); // `w` must not be zero
+}
+
+#[test]
+fn test_vector4_non_finite() {
+    assert!(Vector4::new(1.0f32, -2.0, 3.0, 0.0).is_finite());
+    assert!(!Vector4::new(1.0f32, -2.0, 3.0, f32::NAN).is_finite());
+
+    let v = Vector4::new(f64::NEG_INFINITY, 0.0, 0.0, 1.0);
+    assert_eq!(v.try_normalize(), None);
+    assert_eq!(v.normalize_or_zero(), Vector4::ZERO);
+    assert_eq!(
+        Vector4::new(0.0f32, 0.0, 0.0, 4.0).normalize_or_zero(),
+        Vector4::new(0.0, 0.0, 0.0, 1.0)
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "vector must be finite")]
+fn test_vector4_normalize_nan() {
+    Vector4::new(0.0f32, f32::NAN, 0.0, 0.0).normalize();
+}
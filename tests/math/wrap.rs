@@ -0,0 +1,120 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use std::f64::consts::PI;
+
+use sky_labs::math::{wrap_angle_degrees, wrap_angle_radians, Wrap};
+
+#[test]
+fn test_wrap_in_range_is_unchanged() {
+    assert_eq!(3i32.wrap(0, 10), 3);
+    assert_eq!(0i32.wrap(0, 10), 0);
+    assert_eq!(2.5f32.wrap(0.0, 10.0), 2.5);
+}
+
+#[test]
+fn test_wrap_max_is_exclusive() {
+    assert_eq!(10i32.wrap(0, 10), 0);
+    assert_eq!(10u32.wrap(0, 10), 0);
+    assert_eq!(10.0f64.wrap(0.0, 10.0), 0.0);
+    assert_eq!((-10i64).wrap(-10, 10), -10);
+    assert_eq!(10i64.wrap(-10, 10), -10);
+}
+
+#[test]
+fn test_wrap_negative_values() {
+    assert_eq!((-1i32).wrap(0, 10), 9);
+    assert_eq!((-10i32).wrap(0, 10), 0);
+    assert_eq!((-11i32).wrap(0, 10), 9);
+    assert_eq!((-7i64).wrap(-5, 5), 3);
+    assert_eq!((-0.5f32).wrap(0.0, 2.0), 1.5);
+    assert_eq!((-2.5f64).wrap(-1.0, 1.0), -0.5);
+}
+
+#[test]
+fn test_wrap_multi_period_overflow() {
+    assert_eq!(35i32.wrap(0, 10), 5);
+    assert_eq!((-35i32).wrap(0, 10), 5);
+    assert_eq!(1_000_003u64.wrap(0, 10), 3);
+    assert_eq!(7.25f64.wrap(1.0, 2.0), 1.25);
+    assert_eq!((-7.25f64).wrap(1.0, 2.0), 1.75);
+}
+
+#[test]
+fn test_wrap_unsigned_below_min() {
+    assert_eq!(2u32.wrap(5, 10), 7);
+    assert_eq!(0u32.wrap(5, 10), 5);
+    assert_eq!(0u64.wrap(3, 7), 4);
+}
+
+#[test]
+fn test_wrap_near_type_limits() {
+    assert_eq!(i32::MAX.wrap(i32::MIN, i32::MAX), i32::MIN);
+    assert_eq!(i32::MIN.wrap(0, 10), 2);
+    assert_eq!(u64::MAX.wrap(0, 10), 5);
+    assert_eq!(i64::MIN.wrap(i64::MIN, 0), i64::MIN);
+}
+
+#[test]
+fn test_wrap_float_never_returns_max() {
+    let wrapped = (-1e-20f64).wrap(0.0, 1.0);
+    assert!((0.0..1.0).contains(&wrapped));
+    let wrapped = (-1e-10f32).wrap(0.0, 360.0);
+    assert!((0.0..360.0).contains(&wrapped));
+}
+
+#[test]
+#[should_panic]
+fn test_wrap_empty_interval_panics() {
+    5i32.wrap(3, 3);
+}
+
+#[test]
+fn test_ping_pong() {
+    assert_eq!(0i32.ping_pong(0, 4), 0);
+    assert_eq!(3i32.ping_pong(0, 4), 3);
+    assert_eq!(4i32.ping_pong(0, 4), 4);
+    assert_eq!(5i32.ping_pong(0, 4), 3);
+    assert_eq!(8i32.ping_pong(0, 4), 0);
+    assert_eq!(9i32.ping_pong(0, 4), 1);
+    assert_eq!((-1i32).ping_pong(0, 4), 1);
+    assert_eq!((-5i32).ping_pong(0, 4), 3);
+    assert_eq!(1u32.ping_pong(2, 5), 3);
+    assert_eq!(1.5f32.ping_pong(0.0, 1.0), 0.5);
+    assert_eq!(2.25f64.ping_pong(0.0, 1.0), 0.25);
+    assert_eq!((-0.25f64).ping_pong(0.0, 1.0), 0.25);
+}
+
+#[test]
+fn test_wrap_angle_radians() {
+    assert_eq!(wrap_angle_radians(0.0f64), 0.0);
+    assert_eq!(wrap_angle_radians(PI), -PI);
+    assert!((wrap_angle_radians(3.0 * PI / 2.0) + PI / 2.0).abs() < 1e-12);
+    assert!((wrap_angle_radians(-5.0 * PI) + PI).abs() < 1e-12);
+    assert!((wrap_angle_radians(7.0f32) - (7.0 - 2.0 * std::f32::consts::PI)).abs() < 1e-6);
+}
+
+#[test]
+fn test_wrap_angle_degrees() {
+    assert_eq!(wrap_angle_degrees(90.0f32), 90.0);
+    assert_eq!(wrap_angle_degrees(180.0f32), -180.0);
+    assert_eq!(wrap_angle_degrees(270.0f64), -90.0);
+    assert_eq!(wrap_angle_degrees(-190.0f64), 170.0);
+    assert_eq!(wrap_angle_degrees(720.0f64), 0.0);
+}
@@ -0,0 +1,114 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::math::easing::Easing;
+use sky_labs::renderer::Color;
+
+fn assert_color_near(result: Color<f64>, expected: Color<f64>, tolerance: f64) {
+    for (r, e) in result.as_slice().iter().zip(expected.as_slice()) {
+        assert!((r - e).abs() <= tolerance, "{result:?} != {expected:?}");
+    }
+}
+
+#[test]
+fn test_color_to_linear() {
+    let gray = Color::new(0.5f64, 0.5, 0.5, 0.5);
+    let linear = gray.to_linear();
+    assert!((linear.r - 0.2140).abs() < 1e-4);
+    assert!((linear.g - 0.2140).abs() < 1e-4);
+    assert!((linear.b - 0.2140).abs() < 1e-4);
+    // Alpha is not encoded.
+    assert_eq!(linear.a, 0.5);
+
+    // The linear segment near black and the end points.
+    let dark = Color::new(0.0f64, 0.04, 1.0, 1.0).to_linear();
+    assert_eq!(dark.r, 0.0);
+    assert!((dark.g - 0.04 / 12.92).abs() < 1e-12);
+    assert!((dark.b - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_color_to_srgb() {
+    let linear = Color::new(0.2140f64, 0.0, 1.0, 1.0);
+    let srgb = linear.to_srgb();
+    assert!((srgb.r - 0.5).abs() < 1e-4);
+    assert_eq!(srgb.g, 0.0);
+    assert!((srgb.b - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_color_round_trip() {
+    for i in 0..=255 {
+        let value = i as f64 / 255.0;
+        let color = Color::new(value, 1.0 - value, value * 0.5, 1.0);
+        assert_color_near(color.to_linear().to_srgb(), color, 1e-5);
+        assert_color_near(color.to_srgb().to_linear(), color, 1e-5);
+
+        let color = Color::new(value as f32, 1.0 - value as f32, 0.25, 1.0);
+        let round_trip = color.to_linear().to_srgb();
+        assert!((round_trip.r - color.r).abs() <= 1e-5);
+        assert!((round_trip.g - color.g).abs() <= 1e-5);
+    }
+}
+
+#[test]
+fn test_color_lerp_in_linear_space() {
+    let black = Color::new(0.0f64, 0.0, 0.0, 1.0);
+    let white = Color::new(1.0f64, 1.0, 1.0, 0.0);
+    assert_color_near(black.lerp(&white, 0.0), black, 1e-12);
+    assert_color_near(black.lerp(&white, 1.0), white, 1e-12);
+
+    // Halfway in light is brighter than the sRGB value halfway, 0.5.
+    let middle = black.lerp(&white, 0.5);
+    assert!((middle.r - 0.7354).abs() < 1e-4);
+    assert_eq!(middle.r, middle.g);
+    assert_eq!(middle.r, middle.b);
+    assert_eq!(middle.a, 0.5);
+}
+
+#[test]
+fn test_color_premultiply_alpha() {
+    let color = Color::new(1.0f32, 0.5, 0.25, 0.5);
+    assert_eq!(color.premultiply_alpha(), Color::new(0.5, 0.25, 0.125, 0.5));
+    let opaque = Color::new(0.2f32, 0.4, 0.6, 1.0);
+    assert_eq!(opaque.premultiply_alpha(), opaque);
+}
+
+#[test]
+fn test_color_luminance() {
+    assert_eq!(Color::new(0.0f64, 0.0, 0.0, 1.0).luminance(), 0.0);
+    assert!((Color::new(1.0f64, 1.0, 1.0, 1.0).luminance() - 1.0).abs() < 1e-12);
+    assert!((Color::new(1.0f64, 0.0, 0.0, 1.0).luminance() - 0.2126).abs() < 1e-12);
+    // Green dominates perceived brightness.
+    let yellowish = Color::new(1.0f64, 0.5, 0.0, 1.0);
+    assert!((yellowish.luminance() - 0.3657).abs() < 1e-4);
+}
+
+#[test]
+fn test_color_ease_to() {
+    let black = Color::new(0.0f64, 0.0, 0.0, 0.0);
+    let white = Color::new(1.0f64, 1.0, 1.0, 1.0);
+    assert_color_near(black.ease_to(&white, Easing::QuadOut, 1.5), white, 1e-12);
+    assert_color_near(
+        black.ease_to(&white, Easing::QuadOut, 0.5),
+        black.lerp(&white, 0.75),
+        1e-12,
+    );
+    assert_eq!(black.ease_to(&white, Easing::QuadOut, 0.5).a, 0.75);
+}
@@ -0,0 +1,723 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Tests of the Direct3D 12 renderer, through `DefaultRenderer`.
+
+use std::time::Duration;
+
+use sky_labs::math::{Matrix4x4, Rect, Size, Vector2, Vector3};
+use sky_labs::renderer::*;
+use sky_labs::window::Window;
+
+#[test]
+fn test_renderer_create() {
+    let mut window = Window::create();
+    let renderer = DefaultRenderer::create_for_window(&window);
+    let size = renderer.size();
+    assert_ne!(size, Size::new(0.0, 0.0));
+    assert_eq!(renderer.scale_factor(), window.scale_factor());
+}
+
+#[test]
+fn test_renderer_resize_updates_size() {
+    let mut window = Window::create();
+    window.set_size(Size::new(640, 480));
+    let mut renderer = DefaultRenderer::create_for_window(&window);
+    assert_eq!(renderer.size(), Size::new(640.0, 480.0));
+
+    renderer.resize(Size::new(320, 200));
+    assert_eq!(renderer.size(), Size::new(320.0, 200.0));
+    // A minimized window reports an empty client area, the buffers are kept.
+    renderer.resize(Size::new(0, 0));
+    assert_eq!(renderer.size(), Size::new(320.0, 200.0));
+}
+
+#[test]
+fn test_renderer_auto_resize_after_end_draw() {
+    let mut window = Window::create();
+    window.set_size(Size::new(640, 480));
+    while window.poll_event().is_some() {}
+    let renderer = DefaultRenderer::create_for_window(&window);
+    assert_eq!(renderer.size(), Size::new(640.0, 480.0));
+
+    let mut drawing_session = renderer.begin_draw();
+    drawing_session.clear(&Color::new(0.0, 0.0, 1.0, 1.0));
+    // A resize delivered mid-frame keeps the buffers the frame is drawn to.
+    window.set_size(Size::new(320, 200));
+    while window.poll_event().is_some() {}
+    renderer.end_draw(drawing_session);
+    renderer.present();
+    assert_eq!(renderer.size(), Size::new(640.0, 480.0));
+
+    let drawing_session = renderer.begin_draw();
+    assert_eq!(renderer.size(), Size::new(320.0, 200.0));
+    renderer.end_draw(drawing_session);
+    renderer.present();
+}
+
+#[test]
+fn test_renderer_without_auto_resize() {
+    let mut window = Window::create();
+    window.set_size(Size::new(640, 480));
+    let options = RendererOptions {
+        auto_resize: false,
+        ..Default::default()
+    };
+    let renderer = DefaultRenderer::create_for_window_with_options(&window, &options);
+    window.set_size(Size::new(320, 200));
+    while window.poll_event().is_some() {}
+    let drawing_session = renderer.begin_draw();
+    assert_eq!(renderer.size(), Size::new(640.0, 480.0));
+    renderer.end_draw(drawing_session);
+}
+
+#[test]
+fn test_renderer_offscreen_size_and_scale_factor() {
+    let renderer = DefaultRenderer::create_offscreen(Size::new(64, 32)).unwrap();
+    assert_eq!(renderer.size(), Size::new(64.0, 32.0));
+    assert_eq!(renderer.scale_factor(), 1.0);
+}
+
+#[test]
+fn test_renderer_offscreen_on_warp_clears_frame() {
+    const WIDTH: u32 = 16;
+    const HEIGHT: u32 = 8;
+    let options = RendererOptions {
+        adapter: AdapterPreference::Warp,
+        ..Default::default()
+    };
+    let renderer =
+        DefaultRenderer::create_offscreen_with_options(Size::new(WIDTH, HEIGHT), &options).unwrap();
+    let info = renderer.info();
+    assert!(info.is_warp);
+    assert!(!info.adapter_name.is_empty());
+    assert!(info.feature_level >= (11, 0));
+
+    let mut drawing_session = renderer.begin_draw();
+    drawing_session.clear(&Color::new(0.0, 0.0, 1.0, 1.0));
+    renderer.end_draw(drawing_session);
+
+    let pixels = renderer.read_pixels();
+    assert_eq!(pixel_at(&pixels, WIDTH, 0, 0), [0, 0, 255, 255]);
+    assert_eq!(pixel_at(&pixels, WIDTH, WIDTH - 1, HEIGHT - 1), [0, 0, 255, 255]);
+}
+
+#[test]
+fn test_renderer_shutdown_twice() {
+    let options = RendererOptions {
+        adapter: AdapterPreference::Warp,
+        ..Default::default()
+    };
+    let mut renderer =
+        DefaultRenderer::create_offscreen_with_options(Size::new(16, 8), &options).unwrap();
+    let drawing_session = renderer.begin_draw();
+    renderer.end_draw(drawing_session);
+
+    renderer.shutdown();
+    renderer.shutdown();
+}
+
+#[test]
+fn test_renderer_recreate() {
+    const WIDTH: u32 = 16;
+    const HEIGHT: u32 = 8;
+    let window = Window::create();
+    let options = RendererOptions {
+        adapter: AdapterPreference::Warp,
+        ..Default::default()
+    };
+
+    let mut renderer =
+        DefaultRenderer::create_offscreen_with_options(Size::new(WIDTH, HEIGHT), &options).unwrap();
+    renderer.recreate(&window).unwrap();
+    assert_eq!(renderer.size(), Size::new(WIDTH as f32, HEIGHT as f32));
+    let mut drawing_session = renderer.begin_draw();
+    drawing_session.clear(&Color::new(0.0, 0.0, 1.0, 1.0));
+    renderer.end_draw(drawing_session);
+    assert_eq!(pixel_at(&renderer.read_pixels(), WIDTH, 0, 0), [0, 0, 255, 255]);
+
+    // The swap chain of the previous renderer must be released for the window to take another.
+    let mut renderer = DefaultRenderer::create_for_window_with_options(&window, &options);
+    for _ in 0..2 {
+        renderer.recreate(&window).unwrap();
+        let mut drawing_session = renderer.begin_draw();
+        drawing_session.clear(&Color::new(0.0, 0.0, 1.0, 1.0));
+        renderer.end_draw(drawing_session);
+        renderer.present();
+    }
+}
+
+#[test]
+fn test_renderer_buffer_count() {
+    let window = Window::create();
+    for buffer_count in [2, 3] {
+        let options = RendererOptions {
+            adapter: AdapterPreference::Warp,
+            buffer_count,
+            ..Default::default()
+        };
+        let renderer = DefaultRenderer::create_for_window_with_options(&window, &options);
+        let mut back_buffers = Vec::new();
+        for _ in 0..2 * buffer_count {
+            let mut drawing_session = renderer.begin_draw();
+            drawing_session.clear(&Color::new(0.0, 0.0, 1.0, 1.0));
+            renderer.end_draw(drawing_session);
+            renderer.present();
+            back_buffers.push(renderer.back_buffer_index());
+        }
+        back_buffers.sort();
+        back_buffers.dedup();
+        assert_eq!(back_buffers, (0..buffer_count).collect::<Vec<_>>());
+
+        let renderer =
+            DefaultRenderer::create_offscreen_with_options(Size::new(16, 8), &options).unwrap();
+        for _ in 0..buffer_count {
+            let mut drawing_session = renderer.begin_draw();
+            drawing_session.clear(&Color::new(0.0, 0.0, 1.0, 1.0));
+            renderer.end_draw(drawing_session);
+        }
+        let pixels = renderer.read_pixels();
+        assert_eq!(pixel_at(&pixels, 16, 15, 7), [0, 0, 255, 255]);
+    }
+}
+
+#[test]
+fn test_renderer_invalid_buffer_count() {
+    let window = Window::create();
+    for buffer_count in [0, 1, RendererOptions::MAX_BUFFER_COUNT + 1] {
+        let options = RendererOptions {
+            adapter: AdapterPreference::Warp,
+            buffer_count,
+            ..Default::default()
+        };
+        assert!(matches!(options.validate(), Err(RendererError::Failed(_))));
+        assert!(matches!(
+            DefaultRenderer::create_offscreen_with_options(Size::new(16, 8), &options),
+            Err(RendererError::Failed(_))
+        ));
+        assert!(DefaultRenderer::try_create_for_window_with_options(&window, &options).is_err());
+    }
+    assert_eq!(
+        RendererOptions::default().buffer_count,
+        RendererOptions::DEFAULT_BUFFER_COUNT
+    );
+    assert!(RendererOptions::default().validate().is_ok());
+}
+
+#[test]
+fn test_renderer_frame_latency_waitable() {
+    let window = Window::create();
+    let options = RendererOptions {
+        adapter: AdapterPreference::Warp,
+        max_frame_latency: Some(1),
+        ..Default::default()
+    };
+    let renderer = DefaultRenderer::create_for_window_with_options(&window, &options);
+    for _ in 0..10 {
+        // Signaled once the previous frame left the queue, so the loop never deadlocks.
+        assert!(renderer.try_wait_for_next_frame(Duration::from_secs(5)));
+        let mut drawing_session = renderer.begin_draw();
+        drawing_session.clear(&Color::new(0.0, 0.0, 1.0, 1.0));
+        renderer.end_draw(drawing_session);
+        renderer.present();
+    }
+    renderer.wait_for_next_frame();
+    // Releases the swap chain, the window only takes one at a time.
+    drop(renderer);
+
+    // Without a maximum latency, and offscreen, there is nothing to wait for.
+    let renderer = DefaultRenderer::create_for_window(&window);
+    assert!(renderer.try_wait_for_next_frame(Duration::ZERO));
+    let renderer =
+        DefaultRenderer::create_offscreen_with_options(Size::new(16, 8), &options).unwrap();
+    assert!(renderer.try_wait_for_next_frame(Duration::ZERO));
+}
+
+#[test]
+fn test_renderer_invalid_max_frame_latency() {
+    for latency in [0, RendererOptions::MAX_FRAME_LATENCY + 1] {
+        let options = RendererOptions {
+            adapter: AdapterPreference::Warp,
+            max_frame_latency: Some(latency),
+            ..Default::default()
+        };
+        assert!(matches!(options.validate(), Err(RendererError::Failed(_))));
+    }
+    assert_eq!(RendererOptions::default().max_frame_latency, None);
+}
+
+#[test]
+fn test_renderer_back_buffer_formats() {
+    const WIDTH: u32 = 16;
+    const HEIGHT: u32 = 8;
+    assert_eq!(
+        RendererOptions::default().format,
+        BackBufferFormat::Rgba8Unorm
+    );
+    // sRGB encoded quarter gray, about 0.0508 in linear light.
+    let gray = Color::new(0.25, 0.25, 0.25, 1.0);
+    for format in [
+        BackBufferFormat::Rgba8Unorm,
+        BackBufferFormat::Rgba8UnormSrgb,
+        BackBufferFormat::Rgba16Float,
+    ] {
+        let options = RendererOptions {
+            adapter: AdapterPreference::Warp,
+            format,
+            ..Default::default()
+        };
+        let renderer =
+            DefaultRenderer::create_offscreen_with_options(Size::new(WIDTH, HEIGHT), &options)
+                .unwrap();
+        assert_eq!(renderer.format(), format);
+        assert_eq!(renderer.is_hdr_enabled(), format.is_hdr());
+
+        let mut drawing_session = renderer.begin_draw();
+        drawing_session.clear(&gray);
+        renderer.end_draw(drawing_session);
+
+        let pixels = renderer.read_pixels();
+        let pixel_size = format.bytes_per_pixel();
+        assert_eq!(pixels.len(), (WIDTH * HEIGHT) as usize * pixel_size);
+        let last = &pixels[pixels.len() - pixel_size..];
+        match format {
+            // Both store sRGB encoded values, the sRGB view encodes the linear clear color back.
+            BackBufferFormat::Rgba8Unorm | BackBufferFormat::Rgba8UnormSrgb => {
+                assert!(last[..3].iter().all(|&channel| channel.abs_diff(64) <= 1));
+                assert_eq!(last[3], 255);
+            }
+            // Float buffers hold the linear value.
+            BackBufferFormat::Rgba16Float => {
+                let channels: Vec<f32> = last
+                    .chunks_exact(2)
+                    .map(|bytes| half_to_f32(u16::from_le_bytes([bytes[0], bytes[1]])))
+                    .collect();
+                assert!(channels[..3]
+                    .iter()
+                    .all(|&channel| (channel - 0.0508).abs() < 1e-3));
+                assert_eq!(channels[3], 1.0);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_renderer_hdr_window_falls_back_when_unsupported() {
+    let window = Window::create();
+    let options = RendererOptions {
+        adapter: AdapterPreference::Warp,
+        format: BackBufferFormat::Rgba16Float,
+        ..Default::default()
+    };
+    // Whether the display presents scRGB depends on the machine, either way the renderer draws.
+    let renderer = DefaultRenderer::create_for_window_with_options(&window, &options);
+    assert!(matches!(
+        renderer.format(),
+        BackBufferFormat::Rgba16Float | BackBufferFormat::Rgba8Unorm
+    ));
+    assert_eq!(
+        renderer.is_hdr_enabled(),
+        renderer.format() == BackBufferFormat::Rgba16Float
+    );
+    let mut drawing_session = renderer.begin_draw();
+    drawing_session.clear(&Color::new(0.0, 0.0, 1.0, 1.0));
+    renderer.end_draw(drawing_session);
+    renderer.present();
+}
+
+/// Decodes an IEEE 754 half precision float, as stored by `Rgba16Float` back buffers.
+fn half_to_f32(bits: u16) -> f32 {
+    let sign = if bits & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exponent = ((bits >> 10) & 0x1F) as i32;
+    let mantissa = (bits & 0x3FF) as f32 / 1024.0;
+    match exponent {
+        0 => sign * mantissa * 2f32.powi(-14),
+        0x1F if mantissa == 0.0 => sign * f32::INFINITY,
+        0x1F => f32::NAN,
+        _ => sign * (1.0 + mantissa) * 2f32.powi(exponent - 15),
+    }
+}
+
+/// Returns the RGBA8 pixel at (x, y) of tightly packed rows `width` pixels wide.
+fn pixel_at(pixels: &[u8], width: u32, x: u32, y: u32) -> [u8; 4] {
+    let offset = ((y * width + x) * 4) as usize;
+    pixels[offset..offset + 4].try_into().unwrap()
+}
+
+#[test]
+fn test_renderer_offscreen_golden() {
+    const WIDTH: u32 = 64;
+    const HEIGHT: u32 = 32;
+    let renderer = DefaultRenderer::create_offscreen(Size::new(WIDTH, HEIGHT)).unwrap();
+    assert_eq!(renderer.size(), Size::new(WIDTH as f32, HEIGHT as f32));
+
+    // White rectangle covering the center half of the target, in clip space.
+    let mut drawing_session = renderer.begin_draw();
+    drawing_session.clear(&Color::new(1.0, 0.0, 0.0, 1.0));
+    drawing_session.draw_triangle(
+        &[
+            Vector2::new(-0.5, 0.5),
+            Vector2::new(0.5, 0.5),
+            Vector2::new(0.5, -0.5),
+        ],
+        &Color::new(1.0, 1.0, 1.0, 1.0),
+    );
+    drawing_session.draw_triangle(
+        &[
+            Vector2::new(-0.5, 0.5),
+            Vector2::new(0.5, -0.5),
+            Vector2::new(-0.5, -0.5),
+        ],
+        &Color::new(1.0, 1.0, 1.0, 1.0),
+    );
+    renderer.end_draw(drawing_session);
+
+    let pixels = renderer.read_pixels();
+    assert_eq!(pixels.len(), (WIDTH * HEIGHT * 4) as usize);
+
+    let red = [255, 0, 0, 255];
+    let white = [255, 255, 255, 255];
+    assert_eq!(pixel_at(&pixels, WIDTH, 0, 0), red);
+    assert_eq!(pixel_at(&pixels, WIDTH, WIDTH - 1, HEIGHT - 1), red);
+    assert_eq!(pixel_at(&pixels, WIDTH, 4, HEIGHT / 2), red);
+    assert_eq!(pixel_at(&pixels, WIDTH, WIDTH / 2, HEIGHT / 2), white);
+    assert_eq!(pixel_at(&pixels, WIDTH, WIDTH / 4 + 1, HEIGHT / 4 + 1), white);
+    assert_eq!(pixel_at(&pixels, WIDTH, 3 * WIDTH / 4 - 2, 3 * HEIGHT / 4 - 2), white);
+}
+
+#[test]
+fn test_renderer_draw_mesh_depth_occlusion() {
+    const WIDTH: u32 = 32;
+    const HEIGHT: u32 = 32;
+    let options = RendererOptions {
+        depth: true,
+        ..Default::default()
+    };
+    let renderer =
+        DefaultRenderer::create_offscreen_with_options(Size::new(WIDTH, HEIGHT), &options).unwrap();
+
+    // A small red triangle in front of a large green one, in clip space.
+    let near = [
+        Vector3::new(-0.5, -0.5, 0.25),
+        Vector3::new(0.0, 0.5, 0.25),
+        Vector3::new(0.5, -0.5, 0.25),
+    ];
+    let far = [
+        Vector3::new(-1.0, -1.0, 0.75),
+        Vector3::new(0.0, 1.0, 0.75),
+        Vector3::new(1.0, -1.0, 0.75),
+    ];
+    let red = Color::new(1.0, 0.0, 0.0, 1.0);
+    let green = Color::new(0.0, 1.0, 0.0, 1.0);
+    let identity = Matrix4x4::identity();
+    for near_first in [true, false] {
+        let mut drawing_session = renderer.begin_draw();
+        drawing_session.clear(&Color::new(0.0, 0.0, 0.0, 1.0));
+        drawing_session.clear_depth(1.0);
+        let meshes = if near_first {
+            [(&near, &red), (&far, &green)]
+        } else {
+            [(&far, &green), (&near, &red)]
+        };
+        for (vertices, color) in meshes {
+            drawing_session.draw_mesh(vertices, &[0, 1, 2], &identity, color);
+        }
+        renderer.end_draw(drawing_session);
+
+        let pixels = renderer.read_pixels();
+        let center = pixel_at(&pixels, WIDTH, WIDTH / 2, HEIGHT / 2);
+        assert_eq!(center, [255, 0, 0, 255], "near first: {}", near_first);
+        let edge = pixel_at(&pixels, WIDTH, WIDTH / 2, HEIGHT - 2);
+        assert_eq!(edge, [0, 255, 0, 255], "near first: {}", near_first);
+    }
+}
+
+#[test]
+fn test_renderer_draw_rounded_rectangle() {
+    const WIDTH: u32 = 64;
+    const HEIGHT: u32 = 32;
+    let renderer = DefaultRenderer::create_offscreen(Size::new(WIDTH, HEIGHT)).unwrap();
+
+    let mut drawing_session = renderer.begin_draw();
+    drawing_session.clear(&Color::new(1.0, 0.0, 0.0, 1.0));
+    drawing_session.draw_rounded_rectangle(
+        &Rect {
+            x: 0.0,
+            y: 0.0,
+            width: WIDTH as f32,
+            height: HEIGHT as f32,
+        },
+        12.0,
+        &Color::new(1.0, 1.0, 1.0, 1.0),
+    );
+    renderer.end_draw(drawing_session);
+
+    let pixels = renderer.read_pixels();
+    let red = [255, 0, 0, 255];
+    let white = [255, 255, 255, 255];
+    assert_eq!(pixel_at(&pixels, WIDTH, 0, 0), red);
+    assert_eq!(pixel_at(&pixels, WIDTH, WIDTH - 1, HEIGHT - 1), red);
+    assert_eq!(pixel_at(&pixels, WIDTH, WIDTH / 2, 0), white);
+    assert_eq!(pixel_at(&pixels, WIDTH, 0, HEIGHT / 2), white);
+    assert_eq!(pixel_at(&pixels, WIDTH, WIDTH / 2, HEIGHT / 2), white);
+}
+
+#[test]
+fn test_renderer_draw_circle_fills_ellipse() {
+    const WIDTH: u32 = 64;
+    const HEIGHT: u32 = 32;
+    let renderer = DefaultRenderer::create_offscreen(Size::new(WIDTH, HEIGHT)).unwrap();
+    let bounds = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: WIDTH as f32,
+        height: HEIGHT as f32,
+    };
+    let red = [255, 0, 0, 255];
+    let white = [255, 255, 255, 255];
+
+    for segments in [None, Some(4)] {
+        let mut drawing_session = renderer.begin_draw();
+        drawing_session.clear(&Color::new(1.0, 0.0, 0.0, 1.0));
+        drawing_session.set_circle_segments(segments);
+        drawing_session.draw_circle(&bounds, &Color::new(1.0, 1.0, 1.0, 1.0));
+        let stats = drawing_session.stats();
+        renderer.end_draw(drawing_session);
+
+        // The ellipse reaches the middle of every side but not the corners.
+        let pixels = renderer.read_pixels();
+        assert_eq!(pixel_at(&pixels, WIDTH, 0, 0), red);
+        assert_eq!(pixel_at(&pixels, WIDTH, 8, 4), red);
+        assert_eq!(pixel_at(&pixels, WIDTH, WIDTH / 2, HEIGHT / 2), white);
+        assert_eq!(pixel_at(&pixels, WIDTH, WIDTH / 2, 1), white);
+        assert_eq!(pixel_at(&pixels, WIDTH, 4, HEIGHT / 2), white);
+        match segments {
+            // A diamond with four segments, smooth enough to cover this point otherwise.
+            Some(segments) => {
+                assert_eq!(stats.triangles, segments);
+                assert_eq!(pixel_at(&pixels, WIDTH, 12, 6), red);
+            }
+            None => assert_eq!(pixel_at(&pixels, WIDTH, 12, 6), white),
+        }
+    }
+}
+
+#[test]
+fn test_renderer_draw_line() {
+    const WIDTH: u32 = 64;
+    const HEIGHT: u32 = 32;
+    let renderer = DefaultRenderer::create_offscreen(Size::new(WIDTH, HEIGHT)).unwrap();
+
+    let mut drawing_session = renderer.begin_draw();
+    drawing_session.clear(&Color::new(1.0, 0.0, 0.0, 1.0));
+    drawing_session.draw_line(
+        &Vector2::new(8.0, 16.0),
+        &Vector2::new(56.0, 16.0),
+        4.0,
+        &Color::new(1.0, 1.0, 1.0, 1.0),
+    );
+    renderer.end_draw(drawing_session);
+
+    let pixels = renderer.read_pixels();
+    let red = [255, 0, 0, 255];
+    let white = [255, 255, 255, 255];
+    assert_eq!(pixel_at(&pixels, WIDTH, WIDTH / 2, HEIGHT / 2), white);
+    assert_eq!(pixel_at(&pixels, WIDTH, 8, 15), white);
+    assert_eq!(pixel_at(&pixels, WIDTH, 55, 17), white);
+    assert_eq!(pixel_at(&pixels, WIDTH, 4, HEIGHT / 2), red);
+    assert_eq!(pixel_at(&pixels, WIDTH, WIDTH / 2, 10), red);
+}
+
+#[test]
+fn test_renderer_draw_text() {
+    const WIDTH: u32 = 64;
+    const HEIGHT: u32 = 32;
+    let renderer = DefaultRenderer::create_offscreen(Size::new(WIDTH, HEIGHT)).unwrap();
+
+    // Text on the left half only, drawn twice to go through the glyph atlas cache.
+    let rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: (WIDTH / 2) as f32,
+        height: HEIGHT as f32,
+    };
+    for _ in 0..2 {
+        let mut drawing_session = renderer.begin_draw();
+        drawing_session.clear(&Color::new(0.0, 0.0, 0.0, 1.0));
+        drawing_session.draw_text("Hi", &TextFormat::default(), &rect);
+        renderer.end_draw(drawing_session);
+
+        let pixels = renderer.read_pixels();
+        let covered = |x_range: std::ops::Range<u32>| {
+            x_range
+                .flat_map(|x| (0..HEIGHT).map(move |y| (x, y)))
+                .any(|(x, y)| pixel_at(&pixels, WIDTH, x, y)[0] > 0)
+        };
+        assert!(covered(0..WIDTH / 2));
+        assert!(!covered(WIDTH / 2..WIDTH));
+    }
+}
+
+#[test]
+fn test_renderer_measure_text() {
+    let renderer = DefaultRenderer::create_offscreen(Size::new(16, 16)).unwrap();
+    let format = TextFormat::default();
+
+    let empty = renderer.measure_text("", &format, f32::MAX);
+    assert_eq!(empty, TextMetrics::default());
+    assert_eq!(empty.size(), Size::new(0.0, 0.0));
+
+    let short = renderer.measure_text("sky", &format, f32::MAX);
+    let long = renderer.measure_text("sky-labs", &format, f32::MAX);
+    assert!(short.width > 0.0 && short.height > 0.0);
+    assert!(long.width > short.width);
+    assert_eq!(long.height, short.height);
+    assert_eq!(long.line_count, 1);
+
+    let trailing = renderer.measure_text("sky   ", &format, f32::MAX);
+    assert_eq!(trailing.width, short.width);
+    assert!(trailing.width_including_trailing_whitespace > short.width);
+
+    let wrapped = renderer.measure_text("sky labs sky labs", &format, short.width * 1.5);
+    assert!(wrapped.line_count > 1);
+    assert!(wrapped.width <= short.width * 1.5);
+    assert!(wrapped.height > short.height);
+}
+
+#[test]
+fn test_renderer_measure_text_wrapping_and_max_lines() {
+    let renderer = DefaultRenderer::create_offscreen(Size::new(16, 16)).unwrap();
+    let text = "sky labs sky labs sky labs";
+    let width = renderer
+        .measure_text("sky labs", &TextFormat::default(), f32::MAX)
+        .width;
+    let measure = |format: &TextFormat| renderer.measure_text(text, format, width);
+
+    let wrapped = measure(&TextFormat::default());
+    assert_eq!(wrapped.line_count, 3);
+    let single_line = measure(&TextFormat {
+        wrapping: TextWrapping::None,
+        ..TextFormat::default()
+    });
+    assert_eq!(single_line.line_count, 1);
+    assert!(single_line.width > width);
+    let spaced = measure(&TextFormat {
+        line_spacing: 2.0,
+        ..TextFormat::default()
+    });
+    assert_eq!(spaced.line_count, 3);
+    assert!(spaced.height > wrapped.height);
+
+    let truncated = measure(&TextFormat {
+        max_lines: Some(2),
+        ..TextFormat::default()
+    });
+    assert_eq!(truncated.line_count, 2);
+    assert!(truncated.height < wrapped.height);
+
+    // Text with fewer lines than the maximum is left as is.
+    let format = TextFormat {
+        max_lines: Some(5),
+        ..TextFormat::default()
+    };
+    assert_eq!(measure(&format), wrapped);
+}
+
+/// TrueType font where every printable ASCII character advances by half an em, and whose
+/// ascent and descent add up to one em. Characters other than the space are boxes.
+const BOX_FONT: &[u8] = include_bytes!("fonts/box.ttf");
+
+#[test]
+fn test_renderer_load_font_from_bytes() {
+    let renderer = DefaultRenderer::create_offscreen(Size::new(64, 32)).unwrap();
+    let font = renderer.load_font_from_bytes(BOX_FONT).unwrap();
+    let format = TextFormat {
+        font: Some(font.clone()),
+        ..TextFormat::default()
+    };
+    // Text is laid out at 14 pixels per em.
+    let metrics = renderer.measure_text("sky labs", &format, f32::MAX);
+    assert!((metrics.width - 56.0).abs() < 0.01);
+    assert!((metrics.height - 14.0).abs() < 0.01);
+    let default_metrics = renderer.measure_text("sky labs", &TextFormat::default(), f32::MAX);
+    assert_ne!(metrics.width, default_metrics.width);
+
+    // Formats keep the font loaded after the handle it was loaded with is dropped.
+    drop(font);
+    let rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 64.0,
+        height: 32.0,
+    };
+    let mut session = renderer.begin_draw();
+    session.clear(&Color::new(0.0, 0.0, 0.0, 1.0));
+    session.draw_text("ab", &format, &rect);
+    renderer.end_draw(session);
+    let pixels = renderer.read_pixels();
+    assert!(pixels.chunks_exact(4).any(|pixel| pixel[0] > 0));
+}
+
+#[test]
+fn test_renderer_load_font_from_bytes_rejects_malformed_data() {
+    let renderer = DefaultRenderer::create_offscreen(Size::new(16, 16)).unwrap();
+    assert!(renderer.load_font_from_bytes(b"not a font").is_err());
+    assert!(renderer.load_font_from_bytes(&[]).is_err());
+}
+
+#[test]
+fn test_renderer_draw_text_in_measured_rect_does_not_clip() {
+    const WIDTH: u32 = 128;
+    const HEIGHT: u32 = 96;
+    let renderer = DefaultRenderer::create_offscreen(Size::new(WIDTH, HEIGHT)).unwrap();
+    let text = "sky labs is a game engine";
+    let draw = |format: &TextFormat, rect: &Rect<f32>| {
+        let mut drawing_session = renderer.begin_draw();
+        drawing_session.clear(&Color::new(0.0, 0.0, 0.0, 1.0));
+        drawing_session.draw_text(text, format, rect);
+        renderer.end_draw(drawing_session);
+        renderer.read_pixels()
+    };
+
+    for wrapping in [TextWrapping::Word, TextWrapping::Character] {
+        for max_lines in [None, Some(2)] {
+            let format = TextFormat {
+                wrapping,
+                line_spacing: 1.25,
+                max_lines,
+                ..TextFormat::default()
+            };
+            let metrics = renderer.measure_text(text, &format, WIDTH as f32 / 2.0);
+            let rect = Rect {
+                x: 0.0,
+                y: 0.0,
+                width: metrics.width,
+                height: metrics.height,
+            };
+            let clipped = TextFormat {
+                overflow: TextOverflow::Clip,
+                ..format.clone()
+            };
+            assert!(
+                draw(&format, &rect) == draw(&clipped, &rect),
+                "{wrapping:?} with {max_lines:?} lines"
+            );
+        }
+    }
+}
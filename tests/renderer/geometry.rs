@@ -0,0 +1,270 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::math::{Rect, Vector2};
+use sky_labs::renderer::geometry::*;
+
+fn rect(x: f32, y: f32, width: f32, height: f32) -> Rect<f32> {
+    Rect {
+        x,
+        y,
+        width,
+        height,
+    }
+}
+
+/// Sum of the areas of the triangles in a triangle list.
+fn area(vertices: &[Vector2<f32>]) -> f32 {
+    vertices
+        .chunks_exact(3)
+        .map(|t| {
+            ((t[1].x - t[0].x) * (t[2].y - t[0].y) - (t[2].x - t[0].x) * (t[1].y - t[0].y)).abs()
+                / 2.0
+        })
+        .sum()
+}
+
+fn bounds(vertices: &[Vector2<f32>]) -> (f32, f32, f32, f32) {
+    vertices.iter().fold(
+        (f32::MAX, f32::MAX, f32::MIN, f32::MIN),
+        |(left, top, right, bottom), v| {
+            (left.min(v.x), top.min(v.y), right.max(v.x), bottom.max(v.y))
+        },
+    )
+}
+
+#[test]
+fn test_rectangle_vertices() {
+    let vertices = rectangle_vertices(&rect(10.0, 20.0, 30.0, 40.0));
+    assert_eq!(vertices.len(), 6);
+    assert_eq!(area(&vertices), 1200.0);
+    assert_eq!(bounds(&vertices), (10.0, 20.0, 40.0, 60.0));
+}
+
+#[test]
+fn test_rounded_rectangle_zero_radius_matches_rectangle() {
+    let r = rect(10.0, 20.0, 30.0, 40.0);
+    assert_eq!(
+        rounded_rectangle_vertices(&r, 0.0, DEFAULT_TESSELLATION_TOLERANCE),
+        rectangle_vertices(&r)
+    );
+    assert_eq!(
+        rounded_rectangle_vertices(&r, -5.0, DEFAULT_TESSELLATION_TOLERANCE),
+        rectangle_vertices(&r)
+    );
+}
+
+#[test]
+fn test_rounded_rectangle_area_and_bounds() {
+    let r = rect(0.0, 0.0, 100.0, 50.0);
+    let radius = 10.0;
+    let vertices = rounded_rectangle_vertices(&r, radius, 0.01);
+    assert_eq!(vertices.len() % 3, 0);
+    let expected = 100.0 * 50.0 - (4.0 - std::f32::consts::PI) * radius * radius;
+    assert!((area(&vertices) - expected).abs() < 1.0);
+    let (left, top, right, bottom) = bounds(&vertices);
+    assert!(left.abs() < 1e-4 && top.abs() < 1e-4);
+    assert!((right - 100.0).abs() < 1e-4 && (bottom - 50.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_rounded_rectangle_radius_is_clamped_to_capsule() {
+    let r = rect(0.0, 0.0, 100.0, 20.0);
+    let capsule = rounded_rectangle_vertices(&r, 10.0, 0.01);
+    assert_eq!(rounded_rectangle_vertices(&r, 500.0, 0.01), capsule);
+
+    // Two half circles joined by a 80x20 band, with no side bands left.
+    let expected = 80.0 * 20.0 + std::f32::consts::PI * 10.0 * 10.0;
+    assert!((area(&capsule) - expected).abs() < 1.0);
+    assert!(capsule.iter().all(|v| v.y >= -1e-4 && v.y <= 20.0 + 1e-4));
+}
+
+#[test]
+fn test_rounded_rectangle_vertices_stay_within_tolerance() {
+    let r = rect(0.0, 0.0, 400.0, 400.0);
+    let radius = 200.0;
+    let tolerance = 0.5;
+    let vertices = rounded_rectangle_vertices(&r, radius, tolerance);
+    // Every vertex is on the circle, and every edge midpoint within tolerance of it.
+    let center = Vector2::new(200.0, 200.0);
+    let distance = |v: Vector2<f32>| ((v.x - center.x).powi(2) + (v.y - center.y).powi(2)).sqrt();
+    for triangle in vertices.chunks_exact(3) {
+        assert!((distance(triangle[1]) - radius).abs() < 1e-2);
+        assert!((distance(triangle[2]) - radius).abs() < 1e-2);
+        let midpoint = Vector2::new(
+            (triangle[1].x + triangle[2].x) / 2.0,
+            (triangle[1].y + triangle[2].y) / 2.0,
+        );
+        assert!(radius - distance(midpoint) <= tolerance + 1e-3);
+    }
+}
+
+#[test]
+fn test_line_vertices() {
+    let vertices = line_vertices(Vector2::new(10.0, 20.0), Vector2::new(40.0, 20.0), 4.0);
+    assert_eq!(vertices.len(), 6);
+    assert_eq!(area(&vertices), 120.0);
+    assert_eq!(bounds(&vertices), (10.0, 18.0, 40.0, 22.0));
+
+    // Diagonal lines keep their length and thickness.
+    let vertices = line_vertices(Vector2::new(0.0, 0.0), Vector2::new(30.0, 40.0), 2.0);
+    assert!((area(&vertices) - 100.0).abs() < 1e-3);
+
+    assert!(line_vertices(Vector2::new(5.0, 5.0), Vector2::new(5.0, 5.0), 2.0).is_empty());
+    assert!(line_vertices(Vector2::new(0.0, 0.0), Vector2::new(5.0, 5.0), 0.0).is_empty());
+}
+
+/// Whether every triangle of a triangle list is wound like `rectangle_vertices`.
+fn wound_like_rectangles(vertices: &[Vector2<f32>]) -> bool {
+    vertices
+        .chunks_exact(3)
+        .all(|t| (t[1] - t[0]).cross(&(t[2] - t[0])) > 0.0)
+}
+
+#[test]
+fn test_polygon_vertices() {
+    let square = [
+        Vector2::new(10.0, 20.0),
+        Vector2::new(40.0, 20.0),
+        Vector2::new(40.0, 50.0),
+        Vector2::new(10.0, 50.0),
+    ];
+    let vertices = polygon_vertices(&square);
+    assert_eq!(vertices.len(), 6);
+    assert_eq!(area(&vertices), 900.0);
+    assert_eq!(bounds(&vertices), (10.0, 20.0, 40.0, 50.0));
+    assert!(wound_like_rectangles(&vertices));
+    let rectangle = rectangle_vertices(&rect(10.0, 20.0, 30.0, 30.0));
+    assert!(wound_like_rectangles(&rectangle));
+
+    // The other winding gives the same triangles.
+    let mut reversed = square;
+    reversed.reverse();
+    let vertices = polygon_vertices(&reversed);
+    assert_eq!(area(&vertices), 900.0);
+    assert!(wound_like_rectangles(&vertices));
+
+    // A point in the middle of an edge adds no triangle, and a line has none.
+    let with_midpoint = [
+        Vector2::new(10.0, 20.0),
+        Vector2::new(25.0, 20.0),
+        Vector2::new(40.0, 20.0),
+        Vector2::new(40.0, 50.0),
+    ];
+    assert_eq!(polygon_vertices(&with_midpoint).len(), 3);
+    assert!(polygon_vertices(&[square[0], square[1], Vector2::new(70.0, 20.0)]).is_empty());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "a polygon must have at least 3 points")]
+fn test_polygon_vertices_too_few_points() {
+    polygon_vertices(&[Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0)]);
+}
+
+#[test]
+fn test_polygon_outline_vertices() {
+    let square = [
+        Vector2::new(10.0, 20.0),
+        Vector2::new(20.0, 20.0),
+        Vector2::new(20.0, 30.0),
+        Vector2::new(10.0, 30.0),
+    ];
+    let vertices = polygon_outline_vertices(&square, 2.0);
+    assert_eq!(vertices.len(), 4 * 6);
+    assert!((area(&vertices) - (12.0 * 12.0 - 8.0 * 8.0)).abs() < 1e-3);
+    assert_eq!(bounds(&vertices), (9.0, 19.0, 21.0, 31.0));
+    assert!(wound_like_rectangles(&vertices));
+
+    // The other winding and repeated points give the same outline.
+    let reversed = [
+        square[3], square[3], square[2], square[1], square[0], square[3],
+    ];
+    let vertices = polygon_outline_vertices(&reversed, 2.0);
+    assert!((area(&vertices) - 80.0).abs() < 1e-3);
+    assert_eq!(bounds(&vertices), (9.0, 19.0, 21.0, 31.0));
+    assert!(wound_like_rectangles(&vertices));
+
+    // Sharp corners are cut at the miter limit.
+    let sliver = [
+        Vector2::new(0.0, 0.0),
+        Vector2::new(100.0, 1.0),
+        Vector2::new(0.0, 2.0),
+    ];
+    let (_, _, right, _) = bounds(&polygon_outline_vertices(&sliver, 2.0));
+    assert!(right <= 100.0 + MITER_LIMIT);
+
+    assert!(polygon_outline_vertices(&square, 0.0).is_empty());
+    assert!(polygon_outline_vertices(&[square[0], square[1], square[0]], 2.0).is_empty());
+}
+
+#[test]
+fn test_arc_segment_count() {
+    use std::f32::consts::FRAC_PI_2;
+    assert_eq!(arc_segment_count(0.1, FRAC_PI_2, 0.25), 1);
+    assert!(arc_segment_count(100.0, FRAC_PI_2, 0.25) > arc_segment_count(10.0, FRAC_PI_2, 0.25));
+    assert!(arc_segment_count(100.0, FRAC_PI_2, 0.1) > arc_segment_count(100.0, FRAC_PI_2, 1.0));
+}
+
+#[test]
+fn test_circle_segment_count() {
+    assert_eq!(circle_segment_count(0.0, 0.25), MIN_CIRCLE_SEGMENTS);
+    assert_eq!(circle_segment_count(0.5, 0.25), MIN_CIRCLE_SEGMENTS);
+    assert_eq!(circle_segment_count(1.0e6, 0.25), MAX_CIRCLE_SEGMENTS);
+    assert!(circle_segment_count(100.0, 0.25) > circle_segment_count(10.0, 0.25));
+    assert!(circle_segment_count(100.0, 0.1) > circle_segment_count(100.0, 1.0));
+
+    // Below the cap, each chord stays within tolerance of the circle.
+    for radius in [2.0, 10.0, 50.0, 200.0] {
+        let segments = circle_segment_count(radius, 0.25);
+        assert!(segments < MAX_CIRCLE_SEGMENTS);
+        let half_step = std::f32::consts::PI / segments as f32;
+        assert!(radius * (1.0 - half_step.cos()) <= 0.25 + 1e-4);
+    }
+}
+
+#[test]
+fn test_ellipse_vertices() {
+    let r = rect(10.0, 20.0, 40.0, 20.0);
+    let vertices = ellipse_vertices(&r, 64);
+    assert_eq!(vertices.len(), 64 * 3);
+    let (left, top, right, bottom) = bounds(&vertices);
+    assert!((left - 10.0).abs() < 1e-3 && (right - 50.0).abs() < 1e-3);
+    assert!((top - 20.0).abs() < 1e-3 && (bottom - 40.0).abs() < 1e-3);
+
+    // Every outer vertex is on the ellipse, and the area approaches pi * a * b.
+    for triangle in vertices.chunks_exact(3) {
+        assert_eq!(triangle[0], Vector2::new(30.0, 30.0));
+        for v in &triangle[1..] {
+            let (dx, dy) = ((v.x - 30.0) / 20.0, (v.y - 30.0) / 10.0);
+            assert!((dx * dx + dy * dy - 1.0).abs() < 1e-4);
+        }
+    }
+    let ellipse_area = std::f32::consts::PI * 20.0 * 10.0;
+    assert!(area(&vertices) < ellipse_area);
+    assert!(area(&vertices) > 0.99 * ellipse_area);
+
+    // The fan is closed, and has at least the minimum number of segments.
+    assert_eq!(vertices[1], vertices[vertices.len() - 1]);
+    assert_eq!(
+        ellipse_vertices(&r, 0).len(),
+        MIN_CIRCLE_SEGMENTS as usize * 3
+    );
+    assert!(ellipse_vertices(&rect(0.0, 0.0, 0.0, 10.0), 16).is_empty());
+}
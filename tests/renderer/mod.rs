@@ -17,14 +17,24 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+mod color;
+#[cfg(all(target_os = "windows", feature = "renderer-d3d12"))]
+mod d3d12;
+mod geometry;
+mod recording;
+mod software;
+mod stats;
+
 use sky_labs::renderer::*;
-use sky_labs::math::Size;
-use sky_labs::window::Window;
 
 #[test]
-fn test_renderer_create() {
-    let mut window = Window::create();
-    let renderer = DefaultRenderer::create_for_window(&window);
-    let size = renderer.size();
-    assert_ne!(size, Size::new(0.0, 0.0));
+fn test_back_buffer_format_properties() {
+    assert!(!BackBufferFormat::Rgba8Unorm.takes_linear_colors());
+    assert!(BackBufferFormat::Rgba8UnormSrgb.takes_linear_colors());
+    assert!(BackBufferFormat::Rgba16Float.takes_linear_colors());
+    assert!(!BackBufferFormat::Rgba8Unorm.is_hdr());
+    assert!(!BackBufferFormat::Rgba8UnormSrgb.is_hdr());
+    assert!(BackBufferFormat::Rgba16Float.is_hdr());
+    assert_eq!(BackBufferFormat::Rgba8UnormSrgb.bytes_per_pixel(), 4);
+    assert_eq!(BackBufferFormat::Rgba16Float.bytes_per_pixel(), 8);
 }
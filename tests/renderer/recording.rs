@@ -0,0 +1,333 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::math::{Matrix4x4, Rect, Size, Vector2, Vector3};
+use sky_labs::renderer::software::SoftwareRenderer;
+use sky_labs::renderer::testing::{DrawCommand, DrawCommandKind, RecordingSession};
+use sky_labs::renderer::*;
+
+const BACKGROUND: Color<f32> = Color {
+    r: 0.2,
+    g: 0.2,
+    b: 0.2,
+    a: 1.0,
+};
+
+const FOREGROUND: Color<f32> = Color {
+    r: 0.8,
+    g: 0.1,
+    b: 0.1,
+    a: 1.0,
+};
+
+/// Example of drawing logic under test: a bar filled from the left in proportion to `health`.
+fn draw_health_bar(session: &mut impl DrawingSession, bounds: &Rect<f32>, health: f32) {
+    session.draw_rectangle(bounds, &BACKGROUND);
+    let filled = Rect {
+        width: bounds.width * health.clamp(0.0, 1.0),
+        ..*bounds
+    };
+    if filled.width > 0.0 {
+        session.draw_rectangle(&filled, &FOREGROUND);
+    }
+}
+
+#[test]
+fn test_recording_session_health_bar() {
+    let bounds = Rect {
+        x: 10.0,
+        y: 20.0,
+        width: 200.0,
+        height: 16.0,
+    };
+    let mut session = RecordingSession::new();
+    draw_health_bar(&mut session, &bounds, 0.25);
+
+    assert_eq!(
+        session.commands(),
+        [
+            DrawCommand::Rect {
+                rect: bounds,
+                color: BACKGROUND,
+            },
+            DrawCommand::Rect {
+                rect: Rect {
+                    x: 10.0,
+                    y: 20.0,
+                    width: 50.0,
+                    height: 16.0,
+                },
+                color: FOREGROUND,
+            },
+        ]
+    );
+
+    let mut session = RecordingSession::new();
+    draw_health_bar(&mut session, &bounds, 0.0);
+    assert_eq!(session.count_of(DrawCommandKind::Rect), 1);
+}
+
+#[test]
+fn test_recording_session_count_and_find() {
+    let mut session = RecordingSession::new();
+    session.clear(&Color::new(0.0, 0.0, 0.0, 1.0));
+    session.draw_text("Score: 10", &TextFormat::default(), &Rect::default());
+    session.draw_text("Lives: 3", &TextFormat::default(), &Rect::default());
+    session.draw_line(
+        &Vector2::new(0.0, 0.0),
+        &Vector2::new(10.0, 0.0),
+        1.0,
+        &FOREGROUND,
+    );
+
+    assert_eq!(session.count_of(DrawCommandKind::Clear), 1);
+    assert_eq!(session.count_of(DrawCommandKind::Text), 2);
+    assert_eq!(session.count_of(DrawCommandKind::Line), 1);
+    assert_eq!(session.count_of(DrawCommandKind::Circle), 0);
+
+    match session.find_text_containing("Lives") {
+        Some(DrawCommand::Text { text, .. }) => assert_eq!(text, "Lives: 3"),
+        other => panic!("expected the lives text, got {other:?}"),
+    }
+    assert!(session.find_text_containing("Game over").is_none());
+
+    session.clear_commands();
+    assert!(session.commands().is_empty());
+    assert_eq!(session.stats(), FrameStats::default());
+}
+
+#[test]
+fn test_recording_session_keeps_draw_order() {
+    let panel = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 40.0,
+    };
+    let badge = Rect {
+        x: 80.0,
+        y: 0.0,
+        width: 20.0,
+        height: 20.0,
+    };
+    let mut session = RecordingSession::new();
+    session.draw_rectangle(&panel, &BACKGROUND);
+    session.draw_text("Paused", &TextFormat::default(), &panel);
+    session.flush();
+    session.draw_rectangle(&badge, &FOREGROUND);
+
+    let kinds: Vec<DrawCommandKind> = session.commands().iter().map(DrawCommand::kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            DrawCommandKind::Rect,
+            DrawCommandKind::Text,
+            DrawCommandKind::Rect
+        ]
+    );
+    match &session.commands()[2] {
+        DrawCommand::Rect { color, .. } => assert_eq!(*color, FOREGROUND),
+        other => panic!("expected the badge last, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_recording_session_circle_centered_at() {
+    let mut session = RecordingSession::new();
+    session.draw_circle_centered_at(&Vector2::new(5.0, 6.0), 2.0, &FOREGROUND);
+
+    assert_eq!(
+        session.commands(),
+        [DrawCommand::Circle {
+            bounds: Rect {
+                x: 3.0,
+                y: 4.0,
+                width: 4.0,
+                height: 4.0,
+            },
+            color: FOREGROUND,
+        }]
+    );
+}
+
+#[test]
+fn test_recording_session_circle_segments() {
+    let small = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 8.0,
+        height: 4.0,
+    };
+    let large = Rect {
+        width: 400.0,
+        height: 200.0,
+        ..small
+    };
+    let triangles = |segments: Option<u32>, bounds: &Rect<f32>| {
+        let mut session = RecordingSession::new();
+        session.set_circle_segments(segments);
+        session.draw_circle(bounds, &FOREGROUND);
+        session.stats().triangles
+    };
+
+    // Adapts to the larger radius by default, and is fixed when overridden.
+    assert_eq!(
+        triangles(None, &small),
+        geometry::circle_segment_count(4.0, geometry::DEFAULT_TESSELLATION_TOLERANCE)
+    );
+    assert!(triangles(None, &large) > triangles(None, &small));
+    assert_eq!(triangles(Some(32), &small), 32);
+    assert_eq!(triangles(Some(32), &large), 32);
+    assert_eq!(triangles(Some(0), &large), geometry::MIN_CIRCLE_SEGMENTS);
+}
+
+#[test]
+fn test_recording_session_stats() {
+    let mut session = RecordingSession::new();
+    session.draw_rectangle(
+        &Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 4.0,
+            height: 4.0,
+        },
+        &FOREGROUND,
+    );
+    session.draw_text("a b", &TextFormat::default(), &Rect::default());
+
+    let stats = session.stats();
+    assert_eq!(stats.draw_calls, 2);
+    assert_eq!(stats.triangles, 2 + 4);
+    assert_eq!(stats.text_runs, 1);
+}
+
+#[test]
+fn test_recording_session_polygon() {
+    let mut session = RecordingSession::new();
+    let hexagon: Vec<_> = (0..6)
+        .map(|i| {
+            let angle = std::f32::consts::PI / 3.0 * i as f32;
+            Vector2::new(10.0 * angle.cos(), 10.0 * angle.sin())
+        })
+        .collect();
+    session.draw_polygon(&hexagon, &FOREGROUND);
+    session.draw_polygon_outline(&hexagon, 2.0, &BACKGROUND);
+
+    assert_eq!(
+        session.commands(),
+        &[
+            DrawCommand::Polygon {
+                points: hexagon.clone(),
+                color: FOREGROUND,
+            },
+            DrawCommand::PolygonOutline {
+                points: hexagon,
+                thickness: 2.0,
+                color: BACKGROUND,
+            },
+        ]
+    );
+    let stats = session.stats();
+    assert_eq!(stats.draw_calls, 2);
+    assert_eq!(stats.triangles, 4 + 6 * 2);
+}
+
+#[test]
+fn test_recording_session_mesh() {
+    let mut session = RecordingSession::new();
+    let vertices = [
+        Vector3::new(0.0, 0.0, 0.5),
+        Vector3::new(1.0, 0.0, 0.5),
+        Vector3::new(0.0, 1.0, 0.5),
+    ];
+    session.clear_depth(1.0);
+    session.draw_mesh(&vertices, &[0, 1, 2], &Matrix4x4::identity(), &FOREGROUND);
+
+    assert_eq!(
+        session.commands(),
+        &[
+            DrawCommand::ClearDepth { value: 1.0 },
+            DrawCommand::Mesh {
+                vertices: vertices.to_vec(),
+                indices: vec![0, 1, 2],
+                transform: Matrix4x4::identity(),
+                color: FOREGROUND,
+            },
+        ]
+    );
+    assert_eq!(session.count_of(DrawCommandKind::Mesh), 1);
+    assert_eq!(session.stats().triangles, 1);
+}
+
+#[test]
+fn test_recording_session_text_layout() {
+    let mut session = RecordingSession::new();
+    let color = Color::new(1.0, 0.8, 0.0, 1.0);
+    let mut layout = SoftwareRenderer::new(Size::new(1, 1)).create_text_layout(
+        "60",
+        &TextFormat::default(),
+        Size::new(80.0, 40.0),
+    );
+    session.draw_text_layout(&layout, &Vector2::new(4.0, 2.0), &color);
+    layout.update_text("120");
+    session.draw_text_layout(&layout, &Vector2::new(4.0, 2.0), &color);
+
+    let expected = |text: &str| DrawCommand::Text {
+        text: text.to_string(),
+        format: TextFormat::default(),
+        coord: Rect {
+            x: 4.0,
+            y: 2.0,
+            width: 80.0,
+            height: 40.0,
+        },
+        spans: vec![(0..text.len(), color)],
+    };
+    assert_eq!(session.commands(), [expected("60"), expected("120")]);
+    assert_eq!(session.stats().text_runs, 2);
+}
+
+#[test]
+fn test_recording_session_text_styled() {
+    let mut session = RecordingSession::new();
+    let highlight = Color::new(1.0, 0.8, 0.0, 1.0);
+    let coord = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 20.0,
+    };
+    session.draw_text("Score: ", &TextFormat::default(), &coord);
+    session.draw_text_styled(
+        "Score: 42",
+        &TextFormat::default(),
+        &coord,
+        &[(7..9, highlight)],
+    );
+
+    let spans: Vec<_> = session
+        .commands_of(DrawCommandKind::Text)
+        .map(|command| match command {
+            DrawCommand::Text { spans, .. } => spans.clone(),
+            other => panic!("expected a text, got {other:?}"),
+        })
+        .collect();
+    assert_eq!(spans, vec![vec![], vec![(7..9, highlight)]]);
+    assert_eq!(session.stats().text_runs, 2);
+}
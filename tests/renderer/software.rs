@@ -0,0 +1,808 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::math::{DepthRange, Matrix4x4, Rect, Size, Vector2, Vector3};
+use sky_labs::renderer::software::*;
+use sky_labs::renderer::*;
+
+const WHITE: Color<f32> = Color {
+    r: 1.0,
+    g: 1.0,
+    b: 1.0,
+    a: 1.0,
+};
+const BLACK: Color<f32> = Color {
+    r: 0.0,
+    g: 0.0,
+    b: 0.0,
+    a: 1.0,
+};
+
+fn rect(x: f32, y: f32, width: f32, height: f32) -> Rect<f32> {
+    Rect {
+        x,
+        y,
+        width,
+        height,
+    }
+}
+
+/// Clears a `width` x `height` renderer to black, draws with `draw` and returns the renderer.
+fn render(
+    width: u32,
+    height: u32,
+    draw: impl FnOnce(&mut SoftwareDrawingSession),
+) -> SoftwareRenderer {
+    let renderer = SoftwareRenderer::new(Size::new(width, height));
+    let mut session = renderer.begin_draw();
+    session.clear(&BLACK);
+    draw(&mut session);
+    renderer.end_draw(session);
+    renderer
+}
+
+/// Compares the red channel of the framebuffer with a golden image, where `#` is fully
+/// covered, `.` is not covered and `+` is partially covered.
+fn assert_golden(renderer: &SoftwareRenderer, golden: &[&str]) {
+    let size = renderer.size();
+    assert_eq!(size.height as usize, golden.len());
+    let rows: Vec<String> = (0..size.height as u32)
+        .map(|y| {
+            (0..size.width as u32)
+                .map(|x| match renderer.pixel(x, y)[0] {
+                    0 => '.',
+                    255 => '#',
+                    _ => '+',
+                })
+                .collect()
+        })
+        .collect();
+    assert_eq!(rows, golden);
+}
+
+#[test]
+fn test_software_renderer_offscreen_size_and_scale_factor() {
+    let renderer = SoftwareRenderer::create_offscreen(Size::new(12, 7)).unwrap();
+    assert_eq!(renderer.size(), Size::new(12.0, 7.0));
+    assert_eq!(renderer.scale_factor(), 1.0);
+}
+
+#[test]
+fn test_software_renderer_clear() {
+    let renderer = SoftwareRenderer::new(Size::new(4, 3));
+    assert_eq!(renderer.size(), Size::new(4.0, 3.0));
+    assert_eq!(renderer.framebuffer().len(), 4 * 3 * 4);
+    assert!(renderer.framebuffer().iter().all(|value| *value == 0));
+
+    let mut session = renderer.begin_draw();
+    session.clear(&Color::new(1.0, 0.5, 0.0, 1.0));
+    renderer.end_draw(session);
+    for pixel in renderer.framebuffer().chunks_exact(4) {
+        assert_eq!(pixel, [255, 128, 0, 255]);
+    }
+}
+
+#[test]
+fn test_software_renderer_rectangle() {
+    let renderer = render(8, 5, |session| {
+        session.draw_rectangle(&rect(2.0, 1.0, 4.0, 3.0), &WHITE);
+        // Half a pixel wide on each side.
+        session.draw_rectangle(&rect(0.5, 4.0, 2.0, 1.0), &WHITE);
+    });
+    assert_golden(
+        &renderer,
+        &[
+            "........", //
+            "..####..", "..####..", "..####..", "+#+.....",
+        ],
+    );
+    assert_eq!(renderer.pixel(0, 4), [128, 128, 128, 255]);
+}
+
+#[test]
+fn test_software_renderer_triangle() {
+    // Clip space, covering the bottom left half of the target.
+    let renderer = render(8, 8, |session| {
+        session.draw_triangle(
+            &[
+                Vector2::new(-1.0, 1.0),
+                Vector2::new(1.0, -1.0),
+                Vector2::new(-1.0, -1.0),
+            ],
+            &WHITE,
+        );
+    });
+    assert_golden(
+        &renderer,
+        &[
+            "+.......", //
+            "#+......", "##+.....", "###+....", "####+...", "#####+..", "######+.", "#######+",
+        ],
+    );
+}
+
+#[test]
+fn test_software_renderer_circle() {
+    let renderer = render(9, 9, |session| {
+        session.draw_circle_centered_at(&Vector2::new(4.5, 4.5), 3.5, &WHITE);
+    });
+    assert_golden(
+        &renderer,
+        &[
+            ".........", //
+            "..++#++..",
+            ".+#####+.",
+            ".+#####+.",
+            ".#######.",
+            ".+#####+.",
+            ".+#####+.",
+            "..++#++..",
+            ".........",
+        ],
+    );
+
+    let bounds = render(9, 9, |session| {
+        session.draw_circle(&rect(1.0, 1.0, 7.0, 7.0), &WHITE)
+    });
+    assert_eq!(bounds.framebuffer()[..], renderer.framebuffer()[..]);
+}
+
+#[test]
+fn test_software_renderer_rounded_rectangle() {
+    let renderer = render(12, 8, |session| {
+        session.draw_rounded_rectangle(&rect(1.0, 1.0, 10.0, 6.0), 3.0, &WHITE);
+    });
+    assert_golden(
+        &renderer,
+        &[
+            "............", //
+            "..++####++..",
+            ".+########+.",
+            ".+########+.",
+            ".+########+.",
+            ".+########+.",
+            "..++####++..",
+            "............",
+        ],
+    );
+
+    let square = render(12, 8, |session| {
+        session.draw_rounded_rectangle(&rect(1.0, 1.0, 10.0, 6.0), 0.0, &WHITE);
+    });
+    let rectangle = render(12, 8, |session| {
+        session.draw_rectangle(&rect(1.0, 1.0, 10.0, 6.0), &WHITE)
+    });
+    assert_eq!(square.framebuffer()[..], rectangle.framebuffer()[..]);
+}
+
+#[test]
+fn test_software_renderer_line() {
+    let renderer = render(8, 8, |session| {
+        session.draw_line(
+            &Vector2::new(1.0, 1.0),
+            &Vector2::new(7.0, 7.0),
+            1.5,
+            &WHITE,
+        );
+    });
+    assert_golden(
+        &renderer,
+        &[
+            ".+......", //
+            "+#+.....", ".+#+....", "..+#+...", "...+#+..", "....+#+.", ".....+#+", "......+.",
+        ],
+    );
+
+    // Flat ends, a line on pixel boundaries covers whole pixels.
+    let renderer = render(8, 4, |session| {
+        session.draw_line(
+            &Vector2::new(1.0, 2.0),
+            &Vector2::new(7.0, 2.0),
+            2.0,
+            &WHITE,
+        );
+        session.draw_line(
+            &Vector2::new(3.0, 3.0),
+            &Vector2::new(3.0, 3.0),
+            2.0,
+            &WHITE,
+        );
+    });
+    assert_golden(&renderer, &["........", ".######.", ".######.", "........"]);
+}
+
+#[test]
+fn test_software_renderer_polygon() {
+    // A square on pixel boundaries, with a point in the middle of its top edge, in clockwise
+    // winding, and a diamond in counter-clockwise winding.
+    let renderer = render(8, 8, |session| {
+        session.draw_polygon(
+            &[
+                Vector2::new(1.0, 1.0),
+                Vector2::new(2.0, 1.0),
+                Vector2::new(3.0, 1.0),
+                Vector2::new(3.0, 3.0),
+                Vector2::new(1.0, 3.0),
+            ],
+            &WHITE,
+        );
+        session.draw_polygon(
+            &[
+                Vector2::new(6.0, 3.0),
+                Vector2::new(4.0, 5.0),
+                Vector2::new(6.0, 7.0),
+                Vector2::new(8.0, 5.0),
+            ],
+            &WHITE,
+        );
+    });
+    assert_golden(
+        &renderer,
+        &[
+            "........", ".##.....", ".##.....", ".....++.", "....+##+", "....+##+", ".....++.",
+            "........",
+        ],
+    );
+
+    // Overlapping edges of the outline are blended once.
+    let renderer = render(8, 8, |session| {
+        session.draw_polygon_outline(
+            &[
+                Vector2::new(1.0, 1.0),
+                Vector2::new(7.0, 1.0),
+                Vector2::new(7.0, 7.0),
+                Vector2::new(1.0, 7.0),
+            ],
+            2.0,
+            &Color { a: 0.5, ..WHITE },
+        );
+    });
+    assert_golden(
+        &renderer,
+        &[
+            "++++++++", "++++++++", "++....++", "++....++", "++....++", "++....++", "++++++++",
+            "++++++++",
+        ],
+    );
+    assert_eq!(renderer.pixel(0, 0), renderer.pixel(0, 3));
+    assert_eq!(renderer.last_frame_stats().draw_calls, 1);
+}
+
+#[test]
+fn test_software_renderer_text() {
+    let renderer = render(24, 18, |session| {
+        session.draw_text("Hi", &TextFormat::default(), &rect(0.0, 0.0, 24.0, 18.0));
+    });
+    let mut golden = vec!["........................"];
+    golden.extend([".######..######........."; 14]);
+    golden.extend(["........................"; 3]);
+    assert_golden(&renderer, &golden);
+
+    // Aligned to the bottom right and clipped to the rectangle.
+    let format = TextFormat {
+        horizontal_alignment: HorizontalAlignment::Right,
+        vertical_alignment: VerticalAlignment::Bottom,
+        overflow: TextOverflow::Clip,
+        ..TextFormat::default()
+    };
+    let renderer = render(24, 18, |session| {
+        session.draw_text("ab", &format, &rect(4.0, 4.0, 20.0, 14.0));
+    });
+    // The text is taller than the rectangle, so it is cut at its top edge.
+    let mut golden = vec!["........................"; 4];
+    golden.extend([".........######..######."; 13]);
+    golden.push("........................");
+    assert_golden(&renderer, &golden);
+}
+
+#[test]
+fn test_software_renderer_text_styled() {
+    let red = Color::new(1.0, 0.0, 0.0, 1.0);
+    let blue = Color::new(0.0, 0.0, 1.0, 1.0);
+    // Wrapped onto two lines, "é" taking two bytes: the spans follow the characters.
+    let format = TextFormat {
+        wrapping: TextWrapping::Character,
+        ..TextFormat::default()
+    };
+    let renderer = render(16, 32, |session| {
+        session.draw_text_styled(
+            "aébc",
+            &format,
+            &rect(0.0, 0.0, 16.0, 32.0),
+            &[(1..4, red), (3..4, blue)],
+        );
+    });
+    assert_eq!(renderer.pixel(4, 8), [255, 255, 255, 255]);
+    assert_eq!(renderer.pixel(12, 8), [255, 0, 0, 255]);
+    assert_eq!(renderer.pixel(4, 24), [0, 0, 255, 255]);
+    assert_eq!(renderer.pixel(12, 24), [255, 255, 255, 255]);
+}
+
+#[test]
+fn test_software_renderer_text_layout() {
+    let red = Color::new(1.0, 0.0, 0.0, 1.0);
+    let format = TextFormat {
+        horizontal_alignment: HorizontalAlignment::Right,
+        ..TextFormat::default()
+    };
+    let mut layout = SoftwareRenderer::new(Size::new(1, 1)).create_text_layout(
+        "a",
+        &format,
+        Size::new(16.0, 16.0),
+    );
+    assert_eq!(
+        layout.rect_at(&Vector2::new(8.0, 0.0)),
+        rect(8.0, 0.0, 16.0, 16.0)
+    );
+
+    // Drawn in the layout box at the origin, aligned by its format and in the given color.
+    let renderer = render(24, 16, |session| {
+        session.draw_text_layout(&layout, &Vector2::new(8.0, 0.0), &red);
+    });
+    assert_eq!(renderer.pixel(4, 8), [0, 0, 0, 255]);
+    assert_eq!(renderer.pixel(12, 8), [0, 0, 0, 255]);
+    assert_eq!(renderer.pixel(20, 8), [255, 0, 0, 255]);
+    assert_eq!(renderer.last_frame_stats().text_runs, 1);
+
+    layout.update_text("ab");
+    assert_eq!(layout.text(), "ab");
+    let renderer = render(24, 16, |session| {
+        session.draw_text_layout(&layout, &Vector2::new(8.0, 0.0), &red);
+    });
+    assert_eq!(renderer.pixel(12, 8), [255, 0, 0, 255]);
+    assert_eq!(renderer.pixel(20, 8), [255, 0, 0, 255]);
+}
+
+#[test]
+#[should_panic(expected = "is not a range of characters")]
+fn test_software_renderer_text_styled_span_inside_character() {
+    render(16, 16, |session| {
+        session.draw_text_styled(
+            "é",
+            &TextFormat::default(),
+            &rect(0.0, 0.0, 16.0, 16.0),
+            &[(0..1, BLACK)],
+        );
+    });
+}
+
+#[test]
+fn test_software_renderer_text_decorations() {
+    let format = TextFormat {
+        underline: true,
+        strikethrough: true,
+        ..TextFormat::default()
+    };
+    let renderer = render(16, 16, |session| {
+        session.draw_text("ab", &format, &rect(0.0, 0.0, 16.0, 16.0));
+    });
+    // The strikethrough crosses the gaps between the blocks, the underline is the last row.
+    let mut golden = vec!["................"];
+    golden.extend([".######..######."; 7]);
+    golden.push("################");
+    golden.extend([".######..######."; 6]);
+    golden.push("################");
+    assert_golden(&renderer, &golden);
+}
+
+#[test]
+fn test_software_renderer_load_font_from_bytes() {
+    let renderer = SoftwareRenderer::new(Size::new(16, 16));
+    let font = renderer
+        .load_font_from_bytes(include_bytes!("fonts/box.ttf"))
+        .unwrap();
+    assert_eq!(font, font.clone());
+    assert_ne!(
+        font,
+        renderer
+            .load_font_from_bytes(include_bytes!("fonts/box.ttf"))
+            .unwrap()
+    );
+
+    // Every font is measured with the text fallback.
+    let format = TextFormat {
+        font: Some(font),
+        ..TextFormat::default()
+    };
+    assert_eq!(
+        renderer.measure_text("sky", &format, f32::MAX),
+        renderer.measure_text("sky", &TextFormat::default(), f32::MAX)
+    );
+
+    assert!(renderer.load_font_from_bytes(b"not a font").is_err());
+    assert!(renderer.load_font_from_bytes(&[]).is_err());
+}
+
+#[test]
+fn test_software_renderer_measure_text() {
+    let renderer = SoftwareRenderer::new(Size::new(16, 16));
+    let format = TextFormat::default();
+    assert_eq!(
+        renderer.measure_text("", &format, f32::MAX),
+        TextMetrics::default()
+    );
+
+    let metrics = renderer.measure_text("sky  ", &format, f32::MAX);
+    assert_eq!(metrics.width, 3.0 * GLYPH_CELL_SIZE.width);
+    assert_eq!(
+        metrics.width_including_trailing_whitespace,
+        5.0 * GLYPH_CELL_SIZE.width
+    );
+    assert_eq!(metrics.height, GLYPH_CELL_SIZE.height);
+    assert_eq!(metrics.line_count, 1);
+
+    // Line breaks, then wrapping at the last character fitting.
+    let metrics = renderer.measure_text("sky\nlabs", &format, 3.0 * GLYPH_CELL_SIZE.width);
+    assert_eq!(metrics.line_count, 3);
+    assert_eq!(metrics.width, 3.0 * GLYPH_CELL_SIZE.width);
+    assert_eq!(metrics.size(), Size::new(24.0, 48.0));
+}
+
+#[test]
+fn test_software_renderer_text_wrapping() {
+    let renderer = SoftwareRenderer::new(Size::new(16, 16));
+    let width = 6.0 * GLYPH_CELL_SIZE.width;
+    let lines = |wrapping| {
+        let format = TextFormat {
+            wrapping,
+            ..TextFormat::default()
+        };
+        renderer
+            .measure_text("sky labs game", &format, width)
+            .line_count
+    };
+    assert_eq!(lines(TextWrapping::None), 1);
+    // "sky", "labs", "game" against "sky la", "bs gam", "e".
+    assert_eq!(lines(TextWrapping::Word), 3);
+    assert_eq!(lines(TextWrapping::Character), 3);
+    let format = TextFormat::default();
+    assert_eq!(
+        renderer.measure_text("sky labs game", &format, width).width,
+        4.0 * GLYPH_CELL_SIZE.width
+    );
+    let format = TextFormat {
+        wrapping: TextWrapping::Character,
+        ..TextFormat::default()
+    };
+    assert_eq!(
+        renderer.measure_text("sky labs game", &format, width).width,
+        width
+    );
+
+    // Words longer than a line are broken, and line breaks are kept without wrapping.
+    assert_eq!(
+        renderer
+            .measure_text("skylabsgame", &TextFormat::default(), width)
+            .line_count,
+        2
+    );
+    let format = TextFormat {
+        wrapping: TextWrapping::None,
+        ..TextFormat::default()
+    };
+    assert_eq!(
+        renderer
+            .measure_text("sky\nlabs", &format, width)
+            .line_count,
+        2
+    );
+
+    // Without wrapping, the text is clipped to the rectangle.
+    let renderer = render(16, 16, |session| {
+        session.draw_text("abc", &format, &rect(0.0, 0.0, 12.0, 16.0));
+    });
+    let mut golden = vec!["................"];
+    golden.extend([".######..###...."; 14]);
+    golden.push("................");
+    assert_golden(&renderer, &golden);
+}
+
+#[test]
+fn test_software_renderer_text_line_spacing() {
+    let renderer = SoftwareRenderer::new(Size::new(16, 16));
+    let format = TextFormat {
+        line_spacing: 1.5,
+        ..TextFormat::default()
+    };
+    let metrics = renderer.measure_text("a\nb\nc", &format, f32::MAX);
+    assert_eq!(metrics.line_count, 3);
+    assert_eq!(metrics.height, 3.0 * 1.5 * GLYPH_CELL_SIZE.height);
+
+    let renderer = render(8, 40, |session| {
+        session.draw_text("a\nb", &format, &rect(0.0, 0.0, 8.0, 40.0));
+    });
+    assert_eq!(renderer.pixel(4, 8), [255, 255, 255, 255]);
+    assert_eq!(renderer.pixel(4, 20), [0, 0, 0, 255]);
+    assert_eq!(renderer.pixel(4, 32), [255, 255, 255, 255]);
+}
+
+#[test]
+fn test_software_renderer_text_max_lines() {
+    let renderer = SoftwareRenderer::new(Size::new(16, 16));
+    let width = 6.0 * GLYPH_CELL_SIZE.width;
+    let format = TextFormat {
+        max_lines: Some(2),
+        ..TextFormat::default()
+    };
+    // "sky", then "labs" ending with an ellipsis instead of "game".
+    let metrics = renderer.measure_text("sky labs game", &format, width);
+    assert_eq!(metrics.line_count, 2);
+    assert_eq!(metrics.width, 5.0 * GLYPH_CELL_SIZE.width);
+    assert_eq!(metrics.height, 2.0 * GLYPH_CELL_SIZE.height);
+
+    // The ellipsis replaces the last character of a full line.
+    let metrics = renderer.measure_text("skylabsgame", &format, width);
+    assert_eq!(metrics.line_count, 2);
+    assert_eq!(metrics.width, width);
+
+    // Text with fewer lines than the maximum is left as is.
+    let unlimited = TextFormat::default();
+    for text in ["sky", "sky labs"] {
+        assert_eq!(
+            renderer.measure_text(text, &format, width),
+            renderer.measure_text(text, &unlimited, width)
+        );
+        let limited = render(48, 32, |session| {
+            session.draw_text(text, &format, &rect(0.0, 0.0, 48.0, 32.0))
+        });
+        let reference = render(48, 32, |session| {
+            session.draw_text(text, &unlimited, &rect(0.0, 0.0, 48.0, 32.0))
+        });
+        assert_eq!(
+            limited.framebuffer().to_vec(),
+            reference.framebuffer().to_vec()
+        );
+    }
+}
+
+#[test]
+fn test_software_renderer_text_measured_rect_does_not_clip() {
+    let text = "sky labs is a game engine";
+    for wrapping in [
+        TextWrapping::None,
+        TextWrapping::Word,
+        TextWrapping::Character,
+    ] {
+        for max_lines in [None, Some(2)] {
+            let format = TextFormat {
+                wrapping,
+                line_spacing: 1.25,
+                max_lines,
+                ..TextFormat::default()
+            };
+            let clipped = TextFormat {
+                overflow: TextOverflow::Clip,
+                ..format.clone()
+            };
+            let renderer = SoftwareRenderer::new(Size::new(16, 16));
+            let metrics = renderer.measure_text(text, &format, 10.0 * GLYPH_CELL_SIZE.width);
+            let bounds = rect(0.0, 0.0, metrics.width, metrics.height);
+            let size = Size::new(240, 80);
+            let visible = render(size.width, size.height, |session| {
+                session.draw_text(text, &format, &bounds)
+            });
+            let cut = render(size.width, size.height, |session| {
+                session.draw_text(text, &clipped, &bounds)
+            });
+            assert_eq!(
+                visible.framebuffer().to_vec(),
+                cut.framebuffer().to_vec(),
+                "{wrapping:?} with {max_lines:?} lines"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_software_renderer_blending() {
+    let renderer = render(2, 1, |session| {
+        session.draw_rectangle(&rect(0.0, 0.0, 1.0, 1.0), &Color::new(1.0, 1.0, 1.0, 0.5));
+        session.draw_rectangle(&rect(1.0, 0.0, 1.0, 1.0), &Color::new(1.0, 0.0, 0.0, 0.0));
+    });
+    assert_eq!(renderer.pixel(0, 0), [128, 128, 128, 255]);
+    assert_eq!(renderer.pixel(1, 0), [0, 0, 0, 255]);
+
+    // Sessions draw over what previous sessions submitted.
+    let mut session = renderer.begin_draw();
+    session.draw_rectangle(&rect(0.0, 0.0, 1.0, 1.0), &Color::new(0.0, 0.0, 0.0, 0.5));
+    renderer.end_draw(session);
+    assert_eq!(renderer.pixel(0, 0), [64, 64, 64, 255]);
+}
+
+#[test]
+fn test_software_renderer_draw_order() {
+    let red = Color::new(1.0, 0.0, 0.0, 1.0);
+    let blue = Color::new(0.0, 0.0, 1.0, 1.0);
+    // A panel, a text over it, and a badge over the text.
+    let draw = |session: &mut SoftwareDrawingSession, flush: bool| {
+        session.draw_rectangle(&rect(0.0, 0.0, 16.0, 18.0), &red);
+        if flush {
+            session.flush();
+        }
+        session.draw_text("H", &TextFormat::default(), &rect(0.0, 0.0, 24.0, 18.0));
+        if flush {
+            session.flush();
+        }
+        session.draw_rectangle(&rect(4.0, 4.0, 8.0, 8.0), &blue);
+    };
+
+    for flush in [false, true] {
+        let renderer = render(24, 18, |session| draw(session, flush));
+        // The text covers the panel, and the badge covers both.
+        assert_eq!(renderer.pixel(2, 2), [255, 255, 255, 255]);
+        assert_eq!(renderer.pixel(5, 5), [0, 0, 255, 255]);
+        assert_eq!(renderer.pixel(10, 5), [0, 0, 255, 255]);
+        assert_eq!(renderer.pixel(10, 14), [255, 0, 0, 255]);
+        assert_eq!(renderer.pixel(20, 2), [0, 0, 0, 255]);
+    }
+}
+
+/// Quad from `(left, top)` to `(right, bottom)` in clip space at depth `z`, as a mesh.
+fn quad(left: f32, top: f32, right: f32, bottom: f32, z: f32) -> ([Vector3<f32>; 4], [u32; 6]) {
+    (
+        [
+            Vector3::new(left, top, z),
+            Vector3::new(right, top, z),
+            Vector3::new(right, bottom, z),
+            Vector3::new(left, bottom, z),
+        ],
+        [0, 1, 2, 0, 2, 3],
+    )
+}
+
+#[test]
+fn test_software_renderer_mesh_depth_occlusion() {
+    let red = Color::new(1.0, 0.0, 0.0, 1.0);
+    let green = Color::new(0.0, 1.0, 0.0, 1.0);
+    let (near_vertices, near_indices) = quad(-0.5, 0.5, 0.5, -0.5, 0.25);
+    let (far_vertices, far_indices) = quad(-1.0, 1.0, 1.0, -1.0, 0.75);
+    let identity = Matrix4x4::identity();
+
+    for near_first in [true, false] {
+        let renderer = render(8, 8, |session| {
+            session.clear_depth(1.0);
+            let draw_near = |session: &mut SoftwareDrawingSession| {
+                session.draw_mesh(&near_vertices, &near_indices, &identity, &red)
+            };
+            if near_first {
+                draw_near(session);
+            }
+            session.draw_mesh(&far_vertices, &far_indices, &identity, &green);
+            if !near_first {
+                draw_near(session);
+            }
+        });
+        assert_eq!(
+            renderer.pixel(4, 4),
+            [255, 0, 0, 255],
+            "near first: {}",
+            near_first
+        );
+        assert_eq!(
+            renderer.pixel(2, 2),
+            [255, 0, 0, 255],
+            "near first: {}",
+            near_first
+        );
+        assert_eq!(
+            renderer.pixel(1, 1),
+            [0, 255, 0, 255],
+            "near first: {}",
+            near_first
+        );
+        assert_eq!(
+            renderer.pixel(7, 7),
+            [0, 255, 0, 255],
+            "near first: {}",
+            near_first
+        );
+    }
+}
+
+#[test]
+fn test_software_renderer_mesh_depth_range() {
+    let (vertices, indices) = quad(-1.0, 1.0, 1.0, -1.0, 0.5);
+    let renderer = render(4, 4, |session| {
+        // Nothing is nearer than a depth buffer cleared to 0.
+        session.clear_depth(0.0);
+        session.draw_mesh(&vertices, &indices, &Matrix4x4::identity(), &WHITE);
+    });
+    assert_eq!(renderer.pixel(1, 1), [0, 0, 0, 255]);
+
+    let renderer = render(4, 4, |session| {
+        // Beyond the far plane of the projection.
+        let projection =
+            Matrix4x4::<f32>::make_perspective(1.5, 1.0, 0.1, 10.0, DepthRange::ZeroToOne);
+        let (vertices, indices) = quad(-10.0, 10.0, 10.0, -10.0, 20.0);
+        session.draw_mesh(&vertices, &indices, &projection, &WHITE);
+    });
+    assert_eq!(renderer.pixel(1, 1), [0, 0, 0, 255]);
+
+    let renderer = render(4, 4, |session| {
+        let projection =
+            Matrix4x4::<f32>::make_perspective(1.5, 1.0, 0.1, 10.0, DepthRange::ZeroToOne);
+        let (vertices, indices) = quad(-5.0, 5.0, 5.0, -5.0, 5.0);
+        session.draw_mesh(&vertices, &indices, &projection, &WHITE);
+        assert_eq!(session.stats().triangles, 2);
+    });
+    assert_eq!(renderer.pixel(1, 1), [255, 255, 255, 255]);
+}
+
+#[test]
+fn test_software_renderer_stats() {
+    let renderer = SoftwareRenderer::new(Size::new(32, 32));
+    assert_eq!(renderer.last_frame_stats(), FrameStats::default());
+
+    let mut session = renderer.begin_draw();
+    session.clear(&BLACK);
+    session.draw_rectangle(&rect(0.0, 0.0, 8.0, 8.0), &WHITE);
+    session.draw_rectangle(&rect(0.0, 0.0, 0.0, 8.0), &WHITE);
+    session.draw_line(
+        &Vector2::new(0.0, 0.0),
+        &Vector2::new(8.0, 8.0),
+        1.0,
+        &WHITE,
+    );
+    session.draw_text("Hi !", &TextFormat::default(), &rect(0.0, 0.0, 32.0, 32.0));
+    let stats = session.stats();
+    assert_eq!(stats.draw_calls, 3);
+    assert_eq!(stats.triangles, 2 + 2 + 3 * 2);
+    assert_eq!(stats.text_runs, 1);
+    renderer.end_draw(session);
+    assert_eq!(renderer.last_frame_stats(), stats);
+}
+
+#[test]
+fn test_software_renderer_frame_index() {
+    let renderer = SoftwareRenderer::new(Size::new(8, 8));
+    assert_eq!(renderer.frame_index(), 0);
+    assert_eq!(renderer.back_buffer_index(), 0);
+
+    for frame in 0..3 {
+        let session = renderer.begin_draw();
+        // Stable while drawing, the index of the frame being drawn.
+        assert_eq!(renderer.frame_index(), frame);
+        assert_eq!(renderer.back_buffer_index(), 0);
+        renderer.end_draw(session);
+        assert_eq!(renderer.frame_index(), frame + 1);
+    }
+
+    // Sessions dropped without `end_draw` aren't counted.
+    drop(renderer.begin_draw());
+    assert_eq!(renderer.frame_index(), 3);
+}
+
+#[test]
+fn test_software_renderer_save_ppm() {
+    let renderer = render(3, 2, |session| {
+        session.draw_rectangle(&rect(0.0, 0.0, 1.0, 1.0), &WHITE)
+    });
+    let path = std::env::temp_dir().join("sky_labs_test_software_renderer_save_ppm.ppm");
+    renderer.save_ppm(&path).unwrap();
+    let data = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let header = b"P6\n3 2\n255\n";
+    assert_eq!(&data[..header.len()], header);
+    assert_eq!(data.len(), header.len() + 3 * 2 * 3);
+    assert_eq!(
+        &data[header.len()..header.len() + 6],
+        [255, 255, 255, 0, 0, 0]
+    );
+}
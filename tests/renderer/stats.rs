@@ -0,0 +1,329 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use std::ops::Range;
+
+use sky_labs::math::{Matrix4x4, Rect, Size, Vector2, Vector3};
+use sky_labs::renderer::geometry::{
+    circle_segment_count, ellipse_vertices, line_vertices, polygon_outline_vertices,
+    polygon_vertices, rectangle_vertices, rounded_rectangle_vertices,
+    DEFAULT_TESSELLATION_TOLERANCE,
+};
+use sky_labs::renderer::software::SoftwareRenderer;
+use sky_labs::renderer::testing::RecordingSession;
+use sky_labs::renderer::*;
+
+/// Drawing session counting operations like the GPU backends do, without drawing anything:
+/// one draw call per shape and one per text run, two triangles per glyph.
+#[derive(Default)]
+struct CountingSession {
+    stats: FrameStats,
+    circle_segments: Option<u32>,
+}
+
+impl CountingSession {
+    /// Counts a draw call of the triangle list `vertices`, none when it is empty.
+    fn record_vertices(&mut self, vertices: &[Vector2<f32>]) {
+        if !vertices.is_empty() {
+            self.stats.record_draw_call(vertices.len() as u32 / 3);
+        }
+    }
+}
+
+impl DrawingSession for CountingSession {
+    fn clear(&mut self, _color: &Color<f32>) {}
+
+    fn draw_text(&mut self, text: &str, _format: &TextFormat, _coord: &Rect<f32>) {
+        self.stats.record_text_run();
+        let glyphs = text.chars().filter(|c| !c.is_whitespace()).count() as u32;
+        if glyphs > 0 {
+            self.stats.record_draw_call(2 * glyphs);
+        }
+    }
+
+    fn draw_text_styled(
+        &mut self,
+        text: &str,
+        format: &TextFormat,
+        coord: &Rect<f32>,
+        _spans: &[(Range<usize>, Color<f32>)],
+    ) {
+        self.draw_text(text, format, coord);
+    }
+
+    fn draw_text_layout(
+        &mut self,
+        layout: &TextLayout,
+        origin: &Vector2<f32>,
+        _color: &Color<f32>,
+    ) {
+        self.draw_text(layout.text(), layout.format(), &layout.rect_at(origin));
+    }
+
+    fn draw_triangle(&mut self, _points: &[Vector2<f32>; 3], _color: &Color<f32>) {
+        self.stats.record_draw_call(1);
+    }
+
+    fn draw_rectangle(&mut self, rect: &Rect<f32>, _color: &Color<f32>) {
+        self.stats
+            .record_draw_call(rectangle_vertices(rect).len() as u32 / 3);
+    }
+
+    fn draw_rounded_rectangle(
+        &mut self,
+        rect: &Rect<f32>,
+        corner_radius: f32,
+        _color: &Color<f32>,
+    ) {
+        let vertices =
+            rounded_rectangle_vertices(rect, corner_radius, DEFAULT_TESSELLATION_TOLERANCE);
+        self.stats.record_draw_call(vertices.len() as u32 / 3);
+    }
+
+    fn draw_line(
+        &mut self,
+        from: &Vector2<f32>,
+        to: &Vector2<f32>,
+        thickness: f32,
+        _color: &Color<f32>,
+    ) {
+        let vertices = line_vertices(*from, *to, thickness);
+        self.stats.record_draw_call(vertices.len() as u32 / 3);
+    }
+
+    fn draw_polygon(&mut self, points: &[Vector2<f32>], _color: &Color<f32>) {
+        self.record_vertices(&polygon_vertices(points));
+    }
+
+    fn draw_polygon_outline(
+        &mut self,
+        points: &[Vector2<f32>],
+        thickness: f32,
+        _color: &Color<f32>,
+    ) {
+        self.record_vertices(&polygon_outline_vertices(points, thickness));
+    }
+
+    fn draw_circle(&mut self, bounds: &Rect<f32>, _color: &Color<f32>) {
+        let segments = self.circle_segments.unwrap_or_else(|| {
+            circle_segment_count(
+                bounds.width.max(bounds.height) / 2.0,
+                DEFAULT_TESSELLATION_TOLERANCE,
+            )
+        });
+        self.record_vertices(&ellipse_vertices(bounds, segments));
+    }
+
+    fn draw_circle_centered_at(&mut self, center: &Vector2<f32>, radius: f32, color: &Color<f32>) {
+        let bounds = Rect {
+            x: center.x - radius,
+            y: center.y - radius,
+            width: 2.0 * radius,
+            height: 2.0 * radius,
+        };
+        self.draw_circle(&bounds, color);
+    }
+
+    fn set_circle_segments(&mut self, segments: Option<u32>) {
+        self.circle_segments = segments;
+    }
+
+    fn clear_depth(&mut self, _value: f32) {}
+
+    fn draw_mesh(
+        &mut self,
+        _vertices: &[Vector3<f32>],
+        indices: &[u32],
+        _transform: &Matrix4x4<f32>,
+        _color: &Color<f32>,
+    ) {
+        self.stats.record_draw_call(indices.len() as u32 / 3);
+    }
+
+    fn stats(&self) -> FrameStats {
+        self.stats
+    }
+}
+
+const SQUARE: Rect<f32> = Rect {
+    x: 4.0,
+    y: 4.0,
+    width: 8.0,
+    height: 8.0,
+};
+
+/// Draws one of each counted operation.
+fn draw_scene(session: &mut dyn DrawingSession) {
+    let white = Color::new(1.0, 1.0, 1.0, 1.0);
+    session.clear(&Color::new(0.0, 0.0, 0.0, 1.0));
+    session.draw_triangle(
+        &[
+            Vector2::new(-1.0, 1.0),
+            Vector2::new(0.0, 1.0),
+            Vector2::new(-1.0, 0.0),
+        ],
+        &white,
+    );
+    session.draw_rectangle(&SQUARE, &white);
+    session.draw_rounded_rectangle(&SQUARE, 0.0, &white);
+    session.draw_text("Hi", &TextFormat::default(), &SQUARE);
+}
+
+/// Draws the shapes tessellated into fans: a circle of 16 segments and a filled and outlined
+/// hexagon.
+fn draw_fans(session: &mut dyn DrawingSession) {
+    let white = Color::new(1.0, 1.0, 1.0, 1.0);
+    session.set_circle_segments(Some(16));
+    session.draw_circle_centered_at(&Vector2::new(8.0, 8.0), 4.0, &white);
+    let hexagon = hexagon();
+    session.draw_polygon(&hexagon, &white);
+    session.draw_polygon_outline(&hexagon, 1.0, &white);
+}
+
+/// Regular hexagon of radius 4 centered in `SQUARE`.
+fn hexagon() -> Vec<Vector2<f32>> {
+    (0..6)
+        .map(|i| {
+            let angle = i as f32 * std::f32::consts::FRAC_PI_3;
+            Vector2::new(8.0 + 4.0 * angle.cos(), 8.0 + 4.0 * angle.sin())
+        })
+        .collect()
+}
+
+/// Statistics of `draw_scene` followed by `draw_fans`.
+const SCENE_STATS: FrameStats = FrameStats {
+    draw_calls: 7,
+    // Triangle, rectangle, rounded rectangle, "Hi", circle, hexagon fan and outline quads.
+    triangles: 1 + 2 + 2 + 4 + 16 + 4 + 6 * 2,
+    text_runs: 1,
+    gpu_wait_ms: 0.0,
+};
+
+#[test]
+fn test_frame_stats_record() {
+    let mut stats = FrameStats::default();
+    stats.record_draw_call(2);
+    stats.record_draw_call(1);
+    stats.record_text_run();
+    assert_eq!(
+        stats,
+        FrameStats {
+            draw_calls: 2,
+            triangles: 3,
+            text_runs: 1,
+            gpu_wait_ms: 0.0,
+        }
+    );
+}
+
+#[test]
+fn test_counting_session_stats() {
+    let mut session = CountingSession::default();
+    assert_eq!(session.stats(), FrameStats::default());
+
+    draw_scene(&mut session);
+    let stats = session.stats();
+    assert_eq!(stats.draw_calls, 4);
+    assert_eq!(stats.triangles, 1 + 2 + 2 + 4);
+    assert_eq!(stats.text_runs, 1);
+
+    session.draw_text("   ", &TextFormat::default(), &SQUARE);
+    assert_eq!(session.stats().text_runs, 2);
+    assert_eq!(session.stats().draw_calls, 4);
+}
+
+#[test]
+fn test_counting_session_polygon_stats() {
+    let mut session = CountingSession::default();
+    let white = Color::new(1.0, 1.0, 1.0, 1.0);
+    let hexagon = hexagon();
+
+    // A fan of 4 triangles fills the hexagon, and each of its 6 edges is a quad.
+    session.draw_polygon(&hexagon, &white);
+    assert_eq!(session.stats().draw_calls, 1);
+    assert_eq!(session.stats().triangles, 4);
+    session.draw_polygon_outline(&hexagon, 1.0, &white);
+    assert_eq!(session.stats().draw_calls, 2);
+    assert_eq!(session.stats().triangles, 4 + 6 * 2);
+
+    // Collinear points have no triangle to draw.
+    let line = [
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 1.0),
+        Vector2::new(2.0, 2.0),
+    ];
+    session.draw_polygon(&line, &white);
+    session.draw_polygon_outline(&line, 1.0, &white);
+    assert_eq!(session.stats().draw_calls, 2);
+}
+
+#[test]
+fn test_software_renderer_scene_stats() {
+    let renderer = SoftwareRenderer::new(Size::new(16, 16));
+    let mut drawing_session = renderer.begin_draw();
+    draw_scene(&mut drawing_session);
+    draw_fans(&mut drawing_session);
+    renderer.end_draw(drawing_session);
+    assert_eq!(renderer.last_frame_stats(), SCENE_STATS);
+}
+
+#[test]
+fn test_recording_session_scene_stats() {
+    let mut session = RecordingSession::new();
+    draw_scene(&mut session);
+    draw_fans(&mut session);
+    assert_eq!(session.stats(), SCENE_STATS);
+
+    // The counting session used to script the other tests agrees with the backends.
+    let mut counting = CountingSession::default();
+    draw_scene(&mut counting);
+    draw_fans(&mut counting);
+    assert_eq!(counting.stats(), SCENE_STATS);
+}
+
+#[cfg(all(target_os = "windows", feature = "renderer-d3d12"))]
+#[test]
+fn test_renderer_last_frame_stats() {
+    let renderer = DefaultRenderer::create_offscreen(Size::new(16, 16)).unwrap();
+    assert_eq!(renderer.last_frame_stats(), FrameStats::default());
+
+    let mut drawing_session = renderer.begin_draw();
+    draw_scene(&mut drawing_session);
+    let recorded = drawing_session.stats();
+    renderer.end_draw(drawing_session);
+
+    let stats = renderer.last_frame_stats();
+    assert_eq!(stats.draw_calls, 4);
+    assert_eq!(stats.text_runs, 1);
+    // Glyph quads of "Hi" come on top of the shape triangles.
+    assert!(stats.triangles >= 1 + 2 + 2 + 2);
+    assert!(stats.gpu_wait_ms >= 0.0);
+    assert_eq!(
+        stats,
+        FrameStats {
+            gpu_wait_ms: stats.gpu_wait_ms,
+            ..recorded
+        }
+    );
+
+    // An empty frame resets the counters.
+    let drawing_session = renderer.begin_draw();
+    renderer.end_draw(drawing_session);
+    assert_eq!(renderer.last_frame_stats().draw_calls, 0);
+}
@@ -17,7 +17,17 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
-#[cfg(test)]
+#[cfg(feature = "renderer")]
+mod debug_overlay;
+#[cfg(feature = "windowing")]
+mod events;
+#[cfg(feature = "windowing")]
+mod input;
+#[cfg(feature = "math")]
 mod math;
-#[cfg(test)]
+#[cfg(feature = "renderer")]
 mod renderer;
+#[cfg(feature = "windowing")]
+mod timer;
+#[cfg(feature = "windowing")]
+mod window;
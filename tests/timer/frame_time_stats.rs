@@ -0,0 +1,107 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::timer::frame_time_stats::DEFAULT_FRAME_TIME_CAPACITY;
+use sky_labs::timer::FrameTimeStats;
+
+/// 59 frames of 16 ms with a stutter of 100 ms in the middle.
+fn stuttering() -> FrameTimeStats {
+    let mut stats = FrameTimeStats::new(60);
+    for frame in 0..60 {
+        stats.record_ms(if frame == 30 { 100.0 } else { 16.0 });
+    }
+    stats
+}
+
+fn assert_near(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-9,
+        "{actual} is not {expected}"
+    );
+}
+
+#[test]
+fn test_frame_time_stats_empty() {
+    let stats = FrameTimeStats::default();
+    assert_eq!(stats.capacity(), DEFAULT_FRAME_TIME_CAPACITY);
+    assert!(stats.is_empty());
+    assert_eq!(stats.average_ms(), 0.0);
+    assert_eq!(stats.percentile_ms(99.0), 0.0);
+    assert_eq!(stats.worst_ms(), 0.0);
+    assert_eq!(stats.histogram(&[10.0, 20.0]), [0, 0, 0]);
+}
+
+#[test]
+fn test_frame_time_stats_percentiles() {
+    let stats = stuttering();
+    assert_eq!(stats.len(), 60);
+    assert_eq!(stats.worst_ms(), 100.0);
+    assert_near(stats.average_ms(), 17.4);
+    // Rank 0.99 * 59 = 58.41, between the last 16 ms frame and the stutter.
+    assert_near(stats.percentile_ms(99.0), 16.0 + 0.41 * 84.0);
+    assert_eq!(stats.percentile_ms(50.0), 16.0);
+    assert_eq!(stats.percentile_ms(0.0), 16.0);
+    assert_eq!(stats.percentile_ms(100.0), 100.0);
+    assert_eq!(stats.percentile_ms(150.0), 100.0);
+}
+
+#[test]
+fn test_frame_time_stats_drops_oldest_frames() {
+    let mut stats = stuttering();
+    for _ in 0..30 {
+        stats.record_ms(20.0);
+    }
+    // The stutter is the oldest frame left.
+    assert_eq!(stats.len(), 60);
+    assert_eq!(stats.frame_times_ms().next(), Some(100.0));
+    assert_eq!(stats.frame_times_ms().last(), Some(20.0));
+    assert_eq!(stats.worst_ms(), 100.0);
+
+    stats.record_ms(20.0);
+    assert_eq!(stats.worst_ms(), 20.0);
+    assert_eq!(stats.percentile_ms(0.0), 16.0);
+    assert_near(stats.average_ms(), (29.0 * 16.0 + 31.0 * 20.0) / 60.0);
+
+    stats.clear();
+    assert!(stats.is_empty());
+    assert_eq!(stats.frame_times_ms().count(), 0);
+    stats.record_ms(5.0);
+    assert_eq!(stats.frame_times_ms().collect::<Vec<_>>(), [5.0]);
+    assert_eq!(stats.percentile_ms(99.0), 5.0);
+}
+
+#[test]
+fn test_frame_time_stats_histogram() {
+    let mut stats = stuttering();
+    stats.record_ms(33.3);
+    assert_eq!(stats.histogram(&[16.0, 17.0, 34.0]), [0, 58, 1, 1]);
+    assert_eq!(stats.histogram(&[]), [60]);
+}
+
+#[test]
+#[should_panic(expected = "ascending order")]
+fn test_frame_time_stats_histogram_unsorted_buckets() {
+    stuttering().histogram(&[20.0, 10.0]);
+}
+
+#[test]
+#[should_panic(expected = "at least 1")]
+fn test_frame_time_stats_zero_capacity() {
+    FrameTimeStats::new(0);
+}
@@ -0,0 +1,50 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+mod frame_time_stats;
+mod step_timer;
+
+use std::time::Duration;
+
+use sky_labs::timer::PerformanceCounter;
+
+#[test]
+fn test_performance_counter_duration_since() {
+    PerformanceCounter::init();
+    let start = PerformanceCounter::now();
+    std::thread::sleep(Duration::from_millis(10));
+    let end = PerformanceCounter::now();
+
+    let elapsed = end.duration_since(&start);
+    assert!(elapsed >= Duration::from_millis(10));
+    assert!((elapsed.as_secs_f64() - (end - start).total_seconds()).abs() < 1e-6);
+    assert_eq!(start.duration_since(&start), Duration::ZERO);
+    // Like `Instant::duration_since`, an earlier counter gives zero rather than panicking.
+    assert_eq!(start.duration_since(&end), Duration::ZERO);
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+fn test_performance_counter_without_backend() {
+    PerformanceCounter::init();
+    assert_eq!(PerformanceCounter::frequency(), 1_000_000_000);
+    let start = PerformanceCounter::now();
+    let end = PerformanceCounter::now();
+    assert!(end >= start);
+}
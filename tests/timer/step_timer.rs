@@ -0,0 +1,96 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use sky_labs::timer::{PerformanceCounter, StepTimer};
+
+/// Returns the counter `seconds` after the start of the tests' timers.
+fn at(seconds: f64) -> PerformanceCounter {
+    PerformanceCounter::init();
+    let ticks = (seconds * PerformanceCounter::frequency() as f64).round() as u64;
+    PerformanceCounter::from_ticks(1_000_000 + ticks)
+}
+
+fn assert_near(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-6,
+        "{actual} is not {expected}"
+    );
+}
+
+#[test]
+fn test_step_timer_elapsed() {
+    let mut timer = StepTimer::starting_at(at(0.0));
+    assert_eq!(timer.elapsed_seconds(), 0.0);
+    timer.tick_at(at(0.5));
+    timer.tick_at(at(0.75));
+    assert_near(timer.elapsed_seconds(), 0.25);
+    assert_near(timer.elapsed_ms(), 250.0);
+    assert_near(timer.real_elapsed_seconds(), 0.25);
+    assert_near(timer.total_elapsed_seconds(), 0.75);
+    assert_near(timer.total_real_elapsed_seconds(), 0.75);
+}
+
+#[test]
+fn test_step_timer_pause_across_ticks() {
+    let mut timer = StepTimer::starting_at(at(0.0));
+    timer.tick_at(at(1.0));
+    timer.pause();
+    assert!(timer.is_paused());
+    for tick in 2..5 {
+        timer.tick_at(at(tick as f64));
+        assert_eq!(timer.elapsed_seconds(), 0.0);
+        // The interface keeps its real time.
+        assert_near(timer.real_elapsed_seconds(), 1.0);
+    }
+    assert_near(timer.total_elapsed_seconds(), 1.0);
+    assert_near(timer.total_real_elapsed_seconds(), 4.0);
+
+    timer.resume();
+    assert!(!timer.is_paused());
+    timer.tick_at(at(4.5));
+    assert_near(timer.elapsed_seconds(), 0.5);
+    assert_near(timer.total_elapsed_seconds(), 1.5);
+    assert_near(timer.total_real_elapsed_seconds(), 4.5);
+}
+
+#[test]
+fn test_step_timer_time_scale_changes() {
+    let mut timer = StepTimer::starting_at(at(0.0));
+    assert_eq!(timer.time_scale(), 1.0);
+    timer.tick_at(at(1.0));
+    timer.set_time_scale(0.25);
+    timer.tick_at(at(2.0));
+    assert_near(timer.elapsed_seconds(), 0.25);
+    assert_near(timer.real_elapsed_seconds(), 1.0);
+    timer.set_time_scale(2.0);
+    timer.tick_at(at(2.5));
+    assert_near(timer.elapsed_seconds(), 1.0);
+    // Scaled while paused, nothing elapses either.
+    timer.pause();
+    timer.tick_at(at(3.0));
+    assert_eq!(timer.elapsed_seconds(), 0.0);
+    assert_near(timer.total_elapsed_seconds(), 2.25);
+    assert_near(timer.total_real_elapsed_seconds(), 3.0);
+}
+
+#[test]
+#[should_panic(expected = "Time scale must be finite")]
+fn test_step_timer_negative_time_scale() {
+    StepTimer::starting_at(at(0.0)).set_time_scale(-1.0);
+}
@@ -0,0 +1,168 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use sky_labs::math::{Size, Vector2};
+use sky_labs::window::*;
+
+#[cfg(target_os = "windows")]
+mod win32;
+
+/// Window double replaying a scripted sequence of message processing results.
+/// Once the script is exhausted the queue is reported as empty.
+struct ScriptedWindow {
+    script: VecDeque<WindowProcessResult>,
+    calls: usize,
+    text_input_enabled: bool,
+}
+
+impl ScriptedWindow {
+    fn with_script(script: Vec<WindowProcessResult>) -> Self {
+        Self {
+            script: script.into(),
+            calls: 0,
+            text_input_enabled: false,
+        }
+    }
+}
+
+impl NativeWindow for ScriptedWindow {
+    fn create() -> Self {
+        Self::with_script(Vec::new())
+    }
+
+    fn size(&self) -> Size<u32> {
+        Size::new(800, 600)
+    }
+
+    fn set_size(&mut self, _size: Size<u32>) {}
+
+    fn is_focused(&self) -> bool {
+        true
+    }
+
+    fn is_minimized(&self) -> bool {
+        false
+    }
+
+    fn dpi(&self) -> u32 {
+        DEFAULT_DPI
+    }
+
+    fn poll_event(&mut self) -> Option<WindowEvent> {
+        None
+    }
+
+    fn fullscreen_mode(&self) -> FullscreenMode {
+        FullscreenMode::Windowed
+    }
+
+    fn set_fullscreen(&mut self, _mode: FullscreenMode) {}
+
+    fn set_title(&mut self, _title: &str) {}
+
+    fn set_cursor_visible(&mut self, _visible: bool) {}
+
+    fn set_cursor_position(&mut self, _position: Vector2<i32>) {}
+
+    fn confine_cursor(&mut self, _confine: bool) {}
+
+    fn set_text_input_enabled(&mut self, enabled: bool) {
+        self.text_input_enabled = enabled;
+    }
+
+    #[cfg(target_os = "windows")]
+    fn handle(&self) -> NativeWindowHandle {
+        NativeWindowHandle::default()
+    }
+
+    fn process_until_end(&mut self) -> WindowProcessResult {
+        // Running out of scripted messages ends the window instead of blocking.
+        match self.pump_messages() {
+            WindowProcessResult::Idle => WindowProcessResult::Exit,
+            result => result,
+        }
+    }
+
+    fn process_message_if_available(&mut self) -> WindowProcessResult {
+        self.calls += 1;
+        self.script.pop_front().unwrap_or(WindowProcessResult::Idle)
+    }
+
+    /// Scripted messages are all pending already, so there is nothing to wait for.
+    fn wait_message(&mut self, _timeout: Option<Duration>) -> WindowProcessResult {
+        self.process_message_if_available()
+    }
+}
+
+#[test]
+fn test_pump_messages_drains_queue() {
+    let mut window = ScriptedWindow::with_script(vec![
+        WindowProcessResult::Processed,
+        WindowProcessResult::Processed,
+        WindowProcessResult::Processed,
+    ]);
+    assert_eq!(window.pump_messages(), WindowProcessResult::Idle);
+    assert_eq!(window.calls, 4);
+    assert_eq!(window.pump_messages(), WindowProcessResult::Idle);
+    assert_eq!(window.calls, 5);
+}
+
+#[test]
+fn test_pump_messages_stops_on_exit() {
+    let mut window = ScriptedWindow::with_script(vec![
+        WindowProcessResult::Processed,
+        WindowProcessResult::Exit,
+        WindowProcessResult::Processed,
+    ]);
+    assert_eq!(window.pump_messages(), WindowProcessResult::Exit);
+    assert_eq!(window.calls, 2);
+    assert_eq!(window.script.len(), 1);
+}
+
+#[test]
+fn test_pump_messages_stops_on_error() {
+    let mut window = ScriptedWindow::with_script(vec![
+        WindowProcessResult::Error(String::from("invalid window handle")),
+        WindowProcessResult::Processed,
+    ]);
+    assert_eq!(
+        window.pump_messages(),
+        WindowProcessResult::Error(String::from("invalid window handle"))
+    );
+    assert_eq!(window.calls, 1);
+}
+
+#[test]
+fn test_scale_factor_from_dpi() {
+    let window = ScriptedWindow::create();
+    assert_eq!(window.scale_factor(), 1.0);
+}
+
+#[test]
+fn test_set_text_input_enabled() {
+    let mut window = ScriptedWindow::create();
+    assert!(!window.text_input_enabled);
+    window.set_text_input_enabled(true);
+    assert!(window.text_input_enabled);
+    window.set_text_input_enabled(false);
+    assert!(!window.text_input_enabled);
+}
@@ -0,0 +1,192 @@
+// Copyright (c) 2025 Lucas B. Andrade
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Tests of `Window`, backed by a Win32 window.
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+use std::time::{Duration, Instant};
+
+use sky_labs::events::{Observable, Observer};
+use sky_labs::math::Size;
+use sky_labs::win::ComApartment;
+use sky_labs::window::*;
+use windows::Win32::System::Threading::GetCurrentThreadId;
+use windows::Win32::UI::WindowsAndMessaging::{
+    PostThreadMessageW, ShowWindow, SW_MINIMIZE, SW_RESTORE, WM_QUIT,
+};
+
+#[test]
+fn test_window_create_and_drop_sequentially() {
+    for _ in 0..2 {
+        let mut window = Window::create();
+        assert_eq!(window.pump_messages(), WindowProcessResult::Idle);
+    }
+}
+
+#[test]
+fn test_window_drop_keeps_other_window_running() {
+    let mut first = Window::create();
+    let second = Window::create();
+    drop(second);
+    // Dropping a window must not end the message loop of the remaining one.
+    assert_eq!(first.pump_messages(), WindowProcessResult::Idle);
+}
+
+/// Waits for messages until the wait ends with something else than a processed message.
+fn wait_until_not_processed(window: &mut Window, timeout: Duration) -> WindowProcessResult {
+    loop {
+        match window.wait_message(Some(timeout)) {
+            WindowProcessResult::Processed => continue,
+            result => return result,
+        }
+    }
+}
+
+#[test]
+fn test_window_wait_message_times_out() {
+    let mut window = Window::create();
+    let timeout = Duration::from_millis(50);
+    let start = Instant::now();
+    assert_eq!(
+        wait_until_not_processed(&mut window, timeout),
+        WindowProcessResult::Idle
+    );
+    assert!(start.elapsed() >= timeout);
+}
+
+#[test]
+fn test_window_wait_message_returns_exit_once() {
+    let mut window = Window::create();
+    assert_eq!(window.pump_messages(), WindowProcessResult::Idle);
+
+    // Posted while the window waits.
+    let thread_id = unsafe { GetCurrentThreadId() };
+    let poster = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(50));
+        unsafe { PostThreadMessageW(thread_id, WM_QUIT, Default::default(), Default::default()) }
+    });
+    assert_eq!(
+        wait_until_not_processed(&mut window, Duration::from_secs(10)),
+        WindowProcessResult::Exit
+    );
+    poster.join().unwrap().unwrap();
+
+    assert_eq!(
+        wait_until_not_processed(&mut window, Duration::from_millis(20)),
+        WindowProcessResult::Idle
+    );
+}
+
+#[test]
+fn test_window_minimized_and_restored() {
+    let mut window = Window::create();
+    assert!(!window.is_minimized());
+    while window.poll_event().is_some() {}
+
+    let events = |window: &mut Window| {
+        assert_eq!(window.pump_messages(), WindowProcessResult::Idle);
+        std::iter::from_fn(|| window.poll_event()).collect::<Vec<_>>()
+    };
+    unsafe {
+        let _ = ShowWindow(window.native_window_handle(), SW_MINIMIZE);
+    }
+    assert!(window.is_minimized());
+    assert!(events(&mut window).contains(&WindowEvent::Minimized));
+
+    unsafe {
+        let _ = ShowWindow(window.native_window_handle(), SW_RESTORE);
+    }
+    assert!(!window.is_minimized());
+    let restored = events(&mut window);
+    assert!(restored.contains(&WindowEvent::Restored));
+    assert!(!restored.contains(&WindowEvent::Minimized));
+}
+
+/// Observer keeping every event it is notified of.
+#[derive(Default)]
+struct EventLog(Vec<WindowEvent>);
+
+impl Observer<WindowEvent> for EventLog {
+    fn on_event(&mut self, event: &WindowEvent) {
+        self.0.push(*event);
+    }
+}
+
+#[test]
+fn test_window_notifies_observers_of_polled_events() {
+    let mut window = Window::create();
+    let log = Rc::new(RefCell::new(EventLog::default()));
+    let observer: Weak<RefCell<dyn Observer<WindowEvent>>> = Rc::downgrade(&log);
+    window.regiter(observer.clone());
+
+    window.set_size(Size::new(640, 480));
+    // Nothing is dispatched until the events are taken.
+    assert!(log.borrow().0.is_empty());
+    let mut polled = Vec::new();
+    while let Some(event) = window.poll_event() {
+        polled.push(event);
+    }
+    assert!(polled.contains(&WindowEvent::Resized(Size::new(640, 480))));
+    assert_eq!(log.borrow().0, polled);
+
+    window.unregister(observer);
+    window.set_size(Size::new(320, 200));
+    while window.poll_event().is_some() {}
+    assert_eq!(log.borrow().0, polled);
+}
+
+#[test]
+fn test_window_drops_released_observers() {
+    let mut window = Window::create();
+    let log = Rc::new(RefCell::new(EventLog::default()));
+    window.regiter(Rc::downgrade(&log) as Weak<RefCell<dyn Observer<WindowEvent>>>);
+    drop(log);
+    window.set_size(Size::new(640, 480));
+    while window.poll_event().is_some() {}
+}
+
+#[test]
+fn test_window_create_in_single_threaded_apartment() {
+    std::thread::spawn(|| {
+        let apartment = ComApartment::single_threaded().unwrap();
+        assert!(apartment.is_initialized());
+        // The window joins the apartment the host already set up instead of failing.
+        for _ in 0..2 {
+            let mut window = Window::create();
+            assert_eq!(window.pump_messages(), WindowProcessResult::Idle);
+        }
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn test_com_apartment_nesting() {
+    std::thread::spawn(|| {
+        let outer = ComApartment::multithreaded().unwrap();
+        let inner = ComApartment::multithreaded().unwrap();
+        assert!(outer.is_initialized());
+        assert!(inner.is_initialized());
+        let other = ComApartment::single_threaded().unwrap();
+        assert!(!other.is_initialized());
+    })
+    .join()
+    .unwrap();
+}